@@ -59,7 +59,14 @@ where
     C::Api: subtensor_custom_rpc_runtime_api::DelegateInfoRuntimeApi<Block>,
     C::Api: subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi<Block>,
     C::Api: subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block>,
     C::Api: subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::NetworkStatsRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::EmissionInfoRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::HotkeysInfoRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::CommitmentsRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::KeySwapRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::ChainConstantsRuntimeApi<Block>,
     B: sc_client_api::Backend<Block> + Send + Sync + 'static,
     P: TransactionPool + 'static,
 {
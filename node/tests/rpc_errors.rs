@@ -0,0 +1,89 @@
+use jsonrpsee::types::ErrorObjectOwned;
+use sp_api::ApiError;
+use subtensor_custom_rpc::{QueryContext, SubtensorRpcError};
+
+fn error_data(error: &ErrorObjectOwned) -> Option<serde_json::Value> {
+    error
+        .data()
+        .map(|data| serde_json::from_str(data.get()).expect("error data is valid json"))
+}
+
+#[test]
+fn test_runtime_error_carries_netuid() {
+    let error: ErrorObjectOwned = SubtensorRpcError::RuntimeError(
+        "Unable to get subnet info".into(),
+        QueryContext::default().with_netuid(7),
+    )
+    .into();
+    assert_eq!(error.code(), 1);
+    assert_eq!(error_data(&error), Some(serde_json::json!({ "netuid": 7 })));
+}
+
+#[test]
+fn test_error_codes_are_distinct() {
+    let context = QueryContext::default();
+    let codes: Vec<i32> = vec![
+        SubtensorRpcError::RuntimeError("".into(), context.clone()).into(),
+        SubtensorRpcError::RuntimeApiUnavailable("".into(), context.clone()).into(),
+        SubtensorRpcError::DecodeFailure("".into(), context.clone()).into(),
+        SubtensorRpcError::BlockNotFound(context).into(),
+    ];
+    assert_eq!(codes, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_runtime_api_unavailable_carries_keys() {
+    let context = QueryContext::default()
+        .with_netuid(1)
+        .with_hotkey(&[1, 2])
+        .with_coldkey(&[3]);
+    let error: ErrorObjectOwned =
+        SubtensorRpcError::RuntimeApiUnavailable("Unable to get stake info".into(), context).into();
+    assert_eq!(error.code(), 2);
+    assert_eq!(error.message(), "Unable to get stake info");
+    assert_eq!(
+        error_data(&error),
+        Some(serde_json::json!({ "netuid": 1, "hotkey": [1, 2], "coldkey": [3] }))
+    );
+}
+
+#[test]
+fn test_empty_context_has_no_data() {
+    let error: ErrorObjectOwned = SubtensorRpcError::BlockNotFound(QueryContext::default()).into();
+    assert_eq!(error.code(), 4);
+    assert!(error.data().is_none());
+}
+
+#[test]
+fn test_api_errors_are_classified() {
+    let context = QueryContext::default().with_hotkey(&[9]);
+
+    let unknown_block = SubtensorRpcError::from_api_error(
+        ApiError::UnknownBlock("0x00".into()),
+        "get delegates info",
+        context.clone(),
+    );
+    assert_eq!(
+        unknown_block,
+        SubtensorRpcError::BlockNotFound(context.clone())
+    );
+
+    let decode = SubtensorRpcError::from_api_error(
+        ApiError::FailedToConvertParameter {
+            function: "get_delegate",
+            error: "bad input".into(),
+        },
+        "get delegates info",
+        context.clone(),
+    );
+    assert_eq!(decode.code(), 3);
+    assert_eq!(decode.context(), &context);
+
+    let other = SubtensorRpcError::from_api_error(
+        ApiError::Application("boom".into()),
+        "get delegates info",
+        context,
+    );
+    assert_eq!(other.code(), 1);
+    assert!(other.message().starts_with("Unable to get delegates info"));
+}
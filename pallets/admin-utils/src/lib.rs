@@ -6,6 +6,7 @@ pub use weights::WeightInfo;
 
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{traits::Member, RuntimeAppPublic};
+use subtensor_macros::freeze_struct;
 
 mod benchmarking;
 
@@ -13,11 +14,100 @@ mod benchmarking;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use codec::DecodeAll;
     use frame_support::dispatch::DispatchResult;
     use frame_support::pallet_prelude::*;
-    use frame_support::traits::tokens::Balance;
+    use frame_support::traits::{tokens::Balance, UnfilteredDispatchable};
     use frame_system::pallet_prelude::*;
-    use sp_runtime::BoundedVec;
+    use sp_runtime::{traits::Saturating, BoundedVec};
+
+    /// Maximum length of an encoded call held by a subnet parameter proposal.
+    pub const MAX_SUBNET_PARAM_CALL_LEN: u32 = 256;
+
+    /// A hyperparameter change proposed by a subnet owner, awaiting ratification by the subnet validators.
+    #[freeze_struct("fdf5f747d6c39fe")]
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+    pub struct SubnetParamProposal<AccountId, BlockNumber> {
+        /// The subnet owner who submitted the proposal.
+        pub proposer: AccountId,
+        /// The encoded admin call dispatched once the proposal is ratified.
+        pub call: BoundedVec<u8, ConstU32<MAX_SUBNET_PARAM_CALL_LEN>>,
+        /// Stake of the validators that voted in favour.
+        pub ayes: u64,
+        /// Stake of the validators that voted against.
+        pub nays: u64,
+        /// Last block at which the proposal can be voted on.
+        pub end: BlockNumber,
+    }
+
+    /// The origin a ratified hyperparameter proposal is dispatched with.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum RawOrigin {
+        /// The validators of the subnet ratified the proposal. It may call the hyperparameter
+        /// setters of that subnet like its owner, and nothing else.
+        SubnetValidators(u16),
+    }
+
+    /// The origin of this pallet.
+    #[pallet::origin]
+    pub type Origin = RawOrigin;
+
+    /// Subnet hyperparameters set together by `sudo_set_subnet_params_batch`. Fields left `None`
+    /// are unchanged.
+    #[freeze_struct("a37601b10ac04156")]
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default, TypeInfo)]
+    pub struct SubnetParamUpdate {
+        /// Blocks between axon or prometheus updates of a neuron.
+        pub serving_rate_limit: Option<u64>,
+        /// Minimum POW registration difficulty.
+        pub min_difficulty: Option<u64>,
+        /// Maximum POW registration difficulty.
+        pub max_difficulty: Option<u64>,
+        /// Minimum weights version key of the validators.
+        pub weights_version_key: Option<u64>,
+        /// Blocks between weight updates of a neuron.
+        pub weights_set_rate_limit: Option<u64>,
+        /// Alpha of the registration difficulty adjustment.
+        pub adjustment_alpha: Option<u64>,
+        /// Maximum weight a validator can set on a single uid.
+        pub max_weight_limit: Option<u16>,
+        /// Blocks a new neuron is immune to pruning.
+        pub immunity_period: Option<u16>,
+        /// Minimum number of weights a validator must set.
+        pub min_allowed_weights: Option<u16>,
+        /// Kappa of the epoch consensus.
+        pub kappa: Option<u16>,
+        /// Rho of the epoch incentive.
+        pub rho: Option<u16>,
+        /// Blocks without weights after which a validator is inactive.
+        pub activity_cutoff: Option<u16>,
+        /// Whether registrations are allowed.
+        pub network_registration_allowed: Option<bool>,
+        /// Whether POW registrations are allowed.
+        pub network_pow_registration_allowed: Option<bool>,
+        /// Minimum registration burn.
+        pub min_burn: Option<u64>,
+        /// Maximum registration burn.
+        pub max_burn: Option<u64>,
+        /// POW registration difficulty.
+        pub difficulty: Option<u64>,
+        /// Moving average of the bonds.
+        pub bonds_moving_average: Option<u64>,
+        /// Blocks between a weights commit and its reveal.
+        pub commit_reveal_weights_interval: Option<u64>,
+        /// Whether weights are committed before they are revealed.
+        pub commit_reveal_weights_enabled: Option<bool>,
+        /// Whether liquid alpha is enabled.
+        pub liquid_alpha_enabled: Option<bool>,
+        /// Liquid alpha low and high values.
+        pub alpha_values: Option<(u16, u16)>,
+        /// Whether part of the registration burn is refunded on voluntary deregistration.
+        pub recycle_registration_burn: Option<bool>,
+        /// Minimum version an axon must serve with.
+        pub min_axon_version: Option<u32>,
+        /// Whether an epoch without weights pays a uniform incentive.
+        pub empty_epoch_uniform_incentive: Option<bool>,
+    }
 
     /// The main data structure of the module.
     #[pallet::pallet]
@@ -30,6 +120,12 @@ pub mod pallet {
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// The runtime origin, which can carry the origin ratified hyperparameter proposals are
+        /// dispatched with.
+        type RuntimeOrigin: From<RawOrigin>
+            + Into<Result<RawOrigin, <Self as Config>::RuntimeOrigin>>
+            + IsType<<Self as frame_system::Config>::RuntimeOrigin>;
+
         /// Implementation of the AuraInterface
         type Aura: crate::AuraInterface<Self::AuthorityId, Self::MaxAuthorities>;
 
@@ -48,10 +144,72 @@ pub mod pallet {
 
         /// Unit of assets
         type Balance: Balance;
+
+        /// Origin of a senate proposal allowed to dispatch the whitelisted network-wide setters
+        /// alongside root, yielding the number of ayes and the number of senate seats.
+        type SenateOrigin: EnsureOrigin<
+            <Self as frame_system::Config>::RuntimeOrigin,
+            Success = (u32, u32),
+        >;
     }
 
     #[pallet::event]
-    pub enum Event<T: Config> {}
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A subnet owner proposed a hyperparameter change to the subnet validators.
+        SubnetParamProposed {
+            /// The subnet the proposal applies to.
+            netuid: u16,
+            /// The index of the proposal on this subnet.
+            proposal_index: u32,
+            /// The subnet owner who made the proposal.
+            proposer: T::AccountId,
+        },
+        /// A subnet validator voted on a hyperparameter proposal.
+        SubnetParamVoted {
+            /// The subnet the proposal applies to.
+            netuid: u16,
+            /// The index of the proposal on this subnet.
+            proposal_index: u32,
+            /// The validator hotkey that voted.
+            hotkey: T::AccountId,
+            /// Whether the vote was in favour.
+            approve: bool,
+            /// Total stake in favour after this vote.
+            ayes: u64,
+            /// Total stake against after this vote.
+            nays: u64,
+        },
+        /// A ratified hyperparameter proposal was dispatched.
+        SubnetParamExecuted {
+            /// The subnet the proposal applies to.
+            netuid: u16,
+            /// The index of the proposal on this subnet.
+            proposal_index: u32,
+            /// The result of dispatching the proposed call.
+            result: DispatchResult,
+        },
+        /// A hyperparameter proposal ran out of time and was removed.
+        SubnetParamProposalExpired {
+            /// The subnet the proposal applied to.
+            netuid: u16,
+            /// The index of the proposal on this subnet.
+            proposal_index: u32,
+        },
+        /// The voting period for hyperparameter proposals was set.
+        SubnetParamVotingPeriodSet(BlockNumberFor<T>),
+        /// The approval threshold for hyperparameter proposals was set.
+        SubnetParamApprovalThresholdSet(u16),
+        /// A batch of subnet hyperparameters was set.
+        SubnetParamsBatchSet {
+            /// The subnet the hyperparameters apply to.
+            netuid: u16,
+            /// The hyperparameters that were set.
+            update: SubnetParamUpdate,
+        },
+        /// The share of the senate that must approve a senate proposal was set.
+        SenateApprovalThresholdSet(u16),
+    }
 
     // Errors inform users that something went wrong.
     #[pallet::error]
@@ -62,6 +220,103 @@ pub mod pallet {
         MaxValidatorsLargerThanMaxUIds,
         /// The maximum number of subnet validators must be more than the current number of UIDs already in the subnet.
         MaxAllowedUIdsLessThanCurrentUIds,
+        /// The caller is not the owner of the subnet.
+        NotSubnetOwner,
+        /// Validator governance of hyperparameters is not enabled on this subnet.
+        ParamGovernanceDisabled,
+        /// The encoded call is longer than a proposal can hold.
+        SubnetParamCallTooLong,
+        /// The encoded call is not a hyperparameter setter for this subnet.
+        SubnetParamCallNotAllowed,
+        /// The hyperparameter proposal does not exist.
+        SubnetParamProposalNotFound,
+        /// The voting period of the hyperparameter proposal is over.
+        SubnetParamProposalExpired,
+        /// The coldkey does not own the voting hotkey.
+        NonAssociatedColdKey,
+        /// The voting hotkey is not registered on the subnet.
+        HotKeyNotRegisteredInSubNet,
+        /// The voting hotkey does not hold a validator permit on the subnet.
+        NoValidatorPermit,
+        /// The hotkey has already voted on this proposal.
+        DuplicateSubnetParamVote,
+        /// The subnet owner updated hyperparameters within the owner hyperparameter rate limit.
+        OwnerHyperparamRateLimitExceeded,
+        /// The epoch history depth is above `MAX_EPOCH_HISTORY_DEPTH`.
+        EpochHistoryDepthTooHigh,
+        /// The registration burn owner share is above its cap.
+        RegistrationBurnOwnerShareTooHigh,
+        /// The max weights limit length is above the max allowed uids of the subnet.
+        MaxWeightsLimitLenTooHigh,
+        /// The emission multiplier is above `MAX_EMISSION_MULTIPLIER`.
+        EmissionMultiplierTooHigh,
+        /// The senate proposal was approved by less than `SenateApprovalThreshold` of the senate.
+        SenateApprovalBelowThreshold,
+        /// The root network cannot be dissolved.
+        CannotDissolveRootNetwork,
+        /// The minimum key swap cost would be above the maximum key swap cost.
+        MinKeySwapCostAboveMax,
+    }
+
+    #[pallet::type_value]
+    /// Default number of blocks a hyperparameter proposal stays open for voting.
+    pub fn DefaultSubnetParamVotingPeriod<T: Config>() -> BlockNumberFor<T> {
+        BlockNumberFor::<T>::from(7200_u32)
+    }
+    #[pallet::type_value]
+    /// Default share of validator stake, out of u16::MAX, that must approve a proposal.
+    pub fn DefaultSubnetParamApprovalThreshold<T: Config>() -> u16 {
+        u16::MAX / 2
+    }
+    #[pallet::type_value]
+    /// Default share of the senate, out of u16::MAX, that must approve a senate proposal.
+    pub fn DefaultSenateApprovalThreshold<T: Config>() -> u16 {
+        u16::MAX / 3 * 2
+    }
+
+    #[pallet::storage]
+    /// ITEM( subnet_param_voting_period )
+    pub type SubnetParamVotingPeriod<T: Config> =
+        StorageValue<_, BlockNumberFor<T>, ValueQuery, DefaultSubnetParamVotingPeriod<T>>;
+    #[pallet::storage]
+    /// ITEM( subnet_param_approval_threshold )
+    pub type SubnetParamApprovalThreshold<T> =
+        StorageValue<_, u16, ValueQuery, DefaultSubnetParamApprovalThreshold<T>>;
+    #[pallet::storage]
+    /// ITEM( senate_approval_threshold )
+    pub type SenateApprovalThreshold<T> =
+        StorageValue<_, u16, ValueQuery, DefaultSenateApprovalThreshold<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> index of the next hyperparameter proposal
+    pub type SubnetParamProposalCount<T> = StorageMap<_, Identity, u16, u32, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, proposal_index ) --> proposal
+    pub type SubnetParamProposals<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Identity,
+        u32,
+        SubnetParamProposal<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- DMAP ( (netuid, proposal_index), hotkey ) --> approve
+    pub type SubnetParamVotes<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        (u16, u32),
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        OptionQuery,
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::remove_expired_subnet_param_proposals(now, remaining_weight)
+        }
     }
 
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -87,24 +342,24 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the default take for the network.
-        /// It is only callable by the root account.
+        /// It is callable by the root account or a senate supermajority.
         /// The extrinsic will call the Subtensor pallet to set the default take.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::sudo_set_default_take())]
         pub fn sudo_set_default_take(origin: OriginFor<T>, default_take: u16) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_root_or_senate(origin)?;
             pallet_subtensor::Pallet::<T>::set_max_delegate_take(default_take);
             log::debug!("DefaultTakeSet( default_take: {:?} ) ", default_take);
             Ok(())
         }
 
         /// The extrinsic sets the transaction rate limit for the network.
-        /// It is only callable by the root account.
+        /// It is callable by the root account or a senate supermajority.
         /// The extrinsic will call the Subtensor pallet to set the transaction rate limit.
         #[pallet::call_index(2)]
         #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
         pub fn sudo_set_tx_rate_limit(origin: OriginFor<T>, tx_rate_limit: u64) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_root_or_senate(origin)?;
             pallet_subtensor::Pallet::<T>::set_tx_rate_limit(tx_rate_limit);
             log::debug!("TxRateLimitSet( tx_rate_limit: {:?} ) ", tx_rate_limit);
             Ok(())
@@ -120,7 +375,7 @@ pub mod pallet {
             netuid: u16,
             serving_rate_limit: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             pallet_subtensor::Pallet::<T>::set_serving_rate_limit(netuid, serving_rate_limit);
             log::debug!(
@@ -140,7 +395,7 @@ pub mod pallet {
             netuid: u16,
             min_difficulty: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -165,7 +420,7 @@ pub mod pallet {
             netuid: u16,
             max_difficulty: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -190,7 +445,7 @@ pub mod pallet {
             netuid: u16,
             weights_version_key: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -215,7 +470,7 @@ pub mod pallet {
             netuid: u16,
             weights_set_rate_limit: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -274,7 +529,7 @@ pub mod pallet {
             netuid: u16,
             adjustment_alpha: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -298,7 +553,7 @@ pub mod pallet {
             netuid: u16,
             max_weight_limit: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -323,12 +578,16 @@ pub mod pallet {
             netuid: u16,
             immunity_period: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
 
+            pallet_subtensor::Pallet::<T>::ensure_subnet_params_update(netuid, |params| {
+                params.immunity_period = immunity_period
+            })?;
+
             pallet_subtensor::Pallet::<T>::set_immunity_period(netuid, immunity_period);
             log::debug!(
                 "ImmunityPeriodSet( netuid: {:?} immunity_period: {:?} ) ",
@@ -348,12 +607,15 @@ pub mod pallet {
             netuid: u16,
             min_allowed_weights: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            pallet_subtensor::Pallet::<T>::ensure_subnet_params_update(netuid, |params| {
+                params.min_allowed_weights = min_allowed_weights
+            })?;
             pallet_subtensor::Pallet::<T>::set_min_allowed_weights(netuid, min_allowed_weights);
             log::debug!(
                 "MinAllowedWeightSet( netuid: {:?} min_allowed_weights: {:?} ) ",
@@ -382,6 +644,9 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::get_subnetwork_n(netuid) < max_allowed_uids,
                 Error::<T>::MaxAllowedUIdsLessThanCurrentUIds
             );
+            pallet_subtensor::Pallet::<T>::ensure_subnet_params_update(netuid, |params| {
+                params.max_allowed_uids = max_allowed_uids
+            })?;
             pallet_subtensor::Pallet::<T>::set_max_allowed_uids(netuid, max_allowed_uids);
             log::debug!(
                 "MaxAllowedUidsSet( netuid: {:?} max_allowed_uids: {:?} ) ",
@@ -397,12 +662,15 @@ pub mod pallet {
         #[pallet::call_index(16)]
         #[pallet::weight(T::WeightInfo::sudo_set_kappa())]
         pub fn sudo_set_kappa(origin: OriginFor<T>, netuid: u16, kappa: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            pallet_subtensor::Pallet::<T>::ensure_subnet_params_update(netuid, |params| {
+                params.kappa = kappa
+            })?;
             pallet_subtensor::Pallet::<T>::set_kappa(netuid, kappa);
             log::debug!("KappaSet( netuid: {:?} kappa: {:?} ) ", netuid, kappa);
             Ok(())
@@ -414,12 +682,15 @@ pub mod pallet {
         #[pallet::call_index(17)]
         #[pallet::weight(T::WeightInfo::sudo_set_rho())]
         pub fn sudo_set_rho(origin: OriginFor<T>, netuid: u16, rho: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            pallet_subtensor::Pallet::<T>::ensure_subnet_params_update(netuid, |params| {
+                params.rho = rho
+            })?;
             pallet_subtensor::Pallet::<T>::set_rho(netuid, rho);
             log::debug!("RhoSet( netuid: {:?} rho: {:?} ) ", netuid, rho);
             Ok(())
@@ -435,12 +706,15 @@ pub mod pallet {
             netuid: u16,
             activity_cutoff: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            pallet_subtensor::Pallet::<T>::ensure_subnet_params_update(netuid, |params| {
+                params.activity_cutoff = activity_cutoff
+            })?;
             pallet_subtensor::Pallet::<T>::set_activity_cutoff(netuid, activity_cutoff);
             log::debug!(
                 "ActivityCutoffSet( netuid: {:?} activity_cutoff: {:?} ) ",
@@ -466,7 +740,7 @@ pub mod pallet {
             netuid: u16,
             registration_allowed: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             pallet_subtensor::Pallet::<T>::set_network_registration_allowed(
                 netuid,
@@ -494,7 +768,7 @@ pub mod pallet {
             netuid: u16,
             registration_allowed: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             pallet_subtensor::Pallet::<T>::set_network_pow_registration_allowed(
                 netuid,
@@ -545,7 +819,7 @@ pub mod pallet {
             netuid: u16,
             min_burn: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -570,7 +844,7 @@ pub mod pallet {
             netuid: u16,
             max_burn: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -595,7 +869,7 @@ pub mod pallet {
             netuid: u16,
             difficulty: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -652,7 +926,7 @@ pub mod pallet {
             netuid: u16,
             bonds_moving_average: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -719,7 +993,7 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the network rate limit for the network.
-        /// It is only callable by the root account.
+        /// It is callable by the root account or a senate supermajority.
         /// The extrinsic will call the Subtensor pallet to set the network rate limit.
         #[pallet::call_index(29)]
         #[pallet::weight((
@@ -732,7 +1006,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             rate_limit: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_root_or_senate(origin)?;
             pallet_subtensor::Pallet::<T>::set_network_rate_limit(rate_limit);
             log::debug!("NetworkRateLimit( rate_limit: {:?} ) ", rate_limit);
             Ok(())
@@ -749,6 +1023,9 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            pallet_subtensor::Pallet::<T>::ensure_subnet_params_update(netuid, |params| {
+                params.tempo = tempo
+            })?;
             pallet_subtensor::Pallet::<T>::set_tempo(netuid, tempo);
             log::debug!("TempoSet( netuid: {:?} tempo: {:?} ) ", netuid, tempo);
             Ok(())
@@ -794,7 +1071,7 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the min lock cost for the network.
-        /// It is only callable by the root account.
+        /// It is callable by the root account or a senate supermajority.
         /// The extrinsic will call the Subtensor pallet to set the min lock cost for the network.
         #[pallet::call_index(36)]
         #[pallet::weight((
@@ -807,7 +1084,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             lock_cost: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_root_or_senate(origin)?;
 
             pallet_subtensor::Pallet::<T>::set_network_min_lock(lock_cost);
 
@@ -836,7 +1113,7 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the lock reduction interval for the network.
-        /// It is only callable by the root account.
+        /// It is callable by the root account or a senate supermajority.
         /// The extrinsic will call the Subtensor pallet to set the lock reduction interval.
         #[pallet::call_index(38)]
         #[pallet::weight((
@@ -849,7 +1126,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             interval: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_root_or_senate(origin)?;
 
             pallet_subtensor::Pallet::<T>::set_lock_reduction_interval(interval);
 
@@ -970,7 +1247,7 @@ pub mod pallet {
             netuid: u16,
             interval: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -996,7 +1273,7 @@ pub mod pallet {
             netuid: u16,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -1024,7 +1301,7 @@ pub mod pallet {
             netuid: u16,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
             pallet_subtensor::Pallet::<T>::set_liquid_alpha_enabled(netuid, enabled);
             log::debug!(
                 "LiquidAlphaEnableToggled( netuid: {:?}, Enabled: {:?} ) ",
@@ -1043,7 +1320,12 @@ pub mod pallet {
             alpha_low: u16,
             alpha_high: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+            Self::ensure_subnet_owner_or_root_rate_limited(origin.clone(), netuid)?;
+            if Self::ratified_subnet(origin.clone()).is_some() {
+                return pallet_subtensor::Pallet::<T>::try_set_alpha_values(
+                    netuid, alpha_low, alpha_high,
+                );
+            }
             pallet_subtensor::Pallet::<T>::do_set_alpha_values(
                 origin, netuid, alpha_low, alpha_high,
             )
@@ -1196,6 +1478,1803 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Sets or clears the stake threshold override for a subnet.
+        ///
+        /// When set, the override replaces the chain-wide stake threshold for weight setting on
+        /// this subnet. Passing `None` falls back to the chain-wide value.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `min_stake` - The new stake threshold for the subnet, or `None` to remove the override.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(56)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_stake_threshold(
+            origin: OriginFor<T>,
+            netuid: u16,
+            min_stake: Option<u64>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_subnet_stake_threshold(netuid, min_stake);
+            log::debug!(
+                "SubnetStakeThresholdSet( netuid: {:?} min_stake: {:?} ) ",
+                netuid,
+                min_stake
+            );
+            Ok(())
+        }
+
+        /// Enables or disables validator ratification of hyperparameter changes on a subnet.
+        ///
+        /// While enabled, the subnet owner can no longer call the hyperparameter setters directly
+        /// and must go through `propose_subnet_param` instead.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `enabled` - Whether hyperparameter changes must be ratified by the subnet validators.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(57)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_param_governance_enabled(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            if Self::ratified_subnet(origin.clone()) != Some(netuid) {
+                pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            }
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_param_governance_enabled(netuid, enabled);
+            log::debug!(
+                "ParamGovernanceEnabledSet( netuid: {:?} enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Sets the number of blocks a hyperparameter proposal stays open for voting.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `period` - The new voting period, in number of blocks.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(58)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_param_voting_period(
+            origin: OriginFor<T>,
+            period: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            SubnetParamVotingPeriod::<T>::put(period);
+            Self::deposit_event(Event::SubnetParamVotingPeriodSet(period));
+            log::debug!("SubnetParamVotingPeriodSet( period: {:?} ) ", period);
+            Ok(())
+        }
+
+        /// Sets the share of validator stake, out of `u16::MAX`, that must approve a hyperparameter proposal.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `threshold` - The new approval threshold.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(59)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_param_approval_threshold(
+            origin: OriginFor<T>,
+            threshold: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            SubnetParamApprovalThreshold::<T>::put(threshold);
+            Self::deposit_event(Event::SubnetParamApprovalThresholdSet(threshold));
+            log::debug!(
+                "SubnetParamApprovalThresholdSet( threshold: {:?} ) ",
+                threshold
+            );
+            Ok(())
+        }
+
+        /// Proposes a hyperparameter change to the validators of a subnet under validator governance.
+        ///
+        /// The encoded call must be one of the subnet owner hyperparameter setters of this pallet,
+        /// targeting `netuid`. It is dispatched with the `SubnetValidators(netuid)` origin once
+        /// ratified, which the setters accept in place of the subnet owner.
+        ///
+        /// # Arguments
+        /// * `origin` - The subnet owner coldkey.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `encoded_call` - The SCALE encoded call of this pallet to dispatch.
+        ///
+        /// # Errors
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `NotSubnetOwner` - If the caller does not own the subnet.
+        /// * `ParamGovernanceDisabled` - If the subnet is not under validator governance.
+        /// * `SubnetParamCallTooLong` - If the encoded call does not fit in a proposal.
+        /// * `SubnetParamCallNotAllowed` - If the call is not a hyperparameter setter for this subnet.
+        #[pallet::call_index(60)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn propose_subnet_param(
+            origin: OriginFor<T>,
+            netuid: u16,
+            encoded_call: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                pallet_subtensor::SubnetOwner::<T>::get(netuid) == who,
+                Error::<T>::NotSubnetOwner
+            );
+            ensure!(
+                pallet_subtensor::Pallet::<T>::get_param_governance_enabled(netuid),
+                Error::<T>::ParamGovernanceDisabled
+            );
+
+            let call: BoundedVec<u8, ConstU32<MAX_SUBNET_PARAM_CALL_LEN>> = encoded_call
+                .try_into()
+                .map_err(|_| Error::<T>::SubnetParamCallTooLong)?;
+            let decoded = Call::<T>::decode_all(&mut &call[..])
+                .map_err(|_| Error::<T>::SubnetParamCallNotAllowed)?;
+            ensure!(
+                Self::subnet_param_call_netuid(&decoded) == Some(netuid),
+                Error::<T>::SubnetParamCallNotAllowed
+            );
+
+            let proposal_index = SubnetParamProposalCount::<T>::get(netuid);
+            SubnetParamProposalCount::<T>::insert(netuid, proposal_index.saturating_add(1));
+            let end = frame_system::Pallet::<T>::block_number()
+                .saturating_add(SubnetParamVotingPeriod::<T>::get());
+            SubnetParamProposals::<T>::insert(
+                netuid,
+                proposal_index,
+                SubnetParamProposal {
+                    proposer: who.clone(),
+                    call,
+                    ayes: 0,
+                    nays: 0,
+                    end,
+                },
+            );
+
+            log::debug!(
+                "SubnetParamProposed( netuid: {:?} proposal_index: {:?} ) ",
+                netuid,
+                proposal_index
+            );
+            Self::deposit_event(Event::SubnetParamProposed {
+                netuid,
+                proposal_index,
+                proposer: who,
+            });
+            Ok(())
+        }
+
+        /// Votes on a hyperparameter proposal with the stake of a validator hotkey.
+        ///
+        /// The voting power of the hotkey is its total stake, and only hotkeys holding a validator
+        /// permit on the subnet may vote. Once the approving stake exceeds the approval threshold
+        /// share of all permitted validator stake, the proposed call is dispatched.
+        ///
+        /// # Arguments
+        /// * `origin` - The coldkey owning `hotkey`.
+        /// * `hotkey` - The validator hotkey voting.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `proposal_index` - The index of the proposal on this subnet.
+        /// * `approve` - Whether the vote is in favour.
+        ///
+        /// # Errors
+        /// * `SubnetParamProposalNotFound` - If the proposal does not exist.
+        /// * `SubnetParamProposalExpired` - If the voting period is over.
+        /// * `NonAssociatedColdKey` - If the caller does not own the hotkey.
+        /// * `HotKeyNotRegisteredInSubNet` - If the hotkey is not registered on the subnet.
+        /// * `NoValidatorPermit` - If the hotkey does not hold a validator permit.
+        /// * `DuplicateSubnetParamVote` - If the hotkey already voted on the proposal.
+        #[pallet::call_index(61)]
+        #[pallet::weight((Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4_104))
+            .saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn vote_subnet_param(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+            proposal_index: u32,
+            approve: bool,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            let mut proposal = SubnetParamProposals::<T>::get(netuid, proposal_index)
+                .ok_or(Error::<T>::SubnetParamProposalNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= proposal.end,
+                Error::<T>::SubnetParamProposalExpired
+            );
+            ensure!(
+                pallet_subtensor::Pallet::<T>::coldkey_owns_hotkey(&coldkey, &hotkey),
+                Error::<T>::NonAssociatedColdKey
+            );
+            let uid = pallet_subtensor::Pallet::<T>::get_uid_for_net_and_hotkey(netuid, &hotkey)
+                .map_err(|_| Error::<T>::HotKeyNotRegisteredInSubNet)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::get_validator_permit_for_uid(netuid, uid),
+                Error::<T>::NoValidatorPermit
+            );
+            ensure!(
+                !SubnetParamVotes::<T>::contains_key((netuid, proposal_index), &hotkey),
+                Error::<T>::DuplicateSubnetParamVote
+            );
+
+            let stake = pallet_subtensor::Pallet::<T>::get_total_stake_for_hotkey(&hotkey);
+            if approve {
+                proposal.ayes = proposal.ayes.saturating_add(stake);
+            } else {
+                proposal.nays = proposal.nays.saturating_add(stake);
+            }
+            SubnetParamVotes::<T>::insert((netuid, proposal_index), &hotkey, approve);
+            Self::deposit_event(Event::SubnetParamVoted {
+                netuid,
+                proposal_index,
+                hotkey,
+                approve,
+                ayes: proposal.ayes,
+                nays: proposal.nays,
+            });
+
+            // --- Dispatch the call once enough of the validator stake approves it.
+            let threshold = SubnetParamApprovalThreshold::<T>::get() as u128;
+            let total = Self::subnet_param_voting_power(netuid) as u128;
+            if (proposal.ayes as u128).saturating_mul(u16::MAX as u128)
+                > threshold.saturating_mul(total)
+            {
+                Self::close_subnet_param_proposal(netuid, proposal_index);
+                let result = Call::<T>::decode_all(&mut &proposal.call[..])
+                    .map_err(|_| Error::<T>::SubnetParamCallNotAllowed.into())
+                    .and_then(|call| {
+                        let origin: OriginFor<T> =
+                            <T as Config>::RuntimeOrigin::from(RawOrigin::SubnetValidators(netuid))
+                                .into();
+                        call.dispatch_bypass_filter(origin)
+                            .map(|_| ())
+                            .map_err(|e| e.error)
+                    });
+                log::debug!(
+                    "SubnetParamExecuted( netuid: {:?} proposal_index: {:?} result: {:?} ) ",
+                    netuid,
+                    proposal_index,
+                    result
+                );
+                Self::deposit_event(Event::SubnetParamExecuted {
+                    netuid,
+                    proposal_index,
+                    result,
+                });
+            } else {
+                SubnetParamProposals::<T>::insert(netuid, proposal_index, proposal);
+            }
+            Ok(())
+        }
+
+        /// Sets the share of the maximum block weight a coldkey swap may declare.
+        ///
+        /// Coldkeys whose swap is estimated above this share cannot schedule a swap.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `share` - The share of the maximum block weight, out of u16::MAX.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(62)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_coldkey_swap_max_weight_share(
+            origin: OriginFor<T>,
+            share: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_coldkey_swap_max_weight_share(share);
+            log::debug!("ColdkeySwapMaxWeightShareSet( share: {:?} )", share);
+            Ok(())
+        }
+
+        /// Enables or disables stake transfers between coldkeys on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `enabled` - Whether stake on the subnet's hotkeys can be transferred between coldkeys.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(63)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_transfer_stake_enabled(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_transfer_stake_enabled(netuid, enabled);
+            log::debug!(
+                "TransferStakeEnabledSet( netuid: {:?} enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Enables or disables refunding part of the registration burn on voluntary deregistration.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `enabled` - Whether half of the registration burn is refunded on deregistration.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(64)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_recycle_registration_burn(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_recycle_registration_burn(netuid, enabled);
+            log::debug!(
+                "RecycleRegistrationBurnSet( netuid: {:?} enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Sets the number of hotkeys a coldkey can own, unless it is exempt.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `max_hotkeys` - The number of hotkeys, capped at the storage bound.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(65)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_hotkeys_per_coldkey(
+            origin: OriginFor<T>,
+            max_hotkeys: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_max_hotkeys_per_coldkey(max_hotkeys);
+            log::debug!("MaxHotkeysPerColdkeySet( max_hotkeys: {:?} )", max_hotkeys);
+            Ok(())
+        }
+
+        /// Exempts a coldkey from the number of hotkeys a coldkey can own, or lifts the exemption.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `coldkey` - The coldkey whose exemption changes.
+        /// * `exempt` - Whether the coldkey is exempt.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(66)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_hotkey_limit_exemption(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            exempt: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_hotkey_limit_exemption(&coldkey, exempt);
+            log::debug!(
+                "HotkeyLimitExemptionSet( coldkey: {:?} exempt: {:?} )",
+                coldkey,
+                exempt
+            );
+            Ok(())
+        }
+
+        /// Sets the minimum version an axon must serve with on a subnet.
+        ///
+        /// Axons already served below the new minimum are kept and reported as outdated.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `min_version` - The minimum axon version.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(67)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_axon_version(
+            origin: OriginFor<T>,
+            netuid: u16,
+            min_version: u32,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_min_axon_version(netuid, min_version);
+            log::debug!(
+                "MinAxonVersionSet( netuid: {:?} min_version: {:?} ) ",
+                netuid,
+                min_version
+            );
+            Ok(())
+        }
+
+        /// Sets the number of key swaps per adjustment interval the key swap cost is adjusted toward.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `target` - The target number of key swaps per adjustment interval.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(68)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_target_key_swaps_per_interval(
+            origin: OriginFor<T>,
+            target: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_target_key_swaps_per_interval(target);
+            log::debug!("TargetKeySwapsPerIntervalSet( target: {:?} )", target);
+            Ok(())
+        }
+
+        /// Sets the number of blocks between two key swap cost adjustments.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `interval` - The number of blocks.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(69)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_key_swap_cost_adjustment_interval(
+            origin: OriginFor<T>,
+            interval: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_key_swap_cost_adjustment_interval(interval);
+            log::debug!(
+                "KeySwapCostAdjustmentIntervalSet( interval: {:?} )",
+                interval
+            );
+            Ok(())
+        }
+
+        /// Sets the minimum the key swap cost can be adjusted to.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `min_cost` - The minimum key swap cost.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `MinKeySwapCostAboveMax` - If `min_cost` is above the maximum key swap cost.
+        #[pallet::call_index(70)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_key_swap_cost(origin: OriginFor<T>, min_cost: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                min_cost <= pallet_subtensor::Pallet::<T>::get_max_key_swap_cost(),
+                Error::<T>::MinKeySwapCostAboveMax
+            );
+            pallet_subtensor::Pallet::<T>::set_min_key_swap_cost(min_cost);
+            log::debug!("MinKeySwapCostSet( min_cost: {:?} )", min_cost);
+            Ok(())
+        }
+
+        /// Sets the maximum the key swap cost can be adjusted to.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `max_cost` - The maximum key swap cost.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `MinKeySwapCostAboveMax` - If `max_cost` is below the minimum key swap cost.
+        #[pallet::call_index(71)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_key_swap_cost(origin: OriginFor<T>, max_cost: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                max_cost >= pallet_subtensor::Pallet::<T>::get_min_key_swap_cost(),
+                Error::<T>::MinKeySwapCostAboveMax
+            );
+            pallet_subtensor::Pallet::<T>::set_max_key_swap_cost(max_cost);
+            log::debug!("MaxKeySwapCostSet( max_cost: {:?} )", max_cost);
+            Ok(())
+        }
+
+        /// Sets whether an epoch of a subnet without weights or active stake writes a uniform
+        /// incentive instead of a zero one.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `uniform` - Whether the incentive is uniform.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(72)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_empty_epoch_uniform_incentive(
+            origin: OriginFor<T>,
+            netuid: u16,
+            uniform: bool,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_empty_epoch_uniform_incentive(netuid, uniform);
+            log::debug!(
+                "EmptyEpochUniformIncentiveSet( netuid: {:?} uniform: {:?} ) ",
+                netuid,
+                uniform
+            );
+            Ok(())
+        }
+
+        /// Sets the number of blocks before requested children are applied.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `cooldown` - The number of blocks.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(73)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_pending_child_key_cooldown(
+            origin: OriginFor<T>,
+            cooldown: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_pending_child_key_cooldown(cooldown);
+            log::debug!("PendingChildKeyCooldownSet( cooldown: {:?} )", cooldown);
+            Ok(())
+        }
+
+        /// Sets the deposit withdrawn from the owner coldkey for a weights commit.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `deposit` - The deposit in RAO.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(74)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_weight_commit_deposit(
+            origin: OriginFor<T>,
+            deposit: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_weight_commit_deposit(deposit);
+            log::debug!("WeightCommitDepositSet( deposit: {:?} )", deposit);
+            Ok(())
+        }
+
+        /// Sets several hyperparameters of a subnet at once.
+        ///
+        /// Every provided field is validated before any is applied, so the subnet never sees part
+        /// of the update. A subnet owner's batch counts once against the owner hyperparameter rate
+        /// limit.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `update` - The hyperparameters to set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `OwnerHyperparamRateLimitExceeded` - If the owner updated hyperparameters too recently.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `LiquidAlphaDisabled` - If alpha values are set while liquid alpha stays disabled.
+        /// * `AlphaHighTooLow` / `AlphaLowOutOfRange` - If the alpha values are out of range.
+        /// * A subnet parameter invariant error - If the update breaks one of them, see
+        ///   `SubnetParams::violations`.
+        #[pallet::call_index(75)]
+        #[pallet::weight((
+            Weight::from_parts(44_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(4))
+                .saturating_add(T::DbWeight::get().writes(27)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_params_batch(
+            origin: OriginFor<T>,
+            netuid: u16,
+            update: SubnetParamUpdate,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            // Validate every provided field before applying any of them.
+            if let Some((alpha_low, alpha_high)) = update.alpha_values {
+                let liquid_alpha_enabled = update.liquid_alpha_enabled.unwrap_or_else(|| {
+                    pallet_subtensor::Pallet::<T>::get_liquid_alpha_enabled(netuid)
+                });
+                ensure!(
+                    liquid_alpha_enabled,
+                    pallet_subtensor::Error::<T>::LiquidAlphaDisabled
+                );
+                pallet_subtensor::Pallet::<T>::ensure_valid_alpha_values(alpha_low, alpha_high)?;
+            }
+            pallet_subtensor::Pallet::<T>::ensure_subnet_params_update(netuid, |params| {
+                params.immunity_period = update.immunity_period.unwrap_or(params.immunity_period);
+                params.min_allowed_weights = update
+                    .min_allowed_weights
+                    .unwrap_or(params.min_allowed_weights);
+                params.kappa = update.kappa.unwrap_or(params.kappa);
+                params.rho = update.rho.unwrap_or(params.rho);
+                params.activity_cutoff = update.activity_cutoff.unwrap_or(params.activity_cutoff);
+            })?;
+
+            Self::apply_subnet_param_update(netuid, &update);
+            log::debug!(
+                "SubnetParamsBatchSet( netuid: {:?} update: {:?} ) ",
+                netuid,
+                update
+            );
+            Self::deposit_event(Event::SubnetParamsBatchSet { netuid, update });
+            Ok(())
+        }
+
+        /// Sets the number of blocks a subnet owner must wait between hyperparameter updates.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `rate_limit` - The number of blocks, 0 disables the limit.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(76)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_owner_hyperparam_rate_limit(
+            origin: OriginFor<T>,
+            rate_limit: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_owner_hyperparam_rate_limit(rate_limit);
+            log::debug!(
+                "OwnerHyperparamRateLimitSet( rate_limit: {:?} )",
+                rate_limit
+            );
+            Ok(())
+        }
+
+        /// Pauses staking operations chain-wide.
+        ///
+        /// Adding, removing and transferring stake and swapping keys fail until staking is
+        /// unpaused. Weights, serving and registration are unaffected, and emission keeps accruing.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(77)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_pause_staking(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_staking_operations_paused(true);
+            log::debug!("StakingOperationsPausedSet( paused: true )");
+            Ok(())
+        }
+
+        /// Resumes staking operations paused by `sudo_pause_staking`.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(78)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_unpause_staking(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_staking_operations_paused(false);
+            log::debug!("StakingOperationsPausedSet( paused: false )");
+            Ok(())
+        }
+
+        /// Sets the source of the scores the pruning selection of a subnet ranks its neurons by.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `source` - The pruning score source.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(79)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_pruning_score_source(
+            origin: OriginFor<T>,
+            netuid: u16,
+            source: pallet_subtensor::PruningScoreSource,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_pruning_score_source(netuid, source);
+            log::debug!(
+                "PruningScoreSourceSet( netuid: {:?} source: {:?} ) ",
+                netuid,
+                source
+            );
+            Ok(())
+        }
+
+        /// Sets the pruning scores of the neurons of a subnet, used when its pruning score source
+        /// is `Custom`.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `scores` - The score of every neuron of the subnet, indexed by uid.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `InvalidPruningScoresLength` - If the scores do not cover every neuron of the subnet.
+        #[pallet::call_index(80)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_custom_pruning_scores(
+            origin: OriginFor<T>,
+            netuid: u16,
+            scores: Vec<u16>,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::do_set_custom_pruning_scores(netuid, scores)?;
+            log::debug!("CustomPruningScoresSet( netuid: {:?} ) ", netuid);
+            Ok(())
+        }
+
+        /// Sets the number of tempos a subnet can go without emission before it is flagged.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `tempos` - The number of tempos.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(81)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_warning_tempos(origin: OriginFor<T>, tempos: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_subnet_warning_tempos(tempos);
+            log::debug!("SubnetWarningTemposSet( tempos: {:?} ) ", tempos);
+            Ok(())
+        }
+
+        /// Sets whether subnets flagged for having no emission refuse new registrations until
+        /// their emission resumes.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `blocks_registration` - Whether flagged subnets refuse registrations.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(82)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_warning_blocks_registration(
+            origin: OriginFor<T>,
+            blocks_registration: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_subnet_warning_blocks_registration(
+                blocks_registration,
+            );
+            log::debug!(
+                "SubnetWarningBlocksRegistrationSet( blocks_registration: {:?} ) ",
+                blocks_registration
+            );
+            Ok(())
+        }
+
+        /// Sets the number of epochs kept in the epoch history of a subnet. Setting it clears the
+        /// history, and a depth of 0 disables it.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `depth` - The number of epochs kept, at most `MAX_EPOCH_HISTORY_DEPTH`.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `EpochHistoryDepthTooHigh` - If the depth is above `MAX_EPOCH_HISTORY_DEPTH`.
+        #[pallet::call_index(83)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_epoch_history_depth(
+            origin: OriginFor<T>,
+            netuid: u16,
+            depth: u16,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                depth <= pallet_subtensor::MAX_EPOCH_HISTORY_DEPTH,
+                Error::<T>::EpochHistoryDepthTooHigh
+            );
+            pallet_subtensor::Pallet::<T>::set_epoch_history_depth(netuid, depth);
+            log::debug!(
+                "EpochHistoryDepthSet( netuid: {:?} depth: {:?} ) ",
+                netuid,
+                depth
+            );
+            Ok(())
+        }
+
+        /// Sets whether registering a subnet at the subnet limit prunes the lowest emission
+        /// subnet out of its immunity period, or fails with `SubnetLimitReached`.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `enabled` - Whether a subnet is pruned to make room.
+        #[pallet::call_index(84)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_limit_pruning(
+            origin: OriginFor<T>,
+            enabled: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_subnet_limit_pruning(enabled);
+            log::debug!("SubnetLimitPruningSet( enabled: {:?} ) ", enabled);
+            Ok(())
+        }
+
+        /// Sets the drop in validator trust over an epoch above which a validator of a subnet is
+        /// reported with `ValidatorHealthDegraded`. A threshold of 0 disables the reports.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `threshold` - The drop in validator trust, in the units of the validator trust.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(85)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_validator_health_drop_threshold(
+            origin: OriginFor<T>,
+            netuid: u16,
+            threshold: u16,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_validator_health_drop_threshold(netuid, threshold);
+            log::debug!(
+                "ValidatorHealthDropThresholdSet( netuid: {:?} threshold: {:?} ) ",
+                netuid,
+                threshold
+            );
+            Ok(())
+        }
+
+        /// Sets the first senate proposal index tallied by the stake of its voters, and the
+        /// share of the senate stake, out of u16::MAX, that passes such a proposal.
+        ///
+        /// Proposals before `from` keep one vote per member. `None` turns stake-weighted voting
+        /// off for new proposals.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `from` - The index of the first stake-weighted proposal.
+        /// * `threshold` - The share of the senate stake that passes a proposal.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not root.
+        #[pallet::call_index(86)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_stake_weighted_voting(
+            origin: OriginFor<T>,
+            from: Option<u32>,
+            threshold: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_stake_weighted_voting(from, threshold);
+            log::debug!(
+                "StakeWeightedVotingSet( from: {:?} threshold: {:?} ) ",
+                from,
+                threshold
+            );
+            Ok(())
+        }
+
+        /// Sets the share of the registration burn paid to the subnet owner instead of being
+        /// burned.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `share` - The owner share, in basis points, at most `MaxRegistrationBurnOwnerShare`.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `RegistrationBurnOwnerShareTooHigh` - If the share is above the cap.
+        #[pallet::call_index(87)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_registration_burn_owner_share(
+            origin: OriginFor<T>,
+            netuid: u16,
+            share: u16,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                share <= pallet_subtensor::Pallet::<T>::get_max_registration_burn_owner_share(),
+                Error::<T>::RegistrationBurnOwnerShareTooHigh
+            );
+            pallet_subtensor::Pallet::<T>::set_registration_burn_owner_share(netuid, share);
+            log::debug!(
+                "RegistrationBurnOwnerShareSet( netuid: {:?} share: {:?} ) ",
+                netuid,
+                share
+            );
+            Ok(())
+        }
+
+        /// Sets the cap on the share of the registration burn subnet owners can take.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `max_share` - The cap, in basis points, at most 10_000.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not root.
+        /// * `RegistrationBurnOwnerShareTooHigh` - If the cap is above 10_000.
+        #[pallet::call_index(88)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_registration_burn_owner_share(
+            origin: OriginFor<T>,
+            max_share: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                max_share <= 10_000,
+                Error::<T>::RegistrationBurnOwnerShareTooHigh
+            );
+            pallet_subtensor::Pallet::<T>::set_max_registration_burn_owner_share(max_share);
+            log::debug!(
+                "MaxRegistrationBurnOwnerShareSet( max_share: {:?} ) ",
+                max_share
+            );
+            Ok(())
+        }
+
+        /// Sets the number of weights a validator can set on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `max_weights_limit_len` - The number of weights, at most the max allowed uids.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `MaxWeightsLimitLenTooHigh` - If the length is above the max allowed uids.
+        #[pallet::call_index(89)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_weights_limit_len(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_weights_limit_len: u16,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                max_weights_limit_len
+                    <= pallet_subtensor::Pallet::<T>::get_max_allowed_uids(netuid),
+                Error::<T>::MaxWeightsLimitLenTooHigh
+            );
+            pallet_subtensor::Pallet::<T>::ensure_subnet_params_update(netuid, |params| {
+                params.max_weights_limit_len = max_weights_limit_len
+            })?;
+            pallet_subtensor::Pallet::<T>::set_max_weights_limit_len(
+                netuid,
+                max_weights_limit_len,
+            );
+            log::debug!(
+                "MaxWeightsLimitLenSet( netuid: {:?} max_weights_limit_len: {:?} ) ",
+                netuid,
+                max_weights_limit_len
+            );
+            Ok(())
+        }
+
+        /// Sets the take of hotkeys becoming delegates through `become_delegate`, which takes no
+        /// take argument. Existing delegates keep their take.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `take` - The take, within the min and max delegate take.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not root.
+        /// * `DelegateTakeTooLow` - If the take is below the min delegate take.
+        /// * `DelegateTakeTooHigh` - If the take is above the max delegate take.
+        #[pallet::call_index(90)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_default_delegate_take(origin: OriginFor<T>, take: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                take >= pallet_subtensor::Pallet::<T>::get_min_delegate_take(),
+                pallet_subtensor::Error::<T>::DelegateTakeTooLow
+            );
+            ensure!(
+                take <= pallet_subtensor::Pallet::<T>::get_max_delegate_take(),
+                pallet_subtensor::Error::<T>::DelegateTakeTooHigh
+            );
+            pallet_subtensor::Pallet::<T>::set_default_delegate_take(take);
+            log::debug!("DefaultDelegateTakeSet( default_delegate_take: {:?} ) ", take);
+            Ok(())
+        }
+
+        /// Sets the max stake operations a coldkey can make per stake interval over all its
+        /// hotkeys, on top of the target stakes per interval on each hotkey. `u64::MAX` lifts it.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `max_stake_ops` - The max stake operations per interval per coldkey.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not root.
+        #[pallet::call_index(91)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_stake_ops_per_interval_per_coldkey(
+            origin: OriginFor<T>,
+            max_stake_ops: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_max_stake_ops_per_interval_per_coldkey(
+                max_stake_ops,
+            );
+            log::debug!(
+                "MaxStakeOpsPerIntervalPerColdkeySet( max_stake_ops: {:?} ) ",
+                max_stake_ops
+            );
+            Ok(())
+        }
+
+        /// Requires a commitment of the weighted hotkey on the subnet before validators can put
+        /// weight on its uid. Weights on uids without one are zeroed, or rejected if the subnet
+        /// rejects uncommitted weights.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `enabled` - Whether weights need a commitment of the weighted hotkey.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(92)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_require_commitment_for_weights(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_require_commitment_for_weights(netuid, enabled);
+            log::debug!(
+                "RequireCommitmentForWeightsSet( netuid: {:?} enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Rejects, rather than zeroes, weights on uids without a commitment on a subnet that
+        /// requires one.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `enabled` - Whether weights on uncommitted uids fail the whole weight set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(93)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_reject_uncommitted_weights(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_reject_uncommitted_weights(netuid, enabled);
+            log::debug!(
+                "RejectUncommittedWeightsSet( netuid: {:?} enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Sets the stake the owner of a delegate must keep on it for the delegate to be created
+        /// and to accept new nominations. Existing nominators can always unstake.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `min_stake` - The min owner stake, in rao.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not root.
+        #[pallet::call_index(94)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_owner_stake_to_delegate(
+            origin: OriginFor<T>,
+            min_stake: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_min_owner_stake_to_delegate(min_stake);
+            log::debug!(
+                "MinOwnerStakeToDelegateSet( min_owner_stake_to_delegate: {:?} ) ",
+                min_stake
+            );
+            Ok(())
+        }
+
+        /// Sets the total coldkey stake each of the three transaction fee discount tiers starts at.
+        /// Coldkeys move to their tier under the new thresholds at their next stake change.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `thresholds` - The thresholds of the tiers, in ascending order.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not root.
+        /// * `FeeDiscountThresholdsNotAscending` - If the thresholds are not in ascending order.
+        #[pallet::call_index(95)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_stake_fee_discount_thresholds(
+            origin: OriginFor<T>,
+            thresholds: [u64; 3],
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_stake_fee_discount_thresholds(thresholds)?;
+            log::debug!(
+                "StakeFeeDiscountThresholdsSet( stake_fee_discount_thresholds: {:?} ) ",
+                thresholds
+            );
+            Ok(())
+        }
+
+        /// Sets the number of tempos a staked hotkey stays off every subnet before the stake of
+        /// its nominators moves to their fallback hotkeys. Hotkeys already waiting keep their
+        /// block.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `tempos` - The number of tempos, of the subnet the hotkey left last.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not root.
+        #[pallet::call_index(96)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_auto_redelegate_after_tempos(
+            origin: OriginFor<T>,
+            tempos: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_auto_redelegate_after_tempos(tempos);
+            log::debug!(
+                "AutoRedelegateAfterTemposSet( auto_redelegate_after_tempos: {:?} ) ",
+                tempos
+            );
+            Ok(())
+        }
+
+        /// Sets the factor the share of a subnet in the block emission is scaled by, from the next
+        /// root epoch. The share a multiplier below 1.0 takes away goes to the other subnets.
+        /// It is callable by the root account or a senate supermajority.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be root or the senate.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `multiplier` - The factor in millionths, at most `MAX_EMISSION_MULTIPLIER` (2.0).
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the senate.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `EmissionMultiplierTooHigh` - If the multiplier is above `MAX_EMISSION_MULTIPLIER`.
+        #[pallet::call_index(97)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_emission_multiplier(
+            origin: OriginFor<T>,
+            netuid: u16,
+            multiplier: u32,
+        ) -> DispatchResult {
+            Self::ensure_root_or_senate(origin)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                multiplier <= pallet_subtensor::MAX_EMISSION_MULTIPLIER,
+                Error::<T>::EmissionMultiplierTooHigh
+            );
+            pallet_subtensor::Pallet::<T>::set_emission_multiplier(netuid, multiplier);
+            log::debug!(
+                "EmissionMultiplierSet( netuid: {:?} multiplier: {:?} ) ",
+                netuid,
+                multiplier
+            );
+            Ok(())
+        }
+
+        /// Disallows a validator on a subnet to weight uids whose hotkey is owned by its own
+        /// coldkey. Such weights are zeroed, or rejected if the subnet rejects them, when weights
+        /// are set or revealed.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `enabled` - Whether weights on uids of the validator's coldkey are disallowed.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(98)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_disallow_self_weight_by_coldkey(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_disallow_self_weight_by_coldkey(netuid, enabled);
+            log::debug!(
+                "DisallowSelfWeightByColdkeySet( netuid: {:?} enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Rejects, rather than zeroes, weights on uids of the validator's coldkey on a subnet
+        /// that disallows them.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `enabled` - Whether weights on uids of the validator's coldkey fail the whole weight
+        ///   set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(99)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_reject_self_weight_by_coldkey(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_reject_self_weight_by_coldkey(netuid, enabled);
+            log::debug!(
+                "RejectSelfWeightByColdkeySet( netuid: {:?} enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Pauses or resumes the block emission of a subnet pending governance review.
+        ///
+        /// While paused, the block emission of the subnet is withheld and redistributed to the
+        /// other subnets, and its epochs keep running. The withheld emission is kept in
+        /// `WithheldEmission` until it is released or burned.
+        ///
+        /// It is callable by the root account or a senate supermajority.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be root or the senate.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `paused` - Whether the block emission of the subnet is withheld.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the senate.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(100)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_pause_subnet_emission(
+            origin: OriginFor<T>,
+            netuid: u16,
+            paused: bool,
+        ) -> DispatchResult {
+            Self::ensure_root_or_senate(origin)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_subnet_emission_paused(netuid, paused);
+            log::debug!(
+                "SubnetEmissionPausedSet( netuid: {:?} paused: {:?} ) ",
+                netuid,
+                paused
+            );
+            Ok(())
+        }
+
+        /// Adds the emission withheld from a subnet to its pending emission, which its next epoch
+        /// distributes.
+        ///
+        /// It is callable by the root account or a senate supermajority.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be root or the senate.
+        /// * `netuid` - The unique identifier of the subnet.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the senate.
+        /// * `NoWithheldEmission` - If no emission is withheld from the subnet.
+        #[pallet::call_index(101)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_release_withheld_emission(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::ensure_root_or_senate(origin)?;
+            pallet_subtensor::Pallet::<T>::release_withheld_emission(netuid)?;
+            log::debug!("WithheldEmissionReleased( netuid: {:?} ) ", netuid);
+            Ok(())
+        }
+
+        /// Burns the emission withheld from a subnet.
+        ///
+        /// It is callable by the root account or a senate supermajority.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be root or the senate.
+        /// * `netuid` - The unique identifier of the subnet.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the senate.
+        /// * `NoWithheldEmission` - If no emission is withheld from the subnet.
+        #[pallet::call_index(102)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_burn_withheld_emission(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::ensure_root_or_senate(origin)?;
+            pallet_subtensor::Pallet::<T>::burn_withheld_emission(netuid)?;
+            log::debug!("WithheldEmissionBurned( netuid: {:?} ) ", netuid);
+            Ok(())
+        }
+
+        /// Sets whether registering on a subnet requires an invite signed by its owner. Such a
+        /// subnet registers hotkeys through `burned_register_with_invite` only, and not by proof
+        /// of work. Neurons already registered are kept.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `enabled` - Whether registration requires an invite.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(103)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_invite_only_registration(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root_rate_limited(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_invite_only_registration(netuid, enabled);
+            log::debug!(
+                "InviteOnlyRegistrationSet( netuid: {:?} enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Sets the share of the senate, out of `u16::MAX`, whose ayes a senate proposal needs
+        /// to dispatch the calls open to the senate. The collective still requires a simple
+        /// majority to execute a proposal, so a lower share has no effect.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `threshold` - The new approval threshold.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(104)]
+        #[pallet::weight((
+			Weight::from_parts(14_000_000, 0)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+        pub fn sudo_set_senate_approval_threshold(
+            origin: OriginFor<T>,
+            threshold: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            SenateApprovalThreshold::<T>::put(threshold);
+            Self::deposit_event(Event::SenateApprovalThresholdSet(threshold));
+            log::debug!("SenateApprovalThresholdSet( threshold: {:?} ) ", threshold);
+            Ok(())
+        }
+
+        /// Dissolves a subnet, such as one abandoned by its owner. The lock of the subnet is
+        /// returned to its owner.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or the senate.
+        /// * `netuid` - The unique identifier of the subnet.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the senate.
+        /// * `CannotDissolveRootNetwork` - If `netuid` is the root network.
+        /// * `SubNetworkDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(105)]
+        #[pallet::weight((
+			Weight::from_parts(119_000_000, 0)
+				.saturating_add(T::DbWeight::get().reads(6))
+				.saturating_add(T::DbWeight::get().writes(31)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+        pub fn sudo_dissolve_network(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::ensure_root_or_senate(origin)?;
+            ensure!(
+                netuid != pallet_subtensor::Pallet::<T>::get_root_netuid(),
+                Error::<T>::CannotDissolveRootNetwork
+            );
+            let owner = pallet_subtensor::Pallet::<T>::get_subnet_owner(netuid);
+            pallet_subtensor::Pallet::<T>::user_remove_network(owner, netuid)?;
+            log::debug!("NetworkDissolved( netuid: {:?} ) ", netuid);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Ensures the origin is root or a senate proposal passed through `SenateOrigin` with the
+        /// ayes of at least `SenateApprovalThreshold` of the senate seats.
+        ///
+        /// Only the network-wide calls that call this are open to the senate; every other root
+        /// call keeps `ensure_root`.
+        pub fn ensure_root_or_senate(origin: OriginFor<T>) -> DispatchResult {
+            if let Ok((ayes, seats)) = T::SenateOrigin::try_origin(origin.clone()) {
+                let threshold = SenateApprovalThreshold::<T>::get() as u64;
+                ensure!(
+                    (ayes as u64).saturating_mul(u16::MAX as u64)
+                        >= threshold.saturating_mul(seats as u64),
+                    Error::<T>::SenateApprovalBelowThreshold
+                );
+                return Ok(());
+            }
+            ensure_root(origin)?;
+            Ok(())
+        }
+
+        /// Returns the subnet whose validators ratified the proposal dispatched with `origin`, or
+        /// `None` if `origin` is not a ratified proposal.
+        pub fn ratified_subnet(origin: OriginFor<T>) -> Option<u16> {
+            let origin: Result<RawOrigin, <T as Config>::RuntimeOrigin> =
+                <T as Config>::RuntimeOrigin::from(origin).into();
+            match origin {
+                Ok(RawOrigin::SubnetValidators(netuid)) => Some(netuid),
+                Err(_) => None,
+            }
+        }
+
+        /// Ensures the origin is root, the owner of the subnet or a proposal ratified by its
+        /// validators, and counts an owner call against the owner hyperparameter rate limit of the
+        /// subnet.
+        pub fn ensure_subnet_owner_or_root_rate_limited(
+            origin: OriginFor<T>,
+            netuid: u16,
+        ) -> DispatchResult {
+            if Self::ratified_subnet(origin.clone()) == Some(netuid) {
+                return Ok(());
+            }
+            let maybe_owner = ensure_signed_or_root(origin.clone())?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            if let Some(owner) = maybe_owner {
+                let tx_type =
+                    pallet_subtensor::utils::rate_limiting::TransactionType::OwnerHyperparamUpdate;
+                ensure!(
+                    pallet_subtensor::Pallet::<T>::passes_rate_limit_on_subnet(
+                        &tx_type, &owner, netuid
+                    ),
+                    Error::<T>::OwnerHyperparamRateLimitExceeded
+                );
+                pallet_subtensor::Pallet::<T>::set_last_transaction_block(
+                    &owner,
+                    netuid,
+                    &tx_type,
+                    pallet_subtensor::Pallet::<T>::get_current_block_as_u64(),
+                );
+            }
+            Ok(())
+        }
+
+        /// Sets the hyperparameters provided in `update` on the subnet.
+        fn apply_subnet_param_update(netuid: u16, update: &SubnetParamUpdate) {
+            type Subtensor<T> = pallet_subtensor::Pallet<T>;
+
+            if let Some(value) = update.serving_rate_limit {
+                Subtensor::<T>::set_serving_rate_limit(netuid, value);
+            }
+            if let Some(value) = update.min_difficulty {
+                Subtensor::<T>::set_min_difficulty(netuid, value);
+            }
+            if let Some(value) = update.max_difficulty {
+                Subtensor::<T>::set_max_difficulty(netuid, value);
+            }
+            if let Some(value) = update.weights_version_key {
+                Subtensor::<T>::set_weights_version_key(netuid, value);
+            }
+            if let Some(value) = update.weights_set_rate_limit {
+                Subtensor::<T>::set_weights_set_rate_limit(netuid, value);
+            }
+            if let Some(value) = update.adjustment_alpha {
+                Subtensor::<T>::set_adjustment_alpha(netuid, value);
+            }
+            if let Some(value) = update.max_weight_limit {
+                Subtensor::<T>::set_max_weight_limit(netuid, value);
+            }
+            if let Some(value) = update.immunity_period {
+                Subtensor::<T>::set_immunity_period(netuid, value);
+            }
+            if let Some(value) = update.min_allowed_weights {
+                Subtensor::<T>::set_min_allowed_weights(netuid, value);
+            }
+            if let Some(value) = update.kappa {
+                Subtensor::<T>::set_kappa(netuid, value);
+            }
+            if let Some(value) = update.rho {
+                Subtensor::<T>::set_rho(netuid, value);
+            }
+            if let Some(value) = update.activity_cutoff {
+                Subtensor::<T>::set_activity_cutoff(netuid, value);
+            }
+            if let Some(value) = update.network_registration_allowed {
+                Subtensor::<T>::set_network_registration_allowed(netuid, value);
+            }
+            if let Some(value) = update.network_pow_registration_allowed {
+                Subtensor::<T>::set_network_pow_registration_allowed(netuid, value);
+            }
+            if let Some(value) = update.min_burn {
+                Subtensor::<T>::set_min_burn(netuid, value);
+            }
+            if let Some(value) = update.max_burn {
+                Subtensor::<T>::set_max_burn(netuid, value);
+            }
+            if let Some(value) = update.difficulty {
+                Subtensor::<T>::set_difficulty(netuid, value);
+            }
+            if let Some(value) = update.bonds_moving_average {
+                Subtensor::<T>::set_bonds_moving_average(netuid, value);
+            }
+            if let Some(value) = update.commit_reveal_weights_interval {
+                Subtensor::<T>::set_commit_reveal_weights_interval(netuid, value);
+            }
+            if let Some(value) = update.commit_reveal_weights_enabled {
+                Subtensor::<T>::set_commit_reveal_weights_enabled(netuid, value);
+            }
+            if let Some(value) = update.liquid_alpha_enabled {
+                Subtensor::<T>::set_liquid_alpha_enabled(netuid, value);
+            }
+            if let Some((alpha_low, alpha_high)) = update.alpha_values {
+                pallet_subtensor::AlphaValues::<T>::insert(netuid, (alpha_low, alpha_high));
+            }
+            if let Some(value) = update.recycle_registration_burn {
+                Subtensor::<T>::set_recycle_registration_burn(netuid, value);
+            }
+            if let Some(value) = update.min_axon_version {
+                Subtensor::<T>::set_min_axon_version(netuid, value);
+            }
+            if let Some(value) = update.empty_epoch_uniform_incentive {
+                Subtensor::<T>::set_empty_epoch_uniform_incentive(netuid, value);
+            }
+        }
+
+        /// Returns the subnet targeted by `call` if it is a hyperparameter setter that subnet
+        /// validators may ratify.
+        pub fn subnet_param_call_netuid(call: &Call<T>) -> Option<u16> {
+            match call {
+                Call::sudo_set_serving_rate_limit { netuid, .. }
+                | Call::sudo_set_min_difficulty { netuid, .. }
+                | Call::sudo_set_max_difficulty { netuid, .. }
+                | Call::sudo_set_weights_version_key { netuid, .. }
+                | Call::sudo_set_weights_set_rate_limit { netuid, .. }
+                | Call::sudo_set_adjustment_alpha { netuid, .. }
+                | Call::sudo_set_max_weight_limit { netuid, .. }
+                | Call::sudo_set_max_weights_limit_len { netuid, .. }
+                | Call::sudo_set_immunity_period { netuid, .. }
+                | Call::sudo_set_min_allowed_weights { netuid, .. }
+                | Call::sudo_set_kappa { netuid, .. }
+                | Call::sudo_set_rho { netuid, .. }
+                | Call::sudo_set_activity_cutoff { netuid, .. }
+                | Call::sudo_set_network_registration_allowed { netuid, .. }
+                | Call::sudo_set_network_pow_registration_allowed { netuid, .. }
+                | Call::sudo_set_min_burn { netuid, .. }
+                | Call::sudo_set_max_burn { netuid, .. }
+                | Call::sudo_set_difficulty { netuid, .. }
+                | Call::sudo_set_bonds_moving_average { netuid, .. }
+                | Call::sudo_set_commit_reveal_weights_interval { netuid, .. }
+                | Call::sudo_set_commit_reveal_weights_enabled { netuid, .. }
+                | Call::sudo_set_liquid_alpha_enabled { netuid, .. }
+                | Call::sudo_set_transfer_stake_enabled { netuid, .. }
+                | Call::sudo_set_recycle_registration_burn { netuid, .. }
+                | Call::sudo_set_registration_burn_owner_share { netuid, .. }
+                | Call::sudo_set_min_axon_version { netuid, .. }
+                | Call::sudo_set_empty_epoch_uniform_incentive { netuid, .. }
+                | Call::sudo_set_subnet_params_batch { netuid, .. }
+                | Call::sudo_set_pruning_score_source { netuid, .. }
+                | Call::sudo_set_custom_pruning_scores { netuid, .. }
+                | Call::sudo_set_epoch_history_depth { netuid, .. }
+                | Call::sudo_set_param_governance_enabled { netuid, .. }
+                | Call::sudo_set_alpha_values { netuid, .. }
+                | Call::sudo_set_validator_health_drop_threshold { netuid, .. }
+                | Call::sudo_set_require_commitment_for_weights { netuid, .. }
+                | Call::sudo_set_reject_uncommitted_weights { netuid, .. }
+                | Call::sudo_set_disallow_self_weight_by_coldkey { netuid, .. }
+                | Call::sudo_set_reject_self_weight_by_coldkey { netuid, .. }
+                | Call::sudo_set_invite_only_registration { netuid, .. } => Some(*netuid),
+                _ => None,
+            }
+        }
+
+        /// Returns the total stake of the validator permit holders of a subnet.
+        pub fn subnet_param_voting_power(netuid: u16) -> u64 {
+            let permits = pallet_subtensor::Pallet::<T>::get_validator_permit(netuid);
+            pallet_subtensor::Keys::<T>::iter_prefix(netuid)
+                .filter(|(uid, _)| permits.get(*uid as usize).copied().unwrap_or(false))
+                .fold(0_u64, |total, (_, hotkey)| {
+                    total.saturating_add(pallet_subtensor::Pallet::<T>::get_total_stake_for_hotkey(
+                        &hotkey,
+                    ))
+                })
+        }
+
+        /// Removes a hyperparameter proposal and its votes.
+        fn close_subnet_param_proposal(netuid: u16, proposal_index: u32) -> u64 {
+            SubnetParamProposals::<T>::remove(netuid, proposal_index);
+            let removed =
+                SubnetParamVotes::<T>::clear_prefix((netuid, proposal_index), u32::MAX, None);
+            u64::from(removed.unique).saturating_add(1)
+        }
+
+        /// Removes the proposals whose voting period is over, as long as the remaining weight allows it.
+        pub fn remove_expired_subnet_param_proposals(
+            now: BlockNumberFor<T>,
+            remaining_weight: Weight,
+        ) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut weight = Weight::zero();
+            let mut expired: Vec<(u16, u32)> = Vec::new();
+            for (netuid, proposal_index, proposal) in SubnetParamProposals::<T>::iter() {
+                // Leave room to read the next proposal and remove the expired ones found so far.
+                let next = weight.saturating_add(
+                    db_weight.reads_writes(1, (expired.len() as u64).saturating_add(1)),
+                );
+                if next.any_gt(remaining_weight) {
+                    break;
+                }
+                weight = weight.saturating_add(db_weight.reads(1));
+                if proposal.end < now {
+                    expired.push((netuid, proposal_index));
+                }
+            }
+
+            for (netuid, proposal_index) in expired {
+                let writes = Self::close_subnet_param_proposal(netuid, proposal_index);
+                weight = weight.saturating_add(db_weight.writes(writes));
+                Self::deposit_event(Event::SubnetParamProposalExpired {
+                    netuid,
+                    proposal_index,
+                });
+            }
+            weight
+        }
     }
 }
 
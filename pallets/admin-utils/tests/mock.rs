@@ -1,17 +1,17 @@
 #![allow(clippy::arithmetic_side_effects, clippy::unwrap_used)]
 
 use frame_support::{
-    assert_ok, derive_impl, parameter_types,
-    traits::{Everything, Hooks, PrivilegeCmp},
+    assert_ok, derive_impl, ord_parameter_types, parameter_types,
+    traits::{Everything, Hooks, MapSuccess, PrivilegeCmp},
     weights,
 };
 use frame_system as system;
-use frame_system::{limits, EnsureNever, EnsureRoot};
+use frame_system::{limits, EnsureNever, EnsureRoot, EnsureSignedBy};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::U256;
 use sp_core::{ConstU64, H256};
 use sp_runtime::{
-    traits::{BlakeTwo256, ConstU32, IdentityLookup},
+    traits::{BlakeTwo256, ConstU32, IdentifyAccount, IdentityLookup, Lazy, Replace, Verify},
     BuildStorage, Perbill,
 };
 use sp_std::cmp::Ordering;
@@ -124,18 +124,50 @@ parameter_types! {
     pub const InitialHotkeyEmissionTempo: u64 = 1;
     pub const InitialNetworkMaxStake: u64 = u64::MAX; // Maximum possible value for u64, this make the make stake infinity
     pub const InitialColdkeySwapScheduleDuration: u64 = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const InitialColdkeySwapRedirectDuration: u64 = 180 * 24 * 60 * 60 / 12; // 6 months
     pub const InitialDissolveNetworkScheduleDuration: u64 = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const InitialPendingChildKeyCooldown: u64 = 0; // Children apply immediately
+    pub const InitialWeightCommitDeposit: u64 = 0; // Commits hold no deposit
+}
+
+// Signature of a test account over the message it carries.
+#[derive(codec::Encode, codec::Decode, Clone, PartialEq, Eq, Debug, scale_info::TypeInfo)]
+pub struct TestSignature(pub U256, pub Vec<u8>);
+
+impl Verify for TestSignature {
+    type Signer = TestSigner;
+
+    fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &U256) -> bool {
+        self.0 == *signer && self.1 == msg.get()
+    }
+}
+
+// Public key of a test account.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TestSigner(pub U256);
+
+impl IdentifyAccount for TestSigner {
+    type AccountId = U256;
+
+    fn into_account(self) -> U256 {
+        self.0
+    }
 }
 
 impl pallet_subtensor::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
+    type CallSignature = TestSignature;
+    type CallSigner = TestSigner;
     type Currency = Balances;
+    type VestingCurrency = Balances;
     type InitialIssuance = InitialIssuance;
     type SudoRuntimeCall = TestRuntimeCall;
     type CouncilOrigin = EnsureNever<AccountId>;
     type SenateMembers = ();
     type TriumvirateInterface = ();
+    type OnNeuronRemoved = ();
+    type Commitments = ();
     type Scheduler = Scheduler;
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -189,7 +221,10 @@ impl pallet_subtensor::Config for Test {
     type InitialNetworkMaxStake = InitialNetworkMaxStake;
     type Preimages = ();
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
+    type InitialColdkeySwapRedirectDuration = InitialColdkeySwapRedirectDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type InitialPendingChildKeyCooldown = InitialPendingChildKeyCooldown;
+    type InitialWeightCommitDeposit = InitialWeightCommitDeposit;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -243,13 +278,24 @@ impl PrivilegeCmp<OriginCaller> for OriginPrivilegeCmp {
     }
 }
 
+ord_parameter_types! {
+    pub const SenateAccount: U256 = U256([777, 0, 0, 0]);
+}
+
+parameter_types! {
+    // Senate proposals in tests pass with two ayes out of three seats.
+    pub const SenateVotes: (u32, u32) = (2, 3);
+}
+
 impl pallet_admin_utils::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
     type AuthorityId = AuraId;
     type MaxAuthorities = ConstU32<32>;
     type Aura = ();
     type Balance = Balance;
     type WeightInfo = ();
+    type SenateOrigin = MapSuccess<EnsureSignedBy<SenateAccount, AccountId>, Replace<SenateVotes>>;
 }
 
 parameter_types! {
@@ -291,6 +337,7 @@ pub(crate) fn run_to_block(n: u64) {
         System::set_block_number(System::block_number() + 1);
         System::on_initialize(System::block_number());
         SubtensorModule::on_initialize(System::block_number());
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
     }
 }
 
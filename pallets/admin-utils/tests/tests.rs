@@ -1,10 +1,14 @@
+use codec::Encode;
 use frame_support::sp_runtime::DispatchError;
 use frame_support::{
     assert_err, assert_noop, assert_ok,
     dispatch::{DispatchClass, GetDispatchInfo, Pays},
+    traits::Hooks,
+    weights::Weight,
 };
 use frame_system::Config;
 use pallet_admin_utils::Error;
+use pallet_subtensor::subnets::param_invariants::SubnetParamViolation;
 use pallet_subtensor::Error as SubtensorError;
 use pallet_subtensor::{migrations, Event};
 use sp_core::U256;
@@ -373,6 +377,7 @@ fn test_sudo_set_min_allowed_weights() {
         let netuid: u16 = 1;
         let to_be_set: u16 = 10;
         add_network(netuid, 10);
+        SubtensorModule::set_max_allowed_uids(netuid, 16);
         let init_value: u16 = SubtensorModule::get_min_allowed_weights(netuid);
         assert_eq!(
             AdminUtils::sudo_set_min_allowed_weights(
@@ -701,6 +706,48 @@ fn test_sudo_set_weights_min_stake() {
     });
 }
 
+#[test]
+fn test_sudo_set_subnet_stake_threshold() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u64 = 10;
+        add_network(netuid, 10);
+        SubtensorModule::set_weights_min_stake(5);
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_stake_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                Some(to_be_set)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_stake_threshold(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                Some(to_be_set)
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_stake_threshold_for_subnet(netuid), 5);
+        assert_ok!(AdminUtils::sudo_set_subnet_stake_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            Some(to_be_set)
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_threshold_for_subnet(netuid),
+            to_be_set
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_stake_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            None
+        ));
+        assert_eq!(SubtensorModule::get_stake_threshold_for_subnet(netuid), 5);
+    });
+}
+
 #[test]
 fn test_sudo_set_bonds_moving_average() {
     new_test_ext().execute_with(|| {
@@ -1435,3 +1482,1992 @@ fn test_sudo_set_dissolve_network_schedule_duration() {
         System::assert_last_event(Event::DissolveNetworkScheduleDurationSet(new_duration).into());
     });
 }
+
+fn setup_subnet_param_governance(netuid: u16, owner: U256) {
+    add_network(netuid, 10);
+    pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+    let validators = [
+        (10_u64, 20_u64, 100_u64, 39420842_u64),
+        (11, 21, 300, 45729838),
+    ];
+    for (uid, (hotkey, coldkey, stake, nonce)) in validators.iter().enumerate() {
+        let hotkey = U256::from(*hotkey);
+        let coldkey = U256::from(*coldkey);
+        register_ok_neuron(netuid, hotkey, coldkey, *nonce);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, *stake);
+        SubtensorModule::set_validator_permit_for_uid(netuid, uid as u16, true);
+    }
+    assert_ok!(AdminUtils::sudo_set_param_governance_enabled(
+        <<Test as Config>::RuntimeOrigin>::signed(owner),
+        netuid,
+        true
+    ));
+}
+
+#[test]
+fn test_subnet_param_proposal_passes() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        setup_subnet_param_governance(netuid, owner);
+        let init_value: u16 = SubtensorModule::get_kappa(netuid);
+
+        // The owner can no longer set the parameter directly.
+        assert_eq!(
+            AdminUtils::sudo_set_kappa(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                123
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        let call = pallet_admin_utils::Call::<Test>::sudo_set_kappa { netuid, kappa: 123 };
+        assert_ok!(AdminUtils::propose_subnet_param(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            call.encode()
+        ));
+
+        // 100 of 400 stake is not enough.
+        assert_ok!(AdminUtils::vote_subnet_param(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(20)),
+            U256::from(10),
+            netuid,
+            0,
+            true
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_value);
+
+        // 400 of 400 stake passes and dispatches the call.
+        assert_ok!(AdminUtils::vote_subnet_param(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(21)),
+            U256::from(11),
+            netuid,
+            0,
+            true
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), 123);
+        assert!(pallet_admin_utils::SubnetParamProposals::<Test>::get(netuid, 0).is_none());
+        System::assert_last_event(
+            pallet_admin_utils::Event::SubnetParamExecuted {
+                netuid,
+                proposal_index: 0,
+                result: Ok(()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_subnet_param_proposal_fails() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        setup_subnet_param_governance(netuid, owner);
+        let init_value: u16 = SubtensorModule::get_kappa(netuid);
+
+        let call = pallet_admin_utils::Call::<Test>::sudo_set_kappa { netuid, kappa: 123 };
+        assert_ok!(AdminUtils::propose_subnet_param(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            call.encode()
+        ));
+
+        // Only the owning coldkey of a permitted validator can vote, once.
+        assert_err!(
+            AdminUtils::vote_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(21)),
+                U256::from(10),
+                netuid,
+                0,
+                false
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, false);
+        assert_err!(
+            AdminUtils::vote_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(21)),
+                U256::from(11),
+                netuid,
+                0,
+                false
+            ),
+            Error::<Test>::NoValidatorPermit
+        );
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        assert_ok!(AdminUtils::vote_subnet_param(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(21)),
+            U256::from(11),
+            netuid,
+            0,
+            false
+        ));
+        assert_err!(
+            AdminUtils::vote_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(21)),
+                U256::from(11),
+                netuid,
+                0,
+                true
+            ),
+            Error::<Test>::DuplicateSubnetParamVote
+        );
+        assert_ok!(AdminUtils::vote_subnet_param(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(20)),
+            U256::from(10),
+            netuid,
+            0,
+            true
+        ));
+
+        // 100 in favour, 300 against: the call is never dispatched.
+        let proposal = pallet_admin_utils::SubnetParamProposals::<Test>::get(netuid, 0)
+            .expect("Proposal is still open");
+        assert_eq!((proposal.ayes, proposal.nays), (100, 300));
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_value);
+    });
+}
+
+#[test]
+fn test_subnet_param_proposal_expires() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        setup_subnet_param_governance(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_subnet_param_voting_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            5
+        ));
+
+        let call = pallet_admin_utils::Call::<Test>::sudo_set_kappa { netuid, kappa: 123 };
+        assert_ok!(AdminUtils::propose_subnet_param(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            call.encode()
+        ));
+
+        // Still open at the last block of the voting period.
+        System::set_block_number(System::block_number() + 5);
+        AdminUtils::on_idle(System::block_number(), Weight::MAX);
+        assert!(pallet_admin_utils::SubnetParamProposals::<Test>::get(netuid, 0).is_some());
+
+        System::set_block_number(System::block_number() + 1);
+        assert_err!(
+            AdminUtils::vote_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(21)),
+                U256::from(11),
+                netuid,
+                0,
+                true
+            ),
+            Error::<Test>::SubnetParamProposalExpired
+        );
+
+        AdminUtils::on_idle(System::block_number(), Weight::MAX);
+        assert!(pallet_admin_utils::SubnetParamProposals::<Test>::get(netuid, 0).is_none());
+        System::assert_last_event(
+            pallet_admin_utils::Event::SubnetParamProposalExpired {
+                netuid,
+                proposal_index: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_subnet_param_proposal_rejects_non_whitelisted_call() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(2, 10);
+        setup_subnet_param_governance(netuid, owner);
+
+        // Root only setter.
+        let call = pallet_admin_utils::Call::<Test>::sudo_set_tempo { netuid, tempo: 1 };
+        assert_err!(
+            AdminUtils::propose_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                call.encode()
+            ),
+            Error::<Test>::SubnetParamCallNotAllowed
+        );
+        // Setter for another subnet.
+        let call = pallet_admin_utils::Call::<Test>::sudo_set_kappa {
+            netuid: 2,
+            kappa: 123,
+        };
+        assert_err!(
+            AdminUtils::propose_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                call.encode()
+            ),
+            Error::<Test>::SubnetParamCallNotAllowed
+        );
+        // Not a call at all.
+        assert_err!(
+            AdminUtils::propose_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                vec![255, 255, 255]
+            ),
+            Error::<Test>::SubnetParamCallNotAllowed
+        );
+
+        let call = pallet_admin_utils::Call::<Test>::sudo_set_kappa { netuid, kappa: 123 };
+        assert_err!(
+            AdminUtils::propose_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                call.encode()
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert_err!(
+            AdminUtils::propose_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                2,
+                pallet_admin_utils::Call::<Test>::sudo_set_kappa {
+                    netuid: 2,
+                    kappa: 123
+                }
+                .encode()
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        pallet_subtensor::SubnetOwner::<Test>::insert(2, owner);
+        assert_err!(
+            AdminUtils::propose_subnet_param(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                2,
+                pallet_admin_utils::Call::<Test>::sudo_set_kappa {
+                    netuid: 2,
+                    kappa: 123
+                }
+                .encode()
+            ),
+            Error::<Test>::ParamGovernanceDisabled
+        );
+        assert_eq!(
+            pallet_admin_utils::SubnetParamProposalCount::<Test>::get(netuid),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_subnet_param_whitelist_covers_owner_setters() {
+    // The owner of a subnet under validator governance can only change a parameter through a
+    // proposal, so every setter open to the owner must be one a proposal can dispatch.
+    let source = include_str!("../src/lib.rs");
+    let whitelist = source
+        .split("pub fn subnet_param_call_netuid")
+        .nth(1)
+        .and_then(|rest| rest.split("_ => None").next())
+        .expect("The whitelist is defined");
+    let mut owner_setters = 0;
+    for item in source.split("        pub fn ").skip(1) {
+        let name = item.split('(').next().unwrap_or_default();
+        if !name.starts_with("sudo_") || !item.contains("ensure_subnet_owner_or_root") {
+            continue;
+        }
+        owner_setters += 1;
+        assert!(
+            whitelist.contains(&format!("Call::{} {{", name)),
+            "{} is open to the subnet owner but can not be proposed",
+            name
+        );
+    }
+    assert_eq!(whitelist.matches("Call::").count(), owner_setters);
+}
+
+#[test]
+fn test_subnet_param_proposal_dispatches_as_subnet_validators() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(2, 10);
+        setup_subnet_param_governance(netuid, owner);
+
+        // A setter added after the baseline whitelist passes through a proposal.
+        let call = pallet_admin_utils::Call::<Test>::sudo_set_invite_only_registration {
+            netuid,
+            enabled: true,
+        };
+        assert_ok!(AdminUtils::propose_subnet_param(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            call.encode()
+        ));
+        assert_ok!(AdminUtils::vote_subnet_param(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(21)),
+            U256::from(11),
+            netuid,
+            0,
+            true
+        ));
+        assert!(pallet_subtensor::InviteOnlyRegistration::<Test>::get(
+            netuid
+        ));
+        System::assert_last_event(
+            pallet_admin_utils::Event::SubnetParamExecuted {
+                netuid,
+                proposal_index: 0,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        // The origin of a ratified proposal stands in for the owner of its subnet only.
+        let ratified: RuntimeOrigin =
+            pallet_admin_utils::RawOrigin::SubnetValidators(netuid).into();
+        assert_ok!(AdminUtils::sudo_set_kappa(ratified.clone(), netuid, 123));
+        assert_eq!(SubtensorModule::get_kappa(netuid), 123);
+        assert_eq!(
+            AdminUtils::sudo_set_kappa(ratified.clone(), 2, 123),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(ratified.clone(), netuid, 1),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_default_take(ratified, 100),
+            Err(DispatchError::BadOrigin)
+        );
+    });
+}
+
+#[test]
+fn test_senate_can_set_whitelisted_params() {
+    new_test_ext().execute_with(|| {
+        let senate = <<Test as Config>::RuntimeOrigin>::signed(U256([777, 0, 0, 0]));
+
+        assert_ok!(AdminUtils::sudo_set_default_take(senate.clone(), 1_000));
+        assert_eq!(SubtensorModule::get_default_delegate_take(), 1_000);
+
+        assert_ok!(AdminUtils::sudo_set_network_rate_limit(senate.clone(), 42));
+        assert_eq!(pallet_subtensor::NetworkRateLimit::<Test>::get(), 42);
+
+        assert_ok!(AdminUtils::sudo_set_tx_rate_limit(senate.clone(), 7));
+        assert_eq!(SubtensorModule::get_tx_rate_limit(), 7);
+
+        assert_ok!(AdminUtils::sudo_set_network_min_lock_cost(
+            senate.clone(),
+            5
+        ));
+        assert_eq!(SubtensorModule::get_network_min_lock(), 5);
+
+        assert_ok!(AdminUtils::sudo_set_lock_reduction_interval(senate, 9));
+        assert_eq!(SubtensorModule::get_lock_reduction_interval(), 9);
+    });
+}
+
+#[test]
+fn test_senate_cannot_set_non_whitelisted_params() {
+    new_test_ext().execute_with(|| {
+        let senate = <<Test as Config>::RuntimeOrigin>::signed(U256([777, 0, 0, 0]));
+        let init_value: u16 = SubtensorModule::get_max_subnets();
+
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_limit(senate, init_value.saturating_add(1)),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_max_subnets(), init_value);
+    });
+}
+
+#[test]
+fn test_sudo_set_senate_approval_threshold() {
+    new_test_ext().execute_with(|| {
+        let senate = <<Test as Config>::RuntimeOrigin>::signed(U256([777, 0, 0, 0]));
+        let init_value: u16 = pallet_admin_utils::SenateApprovalThreshold::<Test>::get();
+
+        assert_eq!(
+            AdminUtils::sudo_set_senate_approval_threshold(senate.clone(), u16::MAX),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            pallet_admin_utils::SenateApprovalThreshold::<Test>::get(),
+            init_value
+        );
+
+        // The mock senate approves with two ayes out of three seats, which clears the default
+        // two thirds but not a unanimous threshold.
+        assert_ok!(AdminUtils::sudo_set_default_take(senate.clone(), 1_000));
+
+        assert_ok!(AdminUtils::sudo_set_senate_approval_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            u16::MAX
+        ));
+        assert_eq!(
+            pallet_admin_utils::SenateApprovalThreshold::<Test>::get(),
+            u16::MAX
+        );
+        System::assert_last_event(
+            pallet_admin_utils::Event::SenateApprovalThresholdSet(u16::MAX).into(),
+        );
+
+        assert_noop!(
+            AdminUtils::sudo_set_default_take(senate, 2_000),
+            Error::<Test>::SenateApprovalBelowThreshold
+        );
+        assert_ok!(AdminUtils::sudo_set_default_take(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            2_000
+        ));
+        assert_eq!(SubtensorModule::get_default_delegate_take(), 2_000);
+    });
+}
+
+#[test]
+fn test_sudo_dissolve_network() {
+    new_test_ext().execute_with(|| {
+        let senate = <<Test as Config>::RuntimeOrigin>::signed(U256([777, 0, 0, 0]));
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        assert_eq!(
+            AdminUtils::sudo_dissolve_network(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_dissolve_network(senate.clone(), 0),
+            Error::<Test>::CannotDissolveRootNetwork
+        );
+
+        assert_ok!(AdminUtils::sudo_dissolve_network(senate.clone(), netuid));
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+        System::assert_last_event(Event::NetworkRemoved(netuid).into());
+
+        assert_noop!(
+            AdminUtils::sudo_dissolve_network(senate, netuid),
+            SubtensorError::<Test>::SubNetworkDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_coldkey_swap_max_weight_share() {
+    new_test_ext().execute_with(|| {
+        let share: u16 = u16::MAX / 4;
+
+        assert_noop!(
+            AdminUtils::sudo_set_coldkey_swap_max_weight_share(
+                RuntimeOrigin::signed(U256::from(1)),
+                share
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_coldkey_swap_max_weight_share(
+            RuntimeOrigin::root(),
+            share
+        ));
+        assert_eq!(
+            pallet_subtensor::ColdkeySwapMaxWeightShare::<Test>::get(),
+            share
+        );
+        System::assert_last_event(Event::ColdkeySwapMaxWeightShareSet(share).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_max_hotkeys_per_coldkey() {
+    new_test_ext().execute_with(|| {
+        let max_hotkeys: u32 = 64;
+
+        assert_noop!(
+            AdminUtils::sudo_set_max_hotkeys_per_coldkey(
+                RuntimeOrigin::signed(U256::from(1)),
+                max_hotkeys
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_max_hotkeys_per_coldkey(
+            RuntimeOrigin::root(),
+            max_hotkeys
+        ));
+        assert_eq!(
+            pallet_subtensor::MaxHotkeysPerColdkey::<Test>::get(),
+            max_hotkeys
+        );
+        System::assert_last_event(Event::MaxHotkeysPerColdkeySet(max_hotkeys).into());
+
+        // Capped at the storage bound.
+        assert_ok!(AdminUtils::sudo_set_max_hotkeys_per_coldkey(
+            RuntimeOrigin::root(),
+            u32::MAX
+        ));
+        assert_eq!(
+            pallet_subtensor::MaxHotkeysPerColdkey::<Test>::get(),
+            pallet_subtensor::MAX_OWNED_HOTKEYS
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_hotkey_limit_exemption() {
+    new_test_ext().execute_with(|| {
+        let coldkey = U256::from(2);
+
+        assert_noop!(
+            AdminUtils::sudo_set_hotkey_limit_exemption(
+                RuntimeOrigin::signed(U256::from(1)),
+                coldkey,
+                true
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_hotkey_limit_exemption(
+            RuntimeOrigin::root(),
+            coldkey,
+            true
+        ));
+        assert!(pallet_subtensor::HotkeyLimitExempt::<Test>::contains_key(
+            coldkey
+        ));
+        System::assert_last_event(
+            Event::HotkeyLimitExemptionSet {
+                coldkey,
+                exempt: true,
+            }
+            .into(),
+        );
+
+        assert_ok!(AdminUtils::sudo_set_hotkey_limit_exemption(
+            RuntimeOrigin::root(),
+            coldkey,
+            false
+        ));
+        assert!(!pallet_subtensor::HotkeyLimitExempt::<Test>::contains_key(
+            coldkey
+        ));
+    });
+}
+
+#[test]
+fn test_sudo_set_min_axon_version() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        let min_version: u32 = 5;
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_min_axon_version(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                min_version
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_min_axon_version(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                min_version
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_min_axon_version(netuid), 0);
+
+        assert_ok!(AdminUtils::sudo_set_min_axon_version(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            min_version
+        ));
+        assert_eq!(SubtensorModule::get_min_axon_version(netuid), min_version);
+        System::assert_last_event(Event::MinAxonVersionSet(netuid, min_version).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_target_key_swaps_per_interval() {
+    new_test_ext().execute_with(|| {
+        let target: u64 = 12_345;
+
+        assert_noop!(
+            AdminUtils::sudo_set_target_key_swaps_per_interval(
+                RuntimeOrigin::signed(U256::from(1)),
+                target
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_target_key_swaps_per_interval(
+            RuntimeOrigin::root(),
+            target
+        ));
+        assert_eq!(
+            pallet_subtensor::TargetKeySwapsPerInterval::<Test>::get(),
+            target
+        );
+        System::assert_last_event(Event::TargetKeySwapsPerIntervalSet(target).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_key_swap_cost_adjustment_interval() {
+    new_test_ext().execute_with(|| {
+        let interval: u64 = 12_345;
+
+        assert_noop!(
+            AdminUtils::sudo_set_key_swap_cost_adjustment_interval(
+                RuntimeOrigin::signed(U256::from(1)),
+                interval
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_key_swap_cost_adjustment_interval(
+            RuntimeOrigin::root(),
+            interval
+        ));
+        assert_eq!(
+            pallet_subtensor::KeySwapCostAdjustmentInterval::<Test>::get(),
+            interval
+        );
+        System::assert_last_event(Event::KeySwapCostAdjustmentIntervalSet(interval).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_min_key_swap_cost() {
+    new_test_ext().execute_with(|| {
+        let min_cost: u64 = 12_345;
+
+        assert_noop!(
+            AdminUtils::sudo_set_min_key_swap_cost(RuntimeOrigin::signed(U256::from(1)), min_cost),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_min_key_swap_cost(
+                RuntimeOrigin::root(),
+                SubtensorModule::get_max_key_swap_cost().saturating_add(1)
+            ),
+            Error::<Test>::MinKeySwapCostAboveMax
+        );
+
+        assert_ok!(AdminUtils::sudo_set_min_key_swap_cost(
+            RuntimeOrigin::root(),
+            min_cost
+        ));
+        assert_eq!(pallet_subtensor::MinKeySwapCost::<Test>::get(), min_cost);
+        System::assert_last_event(Event::MinKeySwapCostSet(min_cost).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_max_key_swap_cost() {
+    new_test_ext().execute_with(|| {
+        let max_cost: u64 = 12_345;
+
+        assert_noop!(
+            AdminUtils::sudo_set_max_key_swap_cost(RuntimeOrigin::signed(U256::from(1)), max_cost),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_max_key_swap_cost(RuntimeOrigin::root(), max_cost),
+            Error::<Test>::MinKeySwapCostAboveMax
+        );
+
+        assert_ok!(AdminUtils::sudo_set_min_key_swap_cost(
+            RuntimeOrigin::root(),
+            max_cost
+        ));
+
+        assert_ok!(AdminUtils::sudo_set_max_key_swap_cost(
+            RuntimeOrigin::root(),
+            max_cost
+        ));
+        assert_eq!(pallet_subtensor::MaxKeySwapCost::<Test>::get(), max_cost);
+        System::assert_last_event(Event::MaxKeySwapCostSet(max_cost).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_empty_epoch_uniform_incentive() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_empty_epoch_uniform_incentive(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_empty_epoch_uniform_incentive(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert!(!SubtensorModule::get_empty_epoch_uniform_incentive(netuid));
+
+        assert_ok!(AdminUtils::sudo_set_empty_epoch_uniform_incentive(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_empty_epoch_uniform_incentive(netuid));
+        System::assert_last_event(Event::EmptyEpochUniformIncentiveSet(netuid, true).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_pending_child_key_cooldown() {
+    new_test_ext().execute_with(|| {
+        let cooldown: u64 = 7_200;
+
+        assert_noop!(
+            AdminUtils::sudo_set_pending_child_key_cooldown(
+                RuntimeOrigin::signed(U256::from(1)),
+                cooldown
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_pending_child_key_cooldown(
+            RuntimeOrigin::root(),
+            cooldown
+        ));
+        assert_eq!(
+            pallet_subtensor::PendingChildKeyCooldown::<Test>::get(),
+            cooldown
+        );
+        System::assert_last_event(Event::PendingChildKeyCooldownSet(cooldown).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_weight_commit_deposit() {
+    new_test_ext().execute_with(|| {
+        let deposit: u64 = 10_000_000;
+
+        assert_noop!(
+            AdminUtils::sudo_set_weight_commit_deposit(
+                RuntimeOrigin::signed(U256::from(1)),
+                deposit
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_weight_commit_deposit(
+            RuntimeOrigin::root(),
+            deposit
+        ));
+        assert_eq!(
+            pallet_subtensor::WeightCommitDeposit::<Test>::get(),
+            deposit
+        );
+        System::assert_last_event(Event::WeightCommitDepositSet(deposit).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_params_batch_invalid_field_changes_nothing() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        let init_kappa = SubtensorModule::get_kappa(netuid);
+        let init_rho = SubtensorModule::get_rho(netuid);
+        let update = pallet_admin_utils::SubnetParamUpdate {
+            kappa: Some(init_kappa + 1),
+            rho: Some(init_rho + 1),
+            liquid_alpha_enabled: Some(true),
+            alpha_values: Some((1_000, (u16::MAX as u32 * 4 / 5) as u16 - 1)),
+            ..Default::default()
+        };
+
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_params_batch(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                update
+            ),
+            SubtensorError::<Test>::AlphaHighTooLow
+        );
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_kappa);
+        assert_eq!(SubtensorModule::get_rho(netuid), init_rho);
+        assert!(!SubtensorModule::get_liquid_alpha_enabled(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_params_batch() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        let update = pallet_admin_utils::SubnetParamUpdate {
+            kappa: Some(12),
+            rho: Some(34),
+            max_weight_limit: Some(56),
+            liquid_alpha_enabled: Some(true),
+            alpha_values: Some((1_000, u16::MAX - 1)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_params_batch(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                update.clone()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_params_batch(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                update.clone()
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+
+        assert_ok!(AdminUtils::sudo_set_subnet_params_batch(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            update.clone()
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), 12);
+        assert_eq!(SubtensorModule::get_rho(netuid), 34);
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), 56);
+        assert!(SubtensorModule::get_liquid_alpha_enabled(netuid));
+        assert_eq!(
+            pallet_subtensor::AlphaValues::<Test>::get(netuid),
+            (1_000, u16::MAX - 1)
+        );
+        System::assert_last_event(
+            pallet_admin_utils::Event::SubnetParamsBatchSet { netuid, update }.into(),
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_params_batch_consumes_rate_limit_once() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_owner_hyperparam_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            10
+        ));
+        let update = pallet_admin_utils::SubnetParamUpdate {
+            kappa: Some(12),
+            rho: Some(34),
+            max_weight_limit: Some(56),
+            ..Default::default()
+        };
+
+        // All fields of the batch are applied under a single rate limited update.
+        assert_ok!(AdminUtils::sudo_set_subnet_params_batch(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            update.clone()
+        ));
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), 56);
+
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_params_batch(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                update.clone()
+            ),
+            Error::<Test>::OwnerHyperparamRateLimitExceeded
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_kappa(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, 1),
+            Error::<Test>::OwnerHyperparamRateLimitExceeded
+        );
+        // Root is not rate limited.
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            1
+        ));
+
+        run_to_block(11);
+        assert_ok!(AdminUtils::sudo_set_subnet_params_batch(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            update
+        ));
+    });
+}
+
+#[test]
+fn test_sudo_set_owner_hyperparam_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let rate_limit: u64 = 100;
+
+        assert_noop!(
+            AdminUtils::sudo_set_owner_hyperparam_rate_limit(
+                RuntimeOrigin::signed(U256::from(1)),
+                rate_limit
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_owner_hyperparam_rate_limit(
+            RuntimeOrigin::root(),
+            rate_limit
+        ));
+        assert_eq!(
+            SubtensorModule::get_owner_hyperparam_rate_limit(),
+            rate_limit
+        );
+        System::assert_last_event(Event::OwnerHyperparamRateLimitSet(rate_limit).into());
+    });
+}
+
+#[test]
+fn test_sudo_pause_and_unpause_staking() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AdminUtils::sudo_pause_staking(RuntimeOrigin::signed(U256::from(1))),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AdminUtils::sudo_unpause_staking(RuntimeOrigin::signed(U256::from(1))),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_pause_staking(RuntimeOrigin::root()));
+        assert!(SubtensorModule::get_staking_operations_paused());
+        System::assert_last_event(Event::StakingOperationsPausedSet(true).into());
+
+        assert_ok!(AdminUtils::sudo_unpause_staking(RuntimeOrigin::root()));
+        assert!(!SubtensorModule::get_staking_operations_paused());
+        System::assert_last_event(Event::StakingOperationsPausedSet(false).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_pruning_score_source() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert_eq!(
+            SubtensorModule::get_pruning_score_source(netuid),
+            pallet_subtensor::PruningScoreSource::EmissionBased
+        );
+
+        assert_eq!(
+            AdminUtils::sudo_set_pruning_score_source(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                pallet_subtensor::PruningScoreSource::Custom
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_pruning_score_source(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                pallet_subtensor::PruningScoreSource::Custom
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+
+        assert_ok!(AdminUtils::sudo_set_pruning_score_source(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            pallet_subtensor::PruningScoreSource::Custom
+        ));
+        assert_eq!(
+            SubtensorModule::get_pruning_score_source(netuid),
+            pallet_subtensor::PruningScoreSource::Custom
+        );
+        System::assert_last_event(
+            Event::PruningScoreSourceSet(netuid, pallet_subtensor::PruningScoreSource::Custom)
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_custom_pruning_scores_checks_length() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 100_000);
+
+        assert_noop!(
+            AdminUtils::sudo_set_custom_pruning_scores(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                vec![1]
+            ),
+            SubtensorError::<Test>::InvalidPruningScoresLength
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_custom_pruning_scores(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                vec![1, 2, 3]
+            ),
+            SubtensorError::<Test>::InvalidPruningScoresLength
+        );
+
+        assert_ok!(AdminUtils::sudo_set_custom_pruning_scores(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            vec![1, 2]
+        ));
+        assert_eq!(
+            pallet_subtensor::CustomPruningScores::<Test>::get(netuid),
+            vec![1, 2]
+        );
+        System::assert_last_event(Event::CustomPruningScoresSet(netuid).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_warning_policy() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_warning_tempos(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                10
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_warning_blocks_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                true
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_subnet_warning_tempos(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            10
+        ));
+        assert_eq!(SubtensorModule::get_subnet_warning_tempos(), 10);
+        System::assert_last_event(Event::SubnetWarningTemposSet(10).into());
+
+        assert_ok!(AdminUtils::sudo_set_subnet_warning_blocks_registration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            true
+        ));
+        assert!(SubtensorModule::get_subnet_warning_blocks_registration());
+        System::assert_last_event(Event::SubnetWarningBlocksRegistrationSet(true).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_epoch_history_depth() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        assert_noop!(
+            AdminUtils::sudo_set_epoch_history_depth(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                4
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_epoch_history_depth(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                pallet_subtensor::MAX_EPOCH_HISTORY_DEPTH + 1
+            ),
+            Error::<Test>::EpochHistoryDepthTooHigh
+        );
+
+        assert_ok!(AdminUtils::sudo_set_epoch_history_depth(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            4
+        ));
+        assert_eq!(SubtensorModule::get_epoch_history_depth(netuid), 4);
+        System::assert_last_event(Event::EpochHistoryDepthSet(netuid, 4).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_limit_pruning() {
+    new_test_ext().execute_with(|| {
+        assert!(SubtensorModule::get_subnet_limit_pruning());
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_limit_pruning(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                false
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert!(SubtensorModule::get_subnet_limit_pruning());
+
+        assert_ok!(AdminUtils::sudo_set_subnet_limit_pruning(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            false
+        ));
+        assert!(!SubtensorModule::get_subnet_limit_pruning());
+        System::assert_last_event(Event::SubnetLimitPruningSet(false).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_transfer_stake_enabled() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        add_network(other_netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert!(!SubtensorModule::get_transfer_stake_enabled(netuid));
+
+        assert_eq!(
+            AdminUtils::sudo_set_transfer_stake_enabled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_transfer_stake_enabled(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                3,
+                true
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+        assert!(!SubtensorModule::get_transfer_stake_enabled(netuid));
+
+        // The subnet owner enables transfers on its own subnet only.
+        assert_ok!(AdminUtils::sudo_set_transfer_stake_enabled(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_transfer_stake_enabled(netuid));
+        assert!(!SubtensorModule::get_transfer_stake_enabled(other_netuid));
+        System::assert_last_event(Event::TransferStakeEnabledSet(netuid, true).into());
+
+        assert_ok!(AdminUtils::sudo_set_transfer_stake_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            false
+        ));
+        assert!(!SubtensorModule::get_transfer_stake_enabled(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_validator_health_drop_threshold() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert_eq!(
+            SubtensorModule::get_validator_health_drop_threshold(netuid),
+            0
+        );
+
+        assert_eq!(
+            AdminUtils::sudo_set_validator_health_drop_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                1000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_validator_health_drop_threshold(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                1000
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+
+        assert_ok!(AdminUtils::sudo_set_validator_health_drop_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            1000
+        ));
+        assert_eq!(
+            SubtensorModule::get_validator_health_drop_threshold(netuid),
+            1000
+        );
+        System::assert_last_event(Event::ValidatorHealthDropThresholdSet(netuid, 1000).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_stake_weighted_voting() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(SubtensorModule::get_stake_weighted_voting_from(), None);
+
+        assert_eq!(
+            AdminUtils::sudo_set_stake_weighted_voting(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                Some(3),
+                40_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_ok!(AdminUtils::sudo_set_stake_weighted_voting(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Some(3),
+            40_000
+        ));
+        assert_eq!(SubtensorModule::get_stake_weighted_voting_from(), Some(3));
+        assert_eq!(SubtensorModule::get_stake_weighted_vote_threshold(), 40_000);
+        System::assert_last_event(Event::StakeWeightedVotingSet(Some(3), 40_000).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_registration_burn_owner_share() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_eq!(
+            SubtensorModule::get_registration_burn_owner_share(netuid),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_max_registration_burn_owner_share(),
+            5_000
+        );
+
+        assert_eq!(
+            AdminUtils::sudo_set_registration_burn_owner_share(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                1_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_registration_burn_owner_share(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                5_001
+            ),
+            Error::<Test>::RegistrationBurnOwnerShareTooHigh
+        );
+        assert_ok!(AdminUtils::sudo_set_registration_burn_owner_share(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            5_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_registration_burn_owner_share(netuid),
+            5_000
+        );
+
+        // Only root moves the cap, and never above the whole burn.
+        assert_eq!(
+            AdminUtils::sudo_set_max_registration_burn_owner_share(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                8_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_max_registration_burn_owner_share(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                10_001
+            ),
+            Error::<Test>::RegistrationBurnOwnerShareTooHigh
+        );
+        assert_ok!(AdminUtils::sudo_set_max_registration_burn_owner_share(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            8_000
+        ));
+        System::assert_last_event(Event::MaxRegistrationBurnOwnerShareSet(8_000).into());
+        assert_ok!(AdminUtils::sudo_set_registration_burn_owner_share(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            8_000
+        ));
+        System::assert_last_event(Event::RegistrationBurnOwnerShareSet(netuid, 8_000).into());
+    });
+}
+
+#[test]
+fn test_chain_constants_follow_admin_updates() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AdminUtils::sudo_set_tx_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            77
+        ));
+        assert_ok!(AdminUtils::sudo_set_network_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            88
+        ));
+
+        let constants = SubtensorModule::get_chain_constants();
+        assert_eq!(constants.tx_rate_limit.0, 77);
+        assert_eq!(constants.network_rate_limit.0, 88);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_weights_limit_len() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_max_allowed_uids(netuid, 64);
+        assert_eq!(SubtensorModule::get_max_weights_limit_len(netuid), 64);
+
+        assert_eq!(
+            AdminUtils::sudo_set_max_weights_limit_len(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                16
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_max_weights_limit_len(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                16
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_max_weights_limit_len(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                65
+            ),
+            Error::<Test>::MaxWeightsLimitLenTooHigh
+        );
+        assert_ok!(AdminUtils::sudo_set_max_weights_limit_len(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            16
+        ));
+        assert_eq!(SubtensorModule::get_max_weights_limit_len(netuid), 16);
+        System::assert_last_event(Event::MaxWeightsLimitLenSet(netuid, 16).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_min_allowed_weights_above_max_weights_len() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        SubtensorModule::set_max_allowed_uids(netuid, 16);
+
+        assert_noop!(
+            AdminUtils::sudo_set_min_allowed_weights(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                17
+            ),
+            SubtensorError::<Test>::MinAllowedWeightsAboveMaxWeightsLen
+        );
+        assert_ok!(AdminUtils::sudo_set_min_allowed_weights(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            8
+        ));
+
+        // Lowering either bound of the weights below the minimum is rejected as well.
+        assert_noop!(
+            AdminUtils::sudo_set_max_allowed_uids(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                7
+            ),
+            SubtensorError::<Test>::MinAllowedWeightsAboveMaxWeightsLen
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_max_weights_limit_len(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                7
+            ),
+            SubtensorError::<Test>::MinAllowedWeightsAboveMaxWeightsLen
+        );
+        assert_ok!(AdminUtils::sudo_set_max_weights_limit_len(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            8
+        ));
+    });
+}
+
+#[test]
+fn test_sudo_set_kappa_and_rho_out_of_range() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        for kappa in [0, u16::MAX] {
+            assert_noop!(
+                AdminUtils::sudo_set_kappa(
+                    <<Test as Config>::RuntimeOrigin>::root(),
+                    netuid,
+                    kappa
+                ),
+                SubtensorError::<Test>::KappaOutOfRange
+            );
+        }
+        assert_noop!(
+            AdminUtils::sudo_set_rho(<<Test as Config>::RuntimeOrigin>::root(), netuid, 0),
+            SubtensorError::<Test>::RhoIsZero
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_activity_cutoff_below_tempo() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        assert_noop!(
+            AdminUtils::sudo_set_activity_cutoff(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                9
+            ),
+            SubtensorError::<Test>::ActivityCutoffBelowTempo
+        );
+        assert_ok!(AdminUtils::sudo_set_activity_cutoff(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            10
+        ));
+        assert_noop!(
+            AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::root(), netuid, 11),
+            SubtensorError::<Test>::ActivityCutoffBelowTempo
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_immunity_period_not_below_activity_cutoff() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1);
+        let activity_cutoff: u16 = SubtensorModule::get_activity_cutoff(netuid);
+
+        assert_noop!(
+            AdminUtils::sudo_set_immunity_period(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                activity_cutoff
+            ),
+            SubtensorError::<Test>::ImmunityPeriodNotBelowActivityCutoff
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_activity_cutoff(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                SubtensorModule::get_immunity_period(netuid)
+            ),
+            SubtensorError::<Test>::ImmunityPeriodNotBelowActivityCutoff
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_params_batch_rejects_broken_params() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        let init_kappa = SubtensorModule::get_kappa(netuid);
+
+        // Each field is valid on its own against the current parameters, but not together.
+        let update = pallet_admin_utils::SubnetParamUpdate {
+            kappa: Some(init_kappa + 1),
+            immunity_period: Some(100),
+            activity_cutoff: Some(100),
+            ..Default::default()
+        };
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_params_batch(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                update
+            ),
+            SubtensorError::<Test>::ImmunityPeriodNotBelowActivityCutoff
+        );
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_kappa);
+
+        let update = pallet_admin_utils::SubnetParamUpdate {
+            immunity_period: Some(99),
+            activity_cutoff: Some(100),
+            ..Default::default()
+        };
+        assert_ok!(AdminUtils::sudo_set_subnet_params_batch(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            update
+        ));
+        assert_eq!(
+            SubtensorModule::validate_subnet_params(netuid),
+            Some(vec![])
+        );
+    });
+}
+
+#[test]
+fn test_broken_subnet_params_can_be_repaired() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        SubtensorModule::set_kappa(netuid, 0);
+        SubtensorModule::set_rho(netuid, 0);
+
+        // Invariants the subnet already violates do not block other updates.
+        assert_ok!(AdminUtils::sudo_set_rho(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            10
+        ));
+        assert_eq!(
+            SubtensorModule::validate_subnet_params(netuid),
+            Some(vec![SubnetParamViolation::KappaOutOfRange])
+        );
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            10
+        ));
+        assert_eq!(
+            SubtensorModule::validate_subnet_params(netuid),
+            Some(vec![])
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_default_delegate_take() {
+    new_test_ext().execute_with(|| {
+        let min_take = SubtensorModule::get_min_delegate_take();
+        let max_take = SubtensorModule::get_max_delegate_take();
+        let init_value = SubtensorModule::get_default_delegate_take();
+        assert_eq!(
+            AdminUtils::sudo_set_default_delegate_take(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                min_take
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_default_delegate_take(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                min_take - 1
+            ),
+            SubtensorError::<Test>::DelegateTakeTooLow
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_default_delegate_take(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                max_take + 1
+            ),
+            SubtensorError::<Test>::DelegateTakeTooHigh
+        );
+        assert_eq!(SubtensorModule::get_default_delegate_take(), init_value);
+
+        assert_ok!(AdminUtils::sudo_set_default_delegate_take(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            min_take
+        ));
+        assert_eq!(SubtensorModule::get_default_delegate_take(), min_take);
+        assert_eq!(
+            SubtensorModule::get_chain_constants()
+                .default_delegate_take
+                .0,
+            min_take
+        );
+        System::assert_last_event(Event::DefaultDelegateTakeSet(min_take).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_max_stake_ops_per_interval_per_coldkey() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 10;
+        let init_value = SubtensorModule::get_max_stake_ops_per_interval_per_coldkey();
+        assert_eq!(
+            AdminUtils::sudo_set_max_stake_ops_per_interval_per_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_max_stake_ops_per_interval_per_coldkey(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_max_stake_ops_per_interval_per_coldkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_stake_ops_per_interval_per_coldkey(),
+            to_be_set
+        );
+        System::assert_last_event(Event::MaxStakeOpsPerIntervalPerColdkeySet(to_be_set).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_commitment_for_weights_flags() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert!(!SubtensorModule::get_require_commitment_for_weights(netuid));
+        assert!(!SubtensorModule::get_reject_uncommitted_weights(netuid));
+
+        assert_eq!(
+            AdminUtils::sudo_set_require_commitment_for_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_reject_uncommitted_weights(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+
+        assert_ok!(AdminUtils::sudo_set_require_commitment_for_weights(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        System::assert_last_event(Event::RequireCommitmentForWeightsSet(netuid, true).into());
+        assert_ok!(AdminUtils::sudo_set_reject_uncommitted_weights(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        System::assert_last_event(Event::RejectUncommittedWeightsSet(netuid, true).into());
+        assert!(SubtensorModule::get_require_commitment_for_weights(netuid));
+        assert!(SubtensorModule::get_reject_uncommitted_weights(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_min_owner_stake_to_delegate() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 1_000_000_000;
+        let init_value = SubtensorModule::get_min_owner_stake_to_delegate();
+        assert_eq!(
+            AdminUtils::sudo_set_min_owner_stake_to_delegate(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_min_owner_stake_to_delegate(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_min_owner_stake_to_delegate(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_min_owner_stake_to_delegate(),
+            to_be_set
+        );
+        System::assert_last_event(Event::MinOwnerStakeToDelegateSet(to_be_set).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_stake_fee_discount_thresholds() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: [u64; 3] = [1_000, 10_000, 100_000];
+        let init_value = SubtensorModule::get_stake_fee_discount_thresholds();
+        assert_eq!(
+            AdminUtils::sudo_set_stake_fee_discount_thresholds(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_stake_fee_discount_thresholds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                [1_000, 100_000, 10_000]
+            ),
+            SubtensorError::<Test>::FeeDiscountThresholdsNotAscending
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_fee_discount_thresholds(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_stake_fee_discount_thresholds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_fee_discount_thresholds(),
+            to_be_set
+        );
+        System::assert_last_event(Event::StakeFeeDiscountThresholdsSet(to_be_set).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_auto_redelegate_after_tempos() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 10;
+        let init_value = SubtensorModule::get_auto_redelegate_after_tempos();
+        assert_eq!(
+            AdminUtils::sudo_set_auto_redelegate_after_tempos(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_auto_redelegate_after_tempos(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_auto_redelegate_after_tempos(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_auto_redelegate_after_tempos(),
+            to_be_set
+        );
+        System::assert_last_event(Event::AutoRedelegateAfterTemposSet(to_be_set).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_emission_multiplier() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u32 = 500_000;
+        add_network(netuid, 10);
+        let init_value = SubtensorModule::get_emission_multiplier(netuid);
+        assert_eq!(init_value, pallet_subtensor::EMISSION_MULTIPLIER_ONE);
+        assert_eq!(
+            AdminUtils::sudo_set_emission_multiplier(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_emission_multiplier(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_emission_multiplier(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                pallet_subtensor::MAX_EMISSION_MULTIPLIER + 1
+            ),
+            Error::<Test>::EmissionMultiplierTooHigh
+        );
+        assert_eq!(SubtensorModule::get_emission_multiplier(netuid), init_value);
+
+        assert_ok!(AdminUtils::sudo_set_emission_multiplier(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_emission_multiplier(netuid), to_be_set);
+        System::assert_last_event(Event::EmissionMultiplierSet(netuid, to_be_set).into());
+
+        // The senate can set the multiplier too, up to the bound.
+        let senate = <<Test as Config>::RuntimeOrigin>::signed(U256([777, 0, 0, 0]));
+        assert_ok!(AdminUtils::sudo_set_emission_multiplier(
+            senate,
+            netuid,
+            pallet_subtensor::MAX_EMISSION_MULTIPLIER
+        ));
+        assert_eq!(
+            SubtensorModule::get_emission_multiplier(netuid),
+            pallet_subtensor::MAX_EMISSION_MULTIPLIER
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_self_weight_by_coldkey_flags() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert!(!SubtensorModule::get_disallow_self_weight_by_coldkey(
+            netuid
+        ));
+        assert!(!SubtensorModule::get_reject_self_weight_by_coldkey(netuid));
+
+        assert_eq!(
+            AdminUtils::sudo_set_disallow_self_weight_by_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_reject_self_weight_by_coldkey(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+
+        assert_ok!(AdminUtils::sudo_set_disallow_self_weight_by_coldkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        System::assert_last_event(Event::DisallowSelfWeightByColdkeySet(netuid, true).into());
+        assert_ok!(AdminUtils::sudo_set_reject_self_weight_by_coldkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        System::assert_last_event(Event::RejectSelfWeightByColdkeySet(netuid, true).into());
+        assert!(SubtensorModule::get_disallow_self_weight_by_coldkey(netuid));
+        assert!(SubtensorModule::get_reject_self_weight_by_coldkey(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_pause_subnet_emission() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert!(!SubtensorModule::is_subnet_emission_paused(netuid));
+
+        assert_eq!(
+            AdminUtils::sudo_pause_subnet_emission(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_pause_subnet_emission(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+
+        // The senate can pause the emission too.
+        let senate = <<Test as Config>::RuntimeOrigin>::signed(U256([777, 0, 0, 0]));
+        assert_ok!(AdminUtils::sudo_pause_subnet_emission(
+            senate.clone(),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::is_subnet_emission_paused(netuid));
+        System::assert_last_event(Event::SubnetEmissionPausedSet(netuid, true).into());
+
+        assert_noop!(
+            AdminUtils::sudo_release_withheld_emission(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid
+            ),
+            SubtensorError::<Test>::NoWithheldEmission
+        );
+        pallet_subtensor::WithheldEmission::<Test>::insert(netuid, 500);
+        assert_ok!(AdminUtils::sudo_release_withheld_emission(senate, netuid));
+        System::assert_last_event(
+            Event::WithheldEmissionReleased {
+                netuid,
+                amount: 500,
+            }
+            .into(),
+        );
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 500);
+
+        pallet_subtensor::WithheldEmission::<Test>::insert(netuid, 200);
+        assert_ok!(AdminUtils::sudo_burn_withheld_emission(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid
+        ));
+        assert_eq!(SubtensorModule::get_withheld_emission(netuid), 0);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 500);
+    });
+}
+
+#[test]
+fn test_sudo_set_invite_only_registration() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert!(!SubtensorModule::is_invite_only_registration(netuid));
+
+        assert_eq!(
+            AdminUtils::sudo_set_invite_only_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_invite_only_registration(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+
+        assert_ok!(AdminUtils::sudo_set_invite_only_registration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        System::assert_last_event(Event::InviteOnlyRegistrationSet(netuid, true).into());
+        assert!(SubtensorModule::is_invite_only_registration(netuid));
+    });
+}
@@ -134,6 +134,9 @@ pub enum RawOrigin<AccountId, I> {
     Members(MemberCount, MemberCount),
     /// It has been condoned by a single member of the collective.
     Member(AccountId),
+    /// It has been approved by the share of the stake of the members the collective requires,
+    /// tallied outside of the collective.
+    StakeApproved,
     /// Dummy to manage the fact we have instancing.
     _Phantom(PhantomData<I>),
 }
@@ -960,12 +963,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         yes_votes: MemberCount,
         proposal_hash: T::Hash,
         proposal: <T as Config<I>>::Proposal,
+    ) -> (Weight, u32) {
+        Self::do_approve_proposal_with_origin(
+            RawOrigin::Members(yes_votes, seats),
+            proposal_hash,
+            proposal,
+        )
+    }
+
+    /// Dispatches an approved proposal with `origin`, see `do_approve_proposal`.
+    fn do_approve_proposal_with_origin(
+        origin: RawOrigin<T::AccountId, I>,
+        proposal_hash: T::Hash,
+        proposal: <T as Config<I>>::Proposal,
     ) -> (Weight, u32) {
         Self::deposit_event(Event::Approved { proposal_hash });
 
         let dispatch_weight = proposal.get_dispatch_info().weight;
-        let origin = RawOrigin::Members(yes_votes, seats).into();
-        let result = proposal.dispatch(origin);
+        let result = proposal.dispatch(origin.into());
         Self::deposit_event(Event::Executed {
             proposal_hash,
             result: result.map(|_| ()).map_err(|e| e.error),
@@ -1026,6 +1041,72 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
         Ok(position_yes.is_some() || position_no.is_some())
     }
+
+    /// Returns the index of an open proposal.
+    pub fn proposal_index(proposal: T::Hash) -> Option<ProposalIndex> {
+        Self::voting(proposal).map(|voting| voting.index)
+    }
+
+    /// Close a vote whose outcome was tallied by stake outside of the collective.
+    ///
+    /// An approved proposal is dispatched with the `StakeApproved` origin, which only origins
+    /// built on `EnsureStakeApproved` accept. A rejected proposal can only be closed once its
+    /// voting period has ended. `yes_votes` and `no_votes` count the members that voted, and are
+    /// only reported in the `Closed` event.
+    pub fn do_close_with_outcome(
+        proposal_hash: T::Hash,
+        index: ProposalIndex,
+        approved: bool,
+        yes_votes: MemberCount,
+        no_votes: MemberCount,
+    ) -> DispatchResultWithPostInfo {
+        let voting = Self::voting(proposal_hash).ok_or(Error::<T, I>::ProposalNotExists)?;
+        ensure!(
+            voting.index == index,
+            Error::<T, I>::IndexMismatchProposalHash
+        );
+        let members = T::GetVotingMembers::get_count() as MemberCount;
+
+        if approved {
+            let proposal =
+                ProposalOf::<T, I>::get(proposal_hash).ok_or(Error::<T, I>::ProposalNotExists)?;
+            let len = proposal.encoded_size();
+            Self::deposit_event(Event::Closed {
+                proposal_hash,
+                yes: yes_votes,
+                no: no_votes,
+            });
+            let (proposal_weight, proposal_count) = Self::do_approve_proposal_with_origin(
+                RawOrigin::StakeApproved,
+                proposal_hash,
+                proposal,
+            );
+            return Ok((
+                Some(
+                    T::WeightInfo::close_approved(len as u32, members, proposal_count)
+                        .saturating_add(proposal_weight),
+                ),
+                Pays::Yes,
+            )
+                .into());
+        }
+
+        ensure!(
+            frame_system::Pallet::<T>::block_number() >= voting.end,
+            Error::<T, I>::TooEarlyToCloseProposal
+        );
+        Self::deposit_event(Event::Closed {
+            proposal_hash,
+            yes: yes_votes,
+            no: no_votes,
+        });
+        let proposal_count = Self::do_disapprove_proposal(proposal_hash);
+        Ok((
+            Some(T::WeightInfo::close_disapproved(members, proposal_count)),
+            Pays::No,
+        )
+            .into())
+    }
 }
 
 impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
@@ -1200,6 +1281,24 @@ impl<
     }
 }
 
+pub struct EnsureStakeApproved<AccountId, I: 'static>(PhantomData<(AccountId, I)>);
+impl<O: Into<Result<RawOrigin<AccountId, I>, O>> + From<RawOrigin<AccountId, I>>, AccountId, I>
+    EnsureOrigin<O> for EnsureStakeApproved<AccountId, I>
+{
+    type Success = ();
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().and_then(|o| match o {
+            RawOrigin::StakeApproved => Ok(()),
+            r => Err(O::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(RawOrigin::StakeApproved))
+    }
+}
+
 /// CanPropose
 pub trait CanPropose<AccountId> {
     /// Check whether or not the passed AccountId can propose a new motion
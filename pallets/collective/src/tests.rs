@@ -346,6 +346,62 @@ fn close_works() {
     });
 }
 
+#[test]
+fn close_with_outcome_dispatches_with_stake_approved_origin() {
+    new_test_ext().execute_with(|| {
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let hash = BlakeTwo256::hash_of(&proposal);
+
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        assert_ok!(Collective::do_close_with_outcome(hash, 0, true, 1, 0));
+
+        assert_eq!(
+            System::events(),
+            vec![
+                record(RuntimeEvent::Collective(CollectiveEvent::Proposed {
+                    account: 1,
+                    proposal_index: 0,
+                    proposal_hash: hash,
+                    threshold: 2
+                })),
+                record(RuntimeEvent::Collective(CollectiveEvent::Closed {
+                    proposal_hash: hash,
+                    yes: 1,
+                    no: 0
+                })),
+                record(RuntimeEvent::Collective(CollectiveEvent::Approved {
+                    proposal_hash: hash
+                })),
+                record(RuntimeEvent::Collective(CollectiveEvent::Executed {
+                    proposal_hash: hash,
+                    result: Err(DispatchError::BadOrigin)
+                }))
+            ]
+        );
+
+        // The stake-approved origin is not mistaken for a share of the members.
+        let origin = || RuntimeOrigin::from(RawOrigin::<u64, Instance1>::StakeApproved);
+        assert_ok!(EnsureStakeApproved::<u64, Instance1>::try_origin(origin()));
+        assert!(EnsureProportionAtLeast::<u64, Instance1, 0, 1>::try_origin(origin()).is_err());
+        assert!(EnsureMembers::<u64, Instance1, 0>::try_origin(origin()).is_err());
+        assert!(
+            EnsureStakeApproved::<u64, Instance1>::try_origin(RuntimeOrigin::from(RawOrigin::<
+                u64,
+                Instance1,
+            >::Members(
+                3, 3
+            )))
+            .is_err()
+        );
+    });
+}
+
 #[test]
 fn proposal_weight_limit_works_on_approve() {
     new_test_ext().execute_with(|| {
@@ -13,7 +13,7 @@ pub use types::*;
 pub use weights::WeightInfo;
 
 use frame_support::traits::Currency;
-use sp_runtime::{traits::Zero, Saturating};
+use sp_runtime::{traits::Zero, SaturatedConversion, Saturating};
 use sp_std::boxed::Box;
 
 type BalanceOf<T> =
@@ -22,7 +22,10 @@ type BalanceOf<T> =
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{BalanceStatus, ReservableCurrency},
+    };
     use frame_system::pallet_prelude::{BlockNumberFor, *};
 
     #[pallet::pallet]
@@ -44,21 +47,24 @@ pub mod pallet {
         /// Interface to access-limit metadata commitments
         type CanCommit: CanCommit<Self::AccountId>;
 
+        /// Interface to read the tempo of a subnet, which the rate limit is counted in
+        type TempoInterface: GetTempo;
+
         /// The maximum number of additional fields that can be added to a commitment
         #[pallet::constant]
         type MaxFields: Get<u32>;
 
-        /// The amount held on deposit for a registered identity
+        /// The maximum encoded size of a commitment in bytes
         #[pallet::constant]
-        type InitialDeposit: Get<BalanceOf<Self>>;
+        type MaxCommitmentSize: Get<u32>;
 
-        /// The amount held on deposit per additional field for a registered identity.
+        /// The amount held on deposit for a registered identity
         #[pallet::constant]
-        type FieldDeposit: Get<BalanceOf<Self>>;
+        type InitialDeposit: Get<BalanceOf<Self>>;
 
-        /// The rate limit for commitments
+        /// The amount held on deposit per byte of a commitment.
         #[pallet::constant]
-        type RateLimit: Get<BlockNumberFor<Self>>;
+        type ByteDeposit: Get<BalanceOf<Self>>;
     }
 
     #[pallet::event]
@@ -71,6 +77,29 @@ pub mod pallet {
             /// The account
             who: T::AccountId,
         },
+        /// A commitment was removed along with the neuron that made it
+        CommitmentRemoved {
+            /// The netuid of the commitment
+            netuid: u16,
+            /// The account
+            who: T::AccountId,
+        },
+        /// A commitment moved to the new hotkey of the neuron that made it
+        CommitmentSwapped {
+            /// The netuid of the commitment
+            netuid: u16,
+            /// The account the commitment moved from
+            from: T::AccountId,
+            /// The account the commitment moved to
+            to: T::AccountId,
+        },
+        /// The rate limit of commitments on a subnet was set
+        RateLimitSet {
+            /// The netuid
+            netuid: u16,
+            /// The number of tempos between two commitments of an account
+            rate_limit: u64,
+        },
     }
 
     #[pallet::error]
@@ -81,6 +110,14 @@ pub mod pallet {
         AccountNotAllowedCommit,
         /// Account is trying to commit data too fast, rate limit exceeded
         CommitmentSetRateLimitExceeded,
+        /// The commitment is larger than the maximum commitment size
+        CommitmentTooLarge,
+    }
+
+    /// Default number of tempos between two commitments of an account
+    #[pallet::type_value]
+    pub fn DefaultRateLimit<T: Config>() -> u64 {
+        1
     }
 
     /// Identity data by account
@@ -108,6 +145,12 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Number of tempos between two commitments of an account, by netuid
+    #[pallet::storage]
+    #[pallet::getter(fn rate_limit)]
+    pub(super) type RateLimit<T: Config> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultRateLimit<T>>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Set the commitment for a given netuid
@@ -128,36 +171,93 @@ pub mod pallet {
                 Error::<T>::AccountNotAllowedCommit
             );
 
+            Self::do_set_commitment(netuid, who, *info)
+        }
+
+        /// Set the commitment of a hotkey for a given netuid, signed by the hotkey or the coldkey
+        /// owning it. The deposit is held on the hotkey either way.
+        #[pallet::call_index(1)]
+        #[pallet::weight((
+			T::WeightInfo::set_commitment().saturating_add(T::DbWeight::get().reads(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+        pub fn set_hotkey_commitment(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            info: Box<CommitmentInfo<T::MaxFields>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                T::CanCommit::can_commit(netuid, &hotkey)
+                    && (who == hotkey || T::CanCommit::is_owner(&hotkey, &who)),
+                Error::<T>::AccountNotAllowedCommit
+            );
+
+            Self::do_set_commitment(netuid, hotkey, *info)
+        }
+
+        /// Set the number of tempos between two commitments of an account on a subnet
+        #[pallet::call_index(2)]
+        #[pallet::weight((
+			T::DbWeight::get().writes(1),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+        pub fn set_rate_limit(
+            origin: OriginFor<T>,
+            netuid: u16,
+            rate_limit: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            <RateLimit<T>>::insert(netuid, rate_limit);
+            Self::deposit_event(Event::RateLimitSet { netuid, rate_limit });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn do_set_commitment(
+            netuid: u16,
+            who: T::AccountId,
+            info: CommitmentInfo<T::MaxFields>,
+        ) -> DispatchResult {
             let extra_fields = info.fields.len() as u32;
             ensure!(
                 extra_fields <= T::MaxFields::get(),
                 Error::<T>::TooManyFieldsInCommitmentInfo
             );
+            let size = info.encoded_size() as u32;
+            ensure!(
+                size <= T::MaxCommitmentSize::get(),
+                Error::<T>::CommitmentTooLarge
+            );
 
             let cur_block = <frame_system::Pallet<T>>::block_number();
             if let Some(last_commit) = <LastCommitment<T>>::get(netuid, &who) {
                 ensure!(
-                    cur_block >= last_commit.saturating_add(T::RateLimit::get()),
+                    cur_block >= last_commit.saturating_add(Self::rate_limit_blocks(netuid)),
                     Error::<T>::CommitmentSetRateLimitExceeded
                 );
             }
 
-            let fd = <BalanceOf<T>>::from(extra_fields).saturating_mul(T::FieldDeposit::get());
+            let bd = <BalanceOf<T>>::from(size).saturating_mul(T::ByteDeposit::get());
             let mut id = match <CommitmentOf<T>>::get(netuid, &who) {
                 Some(mut id) => {
-                    id.info = *info;
+                    id.info = info;
                     id.block = cur_block;
                     id
                 }
                 None => Registration {
-                    info: *info,
+                    info,
                     block: cur_block,
                     deposit: Zero::zero(),
                 },
             };
 
             let old_deposit = id.deposit;
-            id.deposit = T::InitialDeposit::get().saturating_add(fd);
+            id.deposit = T::InitialDeposit::get().saturating_add(bd);
             if id.deposit > old_deposit {
                 T::Currency::reserve(&who, id.deposit.saturating_sub(old_deposit))?;
             }
@@ -173,12 +273,83 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Returns the number of blocks between two commitments of an account on a subnet.
+        pub fn rate_limit_blocks(netuid: u16) -> BlockNumberFor<T> {
+            let tempo_blocks = u64::from(T::TempoInterface::get_tempo(netuid)).saturating_add(1);
+            <RateLimit<T>>::get(netuid)
+                .saturating_mul(tempo_blocks)
+                .saturated_into()
+        }
+
+        /// Returns the commitment of an account on a subnet, if any.
+        pub fn get_commitment(
+            netuid: u16,
+            who: &T::AccountId,
+        ) -> Option<Registration<BalanceOf<T>, T::MaxFields, BlockNumberFor<T>>> {
+            <CommitmentOf<T>>::get(netuid, who)
+        }
+
+        /// Removes the commitment of an account on a subnet and returns its deposit.
+        pub fn purge_commitment(netuid: u16, who: &T::AccountId) {
+            <LastCommitment<T>>::remove(netuid, who);
+            if let Some(id) = <CommitmentOf<T>>::take(netuid, who) {
+                let err_amount = T::Currency::unreserve(who, id.deposit);
+                debug_assert!(err_amount.is_zero());
+                Self::deposit_event(Event::CommitmentRemoved {
+                    netuid,
+                    who: who.clone(),
+                });
+            }
+        }
+
+        /// Moves the commitment of an account on a subnet to another account, along with its
+        /// deposit and its last commitment block. A commitment `to` already had is removed first.
+        /// If the deposit cannot be moved to `to`, the commitment is removed instead.
+        pub fn swap_commitment(netuid: u16, from: &T::AccountId, to: &T::AccountId) {
+            let Some(id) = <CommitmentOf<T>>::get(netuid, from) else {
+                return;
+            };
+            Self::purge_commitment(netuid, to);
+            if T::Currency::repatriate_reserved(from, to, id.deposit, BalanceStatus::Reserved)
+                .is_err()
+            {
+                Self::purge_commitment(netuid, from);
+                return;
+            }
+
+            <CommitmentOf<T>>::remove(netuid, from);
+            <CommitmentOf<T>>::insert(netuid, to, id);
+            if let Some(last_commit) = <LastCommitment<T>>::take(netuid, from) {
+                <LastCommitment<T>>::insert(netuid, to, last_commit);
+            }
+            Self::deposit_event(Event::CommitmentSwapped {
+                netuid,
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+
+        /// Removes every commitment on a subnet and returns their deposits.
+        pub fn purge_subnet(netuid: u16) {
+            for (who, id) in <CommitmentOf<T>>::drain_prefix(netuid) {
+                let err_amount = T::Currency::unreserve(&who, id.deposit);
+                debug_assert!(err_amount.is_zero());
+            }
+            let _ = <LastCommitment<T>>::clear_prefix(netuid, u32::MAX, None);
+            <RateLimit<T>>::remove(netuid);
+        }
     }
 }
 
 // Interfaces to interact with other pallets
 pub trait CanCommit<AccountId> {
     fn can_commit(netuid: u16, who: &AccountId) -> bool;
+
+    /// Returns true if `who` is the coldkey owning `hotkey`.
+    fn is_owner(_hotkey: &AccountId, _who: &AccountId) -> bool {
+        false
+    }
 }
 
 impl<A> CanCommit<A> for () {
@@ -187,6 +358,16 @@ impl<A> CanCommit<A> for () {
     }
 }
 
+pub trait GetTempo {
+    fn get_tempo(netuid: u16) -> u16;
+}
+
+impl GetTempo for () {
+    fn get_tempo(_: u16) -> u16 {
+        0
+    }
+}
+
 /************************************************************
     CallType definition
 ************************************************************/
@@ -284,7 +465,7 @@ where
         _len: usize,
     ) -> Result<Self::Pre, TransactionValidityError> {
         match call.is_sub_type() {
-            Some(Call::set_commitment { .. }) => {
+            Some(Call::set_commitment { .. } | Call::set_hotkey_commitment { .. }) => {
                 let transaction_fee = 0;
                 Ok((CallType::SetCommitment, transaction_fee, who.clone()))
             }
@@ -1,13 +1,15 @@
-#![allow(non_camel_case_types)]
+#![allow(non_camel_case_types, clippy::unwrap_used)]
 
 use super::*;
 use crate as pallet_commitments;
 use frame_support::derive_impl;
 use frame_support::traits::ConstU64;
+use frame_support::{assert_noop, assert_ok};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, ConstU16, IdentityLookup},
+    BuildStorage,
 };
 
 pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
@@ -79,23 +81,234 @@ impl frame_system::Config for Test {
     type Nonce = u64;
 }
 
+pub struct TestCanCommit;
+impl CanCommit<u64> for TestCanCommit {
+    fn can_commit(_netuid: u16, who: &u64) -> bool {
+        *who != NOT_ALLOWED
+    }
+
+    // Hotkeys are owned by the coldkey 100 above them.
+    fn is_owner(hotkey: &u64, who: &u64) -> bool {
+        hotkey.saturating_add(100) == *who
+    }
+}
+
+pub struct TestTempo;
+impl GetTempo for TestTempo {
+    fn get_tempo(_netuid: u16) -> u16 {
+        TEMPO
+    }
+}
+
+pub const TEMPO: u16 = 9;
+pub const NOT_ALLOWED: u64 = 99;
+
 impl pallet_commitments::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type WeightInfo = ();
     type MaxFields = frame_support::traits::ConstU32<16>;
-    type CanCommit = ();
-    type FieldDeposit = frame_support::traits::ConstU64<0>;
+    type MaxCommitmentSize = frame_support::traits::ConstU32<1024>;
+    type CanCommit = TestCanCommit;
+    type TempoInterface = TestTempo;
+    type ByteDeposit = frame_support::traits::ConstU64<1>;
     type InitialDeposit = frame_support::traits::ConstU64<0>;
-    type RateLimit = frame_support::traits::ConstU64<0>;
 }
 
-// // Build genesis storage according to the mock runtime.
-// pub fn new_test_ext() -> sp_io::TestExternalities {
-//     let t = frame_system::GenesisConfig::<Test>::default()
-//         .build_storage()
-//         .unwrap();
-//     let mut ext = sp_io::TestExternalities::new(t);
-//     ext.execute_with(|| System::set_block_number(1));
-//     ext
-// }
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1_000_000), (2, 1_000_000), (NOT_ALLOWED, 1_000_000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn info_with_fields(num_fields: usize) -> Box<CommitmentInfo<frame_support::traits::ConstU32<16>>> {
+    let data = Data::Raw(vec![7; 128].try_into().unwrap());
+    Box::new(CommitmentInfo {
+        fields: vec![data; num_fields].try_into().unwrap(),
+    })
+}
+
+#[test]
+fn test_set_commitment_reserves_deposit_per_byte() {
+    new_test_ext().execute_with(|| {
+        let info = info_with_fields(2);
+        let size = info.encoded_size() as u64;
+
+        assert_ok!(Commitments::set_commitment(
+            RuntimeOrigin::signed(1),
+            1,
+            info
+        ));
+
+        assert_eq!(Balances::reserved_balance(1), size);
+        assert_eq!(Commitments::commitment_of(1, 1).unwrap().deposit, size);
+    });
+}
+
+#[test]
+fn test_set_commitment_not_allowed() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Commitments::set_commitment(RuntimeOrigin::signed(NOT_ALLOWED), 1, info_with_fields(1)),
+            Error::<Test>::AccountNotAllowedCommit
+        );
+    });
+}
+
+#[test]
+fn test_set_commitment_too_large() {
+    new_test_ext().execute_with(|| {
+        // 7 raw fields of 129 encoded bytes each fit within 1024 bytes, 8 do not.
+        assert_ok!(Commitments::set_commitment(
+            RuntimeOrigin::signed(1),
+            1,
+            info_with_fields(7)
+        ));
+        assert_noop!(
+            Commitments::set_commitment(RuntimeOrigin::signed(2), 1, info_with_fields(8)),
+            Error::<Test>::CommitmentTooLarge
+        );
+    });
+}
+
+#[test]
+fn test_set_commitment_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let netuid = 1;
+        assert_ok!(Commitments::set_rate_limit(
+            RuntimeOrigin::root(),
+            netuid,
+            2
+        ));
+        // 2 tempos of 10 blocks each.
+        assert_eq!(Commitments::rate_limit_blocks(netuid), 20);
+
+        assert_ok!(Commitments::set_commitment(
+            RuntimeOrigin::signed(1),
+            netuid,
+            info_with_fields(1)
+        ));
+
+        System::set_block_number(20);
+        assert_noop!(
+            Commitments::set_commitment(RuntimeOrigin::signed(1), netuid, info_with_fields(1)),
+            Error::<Test>::CommitmentSetRateLimitExceeded
+        );
+        // The limit is per subnet.
+        assert_ok!(Commitments::set_commitment(
+            RuntimeOrigin::signed(1),
+            2,
+            info_with_fields(1)
+        ));
+
+        System::set_block_number(21);
+        assert_ok!(Commitments::set_commitment(
+            RuntimeOrigin::signed(1),
+            netuid,
+            info_with_fields(1)
+        ));
+    });
+}
+
+#[test]
+fn test_set_rate_limit_requires_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Commitments::set_rate_limit(RuntimeOrigin::signed(1), 1, 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_set_hotkey_commitment_by_coldkey() {
+    new_test_ext().execute_with(|| {
+        let info = info_with_fields(1);
+        let size = info.encoded_size() as u64;
+
+        assert_noop!(
+            Commitments::set_hotkey_commitment(RuntimeOrigin::signed(102), 1, 1, info.clone()),
+            Error::<Test>::AccountNotAllowedCommit
+        );
+        assert_ok!(Commitments::set_hotkey_commitment(
+            RuntimeOrigin::signed(101),
+            1,
+            1,
+            info
+        ));
+
+        // The deposit is held on the hotkey.
+        assert_eq!(Balances::reserved_balance(1), size);
+        assert!(Commitments::commitment_of(1, 1).is_some());
+    });
+}
+
+#[test]
+fn test_purge_commitment_returns_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commitments::set_commitment(
+            RuntimeOrigin::signed(1),
+            1,
+            info_with_fields(1)
+        ));
+        assert_ok!(Commitments::set_commitment(
+            RuntimeOrigin::signed(2),
+            1,
+            info_with_fields(1)
+        ));
+
+        Commitments::purge_commitment(1, &1);
+        assert!(Commitments::commitment_of(1, 1).is_none());
+        assert!(Commitments::last_commitment(1, 1).is_none());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        System::assert_last_event(Event::CommitmentRemoved { netuid: 1, who: 1 }.into());
+
+        Commitments::purge_subnet(1);
+        assert!(Commitments::commitment_of(1, 2).is_none());
+        assert_eq!(Balances::reserved_balance(2), 0);
+    });
+}
+
+#[test]
+fn test_swap_commitment_moves_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commitments::set_commitment(
+            RuntimeOrigin::signed(1),
+            1,
+            info_with_fields(1)
+        ));
+        let deposit = Balances::reserved_balance(1);
+        let last_commit = Commitments::last_commitment(1, 1);
+
+        Commitments::swap_commitment(1, &1, &2);
+        assert!(Commitments::commitment_of(1, 1).is_none());
+        assert!(Commitments::last_commitment(1, 1).is_none());
+        assert!(Commitments::commitment_of(1, 2).is_some());
+        assert_eq!(Commitments::last_commitment(1, 2), last_commit);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), deposit);
+        System::assert_last_event(
+            Event::CommitmentSwapped {
+                netuid: 1,
+                from: 1,
+                to: 2,
+            }
+            .into(),
+        );
+
+        // An account that does not exist cannot hold the deposit, so the commitment is removed.
+        Commitments::swap_commitment(1, &2, &3);
+        assert!(Commitments::commitment_of(1, 2).is_none());
+        assert!(Commitments::commitment_of(1, 3).is_none());
+        assert_eq!(Balances::reserved_balance(2), 0);
+    });
+}
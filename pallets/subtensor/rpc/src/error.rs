@@ -0,0 +1,114 @@
+//! Errors returned by the custom Subtensor rpc methods
+
+use jsonrpsee::types::{error::ErrorObject, ErrorObjectOwned};
+use serde::Serialize;
+use sp_api::ApiError;
+
+/// The call to the runtime failed for any other reason.
+pub const RUNTIME_ERROR: i32 = 1;
+/// The runtime at the block predates the runtime API the method calls.
+pub const RUNTIME_API_UNAVAILABLE: i32 = 2;
+/// The runtime call arguments or its result could not be encoded or decoded.
+pub const DECODE_FAILURE: i32 = 3;
+/// The node does not know the requested block.
+pub const BLOCK_NOT_FOUND: i32 = 4;
+
+/// The keys a failed request queried, returned as the error data.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct QueryContext {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub netuid: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hotkey: Option<Vec<u8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coldkey: Option<Vec<u8>>,
+}
+
+impl QueryContext {
+    pub fn with_netuid(mut self, netuid: u16) -> Self {
+        self.netuid = Some(netuid);
+        self
+    }
+
+    pub fn with_hotkey(mut self, hotkey: &[u8]) -> Self {
+        self.hotkey = Some(hotkey.to_vec());
+        self
+    }
+
+    pub fn with_coldkey(mut self, coldkey: &[u8]) -> Self {
+        self.coldkey = Some(coldkey.to_vec());
+        self
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Error type of this RPC api.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubtensorRpcError {
+    /// The call to runtime failed.
+    RuntimeError(String, QueryContext),
+    /// The runtime does not implement the called API, usually because it is older than the node.
+    RuntimeApiUnavailable(String, QueryContext),
+    /// The runtime call could not be encoded or its result decoded.
+    DecodeFailure(String, QueryContext),
+    /// The requested block is unknown.
+    BlockNotFound(QueryContext),
+}
+
+impl SubtensorRpcError {
+    /// Classifies an error of the runtime call that was trying to `what`.
+    pub fn from_api_error(error: ApiError, what: &str, context: QueryContext) -> Self {
+        let message = format!("Unable to {}: {:?}", what, error);
+        match error {
+            ApiError::UnknownBlock(_) => Self::BlockNotFound(context),
+            ApiError::FailedToDecodeReturnValue { .. }
+            | ApiError::FailedToConvertReturnValue { .. }
+            | ApiError::FailedToConvertParameter { .. } => Self::DecodeFailure(message, context),
+            _ => Self::RuntimeError(message, context),
+        }
+    }
+
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::RuntimeError(..) => RUNTIME_ERROR,
+            Self::RuntimeApiUnavailable(..) => RUNTIME_API_UNAVAILABLE,
+            Self::DecodeFailure(..) => DECODE_FAILURE,
+            Self::BlockNotFound(_) => BLOCK_NOT_FOUND,
+        }
+    }
+
+    pub fn context(&self) -> &QueryContext {
+        match self {
+            Self::RuntimeError(_, context)
+            | Self::RuntimeApiUnavailable(_, context)
+            | Self::DecodeFailure(_, context)
+            | Self::BlockNotFound(context) => context,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            Self::RuntimeError(message, _)
+            | Self::RuntimeApiUnavailable(message, _)
+            | Self::DecodeFailure(message, _) => message.clone(),
+            Self::BlockNotFound(_) => "Block not found".into(),
+        }
+    }
+}
+
+impl From<SubtensorRpcError> for ErrorObjectOwned {
+    fn from(e: SubtensorRpcError) -> Self {
+        let context = e.context();
+        let data = (!context.is_empty()).then_some(context);
+        ErrorObject::owned(e.code(), e.message(), data)
+    }
+}
+
+impl From<SubtensorRpcError> for i32 {
+    fn from(e: SubtensorRpcError) -> i32 {
+        e.code()
+    }
+}
@@ -1,19 +1,19 @@
 //! RPC interface for the custom Subtensor rpc methods
 
-use jsonrpsee::{
-    core::RpcResult,
-    proc_macros::rpc,
-    types::{error::ErrorObject, ErrorObjectOwned},
-};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiError, ApiExt, ProvideRuntimeApi, RuntimeApiInfo};
+
+mod error;
+pub use error::{QueryContext, SubtensorRpcError};
 
 pub use subtensor_custom_rpc_runtime_api::{
-    DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, SubnetInfoRuntimeApi,
-    SubnetRegistrationRuntimeApi,
+    ChainConstantsRuntimeApi, CommitmentsRuntimeApi, DelegateInfoRuntimeApi,
+    EmissionInfoRuntimeApi, HotkeysInfoRuntimeApi, KeySwapRuntimeApi, NetworkStatsRuntimeApi,
+    NeuronInfoRuntimeApi, StakeInfoRuntimeApi, SubnetInfoRuntimeApi, SubnetRegistrationRuntimeApi,
 };
 
 #[rpc(client, server)]
@@ -41,6 +41,41 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_neurons(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "neuronInfo_getNeuron")]
     fn get_neuron(&self, netuid: u16, uid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getWeightSettingStatus")]
+    fn get_weight_setting_status(
+        &self,
+        netuid: u16,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getHotkeyRateLimitStatus")]
+    fn get_hotkey_rate_limit_status(
+        &self,
+        netuid: u16,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getHotkeyRegistrations")]
+    fn get_hotkey_registrations(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getPruningScores")]
+    fn get_pruning_scores(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u16>>;
+    #[method(name = "neuronInfo_getPruningScoreForUid")]
+    fn get_pruning_score_for_uid(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<u16>>;
+    #[method(name = "neuronInfo_getNetuidsForHotkey")]
+    fn get_netuids_for_hotkey(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u16>>;
 
     #[method(name = "subnetInfo_getSubnetInfo")]
     fn get_subnet_info(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
@@ -52,9 +87,169 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_subnets_info_v2(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetHyperparams")]
     fn get_subnet_hyperparams(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getEpochHistory")]
+    fn get_epoch_history(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getEpochCommitment")]
+    fn get_epoch_commitment(
+        &self,
+        netuid: u16,
+        epoch_index: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getAllSubnetNetuids")]
+    fn get_all_subnet_netuids(&self, at: Option<BlockHash>) -> RpcResult<Vec<u16>>;
+    #[method(name = "subnetInfo_getSubnetsCount")]
+    fn get_subnets_count(&self, at: Option<BlockHash>) -> RpcResult<u16>;
+    #[method(name = "subnetInfo_subnetExists")]
+    fn subnet_exists(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<bool>;
+    #[method(name = "subnetInfo_validateSubnetParams")]
+    fn validate_subnet_params(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 
     #[method(name = "subnetInfo_getLockCost")]
     fn get_network_lock_cost(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+    #[method(name = "subnetInfo_getNetworkRegistrationAllowedBlock")]
+    fn get_network_registration_allowed_block(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+    #[method(name = "subnetInfo_getRegistrationInfo")]
+    fn get_registration_info(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "stakeInfo_simulateRemoveStake")]
+    fn simulate_remove_stake(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+        amount: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_simulateAddStake")]
+    fn simulate_add_stake(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+        amount: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getTopStakesOnSubnet")]
+    fn get_top_stakes_on_subnet(
+        &self,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getStakeForHotkeyAndColdkeyOnNetuid")]
+    fn get_stake_for_hotkey_and_coldkey_on_netuid(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        coldkey_account_vec: Vec<u8>,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u64>;
+    #[method(name = "stakeInfo_getNominatorsForHotkey")]
+    fn get_nominators_for_hotkey(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getNominatorCount")]
+    fn get_nominator_count(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u32>;
+    #[method(name = "stakeInfo_getHotkeyStakeBreakdown")]
+    fn get_hotkey_stake_breakdown(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getStakeRateLimits")]
+    fn get_stake_rate_limits(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getVestedBalance")]
+    fn vested_balance(&self, coldkey_account_vec: Vec<u8>, at: Option<BlockHash>)
+        -> RpcResult<u64>;
+
+    #[method(name = "networkStats_getNetworkStats")]
+    fn get_network_stats(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "emissionInfo_getEmissionInfoForHotkey")]
+    fn get_emission_info_for_hotkey(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "emissionInfo_getPendingEmission")]
+    fn get_pending_emission(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "emissionInfo_getPendingHotkeyEmission")]
+    fn get_pending_hotkey_emission(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "hotkeysInfo_getOwnedHotkeysPaged")]
+    fn get_owned_hotkeys_paged(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "hotkeysInfo_getStakingHotkeysPaged")]
+    fn get_staking_hotkeys_paged(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "hotkeysInfo_getOwnedHotkeysOnSubnetPaged")]
+    fn get_owned_hotkeys_on_subnet_paged(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "hotkeysInfo_getStakingHotkeysOnSubnetPaged")]
+    fn get_staking_hotkeys_on_subnet_paged(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "commitments_getCommitment")]
+    fn get_commitment(
+        &self,
+        netuid: u16,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "commitments_getAttestation")]
+    fn get_attestation(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "keySwap_getKeySwapCost")]
+    fn get_key_swap_cost(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+
+    #[method(name = "chainConstants_getChainConstants")]
+    fn get_chain_constants(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 }
 
 pub struct SubtensorCustom<C, P> {
@@ -73,25 +268,50 @@ impl<C, P> SubtensorCustom<C, P> {
     }
 }
 
-/// Error type of this RPC api.
-pub enum Error {
-    /// The call to runtime failed.
-    RuntimeError(String),
-}
-
-impl From<Error> for ErrorObjectOwned {
-    fn from(e: Error) -> Self {
-        match e {
-            Error::RuntimeError(e) => ErrorObject::owned(1, e, None::<()>),
+impl<C, Block> SubtensorCustom<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+{
+    /// Runs `call` on the runtime API `Api` at `at`, or the best block, turning its failures
+    /// into `SubtensorRpcError`s that carry `context`.
+    fn call_api<Api, R>(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        what: &str,
+        context: QueryContext,
+        call: impl FnOnce(&C::Api, <Block as BlockT>::Hash) -> Result<R, ApiError>,
+    ) -> RpcResult<R>
+    where
+        Api: RuntimeApiInfo + ?Sized,
+    {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        match self.client.header(at) {
+            Ok(Some(_)) => {}
+            Ok(None) => return Err(SubtensorRpcError::BlockNotFound(context).into()),
+            Err(e) => {
+                return Err(SubtensorRpcError::RuntimeError(
+                    format!("Unable to {}: {:?}", what, e),
+                    context,
+                )
+                .into())
+            }
         }
-    }
-}
 
-impl From<Error> for i32 {
-    fn from(e: Error) -> i32 {
-        match e {
-            Error::RuntimeError(_) => 1,
+        let api = self.client.runtime_api();
+        match api.has_api::<Api>(at) {
+            Ok(true) => {}
+            Ok(false) => {
+                return Err(SubtensorRpcError::RuntimeApiUnavailable(
+                    format!("Unable to {}: the runtime does not implement the API", what),
+                    context,
+                )
+                .into())
+            }
+            Err(e) => return Err(SubtensorRpcError::from_api_error(e, what, context).into()),
         }
+
+        call(&api, at).map_err(|e| SubtensorRpcError::from_api_error(e, what, context).into())
     }
 }
 
@@ -102,15 +322,22 @@ where
     C::Api: DelegateInfoRuntimeApi<Block>,
     C::Api: NeuronInfoRuntimeApi<Block>,
     C::Api: SubnetInfoRuntimeApi<Block>,
+    C::Api: StakeInfoRuntimeApi<Block>,
     C::Api: SubnetRegistrationRuntimeApi<Block>,
+    C::Api: NetworkStatsRuntimeApi<Block>,
+    C::Api: EmissionInfoRuntimeApi<Block>,
+    C::Api: HotkeysInfoRuntimeApi<Block>,
+    C::Api: CommitmentsRuntimeApi<Block>,
+    C::Api: KeySwapRuntimeApi<Block>,
+    C::Api: ChainConstantsRuntimeApi<Block>,
 {
     fn get_delegates(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
-
-        api.get_delegates(at).map_err(|e| {
-            Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into()
-        })
+        self.call_api::<dyn DelegateInfoRuntimeApi<Block>, _>(
+            at,
+            "get delegates info",
+            QueryContext::default(),
+            |api, at| api.get_delegates(at),
+        )
     }
 
     fn get_delegate(
@@ -118,12 +345,12 @@ where
         delegate_account_vec: Vec<u8>,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
-
-        api.get_delegate(at, delegate_account_vec).map_err(|e| {
-            Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into()
-        })
+        self.call_api::<dyn DelegateInfoRuntimeApi<Block>, _>(
+            at,
+            "get delegates info",
+            QueryContext::default().with_hotkey(&delegate_account_vec),
+            |api, at| api.get_delegate(at, delegate_account_vec),
+        )
     }
 
     fn get_delegated(
@@ -131,12 +358,12 @@ where
         delegatee_account_vec: Vec<u8>,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
-
-        api.get_delegated(at, delegatee_account_vec).map_err(|e| {
-            Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into()
-        })
+        self.call_api::<dyn DelegateInfoRuntimeApi<Block>, _>(
+            at,
+            "get delegates info",
+            QueryContext::default().with_coldkey(&delegatee_account_vec),
+            |api, at| api.get_delegated(at, delegatee_account_vec),
+        )
     }
 
     fn get_neurons_lite(
@@ -144,12 +371,12 @@ where
         netuid: u16,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
-
-        api.get_neurons_lite(at, netuid).map_err(|e| {
-            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e)).into()
-        })
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get neurons lite info",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_neurons_lite(at, netuid),
+        )
     }
 
     fn get_neuron_lite(
@@ -158,20 +385,21 @@ where
         uid: u16,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
-
-        api.get_neuron_lite(at, netuid, uid).map_err(|e| {
-            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e)).into()
-        })
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get neurons lite info",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_neuron_lite(at, netuid, uid),
+        )
     }
 
     fn get_neurons(&self, netuid: u16, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
-
-        api.get_neurons(at, netuid)
-            .map_err(|e| Error::RuntimeError(format!("Unable to get neurons info: {:?}", e)).into())
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get neurons info",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_neurons(at, netuid),
+        )
     }
 
     fn get_neuron(
@@ -180,23 +408,110 @@ where
         uid: u16,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get neuron info",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_neuron(at, netuid, uid),
+        )
+    }
 
-        api.get_neuron(at, netuid, uid)
-            .map_err(|e| Error::RuntimeError(format!("Unable to get neuron info: {:?}", e)).into())
+    fn get_weight_setting_status(
+        &self,
+        netuid: u16,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get weight setting status",
+            QueryContext::default()
+                .with_netuid(netuid)
+                .with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_weight_setting_status(at, netuid, hotkey_account_vec),
+        )
     }
 
-    fn get_subnet_info(
+    fn get_hotkey_rate_limit_status(
         &self,
         netuid: u16,
+        hotkey_account_vec: Vec<u8>,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get hotkey rate limit status",
+            QueryContext::default()
+                .with_netuid(netuid)
+                .with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_hotkey_rate_limit_status(at, netuid, hotkey_account_vec),
+        )
+    }
+
+    fn get_hotkey_registrations(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get hotkey registrations",
+            QueryContext::default().with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_hotkey_registrations(at, hotkey_account_vec),
+        )
+    }
 
-        api.get_subnet_info(at, netuid)
-            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)).into())
+    fn get_pruning_scores(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u16>> {
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get pruning scores",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_pruning_scores(at, netuid),
+        )
+    }
+
+    fn get_pruning_score_for_uid(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<u16>> {
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get pruning score",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_pruning_score_for_uid(at, netuid, uid),
+        )
+    }
+
+    fn get_netuids_for_hotkey(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u16>> {
+        self.call_api::<dyn NeuronInfoRuntimeApi<Block>, _>(
+            at,
+            "get netuids for hotkey",
+            QueryContext::default().with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_netuids_for_hotkey(at, hotkey_account_vec),
+        )
+    }
+
+    fn get_subnet_info(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "get subnet info",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_subnet_info(at, netuid),
+        )
     }
 
     fn get_subnet_hyperparams(
@@ -204,47 +519,469 @@ where
         netuid: u16,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "get subnet info",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_subnet_hyperparams(at, netuid),
+        )
+    }
 
-        api.get_subnet_hyperparams(at, netuid)
-            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)).into())
+    fn get_epoch_history(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "get epoch history",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_epoch_history(at, netuid),
+        )
     }
 
-    fn get_subnets_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+    fn get_epoch_commitment(
+        &self,
+        netuid: u16,
+        epoch_index: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "get epoch commitment",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_epoch_commitment(at, netuid, epoch_index),
+        )
+    }
 
-        api.get_subnets_info(at)
-            .map_err(|e| Error::RuntimeError(format!("Unable to get subnets info: {:?}", e)).into())
+    fn get_all_subnet_netuids(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u16>> {
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "get subnet netuids",
+            QueryContext::default(),
+            |api, at| api.get_all_subnet_netuids(at),
+        )
     }
 
-    fn get_subnet_info_v2(
+    fn get_subnets_count(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u16> {
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "get subnets count",
+            QueryContext::default(),
+            |api, at| api.get_subnets_count(at),
+        )
+    }
+
+    fn subnet_exists(&self, netuid: u16, at: Option<<Block as BlockT>::Hash>) -> RpcResult<bool> {
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "check subnet existence",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.subnet_exists(at, netuid),
+        )
+    }
+
+    fn validate_subnet_params(
         &self,
         netuid: u16,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "validate subnet params",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.validate_subnet_params(at, netuid),
+        )
+    }
 
-        api.get_subnet_info_v2(at, netuid)
-            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)).into())
+    fn get_subnets_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "get subnets info",
+            QueryContext::default(),
+            |api, at| api.get_subnets_info(at),
+        )
     }
 
-    fn get_subnets_info_v2(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+    fn get_subnet_info_v2(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "get subnet info",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_subnet_info_v2(at, netuid),
+        )
+    }
 
-        api.get_subnets_info_v2(at)
-            .map_err(|e| Error::RuntimeError(format!("Unable to get subnets info: {:?}", e)).into())
+    fn get_subnets_info_v2(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn SubnetInfoRuntimeApi<Block>, _>(
+            at,
+            "get subnets info",
+            QueryContext::default(),
+            |api, at| api.get_subnets_info_v2(at),
+        )
     }
 
     fn get_network_lock_cost(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u64> {
-        let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.call_api::<dyn SubnetRegistrationRuntimeApi<Block>, _>(
+            at,
+            "get subnet lock cost",
+            QueryContext::default(),
+            |api, at| api.get_network_registration_cost(at),
+        )
+    }
+
+    fn get_network_registration_allowed_block(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        self.call_api::<dyn SubnetRegistrationRuntimeApi<Block>, _>(
+            at,
+            "get network registration allowed block",
+            QueryContext::default(),
+            |api, at| api.get_network_registration_allowed_block(at),
+        )
+    }
+
+    fn get_registration_info(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn SubnetRegistrationRuntimeApi<Block>, _>(
+            at,
+            "get registration info",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_registration_info(at, netuid),
+        )
+    }
+
+    fn simulate_remove_stake(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+        amount: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn StakeInfoRuntimeApi<Block>, _>(
+            at,
+            "simulate remove stake",
+            QueryContext::default()
+                .with_coldkey(&coldkey_account_vec)
+                .with_hotkey(&hotkey_account_vec),
+            |api, at| {
+                api.simulate_remove_stake(at, coldkey_account_vec, hotkey_account_vec, amount)
+            },
+        )
+    }
+
+    fn simulate_add_stake(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+        amount: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn StakeInfoRuntimeApi<Block>, _>(
+            at,
+            "simulate add stake",
+            QueryContext::default()
+                .with_coldkey(&coldkey_account_vec)
+                .with_hotkey(&hotkey_account_vec),
+            |api, at| api.simulate_add_stake(at, coldkey_account_vec, hotkey_account_vec, amount),
+        )
+    }
+
+    fn get_top_stakes_on_subnet(
+        &self,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn StakeInfoRuntimeApi<Block>, _>(
+            at,
+            "get top stakes on subnet",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_top_stakes_on_subnet(at, netuid, start, limit),
+        )
+    }
+
+    fn get_stake_for_hotkey_and_coldkey_on_netuid(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        coldkey_account_vec: Vec<u8>,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        self.call_api::<dyn StakeInfoRuntimeApi<Block>, _>(
+            at,
+            "get stake on subnet",
+            QueryContext::default()
+                .with_netuid(netuid)
+                .with_hotkey(&hotkey_account_vec)
+                .with_coldkey(&coldkey_account_vec),
+            |api, at| {
+                api.get_stake_for_hotkey_and_coldkey_on_netuid(
+                    at,
+                    hotkey_account_vec,
+                    coldkey_account_vec,
+                    netuid,
+                )
+            },
+        )
+    }
+
+    fn get_nominators_for_hotkey(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn StakeInfoRuntimeApi<Block>, _>(
+            at,
+            "get nominators for hotkey",
+            QueryContext::default()
+                .with_netuid(netuid)
+                .with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_nominators_for_hotkey(at, hotkey_account_vec, netuid, start, limit),
+        )
+    }
+
+    fn get_nominator_count(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u32> {
+        self.call_api::<dyn StakeInfoRuntimeApi<Block>, _>(
+            at,
+            "get nominator count",
+            QueryContext::default()
+                .with_netuid(netuid)
+                .with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_nominator_count(at, hotkey_account_vec, netuid),
+        )
+    }
+
+    fn get_hotkey_stake_breakdown(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn StakeInfoRuntimeApi<Block>, _>(
+            at,
+            "get hotkey stake breakdown",
+            QueryContext::default()
+                .with_netuid(netuid)
+                .with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_hotkey_stake_breakdown(at, hotkey_account_vec, netuid),
+        )
+    }
+
+    fn get_stake_rate_limits(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn StakeInfoRuntimeApi<Block>, _>(
+            at,
+            "get stake rate limits",
+            QueryContext::default()
+                .with_coldkey(&coldkey_account_vec)
+                .with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_stake_rate_limits(at, coldkey_account_vec, hotkey_account_vec),
+        )
+    }
+
+    fn vested_balance(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        self.call_api::<dyn StakeInfoRuntimeApi<Block>, _>(
+            at,
+            "get vested balance",
+            QueryContext::default().with_coldkey(&coldkey_account_vec),
+            |api, at| api.vested_balance(at, coldkey_account_vec),
+        )
+    }
+
+    fn get_network_stats(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn NetworkStatsRuntimeApi<Block>, _>(
+            at,
+            "get network stats",
+            QueryContext::default(),
+            |api, at| api.get_network_stats(at),
+        )
+    }
+
+    fn get_emission_info_for_hotkey(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn EmissionInfoRuntimeApi<Block>, _>(
+            at,
+            "get emission info",
+            QueryContext::default().with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_emission_info_for_hotkey(at, hotkey_account_vec),
+        )
+    }
+
+    fn get_pending_emission(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn EmissionInfoRuntimeApi<Block>, _>(
+            at,
+            "get pending emission",
+            QueryContext::default().with_netuid(netuid),
+            |api, at| api.get_pending_emission(at, netuid),
+        )
+    }
+
+    fn get_pending_hotkey_emission(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn EmissionInfoRuntimeApi<Block>, _>(
+            at,
+            "get pending hotkey emission",
+            QueryContext::default().with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_pending_hotkey_emission(at, hotkey_account_vec),
+        )
+    }
+
+    fn get_owned_hotkeys_paged(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        start: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn HotkeysInfoRuntimeApi<Block>, _>(
+            at,
+            "get hotkeys",
+            QueryContext::default().with_coldkey(&coldkey_account_vec),
+            |api, at| api.get_owned_hotkeys_paged(at, coldkey_account_vec, start, limit),
+        )
+    }
+
+    fn get_staking_hotkeys_paged(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        start: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn HotkeysInfoRuntimeApi<Block>, _>(
+            at,
+            "get hotkeys",
+            QueryContext::default().with_coldkey(&coldkey_account_vec),
+            |api, at| api.get_staking_hotkeys_paged(at, coldkey_account_vec, start, limit),
+        )
+    }
+
+    fn get_owned_hotkeys_on_subnet_paged(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn HotkeysInfoRuntimeApi<Block>, _>(
+            at,
+            "get hotkeys",
+            QueryContext::default()
+                .with_netuid(netuid)
+                .with_coldkey(&coldkey_account_vec),
+            |api, at| {
+                api.get_owned_hotkeys_on_subnet_paged(at, coldkey_account_vec, netuid, start, limit)
+            },
+        )
+    }
+
+    fn get_staking_hotkeys_on_subnet_paged(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn HotkeysInfoRuntimeApi<Block>, _>(
+            at,
+            "get hotkeys",
+            QueryContext::default()
+                .with_netuid(netuid)
+                .with_coldkey(&coldkey_account_vec),
+            |api, at| {
+                api.get_staking_hotkeys_on_subnet_paged(
+                    at,
+                    coldkey_account_vec,
+                    netuid,
+                    start,
+                    limit,
+                )
+            },
+        )
+    }
+
+    fn get_commitment(
+        &self,
+        netuid: u16,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn CommitmentsRuntimeApi<Block>, _>(
+            at,
+            "get commitment",
+            QueryContext::default()
+                .with_netuid(netuid)
+                .with_hotkey(&hotkey_account_vec),
+            |api, at| api.get_commitment(at, netuid, hotkey_account_vec),
+        )
+    }
+
+    fn get_attestation(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn CommitmentsRuntimeApi<Block>, _>(
+            at,
+            "get attestation",
+            QueryContext::default().with_coldkey(&coldkey_account_vec),
+            |api, at| api.get_attestation(at, coldkey_account_vec),
+        )
+    }
+
+    fn get_key_swap_cost(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u64> {
+        self.call_api::<dyn KeySwapRuntimeApi<Block>, _>(
+            at,
+            "get key swap cost",
+            QueryContext::default(),
+            |api, at| api.get_key_swap_cost(at),
+        )
+    }
 
-        api.get_network_registration_cost(at).map_err(|e| {
-            Error::RuntimeError(format!("Unable to get subnet lock cost: {:?}", e)).into()
-        })
+    fn get_chain_constants(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        self.call_api::<dyn ChainConstantsRuntimeApi<Block>, _>(
+            at,
+            "get chain constants",
+            QueryContext::default(),
+            |api, at| api.get_chain_constants(at),
+        )
     }
 }
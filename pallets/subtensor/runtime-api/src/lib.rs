@@ -16,6 +16,12 @@ sp_api::decl_runtime_apis! {
         fn get_neuron(netuid: u16, uid: u16) -> Vec<u8>;
         fn get_neurons_lite(netuid: u16) -> Vec<u8>;
         fn get_neuron_lite(netuid: u16, uid: u16) -> Vec<u8>;
+        fn get_weight_setting_status(netuid: u16, hotkey_account_vec: Vec<u8>) -> Vec<u8>;
+        fn get_hotkey_rate_limit_status(netuid: u16, hotkey_account_vec: Vec<u8>) -> Vec<u8>;
+        fn get_hotkey_registrations(hotkey_account_vec: Vec<u8>) -> Vec<u8>;
+        fn get_pruning_scores(netuid: u16) -> Vec<u16>;
+        fn get_pruning_score_for_uid(netuid: u16, uid: u16) -> Option<u16>;
+        fn get_netuids_for_hotkey(hotkey_account_vec: Vec<u8>) -> Vec<u16>;
     }
 
     pub trait SubnetInfoRuntimeApi {
@@ -24,14 +30,66 @@ sp_api::decl_runtime_apis! {
         fn get_subnet_info_v2(netuid: u16) -> Vec<u8>;
         fn get_subnets_info_v2() -> Vec<u8>;
         fn get_subnet_hyperparams(netuid: u16) -> Vec<u8>;
+        fn get_epoch_history(netuid: u16) -> Vec<u8>;
+        fn get_epoch_commitment(netuid: u16, epoch_index: u64) -> Vec<u8>;
+        fn get_all_subnet_netuids() -> Vec<u16>;
+        fn get_subnets_count() -> u16;
+        fn subnet_exists(netuid: u16) -> bool;
+        fn validate_subnet_params(netuid: u16) -> Vec<u8>;
     }
 
     pub trait StakeInfoRuntimeApi {
         fn get_stake_info_for_coldkey( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
         fn get_stake_info_for_coldkeys( coldkey_account_vecs: Vec<Vec<u8>> ) -> Vec<u8>;
+        fn simulate_remove_stake( coldkey_account_vec: Vec<u8>, hotkey_account_vec: Vec<u8>, amount: u64 ) -> Vec<u8>;
+        fn simulate_add_stake( coldkey_account_vec: Vec<u8>, hotkey_account_vec: Vec<u8>, amount: u64 ) -> Vec<u8>;
+        fn get_top_stakes_on_subnet( netuid: u16, start: u32, limit: u32 ) -> Vec<u8>;
+        fn get_stake_for_hotkey_and_coldkey_on_netuid( hotkey_account_vec: Vec<u8>, coldkey_account_vec: Vec<u8>, netuid: u16 ) -> u64;
+        fn get_nominators_for_hotkey( hotkey_account_vec: Vec<u8>, netuid: u16, start: u32, limit: u32 ) -> Vec<u8>;
+        fn get_nominator_count( hotkey_account_vec: Vec<u8>, netuid: u16 ) -> u32;
+        fn get_hotkey_stake_breakdown( hotkey_account_vec: Vec<u8>, netuid: u16 ) -> Vec<u8>;
+        fn get_stake_rate_limits( coldkey_account_vec: Vec<u8>, hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn vested_balance( coldkey_account_vec: Vec<u8> ) -> u64;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
         fn get_network_registration_cost() -> u64;
+        fn get_network_registration_allowed_block() -> u64;
+        fn get_registration_info(netuid: u16) -> Vec<u8>;
+    }
+
+    pub trait NetworkStatsRuntimeApi {
+        fn get_network_stats() -> Vec<u8>;
+    }
+
+    pub trait EmissionInfoRuntimeApi {
+        fn get_emission_info_for_hotkey( hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_pending_emission( netuid: u16 ) -> Vec<u8>;
+        fn get_pending_hotkey_emission( hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
+    }
+
+    pub trait HotkeysInfoRuntimeApi {
+        fn get_owned_hotkeys_paged( coldkey_account_vec: Vec<u8>, start: u32, limit: u32 ) -> Vec<u8>;
+        fn get_staking_hotkeys_paged( coldkey_account_vec: Vec<u8>, start: u32, limit: u32 ) -> Vec<u8>;
+        fn get_owned_hotkeys_on_subnet_paged( coldkey_account_vec: Vec<u8>, netuid: u16, start: u32, limit: u32 ) -> Vec<u8>;
+        fn get_staking_hotkeys_on_subnet_paged( coldkey_account_vec: Vec<u8>, netuid: u16, start: u32, limit: u32 ) -> Vec<u8>;
+    }
+
+    pub trait CommitmentsRuntimeApi {
+        fn get_commitment( netuid: u16, hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_attestation( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
+    }
+
+    pub trait KeySwapRuntimeApi {
+        fn get_key_swap_cost() -> u64;
+    }
+
+    pub trait ChainConstantsRuntimeApi {
+        fn get_chain_constants() -> Vec<u8>;
+    }
+
+    /// Only implemented by runtimes built with the `try-runtime` feature.
+    pub trait ColdkeySwapDryRunRuntimeApi {
+        fn execute_coldkey_swap_dry_run( old_coldkey_account_vec: Vec<u8>, new_coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
     }
 }
@@ -1,24 +1,77 @@
 use super::*;
 use frame_support::storage::IterableStorageMap;
+use frame_support::weights::Weight;
+use sp_core::Get;
 use substrate_fixed::types::I110F18;
 
+impl BlockStepStage {
+    /// All stages of the block step, in the order they run.
+    pub const ALL: [BlockStepStage; 9] = [
+        BlockStepStage::AdjustRegistrationTerms,
+        BlockStepStage::ApplyPendingChildKeys,
+        BlockStepStage::RootEpoch,
+        BlockStepStage::AccumulateSubnetEmission,
+        BlockStepStage::RunSubnetEpochs,
+        BlockStepStage::ScheduleHotkeyDrains,
+        BlockStepStage::DrainHotkeys,
+        BlockStepStage::AdjustKeySwapCost,
+        BlockStepStage::PruneExpiredInvites,
+    ];
+}
+
 impl<T: Config> Pallet<T> {
     /// Executes the necessary operations for each block.
-    pub fn block_step() -> Result<(), &'static str> {
+    ///
+    /// Runs every stage of the block step in order and records the weight consumed by each
+    /// stage in `BlockStepWeight`. Returns the total weight, including the record itself.
+    pub fn block_step() -> Weight {
         let block_number: u64 = Self::get_current_block_as_u64();
         log::debug!("block_step for block: {:?} ", block_number);
-        // --- 1. Adjust difficulties.
-        Self::adjust_registration_terms_for_networks();
-        // --- 2. Run emission through network.
-        Self::run_coinbase();
-        // Return ok.
-        Ok(())
+        let mut stage_weights: Vec<(BlockStepStage, Weight)> = Vec::new();
+        for stage in BlockStepStage::ALL {
+            let stage_weight: Weight = Self::run_block_step_stage(stage, block_number);
+            log::debug!("block step stage {:?} weight: {:?}", stage, stage_weight);
+            stage_weights.push((stage, stage_weight));
+        }
+        BlockStepWeight::<T>::put(stage_weights);
+        Self::get_block_step_weight().saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Runs a single stage of the block step and returns the weight it consumed.
+    pub fn run_block_step_stage(stage: BlockStepStage, block_number: u64) -> Weight {
+        match stage {
+            // --- 1. Adjust difficulties.
+            BlockStepStage::AdjustRegistrationTerms => {
+                Self::adjust_registration_terms_for_networks()
+            }
+            // --- 2. Run emission through network.
+            BlockStepStage::RootEpoch => Self::run_root_epoch(block_number),
+            BlockStepStage::AccumulateSubnetEmission => Self::accumulate_subnet_emission(),
+            BlockStepStage::RunSubnetEpochs => Self::run_subnet_epochs(block_number),
+            BlockStepStage::ScheduleHotkeyDrains => Self::schedule_hotkey_drains(block_number),
+            BlockStepStage::DrainHotkeys => {
+                Self::drain_scheduled_hotkeys(Self::hotkey_drain_block_budget())
+            }
+            BlockStepStage::AdjustKeySwapCost => Self::adjust_key_swap_cost(block_number),
+            BlockStepStage::ApplyPendingChildKeys => Self::apply_pending_child_keys(block_number),
+            BlockStepStage::PruneExpiredInvites => Self::prune_expired_invites(block_number),
+        }
+    }
+
+    /// Returns the total weight consumed by the stages of the last block step.
+    pub fn get_block_step_weight() -> Weight {
+        BlockStepWeight::<T>::get()
+            .iter()
+            .fold(Weight::zero(), |total, (_, stage_weight)| {
+                total.saturating_add(*stage_weight)
+            })
     }
 
     /// Adjusts the network difficulties/burns of every active network. Resetting state parameters.
     ///
-    pub fn adjust_registration_terms_for_networks() {
+    pub fn adjust_registration_terms_for_networks() -> Weight {
         log::debug!("adjust_registration_terms_for_networks");
+        let mut weight: Weight = Weight::zero();
 
         // --- 1. Iterate through each network.
         for (netuid, _) in <NetworksAdded<T> as IterableStorageMap<u16, bool>>::iter() {
@@ -35,8 +88,14 @@ impl<T: Config> Pallet<T> {
 
             // --- 3. Check if we are at the adjustment interval for this network.
             // If so, we need to adjust the registration difficulty based on target and actual registrations.
+            weight.saturating_accrue(T::DbWeight::get().reads(3));
             if current_block.saturating_sub(last_adjustment_block) >= adjustment_interval as u64 {
                 log::debug!("interval reached.");
+                // The counters, up to two upgraded terms with their bounds, and the counter resets.
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(12, 6));
+
+                // --- 3.1 Flag the network if it has been without emission for too long.
+                weight.saturating_accrue(Self::update_subnet_warning(netuid, current_block));
 
                 // --- 4. Get the current counters for this network w.r.t burn and difficulty values.
                 let current_burn: u64 = Self::get_burn_as_u64(netuid);
@@ -158,6 +217,10 @@ impl<T: Config> Pallet<T> {
                     }
                 }
 
+                // --- 5.1 Record the adjusted terms in the adjustment history.
+                weight
+                    .saturating_accrue(Self::record_registration_adjustment(netuid, current_block));
+
                 // --- 6. Drain all counters for this network for this interval.
                 Self::set_last_adjustment_block(netuid, current_block);
                 Self::set_registrations_this_interval(netuid, 0);
@@ -169,7 +232,56 @@ impl<T: Config> Pallet<T> {
 
             // --- 7. Drain block registrations for each network. Needed for registration rate limits.
             Self::set_registrations_this_block(netuid, 0);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
         }
+        weight
+    }
+
+    /// Appends the burn and difficulty of a subnet after an adjustment to its adjustment history,
+    /// dropping the oldest entry past `REGISTRATION_ADJUSTMENT_HISTORY_LEN`.
+    pub fn record_registration_adjustment(netuid: u16, current_block: u64) -> Weight {
+        let burn: u64 = Self::get_burn_as_u64(netuid);
+        let difficulty: u64 = Self::get_difficulty_as_u64(netuid);
+        RegistrationAdjustmentHistory::<T>::mutate(netuid, |history| {
+            history.push((current_block, burn, difficulty));
+            if history.len() > REGISTRATION_ADJUSTMENT_HISTORY_LEN {
+                history.remove(0);
+            }
+        });
+        T::DbWeight::get().reads_writes(3, 1)
+    }
+
+    /// Raises the `SubnetWarning` of a subnet once its emission has been zero for
+    /// `SubnetWarningTempos` tempos, and clears it as soon as the subnet receives emission again.
+    ///
+    /// Runs at each registration adjustment of the subnet, so the zero emission period is counted
+    /// from the first adjustment that saw it.
+    pub fn update_subnet_warning(netuid: u16, current_block: u64) -> Weight {
+        if netuid == Self::get_root_netuid() {
+            return Weight::zero();
+        }
+        if Self::get_emission_value(netuid) > 0 {
+            if ZeroEmissionSince::<T>::take(netuid).is_some() && SubnetWarning::<T>::get(netuid) {
+                Self::set_subnet_warning(netuid, false);
+            }
+            return T::DbWeight::get().reads_writes(3, 2);
+        }
+
+        let zero_emission_since: u64 = ZeroEmissionSince::<T>::get(netuid).unwrap_or_else(|| {
+            ZeroEmissionSince::<T>::insert(netuid, current_block);
+            current_block
+        });
+        let tempo_blocks: u64 = (Self::get_tempo(netuid) as u64).saturating_add(1);
+        let zero_emission_tempos: u64 = current_block
+            .saturating_sub(zero_emission_since)
+            .checked_div(tempo_blocks)
+            .unwrap_or(0);
+        if zero_emission_tempos >= SubnetWarningTempos::<T>::get()
+            && !SubnetWarning::<T>::get(netuid)
+        {
+            Self::set_subnet_warning(netuid, true);
+        }
+        T::DbWeight::get().reads_writes(5, 2)
     }
 
     /// Calculates the upgraded difficulty by multiplying the current difficulty by the ratio ( reg_actual + reg_target / reg_target + reg_target )
@@ -209,6 +321,62 @@ impl<T: Config> Pallet<T> {
     /// Calculates the upgraded burn by multiplying the current burn by the ratio ( reg_actual + reg_target / reg_target + reg_target )
     /// We use I110F18 to avoid any overflows on u64. Also min_burn and max_burn bound the range.
     ///
+    /// Adjusts the key swap cost once every `KeySwapCostAdjustmentInterval` blocks and resets the
+    /// key swap counter.
+    ///
+    /// The cost moves toward `TargetKeySwapsPerInterval` the same way the registration burn moves
+    /// toward the target registrations, and is clamped between `MinKeySwapCost` and
+    /// `MaxKeySwapCost`.
+    pub fn adjust_key_swap_cost(block_number: u64) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads(2);
+        if block_number.saturating_sub(LastKeySwapCostAdjustmentBlock::<T>::get())
+            < KeySwapCostAdjustmentInterval::<T>::get()
+        {
+            return weight;
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(5, 3));
+
+        let key_swap_cost: u64 = Self::upgraded_key_swap_cost(
+            KeySwapCost::<T>::get(),
+            KeySwapsThisInterval::<T>::get(),
+            TargetKeySwapsPerInterval::<T>::get(),
+        );
+        log::debug!("key swap cost adjusted to: {:?}", key_swap_cost);
+        KeySwapCost::<T>::put(key_swap_cost);
+        KeySwapsThisInterval::<T>::put(0);
+        LastKeySwapCostAdjustmentBlock::<T>::put(block_number);
+        Self::deposit_event(Event::KeySwapCostAdjusted(key_swap_cost));
+        weight
+    }
+
+    pub fn upgraded_key_swap_cost(
+        current_cost: u64,
+        key_swaps_this_interval: u64,
+        target_key_swaps_per_interval: u64,
+    ) -> u64 {
+        // Without a target, the cost only follows its bounds.
+        let updated_cost: I110F18 = if target_key_swaps_per_interval == 0 {
+            I110F18::from_num(current_cost)
+        } else {
+            I110F18::from_num(current_cost)
+                .saturating_mul(I110F18::from_num(
+                    key_swaps_this_interval.saturating_add(target_key_swaps_per_interval),
+                ))
+                .saturating_div(I110F18::from_num(
+                    target_key_swaps_per_interval.saturating_add(target_key_swaps_per_interval),
+                ))
+        };
+        let max_cost: u64 = MaxKeySwapCost::<T>::get();
+        let min_cost: u64 = MinKeySwapCost::<T>::get();
+        if updated_cost >= I110F18::from_num(max_cost) {
+            max_cost
+        } else if updated_cost <= I110F18::from_num(min_cost) {
+            min_cost
+        } else {
+            updated_cost.to_num::<u64>()
+        }
+    }
+
     pub fn upgraded_burn(
         netuid: u16,
         current_burn: u64,
@@ -0,0 +1,66 @@
+use super::*;
+use crate::epoch::math::*;
+use substrate_fixed::types::I64F64;
+
+impl<T: Config> Pallet<T> {
+    pub fn get_emission_multiplier(netuid: u16) -> u32 {
+        EmissionMultiplier::<T>::get(netuid)
+    }
+    /// Sets the emission multiplier of a subnet, in millionths. The caller checks it is at most
+    /// `MAX_EMISSION_MULTIPLIER`.
+    pub fn set_emission_multiplier(netuid: u16, multiplier: u32) {
+        if multiplier == EMISSION_MULTIPLIER_ONE {
+            EmissionMultiplier::<T>::remove(netuid);
+        } else {
+            EmissionMultiplier::<T>::insert(netuid, multiplier);
+        }
+        Self::deposit_event(Event::EmissionMultiplierSet(netuid, multiplier));
+    }
+
+    /// Scales the normalized block emission shares of `netuids` by their emission multipliers,
+    /// keeping the total.
+    ///
+    /// A subnet with a multiplier below 1.0 gets exactly its scaled share. The remainder is split
+    /// between the other subnets in proportion to their scaled shares, so a multiplier above 1.0
+    /// raises the part of the subnet in that split. When the other subnets have no share, the
+    /// scaled shares are normalized instead.
+    pub fn apply_emission_multipliers(netuids: &[u16], shares: &mut [I64F64]) {
+        let multipliers: Vec<u32> = netuids
+            .iter()
+            .map(|netuid| Self::get_emission_multiplier(*netuid).min(MAX_EMISSION_MULTIPLIER))
+            .collect();
+        if multipliers
+            .iter()
+            .all(|multiplier| *multiplier == EMISSION_MULTIPLIER_ONE)
+        {
+            return;
+        }
+
+        let one: I64F64 = I64F64::from_num(EMISSION_MULTIPLIER_ONE);
+        let mut penalized_total: I64F64 = I64F64::from_num(0);
+        let mut others_total: I64F64 = I64F64::from_num(0);
+        for (share, multiplier) in shares.iter_mut().zip(&multipliers) {
+            *share = share
+                .saturating_mul(I64F64::from_num(*multiplier))
+                .saturating_div(one);
+            if *multiplier < EMISSION_MULTIPLIER_ONE {
+                penalized_total = penalized_total.saturating_add(*share);
+            } else {
+                others_total = others_total.saturating_add(*share);
+            }
+        }
+
+        if others_total == I64F64::from_num(0) {
+            inplace_normalize_64(shares);
+            return;
+        }
+        let scale: I64F64 = I64F64::from_num(1)
+            .saturating_sub(penalized_total)
+            .saturating_div(others_total);
+        for (share, multiplier) in shares.iter_mut().zip(&multipliers) {
+            if *multiplier >= EMISSION_MULTIPLIER_ONE {
+                *share = share.saturating_mul(scale);
+            }
+        }
+    }
+}
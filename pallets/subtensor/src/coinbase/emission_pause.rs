@@ -0,0 +1,91 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    pub fn is_subnet_emission_paused(netuid: u16) -> bool {
+        SubnetEmissionPaused::<T>::get(netuid)
+    }
+    /// Pauses or resumes the block emission of a subnet. While paused, its epochs keep running,
+    /// on the emission it had pending before the pause.
+    pub fn set_subnet_emission_paused(netuid: u16, paused: bool) {
+        if paused {
+            SubnetEmissionPaused::<T>::insert(netuid, true);
+        } else {
+            SubnetEmissionPaused::<T>::remove(netuid);
+        }
+        Self::deposit_event(Event::SubnetEmissionPausedSet(netuid, paused));
+    }
+
+    pub fn get_withheld_emission(netuid: u16) -> u64 {
+        WithheldEmission::<T>::get(netuid)
+    }
+
+    /// Adds the emission withheld from `netuid` to its pending emission, which the next epoch of
+    /// the subnet distributes.
+    ///
+    /// # Errors
+    /// * `NoWithheldEmission` - No emission is withheld from the subnet.
+    pub fn release_withheld_emission(netuid: u16) -> DispatchResult {
+        let amount: u64 = WithheldEmission::<T>::take(netuid);
+        ensure!(amount > 0, Error::<T>::NoWithheldEmission);
+
+        PendingEmission::<T>::mutate(netuid, |pending| *pending = pending.saturating_add(amount));
+        Self::deposit_event(Event::WithheldEmissionReleased { netuid, amount });
+        Ok(())
+    }
+
+    /// Burns the emission withheld from `netuid`. The emission was never minted, so this only
+    /// drops it.
+    ///
+    /// # Errors
+    /// * `NoWithheldEmission` - No emission is withheld from the subnet.
+    pub fn burn_withheld_emission(netuid: u16) -> DispatchResult {
+        let amount: u64 = WithheldEmission::<T>::take(netuid);
+        ensure!(amount > 0, Error::<T>::NoWithheldEmission);
+
+        Self::deposit_event(Event::WithheldEmissionBurned { netuid, amount });
+        Ok(())
+    }
+
+    /// Returns the block emission each subnet accumulates, given the block `emissions` of the
+    /// subnets and which of them are `paused`.
+    ///
+    /// A paused subnet accumulates nothing. Its emission is redistributed to the other subnets in
+    /// proportion to their emission, the rounding remainder going to the subnet with the largest
+    /// emission, so the total is kept. When no other subnet has emission, nothing is
+    /// redistributed.
+    pub fn redistribute_paused_emission(emissions: &[u64], paused: &[bool]) -> Vec<u64> {
+        let mut withheld: u64 = 0;
+        let mut others_total: u64 = 0;
+        let mut accumulated: Vec<u64> = Vec::with_capacity(emissions.len());
+        for (emission, is_paused) in emissions.iter().zip(paused) {
+            if *is_paused {
+                withheld = withheld.saturating_add(*emission);
+                accumulated.push(0);
+            } else {
+                others_total = others_total.saturating_add(*emission);
+                accumulated.push(*emission);
+            }
+        }
+        if withheld == 0 || others_total == 0 {
+            return accumulated;
+        }
+
+        let mut remainder: u64 = withheld;
+        for (accumulated, emission) in accumulated.iter_mut().zip(emissions) {
+            // Paused subnets accumulate nothing, so they get no share either.
+            if *accumulated == 0 {
+                continue;
+            }
+            let share: u64 = u128::from(withheld)
+                .saturating_mul(u128::from(*emission))
+                .checked_div(u128::from(others_total))
+                .unwrap_or(0) as u64;
+            *accumulated = accumulated.saturating_add(share);
+            remainder = remainder.saturating_sub(share);
+        }
+        if let Some(largest) = accumulated.iter_mut().max() {
+            *largest = largest.saturating_add(remainder);
+        }
+        accumulated
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
 pub mod block_step;
+pub mod emission_multiplier;
+pub mod emission_pause;
 pub mod root;
 pub mod run_coinbase;
@@ -71,6 +71,17 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::SubnetLimitSet(limit));
     }
 
+    /// Returns whether a registration at the subnet limit prunes a subnet to make room.
+    pub fn get_subnet_limit_pruning() -> bool {
+        SubnetLimitPruning::<T>::get()
+    }
+
+    /// Sets whether a registration at the subnet limit prunes a subnet to make room, or fails.
+    pub fn set_subnet_limit_pruning(enabled: bool) {
+        SubnetLimitPruning::<T>::put(enabled);
+        Self::deposit_event(Event::SubnetLimitPruningSet(enabled));
+    }
+
     /// Fetches the total count of root network validators
     ///
     /// This function retrieves the total number of root network validators.
@@ -267,7 +278,7 @@ impl<T: Config> Pallet<T> {
 
         // --- 3. Iterate over stored weights and fill the matrix.
         for (uid_i, weights_i) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(
                 Self::get_root_netuid(),
             )
         {
@@ -292,6 +303,12 @@ impl<T: Config> Pallet<T> {
         weights
     }
 
+    /// Returns the number of blocks between two network registrations.
+    ///
+    pub fn get_network_rate_limit() -> u64 {
+        NetworkRateLimit::<T>::get()
+    }
+
     /// Sets the network rate limit and emit the `NetworkRateLimitSet` event
     ///
     pub fn set_network_rate_limit(limit: u64) {
@@ -446,6 +463,11 @@ impl<T: Config> Pallet<T> {
         inplace_normalize_64(&mut weighted_emission);
         log::debug!("Ei64:\n{:?}\n", &weighted_emission);
 
+        // -- 10.1 Scales the shares by the emission multipliers of the subnets.
+        let netuids: Vec<u16> = Self::get_all_subnet_netuids();
+        Self::apply_emission_multipliers(&netuids, &mut weighted_emission);
+        log::debug!("Ei64 multiplied:\n{:?}\n", &weighted_emission);
+
         // -- 11. Converts the normalized 64-bit fixed point rank values to u64 for the final emission calculation.
         let emission_as_tao: Vec<I64F64> = weighted_emission
             .iter()
@@ -457,7 +479,6 @@ impl<T: Config> Pallet<T> {
         log::debug!("Eu64:\n{:?}\n", &emission_u64);
 
         // --- 13. Set the emission values for each subnet directly.
-        let netuids: Vec<u16> = Self::get_all_subnet_netuids();
         log::debug!("netuids: {:?} values: {:?}", netuids, emission_u64);
 
         Self::set_emission_values(&netuids, emission_u64)
@@ -513,7 +534,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // --- 6. Create a network account for the user if it doesn't exist.
-        Self::create_account_if_non_existent(&coldkey, &hotkey);
+        Self::create_account_if_non_existent(&coldkey, &hotkey)?;
 
         // --- 7. Fetch the current size of the subnetwork.
         let current_num_root_validators: u16 = Self::get_num_root_validators();
@@ -576,7 +597,7 @@ impl<T: Config> Pallet<T> {
 
         // --- 14. Force all members on root to become a delegate.
         if !Self::hotkey_is_delegate(&hotkey) {
-            Self::delegate_hotkey(&hotkey, 11_796); // 18% cut defaulted.
+            Self::delegate_hotkey(&coldkey, &hotkey, 11_796); // 18% cut defaulted.
         }
 
         // --- 15. Update the registration counters for both the block and interval.
@@ -635,7 +656,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // --- 3. Create a network account for the user if it doesn't exist.
-        Self::create_account_if_non_existent(&coldkey, &hotkey);
+        Self::create_account_if_non_existent(&coldkey, &hotkey)?;
 
         // --- 4. Join the Senate if eligible.
         // Returns the replaced member, if any.
@@ -812,20 +833,19 @@ impl<T: Config> Pallet<T> {
             Error::<T>::WeightVecLengthIsLow
         );
 
-        // Max-upscale the weights.
-        let max_upscaled_weights: Vec<u16> = vec_u16_max_upscale_to_u16(&values);
-
-        // Ensure the weights are max weight limited
-        ensure!(
-            Self::max_weight_limited(netuid, neuron_uid, &uids, &max_upscaled_weights),
-            Error::<T>::MaxWeightExceeded
-        );
+        // Max-upscale the weights, ensuring they are max weight limited.
+        let max_weight_limit: u16 =
+            Self::get_max_weight_limit_for(netuid, neuron_uid, &uids, &values);
+        let max_upscaled_weights: Vec<u16> = normalize_weight_values(&values, max_weight_limit)
+            .ok_or(Error::<T>::MaxWeightExceeded)?;
 
         // Zip weights for sinking to storage map.
         let mut zipped_weights: Vec<(u16, u16)> = vec![];
         for (uid, val) in uids.iter().zip(max_upscaled_weights.iter()) {
             zipped_weights.push((*uid, *val))
         }
+        let zipped_weights =
+            WeightsRow::try_from(zipped_weights).map_err(|_| Error::<T>::TooManyWeights)?;
 
         // Set weights under netuid, uid double map entry.
         Weights::<T>::insert(netuid, neuron_uid, zipped_weights);
@@ -867,11 +887,17 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotSenateMember
         );
 
-        // --- 4. Detects first vote of the member in the motion
+        // --- 4. Ensure that the proposal is not tallied by stake.
+        ensure!(
+            !Self::is_stake_weighted_proposal(index),
+            Error::<T>::ProposalIsStakeWeighted
+        );
+
+        // --- 5. Detects first vote of the member in the motion
         let is_account_voting_first_time =
             T::TriumvirateInterface::add_vote(hotkey, proposal, index, approve)?;
 
-        // --- 5. Calculate extrinsic weight
+        // --- 6. Calculate extrinsic weight
         let members = T::SenateMembers::members();
         let member_count = members.len() as u32;
         let vote_weight = Weight::from_parts(20_528_275, 4980)
@@ -891,6 +917,130 @@ impl<T: Config> Pallet<T> {
             .into())
     }
 
+    /// Whether the senate proposal with this index is tallied by the stake of its voters.
+    pub fn is_stake_weighted_proposal(index: u32) -> bool {
+        Self::get_stake_weighted_voting_from().is_some_and(|from| index >= from)
+    }
+
+    /// Votes on a stake-weighted senate proposal. The stake of the hotkey is recorded with the
+    /// vote, and counted again from the current stake when the proposal is closed.
+    ///
+    /// A second vote of the same hotkey replaces its first one.
+    ///
+    /// # Errors
+    /// * `NonAssociatedColdKey` - The caller does not own the hotkey.
+    /// * `NotSenateMember` - The hotkey is not a member of the senate.
+    /// * `ProposalNotStakeWeighted` - The proposal predates stake-weighted voting.
+    /// * `ProposalIndexMismatch` - No open proposal has this hash and index.
+    pub fn do_vote_weighted(
+        origin: T::RuntimeOrigin,
+        hotkey: &T::AccountId,
+        proposal: T::Hash,
+        index: u32,
+        approve: bool,
+    ) -> DispatchResult {
+        // --- 1. Ensure that the caller has signed with their coldkey.
+        let coldkey = ensure_signed(origin)?;
+
+        // --- 2. Ensure that the calling coldkey owns the associated hotkey.
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // --- 3. Ensure that the calling hotkey is a member of the senate.
+        ensure!(
+            T::SenateMembers::is_member(hotkey),
+            Error::<T>::NotSenateMember
+        );
+
+        // --- 4. Ensure that the proposal is open and tallied by stake.
+        ensure!(
+            Self::is_stake_weighted_proposal(index),
+            Error::<T>::ProposalNotStakeWeighted
+        );
+        ensure!(
+            T::TriumvirateInterface::proposal_index(proposal) == Some(index),
+            Error::<T>::ProposalIndexMismatch
+        );
+
+        // --- 5. Record the vote, replacing any earlier one of the hotkey.
+        let stake = Self::get_total_stake_for_hotkey(hotkey);
+        StakeWeightedVotes::<T>::insert(proposal, hotkey, (approve, stake));
+
+        Self::deposit_event(Event::StakeWeightedVoteCast {
+            hotkey: hotkey.clone(),
+            proposal,
+            approve,
+            stake,
+        });
+        Ok(())
+    }
+
+    /// Closes a stake-weighted senate proposal.
+    ///
+    /// The votes are tallied by the current total stake of the voting hotkeys, so stake moved
+    /// between hotkeys after they voted is counted once. The proposal passes once the stake voting
+    /// for it, out of the total stake of the senate, reaches `StakeWeightedVoteThreshold`. A passed
+    /// proposal is dispatched with the `StakeApproved` origin of the collective. A proposal that
+    /// has not passed can only be closed once its voting period has ended. Votes of hotkeys that
+    /// have left the senate are not counted.
+    ///
+    /// # Errors
+    /// * `ProposalNotStakeWeighted` - The proposal predates stake-weighted voting.
+    /// * `ProposalIndexMismatch` - No open proposal has this hash and index.
+    pub fn do_close_weighted(
+        origin: T::RuntimeOrigin,
+        proposal: T::Hash,
+        index: u32,
+    ) -> DispatchResultWithPostInfo {
+        ensure_signed(origin)?;
+        ensure!(
+            Self::is_stake_weighted_proposal(index),
+            Error::<T>::ProposalNotStakeWeighted
+        );
+        ensure!(
+            T::TriumvirateInterface::proposal_index(proposal) == Some(index),
+            Error::<T>::ProposalIndexMismatch
+        );
+
+        let total_stake: u64 = T::SenateMembers::members()
+            .iter()
+            .map(Self::get_total_stake_for_hotkey)
+            .fold(0, |acc, stake| acc.saturating_add(stake));
+        let mut aye_stake: u64 = 0;
+        let mut ayes: u32 = 0;
+        let mut nays: u32 = 0;
+        for (hotkey, (approve, _)) in StakeWeightedVotes::<T>::iter_prefix(proposal) {
+            if !T::SenateMembers::is_member(&hotkey) {
+                continue;
+            }
+            if approve {
+                aye_stake = aye_stake.saturating_add(Self::get_total_stake_for_hotkey(&hotkey));
+                ayes = ayes.saturating_add(1);
+            } else {
+                nays = nays.saturating_add(1);
+            }
+        }
+
+        let threshold = Self::get_stake_weighted_vote_threshold() as u128;
+        let approved = total_stake > 0
+            && (aye_stake as u128).saturating_mul(u16::MAX as u128)
+                >= threshold.saturating_mul(total_stake as u128);
+
+        let post_info =
+            T::TriumvirateInterface::close_with_outcome(proposal, index, approved, ayes, nays)?;
+
+        let _ = StakeWeightedVotes::<T>::clear_prefix(proposal, u32::MAX, None);
+        Self::deposit_event(Event::StakeWeightedProposalClosed {
+            proposal,
+            aye_stake,
+            total_stake,
+            approved,
+        });
+        Ok(post_info)
+    }
+
     /// Facilitates user registration of a new subnetwork with subnet identity.
     ///
     /// # Args:
@@ -904,7 +1054,9 @@ impl<T: Config> Pallet<T> {
     /// * `SubnetIdentityRemoved(netuid)`: Emitted when the identity of a removed network is also deleted.
     ///
     /// # Raises:
-    /// * 'TxRateLimitExceeded': If the rate limit for network registration is exceeded.
+    /// * 'NetworkTxRateLimitExceeded': If the rate limit for network registration is exceeded.
+    /// * 'SubnetLimitReached': If the subnet limit is reached and pruning a subnet is disabled.
+    /// * 'AllNetworksInImmunity': If the subnet limit is reached and every subnet is immune.
     /// * 'NotEnoughBalanceToStake': If there isn't enough balance to stake for network registration.
     /// * 'BalanceWithdrawalError': If an error occurs during balance withdrawal for network registration.
     ///
@@ -915,19 +1067,64 @@ impl<T: Config> Pallet<T> {
         // --- 0. Ensure the caller is a signed user.
         let coldkey = ensure_signed(origin)?;
 
+        Self::do_add_network(coldkey, identity, false)
+    }
+
+    /// Facilitates root registration of a new subnetwork owned by `owner`.
+    ///
+    /// Root registrations are exempt from the network rate limit and lock no tokens. They do not
+    /// update `NetworkLastRegistered`, so the rate limit and lock cost of user registrations are
+    /// unaffected.
+    ///
+    /// # Args:
+    /// * `origin` (`T::RuntimeOrigin`): The calling origin. Must be root.
+    /// * `owner` (`T::AccountId`): The coldkey owning the new subnetwork.
+    /// * `identity` (`Option<SubnetIdentityOf>`): Optional identity to be associated with the new subnetwork.
+    ///
+    pub fn root_add_network(
+        origin: T::RuntimeOrigin,
+        owner: T::AccountId,
+        identity: Option<SubnetIdentityOf>,
+    ) -> dispatch::DispatchResult {
+        ensure_root(origin)?;
+
+        Self::do_add_network(owner, identity, true)
+    }
+
+    /// Returns the first block at which a user can register a new subnetwork.
+    pub fn get_network_registration_allowed_block() -> u64 {
+        Self::get_network_last_lock_block().saturating_add(NetworkRateLimit::<T>::get())
+    }
+
+    /// Registers a new subnetwork owned by `coldkey` under the lowest free netuid, pruning a
+    /// subnetwork if the limit is reached and `SubnetLimitPruning` is set. A netuid freed by a
+    /// dissolved or pruned subnetwork is reused, `remove_network` having cleared its storage.
+    ///
+    /// Unless `from_root` is set, the registration is rate limited and locks the network lock cost
+    /// from `coldkey`.
+    fn do_add_network(
+        coldkey: T::AccountId,
+        identity: Option<SubnetIdentityOf>,
+        from_root: bool,
+    ) -> dispatch::DispatchResult {
         // --- 1. Rate limit for network registrations.
         let current_block = Self::get_current_block_as_u64();
-        let last_lock_block = Self::get_network_last_lock_block();
-        ensure!(
-            current_block.saturating_sub(last_lock_block) >= NetworkRateLimit::<T>::get(),
-            Error::<T>::NetworkTxRateLimitExceeded
-        );
+        if !from_root {
+            ensure!(
+                current_block >= Self::get_network_registration_allowed_block(),
+                Error::<T>::NetworkTxRateLimitExceeded
+            );
+        }
 
         // --- 2. Calculate and lock the required tokens.
-        let lock_amount: u64 = Self::get_network_lock_cost();
+        let lock_amount: u64 = if from_root {
+            0
+        } else {
+            Self::get_network_lock_cost()
+        };
         log::debug!("network lock_amount: {:?}", lock_amount);
         ensure!(
-            Self::can_remove_balance_from_coldkey_account(&coldkey, lock_amount),
+            from_root || Self::can_remove_balance_from_coldkey_account(&coldkey, lock_amount),
             Error::<T>::NotEnoughBalanceToStake
         );
 
@@ -949,6 +1146,10 @@ impl<T: Config> Pallet<T> {
                     }
                 }
             } else {
+                ensure!(
+                    Self::get_subnet_limit_pruning(),
+                    Error::<T>::SubnetLimitReached
+                );
                 let netuid_to_prune = Self::get_subnet_to_prune();
                 ensure!(netuid_to_prune > 0, Error::<T>::AllNetworksInImmunity);
 
@@ -967,7 +1168,9 @@ impl<T: Config> Pallet<T> {
         // --- 5. Perform the lock operation.
         let actual_lock_amount = Self::remove_balance_from_coldkey_account(&coldkey, lock_amount)?;
         Self::set_subnet_locked_balance(netuid_to_register, actual_lock_amount);
-        Self::set_network_last_lock(actual_lock_amount);
+        if !from_root {
+            Self::set_network_last_lock(actual_lock_amount);
+        }
 
         // --- 6. Set initial and custom parameters for the network.
         Self::init_new_network(netuid_to_register, 360);
@@ -985,10 +1188,11 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 8. Set netuid storage.
-        let current_block_number: u64 = Self::get_current_block_as_u64();
-        NetworkLastRegistered::<T>::set(current_block_number);
-        NetworkRegisteredAt::<T>::insert(netuid_to_register, current_block_number);
-        SubnetOwner::<T>::insert(netuid_to_register, coldkey);
+        if !from_root {
+            NetworkLastRegistered::<T>::set(current_block);
+        }
+        NetworkRegisteredAt::<T>::insert(netuid_to_register, current_block);
+        Self::set_subnet_owner(netuid_to_register, &coldkey);
 
         // --- 9. Emit the NetworkAdded event.
         log::debug!(
@@ -1140,8 +1344,9 @@ impl<T: Config> Pallet<T> {
         let owner_coldkey: T::AccountId = SubnetOwner::<T>::get(netuid);
         let reserved_amount: u64 = Self::get_subnet_locked_balance(netuid);
 
-        // --- 2. Remove network count.
-        SubnetworkN::<T>::remove(netuid);
+        // --- 2. Remove network count, dropping its neurons from the global count.
+        let subnetwork_n: u32 = SubnetworkN::<T>::take(netuid).into();
+        TotalNeuronCount::<T>::mutate(|n| *n = n.saturating_sub(subnetwork_n));
 
         // --- 3. Remove network modality storage.
         NetworkModality::<T>::remove(netuid);
@@ -1156,21 +1361,39 @@ impl<T: Config> Pallet<T> {
         NetworkRegisteredAt::<T>::remove(netuid);
 
         // --- 7. Remove incentive mechanism memory.
+        for hotkey in Keys::<T>::iter_prefix_values(netuid) {
+            IsNetworkMember::<T>::remove(&hotkey, netuid);
+            Self::queue_inactive_hotkey(netuid, &hotkey);
+        }
         let _ = Uids::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Keys::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Bonds::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = RegistrationBurn::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = BlockAtRegistration::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = Axons::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = Prometheus::<T>::clear_prefix(netuid, u32::MAX, None);
+        let committers: Vec<T::AccountId> =
+            WeightCommitDeposits::<T>::iter_key_prefix(netuid).collect();
+        for hotkey in committers.iter() {
+            Self::refund_weight_commit_deposit(netuid, hotkey);
+        }
+        let _ = WeightCommits::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = PendingChildKeys::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = EpochHistory::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = EpochCommitment::<T>::clear_prefix(netuid, u32::MAX, None);
+        T::OnNeuronRemoved::on_subnet_removed(netuid);
 
         // --- 8. Removes the weights for this subnet (do not remove).
         let _ = Weights::<T>::clear_prefix(netuid, u32::MAX, None);
 
         // --- 9. Iterate over stored weights and fill the matrix.
         for (uid_i, weights_i) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(
                 Self::get_root_netuid(),
             )
         {
             // Create a new vector to hold modified weights.
-            let mut modified_weights: Vec<(u16, u16)> = weights_i.clone();
+            let mut modified_weights: WeightsRow = weights_i.clone();
             // Iterate over each weight entry to potentially update it.
             for (subnet_id, weight) in modified_weights.iter_mut() {
                 if subnet_id == &netuid {
@@ -1190,28 +1413,87 @@ impl<T: Config> Pallet<T> {
         Consensus::<T>::remove(netuid);
         Dividends::<T>::remove(netuid);
         PruningScores::<T>::remove(netuid);
+        CustomPruningScores::<T>::remove(netuid);
         LastUpdate::<T>::remove(netuid);
         ValidatorPermit::<T>::remove(netuid);
         ValidatorTrust::<T>::remove(netuid);
-
-        // --- 11. Erase network parameters.
+        LoadedEmission::<T>::remove(netuid);
+        PendingEmission::<T>::remove(netuid);
+        BlocksSinceLastStep::<T>::remove(netuid);
+        LastMechansimStepBlock::<T>::remove(netuid);
+        NeuronsToPruneAtNextEpoch::<T>::remove(netuid);
+
+        // --- 11. Erase network parameters, so that a subnet registered under the same netuid
+        // starts from the defaults.
         Tempo::<T>::remove(netuid);
         Kappa::<T>::remove(netuid);
+        ParamGovernanceEnabled::<T>::remove(netuid);
+        RecycleRegistrationBurn::<T>::remove(netuid);
+        RegistrationBurnOwnerShare::<T>::remove(netuid);
+        MinAxonVersion::<T>::remove(netuid);
+        EmptyEpochUniformIncentive::<T>::remove(netuid);
+        PruningScoreSources::<T>::remove(netuid);
         Difficulty::<T>::remove(netuid);
+        Burn::<T>::remove(netuid);
         MaxAllowedUids::<T>::remove(netuid);
         ImmunityPeriod::<T>::remove(netuid);
         ActivityCutoff::<T>::remove(netuid);
         EmissionValues::<T>::remove(netuid);
+        EmissionMultiplier::<T>::remove(netuid);
+        SubnetEmissionPaused::<T>::remove(netuid);
+        WithheldEmission::<T>::remove(netuid);
+        ZeroEmissionSince::<T>::remove(netuid);
+        SubnetWarning::<T>::remove(netuid);
+        EpochHistoryDepth::<T>::remove(netuid);
+        ValidatorHealthDropThreshold::<T>::remove(netuid);
+        EpochHistoryNextSlot::<T>::remove(netuid);
+        EpochCommitmentNextIndex::<T>::remove(netuid);
+        RegistrationAdjustmentHistory::<T>::remove(netuid);
         MaxWeightsLimit::<T>::remove(netuid);
+        MaxWeightsLimitLen::<T>::remove(netuid);
         MinAllowedWeights::<T>::remove(netuid);
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
+        RegistrationsThisBlock::<T>::remove(netuid);
+        MaxRegistrationsPerBlock::<T>::remove(netuid);
+        NetworkRegistrationAllowed::<T>::remove(netuid);
+        NetworkPowRegistrationAllowed::<T>::remove(netuid);
+        TargetRegistrationsPerInterval::<T>::remove(netuid);
+        AdjustmentInterval::<T>::remove(netuid);
+        AdjustmentAlpha::<T>::remove(netuid);
+        LastAdjustmentBlock::<T>::remove(netuid);
+        RAORecycledForRegistration::<T>::remove(netuid);
+        MinBurn::<T>::remove(netuid);
+        MaxBurn::<T>::remove(netuid);
+        MinDifficulty::<T>::remove(netuid);
+        MaxDifficulty::<T>::remove(netuid);
+        ServingRateLimit::<T>::remove(netuid);
+        Rho::<T>::remove(netuid);
+        WeightsVersionKey::<T>::remove(netuid);
+        WeightsSetRateLimit::<T>::remove(netuid);
+        MaxAllowedValidators::<T>::remove(netuid);
+        BondsMovingAverage::<T>::remove(netuid);
+        ValidatorPruneLen::<T>::remove(netuid);
+        ScalingLawPower::<T>::remove(netuid);
+        WeightCommitRevealInterval::<T>::remove(netuid);
+        CommitRevealWeightsEnabled::<T>::remove(netuid);
+        LiquidAlphaOn::<T>::remove(netuid);
+        AlphaValues::<T>::remove(netuid);
+        TransferStakeEnabled::<T>::remove(netuid);
+        RequireCommitmentForWeights::<T>::remove(netuid);
+        RejectUncommittedWeights::<T>::remove(netuid);
+        DisallowSelfWeightByColdkey::<T>::remove(netuid);
+        RejectSelfWeightByColdkey::<T>::remove(netuid);
+        InviteOnlyRegistration::<T>::remove(netuid);
+        let _ = UsedInvites::<T>::clear_prefix(netuid, u32::MAX, None);
+        NetworkMaxStake::<T>::remove(netuid);
+        SubnetStakeThreshold::<T>::remove(netuid);
 
         // --- 12. Add the balance back to the owner.
-        Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount);
-        Self::set_subnet_locked_balance(netuid, 0);
-        SubnetOwner::<T>::remove(netuid);
+        Self::add_balance_to_coldkey_account_or_burn(&owner_coldkey, reserved_amount);
+        SubnetLocked::<T>::remove(netuid);
+        Self::remove_subnet_owner(netuid);
 
         // --- 13. Remove subnet identity if it exists.
         if SubnetIdentities::<T>::contains_key(netuid) {
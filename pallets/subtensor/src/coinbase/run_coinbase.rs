@@ -1,22 +1,23 @@
 use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
 use substrate_fixed::types::I64F64;
 use substrate_fixed::types::I96F32;
 
 impl<T: Config> Pallet<T> {
-    /// The `coinbase` function performs a four-part emission distribution process involving
-    /// subnets, epochs, hotkeys, and nominators.
-    // It is divided into several steps, each handling a specific part of the distribution:
+    // The coinbase performs a four-part emission distribution process involving subnets, epochs,
+    // hotkeys, and nominators. Each part is a stage of the block step pipeline:
 
-    // Step 1: Compute the block-wise emission for each subnet.
+    // Step 1 (`run_root_epoch`): Compute the block-wise emission for each subnet.
     // This involves calculating how much (TAO) should be emitted into each subnet using the
     // root epoch function.
 
-    // Step 2: Accumulate the subnet block emission.
+    // Step 2 (`accumulate_subnet_emission`): Accumulate the subnet block emission.
     // After calculating the block-wise emission, these values are accumulated to keep track
     // of how much each subnet should emit before the next distribution phase. This accumulation
     // is a running total that gets updated each block.
 
-    // Step 3: Distribute the accumulated emissions through epochs.
+    // Step 3 (`run_subnet_epochs`): Distribute the accumulated emissions through epochs.
     // Subnets periodically distribute their accumulated emissions to hotkeys (active validators/miners)
     // in the network on a `tempo` --- the time between epochs. This step runs Yuma consensus to
     // determine how emissions are split among hotkeys based on their contributions and roles.
@@ -24,17 +25,27 @@ impl<T: Config> Pallet<T> {
     // The function splits the rewards for a hotkey amongst itself and its `parents`. The parents are
     // the hotkeys that are delegating their stake to the hotkey.
 
-    // Step 4: Further distribute emissions from hotkeys to nominators.
-    // Finally, the emissions received by hotkeys are further distributed to their nominators,
-    // who are stakeholders that support the hotkeys.
-    pub fn run_coinbase() {
-        // --- 0. Get current block.
-        let current_block: u64 = Self::get_current_block_as_u64();
-        log::debug!("Current block: {:?}", current_block);
+    // Step 4 (`schedule_hotkey_drains`, `drain_scheduled_hotkeys`): Further distribute emissions
+    // from hotkeys to nominators. The hotkeys due on a block are queued by the block step, which
+    // drains a bounded number of stake entries per block. `on_idle` drains further as far as the
+    // remaining block weight allows.
 
-        // --- 1. Get all netuids.
-        let subnets: Vec<u16> = Self::get_all_subnet_netuids();
-        log::debug!("All subnet netuids: {:?}", subnets);
+    /// Runs the root epoch, which sets the block emission of each subnet on root tempo blocks.
+    ///
+    /// # Arguments
+    /// * `current_block` - The current block number.
+    ///
+    /// # Returns
+    /// * `Weight` - The weight consumed by the stage.
+    pub fn run_root_epoch(current_block: u64) -> Weight {
+        // --- 1. Check the root tempo.
+        let root_netuid: u16 = Self::get_root_netuid();
+        let mut weight: Weight = T::DbWeight::get().reads(1);
+        if Self::blocks_until_next_epoch(root_netuid, Self::get_tempo(root_netuid), current_block)
+            != 0
+        {
+            return weight;
+        }
 
         // --- 2. Run the root epoch function which computes the block emission for each subnet.
         // coinbase --> root() --> subnet_block_emission
@@ -45,19 +56,72 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        // --- 3. Drain the subnet block emission and accumulate it as subnet emission, which increases until the tempo is reached in #4.
-        // subnet_blockwise_emission -> subnet_pending_emission
-        for netuid in subnets.clone().iter() {
-            // --- 3.1 Get the network's block-wise emission amount.
-            // This value is newly minted TAO which has not reached staking accounts yet.
-            let subnet_blockwise_emission: u64 = EmissionValues::<T>::get(*netuid);
+        // --- 3. Account for the root keys, their stake and weights, and the subnet emission values
+        // and multipliers.
+        let n: u64 = Self::get_num_root_validators() as u64;
+        let k: u64 = Self::get_num_subnets() as u64;
+        weight.saturating_accrue(
+            T::DbWeight::get().reads_writes(
+                n.saturating_mul(3)
+                    .saturating_add(k.saturating_mul(3))
+                    .saturating_add(8),
+                k,
+            ),
+        );
+        weight
+    }
+
+    /// Accumulates the block emission of each subnet as its pending emission, which increases
+    /// until the tempo of the subnet is reached.
+    /// subnet_blockwise_emission -> subnet_pending_emission
+    ///
+    /// The block emission of a paused subnet is added to its withheld emission instead, and
+    /// redistributed to the other subnets.
+    ///
+    /// # Returns
+    /// * `Weight` - The weight consumed by the stage.
+    pub fn accumulate_subnet_emission() -> Weight {
+        // --- 1. Get all netuids.
+        let subnets: Vec<u16> = Self::get_all_subnet_netuids();
+        log::debug!("All subnet netuids: {:?}", subnets);
+        let mut weight: Weight = T::DbWeight::get().reads(subnets.len() as u64);
+
+        // --- 2. Get the network's block-wise emission amounts.
+        // These values are newly minted TAO which has not reached staking accounts yet.
+        let emissions: Vec<u64> = subnets
+            .iter()
+            .map(|netuid| EmissionValues::<T>::get(*netuid))
+            .collect();
+        let paused: Vec<bool> = subnets
+            .iter()
+            .map(|netuid| Self::is_subnet_emission_paused(*netuid))
+            .collect();
+        let accumulated: Vec<u64> = Self::redistribute_paused_emission(&emissions, &paused);
+
+        for (((netuid, emission), is_paused), subnet_blockwise_emission) in
+            subnets.iter().zip(emissions).zip(paused).zip(accumulated)
+        {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 1));
+
+            // --- 3. Withhold the emission of a paused subnet.
+            if is_paused {
+                WithheldEmission::<T>::mutate(*netuid, |withheld| {
+                    *withheld = withheld.saturating_add(emission)
+                });
+                log::debug!(
+                    "Withheld subnet emission for paused netuid {:?}: {:?}",
+                    *netuid,
+                    emission
+                );
+                continue;
+            }
             log::debug!(
                 "Subnet block-wise emission for netuid {:?}: {:?}",
                 *netuid,
                 subnet_blockwise_emission
             );
 
-            // --- 3.2 Accumulate the subnet emission on the subnet.
+            // --- 4. Accumulate the subnet emission on the subnet.
             PendingEmission::<T>::mutate(*netuid, |subnet_emission| {
                 *subnet_emission = subnet_emission.saturating_add(subnet_blockwise_emission);
                 log::debug!(
@@ -67,15 +131,52 @@ impl<T: Config> Pallet<T> {
                 );
             });
         }
+        weight
+    }
+
+    /// Drains the accumulated subnet emissions of the subnets whose tempo is reached and passes
+    /// them through the epoch. Before accumulating on the hotkeys the emission is redistributed
+    /// towards hotkey parents.
+    /// subnet_emission --> epoch() --> hotkey_emission --> (hotkey + parent hotkeys)
+    ///
+    /// # Arguments
+    /// * `current_block` - The current block number.
+    ///
+    /// # Returns
+    /// * `Weight` - The weight consumed by the stage.
+    pub fn run_subnet_epochs(current_block: u64) -> Weight {
+        let subnets: Vec<u16> = Self::get_all_subnet_netuids();
+        let mut weight: Weight = T::DbWeight::get().reads(subnets.len() as u64);
 
-        // --- 4. Drain the accumulated subnet emissions, pass them through the epoch().
-        // Before accumulating on the hotkeys the function redistributes the emission towards hotkey parents.
-        // subnet_emission --> epoch() --> hotkey_emission --> (hotkey + parent hotkeys)
-        for netuid in subnets.clone().iter() {
-            // --- 4.1 Check to see if the subnet should run its epoch.
+        for netuid in subnets.iter() {
+            // --- 1. Check to see if the subnet should run its epoch.
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
             if Self::should_run_epoch(*netuid, current_block) {
-                // --- 4.2 Drain the subnet emission.
+                // --- 1.1 Without weights or active stake there is nothing to distribute on, so the
+                // subnet emission stays pending until the first epoch that has.
+                weight.saturating_accrue(Self::empty_epoch_check_weight(Self::get_subnetwork_n(
+                    *netuid,
+                )));
+                if Self::epoch_is_empty(*netuid) {
+                    log::debug!(
+                        "Skipped the epoch of netuid {:?} without weights or active stake, pending emission retained: {:?}",
+                        *netuid,
+                        PendingEmission::<T>::get(*netuid)
+                    );
+                    Self::write_empty_epoch(*netuid);
+                    Self::set_blocks_since_last_step(*netuid, 0);
+                    Self::set_last_mechanism_step_block(*netuid, current_block);
+                    weight.saturating_accrue(Self::record_epoch_history(*netuid, current_block, 0));
+                    weight.saturating_accrue(Self::record_epoch_commitment(*netuid, current_block));
+                    weight.saturating_accrue(Self::empty_epoch_weight(Self::get_subnetwork_n(
+                        *netuid,
+                    )));
+                    continue;
+                }
+
+                // --- 2. Drain the subnet emission.
                 let mut subnet_emission: u64 = PendingEmission::<T>::get(*netuid);
+                let drained_emission: u64 = subnet_emission;
                 PendingEmission::<T>::insert(*netuid, 0);
                 log::debug!(
                     "Drained subnet emission for netuid {:?}: {:?}",
@@ -83,92 +184,317 @@ impl<T: Config> Pallet<T> {
                     subnet_emission
                 );
 
-                // --- 4.3 Set last step counter.
+                // --- 3. Set last step counter.
                 Self::set_blocks_since_last_step(*netuid, 0);
                 Self::set_last_mechanism_step_block(*netuid, current_block);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
 
-                // --- 4.4 Distribute owner take.
+                // --- 4. Distribute owner take.
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
                 if SubnetOwner::<T>::contains_key(netuid) {
                     // Does the subnet have an owner?
 
-                    // --- 4.4.1 Compute the subnet owner cut.
+                    // --- 4.1 Compute the subnet owner cut.
                     let owner_cut: I96F32 = I96F32::from_num(subnet_emission).saturating_mul(
                         I96F32::from_num(Self::get_subnet_owner_cut())
                             .saturating_div(I96F32::from_num(u16::MAX)),
                     );
 
-                    // --- 4.4.2 Remove the cut from the subnet emission
+                    // --- 4.2 Remove the cut from the subnet emission
                     subnet_emission = subnet_emission.saturating_sub(owner_cut.to_num::<u64>());
 
-                    // --- 4.4.3 Add the cut to the balance of the owner
-                    Self::add_balance_to_coldkey_account(
+                    // --- 4.3 Add the cut to the balance of the owner
+                    let owner_cut_credited: u64 = Self::add_balance_to_coldkey_account(
                         &Self::get_subnet_owner(*netuid),
                         owner_cut.to_num::<u64>(),
                     );
 
-                    // --- 4.4.4 Increase total issuance on the chain.
-                    Self::coinbase(owner_cut.to_num::<u64>());
+                    // --- 4.4 Increase total issuance on the chain by what was actually credited.
+                    Self::coinbase(owner_cut_credited);
+                    weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 2));
                 }
 
-                // 4.3 Pass emission through epoch() --> hotkey emission.
+                // --- 5. Pass emission through epoch() --> hotkey emission.
                 let hotkey_emission: Vec<(T::AccountId, u64, u64)> =
-                    Self::epoch(*netuid, subnet_emission);
+                    Self::run_epoch(*netuid, subnet_emission);
                 log::debug!(
                     "Hotkey emission results for netuid {:?}: {:?}",
                     *netuid,
                     hotkey_emission
                 );
+                weight.saturating_accrue(Self::epoch_weight(Self::get_subnetwork_n(*netuid)));
 
-                // 4.4 Accumulate the tuples on hotkeys:
+                // --- 6. Accumulate the tuples on hotkeys:
+                let mut credited_emission: Vec<(T::AccountId, u64)> = Vec::new();
                 for (hotkey, mining_emission, validator_emission) in hotkey_emission {
-                    // 4.5 Accumulate the emission on the hotkey and parent hotkeys.
-                    Self::accumulate_hotkey_emission(
+                    // --- 6.1 Accumulate the emission on the hotkey and parent hotkeys.
+                    credited_emission.extend(Self::accumulate_hotkey_emission(
                         &hotkey,
                         *netuid,
                         validator_emission, // Amount received from validating
                         mining_emission,    // Amount recieved from mining.
-                    );
+                    ));
+                    weight.saturating_accrue(T::DbWeight::get().reads(4));
                     log::debug!("Accumulated emissions on hotkey {:?} for netuid {:?}: mining {:?}, validator {:?}", hotkey, *netuid, mining_emission, validator_emission);
                 }
+
+                // --- 7. Record the emission credited to each hotkey for this epoch.
+                Self::record_hotkey_emission(*netuid, &credited_emission);
+                weight.saturating_accrue(
+                    T::DbWeight::get()
+                        .reads_writes(2, 3)
+                        .saturating_mul(credited_emission.len() as u64),
+                );
+
+                // --- 8. Keep the summary of the epoch in the epoch history of the subnet.
+                weight.saturating_accrue(Self::record_epoch_history(
+                    *netuid,
+                    current_block,
+                    drained_emission,
+                ));
+
+                // --- 9. Commit to the outcome of the epoch for off-chain verifiers.
+                weight.saturating_accrue(Self::record_epoch_commitment(*netuid, current_block));
             } else {
                 // No epoch, increase blocks since last step and continue
                 Self::set_blocks_since_last_step(
                     *netuid,
                     Self::get_blocks_since_last_step(*netuid).saturating_add(1),
                 );
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
                 log::debug!("Tempo not reached for subnet: {:?}", *netuid);
             }
         }
+        weight
+    }
 
-        // --- 5. Drain the accumulated hotkey emissions through to the nominators.
-        // The hotkey takes a proportion of the emission, the remainder is drained through to the nominators.
-        // We keep track of the last stake increase event for accounting purposes.
-        // hotkeys --> nominators.
+    /// Queues the hotkeys whose accumulated emission is due to be drained on this block.
+    /// The queue is drained through to the nominators by `drain_scheduled_hotkeys`. A hotkey that
+    /// does not fit in a full queue keeps its emission pending until its next drain block.
+    ///
+    /// # Arguments
+    /// * `current_block` - The current block number.
+    ///
+    /// # Returns
+    /// * `Weight` - The weight consumed by the stage.
+    pub fn schedule_hotkey_drains(current_block: u64) -> Weight {
         let emission_tempo: u64 = Self::get_hotkey_emission_tempo();
+        let mut queue = HotkeyDrainQueue::<T>::get();
+        let queued: usize = queue.len();
+        let mut weight: Weight = T::DbWeight::get().reads(2);
         for (hotkey, hotkey_emission) in PendingdHotkeyEmission::<T>::iter() {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
             // Check for zeros.
             // remove zero values.
             if hotkey_emission == 0 {
                 continue;
             }
 
-            // --- 5.1 Check if we should drain the hotkey emission on this block.
-            if Self::should_drain_hotkey(&hotkey, current_block, emission_tempo) {
-                // --- 5.2 Drain the hotkey emission and distribute it to nominators.
-                let total_new_tao: u64 =
-                    Self::drain_hotkey_emission(&hotkey, hotkey_emission, current_block);
-                log::debug!(
-                    "Drained hotkey emission for hotkey {:?} on block {:?}: {:?}",
-                    hotkey,
-                    current_block,
-                    hotkey_emission
-                );
+            // Check if we should drain the hotkey emission on this block.
+            if Self::should_drain_hotkey(&hotkey, current_block, emission_tempo)
+                && !queue.contains(&hotkey)
+                && queue.try_push(hotkey).is_err()
+            {
+                break;
+            }
+        }
+        if queue.len() != queued {
+            HotkeyDrainQueue::<T>::put(queue);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight
+    }
+
+    /// Drains the queued hotkey emissions through to the nominators, in queue order, within
+    /// `remaining_weight`. The stake entries of the hotkey at the head of the queue are visited
+    /// in pages that resume from `HotkeyDrainCursor`, so a hotkey with more stakers than a block
+    /// allows is drained over several blocks instead of holding up the hotkeys queued behind it.
+    /// The hotkey takes a proportion of the emission, the remainder is drained through to the nominators.
+    /// hotkeys --> nominators.
+    ///
+    /// # Arguments
+    /// * `remaining_weight` - The weight available for draining.
+    ///
+    /// # Returns
+    /// * `Weight` - The weight consumed by the drains.
+    pub fn drain_scheduled_hotkeys(remaining_weight: Weight) -> Weight {
+        // Leave room to read and write back the queue and the cursor, to read the owner of the
+        // hotkey being drained, and for at least one visit.
+        let mut weight: Weight = T::DbWeight::get().reads_writes(3, 2);
+        let start_weight: Weight = Self::hotkey_drain_start_weight();
+        let visit_weight: Weight = Self::hotkey_drain_visit_weight();
+        if weight.saturating_add(visit_weight).any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+        let mut queue: Vec<T::AccountId> = HotkeyDrainQueue::<T>::get().into_inner();
+        if queue.is_empty() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let mut progress: Option<HotkeyDrainProgress> = HotkeyDrainCursor::<T>::get();
+        let mut drained: usize = 0;
+        while let Some(hotkey) = queue.get(drained) {
+            // --- 1. Resume the drain in progress, or start draining the next hotkey.
+            let mut state: HotkeyDrainProgress = match progress.take() {
+                Some(state) => state,
+                None => {
+                    if weight
+                        .saturating_add(start_weight)
+                        .saturating_add(visit_weight)
+                        .any_gt(remaining_weight)
+                    {
+                        break;
+                    }
+                    weight.saturating_accrue(start_weight);
+                    let hotkey_emission: u64 = PendingdHotkeyEmission::<T>::get(hotkey);
+                    // Increase total issuance on the chain by the emission about to be paid.
+                    Self::coinbase(hotkey_emission);
+                    Self::start_hotkey_drain(hotkey, hotkey_emission, current_block)
+                }
+            };
 
-                // --- 5.3 Increase total issuance on the chain.
-                Self::coinbase(total_new_tao);
-                log::debug!("Increased total issuance by {:?}", total_new_tao);
+            // --- 2. Visit as many stake entries as the remaining weight allows.
+            let (visits_weight, done) = Self::continue_hotkey_drain(
+                hotkey,
+                &mut state,
+                remaining_weight.saturating_sub(weight),
+            );
+            weight.saturating_accrue(visits_weight);
+            if !done {
+                progress = Some(state);
+                break;
             }
+
+            // --- 3. Pay the owner what is left once every nominator is paid.
+            log::debug!(
+                "Drained hotkey emission for hotkey {:?} on block {:?}: {:?}",
+                hotkey,
+                current_block,
+                state.emission
+            );
+            Self::finish_hotkey_drain(hotkey, state);
+            drained = drained.saturating_add(1);
         }
+
+        if drained > 0 {
+            queue.drain(..drained);
+            HotkeyDrainQueue::<T>::put(BoundedVec::truncate_from(queue));
+        }
+        match progress {
+            Some(state) => HotkeyDrainCursor::<T>::put(state),
+            None => HotkeyDrainCursor::<T>::kill(),
+        }
+        weight
+    }
+
+    /// Writes the summary of the epoch that just ran on a subnet to the next slot of its epoch
+    /// history, overwriting the oldest summary once the history is full. Nothing is read or
+    /// written beyond the depth while the history is disabled.
+    ///
+    /// # Arguments
+    /// * `netuid` - The subnet the epoch ran on.
+    /// * `current_block` - The block the epoch ran in.
+    /// * `total_emission` - The emission drained into the epoch.
+    ///
+    /// # Returns
+    /// * `Weight` - The weight consumed by the write.
+    pub fn record_epoch_history(netuid: u16, current_block: u64, total_emission: u64) -> Weight {
+        let depth: u16 = EpochHistoryDepth::<T>::get(netuid);
+        if depth == 0 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut top_incentives: Vec<(u16, u16)> = Self::get_incentive(netuid)
+            .into_iter()
+            .enumerate()
+            .map(|(uid, incentive)| (uid as u16, incentive))
+            .collect();
+        top_incentives.sort_by(|(uid_a, a), (uid_b, b)| b.cmp(a).then(uid_a.cmp(uid_b)));
+        top_incentives.truncate(EPOCH_SUMMARY_TOP_INCENTIVES);
+        let validator_count: u16 = Self::get_validator_permit(netuid)
+            .into_iter()
+            .filter(|permit| *permit)
+            .count() as u16;
+
+        let slot: u16 = EpochHistoryNextSlot::<T>::get(netuid)
+            .checked_rem(depth)
+            .unwrap_or(0);
+        EpochHistory::<T>::insert(
+            netuid,
+            slot,
+            EpochSummary {
+                block: current_block,
+                total_emission,
+                top_incentives,
+                validator_count,
+            },
+        );
+        EpochHistoryNextSlot::<T>::insert(
+            netuid,
+            slot.saturating_add(1).checked_rem(depth).unwrap_or(0),
+        );
+        T::DbWeight::get().reads_writes(4, 2)
+    }
+
+    /// Returns the weight of draining the emission of a hotkey with `stakers` stake entries in
+    /// one go, each entry being visited once per pass.
+    pub fn hotkey_drain_weight(stakers: u64) -> Weight {
+        Self::hotkey_drain_start_weight().saturating_add(
+            Self::hotkey_drain_visit_weight().saturating_mul(stakers.saturating_mul(2)),
+        )
+    }
+
+    /// Returns the weight of starting and finishing the drain of a hotkey, apart from visiting
+    /// its stake entries.
+    pub fn hotkey_drain_start_weight() -> Weight {
+        // The pending emission, issuance, drain blocks, owner, take and pending take, and paying
+        // the owner.
+        T::DbWeight::get()
+            .reads_writes(8, 5)
+            .saturating_add(Self::hotkey_drain_visit_weight())
+    }
+
+    /// Returns the weight of visiting one stake entry of a hotkey being drained.
+    pub fn hotkey_drain_visit_weight() -> Weight {
+        // Reading the stake and last increase, and paying the share: reading the payout mode and
+        // increasing the stake or balance.
+        T::DbWeight::get().reads_writes(10, 7)
+    }
+
+    /// Returns the weight the block step spends on hotkey drains on every block.
+    pub fn hotkey_drain_block_budget() -> Weight {
+        T::DbWeight::get()
+            .reads_writes(3, 2)
+            .saturating_add(Self::hotkey_drain_start_weight())
+            .saturating_add(
+                Self::hotkey_drain_visit_weight().saturating_mul(HOTKEY_DRAIN_VISITS_PER_BLOCK),
+            )
+    }
+
+    /// Returns the weight of running the epoch on a subnet with `n` neurons.
+    pub fn epoch_weight(n: u16) -> Weight {
+        // The per-subnet hyperparameters and consensus vectors, and per neuron its key, stake,
+        // registration block, weights row (read twice to measure density) and bonds row, and the
+        // written bonds row.
+        let n: u64 = n as u64;
+        T::DbWeight::get()
+            .reads_writes(n.saturating_mul(6).saturating_add(24), n.saturating_add(12))
+    }
+
+    /// Returns the weight of checking whether the epoch of a subnet with `n` neurons is empty.
+    pub fn empty_epoch_check_weight(n: u16) -> Weight {
+        // The activity cutoff and last update vector, and per neuron its weights row, key and stake.
+        let n: u64 = n as u64;
+        T::DbWeight::get().reads(n.saturating_mul(3).saturating_add(2))
+    }
+
+    /// Returns the weight of writing the outcome of an empty epoch on a subnet with `n` neurons.
+    pub fn empty_epoch_weight(n: u16) -> Weight {
+        // The subnet size, activity cutoff, last update vector and max validators, per neuron its
+        // key and stake, and the written consensus vectors.
+        let n: u64 = n as u64;
+        T::DbWeight::get().reads_writes(n.saturating_mul(2).saturating_add(4), 11)
     }
 
     /// Accumulates the mining and validator emissions on a hotkey and distributes the validator emission among its parents.
@@ -184,12 +510,17 @@ impl<T: Config> Pallet<T> {
     /// * `mining_emission` - The amount of mining emission allocated to the hotkey.
     /// * `validator_emission` - The amount of validator emission allocated to the hotkey.
     ///
+    /// # Returns
+    /// * The amounts credited to the pending emission of the hotkey and each of its parents.
+    ///
     pub fn accumulate_hotkey_emission(
         hotkey: &T::AccountId,
         netuid: u16,
         validating_emission: u64,
         mining_emission: u64,
-    ) {
+    ) -> Vec<(T::AccountId, u64)> {
+        let mut credited: Vec<(T::AccountId, u64)> = Vec::new();
+
         // --- 1. First, calculate the hotkey's share of the emission.
         let take_proportion: I64F64 = I64F64::from_num(Self::get_childkey_take(hotkey, netuid))
             .saturating_div(I64F64::from_num(u16::MAX));
@@ -227,9 +558,10 @@ impl<T: Config> Pallet<T> {
                     .to_num::<u64>();
 
                 // --- 5.5. Accumulate emissions for the parent hotkey.
-                PendingdHotkeyEmission::<T>::mutate(parent, |parent_accumulated| {
+                PendingdHotkeyEmission::<T>::mutate(&parent, |parent_accumulated| {
                     *parent_accumulated = parent_accumulated.saturating_add(parent_emission_take)
                 });
+                credited.push((parent, parent_emission_take));
 
                 // --- 5.6. Subtract the parent's share from the remaining emission for this hotkey.
                 remaining_emission = remaining_emission.saturating_sub(parent_emission_take);
@@ -237,13 +569,34 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 6. Add the remaining emission plus the hotkey's initial take to the pending emission for this hotkey.
+        let hotkey_emission: u64 = remaining_emission
+            .saturating_add(hotkey_take)
+            .saturating_add(mining_emission);
         PendingdHotkeyEmission::<T>::mutate(hotkey, |hotkey_pending| {
-            *hotkey_pending = hotkey_pending.saturating_add(
-                remaining_emission
-                    .saturating_add(hotkey_take)
-                    .saturating_add(mining_emission),
-            )
+            *hotkey_pending = hotkey_pending.saturating_add(hotkey_emission)
         });
+        credited.push((hotkey.clone(), hotkey_emission));
+
+        credited
+    }
+
+    /// Records the emission credited to hotkeys on a subnet during an epoch.
+    ///
+    /// `EmissionLastTempo` is overwritten with the sum credited to each hotkey in this epoch and
+    /// `EmissionLifetime` is increased by the same amount. Both are gross of the delegate take.
+    pub fn record_hotkey_emission(netuid: u16, credited: &[(T::AccountId, u64)]) {
+        // Reset first so a hotkey credited more than once in the epoch reports the sum.
+        for (hotkey, _) in credited {
+            EmissionLastTempo::<T>::remove(hotkey, netuid);
+        }
+        for (hotkey, emission) in credited {
+            EmissionLastTempo::<T>::mutate(hotkey, netuid, |last| {
+                *last = last.saturating_add(*emission)
+            });
+            EmissionLifetime::<T>::mutate(hotkey, netuid, |lifetime| {
+                *lifetime = lifetime.saturating_add(*emission)
+            });
+        }
     }
 
     //. --- 4. Drains the accumulated hotkey emission through to the nominators. The hotkey takes a proportion of the emission.
@@ -252,84 +605,177 @@ impl<T: Config> Pallet<T> {
     /// hotkeys --> nominators.
     ///
     /// 1. It resets the accumulated emissions for the hotkey to zero.
-    /// 4. It calculates the total stake for the hotkey and determines the hotkey's own take from the emissions based on its delegation status.
-    /// 5. It then calculates the remaining emissions after the hotkey's take and distributes this remaining amount proportionally among the hotkey's nominators.
-    /// 6. Each nominator's share of the emissions is added to their stake, but only if their stake was not manually increased since the last emission drain.
-    /// 7. Finally, the hotkey's own take and any undistributed emissions are added to the hotkey's total stake.
+    /// 2. It records the drain block, keeping the previous one to tell which nominators are eligible.
+    /// 3. It credits the emission to the hotkey owner and nominators through `distribute_hotkey_emission`.
+    /// 4. It applies a take increase made since the previous drain, so the drained emission is charged
+    ///    the take it accumulated under.
     ///
     /// This function ensures that emissions are fairly distributed according to stake proportions and delegation agreements, and it updates the necessary records to reflect these changes.
     pub fn drain_hotkey_emission(hotkey: &T::AccountId, emission: u64, block_number: u64) -> u64 {
-        // --- 0. For accounting purposes record the total new added stake.
-        let mut total_new_tao: u64 = 0;
+        // --- 1 and 2 Drain the hotkey emission and record the drain block.
+        let mut state: HotkeyDrainProgress =
+            Self::start_hotkey_drain(hotkey, emission, block_number);
 
-        // --- 1.0 Drain the hotkey emission.
-        PendingdHotkeyEmission::<T>::insert(hotkey, 0);
+        // --- 3 and 4 Credit the emission and apply the take increase that waited for this drain.
+        Self::continue_hotkey_drain(hotkey, &mut state, Weight::MAX);
+        Self::finish_hotkey_drain(hotkey, state);
 
-        // --- 2 Retrieve the last time this hotkey's emissions were drained.
-        let last_emission_drain: u64 = LastHotkeyEmissionDrain::<T>::get(hotkey);
+        emission
+    }
 
-        // --- 3 Update the block value to the current block number.
+    /// Resets the accumulated emission of a hotkey and records the drain block, returning the
+    /// progress of a drain of `emission` that has not visited any stake entry yet.
+    pub fn start_hotkey_drain(
+        hotkey: &T::AccountId,
+        emission: u64,
+        block_number: u64,
+    ) -> HotkeyDrainProgress {
+        PendingdHotkeyEmission::<T>::insert(hotkey, 0);
+        let last_emission_drain: u64 = LastHotkeyEmissionDrain::<T>::get(hotkey);
         LastHotkeyEmissionDrain::<T>::insert(hotkey, block_number);
+        HotkeyDrainProgress {
+            emission,
+            last_emission_drain,
+            remainder: emission,
+            ..Default::default()
+        }
+    }
 
-        // --- 4 Retrieve the total stake for the hotkey from all nominations.
-        let total_hotkey_stake: u64 = Self::get_total_stake_for_hotkey(hotkey);
-
-        // --- 5 Calculate the emission take for the hotkey.
-        let take_proportion: I64F64 = I64F64::from_num(Delegates::<T>::get(hotkey))
-            .saturating_div(I64F64::from_num(u16::MAX));
-        let hotkey_take: u64 =
-            (take_proportion.saturating_mul(I64F64::from_num(emission))).to_num::<u64>();
-
-        // --- 6 Compute the remaining emission after deducting the hotkey's take.
-        let emission_minus_take: u64 = emission.saturating_sub(hotkey_take);
-
-        // --- 7 Calculate the remaining emission after the hotkey's take.
-        let mut remainder: u64 = emission_minus_take;
-
-        // --- 8 Iterate over each nominator and get all viable stake.
-        let mut total_viable_nominator_stake: u64 = total_hotkey_stake;
-        for (nominator, nominator_stake) in Stake::<T>::iter_prefix(hotkey) {
-            if LastAddStakeIncrease::<T>::get(hotkey, nominator) > last_emission_drain {
-                total_viable_nominator_stake =
-                    total_viable_nominator_stake.saturating_sub(nominator_stake);
-            }
+    /// Credits the owner of a drained hotkey the emission left once every nominator is paid, and
+    /// applies the take increase that waited for the drain.
+    pub fn finish_hotkey_drain(hotkey: &T::AccountId, state: HotkeyDrainProgress) {
+        let owner: T::AccountId = Self::get_owning_coldkey_for_hotkey(hotkey);
+        Self::pay_out_emission(&owner, hotkey, state.remainder);
+        if let Some(take) = PendingDelegateTake::<T>::take(hotkey) {
+            Self::set_delegate_take(hotkey, take);
         }
+    }
 
-        // --- 9 Iterate over each nominator.
-        if total_viable_nominator_stake != 0 {
-            for (nominator, nominator_stake) in Stake::<T>::iter_prefix(hotkey) {
-                // --- 10 Check if the stake was manually increased by the user since the last emission drain for this hotkey.
-                // If it was, skip this nominator as they will not receive their proportion of the emission.
-                if LastAddStakeIncrease::<T>::get(hotkey, nominator.clone()) > last_emission_drain {
+    /// Credits a hotkey's emission to its owner and nominators.
+    ///
+    /// The emission is split pro-rata over the stake entries of the hotkey, and each share is
+    /// compounded into the stake or paid to the balance as the `EmissionPayout` of its coldkey says. The delegate take is only
+    /// applied to the part attributable to nominator stake: the owner's own stake earns its share at
+    /// zero take. Stake added since `last_emission_drain` does not earn this emission.
+    ///
+    /// # Arguments
+    /// * `hotkey` - The hotkey whose emission is distributed.
+    /// * `emission` - The amount of TAO to distribute.
+    /// * `last_emission_drain` - The block of the previous drain for this hotkey.
+    ///
+    /// # Returns
+    /// * `u64` - The total amount of new TAO paid out, which always equals `emission`.
+    pub fn distribute_hotkey_emission(
+        hotkey: &T::AccountId,
+        emission: u64,
+        last_emission_drain: u64,
+    ) -> u64 {
+        let mut state = HotkeyDrainProgress {
+            emission,
+            last_emission_drain,
+            remainder: emission,
+            ..Default::default()
+        };
+        Self::continue_hotkey_drain(hotkey, &mut state, Weight::MAX);
+        let owner: T::AccountId = Self::get_owning_coldkey_for_hotkey(hotkey);
+        Self::pay_out_emission(&owner, hotkey, state.remainder);
+        emission
+    }
+
+    /// Visits the stake entries of a hotkey being drained, resuming after `state.cursor`, for as
+    /// long as a visit fits in `remaining_weight`. Reading the owner and the take is charged to the
+    /// caller.
+    ///
+    /// 1. The first pass computes the viable stake of the owner and of the nominators, i.e. stake
+    ///    not increased since the last drain.
+    /// 2. Between the passes, it computes the nominators' part of the emission and the owner's
+    ///    take on that part only.
+    /// 3. The second pass credits each nominator its share of the nominators' part minus the take.
+    ///
+    /// Everything left in `state.remainder`, i.e. the owner's own share, the take and rounding
+    /// dust, is for the owner.
+    ///
+    /// # Returns
+    /// * `(Weight, bool)` - The weight consumed, and whether both passes are done.
+    pub fn continue_hotkey_drain(
+        hotkey: &T::AccountId,
+        state: &mut HotkeyDrainProgress,
+        remaining_weight: Weight,
+    ) -> (Weight, bool) {
+        let visit_weight: Weight = Self::hotkey_drain_visit_weight();
+        let owner: T::AccountId = Self::get_owning_coldkey_for_hotkey(hotkey);
+        let mut weight: Weight = Weight::zero();
+        loop {
+            // --- 1 Collect a page of viable stake entries.
+            let mut stakers = match state.cursor.take() {
+                Some(cursor) => Stake::<T>::iter_prefix_from(hotkey, cursor),
+                None => Stake::<T>::iter_prefix(hotkey),
+            };
+            let mut page: Vec<(T::AccountId, u64)> = Vec::new();
+            let mut exhausted: bool = false;
+            while weight
+                .saturating_add(visit_weight)
+                .all_lte(remaining_weight)
+            {
+                let Some((coldkey, stake)) = stakers.next() else {
+                    exhausted = true;
+                    break;
+                };
+                weight.saturating_accrue(visit_weight);
+                state.cursor = Some(Stake::<T>::hashed_key_for(hotkey, &coldkey));
+                if LastAddStakeIncrease::<T>::get(hotkey, &coldkey) > state.last_emission_drain {
                     continue;
                 }
+                page.push((coldkey, stake));
+            }
 
-                // --- 11 Calculate this nominator's share of the emission.
-                let nominator_emission: I64F64 = I64F64::from_num(emission_minus_take)
-                    .saturating_mul(I64F64::from_num(nominator_stake))
-                    .checked_div(I64F64::from_num(total_viable_nominator_stake))
-                    .unwrap_or(I64F64::from_num(0));
-
-                // --- 12 Increase the stake for the nominator.
-                Self::increase_stake_on_coldkey_hotkey_account(
-                    &nominator,
-                    hotkey,
-                    nominator_emission.to_num::<u64>(),
-                );
-
-                // --- 13* Record event and Subtract the nominator's emission from the remainder.
-                total_new_tao = total_new_tao.saturating_add(nominator_emission.to_num::<u64>());
-                remainder = remainder.saturating_sub(nominator_emission.to_num::<u64>());
+            // --- 2 Count the page, or pay it once the stake is counted.
+            for (coldkey, stake) in page {
+                if coldkey == owner {
+                    if !state.paying {
+                        state.owner_viable_stake = stake;
+                    }
+                } else if !state.paying {
+                    state.nominator_viable_stake =
+                        state.nominator_viable_stake.saturating_add(stake);
+                } else {
+                    let nominator_share: u64 = I64F64::from_num(state.nominator_emission)
+                        .saturating_mul(I64F64::from_num(stake))
+                        .checked_div(I64F64::from_num(state.nominator_viable_stake))
+                        .unwrap_or(I64F64::from_num(0))
+                        .to_num::<u64>()
+                        .min(state.remainder);
+                    Self::pay_out_emission(&coldkey, hotkey, nominator_share);
+                    state.remainder = state.remainder.saturating_sub(nominator_share);
+                }
             }
-        }
 
-        // --- 14 Finally, add the stake to the hotkey itself, including its take and the remaining emission.
-        let hotkey_new_tao: u64 = hotkey_take.saturating_add(remainder);
-        Self::increase_stake_on_hotkey_account(hotkey, hotkey_new_tao);
+            if !exhausted {
+                return (weight, false);
+            }
+            if state.paying {
+                state.cursor = None;
+                return (weight, true);
+            }
 
-        // --- 15 Record new tao creation event and return the amount created.
-        total_new_tao = total_new_tao.saturating_add(hotkey_new_tao);
-        total_new_tao
+            // --- 3 Compute the nominators' part of the emission net of the take, and start paying.
+            let total_viable_stake: u64 = state
+                .nominator_viable_stake
+                .saturating_add(state.owner_viable_stake);
+            let nominator_emission: u64 = I64F64::from_num(state.emission)
+                .saturating_mul(I64F64::from_num(state.nominator_viable_stake))
+                .checked_div(I64F64::from_num(total_viable_stake))
+                .unwrap_or(I64F64::from_num(0))
+                .to_num::<u64>();
+            let take_proportion: I64F64 = I64F64::from_num(Self::get_hotkey_take(hotkey))
+                .saturating_div(I64F64::from_num(u16::MAX));
+            let hotkey_take: u64 = take_proportion
+                .saturating_mul(I64F64::from_num(nominator_emission))
+                .to_num::<u64>();
+            state.nominator_emission = nominator_emission.saturating_sub(hotkey_take);
+            state.paying = true;
+            state.cursor = None;
+        }
     }
 
     ///////////////
@@ -350,6 +796,29 @@ impl<T: Config> Pallet<T> {
             == hotkey_idx.rem_euclid(emit_tempo.saturating_add(1))
     }
 
+    /// Returns the number of blocks from `block` until the hotkey emission is next drained,
+    /// zero if it is drained on `block` itself.
+    ///
+    /// # Arguments
+    /// * `hotkey` - The hotkey identifier.
+    /// * `block` - The block to count from.
+    /// * `emit_tempo` - The hotkey emission tempo.
+    ///
+    /// # Returns
+    /// * `u64` - The number of blocks until the next drain.
+    pub fn blocks_until_next_hotkey_drain(
+        hotkey: &T::AccountId,
+        block: u64,
+        emit_tempo: u64,
+    ) -> u64 {
+        let period: u64 = emit_tempo.saturating_add(1);
+        let drain_phase: u64 = Self::hash_hotkey_to_u64(hotkey).rem_euclid(period);
+        drain_phase
+            .saturating_add(period)
+            .saturating_sub(block.rem_euclid(period))
+            .rem_euclid(period)
+    }
+
     /// Checks if the epoch should run for a given subnet based on the current block.
     ///
     /// # Arguments
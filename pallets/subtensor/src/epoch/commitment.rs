@@ -0,0 +1,176 @@
+//! Merkle commitments over the outcome of an epoch, for verifiers that do not replay the chain.
+//!
+//! # Leaf encoding
+//! Each uid of the subnet is one leaf, in uid order. The leaf is
+//! `blake2_256(0x00 ++ SCALE((uid: u16, hotkey: AccountId, incentive: u16, emission: u64)))`,
+//! with the incentive and emission the epoch wrote for the uid.
+//!
+//! # Tree
+//! An inner node is `blake2_256(0x01 ++ left ++ right)`. A node without a sibling on its level is
+//! carried up unchanged. The root of a subnet without neurons is 32 zero bytes.
+//!
+//! # Proof
+//! A proof of the leaf at `index` lists the siblings from the leaf level up, skipping the levels
+//! where the node was carried up. Checking it needs the root, the leaf, its index and the number
+//! of leaves, all of which but the leaf are kept in `EpochCommitmentInfo`.
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+use sp_io::hashing::blake2_256;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Returns the leaf committing to the epoch outcome of `uid`.
+pub fn epoch_leaf_hash<AccountId: Encode>(
+    uid: u16,
+    hotkey: &AccountId,
+    incentive: u16,
+    emission: u64,
+) -> [u8; 32] {
+    let mut preimage: Vec<u8> = vec![LEAF_PREFIX];
+    (uid, hotkey, incentive, emission).encode_to(&mut preimage);
+    blake2_256(&preimage)
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut preimage: [u8; 65] = [0; 65];
+    if let Some((prefix, children)) = preimage.split_first_mut() {
+        *prefix = NODE_PREFIX;
+        let (left_half, right_half) = children.split_at_mut(32);
+        left_half.copy_from_slice(left);
+        right_half.copy_from_slice(right);
+    }
+    blake2_256(&preimage)
+}
+
+// Hashes the nodes of one level pairwise into the next level.
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => *single,
+            _ => [0; 32],
+        })
+        .collect()
+}
+
+/// Returns the root of the tree over `leaves`.
+pub fn epoch_commitment_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut level: Vec<[u8; 32]> = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.first().copied().unwrap_or([0; 32])
+}
+
+/// Returns the proof of the leaf at `index` in the tree over `leaves`, or None if there is no
+/// such leaf.
+pub fn epoch_leaf_proof(leaves: &[[u8; 32]], index: u16) -> Option<Vec<[u8; 32]>> {
+    let mut position: usize = index as usize;
+    if position >= leaves.len() {
+        return None;
+    }
+
+    let mut proof: Vec<[u8; 32]> = Vec::new();
+    let mut level: Vec<[u8; 32]> = leaves.to_vec();
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(position ^ 1) {
+            proof.push(*sibling);
+        }
+        level = next_level(&level);
+        position /= 2;
+    }
+    Some(proof)
+}
+
+/// Checks that `leaf` is the leaf at `index` of the tree with `leaf_count` leaves and `root`.
+pub fn verify_epoch_leaf(
+    root: &[u8; 32],
+    leaf: &[u8; 32],
+    proof: &[[u8; 32]],
+    index: u16,
+    leaf_count: u16,
+) -> bool {
+    if index >= leaf_count {
+        return false;
+    }
+
+    let mut hash: [u8; 32] = *leaf;
+    let mut siblings = proof.iter();
+    let mut position: u16 = index;
+    let mut width: u16 = leaf_count;
+    while width > 1 {
+        // The last node of a level with an odd width has no sibling and is carried up.
+        if position ^ 1 < width {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            hash = if position % 2 == 0 {
+                node_hash(&hash, sibling)
+            } else {
+                node_hash(sibling, &hash)
+            };
+        }
+        position /= 2;
+        width = width.div_ceil(2);
+    }
+    siblings.next().is_none() && hash == *root
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the leaves of the epoch commitment of `netuid` from the current keys, incentives
+    /// and emissions of its uids.
+    pub fn get_epoch_commitment_leaves(netuid: u16) -> Vec<[u8; 32]> {
+        let incentive: Vec<u16> = Self::get_incentive(netuid);
+        let emission: Vec<u64> = Self::get_emission(netuid);
+        (0..Self::get_subnetwork_n(netuid))
+            .map(|uid| {
+                let hotkey: T::AccountId = Keys::<T>::get(netuid, uid);
+                epoch_leaf_hash(
+                    uid,
+                    &hotkey,
+                    incentive.get(uid as usize).copied().unwrap_or(0),
+                    emission.get(uid as usize).copied().unwrap_or(0),
+                )
+            })
+            .collect()
+    }
+
+    /// Commits to the outcome of the epoch that just ran on `netuid` under the next epoch index,
+    /// dropping the commitment that falls out of the last `EPOCH_COMMITMENT_DEPTH`.
+    ///
+    /// # Arguments
+    /// * `netuid` - The subnet the epoch ran on.
+    /// * `current_block` - The block the epoch ran in.
+    ///
+    /// # Returns
+    /// * `Weight` - The weight consumed by the commitment.
+    pub fn record_epoch_commitment(netuid: u16, current_block: u64) -> Weight {
+        let leaves: Vec<[u8; 32]> = Self::get_epoch_commitment_leaves(netuid);
+        let leaf_count: u16 = leaves.len() as u16;
+        let epoch_index: u64 = EpochCommitmentNextIndex::<T>::get(netuid);
+        EpochCommitment::<T>::insert(
+            netuid,
+            epoch_index,
+            EpochCommitmentInfo {
+                block: current_block,
+                leaf_count,
+                root: epoch_commitment_root(&leaves),
+            },
+        );
+        if let Some(expired) = epoch_index.checked_sub(EPOCH_COMMITMENT_DEPTH) {
+            EpochCommitment::<T>::remove(netuid, expired);
+        }
+        EpochCommitmentNextIndex::<T>::insert(netuid, epoch_index.saturating_add(1));
+
+        // The subnet size, incentive and emission vectors, the next index and per uid its key.
+        T::DbWeight::get().reads_writes(u64::from(leaf_count).saturating_add(4), 3)
+    }
+
+    /// Returns the epoch commitment of `netuid` under `epoch_index`, if it is still kept.
+    pub fn get_epoch_commitment(netuid: u16, epoch_index: u64) -> Option<EpochCommitmentInfo> {
+        EpochCommitment::<T>::get(netuid, epoch_index)
+    }
+}
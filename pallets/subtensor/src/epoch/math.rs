@@ -1,3 +1,21 @@
+//! Fixed-point vector and matrix math of the epoch.
+//!
+//! This is the only implementation the epoch uses, and it is public so that off-chain simulators
+//! can reproduce the chain exactly. It is `no_std` and re-exported as `pallet_subtensor::math`.
+//!
+//! Conventions shared by the functions below:
+//! * Values are `I32F32` unless a name says `64`. Arithmetic saturates instead of overflowing, and
+//!   divisions round toward zero, so a normalized vector sums to 1 within one unit per element.
+//! * Normalizing a vector or row that sums to 0 leaves it unchanged.
+//! * In a mask `true` means masked out: the element is set to 0. Masking is idempotent.
+//! * A sparse matrix is a vector of rows, each a vector of `(column, value)` with zeros omitted.
+//! * The stake-weighted median of a set of scores is always one of the scores of a positive stake,
+//!   hence between their minimum and maximum. The pivot is the midpoint rather than random, so it
+//!   is deterministic.
+//! * `clip` sets values to one of two levels around a threshold, while `inplace_col_clip` and
+//!   `col_clip_sparse` cap values at a per-column threshold.
+//! * `mat_ema` returns `alpha * new + (1 - alpha) * old` elementwise.
+
 // we get a compiler warning for this , even though  the trait is used in the
 // quantile function.
 use crate::alloc::borrow::ToOwned;
@@ -146,6 +164,7 @@ pub fn vec_u16_max_upscale_to_u16(vec: &[u16]) -> Vec<u16> {
 
 #[allow(dead_code)]
 // Checks if u16 vector, when normalized, has a max value not greater than a u16 ratio max_limit.
+// Weights set on chain are checked by `normalize_weight_values` instead, in exact arithmetic.
 pub fn check_vec_max_limited(vec: &[u16], max_limit: u16) -> bool {
     let max_limit_fixed: I32F32 =
         I32F32::from_num(max_limit).saturating_div(I32F32::from_num(u16::MAX));
@@ -155,6 +174,72 @@ pub fn check_vec_max_limited(vec: &[u16], max_limit: u16) -> bool {
     max_value.map_or(true, |v| *v <= max_limit_fixed)
 }
 
+/// Normalizes the weight `values` a validator submits to the weights stored for it, and checks
+/// them against `max_weight_limit`. Every path setting weights goes through it: directly, by
+/// revealing a commit, for a child hotkey, and on root.
+///
+/// It uses integer arithmetic only, so a client reproduces it exactly:
+/// 1. Let `max` be the largest value. Each value `v` becomes `round(v * 65535 / max)`, rounding
+///    halves up, that is `(2 * v * 65535 + max) / (2 * max)` in integers. The largest value becomes
+///    `u16::MAX`.
+/// 2. Let `sum` be the sum of the normalized values. They are within the limit when
+///    `65535 * 65535 <= max_weight_limit * sum`, that is when the largest share of the row in the
+///    epoch, `65535 / sum`, is at most `max_weight_limit / 65535`.
+///
+/// Returns `None` when the weights exceed the limit. In particular:
+/// * No values, or only zeros, are returned unchanged and within any limit. The epoch ignores a
+///   row summing to zero.
+/// * A single nonzero value, `u16::MAX` or any other, becomes `u16::MAX`. Its share is 1, so it is
+///   only within a limit of `u16::MAX`, which accepts every vector. A self weight is exempt from
+///   the limit of the subnet, so the caller checks it against `u16::MAX`.
+/// * Sums and products are taken in `u64`. They cannot overflow, while a `u32` sum of `u16::MAX`
+///   values overflows from 65538 of them.
+///
+/// ```
+/// use pallet_subtensor::math::normalize_weight_values;
+///
+/// // The largest value is scaled to u16::MAX, halves rounding up.
+/// assert_eq!(normalize_weight_values(&[1, 2], u16::MAX), Some(vec![32768, 65535]));
+/// assert_eq!(normalize_weight_values(&[16385, 32770], u16::MAX), Some(vec![32768, 65535]));
+/// assert_eq!(normalize_weight_values(&[1, 3], u16::MAX), Some(vec![21845, 65535]));
+///
+/// // Zeros are kept, and within any limit.
+/// assert_eq!(normalize_weight_values(&[0, 0], 0), Some(vec![0, 0]));
+///
+/// // A single value has a share of 1.
+/// assert_eq!(normalize_weight_values(&[7], u16::MAX), Some(vec![u16::MAX]));
+/// assert_eq!(normalize_weight_values(&[u16::MAX], u16::MAX - 1), None);
+///
+/// // Two equal weights have a share of a half: 32768 is the lowest limit they are within.
+/// assert_eq!(normalize_weight_values(&[5, 5], 32767), None);
+/// assert_eq!(normalize_weight_values(&[5, 5], 32768), Some(vec![65535, 65535]));
+/// ```
+pub fn normalize_weight_values(values: &[u16], max_weight_limit: u16) -> Option<Vec<u16>> {
+    let u16_max: u64 = u64::from(u16::MAX);
+    let max: u64 = values.iter().max().map_or(0, |value| u64::from(*value));
+    if max == 0 {
+        return Some(values.to_vec());
+    }
+
+    let normalized: Vec<u16> = values
+        .iter()
+        .map(|value| {
+            u64::from(*value)
+                .saturating_mul(u16_max)
+                .saturating_mul(2)
+                .saturating_add(max)
+                .checked_div(max.saturating_mul(2))
+                .unwrap_or(0) as u16
+        })
+        .collect();
+    let sum: u64 = normalized
+        .iter()
+        .fold(0, |sum, value| sum.saturating_add(u64::from(*value)));
+    let within_limit: bool =
+        u16_max.saturating_mul(u16_max) <= u64::from(max_weight_limit).saturating_mul(sum);
+    within_limit.then_some(normalized)
+}
+
 #[allow(dead_code)]
 pub fn sum(x: &[I32F32]) -> I32F32 {
     x.iter().sum()
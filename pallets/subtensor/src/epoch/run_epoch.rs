@@ -4,6 +4,13 @@ use frame_support::IterableStorageDoubleMap;
 use sp_std::vec;
 use substrate_fixed::types::{I32F32, I64F64, I96F32};
 
+/// Subnets with at most this many neurons always run the dense epoch.
+pub const DENSE_EPOCH_MAX_N: u16 = 256;
+
+/// Larger subnets run the sparse epoch while less than this percentage of their weight matrix is
+/// set.
+pub const SPARSE_EPOCH_MAX_DENSITY_PERCENT: u64 = 10;
+
 impl<T: Config> Pallet<T> {
     /// Calculates the total stake held by a hotkey on the network, considering child/parent relationships.
     ///
@@ -32,9 +39,22 @@ impl<T: Config> Pallet<T> {
     /// use saturating arithmetic to prevent overflows.
     ///
     pub fn get_stake_for_hotkey_on_subnet(hotkey: &T::AccountId, netuid: u16) -> u64 {
+        Self::get_hotkey_stake_breakdown(hotkey, netuid).total
+    }
+
+    /// Splits the stake of a hotkey on a subnet into the parts the epoch adds up, so that the stake
+    /// the epoch uses and the one reported to clients come from the same computation.
+    ///
+    /// The total is the owner and nominated stake, less the stake lent to children, plus the stake
+    /// inherited from parents, capped at the max stake of the subnet.
+    pub fn get_hotkey_stake_breakdown(hotkey: &T::AccountId, netuid: u16) -> HotkeyStakeBreakdown {
         // Retrieve the initial total stake for the hotkey without any child/parent adjustments.
         let initial_stake: u64 = Self::get_total_stake_for_hotkey(hotkey);
         log::debug!("Initial stake: {:?}", initial_stake);
+        let owner_stake: u64 = Self::get_stake_for_coldkey_and_hotkey(
+            &Self::get_owning_coldkey_for_hotkey(hotkey),
+            hotkey,
+        );
         let mut stake_to_children: u64 = 0;
         let mut stake_from_parents: u64 = 0;
 
@@ -72,22 +92,200 @@ impl<T: Config> Pallet<T> {
 
         // Calculate the final stake for the hotkey by adjusting the initial stake with the stakes
         // to/from children and parents.
-        let mut finalized_stake: u64 = initial_stake
+        let finalized_stake: u64 = initial_stake
             .saturating_sub(stake_to_children)
             .saturating_add(stake_from_parents);
 
-        // get the max stake for the network
-        let max_stake = Self::get_network_max_stake(netuid);
+        HotkeyStakeBreakdown {
+            owner_stake,
+            nominated_stake: initial_stake.saturating_sub(owner_stake),
+            inherited_stake: stake_from_parents,
+            lent_stake: stake_to_children,
+            // Capped at the max stake of the network.
+            total: finalized_stake.min(Self::get_network_max_stake(netuid)),
+        }
+    }
+
+    /// Runs the epoch of `netuid` on the matrix representation that suits its weights.
+    ///
+    /// Subnets with more than `DENSE_EPOCH_MAX_N` neurons whose weight matrix is less than
+    /// `SPARSE_EPOCH_MAX_DENSITY_PERCENT` percent set run the sparse `epoch`, all others run
+    /// `epoch_dense`. Both produce identical results, only the cost differs.
+    ///
+    /// With a validator health drop threshold set on the subnet, the validators whose trust
+    /// dropped by more than it are reported afterwards.
+    pub fn run_epoch(netuid: u16, rao_emission: u64) -> Vec<(T::AccountId, u64, u64)> {
+        let threshold: u16 = Self::get_validator_health_drop_threshold(netuid);
+        let validators_before: Option<(Vec<u16>, Vec<bool>)> = (threshold > 0).then(|| {
+            (
+                Self::get_validator_trust(netuid),
+                Self::get_validator_permit(netuid),
+            )
+        });
+
+        let hotkey_emission = if Self::epoch_runs_sparse(netuid) {
+            log::debug!("epoch( netuid: {:?} ) running sparse", netuid);
+            Self::epoch(netuid, rao_emission)
+        } else {
+            log::debug!("epoch( netuid: {:?} ) running dense", netuid);
+            Self::epoch_dense(netuid, rao_emission)
+        };
+
+        if let Some((validator_trust, validator_permit)) = validators_before {
+            Self::report_degraded_validators(
+                netuid,
+                threshold,
+                &validator_trust,
+                &validator_permit,
+            );
+        }
+        hotkey_emission
+    }
+
+    /// Emits `ValidatorHealthDegraded` for every uid that held a validator permit before and after
+    /// the epoch and whose validator trust dropped by more than `threshold` over it.
+    ///
+    /// A uid without a permit before the epoch has no trust to compare, and one without a permit
+    /// after it has stopped validating, which its operator sees from the permit instead.
+    pub fn report_degraded_validators(
+        netuid: u16,
+        threshold: u16,
+        validator_trust_before: &[u16],
+        validator_permit_before: &[bool],
+    ) {
+        let validator_trust_after: Vec<u16> = Self::get_validator_trust(netuid);
+        let validator_permit_after: Vec<bool> = Self::get_validator_permit(netuid);
+        let uids = validator_trust_before
+            .iter()
+            .zip(validator_permit_before)
+            .zip(validator_trust_after.iter().zip(&validator_permit_after))
+            .enumerate();
+        for (uid, ((old, permit_before), (new, permit_after))) in uids {
+            if *permit_before && *permit_after && old.saturating_sub(*new) > threshold {
+                log::debug!(
+                    "ValidatorHealthDegraded( netuid:{:?} uid:{:?} old:{:?} new:{:?} )",
+                    netuid,
+                    uid,
+                    old,
+                    new
+                );
+                Self::deposit_event(Event::ValidatorHealthDegraded {
+                    netuid,
+                    uid: uid as u16,
+                    old: *old,
+                    new: *new,
+                });
+            }
+        }
+    }
+
+    /// Returns true if the epoch of `netuid` should run on sparse matrices, i.e. the subnet is
+    /// larger than `DENSE_EPOCH_MAX_N` and its weight density is below
+    /// `SPARSE_EPOCH_MAX_DENSITY_PERCENT`.
+    pub fn epoch_runs_sparse(netuid: u16) -> bool {
+        let n: u64 = Self::get_subnetwork_n(netuid) as u64;
+        if n <= DENSE_EPOCH_MAX_N as u64 {
+            return false;
+        }
+        let weight_entries: u64 = Weights::<T>::iter_prefix_values(netuid)
+            .fold(0, |entries, row| entries.saturating_add(row.len() as u64));
+        log::debug!(
+            "epoch( netuid: {:?} ) weight entries: {:?} of {:?}",
+            netuid,
+            weight_entries,
+            n.saturating_mul(n)
+        );
+        weight_entries.saturating_mul(100)
+            < n.saturating_mul(n)
+                .saturating_mul(SPARSE_EPOCH_MAX_DENSITY_PERCENT)
+    }
+
+    /// Returns true if the epoch of `netuid` has nothing to distribute on: no neuron has set a
+    /// nonzero weight on another neuron, or no neuron active within the activity cutoff holds
+    /// stake. This is the case of every subnet right after its creation.
+    pub fn epoch_is_empty(netuid: u16) -> bool {
+        let has_weights: bool = Weights::<T>::iter_prefix(netuid).any(|(uid_i, row)| {
+            row.iter()
+                .any(|(uid_j, weight)| *uid_j != uid_i && *weight > 0)
+        });
+        if !has_weights {
+            return true;
+        }
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let activity_cutoff: u64 = Self::get_activity_cutoff(netuid) as u64;
+        let last_update: Vec<u64> = Self::get_last_update(netuid);
+        let has_active_stake: bool = Keys::<T>::iter_prefix(netuid).any(|(uid, hotkey)| {
+            let active: bool = last_update
+                .get(uid as usize)
+                .is_some_and(|updated| updated.saturating_add(activity_cutoff) >= current_block);
+            active && Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid) > 0
+        });
+        !has_active_stake
+    }
+
+    /// Writes the outcome of an epoch of `netuid` that has nothing to distribute on, without
+    /// running the matrix math.
+    ///
+    /// Rank, trust, consensus, validator trust, dividends and emission are zero. The incentive is
+    /// uniform over the neurons if `EmptyEpochUniformIncentive` is set for the subnet, zero
+    /// otherwise, and the pruning scores follow it. Validator permits are still given to the top
+    /// stake, so that validators can set the weights that end the empty epochs. Bonds are kept.
+    pub fn write_empty_epoch(netuid: u16) {
+        let n: usize = Self::get_subnetwork_n(netuid) as usize;
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let activity_cutoff: u64 = Self::get_activity_cutoff(netuid) as u64;
+        let active: Vec<bool> = Self::get_last_update(netuid)
+            .iter()
+            .map(|updated| updated.saturating_add(activity_cutoff) >= current_block)
+            .collect();
+
+        let mut stake_64: Vec<I64F64> = vec![I64F64::from_num(0.0); n];
+        for (uid_i, hotkey) in Keys::<T>::iter_prefix(netuid) {
+            if let Some(stake_i) = stake_64.get_mut(uid_i as usize) {
+                *stake_i = I64F64::from_num(Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid));
+            }
+        }
+        inplace_normalize_64(&mut stake_64);
+        let stake: Vec<I32F32> = vec_fixed64_to_fixed32(stake_64);
+        let new_validator_permits: Vec<bool> =
+            is_topk(&stake, Self::get_max_allowed_validators(netuid) as usize);
 
-        // Return the finalized stake value for the hotkey, but capped at the max stake.
-        finalized_stake = finalized_stake.min(max_stake);
+        let incentive: Vec<I32F32> = if Self::get_empty_epoch_uniform_incentive(netuid) && n > 0 {
+            vec![I32F32::from_num(1).saturating_div(I32F32::from_num(n)); n]
+        } else {
+            vec![I32F32::from_num(0); n]
+        };
+        let zeros: Vec<u16> = vec![0; n];
 
-        // Return the finalized stake value for the hotkey.
-        finalized_stake
+        StakeWeight::<T>::insert(
+            netuid,
+            stake
+                .iter()
+                .map(|xi| fixed_proportion_to_u16(*xi))
+                .collect::<Vec<u16>>(),
+        );
+        Active::<T>::insert(netuid, active);
+        Emission::<T>::insert(netuid, vec![0_u64; n]);
+        Rank::<T>::insert(netuid, zeros.clone());
+        Trust::<T>::insert(netuid, zeros.clone());
+        Consensus::<T>::insert(netuid, zeros.clone());
+        Incentive::<T>::insert(
+            netuid,
+            incentive
+                .iter()
+                .map(|xi| fixed_proportion_to_u16(*xi))
+                .collect::<Vec<u16>>(),
+        );
+        Dividends::<T>::insert(netuid, zeros.clone());
+        PruningScores::<T>::insert(netuid, vec_max_upscale_to_u16(&incentive));
+        ValidatorTrust::<T>::insert(netuid, zeros);
+        ValidatorPermit::<T>::insert(netuid, new_validator_permits);
     }
 
     /// Calculates reward consensus and returns the emissions for uids/hotkeys in a given `netuid`.
-    /// (Dense version used only for testing purposes.)
+    /// (Dense version, run for small or densely weighted subnets.)
     #[allow(clippy::indexing_slicing)]
     pub fn epoch_dense(netuid: u16, rao_emission: u64) -> Vec<(T::AccountId, u64, u64)> {
         // Get subnetwork size.
@@ -107,7 +305,7 @@ impl<T: Config> Pallet<T> {
         log::trace!("activity_cutoff:\n{:?}\n", activity_cutoff);
 
         // Last update vector.
-        let last_update: Vec<u64> = Self::get_last_update(netuid);
+        let last_update: Vec<u64> = Self::get_epoch_last_update(netuid);
         log::trace!("Last update:\n{:?}\n", &last_update);
 
         // Inactive mask.
@@ -140,9 +338,7 @@ impl<T: Config> Pallet<T> {
         // == Stake ==
         // ===========
 
-        let hotkeys: Vec<(u16, T::AccountId)> =
-            <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(netuid)
-                .collect();
+        let hotkeys: Vec<(u16, T::AccountId)> = Self::get_epoch_hotkeys(netuid);
         log::trace!("hotkeys: {:?}", &hotkeys);
 
         // Access network stake as normalized vector.
@@ -160,7 +356,7 @@ impl<T: Config> Pallet<T> {
         // =======================
 
         // Get validator permits.
-        let validator_permits: Vec<bool> = Self::get_validator_permit(netuid);
+        let validator_permits: Vec<bool> = Self::get_epoch_validator_permit(netuid);
         log::trace!("validator_permits: {:?}", validator_permits);
 
         // Logical negation of validator_permits.
@@ -398,14 +594,17 @@ impl<T: Config> Pallet<T> {
             .for_each(|(i, ((new_permit, validator_permit), ema_bond))| {
                 // Set bonds only if uid retains validator permit, otherwise clear bonds.
                 if *new_permit {
+                    // Store only the nonzero bonds, as the sparse epoch does.
                     let new_bonds_row: Vec<(u16, u16)> = (0..n)
-                        .zip(vec_fixed_proportions_to_u16(ema_bond.clone()))
+                        .zip(ema_bond)
+                        .filter(|(_, value)| *value > I32F32::from_num(0))
+                        .map(|(j, value)| (j, fixed_proportion_to_u16(value)))
                         .collect();
-                    Bonds::<T>::insert(netuid, i as u16, new_bonds_row);
+                    // A row holds each uid at most once, so it always fits the bound.
+                    Bonds::<T>::insert(netuid, i as u16, WeightsRow::truncate_from(new_bonds_row));
                 } else if validator_permit {
                     // Only overwrite the intersection.
-                    let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
-                    Bonds::<T>::insert(netuid, i as u16, new_empty_bonds_row);
+                    Bonds::<T>::insert(netuid, i as u16, WeightsRow::new());
                 }
             });
 
@@ -453,7 +652,7 @@ impl<T: Config> Pallet<T> {
         log::trace!("activity_cutoff: {:?}", activity_cutoff);
 
         // Last update vector.
-        let last_update: Vec<u64> = Self::get_last_update(netuid);
+        let last_update: Vec<u64> = Self::get_epoch_last_update(netuid);
         log::trace!("Last update: {:?}", &last_update);
 
         // Inactive mask.
@@ -474,9 +673,7 @@ impl<T: Config> Pallet<T> {
         // == Stake ==
         // ===========
 
-        let hotkeys: Vec<(u16, T::AccountId)> =
-            <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(netuid)
-                .collect();
+        let hotkeys: Vec<(u16, T::AccountId)> = Self::get_epoch_hotkeys(netuid);
         log::trace!("hotkeys: {:?}", &hotkeys);
 
         // Access network stake as normalized vector.
@@ -496,7 +693,7 @@ impl<T: Config> Pallet<T> {
         // =======================
 
         // Get current validator permits.
-        let validator_permits: Vec<bool> = Self::get_validator_permit(netuid);
+        let validator_permits: Vec<bool> = Self::get_epoch_validator_permit(netuid);
         log::trace!("validator_permits: {:?}", validator_permits);
 
         // Logical negation of validator_permits.
@@ -775,11 +972,11 @@ impl<T: Config> Pallet<T> {
                         .iter()
                         .map(|(j, value)| (*j, fixed_proportion_to_u16(*value)))
                         .collect();
-                    Bonds::<T>::insert(netuid, i as u16, new_bonds_row);
+                    // A row holds each uid at most once, so it always fits the bound.
+                    Bonds::<T>::insert(netuid, i as u16, WeightsRow::truncate_from(new_bonds_row));
                 } else if validator_permit {
                     // Only overwrite the intersection.
-                    let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
-                    Bonds::<T>::insert(netuid, i as u16, new_empty_bonds_row);
+                    Bonds::<T>::insert(netuid, i as u16, WeightsRow::new());
                 }
             });
 
@@ -829,12 +1026,39 @@ impl<T: Config> Pallet<T> {
         block_at_registration
     }
 
+    /// Returns the last update of each of the `n` neurons of `netuid`.
+    ///
+    /// The stored vector can be out of sync with the subnet size, e.g. after a shrink. Entries
+    /// past `n` are dropped and missing ones read as never updated, so the epoch cannot index
+    /// out of bounds.
+    pub fn get_epoch_last_update(netuid: u16) -> Vec<u64> {
+        let mut last_update: Vec<u64> = Self::get_last_update(netuid);
+        last_update.resize(Self::get_subnetwork_n(netuid) as usize, 0);
+        last_update
+    }
+
+    /// Returns the validator permit of each of the `n` neurons of `netuid`, sanitized like
+    /// `get_epoch_last_update`. Missing entries read as no permit.
+    pub fn get_epoch_validator_permit(netuid: u16) -> Vec<bool> {
+        let mut validator_permit: Vec<bool> = Self::get_validator_permit(netuid);
+        validator_permit.resize(Self::get_subnetwork_n(netuid) as usize, false);
+        validator_permit
+    }
+
+    /// Returns the uid and hotkey of the neurons of `netuid`, without the keys left past `n`.
+    pub fn get_epoch_hotkeys(netuid: u16) -> Vec<(u16, T::AccountId)> {
+        let n: u16 = Self::get_subnetwork_n(netuid);
+        <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(netuid)
+            .filter(|(uid, _)| *uid < n)
+            .collect()
+    }
+
     /// Output unnormalized sparse weights, input weights are assumed to be row max-upscaled in u16.
     pub fn get_weights_sparse(netuid: u16) -> Vec<Vec<(u16, I32F32)>> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut weights: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
         for (uid_i, weights_i) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
             for (uid_j, weight_ij) in weights_i.iter().filter(|(uid_j, _)| *uid_j < n as u16) {
@@ -852,7 +1076,7 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut weights: Vec<Vec<I32F32>> = vec![vec![I32F32::from_num(0.0); n]; n];
         for (uid_i, weights_vec) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
             for (uid_j, weight_ij) in weights_vec
@@ -875,10 +1099,10 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut bonds: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
         for (uid_i, bonds_vec) in
-            <Bonds<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Bonds<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
-            for (uid_j, bonds_ij) in bonds_vec {
+            for (uid_j, bonds_ij) in bonds_vec.into_iter().filter(|(uid_j, _)| *uid_j < n as u16) {
                 bonds
                     .get_mut(uid_i as usize)
                     .expect("uid_i is filtered to be less than n; qed")
@@ -893,7 +1117,7 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut bonds: Vec<Vec<I32F32>> = vec![vec![I32F32::from_num(0.0); n]; n];
         for (uid_i, bonds_vec) in
-            <Bonds<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Bonds<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
             for (uid_j, bonds_ij) in bonds_vec.into_iter().filter(|(uid_j, _)| *uid_j < n as u16) {
@@ -1288,23 +1512,24 @@ impl<T: Config> Pallet<T> {
         // --- 2. Ensure the function caller is the subnet owner or root.
         Self::ensure_subnet_owner_or_root(origin, netuid)?;
 
-        // --- 3. Ensure liquid alpha is enabled
+        Self::try_set_alpha_values(netuid, alpha_low, alpha_high)
+    }
+
+    /// Sets the liquid alpha values of a subnet once the caller is authorized, if liquid alpha is
+    /// enabled and the values are within range.
+    pub fn try_set_alpha_values(
+        netuid: u16,
+        alpha_low: u16,
+        alpha_high: u16,
+    ) -> Result<(), DispatchError> {
+        // --- 1. Ensure liquid alpha is enabled
         ensure!(
             Self::get_liquid_alpha_enabled(netuid),
             Error::<T>::LiquidAlphaDisabled
         );
 
-        let max_u16: u32 = u16::MAX as u32; // 65535
-        let min_alpha_high: u16 = (max_u16.saturating_mul(4).saturating_div(5)) as u16; // 52428
-
-        // --- 4. Ensure alpha high is greater than the minimum
-        ensure!(alpha_high >= min_alpha_high, Error::<T>::AlphaHighTooLow);
-
-        // -- 5. Ensure alpha low is within range
-        ensure!(
-            alpha_low > 0 && alpha_low < min_alpha_high,
-            Error::<T>::AlphaLowOutOfRange
-        );
+        // --- 2. Ensure the alpha values are within range
+        Self::ensure_valid_alpha_values(alpha_low, alpha_high)?;
 
         AlphaValues::<T>::insert(netuid, (alpha_low, alpha_high));
 
@@ -1316,4 +1541,20 @@ impl<T: Config> Pallet<T> {
         );
         Ok(())
     }
+
+    /// Ensures alpha high is at least 80% of `u16::MAX`, and alpha low is positive and below that.
+    pub fn ensure_valid_alpha_values(alpha_low: u16, alpha_high: u16) -> Result<(), Error<T>> {
+        let max_u16: u32 = u16::MAX as u32; // 65535
+        let min_alpha_high: u16 = (max_u16.saturating_mul(4).saturating_div(5)) as u16; // 52428
+
+        // --- 1. Ensure alpha high is greater than the minimum
+        ensure!(alpha_high >= min_alpha_high, Error::<T>::AlphaHighTooLow);
+
+        // -- 2. Ensure alpha low is within range
+        ensure!(
+            alpha_low > 0 && alpha_low < min_alpha_high,
+            Error::<T>::AlphaLowOutOfRange
+        );
+        Ok(())
+    }
 }
@@ -4,6 +4,10 @@
 // Edit this file to define custom logic or remove it if it is not needed.
 // Learn more about FRAME and the core library of Substrate FRAME pallets:
 // <https://docs.substrate.io/reference/frame-pallets/>
+pub use epoch::commitment::{
+    epoch_commitment_root, epoch_leaf_hash, epoch_leaf_proof, verify_epoch_leaf,
+};
+pub use epoch::math;
 pub use pallet::*;
 
 use frame_system::{self as system, ensure_signed};
@@ -12,7 +16,7 @@ use frame_support::{
     dispatch::{self, DispatchInfo, DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo},
     ensure,
     pallet_macros::import_section,
-    traits::{tokens::fungible, IsSubType},
+    traits::{tokens::fungible, IsSubType, IsType},
 };
 
 use codec::{Decode, Encode};
@@ -44,6 +48,8 @@ pub mod rpc_info;
 pub mod staking;
 pub mod subnets;
 pub mod swap;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod utils;
 use crate::utils::rate_limiting::TransactionType;
 use macros::{config, dispatches, errors, events, genesis, hooks};
@@ -65,12 +71,13 @@ pub mod pallet {
         dispatch::GetDispatchInfo,
         pallet_prelude::{DispatchResult, StorageMap, ValueQuery, *},
         traits::{
-            tokens::fungible, OriginTrait, QueryPreimage, StorePreimage, UnfilteredDispatchable,
+            tokens::fungible, IsSubType, LockableCurrency, OriginTrait, QueryPreimage,
+            StorePreimage, UnfilteredDispatchable,
         },
     };
     use frame_system::pallet_prelude::*;
     use sp_core::H256;
-    use sp_runtime::traits::{Dispatchable, TrailingZeroInput};
+    use sp_runtime::traits::{Dispatchable, IdentifyAccount, TrailingZeroInput, Verify};
     use sp_std::vec;
     use sp_std::vec::Vec;
 
@@ -93,6 +100,48 @@ pub mod pallet {
     /// Minimum balance required to perform a coldkey swap
     pub const MIN_BALANCE_TO_PERFORM_COLDKEY_SWAP: u64 = 100_000_000; // 0.1 TAO in RAO
 
+    /// Hard bound on the number of hotkeys a coldkey can own, including exempt coldkeys
+    pub const MAX_OWNED_HOTKEYS: u32 = 16_384;
+
+    /// Maximum number of epochs kept in the epoch history of a subnet
+    pub const MAX_EPOCH_HISTORY_DEPTH: u16 = 32;
+
+    /// Number of highest incentives recorded in an epoch summary
+    pub const EPOCH_SUMMARY_TOP_INCENTIVES: usize = 16;
+
+    /// Number of epoch commitments kept per subnet
+    pub const EPOCH_COMMITMENT_DEPTH: u64 = 32;
+
+    /// Emission multiplier of 1.0, in millionths: the subnet keeps its share of the block emission
+    pub const EMISSION_MULTIPLIER_ONE: u32 = 1_000_000;
+
+    /// Maximum emission multiplier of a subnet, 2.0 in millionths
+    pub const MAX_EMISSION_MULTIPLIER: u32 = 2_000_000;
+
+    /// Number of registration adjustments kept in the adjustment history of a subnet
+    pub const REGISTRATION_ADJUSTMENT_HISTORY_LEN: usize = 8;
+
+    /// Number of delegate take changes kept in the take history of a hotkey
+    pub const TAKE_HISTORY_LEN: usize = 8;
+
+    /// Hard bound on the length of a weights or bonds row: every uid of a subnet at most once
+    pub const MAX_WEIGHTS_ROW_LEN: u32 = 65_536;
+
+    /// Hard bound on the number of coldkeys a nominator snapshot of a hotkey records
+    pub const MAX_SNAPSHOT_NOMINATORS: u32 = 1_024;
+
+    /// Maximum number of nominators a single `compensate_nominators` call pays
+    pub const MAX_COMPENSATION_PAYOUTS_PER_CALL: u32 = 64;
+
+    /// Hard bound on the number of hotkeys waiting in the hotkey drain queue
+    pub const MAX_HOTKEY_DRAIN_QUEUE: u32 = 4_096;
+
+    /// Number of stake entries the block step visits per block to drain hotkey emission
+    pub const HOTKEY_DRAIN_VISITS_PER_BLOCK: u64 = 512;
+
+    /// A row of ( uid, value ) pairs in the weights or bonds matrix of a subnet
+    pub type WeightsRow = BoundedVec<(u16, u16), ConstU32<MAX_WEIGHTS_ROW_LEN>>;
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -182,6 +231,221 @@ pub mod pallet {
         /// The subnet's contact
         pub subnet_contact: Vec<u8>,
     }
+
+    /// Stages of the block step, run in this order on every block.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum BlockStepStage {
+        /// Adjusts the registration difficulty and burn of every network.
+        AdjustRegistrationTerms,
+        /// Runs the root epoch, which sets the block emission of each subnet.
+        RootEpoch,
+        /// Accumulates the block emission of each subnet as its pending emission.
+        AccumulateSubnetEmission,
+        /// Drains the pending emission of the subnets whose tempo is reached through their epoch.
+        RunSubnetEpochs,
+        /// Queues the hotkeys whose pending emission is due.
+        ScheduleHotkeyDrains,
+        /// Adjusts the key swap cost toward the target number of key swaps.
+        AdjustKeySwapCost,
+        /// Applies the requested children whose cooldown has passed.
+        ApplyPendingChildKeys,
+        /// Removes the used invites that have expired.
+        PruneExpiredInvites,
+        /// Drains the queued hotkeys within a bounded number of stake entries per block.
+        DrainHotkeys,
+    }
+
+    /// Progress of the drain of the hotkey at the head of the hotkey drain queue.
+    ///
+    /// A first pass over the stake entries of the hotkey counts the viable stake, a second pays
+    /// each nominator its share. Both passes resume after `cursor`, so a hotkey with many
+    /// stakers is drained over several blocks.
+    #[crate::freeze_struct("962ae7be15bfe720")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct HotkeyDrainProgress {
+        /// The emission being drained.
+        pub emission: u64,
+        /// The block of the previous drain; stake added since then does not earn the emission.
+        pub last_emission_drain: u64,
+        /// The viable stake of the owner.
+        pub owner_viable_stake: u64,
+        /// The viable stake of the nominators.
+        pub nominator_viable_stake: u64,
+        /// The part of the emission shared by the nominators, net of the take.
+        pub nominator_emission: u64,
+        /// Whether the viable stake is counted and the nominators are being paid.
+        pub paying: bool,
+        /// The emission not paid out yet, which goes to the owner at the end.
+        pub remainder: u64,
+        /// The raw `Stake` key of the last stake entry visited in the current pass.
+        pub cursor: Option<Vec<u8>>,
+    }
+
+    /// Scores the pruning selection of a subnet ranks its neurons by.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug, Default)]
+    pub enum PruningScoreSource {
+        /// The combined emission of the neuron written by the epoch.
+        #[default]
+        EmissionBased,
+        /// The incentive of the neuron.
+        IncentiveBased,
+        /// The scores submitted by the subnet owner.
+        Custom,
+    }
+
+    /// Where the emission drained to a coldkey's stake on a hotkey is paid.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug, Default)]
+    pub enum EmissionPayoutMode {
+        /// The emission is added to the stake.
+        #[default]
+        Compound,
+        /// The emission is credited to the free balance of the coldkey.
+        ToBalance,
+    }
+
+    /// Pending operations of a coldkey that the coldkey swap can not move to the new coldkey.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum PendingOperationKind {
+        /// A coldkey swap to another destination is scheduled.
+        ScheduledColdkeySwap,
+        /// A network dissolution, which names the coldkey as the subnet owner, is scheduled.
+        ScheduledNetworkDissolution,
+        /// The balance of the coldkey vests under a schedule, whose lock stays on the coldkey.
+        VestingSchedule,
+    }
+
+    /// Inconsistent entries of a coldkey that the coldkey swap moves as they are or skips.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum ColdkeySwapAnomalyKind {
+        /// The coldkey is the owner of a subnet that is not added, which the swap skips.
+        SubnetOwnerMismatch,
+        /// A hotkey in the owned hotkeys of the coldkey has another owner.
+        OwnedHotkeyOwnerMismatch,
+        /// A hotkey in the staking hotkeys of the coldkey holds no stake of it.
+        StakingHotkeyWithoutStake,
+    }
+
+    /// Rate limited operations, as the keys of `LastRateLimitedBlock` and the entries of the rate
+    /// limit status of a hotkey.
+    #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum RateLimitedOperation {
+        /// Staking, unstaking, becoming a delegate or swapping a hotkey, per coldkey.
+        Transaction,
+        /// Changing the take of a delegate, per coldkey.
+        DelegateTake,
+        /// Changing the take of a childkey, per coldkey.
+        ChildKeyTake,
+        /// Serving an axon, per subnet and hotkey.
+        ServeAxon,
+        /// Serving a prometheus endpoint, per subnet and hotkey.
+        ServePrometheus,
+        /// Setting weights, per subnet and hotkey.
+        SetWeights,
+        /// Setting a commitment, per subnet and hotkey.
+        SetCommitment,
+        /// Setting the children of a hotkey, per subnet.
+        SetChildren,
+        /// Setting the childkey take of a hotkey, per subnet.
+        SetChildkeyTake,
+    }
+
+    /// Data structure for the detail of a failed call, which its `DispatchError` cannot carry.
+    #[crate::freeze_struct("8e9816346039c914")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct CallFailureInfo {
+        /// The index of the pallet error the call failed with
+        pub error: u8,
+        /// The limit the call was checked against
+        pub expected: u64,
+        /// The value the call provided
+        pub provided: u64,
+        /// The block the call failed in
+        pub block: u64,
+    }
+
+    /// Data structure for the summary of an epoch kept in the epoch history of a subnet.
+    #[crate::freeze_struct("751b4d4721e8d961")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct EpochSummary {
+        /// The block the epoch ran in
+        pub block: u64,
+        /// The emission drained into the epoch, including the owner cut
+        pub total_emission: u64,
+        /// The highest incentives as ( uid, incentive ), highest first
+        pub top_incentives: Vec<(u16, u16)>,
+        /// The number of neurons holding a validator permit
+        pub validator_count: u16,
+    }
+
+    /// Data structure for the merkle commitment over the outcome of an epoch.
+    #[crate::freeze_struct("508ba8e93febfe00")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct EpochCommitmentInfo {
+        /// The block the epoch ran in
+        pub block: u64,
+        /// The number of leaves, one per uid of the subnet
+        pub leaf_count: u16,
+        /// The merkle root over the leaves
+        pub root: [u8; 32],
+    }
+
+    /// Data structure for the vesting schedule of a coldkey.
+    #[crate::freeze_struct("cfbab6ca2409d184")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct VestingScheduleInfo {
+        /// The balance vesting under the schedule
+        pub total: u64,
+        /// The balance vesting each block
+        pub per_block: u64,
+        /// The block vesting starts from
+        pub start_block: u64,
+        /// The unvested balance the coldkey has staked, locked again when it is unstaked
+        pub staked: u64,
+    }
+
+    /// Data structure for the latest payload a coldkey attested, for off-chain ownership proofs.
+    #[crate::freeze_struct("36ec671cde875de1")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ColdkeyAttestationInfo {
+        /// The 32-byte payload attested, e.g. a hash binding the coldkey to an off-chain identity
+        pub payload_hash: [u8; 32],
+        /// The block the payload was attested in
+        pub block: u64,
+        /// The deposit held for the attestation, refunded when it is revoked
+        pub deposit: u64,
+    }
+
+    /// Data structure for the stake of the nominators of a hotkey, recorded to compensate them.
+    #[crate::freeze_struct("5b4d926860ff4da6")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct NominatorSnapshotInfo<AccountId> {
+        /// The block the snapshot was taken in
+        pub block: u64,
+        /// The stake of the coldkeys on the hotkey, as ( coldkey, stake )
+        pub nominators: BoundedVec<(AccountId, u64), ConstU32<MAX_SNAPSHOT_NOMINATORS>>,
+        /// The sum of the recorded stakes
+        pub total_stake: u64,
+        /// The compensation being paid out, or zero if no payout started
+        pub compensation: u64,
+        /// The index of the next nominator to pay
+        pub next_index: u32,
+    }
+
+    /// Data structure for the stake of a hotkey on a subnet, as the epoch adds it up.
+    #[crate::freeze_struct("7a0da07aaa8c365a")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct HotkeyStakeBreakdown {
+        /// The stake of the owning coldkey on the hotkey
+        pub owner_stake: u64,
+        /// The stake of other coldkeys on the hotkey
+        pub nominated_stake: u64,
+        /// The stake inherited from the parents of the hotkey on the subnet
+        pub inherited_stake: u64,
+        /// The stake lent to the children of the hotkey on the subnet
+        pub lent_stake: u64,
+        /// The stake the epoch uses, capped at the max stake of the subnet
+        pub total: u64,
+    }
     /// ============================
     /// ==== Staking + Accounts ====
     /// ============================
@@ -193,7 +457,7 @@ pub mod pallet {
     }
     #[pallet::type_value]
     /// Default Delegate Take.
-    pub fn DefaultDelegateTake<T: Config>() -> u16 {
+    pub fn DefaultInitialDelegateTake<T: Config>() -> u16 {
         T::InitialDefaultDelegateTake::get()
     }
 
@@ -262,11 +526,26 @@ pub mod pallet {
         360
     }
     #[pallet::type_value]
+    /// Default max stake operations per interval per coldkey, unlimited.
+    pub fn DefaultMaxStakeOpsPerIntervalPerColdkey<T: Config>() -> u64 {
+        u64::MAX
+    }
+    #[pallet::type_value]
     /// Default account linkage
     pub fn DefaultAccountLinkage<T: Config>() -> Vec<(u64, T::AccountId)> {
         vec![]
     }
     #[pallet::type_value]
+    /// Default number of blocks before requested children are applied.
+    pub fn DefaultPendingChildKeyCooldown<T: Config>() -> u64 {
+        T::InitialPendingChildKeyCooldown::get()
+    }
+    #[pallet::type_value]
+    /// Default deposit withdrawn from the owner coldkey for a weights commit.
+    pub fn DefaultWeightCommitDeposit<T: Config>() -> u64 {
+        T::InitialWeightCommitDeposit::get()
+    }
+    #[pallet::type_value]
     /// Default account linkage
     pub fn DefaultProportion<T: Config>() -> u64 {
         0
@@ -377,6 +656,32 @@ pub mod pallet {
         0
     }
     #[pallet::type_value]
+    /// Default stake thresholds of the fee discount tiers, which no stake reaches.
+    pub fn DefaultStakeFeeDiscountThresholds<T: Config>() -> [u64; 3] {
+        [u64::MAX; 3]
+    }
+    #[pallet::type_value]
+    /// Default number of tempos a staked hotkey stays off every subnet before its stake is
+    /// redelegated.
+    pub fn DefaultAutoRedelegateAfterTempos<T: Config>() -> u64 {
+        3
+    }
+    #[pallet::type_value]
+    /// Default deposit withdrawn from a coldkey for its attestation, 0.001 TAO.
+    pub fn DefaultAttestationDeposit<T: Config>() -> u64 {
+        1_000_000
+    }
+    #[pallet::type_value]
+    /// Default number of blocks between two attestations of a coldkey.
+    pub fn DefaultAttestationRateLimit<T: Config>() -> u64 {
+        100
+    }
+    #[pallet::type_value]
+    /// Default minimum stake of the owner on its delegate hotkey.
+    pub fn DefaultMinOwnerStakeToDelegate<T: Config>() -> u64 {
+        0
+    }
+    #[pallet::type_value]
     /// Default value for network min allowed UIDs.
     pub fn DefaultNetworkMinAllowedUids<T: Config>() -> u16 {
         T::InitialNetworkMinAllowedUids::get()
@@ -402,6 +707,11 @@ pub mod pallet {
         T::InitialSubnetLimit::get()
     }
     #[pallet::type_value]
+    /// Default value for pruning a subnet at the subnet limit.
+    pub fn DefaultSubnetLimitPruning<T: Config>() -> bool {
+        true
+    }
+    #[pallet::type_value]
     /// Default value for network rate limit.
     pub fn DefaultNetworkRateLimit<T: Config>() -> u64 {
         if cfg!(feature = "pow-faucet") {
@@ -415,6 +725,11 @@ pub mod pallet {
         0
     }
     #[pallet::type_value]
+    /// Default number of tempos without emission before a subnet is flagged.
+    pub fn DefaultSubnetWarningTempos<T: Config>() -> u64 {
+        50
+    }
+    #[pallet::type_value]
     /// Default value for pending emission.
     pub fn DefaultPendingEmission<T: Config>() -> u64 {
         0
@@ -531,7 +846,7 @@ pub mod pallet {
         T::InitialAdjustmentAlpha::get()
     }
     #[pallet::type_value]
-    /// Default minimum stake for weights.
+    /// Default minimum stake for setting weights.
     pub fn DefaultWeightsMinStake<T: Config>() -> u64 {
         0
     }
@@ -552,13 +867,18 @@ pub mod pallet {
     }
     #[pallet::type_value]
     /// Value definition for bonds with type vector of (u16, u16).
-    pub fn DefaultBonds<T: Config>() -> Vec<(u16, u16)> {
-        vec![]
+    pub fn DefaultBonds<T: Config>() -> WeightsRow {
+        WeightsRow::new()
     }
     #[pallet::type_value]
     /// Value definition for weights with vector of (u16, u16).
-    pub fn DefaultWeights<T: Config>() -> Vec<(u16, u16)> {
-        vec![]
+    pub fn DefaultWeights<T: Config>() -> WeightsRow {
+        WeightsRow::new()
+    }
+    #[pallet::type_value]
+    /// Default number of weights a validator can set on a subnet, held to its max allowed uids.
+    pub fn DefaultMaxWeightsLimitLen<T: Config>() -> u16 {
+        u16::MAX
     }
     #[pallet::type_value]
     /// Default value for key with type T::AccountId derived from trailing zeroes.
@@ -607,11 +927,76 @@ pub mod pallet {
         false
     }
     #[pallet::type_value]
+    /// Default value for stake transfers between coldkeys on a subnet.
+    pub fn DefaultTransferStakeEnabled<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
+    /// Default value for requiring a commitment before a uid can be weighted.
+    pub fn DefaultRequireCommitmentForWeights<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
+    /// Default value for rejecting, rather than zeroing, weights on uids without a commitment.
+    pub fn DefaultRejectUncommittedWeights<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
+    /// Default value for disallowing weights on uids owned by the coldkey of the validator.
+    pub fn DefaultDisallowSelfWeightByColdkey<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
+    /// Default value for requiring an invite of the subnet owner to register.
+    pub fn DefaultInviteOnlyRegistration<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
+    /// Default value for rejecting, rather than zeroing, weights on uids of the same coldkey.
+    pub fn DefaultRejectSelfWeightByColdkey<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
+    /// Default value for refunding part of the registration burn on deregistration.
+    pub fn DefaultRecycleRegistrationBurn<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
+    /// Default cap, in basis points, on the share of the registration burn paid to subnet owners.
+    pub fn DefaultMaxRegistrationBurnOwnerShare<T: Config>() -> u16 {
+        5_000
+    }
+    #[pallet::type_value]
+    /// Default emission multiplier of a subnet, which leaves its share of the block emission as is.
+    pub fn DefaultEmissionMultiplier<T: Config>() -> u32 {
+        EMISSION_MULTIPLIER_ONE
+    }
+    #[pallet::type_value]
+    /// Default minimum axon version of a subnet.
+    pub fn DefaultMinAxonVersion<T: Config>() -> u32 {
+        0
+    }
+    #[pallet::type_value]
+    /// Default value for the incentive written by an epoch without weights or active stake.
+    pub fn DefaultEmptyEpochUniformIncentive<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
+    /// Default value for validator governance of subnet parameters.
+    pub fn DefaultParamGovernanceEnabled<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
     /// Senate requirements
     pub fn DefaultSenateRequiredStakePercentage<T: Config>() -> u64 {
         T::InitialSenateRequiredStakePercentage::get()
     }
     #[pallet::type_value]
+    /// Default share of the senate stake, out of u16::MAX, that passes a stake-weighted proposal.
+    pub fn DefaultStakeWeightedVoteThreshold<T: Config>() -> u16 {
+        u16::MAX / 2 + 1
+    }
+    #[pallet::type_value]
     /// -- ITEM (switches liquid alpha on)
     pub fn DefaultLiquidAlpha<T: Config>() -> bool {
         false
@@ -637,6 +1022,87 @@ pub mod pallet {
     pub type ColdkeySwapScheduleDuration<T: Config> =
         StorageValue<_, BlockNumberFor<T>, ValueQuery, DefaultColdkeySwapScheduleDuration<T>>;
 
+    #[pallet::type_value]
+    /// Default value for coldkey swap redirect duration
+    pub fn DefaultColdkeySwapRedirectDuration<T: Config>() -> BlockNumberFor<T> {
+        T::InitialColdkeySwapRedirectDuration::get()
+    }
+
+    #[pallet::storage]
+    pub type ColdkeySwapRedirectDuration<T: Config> =
+        StorageValue<_, BlockNumberFor<T>, ValueQuery, DefaultColdkeySwapRedirectDuration<T>>;
+
+    #[pallet::type_value]
+    /// Default share of the maximum block weight, out of u16::MAX, a coldkey swap may declare.
+    pub fn DefaultColdkeySwapMaxWeightShare<T: Config>() -> u16 {
+        u16::MAX / 2
+    }
+
+    #[pallet::storage]
+    /// ITEM( coldkey_swap_max_weight_share ) | Share of the maximum block weight, out of u16::MAX, a coldkey swap may declare.
+    pub type ColdkeySwapMaxWeightShare<T> =
+        StorageValue<_, u16, ValueQuery, DefaultColdkeySwapMaxWeightShare<T>>;
+
+    #[pallet::type_value]
+    /// Default cost of swapping a key.
+    pub fn DefaultKeySwapCost<T: Config>() -> u64 {
+        T::KeySwapCost::get()
+    }
+
+    #[pallet::storage]
+    /// ITEM( key_swap_cost ) | Current cost of swapping a key, adjusted each KeySwapCostAdjustmentInterval.
+    pub type KeySwapCost<T> = StorageValue<_, u64, ValueQuery, DefaultKeySwapCost<T>>;
+
+    #[pallet::storage]
+    /// ITEM( key_swaps_this_interval ) | Number of key swaps since the last key swap cost adjustment.
+    pub type KeySwapsThisInterval<T> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::type_value]
+    /// Default number of key swaps per adjustment interval the key swap cost is adjusted toward.
+    pub fn DefaultTargetKeySwapsPerInterval<T: Config>() -> u64 {
+        10
+    }
+
+    #[pallet::storage]
+    /// ITEM( target_key_swaps_per_interval ) | Number of key swaps per adjustment interval the key swap cost is adjusted toward.
+    pub type TargetKeySwapsPerInterval<T> =
+        StorageValue<_, u64, ValueQuery, DefaultTargetKeySwapsPerInterval<T>>;
+
+    #[pallet::type_value]
+    /// Default number of blocks between two key swap cost adjustments.
+    pub fn DefaultKeySwapCostAdjustmentInterval<T: Config>() -> u64 {
+        7_200
+    }
+
+    #[pallet::storage]
+    /// ITEM( key_swap_cost_adjustment_interval ) | Number of blocks between two key swap cost adjustments.
+    pub type KeySwapCostAdjustmentInterval<T> =
+        StorageValue<_, u64, ValueQuery, DefaultKeySwapCostAdjustmentInterval<T>>;
+
+    #[pallet::storage]
+    /// ITEM( last_key_swap_cost_adjustment_block ) | Block of the last key swap cost adjustment.
+    pub type LastKeySwapCostAdjustmentBlock<T> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::type_value]
+    /// Default minimum cost of swapping a key.
+    pub fn DefaultMinKeySwapCost<T: Config>() -> u64 {
+        100_000_000
+    }
+
+    #[pallet::storage]
+    /// ITEM( min_key_swap_cost ) | Minimum cost of swapping a key.
+    pub type MinKeySwapCost<T> = StorageValue<_, u64, ValueQuery, DefaultMinKeySwapCost<T>>;
+
+    #[pallet::type_value]
+    /// Default maximum cost of swapping a key.
+    pub fn DefaultMaxKeySwapCost<T: Config>() -> u64 {
+        100_000_000_000
+    }
+
+    #[pallet::storage]
+    /// ITEM( max_key_swap_cost ) | Maximum cost of swapping a key.
+    pub type MaxKeySwapCost<T> = StorageValue<_, u64, ValueQuery, DefaultMaxKeySwapCost<T>>;
+
     #[pallet::type_value]
     /// Default value for dissolve network schedule duration
     pub fn DefaultDissolveNetworkScheduleDuration<T: Config>() -> BlockNumberFor<T> {
@@ -650,6 +1116,24 @@ pub mod pallet {
     #[pallet::storage]
     pub type SenateRequiredStakePercentage<T> =
         StorageValue<_, u64, ValueQuery, DefaultSenateRequiredStakePercentage<T>>;
+    #[pallet::storage]
+    /// --- ITEM ( index of the first senate proposal tallied by stake, none when disabled )
+    pub type StakeWeightedVotingFrom<T> = StorageValue<_, u32, OptionQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( share of the senate stake, out of u16::MAX, that passes a stake-weighted proposal )
+    pub type StakeWeightedVoteThreshold<T> =
+        StorageValue<_, u16, ValueQuery, DefaultStakeWeightedVoteThreshold<T>>;
+    #[pallet::storage]
+    /// --- DMAP ( proposal, hotkey ) --> ( approve, stake ) | Stake-weighted senate votes, with the stake at the vote.
+    pub type StakeWeightedVotes<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        T::Hash,
+        Blake2_128Concat,
+        T::AccountId,
+        (bool, u64),
+        OptionQuery,
+    >;
 
     /// ============================
     /// ==== Staking Variables ====
@@ -667,8 +1151,25 @@ pub mod pallet {
     pub type TotalIssuance<T> = StorageValue<_, u64, ValueQuery, DefaultTotalIssuance<T>>;
     #[pallet::storage] // --- ITEM ( total_stake )
     pub type TotalStake<T> = StorageValue<_, u64, ValueQuery>;
-    #[pallet::storage] // --- ITEM ( default_delegate_take )
-    pub type MaxDelegateTake<T> = StorageValue<_, u16, ValueQuery, DefaultDelegateTake<T>>;
+    #[pallet::storage] // --- ITEM ( total_burned_by_users )
+    pub type TotalBurnedByUsers<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- MAP ( cold ) --> burned | Returns the total amount of TAO the coldkey ever burned.
+    pub type ColdkeyLifetimeBurn<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( max_delegate_take )
+    pub type MaxDelegateTake<T> = StorageValue<_, u16, ValueQuery, DefaultInitialDelegateTake<T>>;
+    #[pallet::storage] // --- ITEM ( default_delegate_take ) | The take given by become_delegate.
+    pub type DefaultDelegateTake<T> =
+        StorageValue<_, u16, ValueQuery, DefaultInitialDelegateTake<T>>;
+    #[pallet::storage] // --- ITEM ( min_owner_stake_to_delegate ) | The owner stake a delegate needs to accept nominations.
+    pub type MinOwnerStakeToDelegate<T> =
+        StorageValue<_, u64, ValueQuery, DefaultMinOwnerStakeToDelegate<T>>;
+    #[pallet::storage] // --- ITEM ( stake_fee_discount_thresholds ) | The total coldkey stake each fee discount tier starts at.
+    pub type StakeFeeDiscountThresholds<T> =
+        StorageValue<_, [u64; 3], ValueQuery, DefaultStakeFeeDiscountThresholds<T>>;
+    #[pallet::storage] // --- MAP ( cold ) --> tier | The fee discount tier of the coldkey, updated when its stake crosses a threshold.
+    pub type ColdkeyFeeDiscountTier<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u8, ValueQuery>;
     #[pallet::storage] // --- ITEM ( min_delegate_take )
     pub type MinDelegateTake<T> = StorageValue<_, u16, ValueQuery, DefaultMinDelegateTake<T>>;
     #[pallet::storage] // --- ITEM ( default_childkey_take )
@@ -702,13 +1203,40 @@ pub mod pallet {
         DefaultStakesPerInterval<T>,
     >;
     #[pallet::storage]
+    /// MAP (cold) --> ops | Returns a tuple (u64: stake operations over all hotkeys, u64: block_number)
+    pub type ColdkeyStakeOpsThisInterval<T: Config> = StorageMap<
+        _,
+        Identity,
+        T::AccountId,
+        (u64, u64),
+        ValueQuery,
+        DefaultStakesPerInterval<T>,
+    >;
+    #[pallet::storage] // --- ITEM (max_stake_ops_per_interval_per_coldkey)
+    pub type MaxStakeOpsPerIntervalPerColdkey<T> =
+        StorageValue<_, u64, ValueQuery, DefaultMaxStakeOpsPerIntervalPerColdkey<T>>;
+    #[pallet::storage]
     /// MAP ( hot ) --> cold | Returns the controlling coldkey for a hotkey.
     pub type Owner<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, ValueQuery, DefaultAccount<T>>;
     #[pallet::storage]
     /// MAP ( hot ) --> take | Returns the hotkey delegation take. And signals that this key is open for delegation.
-    pub type Delegates<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery, DefaultDelegateTake<T>>;
+    pub type Delegates<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u16,
+        ValueQuery,
+        DefaultInitialDelegateTake<T>,
+    >;
+    #[pallet::storage]
+    /// MAP ( hot ) --> take | Returns the increased take applied at the next drain of the hotkey emission.
+    pub type PendingDelegateTake<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u16, OptionQuery>;
+    #[pallet::storage]
+    /// MAP ( hot ) --> Vec<( block, old_take, new_take )> | The last delegate take changes, oldest first.
+    pub type TakeHistory<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<(u64, u16, u16)>, ValueQuery>;
     #[pallet::storage]
     /// DMAP ( hot, netuid ) --> take | Returns the hotkey childkey take for a specific subnet
     pub type ChildkeyTake<T: Config> = StorageDoubleMap<
@@ -720,6 +1248,52 @@ pub mod pallet {
         u16, // Value: take
         ValueQuery,
     >;
+    #[pallet::storage]
+    /// DMAP ( hot, netuid ) --> emission | Emission credited to the hotkey on a subnet in the last epoch it was credited.
+    pub type EmissionLastTempo<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // First key: hotkey
+        Identity,
+        u16, // Second key: netuid
+        u64, // Value: emission
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// DMAP ( hot, netuid ) --> emission | Total emission ever credited to the hotkey on a subnet.
+    pub type EmissionLifetime<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // First key: hotkey
+        Identity,
+        u16, // Second key: netuid
+        u64, // Value: emission
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// MAP ( cold ) --> ( auth_key, threshold_amount ) | Auth key that must approve the coldkey's swaps and unstakes above the threshold.
+    pub type ColdkeyAuth<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (T::AccountId, u64), OptionQuery>;
+    #[pallet::storage]
+    /// DMAP ( cold, call_hash ) --> block | Block at which the auth key approved a call of the coldkey.
+    pub type ColdkeyAuthApprovals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // First key: coldkey
+        Identity,
+        T::Hash, // Second key: call hash
+        u64,     // Value: approval block
+        OptionQuery,
+    >;
+    #[pallet::type_value]
+    /// Default number of blocks an auth key approval stays valid.
+    pub fn DefaultColdkeyAuthApprovalWindow<T: Config>() -> u64 {
+        7200
+    }
+    #[pallet::storage]
+    /// ITEM( coldkey_auth_approval_window ) | Number of blocks an auth key approval stays valid.
+    pub type ColdkeyAuthApprovalWindow<T> =
+        StorageValue<_, u64, ValueQuery, DefaultColdkeyAuthApprovalWindow<T>>;
 
     #[pallet::storage]
     /// DMAP ( hot, cold ) --> stake | Returns the stake under a coldkey prefixed by hotkey.
@@ -734,6 +1308,10 @@ pub mod pallet {
         DefaultAccountTake<T>,
     >;
     #[pallet::storage]
+    /// --- MAP ( hot ) --> nominator_count | Number of coldkeys with a non-zero stake on the hotkey.
+    pub type NominatorCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+    #[pallet::storage]
     /// Map ( hot ) --> last_hotkey_emission_drain | Last block we drained this hotkey's emission.
     pub type LastHotkeyEmissionDrain<T: Config> = StorageMap<
         _,
@@ -758,6 +1336,40 @@ pub mod pallet {
         DefaultAccumulatedEmission<T>,
     >;
     #[pallet::storage]
+    /// ITEM ( hotkey_drain_queue ) | Hotkeys whose pending emission is due to be drained, in order.
+    pub type HotkeyDrainQueue<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, ConstU32<MAX_HOTKEY_DRAIN_QUEUE>>, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM ( hotkey_drain_cursor ) | Progress of the drain of the hotkey at the head of the drain queue.
+    pub type HotkeyDrainCursor<T: Config> = StorageValue<_, HotkeyDrainProgress, OptionQuery>;
+    #[pallet::storage]
+    /// MAP ( cold ) --> () | Coldkeys whose balance was drained, pruned in `on_idle` once they hold nothing.
+    pub type KilledColdkeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+    #[pallet::storage]
+    /// MAP ( cold ) --> hot | The hotkey the stake of the coldkey moves to when a hotkey it stakes on leaves every subnet.
+    pub type AutoRedelegate<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM ( auto_redelegate_after_tempos ) | Tempos a staked hotkey stays off every subnet before its nominators are handled.
+    pub type AutoRedelegateAfterTempos<T> =
+        StorageValue<_, u64, ValueQuery, DefaultAutoRedelegateAfterTempos<T>>;
+    #[pallet::storage]
+    /// ITEM ( inactive_hotkey_queue ) | Staked hotkeys off every subnet, with the block their nominators are handled from.
+    pub type InactiveHotkeyQueue<T: Config> =
+        StorageValue<_, Vec<(T::AccountId, u64)>, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM ( inactive_hotkey_cursor ) | The hotkey being handled and the raw Stake key its nominators resume after.
+    pub type InactiveHotkeyCursor<T: Config> =
+        StorageValue<_, (T::AccountId, Vec<u8>), OptionQuery>;
+    #[pallet::storage]
+    /// ITEM ( block_step_weight ) | Weight consumed by each stage of the block step in this block.
+    pub type BlockStepWeight<T> = StorageValue<_, Vec<(BlockStepStage, Weight)>, ValueQuery>;
+    #[pallet::storage]
+    /// MAP ( account ) --> CallFailureInfo | Detail of the last failed call of the account, overwritten by the next one.
+    pub type LastCallFailureInfo<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, CallFailureInfo, OptionQuery>;
+    #[pallet::storage]
     /// Map ( hot, cold ) --> block_number | Last add stake increase.
     pub type LastAddStakeIncrease<T: Config> = StorageDoubleMap<
         _,
@@ -793,17 +1405,119 @@ pub mod pallet {
         ValueQuery,
         DefaultAccountLinkage<T>,
     >;
+    #[pallet::storage]
+    /// DMAP ( netuid, parent ) --> (Vec<(proportion,child)>, effective_block) | Requested children not applied yet.
+    pub type PendingChildKeys<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        (Vec<(u64, T::AccountId)>, u64),
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// MAP ( effective_block ) --> Vec<(netuid, parent)> | The pending children requests that apply at a block.
+    pub type PendingChildKeysDue<T: Config> =
+        StorageMap<_, Identity, u64, Vec<(u16, T::AccountId)>, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( pending_child_key_cooldown )
+    pub type PendingChildKeyCooldown<T> =
+        StorageValue<_, u64, ValueQuery, DefaultPendingChildKeyCooldown<T>>;
+    #[pallet::storage] // --- NMAP ( parent, child, netuid ) --> allowed | Whether the parent may set weights for the child on the subnet.
+    pub type ChildWeightsPermission<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>, // parent
+            NMapKey<Blake2_128Concat, T::AccountId>, // child
+            NMapKey<Identity, u16>,                  // netuid
+        ),
+        bool,
+        ValueQuery,
+    >;
+    #[pallet::storage] // --- DMAP ( hot, cold ) --> mode | Where the emission of the coldkey's stake on the hotkey is paid.
+    pub type EmissionPayout<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        EmissionPayoutMode,
+        ValueQuery,
+    >;
     #[pallet::storage] // --- DMAP ( cold ) --> Vec<hot> | Maps coldkey to hotkeys that stake to it
     pub type StakingHotkeys<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
     #[pallet::storage] // --- MAP ( cold ) --> Vec<hot> | Returns the vector of hotkeys controlled by this coldkey.
-    pub type OwnedHotkeys<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+    pub type OwnedHotkeys<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, ConstU32<MAX_OWNED_HOTKEYS>>,
+        ValueQuery,
+    >;
+    #[pallet::type_value]
+    /// Default number of hotkeys a coldkey can own.
+    pub fn DefaultMaxHotkeysPerColdkey<T: Config>() -> u32 {
+        4_096
+    }
+    #[pallet::storage] // --- ITEM ( max_hotkeys_per_coldkey ) | Number of hotkeys a coldkey that is not exempt can own.
+    pub type MaxHotkeysPerColdkey<T> =
+        StorageValue<_, u32, ValueQuery, DefaultMaxHotkeysPerColdkey<T>>;
+    #[pallet::storage] // --- MAP ( cold ) --> () | Coldkeys exempt from MaxHotkeysPerColdkey.
+    pub type HotkeyLimitExempt<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
 
     #[pallet::storage] // --- DMAP ( cold ) --> () | Maps coldkey to if a coldkey swap is scheduled.
     pub type ColdkeySwapScheduled<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, (), ValueQuery>;
 
+    #[pallet::storage] // --- MAP ( cold ) --> new_cold | The destination of the coldkey swap scheduled for a coldkey.
+    pub type ColdkeySwapDestination<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> block | The last execution block of the network dissolutions scheduled by a coldkey.
+    pub type DissolveNetworkScheduledUntil<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> schedule | The vesting schedule locking the unvested balance of the coldkey.
+    pub type VestingSchedules<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, VestingScheduleInfo, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> attestation | The latest payload attested by the coldkey.
+    pub type ColdkeyAttestations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ColdkeyAttestationInfo, OptionQuery>;
+
+    #[pallet::storage] // --- ITEM ( attestation_deposit ) | The deposit withdrawn from a coldkey for its attestation.
+    pub type AttestationDeposit<T> = StorageValue<_, u64, ValueQuery, DefaultAttestationDeposit<T>>;
+
+    #[pallet::storage] // --- ITEM ( attestation_rate_limit ) | The number of blocks between two attestations of a coldkey.
+    pub type AttestationRateLimit<T> =
+        StorageValue<_, u64, ValueQuery, DefaultAttestationRateLimit<T>>;
+
+    #[pallet::storage] // --- DMAP ( netuid, hot ) --> snapshot | The stake of the nominators of the hotkey recorded to compensate them.
+    pub type NominatorSnapshots<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        NominatorSnapshotInfo<T::AccountId>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage] // --- MAP ( old_cold ) --> ( new_cold, expiry ) | The coldkey a swapped-away coldkey is swept to, until the expiry block.
+    pub type ColdkeySwapRedirect<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::AccountId, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- MAP ( cold ) --> enabled | Whether a swap of the coldkey records a redirect of its balance.
+    pub type ColdkeySwapRedirectEnabled<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
     /// ============================
     /// ==== Global Parameters =====
     /// ============================
@@ -818,9 +1532,16 @@ pub mod pallet {
     /// --- ITEM( maximum_number_of_networks )
     pub type SubnetLimit<T> = StorageValue<_, u16, ValueQuery, DefaultSubnetLimit<T>>;
     #[pallet::storage]
+    /// --- ITEM( subnet_limit_pruning ) | Whether a registration at the subnet limit prunes a subnet, rather than failing.
+    pub type SubnetLimitPruning<T> =
+        StorageValue<_, bool, ValueQuery, DefaultSubnetLimitPruning<T>>;
+    #[pallet::storage]
     /// --- ITEM( total_number_of_existing_networks )
     pub type TotalNetworks<T> = StorageValue<_, u16, ValueQuery>;
     #[pallet::storage]
+    /// --- ITEM( total_number_of_registered_neurons )
+    pub type TotalNeuronCount<T> = StorageValue<_, u32, ValueQuery>;
+    #[pallet::storage]
     /// ITEM( network_immunity_period )
     pub type NetworkImmunityPeriod<T> =
         StorageValue<_, u64, ValueQuery, DefaultNetworkImmunityPeriod<T>>;
@@ -853,6 +1574,9 @@ pub mod pallet {
     /// ITEM( nominator_min_required_stake )
     pub type NominatorMinRequiredStake<T> =
         StorageValue<_, u64, ValueQuery, DefaultNominatorMinRequiredStake<T>>;
+    #[pallet::storage]
+    /// ITEM( staking_operations_paused ) | Whether staking and key swaps are paused chain-wide.
+    pub type StakingOperationsPaused<T> = StorageValue<_, bool, ValueQuery>;
 
     /// ============================
     /// ==== Subnet Parameters =====
@@ -899,6 +1623,19 @@ pub mod pallet {
     pub type EmissionValues<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultEmissionValues<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> block | First registration adjustment the subnet was seen without emission.
+    pub type ZeroEmissionSince<T> = StorageMap<_, Identity, u16, u64, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> warning | Whether the subnet has been without emission for SubnetWarningTempos.
+    pub type SubnetWarning<T> = StorageMap<_, Identity, u16, bool, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( subnet_warning_tempos ) | Tempos without emission before a subnet is flagged.
+    pub type SubnetWarningTempos<T> =
+        StorageValue<_, u64, ValueQuery, DefaultSubnetWarningTempos<T>>;
+    #[pallet::storage]
+    /// ITEM( subnet_warning_blocks_registration ) | Whether flagged subnets refuse registrations.
+    pub type SubnetWarningBlocksRegistration<T> = StorageValue<_, bool, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> pending_emission
     pub type PendingEmission<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultPendingEmission<T>>;
@@ -915,6 +1652,10 @@ pub mod pallet {
     pub type SubnetOwner<T: Config> =
         StorageMap<_, Identity, u16, T::AccountId, ValueQuery, DefaultSubnetOwner<T>>;
     #[pallet::storage]
+    /// --- MAP ( cold ) --> count | Number of SubnetOwner entries held by the coldkey.
+    pub type OwnedSubnetCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> subnet_locked
     pub type SubnetLocked<T: Config> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultSubnetLocked<T>>;
@@ -959,6 +1700,10 @@ pub mod pallet {
     pub type MaxWeightsLimit<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxWeightsLimit<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> max number of weights a validator can set
+    pub type MaxWeightsLimitLen<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxWeightsLimitLen<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> weights_version_key
     pub type WeightsVersionKey<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultWeightsVersionKey<T>>;
@@ -1031,6 +1776,10 @@ pub mod pallet {
     pub type LastAdjustmentBlock<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultLastAdjustmentBlock<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> Vec<( block, burn, difficulty )> | The last registration adjustments, oldest first.
+    pub type RegistrationAdjustmentHistory<T> =
+        StorageMap<_, Identity, u16, Vec<(u64, u64, u64)>, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> Registrations of this Block.
     pub type RegistrationsThisBlock<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultRegistrationsThisBlock<T>>;
@@ -1050,6 +1799,9 @@ pub mod pallet {
     pub type TxChildkeyTakeRateLimit<T> =
         StorageValue<_, u64, ValueQuery, DefaultTxChildKeyTakeRateLimit<T>>;
     #[pallet::storage]
+    /// --- ITEM ( owner_hyperparam_rate_limit ) | Blocks between hyperparameter updates by a subnet owner.
+    pub type OwnerHyperparamRateLimit<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> Whether or not Liquid Alpha is enabled
     pub type LiquidAlphaOn<T> =
         StorageMap<_, Blake2_128Concat, u16, bool, ValueQuery, DefaultLiquidAlpha<T>>;
@@ -1057,6 +1809,79 @@ pub mod pallet {
     ///  MAP ( netuid ) --> (alpha_low, alpha_high)
     pub type AlphaValues<T> =
         StorageMap<_, Identity, u16, (u16, u16), ValueQuery, DefaultAlphaValues<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether hyperparameter changes must be ratified by the subnet validators
+    pub type ParamGovernanceEnabled<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultParamGovernanceEnabled<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether stake on the subnet's hotkeys can be transferred between coldkeys
+    pub type TransferStakeEnabled<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultTransferStakeEnabled<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether weights need a commitment of the weighted hotkey on the subnet
+    pub type RequireCommitmentForWeights<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultRequireCommitmentForWeights<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether weights on uncommitted hotkeys are rejected instead of zeroed
+    pub type RejectUncommittedWeights<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultRejectUncommittedWeights<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether weights on uids whose hotkey shares the coldkey of the validator are disallowed
+    pub type DisallowSelfWeightByColdkey<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultDisallowSelfWeightByColdkey<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether weights on uids of the same coldkey are rejected instead of zeroed
+    pub type RejectSelfWeightByColdkey<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultRejectSelfWeightByColdkey<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether part of the registration burn is refunded on voluntary deregistration
+    pub type RecycleRegistrationBurn<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultRecycleRegistrationBurn<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Share of the registration burn, in basis points, paid to the subnet owner
+    pub type RegistrationBurnOwnerShare<T> = StorageMap<_, Identity, u16, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( cap on the registration burn owner share of every subnet, in basis points )
+    pub type MaxRegistrationBurnOwnerShare<T> =
+        StorageValue<_, u16, ValueQuery, DefaultMaxRegistrationBurnOwnerShare<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> The factor, in millionths, the share of the subnet in the block emission is scaled by
+    pub type EmissionMultiplier<T> =
+        StorageMap<_, Identity, u16, u32, ValueQuery, DefaultEmissionMultiplier<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether the block emission of the subnet is withheld pending governance review
+    pub type SubnetEmissionPaused<T> = StorageMap<_, Identity, u16, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> The block emission withheld from the subnet while paused, until it is released or burned
+    pub type WithheldEmission<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> The minimum version an axon must serve with on the subnet
+    pub type MinAxonVersion<T> =
+        StorageMap<_, Identity, u16, u32, ValueQuery, DefaultMinAxonVersion<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether an epoch without weights or active stake writes a uniform incentive instead of zero
+    pub type EmptyEpochUniformIncentive<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultEmptyEpochUniformIncentive<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> The number of epochs kept in the epoch history of the subnet, 0 disables it
+    pub type EpochHistoryDepth<T> = StorageMap<_, Identity, u16, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> The slot of the epoch history the next epoch summary is written to
+    pub type EpochHistoryNextSlot<T> = StorageMap<_, Identity, u16, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, slot ) --> The epoch summary written to the slot of the epoch history
+    pub type EpochHistory<T> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, EpochSummary, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> The epoch index the next epoch commitment of the subnet is written under
+    pub type EpochCommitmentNextIndex<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, epoch_index ) --> The merkle commitment over the outcome of the epoch, for the last EPOCH_COMMITMENT_DEPTH epochs
+    pub type EpochCommitment<T> =
+        StorageDoubleMap<_, Identity, u16, Identity, u64, EpochCommitmentInfo, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> The drop in validator trust over an epoch that is reported as degraded, 0 disables it
+    pub type ValidatorHealthDropThreshold<T> = StorageMap<_, Identity, u16, u16, ValueQuery>;
     /// MAP ( netuid ) --> max stake allowed on a subnet.
     #[pallet::storage]
     pub type NetworkMaxStake<T> =
@@ -1119,6 +1944,14 @@ pub mod pallet {
     pub type PruningScores<T: Config> =
         StorageMap<_, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> pruning_score_source | Scores the pruning selection of the subnet uses.
+    pub type PruningScoreSources<T: Config> =
+        StorageMap<_, Identity, u16, PruningScoreSource, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> custom_pruning_scores | Pruning scores submitted by the subnet owner.
+    pub type CustomPruningScores<T: Config> =
+        StorageMap<_, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
+    #[pallet::storage]
     /// --- DMAP ( netuid ) --> validator_permit
     pub type ValidatorPermit<T: Config> =
         StorageMap<_, Identity, u16, Vec<bool>, ValueQuery, EmptyBoolVec<T>>;
@@ -1130,7 +1963,7 @@ pub mod pallet {
         u16,
         Identity,
         u16,
-        Vec<(u16, u16)>,
+        WeightsRow,
         ValueQuery,
         DefaultWeights<T>,
     >;
@@ -1142,7 +1975,7 @@ pub mod pallet {
         u16,
         Identity,
         u16,
-        Vec<(u16, u16)>,
+        WeightsRow,
         ValueQuery,
         DefaultBonds<T>,
     >;
@@ -1159,6 +1992,37 @@ pub mod pallet {
         DefaultBlockAtRegistration<T>,
     >;
     #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> burn paid to register the uid
+    pub type RegistrationBurn<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Whether registering on the subnet requires an invite signed by its owner
+    pub type InviteOnlyRegistration<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultInviteOnlyRegistration<T>>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, ( hotkey, expiry_block ) ) --> Whether the invite of the subnet owner has been used
+    pub type UsedInvites<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        (T::AccountId, u64),
+        bool,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( expiry_block ) --> Vec<(netuid, hotkey)> | The used invites that expire at a block, pruned after it
+    pub type UsedInvitesByExpiry<T: Config> =
+        StorageMap<_, Identity, u64, Vec<(u16, T::AccountId)>, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( coldkey ) --> nonce the next registration signed by the coldkey on its behalf must use
+    pub type RegistrationNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( hotkey ) --> nonce the next association signed by the hotkey must use
+    pub type AssociationNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid, hotkey ) --> axon_info
     pub type Axons<T: Config> =
         StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, AxonInfoOf, OptionQuery>;
@@ -1196,21 +2060,24 @@ pub mod pallet {
         ValueQuery,
     >;
     #[pallet::storage]
-    /// --- MAP ( key ) --> last_block
-    pub type LastTxBlock<T: Config> =
-        StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultLastTxBlock<T>>;
-    #[pallet::storage]
-    /// --- MAP ( key ) --> last_tx_block_childkey_take
-    pub type LastTxBlockChildKeyTake<T: Config> =
-        StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultLastTxBlock<T>>;
-    #[pallet::storage]
-    /// --- MAP ( key ) --> last_tx_block_delegate_take
-    pub type LastTxBlockDelegateTake<T: Config> =
-        StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultLastTxBlock<T>>;
+    /// --- DMAP ( operation, key ) --> last_block | The last block the key ran the operation in.
+    pub type LastRateLimitedBlock<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RateLimitedOperation,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery,
+        DefaultLastTxBlock<T>,
+    >;
     #[pallet::storage]
     /// ITEM( weights_min_stake )
     pub type WeightsMinStake<T> = StorageValue<_, u64, ValueQuery, DefaultWeightsMinStake<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> stake_threshold | Per-subnet override of WeightsMinStake.
+    pub type SubnetStakeThreshold<T> = StorageMap<_, Identity, u16, u64, OptionQuery>;
+    #[pallet::storage]
     /// --- MAP (netuid, who) --> (hash, weight) | Returns the hash and weight committed by an account for a given netuid.
     pub type WeightCommits<T: Config> = StorageDoubleMap<
         _,
@@ -1221,6 +2088,23 @@ pub mod pallet {
         (H256, u64),
         OptionQuery,
     >;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> deposit | The deposit held for the pending weights commit of a hotkey.
+    pub type WeightCommitDeposits<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, T::AccountId, u64, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( weight_commit_deposit )
+    pub type WeightCommitDeposit<T> =
+        StorageValue<_, u64, ValueQuery, DefaultWeightCommitDeposit<T>>;
+    #[pallet::storage]
+    /// ITEM( weight_commits_prune_cursor ) | The raw WeightCommits key the expired commits sweep resumes after.
+    pub type WeightCommitsPruneCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( raw key of the weights row the weights bounding migration resumes from )
+    pub type WeightsBoundCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( raw key of the staking hotkeys the deduplication migration resumes from )
+    pub type StakingHotkeysDedupCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
 
     /// ==================
     /// ==== Genesis =====
@@ -1259,10 +2143,16 @@ pub mod pallet {
             0
         }
 
-        /// Is the caller allowed to set weights
-        pub fn check_weights_min_stake(hotkey: &T::AccountId) -> bool {
+        /// Is the caller allowed to set weights on the passed network
+        pub fn check_weights_min_stake(hotkey: &T::AccountId, netuid: u16) -> bool {
+            // Validator permits are only granted to the top-k stake holders, no need to check further.
+            if let Ok(uid) = Self::get_uid_for_net_and_hotkey(netuid, hotkey) {
+                if Self::get_validator_permit_for_uid(netuid, uid) {
+                    return true;
+                }
+            }
             // Blacklist weights transactions for low stake peers.
-            Self::get_total_stake_for_hotkey(hotkey) >= Self::get_weights_min_stake()
+            Self::get_total_stake_for_hotkey(hotkey) >= Self::get_stake_threshold_for_subnet(netuid)
         }
 
         /// Helper function to check if register is allowed
@@ -1310,12 +2200,16 @@ pub enum CallType {
 #[derive(Debug, PartialEq)]
 pub enum CustomTransactionError {
     ColdkeyInSwapSchedule,
+    CallNotAuthorizable,
+    ChildWeightsNotPermitted,
 }
 
 impl From<CustomTransactionError> for u8 {
     fn from(variant: CustomTransactionError) -> u8 {
         match variant {
             CustomTransactionError::ColdkeyInSwapSchedule => 0,
+            CustomTransactionError::CallNotAuthorizable => 6,
+            CustomTransactionError::ChildWeightsNotPermitted => 7,
         }
     }
 }
@@ -1355,8 +2249,8 @@ where
         Pallet::<T>::get_priority_set_weights(who, netuid)
     }
 
-    pub fn check_weights_min_stake(who: &T::AccountId) -> bool {
-        Pallet::<T>::check_weights_min_stake(who)
+    pub fn check_weights_min_stake(who: &T::AccountId, netuid: u16) -> bool {
+        Pallet::<T>::check_weights_min_stake(who, netuid)
     }
 }
 
@@ -1379,7 +2273,7 @@ where
     type AccountId = T::AccountId;
     type Call = <T as frame_system::Config>::RuntimeCall;
     type AdditionalSigned = ();
-    type Pre = (CallType, u64, Self::AccountId);
+    type Pre = (CallType, u64, Self::AccountId, Option<Call<T>>);
 
     fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
         Ok(())
@@ -1389,12 +2283,22 @@ where
         &self,
         who: &Self::AccountId,
         call: &Self::Call,
-        _info: &DispatchInfoOf<Self::Call>,
-        _len: usize,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
     ) -> TransactionValidity {
         match call.is_sub_type() {
+            Some(Call::authorized_call { coldkey, call }) => {
+                // The call dispatched as the coldkey is validated as if the coldkey signed it.
+                if !Pallet::<T>::is_authorizable_call(call) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::CallNotAuthorizable.into(),
+                    )
+                    .into();
+                }
+                self.validate(coldkey, (**call).into_ref(), info, len)
+            }
             Some(Call::commit_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who) {
+                if Self::check_weights_min_stake(who, *netuid) {
                     let priority: u64 = Self::get_priority_set_weights(who, *netuid);
                     Ok(ValidTransaction {
                         priority,
@@ -1406,7 +2310,7 @@ where
                 }
             }
             Some(Call::reveal_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who) {
+                if Self::check_weights_min_stake(who, *netuid) {
                     let priority: u64 = Self::get_priority_set_weights(who, *netuid);
                     Ok(ValidTransaction {
                         priority,
@@ -1418,7 +2322,7 @@ where
                 }
             }
             Some(Call::set_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who) {
+                if Self::check_weights_min_stake(who, *netuid) {
                     let priority: u64 = Self::get_priority_set_weights(who, *netuid);
                     Ok(ValidTransaction {
                         priority,
@@ -1429,8 +2333,34 @@ where
                     Err(InvalidTransaction::Custom(3).into())
                 }
             }
+            Some(Call::set_weights_for_child {
+                child_hotkey,
+                netuid,
+                ..
+            }) => {
+                // The call pays no fee, so a signer that is not a permitted parent is kept out of
+                // the pool.
+                if Pallet::<T>::ensure_parent_may_set_child_weights(who, child_hotkey, *netuid)
+                    .is_err()
+                {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::ChildWeightsNotPermitted.into(),
+                    )
+                    .into();
+                }
+                if Self::check_weights_min_stake(child_hotkey, *netuid) {
+                    let priority: u64 = Self::get_priority_set_weights(child_hotkey, *netuid);
+                    Ok(ValidTransaction {
+                        priority,
+                        longevity: 1,
+                        ..Default::default()
+                    })
+                } else {
+                    Err(InvalidTransaction::Custom(3).into())
+                }
+            }
             Some(Call::set_root_weights { netuid, hotkey, .. }) => {
-                if Self::check_weights_min_stake(hotkey) {
+                if Self::check_weights_min_stake(hotkey, *netuid) {
                     let priority: u64 = Self::get_priority_set_weights(hotkey, *netuid);
                     Ok(ValidTransaction {
                         priority,
@@ -1449,7 +2379,12 @@ where
                 priority: Self::get_priority_vanilla(),
                 ..Default::default()
             }),
-            Some(Call::register { netuid, .. } | Call::burned_register { netuid, .. }) => {
+            Some(
+                Call::register { netuid, .. }
+                | Call::burned_register { netuid, .. }
+                | Call::burned_register_by_proxy { netuid, .. }
+                | Call::burned_register_with_invite { netuid, .. },
+            ) => {
                 let registrations_this_interval =
                     Pallet::<T>::get_registrations_this_interval(*netuid);
                 let max_registrations_per_interval =
@@ -1468,7 +2403,7 @@ where
                 priority: Self::get_priority_vanilla(),
                 ..Default::default()
             }),
-            Some(Call::dissolve_network { .. }) => {
+            Some(Call::dissolve_network { .. } | Call::burn_tao { .. }) => {
                 if ColdkeySwapScheduled::<T>::contains_key(who) {
                     InvalidTransaction::Custom(CustomTransactionError::ColdkeyInSwapSchedule.into())
                         .into()
@@ -1480,6 +2415,8 @@ where
                 }
             }
             _ => {
+                // Only calls signed by the coldkey itself are caught here. Calls wrapped in a
+                // multisig or proxy are signed by another account and need a dispatch-time check.
                 if let Some(
                     BalancesCall::transfer_keep_alive { .. }
                     | BalancesCall::transfer_all { .. }
@@ -1510,41 +2447,65 @@ where
         _len: usize,
     ) -> Result<Self::Pre, TransactionValidityError> {
         match call.is_sub_type() {
-            Some(Call::add_stake { .. }) => {
+            Some(call @ Call::add_stake { .. }) => {
                 let transaction_fee = 100000;
-                Ok((CallType::AddStake, transaction_fee, who.clone()))
+                Ok((
+                    CallType::AddStake,
+                    transaction_fee,
+                    who.clone(),
+                    Some(call.clone()),
+                ))
             }
-            Some(Call::remove_stake { .. }) => {
+            Some(call @ Call::remove_stake { .. }) => {
                 let transaction_fee = 0;
-                Ok((CallType::RemoveStake, transaction_fee, who.clone()))
+                Ok((
+                    CallType::RemoveStake,
+                    transaction_fee,
+                    who.clone(),
+                    Some(call.clone()),
+                ))
             }
-            Some(Call::set_weights { .. }) => {
+            Some(call @ Call::set_weights { .. }) => {
                 let transaction_fee = 0;
-                Ok((CallType::SetWeights, transaction_fee, who.clone()))
+                Ok((
+                    CallType::SetWeights,
+                    transaction_fee,
+                    who.clone(),
+                    Some(call.clone()),
+                ))
+            }
+            Some(Call::set_weights_for_child { .. }) => {
+                let transaction_fee = 0;
+                Ok((CallType::SetWeights, transaction_fee, who.clone(), None))
             }
             Some(Call::commit_weights { .. }) => {
                 let transaction_fee = 0;
-                Ok((CallType::SetWeights, transaction_fee, who.clone()))
+                Ok((CallType::SetWeights, transaction_fee, who.clone(), None))
             }
             Some(Call::reveal_weights { .. }) => {
                 let transaction_fee = 0;
-                Ok((CallType::SetWeights, transaction_fee, who.clone()))
+                Ok((CallType::SetWeights, transaction_fee, who.clone(), None))
             }
             Some(Call::register { .. }) => {
                 let transaction_fee = 0;
-                Ok((CallType::Register, transaction_fee, who.clone()))
+                Ok((CallType::Register, transaction_fee, who.clone(), None))
             }
             Some(Call::serve_axon { .. }) => {
                 let transaction_fee = 0;
-                Ok((CallType::Serve, transaction_fee, who.clone()))
+                Ok((CallType::Serve, transaction_fee, who.clone(), None))
             }
             Some(Call::register_network { .. }) => {
                 let transaction_fee = 0;
-                Ok((CallType::RegisterNetwork, transaction_fee, who.clone()))
+                Ok((
+                    CallType::RegisterNetwork,
+                    transaction_fee,
+                    who.clone(),
+                    None,
+                ))
             }
             _ => {
                 let transaction_fee = 0;
-                Ok((CallType::Other, transaction_fee, who.clone()))
+                Ok((CallType::Other, transaction_fee, who.clone(), None))
             }
         }
     }
@@ -1554,9 +2515,13 @@ where
         _info: &DispatchInfoOf<Self::Call>,
         _post_info: &PostDispatchInfoOf<Self::Call>,
         _len: usize,
-        _result: &dispatch::DispatchResult,
+        result: &dispatch::DispatchResult,
     ) -> Result<(), TransactionValidityError> {
-        if let Some((call_type, _transaction_fee, _who)) = maybe_pre {
+        if let Some((call_type, _transaction_fee, who, call)) = maybe_pre {
+            // The failed call is rolled back by now, so its detail is recorded against the state it was checked on.
+            if let (Err(error), Some(call)) = (result, call) {
+                Pallet::<T>::record_call_failure(&who, &call, error);
+            }
             match call_type {
                 CallType::SetWeights => {
                     log::debug!("Not Implemented!");
@@ -1639,6 +2604,52 @@ impl<T> MemberManagement<T> for () {
     }
 }
 
+/// Trait for notifying other pallets when neurons and subnets are removed or re-keyed
+pub trait OnNeuronRemoved<AccountId> {
+    /// The hotkey no longer holds a uid on the subnet
+    fn on_neuron_removed(netuid: u16, hotkey: &AccountId);
+
+    /// The subnet was dissolved along with all its neurons
+    fn on_subnet_removed(netuid: u16);
+
+    /// The uid of the old hotkey on the subnet moved to the new hotkey
+    fn on_hotkey_swapped(netuid: u16, old_hotkey: &AccountId, new_hotkey: &AccountId);
+}
+
+impl<T> OnNeuronRemoved<T> for () {
+    fn on_neuron_removed(_: u16, _: &T) {}
+
+    fn on_subnet_removed(_: u16) {}
+
+    fn on_hotkey_swapped(_: u16, _: &T, _: &T) {}
+}
+
+/// Trait for querying the commitments other pallets keep per neuron
+pub trait CommitmentQuery<AccountId> {
+    /// Whether the hotkey has a commitment on the subnet
+    fn has_commitment(netuid: u16, hotkey: &AccountId) -> bool;
+
+    /// The last block the hotkey set its commitment on the subnet in, if any
+    fn last_commitment_block(netuid: u16, hotkey: &AccountId) -> Option<u64>;
+
+    /// The number of blocks between two commitments of a hotkey on the subnet
+    fn commitment_rate_limit(netuid: u16) -> u64;
+}
+
+impl<T> CommitmentQuery<T> for () {
+    fn has_commitment(_: u16, _: &T) -> bool {
+        false
+    }
+
+    fn last_commitment_block(_: u16, _: &T) -> Option<u64> {
+        None
+    }
+
+    fn commitment_rate_limit(_: u16) -> u64 {
+        0
+    }
+}
+
 /// Trait for interacting with collective pallets
 pub trait CollectiveInterface<AccountId, Hash, ProposalIndex> {
     /// Remove vote
@@ -1650,6 +2661,18 @@ pub trait CollectiveInterface<AccountId, Hash, ProposalIndex> {
         index: ProposalIndex,
         approve: bool,
     ) -> Result<bool, DispatchError>;
+
+    /// Index of the proposal, if it is still open
+    fn proposal_index(proposal: Hash) -> Option<ProposalIndex>;
+
+    /// Close the proposal with an outcome tallied by stake outside of the collective
+    fn close_with_outcome(
+        proposal: Hash,
+        index: ProposalIndex,
+        approved: bool,
+        yes: u32,
+        no: u32,
+    ) -> DispatchResultWithPostInfo;
 }
 
 impl<T, H, P> CollectiveInterface<T, H, P> for () {
@@ -1660,4 +2683,12 @@ impl<T, H, P> CollectiveInterface<T, H, P> for () {
     fn add_vote(_: &T, _: H, _: P, _: bool) -> Result<bool, DispatchError> {
         Ok(true)
     }
+
+    fn proposal_index(_: H) -> Option<P> {
+        None
+    }
+
+    fn close_with_outcome(_: H, _: P, _: bool, _: u32, _: u32) -> DispatchResultWithPostInfo {
+        Ok(().into())
+    }
 }
@@ -12,8 +12,10 @@ mod config {
         type RuntimeCall: Parameter
             + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
             + From<Call<Self>>
+            + IsSubType<Call<Self>>
             + IsType<<Self as frame_system::Config>::RuntimeCall>
-            + From<frame_system::Call<Self>>;
+            + From<frame_system::Call<Self>>
+            + GetDispatchInfo;
 
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -30,23 +32,48 @@ mod config {
         type Currency: fungible::Balanced<Self::AccountId, Balance = u64>
             + fungible::Mutate<Self::AccountId>;
 
+        /// Currency the vesting schedules lock the unvested balance of a coldkey with.
+        type VestingCurrency: LockableCurrency<
+            Self::AccountId,
+            Balance = u64,
+            Moment = BlockNumberFor<Self>,
+        >;
+
         /// Senate members with members management functions.
         type SenateMembers: crate::MemberManagement<Self::AccountId>;
 
         /// Interface to allow other pallets to control who can register identities
         type TriumvirateInterface: crate::CollectiveInterface<Self::AccountId, Self::Hash, u32>;
 
+        /// Handler cleaning up or moving the data other pallets keep per neuron when it is removed
+        /// or its hotkey is swapped.
+        type OnNeuronRemoved: crate::OnNeuronRemoved<Self::AccountId>;
+
+        /// Commitments of the neurons, checked before weights are set on subnets requiring them.
+        type Commitments: crate::CommitmentQuery<Self::AccountId>;
+
         /// The scheduler type used for scheduling delayed calls.
         type Scheduler: ScheduleAnon<
-            BlockNumberFor<Self>,
-            LocalCallOf<Self>,
-            PalletsOriginOf<Self>,
-            Hasher = Self::Hashing,
-        >;
+                BlockNumberFor<Self>,
+                LocalCallOf<Self>,
+                PalletsOriginOf<Self>,
+                Hasher = Self::Hashing,
+            > + ScheduleNamed<
+                BlockNumberFor<Self>,
+                LocalCallOf<Self>,
+                PalletsOriginOf<Self>,
+                Hasher = Self::Hashing,
+            >;
 
         /// the preimage to store the call data.
         type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
 
+        /// The signature a coldkey authorizes a registration submitted on its behalf with.
+        type CallSignature: Parameter + Verify<Signer = Self::CallSigner>;
+
+        /// The public key verifying a `CallSignature`, identifying the account that signed it.
+        type CallSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
         /// =================================
         /// ==== Initial Value Constants ====
         /// =================================
@@ -186,7 +213,7 @@ mod config {
         /// Initial target stakes per interval issuance.
         #[pallet::constant]
         type InitialTargetStakesPerInterval: Get<u64>;
-        /// Cost of swapping a hotkey.
+        /// Initial cost of swapping a key.
         #[pallet::constant]
         type KeySwapCost: Get<u64>;
         /// The upper bound for the alpha parameter. Used for Liquid Alpha.
@@ -207,8 +234,17 @@ mod config {
         /// Coldkey swap schedule duartion.
         #[pallet::constant]
         type InitialColdkeySwapScheduleDuration: Get<BlockNumberFor<Self>>;
+        /// Duration a swapped-away coldkey can be swept to its new coldkey.
+        #[pallet::constant]
+        type InitialColdkeySwapRedirectDuration: Get<BlockNumberFor<Self>>;
         /// Dissolve network schedule duration
         #[pallet::constant]
         type InitialDissolveNetworkScheduleDuration: Get<BlockNumberFor<Self>>;
+        /// Initial number of blocks before requested children are applied.
+        #[pallet::constant]
+        type InitialPendingChildKeyCooldown: Get<u64>;
+        /// Initial deposit withdrawn from the owner coldkey for a weights commit.
+        #[pallet::constant]
+        type InitialWeightCommitDeposit: Get<u64>;
     }
 }
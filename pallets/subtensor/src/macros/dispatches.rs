@@ -5,6 +5,7 @@ use frame_support::pallet_macros::pallet_section;
 #[pallet_section]
 mod dispatches {
     use frame_support::traits::schedule::v3::Anon as ScheduleAnon;
+    use frame_support::traits::schedule::v3::Named as ScheduleNamed;
     use frame_support::traits::schedule::DispatchTime;
     use frame_system::pallet_prelude::BlockNumberFor;
     use sp_runtime::traits::Saturating;
@@ -105,10 +106,13 @@ mod dispatches {
         /// * `WeightsCommitNotAllowed`:
         ///   - Attempting to commit when it is not allowed.
         ///
+        /// * `NotEnoughBalanceForCommitDeposit`:
+        ///   - The owner coldkey cannot pay the commit deposit.
+        ///
         #[pallet::call_index(96)]
         #[pallet::weight((Weight::from_parts(46_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
         pub fn commit_weights(
             origin: T::RuntimeOrigin,
             netuid: u16,
@@ -150,8 +154,8 @@ mod dispatches {
         ///
         #[pallet::call_index(97)]
         #[pallet::weight((Weight::from_parts(103_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(11))
-		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(14))
+		.saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Normal, Pays::No))]
         pub fn reveal_weights(
             origin: T::RuntimeOrigin,
             netuid: u16,
@@ -238,7 +242,7 @@ mod dispatches {
             Self::do_set_root_weights(origin, netuid, hotkey, dests, weights, version_key)
         }
 
-        /// --- Sets the key as a delegate.
+        /// --- Sets the key as a delegate, with the take set in `DefaultDelegateTake`.
         ///
         /// # Args:
         /// * 'origin': (<T as frame_system::Config>Origin):
@@ -247,9 +251,6 @@ mod dispatches {
         /// * 'hotkey' (T::AccountId):
         /// 	- The hotkey we are delegating (must be owned by the coldkey.)
         ///
-        /// * 'take' (u64):
-        /// 	- The stake proportion that this hotkey takes from delegations.
-        ///
         /// # Event:
         /// * DelegateAdded;
         /// 	- On successfully setting a hotkey as a delegate.
@@ -261,9 +262,12 @@ mod dispatches {
         /// * 'NonAssociatedColdKey':
         /// 	- The hotkey we are delegating is not owned by the calling coldket.
         ///
+        /// * 'DelegateBelowOwnerStakeMinimum':
+        /// 	- The coldkey has less stake on the hotkey than the min owner stake to delegate.
+        ///
         #[pallet::call_index(1)]
         #[pallet::weight((Weight::from_parts(79_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().reads(8))
 		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
         pub fn become_delegate(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
             Self::do_become_delegate(origin, hotkey, Self::get_default_delegate_take())
@@ -327,6 +331,8 @@ mod dispatches {
         ///        greater than the previous value. T is the new value (rational number),
         ///        the the parameter is calculated as [65535 * T]. For example, 1% would be
         ///        [0.01 * 65535] = [655.35] = 655
+        ///        While the hotkey has pending emission, the new value only applies
+        ///        from the next drain of that emission.
         ///
         /// # Event:
         /// * TakeIncreased;
@@ -382,10 +388,13 @@ mod dispatches {
         ///  * 'BalanceWithdrawalError':
         ///  	- Errors stemming from transaction pallet.
         ///
+        ///  * 'DelegateBelowOwnerStakeMinimum':
+        /// 	- The hotkey is a delegate whose owner has less stake on it than the minimum.
+        ///
         #[pallet::call_index(2)]
         #[pallet::weight((Weight::from_parts(124_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(10))
-		.saturating_add(T::DbWeight::get().writes(7)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(16))
+		.saturating_add(T::DbWeight::get().writes(10)), DispatchClass::Normal, Pays::No))]
         pub fn add_stake(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -406,7 +415,7 @@ mod dispatches {
         /// 	- The associated hotkey account.
         ///
         /// * 'amount_unstaked' (u64):
-        /// 	- The amount of stake to be added to the hotkey staking account.
+        /// 	- The amount of stake to be removed from the hotkey staking account, or u64::MAX to remove the entire position.
         ///
         /// # Event:
         /// * StakeRemoved;
@@ -425,8 +434,9 @@ mod dispatches {
         #[pallet::call_index(3)]
         #[pallet::weight((Weight::from_parts(111_000_000, 0)
 		.saturating_add(Weight::from_parts(0, 43991))
-		.saturating_add(T::DbWeight::get().reads(10))
-		.saturating_add(T::DbWeight::get().writes(7)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(14))
+		.saturating_add(T::DbWeight::get().writes(10))
+		.saturating_add(Pallet::<T>::prune_coldkey_weight()), DispatchClass::Normal, Pays::No))]
         pub fn remove_stake(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -670,12 +680,9 @@ mod dispatches {
         ///
         /// # Weight
         ///
-        /// Weight is calculated based on the number of database reads and writes.
+        /// Weight is estimated up front from the number of hotkeys and subnets the swap iterates.
         #[pallet::call_index(71)]
-        #[pallet::weight((Weight::from_parts(127_713_000, 0)
-        .saturating_add(Weight::from_parts(0, 11645))
-        .saturating_add(T::DbWeight::get().reads(18))
-        .saturating_add(T::DbWeight::get().writes(12)), DispatchClass::Operational, Pays::No))]
+        #[pallet::weight((Pallet::<T>::get_swap_coldkey_weight(old_coldkey), DispatchClass::Operational, Pays::No))]
         pub fn swap_coldkey(
             origin: OriginFor<T>,
             old_coldkey: T::AccountId,
@@ -1019,6 +1026,9 @@ mod dispatches {
         ///
         /// This function may return an error if:
         /// * The origin is not signed.
+        /// * The coldkey has too many hotkeys and stakes to swap within the block weight limit (`TooManyEntriesToSwap`).
+        /// * The new coldkey would own more hotkeys than storage holds (`TooManyHotkeysForColdkey`).
+        /// * The coldkey has a network dissolution scheduled (`PendingOperationsExist`).
         /// * The scheduling fails due to conflicts or system constraints.
         ///
         /// # Notes
@@ -1033,23 +1043,42 @@ mod dispatches {
         /// TODO: Benchmark this call
         #[pallet::call_index(73)]
         #[pallet::weight((Weight::from_parts(119_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().reads(9))
 		.saturating_add(T::DbWeight::get().writes(31)), DispatchClass::Operational, Pays::Yes))]
         pub fn schedule_swap_coldkey(
             origin: OriginFor<T>,
             new_coldkey: T::AccountId,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            Self::ensure_staking_not_paused()?;
             ensure!(
                 !ColdkeySwapScheduled::<T>::contains_key(&who),
                 Error::<T>::SwapAlreadyScheduled
             );
 
+            // The swap must fit in the block once executed, and its merged hotkeys in storage.
+            Self::ensure_swap_coldkey_weight_within_limit(&who)?;
+            Self::ensure_swap_coldkey_owned_hotkeys_within_bound(&who, &new_coldkey)?;
+
+            // Pending operations the swap can not move block it from being scheduled.
+            ensure!(
+                Self::get_pending_operation_blocking_swap(&who, &new_coldkey).is_none(),
+                Error::<T>::PendingOperationsExist
+            );
+
+            // A coldkey protected by an auth key needs its approval to swap.
+            Self::ensure_coldkey_call_authorized(
+                &who,
+                Call::<T>::schedule_swap_coldkey {
+                    new_coldkey: new_coldkey.clone(),
+                },
+            )?;
+
             let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
             let duration: BlockNumberFor<T> = ColdkeySwapScheduleDuration::<T>::get();
             let when: BlockNumberFor<T> = current_block.saturating_add(duration);
 
-            let call = Call::<T>::swap_coldkey {
+            let call = Call::<T>::execute_scheduled_coldkey_swap {
                 old_coldkey: who.clone(),
                 new_coldkey: new_coldkey.clone(),
             };
@@ -1057,7 +1086,9 @@ mod dispatches {
             let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
                 .map_err(|_| Error::<T>::FailedToSchedule)?;
 
-            T::Scheduler::schedule(
+            // The task is named after the old coldkey, so it can be looked up and cancelled.
+            T::Scheduler::schedule_named(
+                Self::coldkey_swap_task_name(&who),
                 DispatchTime::At(when),
                 None,
                 63,
@@ -1067,6 +1098,7 @@ mod dispatches {
             .map_err(|_| Error::<T>::FailedToSchedule)?;
 
             ColdkeySwapScheduled::<T>::insert(&who, ());
+            ColdkeySwapDestination::<T>::insert(&who, &new_coldkey);
             // Emit the SwapScheduled event
             Self::deposit_event(Event::ColdkeySwapScheduled {
                 old_coldkey: who.clone(),
@@ -1094,7 +1126,7 @@ mod dispatches {
 
         #[pallet::call_index(74)]
         #[pallet::weight((Weight::from_parts(119_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().reads(7))
 		.saturating_add(T::DbWeight::get().writes(31)), DispatchClass::Operational, Pays::Yes))]
         pub fn schedule_dissolve_network(
             origin: OriginFor<T>,
@@ -1123,6 +1155,11 @@ mod dispatches {
             )
             .map_err(|_| Error::<T>::FailedToSchedule)?;
 
+            // The call names the coldkey, so its coldkey swap is blocked until the call executes.
+            DissolveNetworkScheduledUntil::<T>::mutate(&who, |until| {
+                *until = Some(until.map_or(when, |until| until.max(when)));
+            });
+
             // Emit the SwapScheduled event
             Self::deposit_event(Event::DissolveNetworkScheduled {
                 account: who.clone(),
@@ -1210,5 +1247,794 @@ mod dispatches {
         ) -> DispatchResult {
             Self::user_add_network(origin, identity)
         }
+
+        /// Sets, rotates or removes the auth key of the calling coldkey.
+        ///
+        /// Once set, scheduling a coldkey swap and unstaking more than `threshold_amount` must be approved by the auth key,
+        /// through `authorized_call` or `approve_coldkey_call`. Changing or removing an existing auth key needs the same approval.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed coldkey.
+        /// * `auth_key` - The new auth key, or `None` to remove it.
+        /// * `threshold_amount` - Unstakes above this amount require the auth key's approval.
+        ///
+        /// # Errors
+        /// * `AuthorizationRequired` - An auth key is already set and has not approved this call.
+        #[pallet::call_index(80)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(2))
+                .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_coldkey_auth(
+            origin: OriginFor<T>,
+            auth_key: Option<T::AccountId>,
+            threshold_amount: u64,
+        ) -> DispatchResult {
+            Self::do_set_coldkey_auth(origin, auth_key, threshold_amount)
+        }
+
+        /// Approves a call of `coldkey` by its auth key, to be submitted by the coldkey within `ColdkeyAuthApprovalWindow` blocks.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed auth key.
+        /// * `coldkey` - The coldkey whose call is approved.
+        /// * `call_hash` - The hash of the approved runtime call.
+        ///
+        /// # Errors
+        /// * `NotColdkeyAuthKey` - The signer is not the auth key of the coldkey.
+        #[pallet::call_index(81)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn approve_coldkey_call(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            call_hash: T::Hash,
+        ) -> DispatchResult {
+            Self::do_approve_coldkey_call(origin, coldkey, call_hash)
+        }
+
+        /// Dispatches `call` as `coldkey`, approved by the coldkey's auth key which signs this extrinsic.
+        ///
+        /// Only `remove_stake` and `set_coldkey_auth` can be dispatched this way, and they are validated as if the coldkey signed them.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed auth key.
+        /// * `coldkey` - The coldkey the call is dispatched as.
+        /// * `call` - The call to dispatch.
+        ///
+        /// # Errors
+        /// * `NotColdkeyAuthKey` - The signer is not the auth key of the coldkey.
+        /// * `CallNotAuthorizable` - The call can not be dispatched by the auth key.
+        #[pallet::call_index(82)]
+        #[pallet::weight({
+            let dispatch_info = call.get_dispatch_info();
+            (
+                dispatch_info.weight
+                    .saturating_add(Weight::from_parts(15_000_000, 0))
+                    .saturating_add(T::DbWeight::get().reads(1))
+                    .saturating_add(T::DbWeight::get().writes(2)),
+                dispatch_info.class,
+                Pays::Yes,
+            )
+        })]
+        pub fn authorized_call(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_authorized_call(origin, coldkey, call)
+        }
+
+        /// Transfers stake on a hotkey from the calling coldkey to another coldkey, without unstaking.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed source coldkey.
+        /// * `destination_coldkey` - The coldkey receiving the stake.
+        /// * `hotkey` - The hotkey the stake is delegated to.
+        /// * `netuid` - The subnet the hotkey is registered on, which must have stake transfers enabled.
+        /// * `amount` - The amount of stake to transfer.
+        ///
+        /// # Errors
+        /// * `TransferStakeDisabled` - The subnet owner has not enabled stake transfers.
+        /// * `SameColdkey` - The destination coldkey is the source coldkey.
+        /// * `NotEnoughStakeToWithdraw` - The source coldkey does not have enough stake on the hotkey.
+        /// * `UnstakeRateLimitExceeded` - The source coldkey has hit the unstaking rate limit on the hotkey.
+        /// * `ColdkeyStakeRateLimitExceeded` - The source coldkey has hit its stake operation limit over all hotkeys.
+        /// * `DelegateBelowOwnerStakeMinimum` - The owner of the delegate has less stake on it than the minimum.
+        #[pallet::call_index(83)]
+        #[pallet::weight((Weight::from_parts(111_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(18))
+                .saturating_add(T::DbWeight::get().writes(12)), DispatchClass::Normal, Pays::Yes))]
+        pub fn transfer_stake(
+            origin: OriginFor<T>,
+            destination_coldkey: T::AccountId,
+            hotkey: T::AccountId,
+            netuid: u16,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_transfer_stake(origin, destination_coldkey, hotkey, netuid, amount)
+        }
+
+        /// The extrinsic for user to change its hotkey on a single subnet.
+        ///
+        /// The registration of the old hotkey on the subnet moves to the new hotkey. Its registrations
+        /// on other subnets and its stake stay with the old hotkey.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed coldkey owning the old hotkey.
+        /// * `hotkey` - The hotkey to swap out.
+        /// * `new_hotkey` - The hotkey to swap in, unowned or owned by the same coldkey.
+        /// * `netuid` - The subnet to swap the hotkey on.
+        ///
+        /// # Errors
+        /// * `HotKeyNotRegisteredInSubNet` - The old hotkey is not registered on the subnet.
+        /// * `HotKeyAlreadyRegisteredInSubNet` - The new hotkey is already registered on the subnet.
+        #[pallet::call_index(84)]
+        #[pallet::weight((Weight::from_parts(300_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(72))
+                .saturating_add(T::DbWeight::get().writes(64)), DispatchClass::Operational, Pays::No))]
+        pub fn swap_hotkey_on_subnet(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            new_hotkey: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_swap_hotkey_on_subnet(origin, &hotkey, &new_hotkey, netuid)
+        }
+
+        /// The extrinsic for a neuron to voluntarily leave a subnet.
+        ///
+        /// Frees the UID of the hotkey on the subnet so that it can be registered into immediately.
+        /// The stake of the hotkey is left in place. If the subnet recycles registration burns, half
+        /// of the burn paid for the UID is refunded to the coldkey.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed coldkey owning the hotkey.
+        /// * `hotkey` - The hotkey to deregister.
+        /// * `netuid` - The subnet to leave.
+        ///
+        /// # Errors
+        /// * `NonAssociatedColdKey` - The coldkey does not own the hotkey.
+        /// * `HotKeyNotRegisteredInSubNet` - The hotkey is not registered on the subnet.
+        #[pallet::call_index(85)]
+        #[pallet::weight((Weight::from_parts(120_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(536))
+                .saturating_add(T::DbWeight::get().writes(530)), DispatchClass::Normal, Pays::Yes))]
+        pub fn deregister(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResult {
+            Self::do_deregister(origin, hotkey, netuid)
+        }
+
+        /// The extrinsic for a coldkey to voluntarily burn TAO from its free balance.
+        ///
+        /// The burned TAO is removed from the total issuance and counted in the lifetime burn of the
+        /// coldkey. The existential deposit of the coldkey is always kept.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed coldkey burning the TAO.
+        /// * `amount` - The amount of TAO to burn.
+        ///
+        /// # Errors
+        /// * `AmountToBurnIsZero` - The amount is zero.
+        /// * `NotEnoughBalanceToBurn` - The amount exceeds the free balance above the existential deposit.
+        #[pallet::call_index(86)]
+        #[pallet::weight((Weight::from_parts(60_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(4))
+                .saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn burn_tao(origin: OriginFor<T>, amount: u64) -> DispatchResult {
+            Self::do_burn_tao(origin, amount)
+        }
+
+        /// Registers a hotkey on behalf of a coldkey, which pays the burn and owns the hotkey.
+        ///
+        /// The coldkey authorizes the registration by signing the encoded
+        /// `(b"subtensor/register_by_proxy", genesis_hash, netuid, hotkey, provider, nonce, max_burn)`,
+        /// where `provider` is the signer of this call and `nonce` is the current
+        /// `RegistrationNonce` of the coldkey. The nonce is incremented on success, so a signature
+        /// cannot be replayed, and the genesis hash binds it to this chain.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed provider submitting the registration.
+        /// * `netuid` - The subnet to register on.
+        /// * `hotkey` - The hotkey to register.
+        /// * `coldkey` - The coldkey paying the burn.
+        /// * `max_burn` - The highest burn the coldkey agrees to pay.
+        /// * `coldkey_signature` - The signature of the coldkey authorizing the registration.
+        ///
+        /// # Errors
+        /// * `InvalidRegistrationSignature` - The signature is not by the coldkey over this provider and nonce.
+        /// * `BurnAboveSignedMaximum` - The burn of the subnet is above `max_burn`.
+        #[pallet::call_index(87)]
+        #[pallet::weight((Weight::from_parts(227_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(27))
+                .saturating_add(T::DbWeight::get().writes(25)), DispatchClass::Normal, Pays::Yes))]
+        pub fn burned_register_by_proxy(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            coldkey: T::AccountId,
+            max_burn: u64,
+            coldkey_signature: T::CallSignature,
+        ) -> DispatchResult {
+            Self::do_burned_register_by_proxy(
+                origin,
+                netuid,
+                hotkey,
+                coldkey,
+                max_burn,
+                coldkey_signature,
+            )
+        }
+
+        /// Registers a new subnetwork owned by `owner`.
+        ///
+        /// Root registrations are exempt from the network rate limit and lock no tokens.
+        ///
+        /// # Arguments
+        /// * `origin` - Must be root.
+        /// * `owner` - The coldkey owning the new subnetwork.
+        /// * `identity` - Optional identity of the new subnetwork.
+        ///
+        /// # Errors
+        /// * `AllNetworksInImmunity` - The subnet limit is reached and no subnet can be pruned.
+        /// * `InvalidIdentity` - The identity is invalid.
+        #[pallet::call_index(88)]
+        #[pallet::weight((Weight::from_parts(157_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(16))
+                .saturating_add(T::DbWeight::get().writes(30)), DispatchClass::Operational, Pays::No))]
+        pub fn root_register_network(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            identity: Option<SubnetIdentityOf>,
+        ) -> DispatchResult {
+            Self::root_add_network(origin, owner, identity)
+        }
+
+        /// Removes a weights commit that can no longer be revealed.
+        ///
+        /// The deposit the committer paid is credited to the caller as a bounty.
+        ///
+        /// # Arguments
+        /// * `origin` - Any signed account.
+        /// * `netuid` - The subnet of the commit.
+        /// * `hotkey` - The hotkey that committed.
+        ///
+        /// # Errors
+        /// * `NoWeightsCommitFound` - The hotkey has no commit on the subnet.
+        /// * `WeightsCommitNotExpired` - The commit can still be revealed.
+        #[pallet::call_index(89)]
+        #[pallet::weight((Weight::from_parts(31_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(4))
+                .saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn prune_expired_commits(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_prune_expired_commits(origin, netuid, hotkey)
+        }
+
+        /// Executes the coldkey swap scheduled by `schedule_swap_coldkey`.
+        ///
+        /// The scheduler dispatches this call as root at the execution block. The old coldkey can
+        /// dispatch it itself once the swap is due, if the scheduler has not executed it yet. The
+        /// preconditions of the swap are checked again at execution, and a swap that fails them is
+        /// dropped with a `ColdkeySwapFailed` event.
+        ///
+        /// # Arguments
+        /// * `origin` - Root, or the old coldkey once the swap is due.
+        /// * `old_coldkey` - The coldkey the swap was scheduled for.
+        /// * `new_coldkey` - The coldkey receiving the swap.
+        ///
+        /// # Errors
+        /// * `SwapNotScheduled` - No swap is scheduled for the old coldkey.
+        /// * `ColdkeySwapDestinationMismatch` - The new coldkey is not the scheduled destination.
+        /// * `ColdkeySwapNotDue` - The old coldkey dispatched the swap before its execution block.
+        #[pallet::call_index(90)]
+        #[pallet::weight((Pallet::<T>::get_swap_coldkey_weight(old_coldkey)
+                .saturating_add(T::DbWeight::get().reads_writes(4, 3)), DispatchClass::Operational, Pays::No))]
+        pub fn execute_scheduled_coldkey_swap(
+            origin: OriginFor<T>,
+            old_coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_execute_scheduled_coldkey_swap(origin, &old_coldkey, &new_coldkey)
+        }
+
+        /// Cancels the coldkey swap scheduled for a coldkey.
+        ///
+        /// # Arguments
+        /// * `origin` - Root.
+        /// * `old_coldkey` - The coldkey the swap was scheduled for.
+        ///
+        /// # Errors
+        /// * `SwapNotScheduled` - No swap is scheduled for the old coldkey.
+        /// * `SwapNotCancellable` - The swap was scheduled before swaps were named.
+        #[pallet::call_index(91)]
+        #[pallet::weight((Weight::from_parts(27_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(3))
+                .saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Operational, Pays::No))]
+        pub fn cancel_scheduled_coldkey_swap(
+            origin: OriginFor<T>,
+            old_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_cancel_scheduled_coldkey_swap(&old_coldkey)
+        }
+
+        /// Moves the free balance sent to a swapped-away coldkey to the coldkey it was swapped
+        /// to, while the redirect recorded by the swap is active. The swap records a redirect only
+        /// if the old coldkey enabled it with `set_coldkey_swap_redirect`.
+        ///
+        /// # Arguments
+        /// * `origin` - The old or the new coldkey.
+        /// * `old_coldkey` - The swapped-away coldkey.
+        ///
+        /// # Errors
+        /// * `NoColdkeySwapRedirect` - The coldkey was not swapped away, or its redirect was removed.
+        /// * `BadOrigin` - The signer is neither the old nor the new coldkey.
+        /// * `ColdkeySwapRedirectExpired` - The redirect of the coldkey has expired.
+        /// * `NothingToSweep` - The coldkey holds no balance.
+        #[pallet::call_index(92)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(3))
+                .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn sweep_swapped_coldkey(
+            origin: OriginFor<T>,
+            old_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            Self::do_sweep_swapped_coldkey(&signer, &old_coldkey)
+        }
+
+        /// Removes the redirect of a swapped-away coldkey, so the coldkey can be reused.
+        ///
+        /// # Arguments
+        /// * `origin` - Root.
+        /// * `old_coldkey` - The swapped-away coldkey.
+        ///
+        /// # Errors
+        /// * `NoColdkeySwapRedirect` - The coldkey has no redirect.
+        #[pallet::call_index(93)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn clear_coldkey_swap_redirect(
+            origin: OriginFor<T>,
+            old_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_clear_coldkey_swap_redirect(&old_coldkey)
+        }
+
+        /// Allows or revokes a parent hotkey setting the weights of a child hotkey on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The coldkey that owns the child.
+        /// * `parent` - The parent hotkey.
+        /// * `child` - The child hotkey.
+        /// * `netuid` - The subnet the permission applies to.
+        /// * `allowed` - Whether the parent may set the weights of the child.
+        ///
+        /// # Errors
+        /// * `NonAssociatedColdKey` - The coldkey does not own the child.
+        #[pallet::call_index(94)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_child_weights_permission(
+            origin: OriginFor<T>,
+            parent: T::AccountId,
+            child: T::AccountId,
+            netuid: u16,
+            allowed: bool,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            Self::do_set_child_weights_permission(coldkey, parent, child, netuid, allowed)
+        }
+
+        /// Sets the weights of a child hotkey, signed by one of its parents. The weights are
+        /// checked as in `set_weights` and attributed to the child's uid.
+        ///
+        /// # Arguments
+        /// * `origin` - The parent hotkey.
+        /// * `child_hotkey` - The child hotkey whose weights are set.
+        /// * `netuid` - The subnet the weights are set on.
+        /// * `dests` - The uids the weights are set on.
+        /// * `weights` - The u16 weights of the uids.
+        /// * `version_key` - The network version key.
+        ///
+        /// # Errors
+        /// * `CommitRevealEnabled` - Commit-reveal is enabled on the subnet.
+        /// * `NotParentOfChild` - The caller is not a parent of the child on the subnet.
+        /// * `ChildWeightsNotPermitted` - The child has not permitted the caller.
+        /// * Any error of `set_weights`.
+        #[pallet::call_index(95)]
+        #[pallet::weight((Weight::from_parts(22_060_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(4109))
+                .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn set_weights_for_child(
+            origin: OriginFor<T>,
+            child_hotkey: T::AccountId,
+            netuid: u16,
+            dests: Vec<u16>,
+            weights: Vec<u16>,
+            version_key: u64,
+        ) -> DispatchResult {
+            Self::do_set_weights_for_child(
+                origin,
+                child_hotkey,
+                netuid,
+                dests,
+                weights,
+                version_key,
+            )
+        }
+
+        /// Sets where the emission of the caller's stake on a hotkey is paid: compounded into
+        /// the stake, or credited to the free balance of the caller.
+        ///
+        /// # Arguments
+        /// * `origin` - The coldkey staking to the hotkey.
+        /// * `hotkey` - The hotkey the stake is on.
+        /// * `mode` - The payout mode.
+        ///
+        /// # Errors
+        /// * `HotKeyAccountNotExists` - The hotkey does not exist.
+        /// * `TxRateLimitExceeded` - The coldkey set a payout mode too recently.
+        #[pallet::call_index(98)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(3))
+                .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_emission_payout_mode(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            mode: EmissionPayoutMode,
+        ) -> DispatchResult {
+            Self::do_set_emission_payout_mode(origin, hotkey, mode)
+        }
+
+        /// Makes the signing coldkey the owner of an unowned hotkey, without registering it.
+        ///
+        /// The hotkey consents by signing the encoded
+        /// `(b"subtensor/associate_hotkey", coldkey, genesis_hash, nonce)`, where `nonce` is the
+        /// current `AssociationNonce` of the hotkey. The nonce is incremented on success, so a
+        /// signature cannot be replayed.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed coldkey.
+        /// * `hotkey` - The hotkey to associate.
+        /// * `hotkey_signature` - The signature of the hotkey consenting to the association.
+        ///
+        /// # Errors
+        /// * `HotKeyAlreadyOwned` - The hotkey is already owned by a coldkey.
+        /// * `InvalidAssociationSignature` - The signature is not by the hotkey over this coldkey and nonce.
+        /// * `TooManyHotkeysForColdkey` - The coldkey already owns the maximum number of hotkeys.
+        #[pallet::call_index(99)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(6))
+                .saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Normal, Pays::Yes))]
+        pub fn associate_hotkey(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            hotkey_signature: T::CallSignature,
+        ) -> DispatchResult {
+            Self::do_associate_hotkey(origin, hotkey, hotkey_signature)
+        }
+
+        /// Removes the axon of a hotkey on a subnet, so other neurons stop dialing its endpoint.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed hotkey, or the signed coldkey owning it.
+        /// * `netuid` - The subnet the axon is served on.
+        /// * `hotkey` - The hotkey whose axon is removed.
+        ///
+        /// # Errors
+        /// * `NonAssociatedColdKey` - The caller is neither the hotkey nor its coldkey.
+        /// * `AxonNotServed` - The hotkey has no axon on the subnet.
+        #[pallet::call_index(100)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(2))
+                .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn clear_axon(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_clear_axon(origin, netuid, hotkey)
+        }
+
+        /// Votes on a stake-weighted senate proposal with the total stake of the hotkey, counted when the proposal is closed.
+        ///
+        /// Proposals created from `StakeWeightedVotingFrom` on take their votes here instead of
+        /// through `vote`. A second vote of the hotkey replaces its first one.
+        ///
+        /// # Arguments
+        /// * `origin` - The coldkey owning the hotkey.
+        /// * `hotkey` - The senate member voting.
+        /// * `proposal` - The hash of the proposal.
+        /// * `index` - The index of the proposal.
+        /// * `approve` - Whether the member approves the proposal.
+        ///
+        /// # Errors
+        /// * `NonAssociatedColdKey` - The caller does not own the hotkey.
+        /// * `NotSenateMember` - The hotkey is not a member of the senate.
+        /// * `ProposalNotStakeWeighted` - The proposal predates stake-weighted voting.
+        /// * `ProposalIndexMismatch` - No open proposal has this hash and index.
+        #[pallet::call_index(101)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(6))
+                .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational))]
+        pub fn vote_weighted(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            proposal: T::Hash,
+            #[pallet::compact] index: u32,
+            approve: bool,
+        ) -> DispatchResult {
+            Self::do_vote_weighted(origin, &hotkey, proposal, index, approve)
+        }
+
+        /// Closes a stake-weighted senate proposal, dispatching it with the `StakeApproved` origin if the current stake
+        /// voting for it reaches `StakeWeightedVoteThreshold` of the total stake of the senate.
+        ///
+        /// # Arguments
+        /// * `origin` - Any signed account.
+        /// * `proposal` - The hash of the proposal.
+        /// * `index` - The index of the proposal.
+        ///
+        /// # Errors
+        /// * `ProposalNotStakeWeighted` - The proposal predates stake-weighted voting.
+        /// * `ProposalIndexMismatch` - No open proposal has this hash and index.
+        #[pallet::call_index(102)]
+        #[pallet::weight((Weight::from_parts(50_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(36))
+                .saturating_add(T::DbWeight::get().writes(16)), DispatchClass::Operational))]
+        pub fn close_weighted(
+            origin: OriginFor<T>,
+            proposal: T::Hash,
+            #[pallet::compact] index: u32,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_close_weighted(origin, proposal, index)
+        }
+
+        /// Removes a delegate with no stake, no registrations and no nominators, along with its
+        /// take history. The owner can become a delegate again later.
+        ///
+        /// # Arguments
+        /// * `origin` - Any signed account.
+        /// * `hotkey` - The delegate hotkey to remove.
+        ///
+        /// # Errors
+        /// * `HotKeyNotDelegate` - The hotkey is not a delegate.
+        /// * `DelegateHasStake` - The hotkey still has stake.
+        /// * `DelegateIsRegistered` - The hotkey is registered on a subnet.
+        /// * `DelegateHasNominators` - Coldkeys still stake on the hotkey.
+        #[pallet::call_index(103)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(4))
+                .saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn prune_delegate(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            Self::do_prune_delegate(origin, hotkey)
+        }
+
+        /// Sets the hotkey the stake of the caller moves to when a hotkey it stakes on stays off
+        /// every subnet for `AutoRedelegateAfterTempos` tempos. Without one, the caller is warned
+        /// with `StakedHotkeyInactive` instead.
+        ///
+        /// The stake only moves if the fallback is registered on a subnet and is a delegate or
+        /// owned by the caller at that time.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed nominator coldkey.
+        /// * `fallback_hotkey` - The hotkey to move the stake to, or None to clear it.
+        ///
+        /// # Errors
+        /// * `HotKeyAccountNotExists` - The fallback hotkey does not exist.
+        #[pallet::call_index(104)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn set_auto_redelegate(
+            origin: OriginFor<T>,
+            fallback_hotkey: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_auto_redelegate(origin, fallback_hotkey)
+        }
+
+        /// Locks `total` of the free balance of a coldkey under a vesting schedule, releasing
+        /// `per_block` each block from `start_block`.
+        ///
+        /// The locked balance can be staked but not transferred. Unstaking it returns it under
+        /// the lock, and the lock shrinks as the balance vests through `claim_vested`.
+        ///
+        /// # Arguments
+        /// * `origin` - Root.
+        /// * `coldkey` - The coldkey whose balance vests.
+        /// * `total` - The balance to lock.
+        /// * `per_block` - The balance vesting each block.
+        /// * `start_block` - The block vesting starts from.
+        ///
+        /// # Errors
+        /// * `InvalidVestingSchedule` - The total or the balance vesting per block is zero.
+        /// * `VestingScheduleExists` - The coldkey already has a vesting schedule.
+        /// * `NotEnoughBalanceToVest` - The free balance of the coldkey is below the total.
+        #[pallet::call_index(105)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(3))
+                .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Operational, Pays::No))]
+        pub fn sudo_create_vesting_schedule(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            total: u64,
+            per_block: u64,
+            start_block: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_create_vesting_schedule(coldkey, total, per_block, start_block)
+        }
+
+        /// Shrinks the vesting lock of the caller to the balance still vesting, removing the
+        /// schedule once it has fully vested.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed coldkey with a vesting schedule.
+        ///
+        /// # Errors
+        /// * `NoVestingSchedule` - The caller has no vesting schedule.
+        #[pallet::call_index(106)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(2))
+                .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn claim_vested(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_claim_vested(origin)
+        }
+
+        /// Records a 32-byte payload as the latest attested by the calling coldkey, e.g. a hash
+        /// binding it to an off-chain identity.
+        ///
+        /// The first attestation holds `AttestationDeposit` from the coldkey until it is revoked.
+        ///
+        /// # Errors
+        /// * `TxRateLimitExceeded` - The coldkey attested less than `AttestationRateLimit` blocks
+        ///   ago.
+        /// * `NotEnoughBalanceForAttestationDeposit` - The coldkey can not pay the deposit.
+        #[pallet::call_index(107)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(4))
+                .saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn attest(origin: OriginFor<T>, payload_hash: [u8; 32]) -> DispatchResult {
+            Self::do_attest(origin, payload_hash)
+        }
+
+        /// Removes the attestation of the calling coldkey and refunds its deposit.
+        ///
+        /// # Errors
+        /// * `NoAttestation` - The coldkey has no attestation.
+        #[pallet::call_index(108)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(2))
+                .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn revoke_attestation(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_revoke_attestation(origin)
+        }
+
+        /// Records the stake of every coldkey on a hotkey, for `compensate_nominators` to pay
+        /// them pro-rata after a correction reduced the stake of the hotkey.
+        ///
+        /// # Arguments
+        /// * `origin` - Root.
+        /// * `hotkey` - The hotkey the nominators stake on.
+        /// * `netuid` - The subnet the hotkey is registered on.
+        ///
+        /// # Errors
+        /// * `HotKeyNotRegisteredInSubNet` - The hotkey is not registered on the subnet.
+        /// * `TooManyNominatorsToSnapshot` - More than `MAX_SNAPSHOT_NOMINATORS` coldkeys stake on
+        ///   the hotkey.
+        /// * `CompensationInProgress` - The payout of the previous snapshot has started.
+        /// * `NominatorSnapshotOnOtherSubnet` - The hotkey has a snapshot on another subnet.
+        #[pallet::call_index(109)]
+        #[pallet::weight((Weight::from_parts(50_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1_028))
+                .saturating_add(T::DbWeight::get()
+                    .reads((Pallet::<T>::get_num_subnets() as u64).saturating_mul(2)))
+                .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn snapshot_nominators(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_snapshot_nominators(hotkey, netuid)
+        }
+
+        /// Pays `amount` from the correction compensation pool to the nominators of the snapshot
+        /// of a hotkey, in proportion to their recorded stake.
+        ///
+        /// A call pays at most `MAX_COMPENSATION_PAYOUTS_PER_CALL` nominators, and the next call
+        /// with the same amount carries on from there.
+        ///
+        /// # Arguments
+        /// * `origin` - Root.
+        /// * `hotkey` - The hotkey of the snapshot.
+        /// * `netuid` - The subnet of the snapshot.
+        /// * `amount` - The compensation shared by every nominator of the snapshot.
+        ///
+        /// # Errors
+        /// * `NoNominatorSnapshot` - The hotkey has no nominator snapshot on the subnet.
+        /// * `CompensationAmountMismatch` - The payout has started with another amount.
+        /// * `NotEnoughBalanceInCompensationPool` - The pool can not pay the nominators of the
+        ///   call.
+        #[pallet::call_index(110)]
+        #[pallet::weight((Weight::from_parts(60_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(66))
+                .saturating_add(T::DbWeight::get().writes(66)), DispatchClass::Operational, Pays::No))]
+        pub fn compensate_nominators(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+            amount: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_compensate_nominators(hotkey, netuid, amount)
+        }
+
+        /// Registers a hotkey on a subnet with invite-only registration, by burning TAO of the
+        /// calling coldkey.
+        ///
+        /// The subnet owner invites the hotkey by signing the encoded
+        /// `(b"subtensor/registration_invite", netuid, hotkey, coldkey, genesis_hash, expiry_block)`.
+        /// An invite can be used once, by the invited coldkey, up to its expiry block.
+        ///
+        /// # Arguments
+        /// * `origin` - The signed coldkey paying the burn.
+        /// * `netuid` - The subnet to register on.
+        /// * `hotkey` - The invited hotkey.
+        /// * `expiry_block` - The last block at which the invite can be used.
+        /// * `owner_signature` - The signature of the subnet owner over the invite.
+        ///
+        /// # Errors
+        /// * `InviteExpired` - The expiry block of the invite has passed.
+        /// * `InviteAlreadyUsed` - The invite has already been used.
+        /// * `InvalidInviteSignature` - The signature is not by the subnet owner over the invite.
+        #[pallet::call_index(111)]
+        #[pallet::weight((Weight::from_parts(227_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(28))
+                .saturating_add(T::DbWeight::get().writes(25)), DispatchClass::Normal, Pays::Yes))]
+        pub fn burned_register_with_invite(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            expiry_block: u64,
+            owner_signature: T::CallSignature,
+        ) -> DispatchResult {
+            Self::do_burned_register_with_invite(
+                origin,
+                netuid,
+                hotkey,
+                expiry_block,
+                owner_signature,
+            )
+        }
+
+        /// Enables or disables the redirect recorded when the calling coldkey is swapped.
+        ///
+        /// While the redirect of a swapped-away coldkey is active, the old or new coldkey can
+        /// sweep the balance sent to the old coldkey to the new one with `sweep_swapped_coldkey`.
+        ///
+        /// # Arguments
+        /// * `origin` - The coldkey to be swapped.
+        /// * `enabled` - Whether its swap records a redirect.
+        #[pallet::call_index(112)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_coldkey_swap_redirect(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            Self::do_set_coldkey_swap_redirect(&coldkey, enabled);
+            Ok(())
+        }
     }
 }
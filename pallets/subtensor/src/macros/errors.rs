@@ -184,5 +184,142 @@ mod errors {
         TxChildkeyTakeRateLimitExceeded,
         /// Invalid identity.
         InvalidIdentity,
+        /// The amount is below the existential deposit and would be dusted when credited to a new account.
+        AmountTooLowToCreateAccount,
+        /// The call needs the approval of the coldkey's auth key.
+        AuthorizationRequired,
+        /// The signer is not the auth key of the coldkey.
+        NotColdkeyAuthKey,
+        /// The coldkey has too many hotkeys and stakes to swap within the block weight limit.
+        TooManyEntriesToSwap,
+        /// Stake transfers between coldkeys are not enabled on this subnet.
+        TransferStakeDisabled,
+        /// The amount of TAO to burn is zero.
+        AmountToBurnIsZero,
+        /// The coldkey does not have enough free balance above the existential deposit to burn the amount.
+        NotEnoughBalanceToBurn,
+        /// The registration signature is not by the coldkey over this provider and its current nonce.
+        InvalidRegistrationSignature,
+        /// The coldkey already owns the maximum number of hotkeys.
+        TooManyHotkeysForColdkey,
+        /// The axon version is below the minimum axon version of the subnet.
+        AxonVersionTooOld,
+        /// The coldkey has a swap scheduled.
+        ColdkeyInSwapSchedule,
+        /// The owner coldkey does not have enough balance for the weights commit deposit.
+        NotEnoughBalanceForCommitDeposit,
+        /// The weights commit can still be revealed.
+        WeightsCommitNotExpired,
+        /// Staking operations are paused by root.
+        StakingPaused,
+        /// No coldkey swap is scheduled for the coldkey.
+        SwapNotScheduled,
+        /// The coldkey swap is not due yet.
+        ColdkeySwapNotDue,
+        /// The new coldkey is not the destination of the scheduled swap.
+        ColdkeySwapDestinationMismatch,
+        /// The pruning scores do not cover every neuron of the subnet.
+        InvalidPruningScoresLength,
+        /// The coldkey has no redirect to a coldkey it was swapped to.
+        NoColdkeySwapRedirect,
+        /// The redirect of the swapped-away coldkey has expired.
+        ColdkeySwapRedirectExpired,
+        /// The swapped-away coldkey holds no balance to sweep.
+        NothingToSweep,
+        /// Registrations are disabled on the subnet while it has no emission.
+        SubnetWarningRegistrationDisabled,
+        /// The hotkey is not a parent of the child on the subnet.
+        NotParentOfChild,
+        /// The child has not permitted the parent to set its weights on the subnet.
+        ChildWeightsNotPermitted,
+        /// The subnet limit is reached and pruning a subnet to make room is disabled.
+        SubnetLimitReached,
+        /// The hotkey is already owned by a coldkey.
+        HotKeyAlreadyOwned,
+        /// The association signature is not by the hotkey over this coldkey and its current nonce.
+        InvalidAssociationSignature,
+        /// The hotkey has no axon on the subnet.
+        AxonNotServed,
+        /// The proposal is tallied by stake and takes its votes through `vote_weighted`.
+        ProposalIsStakeWeighted,
+        /// The proposal was created before stake-weighted voting and takes its votes through `vote`.
+        ProposalNotStakeWeighted,
+        /// No open proposal has this hash and index.
+        ProposalIndexMismatch,
+        /// The weights are longer than the max weights length of the subnet.
+        TooManyWeights,
+        /// The min allowed weights would be above the number of weights a validator can set.
+        MinAllowedWeightsAboveMaxWeightsLen,
+        /// Kappa would be 0 or u16::MAX.
+        KappaOutOfRange,
+        /// Rho would be 0.
+        RhoIsZero,
+        /// The activity cutoff would be below the tempo.
+        ActivityCutoffBelowTempo,
+        /// The immunity period would not be below the activity cutoff.
+        ImmunityPeriodNotBelowActivityCutoff,
+        /// The hotkey is not a delegate.
+        HotKeyNotDelegate,
+        /// The delegate still has stake.
+        DelegateHasStake,
+        /// The delegate is registered on a subnet.
+        DelegateIsRegistered,
+        /// Coldkeys still stake on the delegate.
+        DelegateHasNominators,
+        /// The coldkey reached the max stake operations per interval over all its hotkeys.
+        ColdkeyStakeRateLimitExceeded,
+        /// A weight is on a uid whose hotkey has no commitment on the subnet, which requires one.
+        WeightOnUncommittedUid,
+        /// A weight is on a uid whose hotkey is owned by the coldkey of the validator, which the
+        /// subnet rejects.
+        WeightOnSameColdkeyUid,
+        /// The owner of the delegate has less stake on it than the min owner stake to delegate.
+        DelegateBelowOwnerStakeMinimum,
+        /// The stake fee discount thresholds are not in ascending order.
+        FeeDiscountThresholdsNotAscending,
+        /// The coldkey has pending operations the coldkey swap can not move to the new coldkey.
+        PendingOperationsExist,
+        /// The vesting schedule has no balance to vest or vests nothing per block.
+        InvalidVestingSchedule,
+        /// The coldkey already has a vesting schedule.
+        VestingScheduleExists,
+        /// The coldkey has less free balance than the vesting schedule locks.
+        NotEnoughBalanceToVest,
+        /// The coldkey has no vesting schedule.
+        NoVestingSchedule,
+        /// The stake is unvested balance of the coldkey, which can not leave it.
+        StakeLockedByVesting,
+        /// The coldkey has not enough balance to pay the attestation deposit.
+        NotEnoughBalanceForAttestationDeposit,
+        /// The coldkey has no attestation.
+        NoAttestation,
+        /// No emission is withheld from the subnet.
+        NoWithheldEmission,
+        /// The hotkey has more nominators than a snapshot records.
+        TooManyNominatorsToSnapshot,
+        /// The payout of the nominator snapshot of the hotkey has started.
+        CompensationInProgress,
+        /// The hotkey has no nominator snapshot on the subnet.
+        NoNominatorSnapshot,
+        /// The compensation differs from the one the started payout pays.
+        CompensationAmountMismatch,
+        /// The correction compensation pool can not pay the compensation.
+        NotEnoughBalanceInCompensationPool,
+        /// The subnet only registers hotkeys with an invite signed by its owner.
+        InviteRequired,
+        /// The invite is not signed by the subnet owner over this hotkey, coldkey and expiry block.
+        InvalidInviteSignature,
+        /// The expiry block of the invite has passed.
+        InviteExpired,
+        /// The invite has already been used to register the hotkey.
+        InviteAlreadyUsed,
+        /// The call can not be dispatched by the auth key of a coldkey.
+        CallNotAuthorizable,
+        /// The burn of the subnet is above the maximum the coldkey signed for.
+        BurnAboveSignedMaximum,
+        /// The coldkey swap was scheduled before swaps were named and can not be cancelled.
+        SwapNotCancellable,
+        /// The hotkey has a nominator snapshot on another subnet.
+        NominatorSnapshotOnOtherSubnet,
     }
 }
@@ -12,9 +12,23 @@ mod events {
         /// a network is removed.
         NetworkRemoved(u16),
         /// stake has been transferred from the a coldkey account onto the hotkey staking account.
-        StakeAdded(T::AccountId, u64),
+        StakeAdded {
+            /// The coldkey the stake was moved from.
+            coldkey: T::AccountId,
+            /// The hotkey the stake is delegated to.
+            hotkey: T::AccountId,
+            /// The amount of TAO withdrawn from the coldkey and staked.
+            amount: u64,
+        },
         /// stake has been removed from the hotkey staking account onto the coldkey account.
-        StakeRemoved(T::AccountId, u64),
+        StakeRemoved {
+            /// The coldkey the stake was credited to.
+            coldkey: T::AccountId,
+            /// The hotkey the stake was delegated to.
+            hotkey: T::AccountId,
+            /// The amount of TAO unstaked and credited to the coldkey.
+            amount: u64,
+        },
         /// a caller successfully sets their weights on a subnetwork.
         WeightsSet(u16, u16),
         /// a new neuron account has been registered to the chain.
@@ -103,6 +117,8 @@ mod events {
         RAORecycledForRegistrationSet(u16, u64),
         /// min stake is set for validators to set weights.
         WeightsMinStake(u64),
+        /// min stake to set weights is overridden (or reset when `None`) on a subnet.
+        SubnetStakeThresholdSet(u16, Option<u64>),
         /// setting the minimum required stake amount for senate registration.
         SenateRequiredStakePercentSet(u64),
         /// setting the adjustment alpha on a subnet.
@@ -119,12 +135,32 @@ mod events {
         NetworkMinLockCostSet(u64),
         /// the maximum number of subnets is set
         SubnetLimitSet(u16),
+        /// whether a registration at the subnet limit prunes a subnet is set
+        SubnetLimitPruningSet(bool),
         /// the lock cost reduction is set
         NetworkLockCostReductionIntervalSet(u64),
-        /// the take for a delegate is decreased.
-        TakeDecreased(T::AccountId, T::AccountId, u16),
-        /// the take for a delegate is increased.
-        TakeIncreased(T::AccountId, T::AccountId, u16),
+        /// the delegate or childkey take of a hotkey is decreased.
+        TakeDecreased {
+            /// The owner of the hotkey
+            coldkey: T::AccountId,
+            /// The hotkey
+            hotkey: T::AccountId,
+            /// The take before the change
+            old_take: u16,
+            /// The take after the change
+            new_take: u16,
+        },
+        /// the delegate or childkey take of a hotkey is increased.
+        TakeIncreased {
+            /// The owner of the hotkey
+            coldkey: T::AccountId,
+            /// The hotkey
+            hotkey: T::AccountId,
+            /// The take before the change
+            old_take: u16,
+            /// The take after the change
+            new_take: u16,
+        },
         /// the hotkey is swapped
         HotkeySwapped {
             /// the account ID of coldkey
@@ -138,8 +174,19 @@ mod events {
         MaxDelegateTakeSet(u16),
         /// minimum delegate take is set by sudo/admin transaction
         MinDelegateTakeSet(u16),
+        /// the take of hotkeys becoming delegates without a take is set by sudo/admin transaction
+        DefaultDelegateTakeSet(u16),
+        /// the owner stake a delegate needs to accept nominations is set by sudo/admin transaction
+        MinOwnerStakeToDelegateSet(u64),
+        /// the stake thresholds of the fee discount tiers are set by sudo/admin transaction
+        StakeFeeDiscountThresholdsSet([u64; 3]),
+        /// the tempos a staked hotkey stays off every subnet before redelegation is set by
+        /// sudo/admin transaction
+        AutoRedelegateAfterTemposSet(u64),
         /// the target stakes per interval is set by sudo/admin transaction
         TargetStakesPerIntervalSet(u64),
+        /// the max stake operations per interval per coldkey is set by sudo/admin transaction
+        MaxStakeOpsPerIntervalPerColdkeySet(u64),
         /// a member of the senate is adjusted
         SenateAdjusted {
             /// the account ID of the old senate member, if any
@@ -204,5 +251,386 @@ mod events {
         ColdkeySwapScheduleDurationSet(BlockNumberFor<T>),
         /// The duration of dissolve network has been set
         DissolveNetworkScheduleDurationSet(BlockNumberFor<T>),
+        /// The share of the maximum block weight a coldkey swap may declare has been set
+        ColdkeySwapMaxWeightShareSet(u16),
+        /// validator ratification of hyperparameter changes is enabled/disabled for a subnet.
+        ParamGovernanceEnabledSet(u16, bool),
+        /// The auth key of a coldkey has been set or removed.
+        ColdkeyAuthSet {
+            /// The coldkey protected by the auth key.
+            coldkey: T::AccountId,
+            /// The new auth key, `None` if removed.
+            auth_key: Option<T::AccountId>,
+            /// Unstakes above this amount require the auth key's approval.
+            threshold_amount: u64,
+        },
+        /// The auth key of a coldkey approved one of its calls.
+        ColdkeyCallApproved {
+            /// The coldkey whose call is approved.
+            coldkey: T::AccountId,
+            /// The hash of the approved call.
+            call_hash: T::Hash,
+        },
+        /// stake transfers between coldkeys are enabled/disabled for a subnet.
+        TransferStakeEnabledSet(u16, bool),
+        /// weights on a subnet are set to require a commitment of the weighted hotkey.
+        RequireCommitmentForWeightsSet(u16, bool),
+        /// weights on uncommitted hotkeys of a subnet are set to be rejected rather than zeroed.
+        RejectUncommittedWeightsSet(u16, bool),
+        /// weights on uids of the validator's coldkey are disallowed/allowed on a subnet.
+        DisallowSelfWeightByColdkeySet(u16, bool),
+        /// weights on uids of the validator's coldkey are set to be rejected rather than zeroed.
+        RejectSelfWeightByColdkeySet(u16, bool),
+        /// registration on a subnet is set to require, or not, an invite of the subnet owner.
+        InviteOnlyRegistrationSet(u16, bool),
+        /// stake has been transferred from one coldkey to another on a hotkey.
+        StakeTransferred {
+            /// The coldkey the stake was transferred from.
+            from: T::AccountId,
+            /// The coldkey the stake was transferred to.
+            to: T::AccountId,
+            /// The hotkey the stake is delegated to.
+            hotkey: T::AccountId,
+            /// The subnet the transfer was made on.
+            netuid: u16,
+            /// The amount of stake transferred.
+            amount: u64,
+        },
+        /// a hotkey has been swapped on a single subnet
+        HotkeySwappedOnSubnet {
+            /// the account ID of coldkey
+            coldkey: T::AccountId,
+            /// the account ID of old hotkey
+            old_hotkey: T::AccountId,
+            /// the account ID of new hotkey
+            new_hotkey: T::AccountId,
+            /// the subnet the hotkey was swapped on
+            netuid: u16,
+        },
+        /// refunding part of the registration burn on deregistration is enabled/disabled for a subnet.
+        RecycleRegistrationBurnSet(u16, bool),
+        /// a neuron has voluntarily left a subnet.
+        NeuronDeregistered {
+            /// The subnet the neuron left.
+            netuid: u16,
+            /// The uid the neuron held.
+            uid: u16,
+            /// The hotkey of the neuron.
+            hotkey: T::AccountId,
+            /// The part of the registration burn refunded to the coldkey.
+            refund: u64,
+        },
+        /// a coldkey has voluntarily burned TAO from its free balance.
+        TaoBurned {
+            /// The coldkey that burned the TAO.
+            coldkey: T::AccountId,
+            /// The amount of TAO burned.
+            amount: u64,
+        },
+        /// the number of hotkeys a coldkey can own is set.
+        MaxHotkeysPerColdkeySet(u32),
+        /// a coldkey is exempted from, or made subject to, the hotkey limit.
+        HotkeyLimitExemptionSet {
+            /// The coldkey whose exemption changed.
+            coldkey: T::AccountId,
+            /// Whether the coldkey is exempt.
+            exempt: bool,
+        },
+        /// the minimum axon version is set for a subnet.
+        MinAxonVersionSet(u16, u32),
+        /// the key swap cost is adjusted.
+        KeySwapCostAdjusted(u64),
+        /// the target number of key swaps per adjustment interval is set.
+        TargetKeySwapsPerIntervalSet(u64),
+        /// the number of blocks between two key swap cost adjustments is set.
+        KeySwapCostAdjustmentIntervalSet(u64),
+        /// the minimum key swap cost is set.
+        MinKeySwapCostSet(u64),
+        /// the maximum key swap cost is set.
+        MaxKeySwapCostSet(u64),
+        /// whether an epoch without weights or active stake writes a uniform incentive is set for a subnet.
+        EmptyEpochUniformIncentiveSet(u16, bool),
+        /// requested children are applied to a hotkey on a subnet.
+        ChildrenApplied(T::AccountId, u16, Vec<(u64, T::AccountId)>),
+        /// the number of blocks before requested children are applied is set.
+        PendingChildKeyCooldownSet(u64),
+        /// the deposit withdrawn from the owner coldkey for a weights commit is set.
+        WeightCommitDepositSet(u64),
+        /// an expired weights commit is removed, paying its deposit as a bounty to the pruner.
+        ExpiredWeightsCommitPruned {
+            /// The subnet of the commit.
+            netuid: u16,
+            /// The hotkey that committed.
+            hotkey: T::AccountId,
+            /// The signer that pruned the commit, or `None` when swept on idle.
+            pruner: Option<T::AccountId>,
+            /// The deposit paid to the pruner, burned when swept on idle.
+            bounty: u64,
+        },
+        /// the number of blocks between hyperparameter updates by a subnet owner is set.
+        OwnerHyperparamRateLimitSet(u64),
+        /// staking operations are paused or resumed by root.
+        StakingOperationsPausedSet(bool),
+        /// the pruning score source of a subnet is set.
+        PruningScoreSourceSet(u16, PruningScoreSource),
+        /// the custom pruning scores of a subnet are set.
+        CustomPruningScoresSet(u16),
+        /// the zero emission warning of a subnet is raised or cleared.
+        SubnetWarningSet(u16, bool),
+        /// the number of tempos without emission before a subnet is flagged is set.
+        SubnetWarningTemposSet(u64),
+        /// whether flagged subnets refuse registrations is set.
+        SubnetWarningBlocksRegistrationSet(bool),
+        /// the epoch history depth of a subnet is set.
+        EpochHistoryDepthSet(u16, u16),
+        /// a scheduled coldkey swap failed its checks at execution and was dropped.
+        ColdkeySwapFailed {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey
+            new_coldkey: T::AccountId,
+            /// The error the swap failed with
+            error: DispatchError,
+        },
+        /// a scheduled coldkey swap has been cancelled by root.
+        ColdkeySwapCancelled {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey
+            new_coldkey: T::AccountId,
+        },
+        /// the balance of a swapped-away coldkey has been swept to its new coldkey.
+        SwappedColdkeySwept {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey
+            new_coldkey: T::AccountId,
+            /// The amount of TAO swept
+            amount: u64,
+        },
+        /// the redirect of a swapped-away coldkey has been removed by root.
+        ColdkeySwapRedirectCleared {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+        },
+        /// a coldkey has enabled or disabled the redirect recorded when it is swapped.
+        ColdkeySwapRedirectSet {
+            /// The account ID of the coldkey
+            coldkey: T::AccountId,
+            /// Whether a swap of the coldkey records a redirect
+            enabled: bool,
+        },
+        /// the permission of a parent to set the weights of a child on a subnet is set.
+        ChildWeightsPermissionSet {
+            /// The parent hotkey
+            parent: T::AccountId,
+            /// The child hotkey
+            child: T::AccountId,
+            /// The subnet
+            netuid: u16,
+            /// Whether the parent may set the weights of the child
+            allowed: bool,
+        },
+        /// where the emission of a coldkey's stake on a hotkey is paid is set.
+        EmissionPayoutModeSet {
+            /// The coldkey
+            coldkey: T::AccountId,
+            /// The hotkey
+            hotkey: T::AccountId,
+            /// The payout mode
+            mode: EmissionPayoutMode,
+        },
+        /// a hotkey is associated with a coldkey without being registered.
+        HotkeyAssociated {
+            /// The coldkey now owning the hotkey
+            coldkey: T::AccountId,
+            /// The hotkey
+            hotkey: T::AccountId,
+        },
+        /// the axon server information of a hotkey is removed from the network.
+        AxonCleared(u16, T::AccountId),
+        /// the validator health drop threshold of a subnet is set.
+        ValidatorHealthDropThresholdSet(u16, u16),
+        /// the validator trust of a validator dropped by more than the threshold of its subnet over
+        /// an epoch.
+        ValidatorHealthDegraded {
+            /// The subnet
+            netuid: u16,
+            /// The uid of the validator
+            uid: u16,
+            /// The validator trust before the epoch
+            old: u16,
+            /// The validator trust after the epoch
+            new: u16,
+        },
+        /// the share of the registration burn paid to the subnet owner is set, in basis points.
+        RegistrationBurnOwnerShareSet(u16, u16),
+        /// the cap on the registration burn owner share is set, in basis points.
+        MaxRegistrationBurnOwnerShareSet(u16),
+        /// stake-weighted senate voting is set, with its first proposal index and threshold.
+        StakeWeightedVotingSet(Option<u32>, u16),
+        /// a senate member voted on a stake-weighted proposal.
+        StakeWeightedVoteCast {
+            /// The senate member
+            hotkey: T::AccountId,
+            /// The proposal
+            proposal: T::Hash,
+            /// Whether the member approves the proposal
+            approve: bool,
+            /// The stake of the member counted for its vote
+            stake: u64,
+        },
+        /// a stake-weighted proposal is closed.
+        StakeWeightedProposalClosed {
+            /// The proposal
+            proposal: T::Hash,
+            /// The stake approving the proposal
+            aye_stake: u64,
+            /// The total stake of the senate
+            total_stake: u64,
+            /// Whether the proposal passed
+            approved: bool,
+        },
+        /// the number of weights a validator can set is set for a subnet.
+        MaxWeightsLimitLenSet(u16, u16),
+        /// a weights row over the bound of its subnet is truncated to its highest weights.
+        WeightsRowTruncated {
+            /// The subnet of the row
+            netuid: u16,
+            /// The validator uid of the row
+            uid: u16,
+            /// The number of weights removed from the row
+            removed: u32,
+        },
+        /// a delegate with no stake, registrations or nominators is removed.
+        DelegatePruned(T::AccountId),
+        /// a coldkey sets or clears the hotkey its stake moves to when a hotkey it stakes on leaves
+        /// every subnet.
+        AutoRedelegateSet {
+            /// The nominator coldkey
+            coldkey: T::AccountId,
+            /// The fallback hotkey, or None once cleared
+            fallback_hotkey: Option<T::AccountId>,
+        },
+        /// the stake of a coldkey on a hotkey off every subnet is moved to its fallback hotkey.
+        StakeAutoRedelegated {
+            /// The nominator coldkey
+            coldkey: T::AccountId,
+            /// The inactive hotkey
+            from_hotkey: T::AccountId,
+            /// The fallback hotkey
+            to_hotkey: T::AccountId,
+            /// The stake moved
+            amount: u64,
+        },
+        /// a coldkey stakes on a hotkey off every subnet and has no usable fallback hotkey.
+        StakedHotkeyInactive {
+            /// The inactive hotkey
+            hotkey: T::AccountId,
+            /// The nominator coldkey
+            coldkey: T::AccountId,
+        },
+        /// the emission multiplier of a subnet is set, in millionths.
+        EmissionMultiplierSet(u16, u32),
+        /// the block emission of a subnet is paused/resumed.
+        SubnetEmissionPausedSet(u16, bool),
+        /// the emission withheld from a subnet has been added to its pending emission.
+        WithheldEmissionReleased {
+            /// The subnet the emission was withheld from
+            netuid: u16,
+            /// The emission released
+            amount: u64,
+        },
+        /// the emission withheld from a subnet has been burned.
+        WithheldEmissionBurned {
+            /// The subnet the emission was withheld from
+            netuid: u16,
+            /// The emission burned
+            amount: u64,
+        },
+        /// a scheduled coldkey swap has been blocked by a pending operation of the old coldkey.
+        ColdkeySwapBlocked {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey
+            new_coldkey: T::AccountId,
+            /// The kind of the pending operation
+            kind: PendingOperationKind,
+        },
+        /// a coldkey swap has met inconsistent entries of the old coldkey.
+        ColdkeySwapAnomalies {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey
+            new_coldkey: T::AccountId,
+            /// The number of inconsistent entries
+            count: u32,
+            /// The kinds of inconsistent entries met, each listed once
+            kinds: Vec<ColdkeySwapAnomalyKind>,
+        },
+        /// a vesting schedule has locked the balance of a coldkey.
+        VestingScheduleCreated {
+            /// The coldkey whose balance vests
+            coldkey: T::AccountId,
+            /// The balance vesting under the schedule
+            total: u64,
+            /// The balance vesting each block
+            per_block: u64,
+            /// The block vesting starts from
+            start_block: u64,
+        },
+        /// the lock of a vesting schedule has been updated to the balance vested so far.
+        VestedBalanceClaimed {
+            /// The coldkey whose balance vests
+            coldkey: T::AccountId,
+            /// The balance vested so far
+            vested: u64,
+            /// The balance still vesting, in the free balance or the stake of the coldkey
+            unvested: u64,
+        },
+        /// a coldkey has attested a payload, replacing its previous attestation.
+        ColdkeyAttested {
+            /// The attesting coldkey
+            coldkey: T::AccountId,
+            /// The payload attested
+            payload_hash: [u8; 32],
+            /// The block the payload was attested in
+            block: u64,
+        },
+        /// a coldkey has revoked its attestation and got its deposit back.
+        AttestationRevoked {
+            /// The coldkey whose attestation was revoked
+            coldkey: T::AccountId,
+            /// The deposit refunded to the coldkey
+            deposit: u64,
+        },
+        /// the stake of the nominators of a hotkey has been recorded to compensate them.
+        NominatorsSnapshotted {
+            /// The hotkey the nominators stake on
+            hotkey: T::AccountId,
+            /// The subnet the snapshot is taken for
+            netuid: u16,
+            /// The block the snapshot was taken in
+            block: u64,
+            /// The number of coldkeys recorded
+            nominators: u32,
+            /// The sum of the recorded stakes
+            total_stake: u64,
+        },
+        /// nominators of a hotkey have been paid from the correction compensation pool.
+        NominatorsCompensated {
+            /// The hotkey the nominators stake on
+            hotkey: T::AccountId,
+            /// The subnet the snapshot was taken for
+            netuid: u16,
+            /// The balance paid by this call
+            paid: u64,
+            /// The number of nominators paid by this call
+            nominators: u32,
+            /// Whether every recorded nominator has been paid, which removes the snapshot
+            complete: bool,
+        },
+        /// requested children are dropped because they are no longer valid when due.
+        PendingChildrenDiscarded(T::AccountId, u16),
     }
 }
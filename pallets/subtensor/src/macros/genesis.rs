@@ -115,6 +115,7 @@ mod genesis {
                     TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(*stake));
 
                     Stake::<T>::insert(hotkey.clone(), coldkey.clone(), stake);
+                    Pallet::<T>::update_nominator_count(hotkey, 0, *stake);
 
                     next_uid = next_uid.saturating_add(1);
                 }
@@ -122,6 +123,7 @@ mod genesis {
 
             // Set correct length for Subnet neurons
             SubnetworkN::<T>::insert(netuid, next_uid);
+            TotalNeuronCount::<T>::mutate(|n| *n = n.saturating_add(next_uid.into()));
 
             // --- Increase total network count.
             TotalNetworks::<T>::mutate(|n| *n = n.saturating_add(1));
@@ -15,23 +15,51 @@ mod hooks {
         // 	* 'n': (BlockNumberFor<T>):
         // 		- The number of the block we are initializing.
         fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
-            let block_step_result = Self::block_step();
-            match block_step_result {
-                Ok(_) => {
-                    // --- If the block step was successful, return the weight.
-                    log::debug!("Successfully ran block step.");
-                    Weight::from_parts(110_634_229_000_u64, 0)
-                        .saturating_add(T::DbWeight::get().reads(8304_u64))
-                        .saturating_add(T::DbWeight::get().writes(110_u64))
-                }
-                Err(e) => {
-                    // --- If the block step was unsuccessful, return the weight anyway.
-                    log::error!("Error while stepping block: {:?}", e);
-                    Weight::from_parts(110_634_229_000_u64, 0)
-                        .saturating_add(T::DbWeight::get().reads(8304_u64))
-                        .saturating_add(T::DbWeight::get().writes(110_u64))
-                }
-            }
+            // --- Run every stage of the block step, returning the weight they consumed.
+            let weight: Weight = Self::block_step();
+            log::debug!("Successfully ran block step with weight: {:?}", weight);
+            weight
+        }
+
+        // ---- Called with the weight left in the block after all extrinsics.
+        //
+        // # Args:
+        // 	* 'n': (BlockNumberFor<T>):
+        // 		- The number of the block.
+        // 	* 'remaining_weight': (Weight):
+        // 		- The weight available to deferred work.
+        fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            // --- Catch up on the queued hotkey drains the block step could not fit.
+            let weight: Weight = Self::drain_scheduled_hotkeys(remaining_weight);
+
+            // --- Prune the expired weights commits with the weight left.
+            let weight: Weight = weight.saturating_add(Self::prune_expired_weight_commits(
+                remaining_weight.saturating_sub(weight),
+            ));
+
+            // --- Bound the weights rows set before MaxWeightsLimitLen with the weight left.
+            let weight: Weight = weight.saturating_add(
+                migrations::migrate_bound_weights::migrate_bound_weights::<T>(
+                    remaining_weight.saturating_sub(weight),
+                ),
+            );
+
+            // --- Deduplicate the staking hotkeys with the weight left.
+            let weight: Weight = weight.saturating_add(
+                migrations::migrate_dedup_staking_hotkeys::migrate_dedup_staking_hotkeys::<T>(
+                    remaining_weight.saturating_sub(weight),
+                ),
+            );
+
+            // --- Redelegate or warn the nominators of inactive hotkeys with the weight left.
+            let weight: Weight = weight.saturating_add(Self::process_inactive_hotkeys(
+                remaining_weight.saturating_sub(weight),
+            ));
+
+            // --- Prune the coldkeys whose balance was drained with the weight left.
+            weight.saturating_add(Self::prune_killed_coldkeys(
+                remaining_weight.saturating_sub(weight),
+            ))
         }
 
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
@@ -70,10 +98,45 @@ mod hooks {
                 // Storage version v8 -> v9
                 .saturating_add(migrations::migrate_fix_total_coldkey_stake::migrate_fix_total_coldkey_stake::<T>())
                 // Migrate Delegate Ids on chain
-                .saturating_add(migrations::migrate_chain_identity::migrate_set_hotkey_identities::<T>());
+                .saturating_add(migrations::migrate_chain_identity::migrate_set_hotkey_identities::<T>())
+                // Initialize the global neuron counter
+                .saturating_add(migrations::migrate_init_total_neuron_count::migrate_init_total_neuron_count::<T>())
+                // Bound the number of hotkeys a coldkey can own
+                .saturating_add(migrations::migrate_bound_owned_hotkeys::migrate_bound_owned_hotkeys::<T>())
+                // Initialize the per hotkey nominator counter
+                .saturating_add(migrations::migrate_init_nominator_count::migrate_init_nominator_count::<T>())
+                // Drop the network memberships left behind by dissolved networks
+                .saturating_add(migrations::migrate_rebuild_network_membership::migrate_rebuild_network_membership::<T>())
+                // Move the last rate limited blocks into a single map keyed by operation
+                .saturating_add(migrations::migrate_rate_limit_blocks::migrate_rate_limit_blocks::<T>())
+                // Initialize the per coldkey owned subnet counter
+                .saturating_add(migrations::migrate_init_owned_subnet_count::migrate_init_owned_subnet_count::<T>());
             weight
         }
 
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            // --- The state must be sound before it is migrated.
+            Self::check_accounting_invariants()?;
+
+            // --- No live migration moves stake, so the total is carried over to check after.
+            Ok(TotalStake::<T>::get().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let total_stake_before: u64 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                TotalStake::<T>::get() == total_stake_before,
+                "TotalStake changed during the upgrade",
+            );
+
+            Self::check_accounting_invariants()?;
+            Self::check_migrated_state()?;
+            Ok(())
+        }
+
         #[cfg(feature = "try-runtime")]
         fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
             Self::check_accounting_invariants()?;
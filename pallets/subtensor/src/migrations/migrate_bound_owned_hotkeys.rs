@@ -0,0 +1,70 @@
+use super::*;
+use frame_support::{storage::unhashed, traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+use sp_std::vec::Vec;
+
+/// Checks that every `OwnedHotkeys` list fits the `BoundedVec` of at most `MAX_OWNED_HOTKEYS`
+/// hotkeys it is now read as. Both encode the same way, so lists within the bound need no change.
+///
+/// A list over the bound is left untouched and logged, and the migration is not marked as run, so
+/// it is checked again on the next upgrade. No owned hotkey is dropped.
+pub fn migrate_bound_owned_hotkeys<T: Config>() -> Weight {
+    let migration_name = b"migrate_bound_owned_hotkeys".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    let mut keys_checked: u64 = 0;
+    let mut keys_over_bound: u64 = 0;
+    for coldkey in OwnedHotkeys::<T>::iter_keys() {
+        keys_checked = keys_checked.saturating_add(1);
+        let hotkeys: Vec<T::AccountId> =
+            unhashed::get(&OwnedHotkeys::<T>::hashed_key_for(&coldkey)).unwrap_or_default();
+        if hotkeys.len() > MAX_OWNED_HOTKEYS as usize {
+            keys_over_bound = keys_over_bound.saturating_add(1);
+            log::error!(
+                "Migration '{}': coldkey {:?} owns {} hotkeys, over the bound of {}.",
+                String::from_utf8_lossy(&migration_name),
+                coldkey,
+                hotkeys.len(),
+                MAX_OWNED_HOTKEYS
+            );
+        }
+    }
+    weight = weight.saturating_add(T::DbWeight::get().reads(keys_checked));
+
+    if keys_over_bound > 0 {
+        log::error!(
+            "Migration '{}' failed: {} of {} coldkeys own more hotkeys than the bound.",
+            String::from_utf8_lossy(&migration_name),
+            keys_over_bound,
+            keys_checked
+        );
+        return weight;
+    }
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully. Checked {} coldkeys.",
+        String::from_utf8_lossy(&migration_name),
+        keys_checked
+    );
+
+    weight
+}
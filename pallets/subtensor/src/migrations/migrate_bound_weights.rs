@@ -0,0 +1,84 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+use sp_std::vec::Vec;
+
+/// Truncates the weights rows set before `MaxWeightsLimitLen` to the bound of their subnet,
+/// keeping the highest weights of each row.
+///
+/// Rows are visited in chunks from `on_idle`, resuming from `WeightsBoundCursor`, as there are
+/// too many of them to visit in a single block. The migration is marked as run once the sweep
+/// reaches the end of the map.
+pub fn migrate_bound_weights<T: Config>(remaining_weight: Weight) -> Weight {
+    let migration_name = b"migrate_bound_weights".to_vec();
+
+    // Reading the migration flag and the cursor, and writing the cursor back.
+    let mut weight: Weight = T::DbWeight::get().reads_writes(2, 1);
+    // Reading a row and the bound of its subnet, and writing the truncated row.
+    let visit_weight: Weight = T::DbWeight::get().reads_writes(3, 1);
+    if weight.saturating_add(visit_weight).any_gt(remaining_weight)
+        || HasMigrationRun::<T>::get(&migration_name)
+    {
+        return Weight::zero();
+    }
+
+    let mut rows = match WeightsBoundCursor::<T>::get() {
+        Some(cursor) => Weights::<T>::iter_from(cursor),
+        None => Weights::<T>::iter(),
+    };
+    let mut over_bound: Vec<(u16, u16, WeightsRow, usize)> = Vec::new();
+    let mut cursor: Option<Vec<u8>> = None;
+    while weight
+        .saturating_add(visit_weight)
+        .all_lte(remaining_weight)
+    {
+        let Some((netuid, uid, row)) = rows.next() else {
+            cursor = None;
+            break;
+        };
+        weight.saturating_accrue(T::DbWeight::get().reads(3));
+        cursor = Some(Weights::<T>::hashed_key_for(netuid, uid));
+
+        let bound: usize = Pallet::<T>::get_max_weights_limit_len(netuid) as usize;
+        if row.len() > bound {
+            over_bound.push((netuid, uid, row, bound));
+        }
+    }
+
+    match cursor {
+        Some(cursor) => WeightsBoundCursor::<T>::put(cursor),
+        None => {
+            WeightsBoundCursor::<T>::kill();
+            HasMigrationRun::<T>::insert(&migration_name, true);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+            log::info!(
+                "Migration '{}' completed successfully.",
+                String::from_utf8_lossy(&migration_name)
+            );
+        }
+    }
+
+    for (netuid, uid, row, bound) in over_bound {
+        let removed: u32 = row.len().saturating_sub(bound) as u32;
+        Weights::<T>::insert(netuid, uid, truncate_weights_row(row, bound));
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        Pallet::<T>::deposit_event(Event::WeightsRowTruncated {
+            netuid,
+            uid,
+            removed,
+        });
+    }
+    weight
+}
+
+/// Keeps the `bound` highest weights of `row`, in uid order.
+fn truncate_weights_row(row: WeightsRow, bound: usize) -> WeightsRow {
+    let mut kept: Vec<(u16, u16)> = row.into_inner();
+    // Ties keep the earlier entries of the row, as the sort is stable.
+    kept.sort_by(|(_, a), (_, b)| b.cmp(a));
+    kept.truncate(bound);
+    kept.sort_by_key(|(uid, _)| *uid);
+    WeightsRow::truncate_from(kept)
+}
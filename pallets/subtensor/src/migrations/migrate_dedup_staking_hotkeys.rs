@@ -0,0 +1,61 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// Removes the duplicate entries older stake paths left in `StakingHotkeys`.
+///
+/// Coldkeys are visited in chunks from `on_idle`, resuming from `StakingHotkeysDedupCursor`, as
+/// there are too many of them to visit in a single block. The migration is marked as run once
+/// the sweep reaches the end of the map.
+pub fn migrate_dedup_staking_hotkeys<T: Config>(remaining_weight: Weight) -> Weight {
+    let migration_name = b"migrate_dedup_staking_hotkeys".to_vec();
+
+    // Reading the migration flag and the cursor, and writing the cursor back.
+    let mut weight: Weight = T::DbWeight::get().reads_writes(2, 1);
+    // Reading the hotkeys of a coldkey and writing them deduplicated.
+    let visit_weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+    if weight.saturating_add(visit_weight).any_gt(remaining_weight)
+        || HasMigrationRun::<T>::get(&migration_name)
+    {
+        return Weight::zero();
+    }
+
+    let mut entries = match StakingHotkeysDedupCursor::<T>::get() {
+        Some(cursor) => StakingHotkeys::<T>::iter_keys_from(cursor),
+        None => StakingHotkeys::<T>::iter_keys(),
+    };
+    let mut coldkeys: Vec<T::AccountId> = Vec::new();
+    let mut cursor: Option<Vec<u8>> = None;
+    while weight
+        .saturating_add(visit_weight)
+        .all_lte(remaining_weight)
+    {
+        let Some(coldkey) = entries.next() else {
+            cursor = None;
+            break;
+        };
+        weight.saturating_accrue(visit_weight);
+        cursor = Some(StakingHotkeys::<T>::hashed_key_for(&coldkey));
+        coldkeys.push(coldkey);
+    }
+
+    // The entries are only rewritten once the iteration is over.
+    for coldkey in coldkeys {
+        Pallet::<T>::sync_staking_hotkeys(&coldkey);
+    }
+
+    match cursor {
+        Some(cursor) => StakingHotkeysDedupCursor::<T>::put(cursor),
+        None => {
+            StakingHotkeysDedupCursor::<T>::kill();
+            HasMigrationRun::<T>::insert(&migration_name, true);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+            log::info!(
+                "Migration '{}' completed successfully.",
+                String::from_utf8_lossy(&migration_name)
+            );
+        }
+    }
+    weight
+}
@@ -0,0 +1,47 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// Initializes `NominatorCount` from the non-zero `Stake` entries of every hotkey.
+pub fn migrate_init_nominator_count<T: Config>() -> Weight {
+    let migration_name = b"migrate_init_nominator_count".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Count the coldkeys with a non-zero stake on each hotkey.
+    let mut nominations: u64 = 0;
+    for (hotkey, _, stake) in Stake::<T>::iter() {
+        weight = weight.saturating_add(T::DbWeight::get().reads(1));
+        if stake > 0 {
+            NominatorCount::<T>::mutate(hotkey, |count| *count = count.saturating_add(1));
+            nominations = nominations.saturating_add(1);
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+        }
+    }
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully. Nominations counted: {}",
+        String::from_utf8_lossy(&migration_name),
+        nominations
+    );
+
+    weight
+}
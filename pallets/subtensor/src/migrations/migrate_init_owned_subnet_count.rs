@@ -0,0 +1,44 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// Initializes `OwnedSubnetCount` from the `SubnetOwner` entries.
+pub fn migrate_init_owned_subnet_count<T: Config>() -> Weight {
+    let migration_name = b"migrate_init_owned_subnet_count".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Count the subnets owned by each coldkey.
+    let mut subnets: u64 = 0;
+    for (_, owner) in SubnetOwner::<T>::iter() {
+        OwnedSubnetCount::<T>::mutate(owner, |count| *count = count.saturating_add(1));
+        subnets = subnets.saturating_add(1);
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+    }
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully. Subnet owners counted: {}",
+        String::from_utf8_lossy(&migration_name),
+        subnets
+    );
+
+    weight
+}
@@ -0,0 +1,45 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// Initializes `TotalNeuronCount` from the sum of `SubnetworkN` over all subnets.
+pub fn migrate_init_total_neuron_count<T: Config>() -> Weight {
+    let migration_name = b"migrate_init_total_neuron_count".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Sum the neuron count of every subnet.
+    let mut total_neuron_count: u32 = 0;
+    for (_, subnetwork_n) in SubnetworkN::<T>::iter() {
+        total_neuron_count = total_neuron_count.saturating_add(subnetwork_n.into());
+        weight = weight.saturating_add(T::DbWeight::get().reads(1));
+    }
+    TotalNeuronCount::<T>::put(total_neuron_count);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully. Total neuron count: {}",
+        String::from_utf8_lossy(&migration_name),
+        total_neuron_count
+    );
+
+    weight
+}
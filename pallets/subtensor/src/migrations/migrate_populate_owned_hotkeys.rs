@@ -45,8 +45,7 @@ pub fn migrate_populate_owned<T: Config>() -> Weight {
             storage_reads = storage_reads.saturating_add(1); // Read from OwnedHotkeys storage
 
             // Add the hotkey if it's not already in the vector
-            if !hotkeys.contains(&hotkey) {
-                hotkeys.push(hotkey);
+            if !hotkeys.contains(&hotkey) && hotkeys.try_push(hotkey).is_ok() {
                 keys_touched = keys_touched.saturating_add(1);
 
                 // Update longest hotkey vector info
@@ -0,0 +1,72 @@
+use super::*;
+use frame_support::{storage_alias, traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// Module containing the deprecated per operation maps of the last rate limited blocks.
+pub mod deprecated_last_tx_block {
+    use super::*;
+    use frame_support::pallet_prelude::{Identity, OptionQuery};
+
+    #[storage_alias]
+    pub(super) type LastTxBlock<T: Config> =
+        StorageMap<Pallet<T>, Identity, AccountIdOf<T>, u64, OptionQuery>;
+
+    #[storage_alias]
+    pub(super) type LastTxBlockDelegateTake<T: Config> =
+        StorageMap<Pallet<T>, Identity, AccountIdOf<T>, u64, OptionQuery>;
+
+    #[storage_alias]
+    pub(super) type LastTxBlockChildKeyTake<T: Config> =
+        StorageMap<Pallet<T>, Identity, AccountIdOf<T>, u64, OptionQuery>;
+}
+
+/// Moves the entries of `LastTxBlock`, `LastTxBlockDelegateTake` and `LastTxBlockChildKeyTake`
+/// into `LastRateLimitedBlock`, under their operation.
+pub fn migrate_rate_limit_blocks<T: Config>() -> Weight {
+    let migration_name = b"migrate_rate_limit_blocks".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Drain each old map into the new one, clearing the old keys.
+    let mut entries: u64 = 0;
+    for (key, block) in deprecated_last_tx_block::LastTxBlock::<T>::drain() {
+        LastRateLimitedBlock::<T>::insert(RateLimitedOperation::Transaction, key, block);
+        entries = entries.saturating_add(1);
+    }
+    for (key, block) in deprecated_last_tx_block::LastTxBlockDelegateTake::<T>::drain() {
+        LastRateLimitedBlock::<T>::insert(RateLimitedOperation::DelegateTake, key, block);
+        entries = entries.saturating_add(1);
+    }
+    for (key, block) in deprecated_last_tx_block::LastTxBlockChildKeyTake::<T>::drain() {
+        LastRateLimitedBlock::<T>::insert(RateLimitedOperation::ChildKeyTake, key, block);
+        entries = entries.saturating_add(1);
+    }
+    weight =
+        weight.saturating_add(T::DbWeight::get().reads_writes(entries, entries.saturating_mul(2)));
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully. Entries moved: {}",
+        String::from_utf8_lossy(&migration_name),
+        entries
+    );
+
+    weight
+}
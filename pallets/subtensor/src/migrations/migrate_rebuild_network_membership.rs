@@ -0,0 +1,50 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// Rebuilds `IsNetworkMember` from `Uids`, dropping the entries left behind by dissolved
+/// networks.
+pub fn migrate_rebuild_network_membership<T: Config>() -> Weight {
+    let migration_name = b"migrate_rebuild_network_membership".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Drop every membership, stale or not.
+    let removed: u64 = IsNetworkMember::<T>::clear(u32::MAX, None).unique.into();
+    weight = weight.saturating_add(T::DbWeight::get().writes(removed));
+
+    // Insert a membership for every registered neuron.
+    let mut memberships: u64 = 0;
+    for (netuid, hotkey, _) in Uids::<T>::iter() {
+        IsNetworkMember::<T>::insert(&hotkey, netuid, true);
+        memberships = memberships.saturating_add(1);
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+    }
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully. Memberships removed: {}, rebuilt: {}",
+        String::from_utf8_lossy(&migration_name),
+        removed,
+        memberships
+    );
+
+    weight
+}
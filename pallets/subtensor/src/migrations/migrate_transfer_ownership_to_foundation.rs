@@ -65,8 +65,8 @@ pub fn migrate_transfer_ownership_to_foundation<T: Config>(coldkey: [u8; 32]) ->
         weight.saturating_accrue(T::DbWeight::get().reads(1));
 
         // Transfer ownership of subnets 1 and 11 to the foundation
-        SubnetOwner::<T>::insert(1, coldkey_account.clone());
-        SubnetOwner::<T>::insert(11, coldkey_account);
+        Pallet::<T>::set_subnet_owner(1, &coldkey_account);
+        Pallet::<T>::set_subnet_owner(11, &coldkey_account);
 
         // Set the registration time for subnet 1 to extend immunity period
         NetworkRegisteredAt::<T>::insert(1, current_block.saturating_add(13 * 7200));
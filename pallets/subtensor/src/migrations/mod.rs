@@ -1,12 +1,20 @@
 use super::*;
+pub mod migrate_bound_owned_hotkeys;
+pub mod migrate_bound_weights;
 pub mod migrate_chain_identity;
 pub mod migrate_create_root_network;
+pub mod migrate_dedup_staking_hotkeys;
 pub mod migrate_delete_subnet_21;
 pub mod migrate_delete_subnet_3;
 pub mod migrate_fix_total_coldkey_stake;
+pub mod migrate_init_nominator_count;
+pub mod migrate_init_owned_subnet_count;
 pub mod migrate_init_total_issuance;
+pub mod migrate_init_total_neuron_count;
 pub mod migrate_populate_owned_hotkeys;
 pub mod migrate_populate_staking_hotkeys;
+pub mod migrate_rate_limit_blocks;
+pub mod migrate_rebuild_network_membership;
 pub mod migrate_to_v1_separate_emission;
 pub mod migrate_to_v2_fixed_total_stake;
 pub mod migrate_total_issuance;
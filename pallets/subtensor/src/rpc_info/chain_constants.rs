@@ -0,0 +1,48 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::traits::GetStorageVersion;
+extern crate alloc;
+use codec::Compact;
+
+/// Layout version of `ChainConstants`. Fields are only ever appended, and every addition bumps
+/// the version so clients know which trailing fields to expect.
+pub const CHAIN_CONSTANTS_VERSION: u16 = 1;
+
+#[freeze_struct("3a4c0a6b21cc4ddc")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ChainConstants {
+    pub version: Compact<u16>,
+    pub key_swap_cost: Compact<u64>,
+    pub min_delegate_take: Compact<u16>,
+    pub max_delegate_take: Compact<u16>,
+    pub default_delegate_take: Compact<u16>,
+    pub target_stakes_per_interval: Compact<u64>,
+    pub tx_rate_limit: Compact<u64>,
+    pub network_rate_limit: Compact<u64>,
+    pub nominator_min_required_stake: Compact<u64>,
+    pub stake_threshold: Compact<u64>,
+    pub storage_version: Compact<u16>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the governance-set constants wallets need, read from storage on every call.
+    pub fn get_chain_constants() -> ChainConstants {
+        let storage_version = Self::on_chain_storage_version().encode();
+
+        ChainConstants {
+            version: CHAIN_CONSTANTS_VERSION.into(),
+            key_swap_cost: Self::get_key_swap_cost().into(),
+            min_delegate_take: Self::get_min_delegate_take().into(),
+            max_delegate_take: Self::get_max_delegate_take().into(),
+            default_delegate_take: Self::get_default_delegate_take().into(),
+            target_stakes_per_interval: Self::get_target_stakes_per_interval().into(),
+            tx_rate_limit: Self::get_tx_rate_limit().into(),
+            network_rate_limit: Self::get_network_rate_limit().into(),
+            nominator_min_required_stake: Self::get_nominator_min_required_stake().into(),
+            stake_threshold: Self::get_weights_min_stake().into(),
+            storage_version: u16::decode(&mut storage_version.as_slice())
+                .unwrap_or_default()
+                .into(),
+        }
+    }
+}
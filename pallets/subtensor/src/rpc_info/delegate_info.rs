@@ -7,7 +7,7 @@ extern crate alloc;
 use codec::Compact;
 use sp_core::hexdisplay::AsBytesRef;
 
-#[freeze_struct("5752e4c650a83e0d")]
+#[freeze_struct("8a887d5e7e37f21f")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct DelegateInfo<T: Config> {
     delegate_ss58: T::AccountId,
@@ -18,6 +18,8 @@ pub struct DelegateInfo<T: Config> {
     validator_permits: Vec<Compact<u16>>, // Vec of netuid this delegate has validator permit on
     return_per_1000: Compact<u64>, // Delegators current daily return per 1000 TAO staked minus take fee
     total_daily_return: Compact<u64>, // Delegators current daily return
+    emissions: Vec<emission_info::EmissionInfo>, // Last epoch and lifetime emission per netuid
+    take_history: Vec<(Compact<u64>, Compact<u16>, Compact<u16>)>, // Last take changes ( block, old, new ), oldest first
 }
 
 impl<T: Config> Pallet<T> {
@@ -58,7 +60,7 @@ impl<T: Config> Pallet<T> {
         }
 
         let owner = Self::get_owning_coldkey_for_hotkey(&delegate.clone());
-        let take: Compact<u16> = <Delegates<T>>::get(delegate.clone()).into();
+        let take: Compact<u16> = Self::get_hotkey_take(&delegate).into();
 
         let total_stake: U64F64 = Self::get_total_stake_for_hotkey(&delegate.clone()).into();
 
@@ -79,6 +81,11 @@ impl<T: Config> Pallet<T> {
             validator_permits,
             return_per_1000: U64F64::to_num::<u64>(return_per_1000).into(),
             total_daily_return: U64F64::to_num::<u64>(emissions_per_day).into(),
+            emissions: Self::get_emission_info(&delegate),
+            take_history: TakeHistory::<T>::get(&delegate)
+                .into_iter()
+                .map(|(block, old_take, new_take)| (block.into(), old_take.into(), new_take.into()))
+                .collect(),
         }
     }
 
@@ -136,10 +143,10 @@ impl<T: Config> Pallet<T> {
         let mut total_delegated = 0u64;
 
         // Get all hotkeys associated with this coldkey
-        let hotkeys = StakingHotkeys::<T>::get(coldkey);
+        let hotkeys = Self::get_all_staked_hotkeys(coldkey);
 
         for hotkey in hotkeys {
-            let owner = Owner::<T>::get(&hotkey);
+            let owner = Self::get_owning_coldkey_for_hotkey(&hotkey);
 
             for (delegator, stake) in Stake::<T>::iter_prefix(&hotkey) {
                 if delegator != owner {
@@ -172,6 +179,6 @@ impl<T: Config> Pallet<T> {
 
     // Helper function to get the coldkey associated with a hotkey
     pub fn get_coldkey_for_hotkey(hotkey: &T::AccountId) -> T::AccountId {
-        Owner::<T>::get(hotkey)
+        Self::get_owning_coldkey_for_hotkey(hotkey)
     }
 }
@@ -0,0 +1,105 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+#[freeze_struct("b9e5785c8099bd9d")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct EmissionInfo {
+    netuid: Compact<u16>,
+    last_tempo: Compact<u64>, // Emission credited in the last epoch the hotkey was credited
+    lifetime: Compact<u64>,   // Total emission ever credited on this subnet
+}
+
+#[freeze_struct("a0597367ab5811d4")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct PendingEmissionInfo {
+    pub netuid: Compact<u16>,
+    pub pending_emission: Compact<u64>, // Emission accumulated since the last epoch
+    pub next_epoch_block: Compact<u64>, // Block the accumulated emission is drained on
+}
+
+#[freeze_struct("2ecf7e4bb6352a26")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct PendingHotkeyEmissionInfo {
+    pub pending_emission: Compact<u64>, // Emission accumulated since the last drain
+    pub blocks_until_drain: Compact<u64>, // Blocks until it is drained to the nominators
+    pub next_drain_block: Compact<u64>, // Block the next drain runs on
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the emission records of a hotkey on every subnet it was ever credited on.
+    ///
+    /// Amounts are gross of the delegate take. Records of dissolved subnets are kept under
+    /// the dissolved netuid so that lifetime emission stays queryable; if that netuid is
+    /// later reused, new emission keeps adding to the same record.
+    pub fn get_emission_info(hotkey: &T::AccountId) -> Vec<EmissionInfo> {
+        EmissionLifetime::<T>::iter_prefix(hotkey)
+            .map(|(netuid, lifetime)| EmissionInfo {
+                netuid: netuid.into(),
+                last_tempo: Self::get_emission_last_tempo(hotkey, netuid).into(),
+                lifetime: lifetime.into(),
+            })
+            .collect()
+    }
+
+    pub fn get_emission_info_for_hotkey(hotkey_account_vec: Vec<u8>) -> Vec<EmissionInfo> {
+        let Ok(hotkey) = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()) else {
+            return Vec::new(); // Invalid account
+        };
+
+        Self::get_emission_info(&hotkey)
+    }
+
+    /// Returns the emission accumulated on a subnet and the block of its next epoch, which drains it.
+    ///
+    /// The next epoch block is the first block after the current one on which the epoch runs, or
+    /// u64::MAX if the subnet has a tempo of zero and never runs it.
+    pub fn get_pending_emission_info(netuid: u16) -> Option<PendingEmissionInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let next_block: u64 = Self::get_current_block_as_u64().saturating_add(1);
+        let next_epoch_block: u64 = next_block.saturating_add(Self::blocks_until_next_epoch(
+            netuid,
+            Self::get_tempo(netuid),
+            next_block,
+        ));
+
+        Some(PendingEmissionInfo {
+            netuid: netuid.into(),
+            pending_emission: Self::get_pending_emission(netuid).into(),
+            next_epoch_block: next_epoch_block.into(),
+        })
+    }
+
+    /// Returns the emission accumulated on a hotkey and when it is next drained to its nominators.
+    pub fn get_pending_hotkey_emission_info(hotkey: &T::AccountId) -> PendingHotkeyEmissionInfo {
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let next_block: u64 = current_block.saturating_add(1);
+        let next_drain_block: u64 =
+            next_block.saturating_add(Self::blocks_until_next_hotkey_drain(
+                hotkey,
+                next_block,
+                Self::get_hotkey_emission_tempo(),
+            ));
+
+        PendingHotkeyEmissionInfo {
+            pending_emission: Self::get_pending_hotkey_emission(hotkey).into(),
+            blocks_until_drain: next_drain_block.saturating_sub(current_block).into(),
+            next_drain_block: next_drain_block.into(),
+        }
+    }
+
+    pub fn get_pending_hotkey_emission_info_for_hotkey(
+        hotkey_account_vec: Vec<u8>,
+    ) -> Option<PendingHotkeyEmissionInfo> {
+        let Ok(hotkey) = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()) else {
+            return None; // Invalid account
+        };
+
+        Some(Self::get_pending_hotkey_emission_info(&hotkey))
+    }
+}
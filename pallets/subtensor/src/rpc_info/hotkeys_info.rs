@@ -0,0 +1,137 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+/// Maximum number of hotkeys returned in a single page.
+pub const MAX_HOTKEYS_PAGE_SIZE: u32 = 256;
+
+#[freeze_struct("3fcb0cdd58d84f31")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct HotkeysPage<T: Config> {
+    pub hotkeys: Vec<T::AccountId>,
+    pub total: Compact<u32>, // Number of hotkeys across all pages
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the page of `hotkeys` starting at `start`, holding at most `limit` hotkeys
+    /// capped at `MAX_HOTKEYS_PAGE_SIZE`.
+    fn paginate_hotkeys(hotkeys: Vec<T::AccountId>, start: u32, limit: u32) -> HotkeysPage<T> {
+        let total: u32 = hotkeys.len().try_into().unwrap_or(u32::MAX);
+        let hotkeys = hotkeys
+            .into_iter()
+            .skip(start as usize)
+            .take(limit.min(MAX_HOTKEYS_PAGE_SIZE) as usize)
+            .collect();
+
+        HotkeysPage {
+            hotkeys,
+            total: total.into(),
+        }
+    }
+
+    /// Returns a page of the hotkeys owned by `coldkey`, in their stored order: registration order
+    /// until a swap merges them, then sorted by account id. Clients needing a stable order sort
+    /// them, as `get_owned_hotkeys_sorted` does.
+    pub fn get_owned_hotkeys_paged(
+        coldkey: &T::AccountId,
+        start: u32,
+        limit: u32,
+    ) -> HotkeysPage<T> {
+        Self::paginate_hotkeys(Self::get_owned_hotkeys(coldkey), start, limit)
+    }
+
+    /// Returns a page of the hotkeys `coldkey` stakes to.
+    pub fn get_staking_hotkeys_paged(
+        coldkey: &T::AccountId,
+        start: u32,
+        limit: u32,
+    ) -> HotkeysPage<T> {
+        Self::paginate_hotkeys(Self::get_all_staked_hotkeys(coldkey), start, limit)
+    }
+
+    /// Returns a page of the hotkeys owned by `coldkey` that are registered on `netuid`
+    /// and hold stake.
+    pub fn get_owned_hotkeys_on_subnet_paged(
+        coldkey: &T::AccountId,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+    ) -> HotkeysPage<T> {
+        let hotkeys = Self::get_owned_hotkeys(coldkey)
+            .into_iter()
+            .filter(|hotkey| {
+                Self::is_hotkey_registered_on_network(netuid, hotkey)
+                    && Self::get_total_stake_for_hotkey(hotkey) > 0
+            })
+            .collect();
+
+        Self::paginate_hotkeys(hotkeys, start, limit)
+    }
+
+    /// Returns a page of the hotkeys `coldkey` stakes to that are registered on `netuid`,
+    /// skipping hotkeys where the stake of `coldkey` is zero.
+    pub fn get_staking_hotkeys_on_subnet_paged(
+        coldkey: &T::AccountId,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+    ) -> HotkeysPage<T> {
+        let hotkeys = Self::get_all_staked_hotkeys(coldkey)
+            .into_iter()
+            .filter(|hotkey| {
+                Self::is_hotkey_registered_on_network(netuid, hotkey)
+                    && Self::get_stake_for_coldkey_and_hotkey(coldkey, hotkey) > 0
+            })
+            .collect();
+
+        Self::paginate_hotkeys(hotkeys, start, limit)
+    }
+
+    pub fn get_owned_hotkeys_paged_for_coldkey(
+        coldkey_account_vec: Vec<u8>,
+        start: u32,
+        limit: u32,
+    ) -> Option<HotkeysPage<T>> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::get_owned_hotkeys_paged(&coldkey, start, limit))
+    }
+
+    pub fn get_staking_hotkeys_paged_for_coldkey(
+        coldkey_account_vec: Vec<u8>,
+        start: u32,
+        limit: u32,
+    ) -> Option<HotkeysPage<T>> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::get_staking_hotkeys_paged(&coldkey, start, limit))
+    }
+
+    pub fn get_owned_hotkeys_on_subnet_paged_for_coldkey(
+        coldkey_account_vec: Vec<u8>,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+    ) -> Option<HotkeysPage<T>> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::get_owned_hotkeys_on_subnet_paged(
+            &coldkey, netuid, start, limit,
+        ))
+    }
+
+    pub fn get_staking_hotkeys_on_subnet_paged_for_coldkey(
+        coldkey_account_vec: Vec<u8>,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+    ) -> Option<HotkeysPage<T>> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::get_staking_hotkeys_on_subnet_paged(
+            &coldkey, netuid, start, limit,
+        ))
+    }
+}
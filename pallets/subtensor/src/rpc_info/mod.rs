@@ -1,5 +1,13 @@
 use super::*;
+pub mod chain_constants;
 pub mod delegate_info;
+pub mod emission_info;
+pub mod hotkeys_info;
+pub mod network_stats;
 pub mod neuron_info;
+pub mod rate_limit_info;
+pub mod registration_info;
 pub mod stake_info;
+pub mod stake_simulation;
 pub mod subnet_info;
+pub mod weights_info;
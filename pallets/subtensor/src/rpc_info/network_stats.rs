@@ -0,0 +1,35 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+#[freeze_struct("82c30102dd695ed4")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct NetworkStats {
+    block_number: Compact<u64>,
+    total_issuance: Compact<u64>,
+    total_stake: Compact<u64>,
+    total_networks: Compact<u16>,
+    total_neurons: Compact<u32>,
+    total_delegates: Compact<u32>,
+    block_emission: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_network_stats() -> NetworkStats {
+        let total_delegates: u32 = Delegates::<T>::iter_keys()
+            .count()
+            .try_into()
+            .unwrap_or(u32::MAX);
+
+        NetworkStats {
+            block_number: Self::get_current_block_as_u64().into(),
+            total_issuance: Self::get_total_issuance().into(),
+            total_stake: Self::get_total_stake().into(),
+            total_networks: Self::get_num_subnets().into(),
+            total_neurons: Self::get_total_neuron_count().into(),
+            total_delegates: total_delegates.into(),
+            block_emission: Self::get_last_block_emission().into(),
+        }
+    }
+}
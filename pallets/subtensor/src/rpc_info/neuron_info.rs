@@ -3,8 +3,9 @@ use frame_support::pallet_prelude::{Decode, Encode};
 use frame_support::storage::IterableStorageDoubleMap;
 extern crate alloc;
 use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
 
-#[freeze_struct("45e69321f5c74b4b")]
+#[freeze_struct("83722a452a3400a3")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct NeuronInfo<T: Config> {
     hotkey: T::AccountId,
@@ -27,9 +28,10 @@ pub struct NeuronInfo<T: Config> {
     weights: Vec<(Compact<u16>, Compact<u16>)>, // Vec of (uid, weight)
     bonds: Vec<(Compact<u16>, Compact<u16>)>,   // Vec of (uid, bond)
     pruning_score: Compact<u16>,
+    is_outdated: bool, // whether the axon version is below the subnet minimum
 }
 
-#[freeze_struct("c21f0f4f22bcb2a1")]
+#[freeze_struct("d8e68f5bfcc12f1d")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct NeuronInfoLite<T: Config> {
     hotkey: T::AccountId,
@@ -51,6 +53,17 @@ pub struct NeuronInfoLite<T: Config> {
     validator_permit: bool,
     // has no weights or bonds
     pruning_score: Compact<u16>,
+    is_outdated: bool, // whether the axon version is below the subnet minimum
+}
+
+#[freeze_struct("457a5d96b33af94b")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct HotkeyRegistration {
+    pub netuid: Compact<u16>,
+    pub uid: Compact<u16>,
+    pub validator_permit: bool,
+    pub axon_info: AxonInfo,
+    pub stake: Compact<u64>, // Stake the hotkey holds on the subnet, as used by the epoch
 }
 
 impl<T: Config> Pallet<T> {
@@ -79,10 +92,11 @@ impl<T: Config> Pallet<T> {
         };
 
         let axon_info = Self::get_axon_info(netuid, &hotkey.clone());
+        let is_outdated = axon_info.version < Self::get_min_axon_version(netuid);
 
         let prometheus_info = Self::get_prometheus_info(netuid, &hotkey.clone());
 
-        let coldkey = Owner::<T>::get(hotkey.clone()).clone();
+        let coldkey = Self::get_owning_coldkey_for_hotkey(&hotkey);
 
         let active = Self::get_active_for_uid(netuid, uid);
         let rank = Self::get_rank_for_uid(netuid, uid);
@@ -142,6 +156,7 @@ impl<T: Config> Pallet<T> {
             weights,
             bonds,
             pruning_score: pruning_score.into(),
+            is_outdated,
         };
 
         Some(neuron)
@@ -162,10 +177,11 @@ impl<T: Config> Pallet<T> {
         };
 
         let axon_info = Self::get_axon_info(netuid, &hotkey.clone());
+        let is_outdated = axon_info.version < Self::get_min_axon_version(netuid);
 
         let prometheus_info = Self::get_prometheus_info(netuid, &hotkey.clone());
 
-        let coldkey = Owner::<T>::get(hotkey.clone()).clone();
+        let coldkey = Self::get_owning_coldkey_for_hotkey(&hotkey);
 
         let active = Self::get_active_for_uid(netuid, uid);
         let rank = Self::get_rank_for_uid(netuid, uid);
@@ -205,6 +221,7 @@ impl<T: Config> Pallet<T> {
             last_update: last_update.into(),
             validator_permit,
             pruning_score: pruning_score.into(),
+            is_outdated,
         };
 
         Some(neuron)
@@ -235,4 +252,55 @@ impl<T: Config> Pallet<T> {
 
         Self::get_neuron_lite_subnet_exists(netuid, uid)
     }
+
+    /// Returns the scores the pruning selection of `netuid` ranks its neurons by, indexed by uid.
+    pub fn get_pruning_scores(netuid: u16) -> Vec<u16> {
+        (0..Self::get_subnetwork_n(netuid))
+            .map(|uid| Self::get_effective_pruning_score_for_uid(netuid, uid))
+            .collect()
+    }
+
+    /// Returns the score the pruning selection of `netuid` ranks `uid` by, or `None` if the uid
+    /// is not registered.
+    pub fn get_pruning_score_for_neuron(netuid: u16, uid: u16) -> Option<u16> {
+        if uid >= Self::get_subnetwork_n(netuid) {
+            return None;
+        }
+        Some(Self::get_effective_pruning_score_for_uid(netuid, uid))
+    }
+
+    /// Returns the registrations of `hotkey` on every subnet it is a member of, ordered by netuid.
+    pub fn get_hotkey_registrations(hotkey: &T::AccountId) -> Vec<HotkeyRegistration> {
+        let mut netuids = Self::get_registered_networks_for_hotkey(hotkey);
+        netuids.sort();
+
+        netuids
+            .into_iter()
+            .filter_map(|netuid| {
+                let uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey).ok()?;
+                Some(HotkeyRegistration {
+                    netuid: netuid.into(),
+                    uid: uid.into(),
+                    validator_permit: Self::get_validator_permit_for_uid(netuid, uid),
+                    axon_info: Self::get_axon_info(netuid, hotkey),
+                    stake: Self::get_stake_for_hotkey_on_subnet(hotkey, netuid).into(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn get_hotkey_registrations_for_account(
+        hotkey_account_vec: Vec<u8>,
+    ) -> Option<Vec<HotkeyRegistration>> {
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        Some(Self::get_hotkey_registrations(&hotkey))
+    }
+
+    /// Returns the netuids the hotkey is registered on, in ascending order.
+    pub fn get_netuids_for_hotkey_account(hotkey_account_vec: Vec<u8>) -> Option<Vec<u16>> {
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        let mut netuids = Self::get_registered_networks_for_hotkey(&hotkey);
+        netuids.sort();
+        Some(netuids)
+    }
 }
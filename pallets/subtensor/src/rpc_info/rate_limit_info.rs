@@ -0,0 +1,115 @@
+use super::*;
+use crate::utils::rate_limiting::TransactionType;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+#[freeze_struct("672069f093a66d7b")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct RateLimitStatus {
+    pub operation: RateLimitedOperation,
+    pub last_block: Option<Compact<u64>>, // None if the operation never ran for the key
+    pub rate_limit: Compact<u64>,         // Blocks between two runs of the operation
+    pub blocks_remaining: Compact<u64>,   // Zero if the operation can run now
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the rate limit window of each operation `hotkey` runs on `netuid`, with the blocks
+    /// left until it can run again.
+    ///
+    /// The transaction and delegate take limits are kept per coldkey, and reported for the owner
+    /// of the hotkey. Registrations are not rate limited per hotkey, so they are not listed.
+    pub fn get_hotkey_rate_limit_status(
+        netuid: u16,
+        hotkey: &T::AccountId,
+    ) -> Vec<RateLimitStatus> {
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let coldkey: T::AccountId = Owner::<T>::get(hotkey);
+        let serving_rate_limit: u64 = Self::get_serving_rate_limit(netuid);
+        let last_weights_block: u64 = Self::get_uid_for_net_and_hotkey(netuid, hotkey)
+            .map_or(0, |uid| Self::get_last_update_for_uid(netuid, uid));
+        let children = TransactionType::SetChildren;
+        let childkey_take = TransactionType::SetChildkeyTake;
+
+        // The transaction and delegate take limits also hold on the block the window ends on.
+        let windows: [(RateLimitedOperation, u64, u64, bool); 8] = [
+            (
+                RateLimitedOperation::Transaction,
+                Self::get_last_tx_block(&coldkey),
+                Self::get_tx_rate_limit(),
+                true,
+            ),
+            (
+                RateLimitedOperation::DelegateTake,
+                Self::get_last_tx_block_delegate_take(&coldkey),
+                Self::get_tx_delegate_take_rate_limit(),
+                true,
+            ),
+            (
+                RateLimitedOperation::ServeAxon,
+                Axons::<T>::get(netuid, hotkey).map_or(0, |axon| axon.block),
+                serving_rate_limit,
+                false,
+            ),
+            (
+                RateLimitedOperation::ServePrometheus,
+                Prometheus::<T>::get(netuid, hotkey).map_or(0, |prometheus| prometheus.block),
+                serving_rate_limit,
+                false,
+            ),
+            (
+                RateLimitedOperation::SetWeights,
+                last_weights_block,
+                Self::get_weights_set_rate_limit(netuid),
+                false,
+            ),
+            (
+                RateLimitedOperation::SetCommitment,
+                T::Commitments::last_commitment_block(netuid, hotkey).unwrap_or(0),
+                T::Commitments::commitment_rate_limit(netuid),
+                false,
+            ),
+            (
+                RateLimitedOperation::SetChildren,
+                Self::get_last_transaction_block(hotkey, netuid, &children),
+                Self::get_rate_limit(&children),
+                false,
+            ),
+            (
+                RateLimitedOperation::SetChildkeyTake,
+                Self::get_last_transaction_block(hotkey, netuid, &childkey_take),
+                Self::get_rate_limit(&childkey_take),
+                false,
+            ),
+        ];
+
+        windows
+            .into_iter()
+            .map(|(operation, last_block, rate_limit, inclusive)| {
+                let blocks_remaining: u64 = if last_block == 0 || rate_limit == 0 {
+                    0
+                } else {
+                    last_block
+                        .saturating_add(rate_limit)
+                        .saturating_add(u64::from(inclusive))
+                        .saturating_sub(current_block)
+                };
+                RateLimitStatus {
+                    operation,
+                    last_block: (last_block > 0).then_some(last_block.into()),
+                    rate_limit: rate_limit.into(),
+                    blocks_remaining: blocks_remaining.into(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn get_hotkey_rate_limit_status_for_account(
+        netuid: u16,
+        hotkey_account_vec: Vec<u8>,
+    ) -> Option<Vec<RateLimitStatus>> {
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        Some(Self::get_hotkey_rate_limit_status(netuid, &hotkey))
+    }
+}
@@ -0,0 +1,43 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+#[freeze_struct("22adc48847e55e93")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct RegistrationInfo {
+    pub burn: Compact<u64>,
+    pub difficulty: Compact<u64>,
+    pub registrations_this_interval: Compact<u16>,
+    pub target_registrations_per_interval: Compact<u16>,
+    pub blocks_until_adjustment: Compact<u64>,
+    pub adjustment_history: Vec<(Compact<u64>, Compact<u64>, Compact<u64>)>, // ( block, burn, difficulty ), oldest first
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the current registration terms of a subnet and its last adjustments, or `None` if
+    /// the subnet does not exist.
+    pub fn get_registration_info(netuid: u16) -> Option<RegistrationInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let next_adjustment_block: u64 = Self::get_last_adjustment_block(netuid)
+            .saturating_add(Self::get_adjustment_interval(netuid) as u64);
+
+        Some(RegistrationInfo {
+            burn: Self::get_burn_as_u64(netuid).into(),
+            difficulty: Self::get_difficulty_as_u64(netuid).into(),
+            registrations_this_interval: Self::get_registrations_this_interval(netuid).into(),
+            target_registrations_per_interval: Self::get_target_registrations_per_interval(netuid)
+                .into(),
+            blocks_until_adjustment: next_adjustment_block
+                .saturating_sub(Self::get_current_block_as_u64())
+                .into(),
+            adjustment_history: RegistrationAdjustmentHistory::<T>::get(netuid)
+                .into_iter()
+                .map(|(block, burn, difficulty)| (block.into(), burn.into(), difficulty.into()))
+                .collect(),
+        })
+    }
+}
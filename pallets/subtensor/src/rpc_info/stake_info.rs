@@ -12,6 +12,37 @@ pub struct StakeInfo<T: Config> {
     stake: Compact<u64>,
 }
 
+/// Maximum number of stakes returned in a single page.
+pub const MAX_STAKES_PAGE_SIZE: u32 = 256;
+
+#[freeze_struct("2121b690453e502e")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetStakesPage<T: Config> {
+    pub stakes: Vec<(T::AccountId, Compact<u64>)>,
+    pub total: Compact<u32>, // Number of hotkeys registered on the subnet
+}
+
+/// Maximum number of nominators returned in a single page.
+pub const MAX_NOMINATORS_PAGE_SIZE: u32 = 512;
+
+#[freeze_struct("dbba7a2be59e0860")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct NominatorsPage<T: Config> {
+    pub nominators: Vec<(T::AccountId, Compact<u64>)>,
+    pub total: Compact<u32>, // Number of coldkeys with a non-zero stake on the hotkey
+    pub truncated: bool,     // Whether nominators remain after this page
+}
+
+#[freeze_struct("4163f6cc0174f15f")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct StakeRateLimits {
+    pub stakes_this_interval: Compact<u64>, // Stake operations of the coldkey on the hotkey
+    pub target_stakes_per_interval: Compact<u64>, // Limit of stake operations on the hotkey
+    pub coldkey_stake_ops_this_interval: Compact<u64>, // Stake operations over all hotkeys
+    pub max_stake_ops_per_interval_per_coldkey: Compact<u64>, // Limit over all hotkeys
+    pub stake_interval: Compact<u64>,       // Blocks after which both counters reset
+}
+
 impl<T: Config> Pallet<T> {
     fn _get_stake_info_for_coldkeys(
         coldkeys: Vec<T::AccountId>,
@@ -81,4 +112,166 @@ impl<T: Config> Pallet<T> {
             first.1.clone()
         }
     }
+
+    /// Returns a page of the hotkeys registered on `netuid` with their stake on the subnet,
+    /// sorted by stake descending, starting at `start` and holding at most `limit` entries
+    /// capped at `MAX_STAKES_PAGE_SIZE`.
+    ///
+    /// Stake is the hotkey stake on the subnet after child and parent adjustments, as reported
+    /// in the neuron info. Ties are ordered by uid, so pages are stable between calls at the
+    /// same block.
+    ///
+    /// The ranking is computed on the fly: the subnet's `n` hotkeys are read and sorted on every
+    /// call, which is O(n log n) with `n` bounded by the max allowed uids of the subnet.
+    pub fn get_top_stakes_on_subnet(netuid: u16, start: u32, limit: u32) -> SubnetStakesPage<T> {
+        let mut stakes: Vec<(u16, T::AccountId, u64)> = Keys::<T>::iter_prefix(netuid)
+            .map(|(uid, hotkey)| {
+                let stake = Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid);
+                (uid, hotkey, stake)
+            })
+            .collect();
+        stakes.sort_by(|(uid_a, _, stake_a), (uid_b, _, stake_b)| {
+            stake_b.cmp(stake_a).then(uid_a.cmp(uid_b))
+        });
+
+        let total: u32 = stakes.len().try_into().unwrap_or(u32::MAX);
+        let stakes = stakes
+            .into_iter()
+            .skip(start as usize)
+            .take(limit.min(MAX_STAKES_PAGE_SIZE) as usize)
+            .map(|(_, hotkey, stake)| (hotkey, stake.into()))
+            .collect();
+
+        SubnetStakesPage {
+            stakes,
+            total: total.into(),
+        }
+    }
+
+    /// Returns the stake of `coldkey` on `hotkey`, or zero if `hotkey` is not registered
+    /// on `netuid`.
+    pub fn get_stake_for_hotkey_and_coldkey_on_netuid(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: u16,
+    ) -> u64 {
+        if !Self::is_hotkey_registered_on_network(netuid, hotkey) {
+            return 0;
+        }
+        Self::get_stake_for_coldkey_and_hotkey(coldkey, hotkey)
+    }
+
+    pub fn get_stake_for_hotkey_and_coldkey_on_netuid_for_accounts(
+        hotkey_account_vec: Vec<u8>,
+        coldkey_account_vec: Vec<u8>,
+        netuid: u16,
+    ) -> Option<u64> {
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::get_stake_for_hotkey_and_coldkey_on_netuid(
+            &hotkey, &coldkey, netuid,
+        ))
+    }
+
+    pub fn get_hotkey_stake_breakdown_for_account(
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+    ) -> Option<HotkeyStakeBreakdown> {
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::get_hotkey_stake_breakdown(&hotkey, netuid))
+    }
+
+    /// Returns a page of the coldkeys with a non-zero stake on `hotkey` and their stake, starting
+    /// at `start` and holding at most `limit` entries capped at `MAX_NOMINATORS_PAGE_SIZE`. The
+    /// page is empty if `hotkey` is not registered on `netuid`.
+    ///
+    /// Coldkeys are read from the `Stake` prefix of the hotkey, whose identity hashed keys keep
+    /// them ordered by their encoding, so pages are stable between calls at the same block. The
+    /// total is read from `NominatorCount` rather than counted.
+    pub fn get_nominators_for_hotkey(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+    ) -> NominatorsPage<T> {
+        let total: u32 = Self::get_nominator_count(hotkey, netuid);
+        if total == 0 {
+            return NominatorsPage {
+                nominators: Vec::new(),
+                total: 0.into(),
+                truncated: false,
+            };
+        }
+
+        let nominators: Vec<(T::AccountId, Compact<u64>)> = Stake::<T>::iter_prefix(hotkey)
+            .filter(|(_, stake)| *stake > 0)
+            .skip(start as usize)
+            .take(limit.min(MAX_NOMINATORS_PAGE_SIZE) as usize)
+            .map(|(coldkey, stake)| (coldkey, stake.into()))
+            .collect();
+        let returned: u32 = nominators.len().try_into().unwrap_or(u32::MAX);
+
+        NominatorsPage {
+            nominators,
+            total: total.into(),
+            truncated: start.saturating_add(returned) < total,
+        }
+    }
+
+    /// Returns the number of coldkeys with a non-zero stake on `hotkey`, or zero if `hotkey` is
+    /// not registered on `netuid`.
+    pub fn get_nominator_count(hotkey: &T::AccountId, netuid: u16) -> u32 {
+        if !Self::is_hotkey_registered_on_network(netuid, hotkey) {
+            return 0;
+        }
+        NominatorCount::<T>::get(hotkey)
+    }
+
+    pub fn get_nominators_for_hotkey_account(
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+        start: u32,
+        limit: u32,
+    ) -> Option<NominatorsPage<T>> {
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        Some(Self::get_nominators_for_hotkey(
+            &hotkey, netuid, start, limit,
+        ))
+    }
+
+    /// Returns the stake operations of `coldkey` in the current staking interval, on `hotkey` and
+    /// over all its hotkeys, along with the limits each counter is checked against.
+    pub fn get_stake_rate_limits(coldkey: &T::AccountId, hotkey: &T::AccountId) -> StakeRateLimits {
+        let stakes_this_interval =
+            Self::peek_stakes_this_interval_for_coldkey_hotkey(coldkey, hotkey);
+        let coldkey_stake_ops_this_interval =
+            Self::peek_stake_ops_this_interval_for_coldkey(coldkey);
+        StakeRateLimits {
+            stakes_this_interval: stakes_this_interval.into(),
+            target_stakes_per_interval: Self::get_target_stakes_per_interval().into(),
+            coldkey_stake_ops_this_interval: coldkey_stake_ops_this_interval.into(),
+            max_stake_ops_per_interval_per_coldkey:
+                Self::get_max_stake_ops_per_interval_per_coldkey().into(),
+            stake_interval: StakeInterval::<T>::get().into(),
+        }
+    }
+
+    pub fn get_stake_rate_limits_for_accounts(
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+    ) -> Option<StakeRateLimits> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        Some(Self::get_stake_rate_limits(&coldkey, &hotkey))
+    }
+
+    pub fn get_nominator_count_for_account(
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+    ) -> Option<u32> {
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        Some(Self::get_nominator_count(&hotkey, netuid))
+    }
 }
@@ -0,0 +1,188 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+use sp_runtime::traits::Hash;
+
+#[freeze_struct("80e5badd2f6ee388")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct StakeSimulation {
+    pub allowed: bool,
+    pub failure_reason: Option<u8>, // Index of the pallet error the call would fail with
+    pub expected_tao: Compact<u64>, // TAO moved between the coldkey balance and the stake
+}
+
+impl<T: Config> Pallet<T> {
+    /// Simulates `remove_stake` of `amount` by `coldkey` from `hotkey` at the current state, without
+    /// mutating it.
+    ///
+    /// The checks mirror `do_remove_stake` in the same order, so the reported failure is the error
+    /// the call would fail with. Stake is denominated in TAO, so the expected TAO is the resolved
+    /// amount credited to the coldkey.
+    pub fn simulate_remove_stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) -> StakeSimulation {
+        Self::stake_simulation(Self::check_remove_stake(coldkey, hotkey, amount))
+    }
+
+    /// Simulates `add_stake` of `amount` by `coldkey` to `hotkey` at the current state, without
+    /// mutating it.
+    ///
+    /// The checks mirror `do_add_stake` in the same order. The expected TAO is the amount moved from
+    /// the coldkey balance into the stake.
+    pub fn simulate_add_stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) -> StakeSimulation {
+        Self::stake_simulation(Self::check_add_stake(coldkey, hotkey, amount))
+    }
+
+    pub fn simulate_remove_stake_for_accounts(
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+        amount: u64,
+    ) -> Option<StakeSimulation> {
+        let (coldkey, hotkey) =
+            Self::decode_simulation_accounts(coldkey_account_vec, hotkey_account_vec)?;
+
+        Some(Self::simulate_remove_stake(&coldkey, &hotkey, amount))
+    }
+
+    pub fn simulate_add_stake_for_accounts(
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+        amount: u64,
+    ) -> Option<StakeSimulation> {
+        let (coldkey, hotkey) =
+            Self::decode_simulation_accounts(coldkey_account_vec, hotkey_account_vec)?;
+
+        Some(Self::simulate_add_stake(&coldkey, &hotkey, amount))
+    }
+
+    fn decode_simulation_accounts(
+        coldkey_account_vec: Vec<u8>,
+        hotkey_account_vec: Vec<u8>,
+    ) -> Option<(T::AccountId, T::AccountId)> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        Some((coldkey, hotkey))
+    }
+
+    fn stake_simulation(result: Result<u64, Error<T>>) -> StakeSimulation {
+        match result {
+            Ok(expected_tao) => StakeSimulation {
+                allowed: true,
+                failure_reason: None,
+                expected_tao: expected_tao.into(),
+            },
+            Err(error) => StakeSimulation {
+                allowed: false,
+                // The first byte of an encoded pallet error is its index in the `Error` enum.
+                failure_reason: error.encode().first().copied(),
+                expected_tao: 0.into(),
+            },
+        }
+    }
+
+    fn check_remove_stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) -> Result<u64, Error<T>> {
+        ensure!(
+            Self::hotkey_account_exists(hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            Self::hotkey_is_delegate(hotkey) || Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        let stake_to_be_removed: u64 = if amount == u64::MAX {
+            Self::get_stake_for_coldkey_and_hotkey(coldkey, hotkey)
+        } else {
+            amount
+        };
+        ensure!(stake_to_be_removed > 0, Error::<T>::StakeToWithdrawIsZero);
+        ensure!(
+            Self::has_enough_stake(coldkey, hotkey, stake_to_be_removed),
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+        ensure!(
+            Self::peek_stakes_this_interval_for_coldkey_hotkey(coldkey, hotkey)
+                < Self::get_target_stakes_per_interval(),
+            Error::<T>::UnstakeRateLimitExceeded
+        );
+        ensure!(
+            Self::peek_stake_ops_this_interval_for_coldkey(coldkey)
+                < Self::get_max_stake_ops_per_interval_per_coldkey(),
+            Error::<T>::ColdkeyStakeRateLimitExceeded
+        );
+
+        if Self::unstake_requires_coldkey_auth(coldkey, stake_to_be_removed) {
+            let call_hash =
+                T::Hashing::hash_of(&<T as Config>::RuntimeCall::from(Call::<T>::remove_stake {
+                    hotkey: hotkey.clone(),
+                    amount_unstaked: amount,
+                }));
+            ensure!(
+                Self::coldkey_call_is_authorized(coldkey, call_hash),
+                Error::<T>::AuthorizationRequired
+            );
+        }
+
+        ensure!(
+            Self::can_add_balance_to_coldkey_account(coldkey, stake_to_be_removed),
+            Error::<T>::AmountTooLowToCreateAccount
+        );
+
+        Ok(stake_to_be_removed)
+    }
+
+    fn check_add_stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) -> Result<u64, Error<T>> {
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(coldkey, amount),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+        ensure!(
+            Self::hotkey_account_exists(hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            Self::hotkey_is_delegate(hotkey) || Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+        ensure!(
+            Self::peek_stakes_this_interval_for_coldkey_hotkey(coldkey, hotkey)
+                < Self::get_target_stakes_per_interval(),
+            Error::<T>::StakeRateLimitExceeded
+        );
+        ensure!(
+            Self::peek_stake_ops_this_interval_for_coldkey(coldkey)
+                < Self::get_max_stake_ops_per_interval_per_coldkey(),
+            Error::<T>::ColdkeyStakeRateLimitExceeded
+        );
+
+        if !Self::coldkey_owns_hotkey(coldkey, hotkey) {
+            ensure!(
+                Self::get_stake_for_coldkey_and_hotkey(coldkey, hotkey).saturating_add(amount)
+                    >= Self::get_nominator_min_required_stake(),
+                Error::<T>::NomStakeBelowMinimumThreshold
+            );
+            ensure!(
+                Self::owner_stake_meets_delegate_minimum(hotkey),
+                Error::<T>::DelegateBelowOwnerStakeMinimum
+            );
+        }
+
+        Ok(amount)
+    }
+}
@@ -27,7 +27,7 @@ pub struct SubnetInfo<T: Config> {
     owner: T::AccountId,
 }
 
-#[freeze_struct("65f931972fa13222")]
+#[freeze_struct("16d1de8a44cfdbdc")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct SubnetInfov2<T: Config> {
     netuid: Compact<u16>,
@@ -49,6 +49,8 @@ pub struct SubnetInfov2<T: Config> {
     burn: Compact<u64>,
     owner: T::AccountId,
     identity: Option<SubnetIdentity>,
+    warning: bool, // The subnet has been without emission for SubnetWarningTempos
+    emission_multiplier: Compact<u32>, // Scale of the subnet emission share, in millionths
 }
 
 #[freeze_struct("55b472510f10e76a")]
@@ -101,7 +103,7 @@ impl<T: Config> Pallet<T> {
         let max_allowed_uids = Self::get_max_allowed_uids(netuid);
         let blocks_since_last_step = Self::get_blocks_since_last_step(netuid);
         let tempo = Self::get_tempo(netuid);
-        let network_modality = <NetworkModality<T>>::get(netuid);
+        let network_modality = Self::get_network_modality(netuid);
         let emission_values = Self::get_emission_value(netuid);
         let burn: Compact<u64> = Self::get_burn_as_u64(netuid).into();
         // DEPRECATED
@@ -154,6 +156,24 @@ impl<T: Config> Pallet<T> {
         subnets_info
     }
 
+    /// Returns the netuids of all added subnets, in ascending order.
+    pub fn get_subnet_netuids() -> Vec<u16> {
+        let mut subnet_netuids: Vec<u16> =
+            <NetworksAdded<T> as IterableStorageMap<u16, bool>>::iter()
+                .filter(|(_, added)| *added)
+                .map(|(netuid, _)| netuid)
+                .collect();
+        subnet_netuids.sort();
+        subnet_netuids
+    }
+
+    /// Returns the number of added subnets.
+    pub fn get_subnets_count() -> u16 {
+        <NetworksAdded<T> as IterableStorageMap<u16, bool>>::iter()
+            .filter(|(_, added)| *added)
+            .count() as u16
+    }
+
     pub fn get_subnet_info_v2(netuid: u16) -> Option<SubnetInfov2<T>> {
         if !Self::if_subnet_exist(netuid) {
             return None;
@@ -171,10 +191,10 @@ impl<T: Config> Pallet<T> {
         let max_allowed_uids = Self::get_max_allowed_uids(netuid);
         let blocks_since_last_step = Self::get_blocks_since_last_step(netuid);
         let tempo = Self::get_tempo(netuid);
-        let network_modality = <NetworkModality<T>>::get(netuid);
+        let network_modality = Self::get_network_modality(netuid);
         let emission_values = Self::get_emission_value(netuid);
         let burn: Compact<u64> = Self::get_burn_as_u64(netuid).into();
-        let identity: Option<SubnetIdentity> = SubnetIdentities::<T>::get(netuid);
+        let identity: Option<SubnetIdentity> = Self::get_subnet_identity(netuid);
 
         // DEPRECATED
         let network_connect: Vec<[u16; 2]> = Vec::<[u16; 2]>::new();
@@ -202,6 +222,8 @@ impl<T: Config> Pallet<T> {
             burn,
             owner: Self::get_subnet_owner(netuid),
             identity,
+            warning: Self::get_subnet_warning(netuid),
+            emission_multiplier: Self::get_emission_multiplier(netuid).into(),
         })
     }
     pub fn get_subnets_info_v2() -> Vec<Option<SubnetInfo<T>>> {
@@ -287,4 +309,14 @@ impl<T: Config> Pallet<T> {
             liquid_alpha_enabled,
         })
     }
+
+    /// Returns the epoch summaries kept in the epoch history of a subnet, oldest first.
+    pub fn get_epoch_history(netuid: u16) -> Vec<EpochSummary> {
+        let depth: u16 = Self::get_epoch_history_depth(netuid);
+        let next_slot: u16 = EpochHistoryNextSlot::<T>::get(netuid);
+        (next_slot..depth)
+            .chain(0..next_slot.min(depth))
+            .filter_map(|slot| EpochHistory::<T>::get(netuid, slot))
+            .collect()
+    }
 }
@@ -0,0 +1,109 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+#[freeze_struct("205f5fabde5ef41c")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct WeightSettingStatus {
+    pub registered: bool,
+    pub uid: Option<Compact<u16>>,
+    pub validator_permit: bool,
+    pub stake: Compact<u64>,
+    pub stake_threshold: Compact<u64>,
+    pub blocks_since_last_set: Option<Compact<u64>>, // None if the neuron never set weights
+    pub weights_set_rate_limit: Compact<u64>,
+    pub commit_reveal_enabled: bool,
+    pub commit_block: Option<Compact<u64>>, // Block of the pending weights commit, if any
+    pub can_commit: bool,
+    pub can_reveal: bool,
+    pub can_set_now: bool,
+    pub failure_reason: Option<u8>, // Index of the pallet error `set_weights` would fail with
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns whether `hotkey` can set weights on `netuid` at the current block, and the state
+    /// that decides it.
+    ///
+    /// `can_set_now` mirrors the `set_weights` extrinsic for non-self weights, checked in the same
+    /// order, so `failure_reason` is the error the call would fail with. Checks that depend on the
+    /// submitted weights (lengths, uids, version key) are not covered. With commit-reveal enabled
+    /// `set_weights` is rejected, and `can_commit` and `can_reveal` report the commit queue state.
+    pub fn get_weight_setting_status(netuid: u16, hotkey: &T::AccountId) -> WeightSettingStatus {
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey).ok();
+        let current_block = Self::get_current_block_as_u64();
+        let commit_reveal_enabled = Self::get_commit_reveal_weights_enabled(netuid);
+        let commit_block = WeightCommits::<T>::get(netuid, hotkey).map(|(_, block)| block);
+
+        let failure_reason = Self::check_set_weights_now(netuid, hotkey)
+            .err()
+            // The first byte of an encoded pallet error is its index in the `Error` enum.
+            .and_then(|error| error.encode().first().copied());
+
+        WeightSettingStatus {
+            registered: uid.is_some(),
+            uid: uid.map(Into::into),
+            validator_permit: uid
+                .is_some_and(|uid| Self::get_validator_permit_for_uid(netuid, uid)),
+            stake: Self::get_total_stake_for_hotkey(hotkey).into(),
+            stake_threshold: Self::get_stake_threshold_for_subnet(netuid).into(),
+            blocks_since_last_set: Self::get_last_weights_set_block(netuid, hotkey)
+                .map(|last| current_block.saturating_sub(last).into()),
+            weights_set_rate_limit: Self::get_weights_set_rate_limit(netuid).into(),
+            commit_reveal_enabled,
+            commit_block: commit_block.map(Into::into),
+            can_commit: commit_reveal_enabled
+                && Self::check_weights_min_stake(hotkey, netuid)
+                && Self::can_commit(netuid, hotkey),
+            can_reveal: commit_reveal_enabled
+                && commit_block.is_some_and(|block| Self::is_reveal_block_range(netuid, block)),
+            can_set_now: failure_reason.is_none(),
+            failure_reason,
+        }
+    }
+
+    pub fn get_weight_setting_status_for_account(
+        netuid: u16,
+        hotkey_account_vec: Vec<u8>,
+    ) -> Option<WeightSettingStatus> {
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        Some(Self::get_weight_setting_status(netuid, &hotkey))
+    }
+
+    fn check_set_weights_now(netuid: u16, hotkey: &T::AccountId) -> Result<(), Error<T>> {
+        ensure!(
+            !Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealEnabled
+        );
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::CanNotSetRootNetworkWeights
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        ensure!(
+            Self::check_weights_min_stake(hotkey, netuid),
+            Error::<T>::NotEnoughStakeToSetWeights
+        );
+
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey)
+            .map_err(|_| Error::<T>::HotKeyNotRegisteredInSubNet)?;
+        ensure!(
+            Self::check_rate_limit(netuid, uid, Self::get_current_block_as_u64()),
+            Error::<T>::SettingWeightsTooFast
+        );
+        ensure!(
+            Self::get_validator_permit_for_uid(netuid, uid),
+            Error::<T>::NeuronNoValidatorPermit
+        );
+
+        Ok(())
+    }
+}
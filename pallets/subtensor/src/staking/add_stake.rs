@@ -30,6 +30,9 @@ impl<T: Config> Pallet<T> {
     /// * 'TxRateLimitExceeded':
     ///     -  Thrown if key has hit transaction rate limit
     ///
+    /// * 'DelegateBelowOwnerStakeMinimum':
+    ///     -  The hotkey is a delegate whose owner has less stake on it than the minimum.
+    ///
     pub fn do_add_stake(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
@@ -44,6 +47,12 @@ impl<T: Config> Pallet<T> {
             stake_to_be_added
         );
 
+        // Ensure staking operations are not paused.
+        Self::ensure_staking_not_paused()?;
+
+        // Release the part of the vesting lock of the coldkey the stake is paid from.
+        Self::unlock_vesting_for_stake(&coldkey, stake_to_be_added);
+
         // Ensure the callers coldkey has enough stake to perform the transaction.
         ensure!(
             Self::can_remove_balance_from_coldkey_account(&coldkey, stake_to_be_added),
@@ -70,6 +79,14 @@ impl<T: Config> Pallet<T> {
             Error::<T>::StakeRateLimitExceeded
         );
 
+        // Ensure the coldkey does not exceed its stake rate limit over all its hotkeys.
+        let coldkey_stake_ops_this_interval =
+            Self::get_stake_ops_this_interval_for_coldkey(&coldkey);
+        ensure!(
+            coldkey_stake_ops_this_interval < Self::get_max_stake_ops_per_interval_per_coldkey(),
+            Error::<T>::ColdkeyStakeRateLimitExceeded
+        );
+
         // Set the last time the stake increased for nominator drain protection.
         LastAddStakeIncrease::<T>::insert(&hotkey, &coldkey, Self::get_current_block_as_u64());
 
@@ -82,6 +99,12 @@ impl<T: Config> Pallet<T> {
                 total_stake_after_add >= NominatorMinRequiredStake::<T>::get(),
                 Error::<T>::NomStakeBelowMinimumThreshold
             );
+
+            // Ensure the delegate owner still has enough skin in the game to take nominations.
+            ensure!(
+                Self::owner_stake_meets_delegate_minimum(&hotkey),
+                Error::<T>::DelegateBelowOwnerStakeMinimum
+            );
         }
 
         // Ensure the remove operation from the coldkey is a success.
@@ -102,14 +125,56 @@ impl<T: Config> Pallet<T> {
             stakes_this_interval.saturating_add(1),
             block,
         );
+        Self::set_stake_ops_this_interval_for_coldkey(
+            &coldkey,
+            coldkey_stake_ops_this_interval.saturating_add(1),
+            block,
+        );
         log::debug!(
-            "StakeAdded( hotkey:{:?}, stake_to_be_added:{:?} )",
+            "StakeAdded( coldkey:{:?}, hotkey:{:?}, stake_to_be_added:{:?} )",
+            coldkey,
             hotkey,
             actual_amount_to_stake
         );
-        Self::deposit_event(Event::StakeAdded(hotkey, actual_amount_to_stake));
+        Self::deposit_event(Event::StakeAdded {
+            coldkey,
+            hotkey,
+            amount: actual_amount_to_stake,
+        });
 
         // Ok and return.
         Ok(())
     }
+
+    /// Returns the limit and the provided value behind an `add_stake` failing with `error`, as
+    /// `(expected, provided)`, for the errors that have one.
+    pub fn add_stake_failure_detail(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        stake_to_be_added: u64,
+        error: &Error<T>,
+    ) -> Option<(u64, u64)> {
+        match error {
+            Error::<T>::NotEnoughBalanceToStake => {
+                Some((Self::get_coldkey_balance(coldkey), stake_to_be_added))
+            }
+            Error::<T>::StakeRateLimitExceeded => Some((
+                Self::get_target_stakes_per_interval(),
+                Self::peek_stakes_this_interval_for_coldkey_hotkey(coldkey, hotkey),
+            )),
+            Error::<T>::ColdkeyStakeRateLimitExceeded => Some((
+                Self::get_max_stake_ops_per_interval_per_coldkey(),
+                Self::peek_stake_ops_this_interval_for_coldkey(coldkey),
+            )),
+            Error::<T>::NomStakeBelowMinimumThreshold => Some((
+                NominatorMinRequiredStake::<T>::get(),
+                Stake::<T>::get(hotkey, coldkey).saturating_add(stake_to_be_added),
+            )),
+            Error::<T>::DelegateBelowOwnerStakeMinimum => Some((
+                Self::get_min_owner_stake_to_delegate(),
+                Self::get_owner_stake_on_hotkey(hotkey),
+            )),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,75 @@
+use super::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{Verify, Zero};
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic associate_hotkey: Makes the caller's coldkey the
+    /// owner of an unowned hotkey, without registering it on any subnet.
+    ///
+    /// The hotkey consents by signing the encoded
+    /// `(b"subtensor/associate_hotkey", coldkey, genesis_hash, nonce)`, where `nonce` is the current
+    /// `AssociationNonce` of the hotkey. The nonce is incremented on success and the genesis hash
+    /// ties the signature to this chain, so a signature cannot be replayed.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey to associate.
+    ///
+    /// * 'hotkey_signature' (T::CallSignature):
+    ///     -  The signature of the hotkey over the coldkey.
+    ///
+    /// # Event:
+    /// * HotkeyAssociated;
+    ///     -  On successfully associating the hotkey.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAlreadyOwned':
+    ///     -  Thrown if the hotkey is already owned by a coldkey.
+    ///
+    /// * 'InvalidAssociationSignature':
+    ///     -  Thrown if the signature is not by the hotkey over this coldkey and its current nonce.
+    ///
+    /// * 'TooManyHotkeysForColdkey':
+    ///     -  Thrown if the coldkey already owns the maximum number of hotkeys.
+    ///
+    pub fn do_associate_hotkey(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        hotkey_signature: T::CallSignature,
+    ) -> dispatch::DispatchResult {
+        // --- 1. Check that the coldkey has signed the transaction.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_associate_hotkey( coldkey:{:?} hotkey:{:?} )",
+            coldkey,
+            hotkey
+        );
+
+        // --- 2. Ensure the hotkey is not owned, so an owned hotkey cannot be taken over.
+        ensure!(
+            !Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAlreadyOwned
+        );
+
+        // --- 3. Ensure the hotkey signed this coldkey on this chain with its current nonce.
+        let nonce: u64 = AssociationNonce::<T>::get(&hotkey);
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+        let message = (b"subtensor/associate_hotkey", &coldkey, genesis_hash, nonce).encode();
+        ensure!(
+            hotkey_signature.verify(message.as_slice(), &hotkey),
+            Error::<T>::InvalidAssociationSignature
+        );
+
+        // --- 4. Create the hotkey account owned by the coldkey.
+        Self::create_account_if_non_existent(&coldkey, &hotkey)?;
+
+        // --- 5. Use up the nonce so that the signature cannot be replayed.
+        AssociationNonce::<T>::insert(&hotkey, nonce.saturating_add(1));
+
+        Self::deposit_event(Event::HotkeyAssociated { coldkey, hotkey });
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_auto_redelegate: sets the hotkey the stake of
+    /// the caller moves to when a hotkey it stakes on stays deregistered from every subnet.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the nominator coldkey.
+    ///
+    /// * 'fallback_hotkey' (Option<T::AccountId>):
+    ///     - The hotkey to move the stake to, or None to only be warned.
+    ///
+    /// # Event:
+    /// * AutoRedelegateSet;
+    ///     - On successfully setting or clearing the fallback hotkey.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     - The fallback hotkey does not exist.
+    ///
+    pub fn do_set_auto_redelegate(
+        origin: T::RuntimeOrigin,
+        fallback_hotkey: Option<T::AccountId>,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        match &fallback_hotkey {
+            Some(hotkey) => {
+                ensure!(
+                    Self::hotkey_account_exists(hotkey),
+                    Error::<T>::HotKeyAccountNotExists
+                );
+                AutoRedelegate::<T>::insert(&coldkey, hotkey);
+            }
+            None => AutoRedelegate::<T>::remove(&coldkey),
+        }
+
+        log::debug!(
+            "AutoRedelegateSet( coldkey:{:?} fallback_hotkey:{:?} )",
+            coldkey,
+            fallback_hotkey
+        );
+        Self::deposit_event(Event::AutoRedelegateSet {
+            coldkey,
+            fallback_hotkey,
+        });
+        Ok(())
+    }
+
+    /// Queues `hotkey` for redelegation once it has been off every subnet for
+    /// `AutoRedelegateAfterTempos` tempos of `netuid`, the subnet it just left.
+    ///
+    /// Hotkeys still registered elsewhere, without stake or already queued are skipped.
+    pub fn queue_inactive_hotkey(netuid: u16, hotkey: &T::AccountId) {
+        if Self::is_hotkey_registered_on_any_network(hotkey)
+            || TotalHotkeyStake::<T>::get(hotkey) == 0
+        {
+            return;
+        }
+
+        let blocks_per_tempo: u64 = u64::from(Self::get_tempo(netuid)).saturating_add(1);
+        let due_block: u64 = Self::get_current_block_as_u64()
+            .saturating_add(AutoRedelegateAfterTempos::<T>::get().saturating_mul(blocks_per_tempo));
+        InactiveHotkeyQueue::<T>::mutate(|queue| {
+            if !queue.iter().any(|(queued, _)| queued == hotkey) {
+                queue.push((hotkey.clone(), due_block));
+            }
+        });
+    }
+
+    /// Drops `hotkey` from the inactive hotkeys once it registers again.
+    pub fn dequeue_inactive_hotkey(hotkey: &T::AccountId) {
+        let mut queue = InactiveHotkeyQueue::<T>::get();
+        if let Some(position) = queue.iter().position(|(queued, _)| queued == hotkey) {
+            queue.remove(position);
+            InactiveHotkeyQueue::<T>::put(queue);
+            if InactiveHotkeyCursor::<T>::get().is_some_and(|(current, _)| current == *hotkey) {
+                InactiveHotkeyCursor::<T>::kill();
+            }
+        }
+    }
+
+    /// Handles the nominators of the first due inactive hotkey the remaining weight allows, moving
+    /// the stake of those with a usable fallback hotkey and warning the others once.
+    ///
+    /// Nominators are visited in chunks from `on_idle`, resuming from `InactiveHotkeyCursor`. The
+    /// hotkey leaves the queue once all its nominators were visited.
+    pub fn process_inactive_hotkeys(remaining_weight: Weight) -> Weight {
+        // Reading the queue and the cursor, and writing them back.
+        let mut weight: Weight = T::DbWeight::get().reads_writes(2, 2);
+        let visit_weight: Weight = Self::inactive_nominator_visit_weight();
+        if weight.saturating_add(visit_weight).any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let mut queue = InactiveHotkeyQueue::<T>::get();
+        let Some(position) = queue.iter().position(|(_, due)| *due <= current_block) else {
+            return T::DbWeight::get().reads(1);
+        };
+        let Some((hotkey, _)) = queue.get(position).cloned() else {
+            return T::DbWeight::get().reads(1);
+        };
+
+        let mut nominators = match InactiveHotkeyCursor::<T>::get() {
+            Some((current, cursor)) if current == hotkey => {
+                Stake::<T>::iter_prefix_from(&hotkey, cursor)
+            }
+            _ => Stake::<T>::iter_prefix(&hotkey),
+        };
+        let mut visited: Vec<(T::AccountId, u64)> = Vec::new();
+        let mut cursor: Option<Vec<u8>> = None;
+        while weight
+            .saturating_add(visit_weight)
+            .all_lte(remaining_weight)
+        {
+            let Some((coldkey, stake)) = nominators.next() else {
+                cursor = None;
+                break;
+            };
+            weight.saturating_accrue(visit_weight);
+            cursor = Some(Stake::<T>::hashed_key_for(&hotkey, &coldkey));
+            visited.push((coldkey, stake));
+        }
+
+        // The stake is only moved once the iteration is over.
+        for (coldkey, stake) in visited {
+            Self::redelegate_or_warn(&hotkey, &coldkey, stake);
+        }
+
+        match cursor {
+            Some(cursor) => InactiveHotkeyCursor::<T>::put((hotkey, cursor)),
+            None => {
+                queue.remove(position);
+                InactiveHotkeyQueue::<T>::put(queue);
+                InactiveHotkeyCursor::<T>::kill();
+            }
+        }
+        weight
+    }
+
+    /// Moves the stake of `coldkey` on the inactive `hotkey` to its fallback hotkey, or warns it
+    /// when it has none or the fallback can not take the stake.
+    fn redelegate_or_warn(hotkey: &T::AccountId, coldkey: &T::AccountId, stake: u64) {
+        let fallback_hotkey = AutoRedelegate::<T>::get(coldkey).filter(|fallback| {
+            fallback != hotkey
+                && Self::is_hotkey_registered_on_any_network(fallback)
+                && (Self::hotkey_is_delegate(fallback)
+                    || Self::coldkey_owns_hotkey(coldkey, fallback))
+        });
+        let Some(fallback_hotkey) = fallback_hotkey else {
+            Self::deposit_event(Event::StakedHotkeyInactive {
+                hotkey: hotkey.clone(),
+                coldkey: coldkey.clone(),
+            });
+            return;
+        };
+
+        Self::decrease_stake_on_coldkey_hotkey_account(coldkey, hotkey, stake);
+        Self::increase_stake_on_coldkey_hotkey_account(coldkey, &fallback_hotkey, stake);
+        LastAddStakeIncrease::<T>::insert(
+            &fallback_hotkey,
+            coldkey,
+            Self::get_current_block_as_u64(),
+        );
+        log::debug!(
+            "StakeAutoRedelegated( coldkey:{:?} from:{:?} to:{:?} amount:{:?} )",
+            coldkey,
+            hotkey,
+            fallback_hotkey,
+            stake
+        );
+        Self::deposit_event(Event::StakeAutoRedelegated {
+            coldkey: coldkey.clone(),
+            from_hotkey: hotkey.clone(),
+            to_hotkey: fallback_hotkey,
+            amount: stake,
+        });
+    }
+
+    /// Weight of handling one nominator of an inactive hotkey: reading its fallback hotkey and the
+    /// registrations of the fallback, then moving the stake maps, totals, nominator counts, fee
+    /// discount tier and staking hotkeys of both sides.
+    pub fn inactive_nominator_visit_weight() -> Weight {
+        let subnets: u64 = u64::from(TotalNetworks::<T>::get());
+        T::DbWeight::get().reads_writes(20_u64.saturating_add(subnets), 15)
+    }
+}
@@ -27,6 +27,9 @@ impl<T: Config> Pallet<T> {
     /// *  'TxRateLimitExceeded':
     ///     - Thrown if key has hit transaction rate limit
     ///
+    /// *  'DelegateBelowOwnerStakeMinimum':
+    ///     - The coldkey has less stake on the hotkey than the min owner stake to delegate.
+    ///
     pub fn do_become_delegate(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
@@ -64,8 +67,14 @@ impl<T: Config> Pallet<T> {
         ensure!(take >= min_take, Error::<T>::DelegateTakeTooLow);
         ensure!(take <= max_take, Error::<T>::DelegateTakeTooHigh);
 
+        // --- 5.2 Ensure the owner has enough stake on the hotkey to accept nominations.
+        ensure!(
+            Self::owner_stake_meets_delegate_minimum(&hotkey),
+            Error::<T>::DelegateBelowOwnerStakeMinimum
+        );
+
         // --- 6. Delegate the key.
-        Self::delegate_hotkey(&hotkey, take);
+        Self::delegate_hotkey(&coldkey, &hotkey, take);
 
         // Set last block for rate limiting
         Self::set_last_tx_block(&coldkey, block);
@@ -0,0 +1,81 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic burn_tao: Burns TAO from the free balance of a coldkey.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'amount' (u64):
+    ///     -  The amount of TAO to burn.
+    ///
+    /// # Event:
+    /// * TaoBurned;
+    ///     -  On successfully burning the TAO.
+    ///
+    /// # Raises:
+    /// * 'AmountToBurnIsZero':
+    ///     -  Thrown if the amount is zero.
+    ///
+    /// * 'NotEnoughBalanceToBurn':
+    ///     -  Thrown if the amount exceeds the free balance of the coldkey above the existential deposit.
+    ///
+    /// * 'ColdkeyInSwapSchedule':
+    ///     -  Thrown if the coldkey has a swap scheduled.
+    ///
+    pub fn do_burn_tao(origin: T::RuntimeOrigin, amount: u64) -> dispatch::DispatchResult {
+        // We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!("do_burn_tao( origin:{:?} amount:{:?} )", coldkey, amount);
+
+        // Ensure the amount to burn is above zero.
+        ensure!(amount > 0, Error::<T>::AmountToBurnIsZero);
+
+        // Ensure the coldkey has no swap scheduled. The signed extension only sees the signer of
+        // the extrinsic, which is not the coldkey when the call comes through a multisig or proxy.
+        ensure!(
+            !ColdkeySwapScheduled::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeyInSwapSchedule
+        );
+
+        // Ensure the coldkey keeps its existential deposit after the burn.
+        ensure!(
+            amount <= Self::get_coldkey_balance_keep_alive(&coldkey),
+            Error::<T>::NotEnoughBalanceToBurn
+        );
+
+        // Withdraw the amount, dropping it from the currency issuance, and burn it from the total issuance.
+        let burned = Self::remove_balance_from_coldkey_account(&coldkey, amount)?;
+        ensure!(burned == amount, Error::<T>::NotEnoughBalanceToBurn);
+        Self::burn_tokens_of_coldkey(&coldkey, burned);
+
+        // Count the burn.
+        TotalBurnedByUsers::<T>::mutate(|total| *total = total.saturating_add(burned));
+
+        log::debug!("TaoBurned( coldkey:{:?}, amount:{:?} )", coldkey, burned);
+        Self::deposit_event(Event::TaoBurned {
+            coldkey,
+            amount: burned,
+        });
+
+        Ok(())
+    }
+
+    /// Burns `amount` taken from or owed to `coldkey`, counting it in the lifetime burn of the
+    /// coldkey.
+    pub fn burn_tokens_of_coldkey(coldkey: &T::AccountId, amount: u64) {
+        Self::burn_tokens(amount);
+        if amount > 0 {
+            ColdkeyLifetimeBurn::<T>::mutate(coldkey, |total| {
+                *total = total.saturating_add(amount)
+            });
+        }
+    }
+
+    /// Returns the total amount of TAO the coldkey ever burned, with `burn_tao`, through fees
+    /// like the swap and registration costs, or as balance it could not be credited.
+    pub fn get_coldkey_lifetime_burn(coldkey: &T::AccountId) -> u64 {
+        ColdkeyLifetimeBurn::<T>::get(coldkey)
+    }
+}
@@ -0,0 +1,195 @@
+use super::*;
+use sp_runtime::traits::TrailingZeroInput;
+
+/// Seed of the keyless account holding the correction compensation pool.
+const CORRECTION_COMPENSATION_POOL_SEED: &[u8] = b"subtensor/correction_compensation";
+
+impl<T: Config> Pallet<T> {
+    /// Returns the account of the correction compensation pool. It has no key, so only
+    /// `compensate_nominators` spends it, and governance funds it with a transfer.
+    pub fn get_correction_compensation_pool() -> T::AccountId {
+        T::AccountId::decode(&mut TrailingZeroInput::new(
+            CORRECTION_COMPENSATION_POOL_SEED,
+        ))
+        .expect("infinite length input; no invalid inputs for type; qed")
+    }
+
+    /// ---- The implementation for the extrinsic snapshot_nominators: records the stake of every
+    /// coldkey on `hotkey`, for a later `compensate_nominators` to pay them pro-rata.
+    ///
+    /// The stake of the owner of the hotkey is recorded like any other. A new snapshot replaces
+    /// the previous one, unless its payout has started. Stake is not kept per subnet, so a hotkey
+    /// has a snapshot on one subnet at a time; otherwise the same stake would be paid twice.
+    ///
+    /// # Event:
+    /// * NominatorsSnapshotted;
+    ///     - On successfully recording the stakes.
+    ///
+    /// # Raises:
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on the subnet.
+    ///
+    /// * 'TooManyNominatorsToSnapshot':
+    ///     - More than `MAX_SNAPSHOT_NOMINATORS` coldkeys stake on the hotkey.
+    ///
+    /// * 'CompensationInProgress':
+    ///     - The payout of the previous snapshot has started.
+    ///
+    /// * 'NominatorSnapshotOnOtherSubnet':
+    ///     - The hotkey has a nominator snapshot on another subnet.
+    ///
+    pub fn do_snapshot_nominators(hotkey: T::AccountId, netuid: u16) -> DispatchResult {
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        ensure!(
+            NominatorCount::<T>::get(&hotkey) <= MAX_SNAPSHOT_NOMINATORS,
+            Error::<T>::TooManyNominatorsToSnapshot
+        );
+        ensure!(
+            NominatorSnapshots::<T>::get(netuid, &hotkey)
+                .map_or(true, |snapshot| snapshot.next_index == 0),
+            Error::<T>::CompensationInProgress
+        );
+        ensure!(
+            !Self::get_all_subnet_netuids()
+                .iter()
+                .any(|other| *other != netuid
+                    && NominatorSnapshots::<T>::contains_key(other, &hotkey)),
+            Error::<T>::NominatorSnapshotOnOtherSubnet
+        );
+
+        let nominators: BoundedVec<(T::AccountId, u64), ConstU32<MAX_SNAPSHOT_NOMINATORS>> =
+            Stake::<T>::iter_prefix(&hotkey)
+                .filter(|(_, stake)| *stake > 0)
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyNominatorsToSnapshot)?;
+        let total_stake: u64 = nominators
+            .iter()
+            .fold(0, |total, (_, stake)| total.saturating_add(*stake));
+        let count: u32 = nominators.len() as u32;
+        let block: u64 = Self::get_current_block_as_u64();
+        NominatorSnapshots::<T>::insert(
+            netuid,
+            &hotkey,
+            NominatorSnapshotInfo {
+                block,
+                nominators,
+                total_stake,
+                compensation: 0,
+                next_index: 0,
+            },
+        );
+
+        Self::deposit_event(Event::NominatorsSnapshotted {
+            hotkey,
+            netuid,
+            block,
+            nominators: count,
+            total_stake,
+        });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic compensate_nominators: pays `amount` from the
+    /// correction compensation pool to the coldkeys of the nominator snapshot of `hotkey`, in
+    /// proportion to their recorded stake.
+    ///
+    /// A call pays at most `MAX_COMPENSATION_PAYOUTS_PER_CALL` nominators. The next call, with
+    /// the same amount, carries on from there, and the snapshot is removed once every nominator
+    /// has been paid. The rounding remainder stays in the pool.
+    ///
+    /// # Event:
+    /// * NominatorsCompensated;
+    ///     - On successfully paying the nominators of the call.
+    ///
+    /// # Raises:
+    /// * 'NoNominatorSnapshot':
+    ///     - The hotkey has no nominator snapshot on the subnet.
+    ///
+    /// * 'CompensationAmountMismatch':
+    ///     - The payout has started with another amount.
+    ///
+    /// * 'NotEnoughBalanceInCompensationPool':
+    ///     - The pool can not pay the nominators of the call and stay alive.
+    ///
+    pub fn do_compensate_nominators(
+        hotkey: T::AccountId,
+        netuid: u16,
+        amount: u64,
+    ) -> DispatchResult {
+        let mut snapshot =
+            NominatorSnapshots::<T>::get(netuid, &hotkey).ok_or(Error::<T>::NoNominatorSnapshot)?;
+        ensure!(
+            snapshot.next_index == 0 || snapshot.compensation == amount,
+            Error::<T>::CompensationAmountMismatch
+        );
+
+        let payouts: Vec<(T::AccountId, u64)> = snapshot
+            .nominators
+            .iter()
+            .skip(snapshot.next_index as usize)
+            .take(MAX_COMPENSATION_PAYOUTS_PER_CALL as usize)
+            .map(|(coldkey, stake)| {
+                let share: u64 = u128::from(amount)
+                    .saturating_mul(u128::from(*stake))
+                    .checked_div(u128::from(snapshot.total_stake))
+                    .unwrap_or(0) as u64;
+                (coldkey.clone(), share)
+            })
+            .collect();
+        let paid: u64 = payouts
+            .iter()
+            .fold(0, |total, (_, share)| total.saturating_add(*share));
+
+        let pool: T::AccountId = Self::get_correction_compensation_pool();
+        ensure!(
+            Self::get_coldkey_balance_keep_alive(&pool) >= paid,
+            Error::<T>::NotEnoughBalanceInCompensationPool
+        );
+        let withdrawn: u64 = Self::remove_balance_from_coldkey_account(&pool, paid)?;
+        ensure!(
+            withdrawn == paid,
+            Error::<T>::NotEnoughBalanceInCompensationPool
+        );
+        for (coldkey, share) in payouts.iter() {
+            Self::add_balance_to_coldkey_account_or_burn(coldkey, *share);
+        }
+
+        let count: u32 = payouts.len() as u32;
+        snapshot.compensation = amount;
+        snapshot.next_index = snapshot.next_index.saturating_add(count);
+        let complete: bool = snapshot.next_index as usize >= snapshot.nominators.len();
+        if complete {
+            NominatorSnapshots::<T>::remove(netuid, &hotkey);
+        } else {
+            NominatorSnapshots::<T>::insert(netuid, &hotkey, snapshot);
+        }
+
+        log::debug!(
+            "NominatorsCompensated( hotkey:{:?} netuid:{:?} paid:{:?} nominators:{:?} )",
+            hotkey,
+            netuid,
+            paid,
+            count
+        );
+        Self::deposit_event(Event::NominatorsCompensated {
+            hotkey,
+            netuid,
+            paid,
+            nominators: count,
+            complete,
+        });
+        Ok(())
+    }
+
+    /// Returns the nominator snapshot of `hotkey` on `netuid`, if it has one.
+    pub fn get_nominator_snapshot(
+        netuid: u16,
+        hotkey: &T::AccountId,
+    ) -> Option<NominatorSnapshotInfo<T::AccountId>> {
+        NominatorSnapshots::<T>::get(netuid, hotkey)
+    }
+}
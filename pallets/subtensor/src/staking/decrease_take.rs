@@ -54,8 +54,10 @@ impl<T: Config> Pallet<T> {
         let min_take = MinDelegateTake::<T>::get();
         ensure!(take >= min_take, Error::<T>::DelegateTakeTooLow);
 
-        // --- 4. Set the new take value.
-        Delegates::<T>::insert(hotkey.clone(), take);
+        // --- 4. Set the new take value, dropping any increase waiting for the next drain.
+        let old_take: u16 = PendingDelegateTake::<T>::take(&hotkey)
+            .unwrap_or_else(|| Self::get_hotkey_take(&hotkey));
+        Self::set_delegate_take(&hotkey, take);
 
         // --- 5. Emit the take value.
         log::debug!(
@@ -64,7 +66,7 @@ impl<T: Config> Pallet<T> {
             hotkey,
             take
         );
-        Self::deposit_event(Event::TakeDecreased(coldkey, hotkey, take));
+        Self::deposit_take_change_event(&coldkey, &hotkey, old_take, take);
 
         // --- 6. Ok and return.
         Ok(())
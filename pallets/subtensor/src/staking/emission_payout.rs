@@ -0,0 +1,90 @@
+use super::*;
+use frame_support::traits::tokens::fungible::Inspect as _;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_emission_payout_mode: Sets where the emission
+    /// of a coldkey's stake on a hotkey is paid.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey the stake is on.
+    ///
+    /// * 'mode' (EmissionPayoutMode):
+    ///     -  The payout mode.
+    ///
+    /// # Event:
+    /// * EmissionPayoutModeSet;
+    ///     -  On successfully setting the payout mode.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if the hotkey does not exist.
+    ///
+    /// * 'TxRateLimitExceeded':
+    ///     -  Thrown if the coldkey set a payout mode too recently.
+    ///
+    pub fn do_set_emission_payout_mode(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        mode: EmissionPayoutMode,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_set_emission_payout_mode( coldkey:{:?} hotkey:{:?} mode:{:?} )",
+            coldkey,
+            hotkey,
+            mode
+        );
+
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        let tx_type = TransactionType::SetEmissionPayoutMode;
+        ensure!(
+            Self::passes_rate_limit_globally(&tx_type, &coldkey),
+            Error::<T>::TxRateLimitExceeded
+        );
+
+        // Compounding is the default, so it is stored as the absence of a mode.
+        match mode {
+            EmissionPayoutMode::Compound => EmissionPayout::<T>::remove(&hotkey, &coldkey),
+            EmissionPayoutMode::ToBalance => EmissionPayout::<T>::insert(&hotkey, &coldkey, mode),
+        }
+        Self::set_last_transaction_block(
+            &coldkey,
+            u16::MAX,
+            &tx_type,
+            Self::get_current_block_as_u64(),
+        );
+
+        Self::deposit_event(Event::EmissionPayoutModeSet {
+            coldkey,
+            hotkey,
+            mode,
+        });
+        Ok(())
+    }
+
+    /// Pays `amount` of the emission of `hotkey` to `coldkey` as its payout mode says.
+    ///
+    /// An amount paid to the balance below the existential deposit, or that the balance cannot
+    /// take, is compounded into the stake instead, so the whole amount is always paid out.
+    pub fn pay_out_emission(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) {
+        let mut compounded: u64 = amount;
+        if EmissionPayout::<T>::get(hotkey, coldkey) == EmissionPayoutMode::ToBalance
+            && amount >= T::Currency::minimum_balance()
+        {
+            let credited: u64 = Self::add_balance_to_coldkey_account(coldkey, amount);
+            if credited == amount {
+                return;
+            }
+            compounded = amount.saturating_sub(credited);
+        }
+        Self::increase_stake_on_coldkey_hotkey_account(coldkey, hotkey, compounded);
+    }
+}
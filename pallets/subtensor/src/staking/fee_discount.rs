@@ -0,0 +1,71 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+use sp_runtime::Percent;
+
+/// Fee discount of each stake tier, in percent. Tier 0 has no discount, tier `i` gets
+/// `FEE_DISCOUNT_PERCENTS[i - 1]`.
+pub const FEE_DISCOUNT_PERCENTS: [u8; 3] = [10, 25, 50];
+
+impl<T: Config> Pallet<T> {
+    /// Returns the fee discount of `coldkey` from its cached stake tier.
+    ///
+    /// Runs on every fee charge, so it only reads `ColdkeyFeeDiscountTier`.
+    pub fn get_fee_discount(coldkey: &T::AccountId) -> Percent {
+        let tier: u8 = ColdkeyFeeDiscountTier::<T>::get(coldkey);
+        let percent: u8 = (tier as usize)
+            .checked_sub(1)
+            .and_then(|index| FEE_DISCOUNT_PERCENTS.get(index))
+            .copied()
+            .unwrap_or(0);
+        Percent::from_percent(percent)
+    }
+
+    /// Returns the stake tier of a coldkey with `total_stake`: the number of fee discount
+    /// thresholds the stake reaches. A coldkey without stake is always in tier 0.
+    pub fn fee_discount_tier_for_stake(total_stake: u64) -> u8 {
+        if total_stake == 0 {
+            return 0;
+        }
+        StakeFeeDiscountThresholds::<T>::get()
+            .iter()
+            .filter(|threshold| total_stake >= **threshold)
+            .count() as u8
+    }
+
+    /// Updates the cached stake tier of `coldkey` after its total stake changed to `total_stake`,
+    /// writing only when the stake crossed a threshold. Returns the weight consumed.
+    pub fn update_fee_discount_tier(coldkey: &T::AccountId, total_stake: u64) -> Weight {
+        let tier: u8 = Self::fee_discount_tier_for_stake(total_stake);
+        if tier == ColdkeyFeeDiscountTier::<T>::get(coldkey) {
+            return T::DbWeight::get().reads(2);
+        }
+
+        if tier == 0 {
+            ColdkeyFeeDiscountTier::<T>::remove(coldkey);
+        } else {
+            ColdkeyFeeDiscountTier::<T>::insert(coldkey, tier);
+        }
+        T::DbWeight::get().reads_writes(2, 1)
+    }
+
+    pub fn get_stake_fee_discount_thresholds() -> [u64; 3] {
+        StakeFeeDiscountThresholds::<T>::get()
+    }
+
+    /// Sets the stake thresholds of the fee discount tiers, which must be ascending.
+    ///
+    /// Cached tiers are not recomputed: each coldkey moves to its tier under the new thresholds
+    /// at its next stake change.
+    pub fn set_stake_fee_discount_thresholds(thresholds: [u64; 3]) -> DispatchResult {
+        ensure!(
+            thresholds
+                .windows(2)
+                .all(|pair| pair.first() <= pair.last()),
+            Error::<T>::FeeDiscountThresholdsNotAscending
+        );
+        StakeFeeDiscountThresholds::<T>::put(thresholds);
+        Self::deposit_event(Event::StakeFeeDiscountThresholdsSet(thresholds));
+        Ok(())
+    }
+}
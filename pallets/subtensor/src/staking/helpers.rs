@@ -4,11 +4,13 @@ use frame_support::{
     traits::{
         tokens::{
             fungible::{Balanced as _, Inspect as _, Mutate as _},
-            Fortitude, Precision, Preservation,
+            Fortitude, Precision, Preservation, Provenance,
         },
-        Imbalance,
+        Imbalance, OnKilledAccount,
     },
+    weights::Weight,
 };
+use sp_core::Get;
 
 impl<T: Config> Pallet<T> {
     // Returns true if the passed hotkey allow delegative staking.
@@ -19,8 +21,10 @@ impl<T: Config> Pallet<T> {
 
     // Sets the hotkey as a delegate with take.
     //
-    pub fn delegate_hotkey(hotkey: &T::AccountId, take: u16) {
-        Delegates::<T>::insert(hotkey, take);
+    pub fn delegate_hotkey(coldkey: &T::AccountId, hotkey: &T::AccountId, take: u16) {
+        let old_take: u16 = Delegates::<T>::get(hotkey);
+        Self::set_delegate_take(hotkey, take);
+        Self::deposit_take_change_event(coldkey, hotkey, old_take, take);
     }
 
     // Returns the total amount of stake in the staking table.
@@ -95,24 +99,80 @@ impl<T: Config> Pallet<T> {
         stakes
     }
 
+    // Returns the stakes made by the cold - hot pairing in the current staking interval, without
+    // resetting an elapsed interval in storage.
+    pub fn peek_stakes_this_interval_for_coldkey_hotkey(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+    ) -> u64 {
+        let (stakes, block_last_staked_at) =
+            TotalHotkeyColdkeyStakesThisInterval::<T>::get(coldkey, hotkey);
+        if block_last_staked_at.saturating_add(StakeInterval::<T>::get())
+            <= Self::get_current_block_as_u64()
+        {
+            return 0;
+        }
+
+        stakes
+    }
+
     pub fn get_target_stakes_per_interval() -> u64 {
         TargetStakesPerInterval::<T>::get()
     }
 
+    // Retrieves the stake operations made by a coldkey over all its hotkeys in the current staking
+    // interval. The counter resets on the same interval boundary as the cold - hot counter.
+    pub fn get_stake_ops_this_interval_for_coldkey(coldkey: &T::AccountId) -> u64 {
+        let (stake_ops, block_last_staked_at) = ColdkeyStakeOpsThisInterval::<T>::get(coldkey);
+        if block_last_staked_at.saturating_add(StakeInterval::<T>::get())
+            <= Self::get_current_block_as_u64()
+        {
+            Self::set_stake_ops_this_interval_for_coldkey(coldkey, 0, block_last_staked_at);
+            return 0;
+        }
+
+        stake_ops
+    }
+
+    // Returns the stake operations made by a coldkey in the current staking interval, without
+    // resetting an elapsed interval in storage.
+    pub fn peek_stake_ops_this_interval_for_coldkey(coldkey: &T::AccountId) -> u64 {
+        let (stake_ops, block_last_staked_at) = ColdkeyStakeOpsThisInterval::<T>::get(coldkey);
+        if block_last_staked_at.saturating_add(StakeInterval::<T>::get())
+            <= Self::get_current_block_as_u64()
+        {
+            return 0;
+        }
+
+        stake_ops
+    }
+
+    pub fn get_max_stake_ops_per_interval_per_coldkey() -> u64 {
+        MaxStakeOpsPerIntervalPerColdkey::<T>::get()
+    }
+
     // Creates a cold - hot pairing account if the hotkey is not already an active account.
+    // Fails with TooManyHotkeysForColdkey if the coldkey cannot own another hotkey.
     //
-    pub fn create_account_if_non_existent(coldkey: &T::AccountId, hotkey: &T::AccountId) {
+    pub fn create_account_if_non_existent(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+    ) -> DispatchResult {
         if !Self::hotkey_account_exists(hotkey) {
-            Stake::<T>::insert(hotkey, coldkey, 0);
-            Owner::<T>::insert(hotkey, coldkey);
+            Self::ensure_coldkey_can_own_hotkey(coldkey, hotkey)?;
 
             // Update OwnedHotkeys map
             let mut hotkeys = OwnedHotkeys::<T>::get(coldkey);
             if !hotkeys.contains(hotkey) {
-                hotkeys.push(hotkey.clone());
+                hotkeys
+                    .try_push(hotkey.clone())
+                    .map_err(|_| Error::<T>::TooManyHotkeysForColdkey)?;
                 OwnedHotkeys::<T>::insert(coldkey, hotkeys);
             }
 
+            Stake::<T>::insert(hotkey, coldkey, 0);
+            Owner::<T>::insert(hotkey, coldkey);
+
             // Update StakingHotkeys map
             let mut staking_hotkeys = StakingHotkeys::<T>::get(coldkey);
             if !staking_hotkeys.contains(hotkey) {
@@ -120,6 +180,29 @@ impl<T: Config> Pallet<T> {
                 StakingHotkeys::<T>::insert(coldkey, staking_hotkeys);
             }
         }
+        Ok(())
+    }
+
+    /// Ensures `coldkey` can take ownership of `hotkey`.
+    ///
+    /// A hotkey that already has an account does not change owner, so it always passes. A new hotkey
+    /// passes if the coldkey is exempt or owns fewer than `MaxHotkeysPerColdkey` hotkeys.
+    ///
+    /// # Errors
+    /// * `TooManyHotkeysForColdkey` - The coldkey already owns the maximum number of hotkeys.
+    pub fn ensure_coldkey_can_own_hotkey(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+    ) -> DispatchResult {
+        if Self::hotkey_account_exists(hotkey) || HotkeyLimitExempt::<T>::contains_key(coldkey) {
+            return Ok(());
+        }
+        let owned_hotkeys: u32 = OwnedHotkeys::<T>::decode_len(coldkey).unwrap_or(0) as u32;
+        ensure!(
+            owned_hotkeys < MaxHotkeysPerColdkey::<T>::get(),
+            Error::<T>::TooManyHotkeysForColdkey
+        );
+        Ok(())
     }
 
     /// Returns the coldkey owning this hotkey. This function should only be called for active accounts.
@@ -133,6 +216,16 @@ impl<T: Config> Pallet<T> {
         Owner::<T>::get(hotkey)
     }
 
+    /// Returns the stake the owning coldkey of the hotkey has on it.
+    pub fn get_owner_stake_on_hotkey(hotkey: &T::AccountId) -> u64 {
+        Stake::<T>::get(hotkey, Owner::<T>::get(hotkey))
+    }
+
+    /// Returns true if the owner of the hotkey has enough stake on it to accept nominations.
+    pub fn owner_stake_meets_delegate_minimum(hotkey: &T::AccountId) -> bool {
+        Self::get_owner_stake_on_hotkey(hotkey) >= Self::get_min_owner_stake_to_delegate()
+    }
+
     /// Returns the hotkey take.
     ///
     /// # Arguments
@@ -144,6 +237,55 @@ impl<T: Config> Pallet<T> {
         Delegates::<T>::get(hotkey)
     }
 
+    /// Sets the delegate take of a hotkey, recording the change in its take history.
+    pub fn set_delegate_take(hotkey: &T::AccountId, take: u16) {
+        let old_take: u16 = Delegates::<T>::get(hotkey);
+        Delegates::<T>::insert(hotkey, take);
+        Self::record_take_change(hotkey, old_take, take);
+    }
+
+    /// Appends a delegate take change to the take history of a hotkey, dropping the oldest entry
+    /// past `TAKE_HISTORY_LEN`. Nothing is recorded if the take is unchanged.
+    pub fn record_take_change(hotkey: &T::AccountId, old_take: u16, new_take: u16) {
+        if old_take == new_take {
+            return;
+        }
+        let current_block: u64 = Self::get_current_block_as_u64();
+        TakeHistory::<T>::mutate(hotkey, |history| {
+            history.push((current_block, old_take, new_take));
+            if history.len() > TAKE_HISTORY_LEN {
+                history.remove(0);
+            }
+        });
+    }
+
+    /// Emits `TakeIncreased` or `TakeDecreased` for a change of the delegate or childkey take of
+    /// a hotkey. Nothing is emitted if the take is unchanged.
+    pub fn deposit_take_change_event(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        old_take: u16,
+        new_take: u16,
+    ) {
+        let coldkey: T::AccountId = coldkey.clone();
+        let hotkey: T::AccountId = hotkey.clone();
+        match new_take.cmp(&old_take) {
+            sp_std::cmp::Ordering::Greater => Self::deposit_event(Event::TakeIncreased {
+                coldkey,
+                hotkey,
+                old_take,
+                new_take,
+            }),
+            sp_std::cmp::Ordering::Less => Self::deposit_event(Event::TakeDecreased {
+                coldkey,
+                hotkey,
+                old_take,
+                new_take,
+            }),
+            sp_std::cmp::Ordering::Equal => {}
+        }
+    }
+
     /// Returns true if the hotkey account has been created.
     ///
     /// # Arguments
@@ -155,6 +297,111 @@ impl<T: Config> Pallet<T> {
         Owner::<T>::contains_key(hotkey)
     }
 
+    /// Returns true if the coldkey still holds anything: a balance, stake, staking or owned
+    /// hotkeys, a subnet, a scheduled swap or network dissolution, an auth key, a vesting
+    /// schedule, an attestation, a swap redirect or its setting, an auto redelegation target or a
+    /// hotkey limit exemption. The payout modes of the coldkey go with its stake, and the pending
+    /// child keys with its owned hotkeys.
+    ///
+    /// # Arguments
+    /// * `coldkey` - The coldkey account ID.
+    ///
+    /// # Returns
+    /// True if the coldkey account exists, false otherwise.
+    pub fn coldkey_account_exists(coldkey: &T::AccountId) -> bool {
+        Self::get_coldkey_balance(coldkey) > 0
+            || TotalColdkeyStake::<T>::get(coldkey) > 0
+            || !StakingHotkeys::<T>::get(coldkey).is_empty()
+            || !OwnedHotkeys::<T>::get(coldkey).is_empty()
+            || OwnedSubnetCount::<T>::get(coldkey) > 0
+            || ColdkeySwapScheduled::<T>::contains_key(coldkey)
+            || ColdkeySwapDestination::<T>::contains_key(coldkey)
+            || DissolveNetworkScheduledUntil::<T>::contains_key(coldkey)
+            || ColdkeyAuth::<T>::contains_key(coldkey)
+            || VestingSchedules::<T>::contains_key(coldkey)
+            || ColdkeyAttestations::<T>::contains_key(coldkey)
+            || ColdkeySwapRedirect::<T>::contains_key(coldkey)
+            || ColdkeySwapRedirectEnabled::<T>::get(coldkey)
+            || AutoRedelegate::<T>::contains_key(coldkey)
+            || HotkeyLimitExempt::<T>::contains_key(coldkey)
+    }
+
+    /// Removes the storage left under `coldkey` once it holds nothing, see `coldkey_account_exists`.
+    ///
+    /// The empty hotkey lists, the zero total stake, the last transaction blocks, the last call
+    /// failure and the auth approvals are removed. The identity, the registration nonce and the
+    /// lifetime burn are kept: the identity is the user's own data, the nonce stops old
+    /// registration signatures from being replayed and the burn record survives. Returns the
+    /// weight consumed.
+    ///
+    /// # Arguments
+    /// * `coldkey` - The coldkey account ID.
+    pub fn prune_coldkey_if_empty(coldkey: &T::AccountId) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads(15);
+        if Self::coldkey_account_exists(coldkey) {
+            return weight;
+        }
+
+        StakingHotkeys::<T>::remove(coldkey);
+        OwnedHotkeys::<T>::remove(coldkey);
+        TotalColdkeyStake::<T>::remove(coldkey);
+        ColdkeyFeeDiscountTier::<T>::remove(coldkey);
+        for operation in [
+            RateLimitedOperation::Transaction,
+            RateLimitedOperation::DelegateTake,
+            RateLimitedOperation::ChildKeyTake,
+        ] {
+            LastRateLimitedBlock::<T>::remove(operation, coldkey);
+        }
+        LastCallFailureInfo::<T>::remove(coldkey);
+        let approvals: u64 = ColdkeyAuthApprovals::<T>::clear_prefix(coldkey, u32::MAX, None)
+            .unique
+            .into();
+        weight.saturating_accrue(T::DbWeight::get().writes(8_u64.saturating_add(approvals)));
+        log::debug!("pruned empty coldkey: {:?}", coldkey);
+
+        weight
+    }
+
+    /// Returns the weight of `prune_coldkey_if_empty` pruning a coldkey without auth approvals.
+    pub fn prune_coldkey_weight() -> Weight {
+        T::DbWeight::get().reads_writes(15, 8)
+    }
+
+    /// Prunes the queued `KilledColdkeys` the remaining weight allows, see
+    /// `prune_coldkey_if_empty`. Returns the weight consumed.
+    ///
+    /// # Arguments
+    /// * `remaining_weight` - The weight available to the pruning.
+    pub fn prune_killed_coldkeys(remaining_weight: Weight) -> Weight {
+        // Visiting a queued coldkey, dequeuing it and pruning it.
+        let mut weight: Weight = T::DbWeight::get().reads(1);
+        let visit_weight: Weight = T::DbWeight::get()
+            .reads_writes(1, 1)
+            .saturating_add(Self::prune_coldkey_weight());
+        if weight.saturating_add(visit_weight).any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut coldkeys: Vec<T::AccountId> = Vec::new();
+        let mut budget: Weight = weight;
+        for coldkey in KilledColdkeys::<T>::iter_keys() {
+            if budget.saturating_add(visit_weight).any_gt(remaining_weight) {
+                break;
+            }
+            budget.saturating_accrue(visit_weight);
+            coldkeys.push(coldkey);
+        }
+
+        for coldkey in coldkeys {
+            KilledColdkeys::<T>::remove(&coldkey);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            weight.saturating_accrue(Self::prune_coldkey_if_empty(&coldkey));
+        }
+
+        weight
+    }
+
     /// Returns true if the passed coldkey owns the hotkey.
     ///
     /// # Arguments
@@ -217,7 +464,7 @@ impl<T: Config> Pallet<T> {
         coldkey: &T::AccountId,
         hotkey: &T::AccountId,
         increment: u64,
-    ) {
+    ) -> Weight {
         log::debug!(
             "Increasing stake: coldkey: {:?}, hotkey: {:?}, amount: {}",
             coldkey,
@@ -225,27 +472,17 @@ impl<T: Config> Pallet<T> {
             increment
         );
 
-        TotalColdkeyStake::<T>::insert(
-            coldkey,
-            TotalColdkeyStake::<T>::get(coldkey).saturating_add(increment),
-        );
-        TotalHotkeyStake::<T>::insert(
-            hotkey,
-            TotalHotkeyStake::<T>::get(hotkey).saturating_add(increment),
-        );
-        Stake::<T>::insert(
-            hotkey,
-            coldkey,
-            Stake::<T>::get(hotkey, coldkey).saturating_add(increment),
-        );
-        TotalStake::<T>::put(TotalStake::<T>::get().saturating_add(increment));
+        let mut weight: Weight = Self::apply_stake_delta(coldkey, hotkey, increment, true);
 
         // Update StakingHotkeys map
         let mut staking_hotkeys = StakingHotkeys::<T>::get(coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
         if !staking_hotkeys.contains(hotkey) {
             staking_hotkeys.push(hotkey.clone());
             StakingHotkeys::<T>::insert(coldkey, staking_hotkeys);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
         }
+        weight
     }
 
     // Decreases the stake on the cold - hot pairing by the decrement while decreasing other counters.
@@ -254,27 +491,125 @@ impl<T: Config> Pallet<T> {
         coldkey: &T::AccountId,
         hotkey: &T::AccountId,
         decrement: u64,
+    ) -> Weight {
+        let mut weight: Weight = Self::apply_stake_delta(coldkey, hotkey, decrement, false);
+
+        // Update StakingHotkeys map and drop the payout mode once the coldkey has no position left
+        // on the hotkey
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        if !Stake::<T>::contains_key(hotkey, coldkey) {
+            StakingHotkeys::<T>::mutate(coldkey, |staking_hotkeys| {
+                staking_hotkeys.retain(|h| h != hotkey)
+            });
+            EmissionPayout::<T>::remove(hotkey, coldkey);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+        weight
+    }
+
+    /// Removes the duplicate entries of the staking hotkeys of `coldkey`, keeping the first one
+    /// of each hotkey. Returns the weight consumed.
+    ///
+    /// Older stake paths could push a hotkey more than once, so the heavy operations iterating
+    /// the list repair it before they do.
+    pub fn sync_staking_hotkeys(coldkey: &T::AccountId) -> Weight {
+        let staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(coldkey);
+        let deduped: Vec<T::AccountId> = Self::dedup_hotkeys(staking_hotkeys.clone());
+        if deduped.len() == staking_hotkeys.len() {
+            return T::DbWeight::get().reads(1);
+        }
+        StakingHotkeys::<T>::insert(coldkey, deduped);
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    /// Returns `hotkeys` without its duplicate entries, keeping the first one of each hotkey.
+    pub fn dedup_hotkeys(hotkeys: Vec<T::AccountId>) -> Vec<T::AccountId> {
+        let mut deduped: Vec<T::AccountId> = Vec::with_capacity(hotkeys.len());
+        for hotkey in hotkeys {
+            if !deduped.contains(&hotkey) {
+                deduped.push(hotkey);
+            }
+        }
+        deduped
+    }
+
+    /// Adds the hotkeys of `other` to `hotkeys` and sorts the result by account id, keeping each
+    /// hotkey once.
+    ///
+    /// Swaps merge hotkey vectors through this, so the merged vector does not depend on the order
+    /// of its inputs.
+    pub fn merge_hotkeys_sorted(
+        hotkeys: &mut Vec<T::AccountId>,
+        other: impl IntoIterator<Item = T::AccountId>,
     ) {
-        TotalColdkeyStake::<T>::mutate(coldkey, |old| *old = old.saturating_sub(decrement));
-        TotalHotkeyStake::<T>::insert(
-            hotkey,
-            TotalHotkeyStake::<T>::get(hotkey).saturating_sub(decrement),
-        );
-        Stake::<T>::insert(
-            hotkey,
-            coldkey,
-            Stake::<T>::get(hotkey, coldkey).saturating_sub(decrement),
-        );
-        TotalStake::<T>::put(TotalStake::<T>::get().saturating_sub(decrement));
+        hotkeys.extend(other);
+        hotkeys.sort();
+        hotkeys.dedup();
+    }
 
-        // TODO: Tech debt: Remove StakingHotkeys entry if stake goes to 0
+    /// Adds `amount` to, or if `increase` is false subtracts it from, the stake of `coldkey` on
+    /// `hotkey` and the coldkey, hotkey and global totals, touching each map once.
+    ///
+    /// An entry that goes from a non-zero value to zero is removed, and no entry is created at
+    /// zero. The nominator count of the hotkey follows the stake. A zero `amount` touches no
+    /// storage. Returns the weight of the storage accesses.
+    pub fn apply_stake_delta(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+        increase: bool,
+    ) -> Weight {
+        if amount == 0 {
+            return Weight::zero();
+        }
+        let delta = |stake: u64| -> u64 {
+            if increase {
+                stake.saturating_add(amount)
+            } else {
+                stake.saturating_sub(amount)
+            }
+        };
+
+        // Stores the new value of an entry, or None if it is zero. An entry already stored at zero
+        // is kept, but a missing one is never created at zero.
+        let apply = |entry: &mut Option<u64>| -> (u64, u64) {
+            let old: u64 = entry.unwrap_or_default();
+            let new: u64 = delta(old);
+            *entry = (new > 0 || *entry == Some(0)).then_some(new);
+            (old, new)
+        };
+
+        let (_, coldkey_stake) =
+            TotalColdkeyStake::<T>::mutate_exists(coldkey, |total| apply(total));
+        TotalHotkeyStake::<T>::mutate_exists(hotkey, |total| apply(total));
+        let (old_stake, new_stake) =
+            Stake::<T>::mutate_exists(hotkey, coldkey, |stake| apply(stake));
+        TotalStake::<T>::mutate(|total| *total = delta(*total));
+        let mut weight: Weight = T::DbWeight::get().reads_writes(4, 4);
+        weight.saturating_accrue(Self::update_fee_discount_tier(coldkey, coldkey_stake));
+
+        if (old_stake == 0) != (new_stake == 0) {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+        Self::update_nominator_count(hotkey, old_stake, new_stake);
+        weight
+    }
+
+    /// Updates the nominator count of `hotkey` when the stake of one of its coldkeys changes from
+    /// `old_stake` to `new_stake`. A coldkey is counted while its stake on the hotkey is non-zero.
+    pub fn update_nominator_count(hotkey: &T::AccountId, old_stake: u64, new_stake: u64) {
+        if old_stake == 0 && new_stake > 0 {
+            NominatorCount::<T>::mutate(hotkey, |count| *count = count.saturating_add(1));
+        } else if old_stake > 0 && new_stake == 0 {
+            NominatorCount::<T>::mutate(hotkey, |count| *count = count.saturating_sub(1));
+        }
     }
 
     /// Empties the stake associated with a given coldkey-hotkey account pairing.
     /// This function retrieves the current stake for the specified coldkey-hotkey pairing,
     /// then subtracts this stake amount from both the TotalColdkeyStake and TotalHotkeyStake.
-    /// It also removes the stake entry for the hotkey-coldkey pairing and adjusts the TotalStake
-    /// and TotalIssuance by subtracting the removed stake amount.
+    /// It also removes the stake entry and payout mode for the hotkey-coldkey pairing and adjusts
+    /// the TotalStake and TotalIssuance by subtracting the removed stake amount.
     ///
     /// Returns the amount of stake that was removed.
     ///
@@ -287,9 +622,15 @@ impl<T: Config> Pallet<T> {
         hotkey: &T::AccountId,
     ) -> u64 {
         let current_stake: u64 = Stake::<T>::get(hotkey, coldkey);
-        TotalColdkeyStake::<T>::mutate(coldkey, |old| *old = old.saturating_sub(current_stake));
+        let coldkey_stake: u64 = TotalColdkeyStake::<T>::mutate(coldkey, |old| {
+            *old = old.saturating_sub(current_stake);
+            *old
+        });
+        Self::update_fee_discount_tier(coldkey, coldkey_stake);
         TotalHotkeyStake::<T>::mutate(hotkey, |stake| *stake = stake.saturating_sub(current_stake));
         Stake::<T>::remove(hotkey, coldkey);
+        EmissionPayout::<T>::remove(hotkey, coldkey);
+        Self::update_nominator_count(hotkey, current_stake, 0);
         TotalStake::<T>::mutate(|stake| *stake = stake.saturating_sub(current_stake));
 
         // Update StakingHotkeys map
@@ -310,11 +651,15 @@ impl<T: Config> Pallet<T> {
         if !Self::coldkey_owns_hotkey(coldkey, hotkey) {
             // If the stake is below the minimum required, it's considered a small nomination and needs to be cleared.
             if stake < Self::get_nominator_min_required_stake() {
+                // Leave the nomination in place if it cannot be credited without being dusted.
+                if !Self::can_add_balance_to_coldkey_account(coldkey, stake) {
+                    return;
+                }
                 // Remove the stake from the nominator account. (this is a more forceful unstake operation which )
                 // Actually deletes the staking account.
                 let cleared_stake = Self::empty_stake_on_coldkey_hotkey_account(coldkey, hotkey);
                 // Add the stake to the coldkey account.
-                Self::add_balance_to_coldkey_account(coldkey, cleared_stake);
+                Self::add_balance_to_coldkey_account_or_burn(coldkey, cleared_stake);
             }
         }
     }
@@ -330,12 +675,44 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Deposits `amount` into the coldkey and returns the amount actually credited.
+    ///
+    /// A deposit that would leave a new account below the existential deposit is dusted by the
+    /// currency and credits nothing, so callers must check the returned amount or use
+    /// `can_add_balance_to_coldkey_account` beforehand.
     pub fn add_balance_to_coldkey_account(
         coldkey: &T::AccountId,
         amount: <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance,
-    ) {
-        // infallible
-        let _ = T::Currency::deposit(coldkey, amount, Precision::BestEffort);
+    ) -> u64 {
+        if amount == 0 {
+            return 0;
+        }
+
+        T::Currency::deposit(coldkey, amount, Precision::BestEffort)
+            .map(|debt| debt.peek())
+            .unwrap_or(0)
+    }
+
+    /// Deposits `amount` into the coldkey and burns from the total issuance whatever could
+    /// not be credited, so that issuance accounting stays exact for infallible paths. The burn
+    /// counts in the lifetime burn of the coldkey.
+    pub fn add_balance_to_coldkey_account_or_burn(
+        coldkey: &T::AccountId,
+        amount: <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance,
+    ) -> u64 {
+        let credited = Self::add_balance_to_coldkey_account(coldkey, amount);
+        Self::burn_tokens_of_coldkey(coldkey, amount.saturating_sub(credited));
+        credited
+    }
+
+    pub fn can_add_balance_to_coldkey_account(
+        coldkey: &T::AccountId,
+        amount: <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance,
+    ) -> bool {
+        amount == 0
+            || T::Currency::can_deposit(coldkey, amount, Provenance::Extant)
+                .into_result()
+                .is_ok()
     }
 
     pub fn set_balance_on_coldkey_account(
@@ -368,6 +745,15 @@ impl<T: Config> Pallet<T> {
         T::Currency::reducible_balance(coldkey, Preservation::Expendable, Fortitude::Polite)
     }
 
+    // Returns the balance of the coldkey that can be withdrawn while keeping the existential deposit.
+    //
+    pub fn get_coldkey_balance_keep_alive(
+        coldkey: &T::AccountId,
+    ) -> <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance
+    {
+        T::Currency::reducible_balance(coldkey, Preservation::Preserve, Fortitude::Polite)
+    }
+
     #[must_use = "Balance must be used to preserve total issuance of token"]
     pub fn remove_balance_from_coldkey_account(
         coldkey: &T::AccountId,
@@ -430,7 +816,15 @@ impl<T: Config> Pallet<T> {
             Self::decrease_stake_on_coldkey_hotkey_account(&delegate_coldkey_i, hotkey, stake_i);
 
             // Add the balance to the coldkey account.
-            Self::add_balance_to_coldkey_account(&delegate_coldkey_i, stake_i);
+            Self::add_balance_to_coldkey_account_or_burn(&delegate_coldkey_i, stake_i);
         }
     }
 }
+
+impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
+    /// Queues the coldkey once its balance is drained, it is pruned in `on_idle` if nothing else
+    /// remains under it.
+    fn on_killed_account(who: &T::AccountId) {
+        KilledColdkeys::<T>::insert(who, ());
+    }
+}
@@ -3,6 +3,9 @@ use super::*;
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for the extrinsic increase_take
     ///
+    /// If the hotkey has pending emission, the increase is only applied once that emission is
+    /// drained, so nominators are charged the take the emission accumulated under.
+    ///
     /// # Args:
     /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
     ///     - The signature of the caller's coldkey.
@@ -48,10 +51,14 @@ impl<T: Config> Pallet<T> {
         //        Ensure that the coldkey is the owner.
         Self::do_take_checks(&coldkey, &hotkey)?;
 
-        // --- 3. Ensure we are strinctly increasing take
-        if let Ok(current_take) = Delegates::<T>::try_get(&hotkey) {
+        // --- 3. Ensure we are strinctly increasing take, including over a pending increase.
+        if let Some(pending_take) = PendingDelegateTake::<T>::get(&hotkey) {
+            ensure!(take > pending_take, Error::<T>::DelegateTakeTooLow);
+        } else if let Ok(current_take) = Delegates::<T>::try_get(&hotkey) {
             ensure!(take > current_take, Error::<T>::DelegateTakeTooLow);
         }
+        let old_take: u16 = PendingDelegateTake::<T>::get(&hotkey)
+            .unwrap_or_else(|| Self::get_hotkey_take(&hotkey));
 
         // --- 4. Ensure take is within the min ..= InitialDefaultDelegateTake (18%) range
         let max_take = MaxDelegateTake::<T>::get();
@@ -70,8 +77,13 @@ impl<T: Config> Pallet<T> {
         // Set last block for rate limiting
         Self::set_last_tx_block_delegate_take(&coldkey, block);
 
-        // --- 6. Set the new take value.
-        Delegates::<T>::insert(hotkey.clone(), take);
+        // --- 6. Set the new take value. Emission already accumulated on the hotkey was earned
+        //        under the current take, so the increase waits for that emission to be drained.
+        if Self::get_pending_hotkey_emission(&hotkey) == 0 {
+            Self::set_delegate_take(&hotkey, take);
+        } else {
+            PendingDelegateTake::<T>::insert(hotkey.clone(), take);
+        }
 
         // --- 7. Emit the take value.
         log::debug!(
@@ -80,7 +92,7 @@ impl<T: Config> Pallet<T> {
             hotkey,
             take
         );
-        Self::deposit_event(Event::TakeIncreased(coldkey, hotkey, take));
+        Self::deposit_take_change_event(&coldkey, &hotkey, old_take, take);
 
         // --- 8. Ok and return.
         Ok(())
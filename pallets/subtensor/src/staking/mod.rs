@@ -1,8 +1,17 @@
 use super::*;
 pub mod add_stake;
+pub mod associate_hotkey;
+pub mod auto_redelegate;
 pub mod become_delegate;
+pub mod burn_tao;
+pub mod compensation;
 pub mod decrease_take;
+pub mod emission_payout;
+pub mod fee_discount;
 pub mod helpers;
 pub mod increase_take;
+pub mod prune_delegate;
 pub mod remove_stake;
 pub mod set_children;
+pub mod transfer_stake;
+pub mod vesting;
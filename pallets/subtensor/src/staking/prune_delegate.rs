@@ -0,0 +1,68 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic prune_delegate: removes a delegate nobody can
+    /// stake through anymore.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - Any signed account.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The delegate hotkey to remove.
+    ///
+    /// # Event:
+    /// * DelegatePruned;
+    ///     - On successfully removing the delegate and its take history.
+    ///
+    /// # Raises:
+    /// * 'HotKeyNotDelegate':
+    ///     - The hotkey is not a delegate.
+    ///
+    /// * 'DelegateHasStake':
+    ///     - The hotkey still has stake.
+    ///
+    /// * 'DelegateIsRegistered':
+    ///     - The hotkey is registered on a subnet.
+    ///
+    /// * 'DelegateHasNominators':
+    ///     - Coldkeys still stake on the hotkey.
+    ///
+    pub fn do_prune_delegate(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        // --- 1. Anyone can prune a delegate.
+        let who = ensure_signed(origin)?;
+        log::debug!("do_prune_delegate( origin:{:?} hotkey:{:?} )", who, hotkey);
+
+        // --- 2. Ensure the hotkey is a delegate nobody stakes through.
+        ensure!(
+            Self::hotkey_is_delegate(&hotkey),
+            Error::<T>::HotKeyNotDelegate
+        );
+        ensure!(
+            Self::get_total_stake_for_hotkey(&hotkey) == 0,
+            Error::<T>::DelegateHasStake
+        );
+        ensure!(
+            !Self::is_hotkey_registered_on_any_network(&hotkey),
+            Error::<T>::DelegateIsRegistered
+        );
+        ensure!(
+            NominatorCount::<T>::get(&hotkey) == 0,
+            Error::<T>::DelegateHasNominators
+        );
+
+        // --- 3. Remove the delegate. The owner can become a delegate again later.
+        Delegates::<T>::remove(&hotkey);
+        PendingDelegateTake::<T>::remove(&hotkey);
+        TakeHistory::<T>::remove(&hotkey);
+
+        // --- 4. Emit the event.
+        log::debug!("DelegatePruned( hotkey:{:?} )", hotkey);
+        Self::deposit_event(Event::DelegatePruned(hotkey));
+
+        Ok(())
+    }
+}
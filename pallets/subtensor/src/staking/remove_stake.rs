@@ -10,12 +10,13 @@ impl<T: Config> Pallet<T> {
     /// * 'hotkey' (T::AccountId):
     ///     -  The associated hotkey account.
     ///
-    /// * 'stake_to_be_added' (u64):
-    ///     -  The amount of stake to be added to the hotkey staking account.
+    /// * 'stake_to_be_removed' (u64):
+    ///     -  The amount of stake to be removed from the hotkey staking account, or u64::MAX to
+    ///        remove the entire position.
     ///
     /// # Event:
     /// * StakeRemoved;
-    ///     -  On the successfully removing stake from the hotkey account.
+    ///     -  On the successfully removing stake from the hotkey account, carrying the resolved amount.
     ///
     /// # Raises:
     /// * 'NotRegistered':
@@ -30,6 +31,12 @@ impl<T: Config> Pallet<T> {
     /// * 'TxRateLimitExceeded':
     ///     -  Thrown if key has hit transaction rate limit
     ///
+    /// * 'AmountTooLowToCreateAccount':
+    ///     -  Thrown if the unstaked amount would be dusted when credited to the coldkey.
+    ///
+    /// * 'AuthorizationRequired':
+    ///     -  Thrown if the amount is above the threshold of the coldkey's auth key and it did not approve the call.
+    ///
     pub fn do_remove_stake(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
@@ -44,6 +51,9 @@ impl<T: Config> Pallet<T> {
             stake_to_be_removed
         );
 
+        // Ensure staking operations are not paused.
+        Self::ensure_staking_not_paused()?;
+
         // Ensure that the hotkey account exists this is only possible through registration.
         ensure!(
             Self::hotkey_account_exists(&hotkey),
@@ -56,6 +66,15 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
         );
 
+        // An amount of u64::MAX is shorthand for the entire position, resolved at execution time.
+        let amount_unstaked: u64 = stake_to_be_removed;
+        let remove_all: bool = stake_to_be_removed == u64::MAX;
+        let stake_to_be_removed: u64 = if remove_all {
+            Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey)
+        } else {
+            stake_to_be_removed
+        };
+
         // Ensure that the stake amount to be removed is above zero.
         ensure!(stake_to_be_removed > 0, Error::<T>::StakeToWithdrawIsZero);
 
@@ -73,11 +92,47 @@ impl<T: Config> Pallet<T> {
             Error::<T>::UnstakeRateLimitExceeded
         );
 
-        // We remove the balance from the hotkey.
-        Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_removed);
+        // Ensure the coldkey does not exceed its stake rate limit over all its hotkeys.
+        let coldkey_stake_ops_this_interval =
+            Self::get_stake_ops_this_interval_for_coldkey(&coldkey);
+        ensure!(
+            coldkey_stake_ops_this_interval < Self::get_max_stake_ops_per_interval_per_coldkey(),
+            Error::<T>::ColdkeyStakeRateLimitExceeded
+        );
+
+        // Unstaking above the threshold of the coldkey's auth key needs its approval.
+        if Self::unstake_requires_coldkey_auth(&coldkey, stake_to_be_removed) {
+            Self::ensure_coldkey_call_authorized(
+                &coldkey,
+                Call::<T>::remove_stake {
+                    hotkey: hotkey.clone(),
+                    amount_unstaked,
+                },
+            )?;
+        }
+
+        // Ensure the unstaked amount can be credited without being dusted, keeping the stake in place otherwise.
+        ensure!(
+            Self::can_add_balance_to_coldkey_account(&coldkey, stake_to_be_removed),
+            Error::<T>::AmountTooLowToCreateAccount
+        );
+
+        // We remove the balance from the hotkey, clearing the position entirely when removing all of it.
+        if remove_all {
+            Self::empty_stake_on_coldkey_hotkey_account(&coldkey, &hotkey);
+        } else {
+            Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_removed);
+        }
 
         // We add the balance to the coldkey.  If the above fails we will not credit this coldkey.
-        Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_removed);
+        let credited = Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_removed);
+        ensure!(
+            credited == stake_to_be_removed,
+            Error::<T>::AmountTooLowToCreateAccount
+        );
+
+        // Unvested balance the coldkey had staked returns under its vesting lock.
+        Self::relock_vesting_after_unstake(&coldkey);
 
         // If the stake is below the minimum, we clear the nomination from storage.
         // This only applies to nominator stakes.
@@ -96,14 +151,58 @@ impl<T: Config> Pallet<T> {
             unstakes_this_interval.saturating_add(1),
             block,
         );
+        Self::set_stake_ops_this_interval_for_coldkey(
+            &coldkey,
+            coldkey_stake_ops_this_interval.saturating_add(1),
+            block,
+        );
         log::debug!(
-            "StakeRemoved( hotkey:{:?}, stake_to_be_removed:{:?} )",
+            "StakeRemoved( coldkey:{:?}, hotkey:{:?}, stake_to_be_removed:{:?} )",
+            coldkey,
             hotkey,
             stake_to_be_removed
         );
-        Self::deposit_event(Event::StakeRemoved(hotkey, stake_to_be_removed));
+        Self::deposit_event(Event::StakeRemoved {
+            coldkey: coldkey.clone(),
+            hotkey,
+            amount: stake_to_be_removed,
+        });
+
+        // Prune the coldkey if the unstake emptied it.
+        let _ = Self::prune_coldkey_if_empty(&coldkey);
 
         // Done and ok.
         Ok(())
     }
+
+    /// Returns the limit and the provided value behind a `remove_stake` failing with `error`, as
+    /// `(expected, provided)`, for the errors that have one.
+    pub fn remove_stake_failure_detail(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        stake_to_be_removed: u64,
+        error: &Error<T>,
+    ) -> Option<(u64, u64)> {
+        let stake: u64 = Self::get_stake_for_coldkey_and_hotkey(coldkey, hotkey);
+        let stake_to_be_removed: u64 = if stake_to_be_removed == u64::MAX {
+            stake
+        } else {
+            stake_to_be_removed
+        };
+
+        match error {
+            Error::<T>::NotEnoughStakeToWithdraw => Some((stake, stake_to_be_removed)),
+            Error::<T>::UnstakeRateLimitExceeded => Some((
+                Self::get_target_stakes_per_interval(),
+                Self::peek_stakes_this_interval_for_coldkey_hotkey(coldkey, hotkey),
+            )),
+            Error::<T>::ColdkeyStakeRateLimitExceeded => Some((
+                Self::get_max_stake_ops_per_interval_per_coldkey(),
+                Self::peek_stake_ops_this_interval_for_coldkey(coldkey),
+            )),
+            Error::<T>::AuthorizationRequired => ColdkeyAuth::<T>::get(coldkey)
+                .map(|(_, threshold)| (threshold, stake_to_be_removed)),
+            _ => None,
+        }
+    }
 }
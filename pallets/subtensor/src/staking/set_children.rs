@@ -1,4 +1,6 @@
 use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
 
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for the extrinsic do_set_child_singular: Sets a single child.
@@ -18,9 +20,15 @@ impl<T: Config> Pallet<T> {
     /// * `children` Vec[(u64, T::AccountId)]:
     ///     - A list of children with their proportions.
     ///
+    /// The children are applied once `PendingChildKeyCooldown` blocks have passed, until then the
+    /// epoch and queries keep using the current children. A new request before then replaces the
+    /// pending one and restarts the cooldown. A zero cooldown applies the children immediately.
+    ///
     /// # Events:
-    /// * `ChildrenAdded`:
-    ///     - On successfully registering children to a hotkey.
+    /// * `SetChildren`:
+    ///     - On successfully requesting children for a hotkey.
+    /// * `ChildrenApplied`:
+    ///     - When the children are applied to the hotkey.
     ///
     /// # Errors:
     /// * `SubNetworkDoesNotExist`:
@@ -39,8 +47,7 @@ impl<T: Config> Pallet<T> {
     /// 4. **Ownership Verification**: Ensures that the coldkey owns the hotkey.
     /// 5. **Hotkey Account Existence Check**: Ensures that the hotkey account already exists.
     /// 6. **Child-Hotkey Distinction**: Ensures that the child is not the same as the hotkey.
-    /// 7. **Old Children Cleanup**: Removes the hotkey from the parent list of its old children.
-    /// 8. **New Children Assignment**: Assigns the new child to the hotkey and updates the parent list for the new child.
+    /// 7. **Pending Request**: Queues the children until the cooldown has passed.
     ///
     pub fn do_set_children(
         origin: T::RuntimeOrigin,
@@ -118,53 +125,143 @@ impl<T: Config> Pallet<T> {
             unique_children.push(child_i.clone());
         }
 
-        // --- 6. Erase myself from old children's parents.
-        let old_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(hotkey.clone(), netuid);
+        // --- 6. Request the children, overwriting and restarting a request not applied yet.
+        log::trace!(
+            "SetChildren( netuid:{:?}, hotkey:{:?}, children:{:?} )",
+            hotkey,
+            netuid,
+            children.clone()
+        );
+        Self::deposit_event(Event::SetChildren(hotkey.clone(), netuid, children.clone()));
+        let cooldown: u64 = Self::get_pending_child_key_cooldown();
+        if cooldown == 0 {
+            PendingChildKeys::<T>::remove(netuid, &hotkey);
+            Self::apply_children(&hotkey, netuid, children);
+        } else {
+            let effective_block: u64 = current_block.saturating_add(cooldown);
+            PendingChildKeys::<T>::insert(netuid, &hotkey, (children, effective_block));
+            PendingChildKeysDue::<T>::append(effective_block, (netuid, hotkey));
+        }
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// Replaces the children of `hotkey` on `netuid` and emits `ChildrenApplied`.
+    ///
+    /// The hotkey is erased from the parents of its old children and added to the parents of
+    /// its new children. Returns the weight consumed.
+    pub fn apply_children(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        children: Vec<(u64, T::AccountId)>,
+    ) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+
+        // --- 1. Erase myself from old children's parents.
+        let old_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(hotkey, netuid);
 
-        // --- 6.0. Iterate over all my old children and remove myself from their parent's map.
-        for (_, old_child_i) in old_children.clone().iter() {
-            // --- 6.1. Get the old child's parents on this network.
+        // --- 1.0. Iterate over all my old children and remove myself from their parent's map.
+        for (_, old_child_i) in old_children.iter() {
+            // --- 1.1. Get the old child's parents on this network.
             let my_old_child_parents: Vec<(u64, T::AccountId)> =
-                ParentKeys::<T>::get(old_child_i.clone(), netuid);
+                ParentKeys::<T>::get(old_child_i, netuid);
 
-            // --- 6.2. Filter my hotkey from my old children's parents list.
+            // --- 1.2. Filter my hotkey from my old children's parents list.
             let filtered_parents: Vec<(u64, T::AccountId)> = my_old_child_parents
                 .into_iter()
-                .filter(|(_, parent)| *parent != hotkey)
+                .filter(|(_, parent)| parent != hotkey)
                 .collect();
 
-            // --- 6.3. Update the parent list in storage
+            // --- 1.3. Update the parent list in storage
             ParentKeys::<T>::insert(old_child_i, netuid, filtered_parents);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
 
-        // --- 7.1. Insert my new children + proportion list into the map.
-        ChildKeys::<T>::insert(hotkey.clone(), netuid, children.clone());
+        // --- 2.1. Insert my new children + proportion list into the map.
+        ChildKeys::<T>::insert(hotkey, netuid, children.clone());
 
-        // --- 7.2. Update the parents list for my new children.
-        for (proportion, new_child_i) in children.clone().iter() {
-            // --- 8.2.1. Get the child's parents on this network.
+        // --- 2.2. Update the parents list for my new children.
+        for (proportion, new_child_i) in children.iter() {
+            // --- 2.2.1. Get the child's parents on this network.
             let mut new_child_previous_parents: Vec<(u64, T::AccountId)> =
-                ParentKeys::<T>::get(new_child_i.clone(), netuid);
+                ParentKeys::<T>::get(new_child_i, netuid);
 
-            // --- 7.2.2. Append my hotkey and proportion to my new child's parents list.
+            // --- 2.2.2. Append my hotkey and proportion to my new child's parents list.
             // NOTE: There are no duplicates possible because I previously removed my self from my old children.
             new_child_previous_parents.push((*proportion, hotkey.clone()));
 
-            // --- 7.2.3. Update the parents list in storage.
-            ParentKeys::<T>::insert(new_child_i.clone(), netuid, new_child_previous_parents);
+            // --- 2.2.3. Update the parents list in storage.
+            ParentKeys::<T>::insert(new_child_i, netuid, new_child_previous_parents);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
 
-        // --- 8. Log and return.
+        // --- 3. Log and emit.
         log::trace!(
-            "SetChildren( netuid:{:?}, hotkey:{:?}, children:{:?} )",
+            "ChildrenApplied( netuid:{:?}, hotkey:{:?}, children:{:?} )",
             hotkey,
             netuid,
-            children.clone()
+            children
         );
-        Self::deposit_event(Event::SetChildren(hotkey.clone(), netuid, children.clone()));
+        Self::deposit_event(Event::ChildrenApplied(hotkey.clone(), netuid, children));
 
-        // Ok and return.
-        Ok(())
+        weight
+    }
+
+    /// Applies the requested children whose cooldown ends at `block_number`.
+    ///
+    /// Only the requests indexed under `block_number` in `PendingChildKeysDue` are read. An index
+    /// entry whose request was replaced, moved or removed since is skipped. A due request whose
+    /// subnet is gone, whose hotkey no longer exists or which names the hotkey as its own child
+    /// is dropped with `PendingChildrenDiscarded`.
+    pub fn apply_pending_child_keys(block_number: u64) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+        for (netuid, hotkey) in PendingChildKeysDue::<T>::take(block_number) {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            let children = match PendingChildKeys::<T>::get(netuid, &hotkey) {
+                Some((children, effective_block)) if effective_block == block_number => children,
+                _ => continue,
+            };
+            PendingChildKeys::<T>::remove(netuid, &hotkey);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
+            if !Self::pending_children_still_valid(&hotkey, netuid, &children) {
+                log::debug!(
+                    "PendingChildrenDiscarded( netuid:{:?}, hotkey:{:?} )",
+                    netuid,
+                    hotkey
+                );
+                Self::deposit_event(Event::PendingChildrenDiscarded(hotkey, netuid));
+                continue;
+            }
+            weight.saturating_accrue(Self::apply_children(&hotkey, netuid, children));
+        }
+
+        weight
+    }
+
+    /// Returns whether requested children can still be applied to `hotkey` on `netuid`.
+    ///
+    /// The subnet or the hotkey may have been removed, or the hotkey swapped to one of its
+    /// requested children, while the request waited for its cooldown.
+    fn pending_children_still_valid(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        children: &[(u64, T::AccountId)],
+    ) -> bool {
+        Self::if_subnet_exist(netuid)
+            && Self::hotkey_account_exists(hotkey)
+            && children.iter().all(|(_, child)| child != hotkey)
+    }
+
+    /// Returns the children requested for `hotkey` on `netuid` and the block they apply at,
+    /// if a request is not applied yet.
+    pub fn get_pending_children(
+        hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> Option<(Vec<(u64, T::AccountId)>, u64)> {
+        PendingChildKeys::<T>::get(netuid, hotkey)
     }
 
     /* Retrieves the list of children for a given hotkey and network.
@@ -266,6 +363,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // Set the new childkey take value for the given hotkey and network
+        let old_take: u16 = ChildkeyTake::<T>::get(&hotkey, netuid);
         ChildkeyTake::<T>::insert(hotkey.clone(), netuid, take);
 
         // Update the last transaction block
@@ -277,6 +375,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // Emit the event
+        Self::deposit_take_change_event(&coldkey, &hotkey, old_take, take);
         Self::deposit_event(Event::ChildKeyTakeSet(hotkey.clone(), take));
         log::debug!(
             "Childkey take set for hotkey: {:?} and take: {:?}",
@@ -301,4 +400,62 @@ impl<T: Config> Pallet<T> {
     pub fn get_childkey_take(hotkey: &T::AccountId, netuid: u16) -> u16 {
         ChildkeyTake::<T>::get(hotkey, netuid)
     }
+
+    /// Allows or revokes `parent` setting the weights of `child` on `netuid` through
+    /// `set_weights_for_child`. A revocation takes effect immediately.
+    ///
+    /// # Arguments:
+    /// * `coldkey` (T::AccountId):
+    ///     - The coldkey that owns the child.
+    ///
+    /// * `parent` (T::AccountId):
+    ///     - The parent hotkey.
+    ///
+    /// * `child` (T::AccountId):
+    ///     - The child hotkey.
+    ///
+    /// * `netuid` (u16):
+    ///     - The subnet the permission applies to.
+    ///
+    /// * `allowed` (bool):
+    ///     - Whether the parent may set the weights of the child.
+    ///
+    /// # Errors:
+    /// * `NonAssociatedColdKey`:
+    ///     - The coldkey does not own the child.
+    pub fn do_set_child_weights_permission(
+        coldkey: T::AccountId,
+        parent: T::AccountId,
+        child: T::AccountId,
+        netuid: u16,
+        allowed: bool,
+    ) -> DispatchResult {
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &child),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        if allowed {
+            ChildWeightsPermission::<T>::insert((&parent, &child, netuid), true);
+        } else {
+            ChildWeightsPermission::<T>::remove((&parent, &child, netuid));
+        }
+
+        Self::deposit_event(Event::ChildWeightsPermissionSet {
+            parent,
+            child,
+            netuid,
+            allowed,
+        });
+        Ok(())
+    }
+
+    /// Returns whether `parent` may set the weights of `child` on `netuid`.
+    pub fn get_child_weights_permission(
+        parent: &T::AccountId,
+        child: &T::AccountId,
+        netuid: u16,
+    ) -> bool {
+        ChildWeightsPermission::<T>::get((parent, child, netuid))
+    }
 }
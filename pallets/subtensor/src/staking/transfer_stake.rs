@@ -0,0 +1,221 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic transfer_stake: Moves stake on a hotkey from one coldkey to another.
+    ///
+    /// The stake is moved between the two ( hotkey, coldkey ) positions without passing through the coldkey
+    /// balances, so the total stake of the hotkey and of the network is unchanged.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the source coldkey.
+    ///
+    /// * 'destination_coldkey' (T::AccountId):
+    ///     -  The coldkey receiving the stake.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey the stake is delegated to.
+    ///
+    /// * 'netuid' (u16):
+    ///     -  The subnet the hotkey is registered on, whose owner controls whether transfers are allowed.
+    ///
+    /// * 'amount' (u64):
+    ///     -  The amount of stake to transfer.
+    ///
+    /// # Event:
+    /// * StakeTransferred;
+    ///     -  On successfully transferring the stake.
+    ///
+    /// # Raises:
+    /// * 'SameColdkey':
+    ///     -  Thrown if the destination coldkey is the source coldkey.
+    ///
+    /// * 'SubNetworkDoesNotExist':
+    ///     -  Thrown if the subnet does not exist.
+    ///
+    /// * 'TransferStakeDisabled':
+    ///     -  Thrown if the subnet owner has not enabled stake transfers.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     -  Thrown if the hotkey is not registered on the subnet.
+    ///
+    /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+    ///     -  Thrown if the hotkey is not a delegate and the destination coldkey does not own it.
+    ///
+    /// * 'StakeToWithdrawIsZero':
+    ///     -  Thrown if the amount is zero.
+    ///
+    /// * 'NotEnoughStakeToWithdraw':
+    ///     -  Thrown if the source coldkey does not have enough stake on the hotkey.
+    ///
+    /// * 'StakeLockedByVesting':
+    ///     -  Thrown if the transfer would take unvested balance staked by the source coldkey.
+    ///
+    /// * 'UnstakeRateLimitExceeded':
+    ///     -  Thrown if the source coldkey has hit the unstaking rate limit on the hotkey.
+    ///
+    /// * 'ColdkeyStakeRateLimitExceeded':
+    ///     -  Thrown if the source coldkey has hit its stake operation limit over all its hotkeys.
+    ///
+    /// * 'AuthorizationRequired':
+    ///     -  Thrown if the amount is above the threshold of the source coldkey's auth key and it did not approve the call.
+    ///
+    /// * 'NomStakeBelowMinimumThreshold':
+    ///     -  Thrown if the resulting nomination of the destination coldkey is below the minimum.
+    ///
+    /// * 'DelegateBelowOwnerStakeMinimum':
+    ///     -  Thrown if the destination nominates a delegate whose owner has less stake on it than the minimum.
+    ///
+    pub fn do_transfer_stake(
+        origin: T::RuntimeOrigin,
+        destination_coldkey: T::AccountId,
+        hotkey: T::AccountId,
+        netuid: u16,
+        amount: u64,
+    ) -> dispatch::DispatchResult {
+        // We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_transfer_stake( origin:{:?} destination_coldkey:{:?} hotkey:{:?}, netuid:{:?}, amount:{:?} )",
+            coldkey,
+            destination_coldkey,
+            hotkey,
+            netuid,
+            amount
+        );
+
+        // Ensure staking operations are not paused.
+        Self::ensure_staking_not_paused()?;
+
+        // Ensure the stake changes coldkey.
+        ensure!(destination_coldkey != coldkey, Error::<T>::SameColdkey);
+
+        // Ensure the subnet exists and its owner allows stake transfers.
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            TransferStakeEnabled::<T>::get(netuid),
+            Error::<T>::TransferStakeDisabled
+        );
+
+        // Ensure the hotkey is registered on the subnet.
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+
+        // Ensure the destination could stake to the hotkey itself.
+        ensure!(
+            Self::hotkey_is_delegate(&hotkey)
+                || Self::coldkey_owns_hotkey(&destination_coldkey, &hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        // Ensure the source has enough stake to transfer.
+        ensure!(amount > 0, Error::<T>::StakeToWithdrawIsZero);
+        ensure!(
+            Self::has_enough_stake(&coldkey, &hotkey, amount),
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
+        // Ensure the transfer leaves the source the unvested balance it has staked.
+        ensure!(
+            Self::get_total_stake_for_coldkey(&coldkey).saturating_sub(amount)
+                >= Self::get_vesting_staked(&coldkey),
+            Error::<T>::StakeLockedByVesting
+        );
+
+        // Ensure the source does not exceed its unstaking rate limit.
+        let unstakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
+        ensure!(
+            unstakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::UnstakeRateLimitExceeded
+        );
+
+        // Ensure the coldkey does not exceed its stake rate limit over all its hotkeys.
+        let coldkey_stake_ops_this_interval =
+            Self::get_stake_ops_this_interval_for_coldkey(&coldkey);
+        ensure!(
+            coldkey_stake_ops_this_interval < Self::get_max_stake_ops_per_interval_per_coldkey(),
+            Error::<T>::ColdkeyStakeRateLimitExceeded
+        );
+
+        // Transferring above the threshold of the source's auth key needs its approval, as an unstake would.
+        if Self::unstake_requires_coldkey_auth(&coldkey, amount) {
+            Self::ensure_coldkey_call_authorized(
+                &coldkey,
+                Call::<T>::transfer_stake {
+                    destination_coldkey: destination_coldkey.clone(),
+                    hotkey: hotkey.clone(),
+                    netuid,
+                    amount,
+                },
+            )?;
+        }
+
+        // If the destination does not own the hotkey, it receives a nomination.
+        if !Self::coldkey_owns_hotkey(&destination_coldkey, &hotkey) {
+            let total_stake_after_transfer =
+                Stake::<T>::get(&hotkey, &destination_coldkey).saturating_add(amount);
+            ensure!(
+                total_stake_after_transfer >= NominatorMinRequiredStake::<T>::get(),
+                Error::<T>::NomStakeBelowMinimumThreshold
+            );
+            ensure!(
+                Self::owner_stake_meets_delegate_minimum(&hotkey),
+                Error::<T>::DelegateBelowOwnerStakeMinimum
+            );
+        }
+
+        // Move the stake between the two positions, leaving the totals of the hotkey and the network unchanged.
+        Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, amount);
+        Self::increase_stake_on_coldkey_hotkey_account(&destination_coldkey, &hotkey, amount);
+
+        // Set the last time the stake increased for nominator drain protection.
+        LastAddStakeIncrease::<T>::insert(
+            &hotkey,
+            &destination_coldkey,
+            Self::get_current_block_as_u64(),
+        );
+
+        // If the remaining stake of the source is below the minimum, we clear the nomination from storage.
+        let remaining_stake = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+        Self::clear_small_nomination_if_required(&hotkey, &coldkey, remaining_stake);
+
+        // Set last block for rate limiting of the source.
+        let block: u64 = Self::get_current_block_as_u64();
+        Self::set_last_tx_block(&coldkey, block);
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            &coldkey,
+            &hotkey,
+            unstakes_this_interval.saturating_add(1),
+            block,
+        );
+        Self::set_stake_ops_this_interval_for_coldkey(
+            &coldkey,
+            coldkey_stake_ops_this_interval.saturating_add(1),
+            block,
+        );
+
+        log::debug!(
+            "StakeTransferred( from:{:?}, to:{:?}, hotkey:{:?}, netuid:{:?}, amount:{:?} )",
+            coldkey,
+            destination_coldkey,
+            hotkey,
+            netuid,
+            amount
+        );
+        Self::deposit_event(Event::StakeTransferred {
+            from: coldkey,
+            to: destination_coldkey,
+            hotkey,
+            netuid,
+            amount,
+        });
+
+        Ok(())
+    }
+}
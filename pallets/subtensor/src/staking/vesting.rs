@@ -0,0 +1,185 @@
+use super::*;
+use frame_support::traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons};
+use frame_support::weights::Weight;
+use sp_core::hexdisplay::AsBytesRef;
+use sp_core::Get;
+
+/// Identifier of the lock holding the unvested balance of a coldkey.
+pub const VESTING_LOCK_ID: LockIdentifier = *b"stvestng";
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic sudo_create_vesting_schedule: locks `total` of
+    /// the free balance of the coldkey, released by `per_block` each block from `start_block`.
+    ///
+    /// The locked balance can be staked, but not transferred. Unstaking it returns it under the
+    /// lock.
+    ///
+    /// # Event:
+    /// * VestingScheduleCreated;
+    ///     - On successfully locking the balance.
+    ///
+    /// # Raises:
+    /// * 'InvalidVestingSchedule':
+    ///     - The total or the balance vesting per block is zero.
+    ///
+    /// * 'VestingScheduleExists':
+    ///     - The coldkey already has a vesting schedule.
+    ///
+    /// * 'NotEnoughBalanceToVest':
+    ///     - The free balance of the coldkey is below the total.
+    ///
+    pub fn do_create_vesting_schedule(
+        coldkey: T::AccountId,
+        total: u64,
+        per_block: u64,
+        start_block: u64,
+    ) -> DispatchResult {
+        ensure!(
+            total > 0 && per_block > 0,
+            Error::<T>::InvalidVestingSchedule
+        );
+        ensure!(
+            !VestingSchedules::<T>::contains_key(&coldkey),
+            Error::<T>::VestingScheduleExists
+        );
+        ensure!(
+            T::VestingCurrency::free_balance(&coldkey) >= total,
+            Error::<T>::NotEnoughBalanceToVest
+        );
+
+        Self::update_vesting_lock(
+            &coldkey,
+            VestingScheduleInfo {
+                total,
+                per_block,
+                start_block,
+                staked: 0,
+            },
+        );
+        log::debug!(
+            "VestingScheduleCreated( coldkey:{:?} total:{:?} per_block:{:?} start_block:{:?} )",
+            coldkey,
+            total,
+            per_block,
+            start_block
+        );
+        Self::deposit_event(Event::VestingScheduleCreated {
+            coldkey,
+            total,
+            per_block,
+            start_block,
+        });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic claim_vested: shrinks the vesting lock of the
+    /// caller to the balance still vesting, removing the schedule once it has fully vested.
+    ///
+    /// # Event:
+    /// * VestedBalanceClaimed;
+    ///     - On successfully updating the lock.
+    ///
+    /// # Raises:
+    /// * 'NoVestingSchedule':
+    ///     - The caller has no vesting schedule.
+    ///
+    pub fn do_claim_vested(origin: T::RuntimeOrigin) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        let schedule = VestingSchedules::<T>::get(&coldkey).ok_or(Error::<T>::NoVestingSchedule)?;
+
+        let vested: u64 = Self::vested_amount(&schedule, Self::get_current_block_as_u64());
+        let unvested: u64 = schedule.total.saturating_sub(vested);
+        Self::update_vesting_lock(&coldkey, schedule);
+        Self::deposit_event(Event::VestedBalanceClaimed {
+            coldkey,
+            vested,
+            unvested,
+        });
+        Ok(())
+    }
+
+    /// Returns the part of `schedule` vested at `block`.
+    pub fn vested_amount(schedule: &VestingScheduleInfo, block: u64) -> u64 {
+        let blocks: u64 = block.saturating_sub(schedule.start_block);
+        schedule
+            .per_block
+            .saturating_mul(blocks)
+            .min(schedule.total)
+    }
+
+    /// Returns the balance of `coldkey` vested so far, or zero if it has no vesting schedule.
+    pub fn get_vested_balance(coldkey: &T::AccountId) -> u64 {
+        VestingSchedules::<T>::get(coldkey).map_or(0, |schedule| {
+            Self::vested_amount(&schedule, Self::get_current_block_as_u64())
+        })
+    }
+
+    pub fn get_vested_balance_for_account(coldkey_account_vec: Vec<u8>) -> Option<u64> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+        Some(Self::get_vested_balance(&coldkey))
+    }
+
+    /// Returns the unvested balance `coldkey` has staked.
+    pub fn get_vesting_staked(coldkey: &T::AccountId) -> u64 {
+        VestingSchedules::<T>::get(coldkey).map_or(0, |schedule| schedule.staked)
+    }
+
+    /// Writes `schedule` and locks the balance still vesting that is not staked. The schedule and
+    /// its lock are removed once it has fully vested.
+    ///
+    /// The staked part vests last: the lock on the free balance is released first.
+    fn update_vesting_lock(coldkey: &T::AccountId, mut schedule: VestingScheduleInfo) {
+        let unvested: u64 = schedule.total.saturating_sub(Self::vested_amount(
+            &schedule,
+            Self::get_current_block_as_u64(),
+        ));
+        if unvested == 0 {
+            VestingSchedules::<T>::remove(coldkey);
+            T::VestingCurrency::remove_lock(VESTING_LOCK_ID, coldkey);
+            return;
+        }
+
+        schedule.staked = schedule.staked.min(unvested);
+        let locked: u64 = unvested.saturating_sub(schedule.staked);
+        if locked == 0 {
+            T::VestingCurrency::remove_lock(VESTING_LOCK_ID, coldkey);
+        } else {
+            T::VestingCurrency::set_lock(VESTING_LOCK_ID, coldkey, locked, WithdrawReasons::all());
+        }
+        VestingSchedules::<T>::insert(coldkey, schedule);
+    }
+
+    /// Releases the part of the vesting lock of `coldkey` that staking `amount` needs beyond its
+    /// unlocked balance, counting it as staked under the schedule. Returns the weight consumed.
+    pub fn unlock_vesting_for_stake(coldkey: &T::AccountId, amount: u64) -> Weight {
+        let Some(mut schedule) = VestingSchedules::<T>::get(coldkey) else {
+            return T::DbWeight::get().reads(1);
+        };
+
+        let unvested: u64 = schedule.total.saturating_sub(Self::vested_amount(
+            &schedule,
+            Self::get_current_block_as_u64(),
+        ));
+        let locked: u64 = unvested.saturating_sub(schedule.staked.min(unvested));
+        let shortfall: u64 = amount.saturating_sub(Self::get_coldkey_balance_keep_alive(coldkey));
+        schedule.staked = schedule.staked.saturating_add(shortfall.min(locked));
+        Self::update_vesting_lock(coldkey, schedule);
+        T::DbWeight::get().reads_writes(3, 2)
+    }
+
+    /// Locks again the unvested stake of `coldkey` its unstaking returned to its free balance.
+    ///
+    /// The unstaked balance comes out of the stake the coldkey holds beyond its unvested stake
+    /// first. Returns the weight consumed.
+    pub fn relock_vesting_after_unstake(coldkey: &T::AccountId) -> Weight {
+        let Some(mut schedule) = VestingSchedules::<T>::get(coldkey) else {
+            return T::DbWeight::get().reads(1);
+        };
+
+        schedule.staked = schedule
+            .staked
+            .min(Self::get_total_stake_for_coldkey(coldkey));
+        Self::update_vesting_lock(coldkey, schedule);
+        T::DbWeight::get().reads_writes(3, 2)
+    }
+}
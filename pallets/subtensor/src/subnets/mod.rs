@@ -1,4 +1,5 @@
 use super::*;
+pub mod param_invariants;
 pub mod registration;
 pub mod serving;
 pub mod uids;
@@ -0,0 +1,129 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// An invariant between the hyperparameters of a subnet that the weights and the epoch of the
+/// subnet rely on, reported when it is violated.
+#[derive(Decode, Encode, TypeInfo, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum SubnetParamViolation {
+    /// `min_allowed_weights` is above the number of weights a validator can set, so no
+    /// validator can set valid weights.
+    MinAllowedWeightsAboveMaxWeightsLen,
+    /// `kappa` is 0 or `u16::MAX`, so the consensus has no stake majority to clip at.
+    KappaOutOfRange,
+    /// `rho` is 0.
+    RhoIsZero,
+    /// `activity_cutoff` is below `tempo`, so a validator setting weights once an epoch is
+    /// inactive at the next one.
+    ActivityCutoffBelowTempo,
+    /// `immunity_period` is not below `activity_cutoff`, so an inactive neuron can still be
+    /// immune to pruning.
+    ImmunityPeriodNotBelowActivityCutoff,
+}
+
+impl<T: Config> From<SubnetParamViolation> for Error<T> {
+    fn from(violation: SubnetParamViolation) -> Self {
+        match violation {
+            SubnetParamViolation::MinAllowedWeightsAboveMaxWeightsLen => {
+                Error::<T>::MinAllowedWeightsAboveMaxWeightsLen
+            }
+            SubnetParamViolation::KappaOutOfRange => Error::<T>::KappaOutOfRange,
+            SubnetParamViolation::RhoIsZero => Error::<T>::RhoIsZero,
+            SubnetParamViolation::ActivityCutoffBelowTempo => Error::<T>::ActivityCutoffBelowTempo,
+            SubnetParamViolation::ImmunityPeriodNotBelowActivityCutoff => {
+                Error::<T>::ImmunityPeriodNotBelowActivityCutoff
+            }
+        }
+    }
+}
+
+/// The hyperparameters of a subnet the invariants are checked on.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SubnetParams {
+    pub min_allowed_weights: u16,
+    pub max_weights_limit_len: u16,
+    pub max_allowed_uids: u16,
+    pub kappa: u16,
+    pub rho: u16,
+    pub tempo: u16,
+    pub activity_cutoff: u16,
+    pub immunity_period: u16,
+}
+
+impl SubnetParams {
+    /// Returns the invariants the hyperparameters violate.
+    ///
+    /// 1. `min_allowed_weights` is at most the number of weights a validator can set.
+    /// 2. `kappa` is strictly between 0 and `u16::MAX`.
+    /// 3. `rho` is nonzero.
+    /// 4. `activity_cutoff` is at least `tempo`.
+    /// 5. `immunity_period` is below `activity_cutoff`.
+    pub fn violations(&self) -> Vec<SubnetParamViolation> {
+        let mut violations: Vec<SubnetParamViolation> = Vec::new();
+        if self.min_allowed_weights > self.max_weights_limit_len.min(self.max_allowed_uids) {
+            violations.push(SubnetParamViolation::MinAllowedWeightsAboveMaxWeightsLen);
+        }
+        if self.kappa == 0 || self.kappa == u16::MAX {
+            violations.push(SubnetParamViolation::KappaOutOfRange);
+        }
+        if self.rho == 0 {
+            violations.push(SubnetParamViolation::RhoIsZero);
+        }
+        if self.activity_cutoff < self.tempo {
+            violations.push(SubnetParamViolation::ActivityCutoffBelowTempo);
+        }
+        if self.immunity_period >= self.activity_cutoff {
+            violations.push(SubnetParamViolation::ImmunityPeriodNotBelowActivityCutoff);
+        }
+        violations
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the current hyperparameters of `netuid` the invariants are checked on.
+    pub fn get_subnet_params(netuid: u16) -> SubnetParams {
+        SubnetParams {
+            min_allowed_weights: Self::get_min_allowed_weights(netuid),
+            max_weights_limit_len: MaxWeightsLimitLen::<T>::get(netuid),
+            max_allowed_uids: Self::get_max_allowed_uids(netuid),
+            kappa: Self::get_kappa(netuid),
+            rho: Self::get_rho(netuid),
+            tempo: Self::get_tempo(netuid),
+            activity_cutoff: Self::get_activity_cutoff(netuid),
+            immunity_period: Self::get_immunity_period(netuid),
+        }
+    }
+
+    /// Returns the invariants the hyperparameters of `netuid` currently violate, or `None` if
+    /// the subnet does not exist.
+    pub fn validate_subnet_params(netuid: u16) -> Option<Vec<SubnetParamViolation>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        Some(Self::get_subnet_params(netuid).violations())
+    }
+
+    /// Ensures applying `update` to the hyperparameters of `netuid` breaks none of the invariants
+    /// they hold, returning the error of the first one it breaks.
+    ///
+    /// Invariants the subnet already violates are not reported, so a broken subnet can be
+    /// repaired one hyperparameter at a time.
+    pub fn ensure_subnet_params_update(
+        netuid: u16,
+        update: impl FnOnce(&mut SubnetParams),
+    ) -> Result<(), Error<T>> {
+        let current: SubnetParams = Self::get_subnet_params(netuid);
+        let mut updated: SubnetParams = current;
+        update(&mut updated);
+
+        let violated: Vec<SubnetParamViolation> = current.violations();
+        match updated
+            .violations()
+            .into_iter()
+            .find(|violation| !violated.contains(violation))
+        {
+            Some(violation) => Err(violation.into()),
+            None => Ok(()),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 use super::*;
-use sp_core::{H256, U256};
+use frame_support::weights::Weight;
+use sp_core::{Get, H256, U256};
 use sp_io::hashing::{keccak_256, sha2_256};
-use sp_runtime::Saturating;
+use sp_runtime::{
+    traits::{Verify, Zero},
+    Saturating,
+};
 use system::pallet_prelude::BlockNumberFor;
 
 const LOG_TARGET: &str = "runtime::subtensor::registration";
@@ -34,6 +38,9 @@ impl<T: Config> Pallet<T> {
     /// * 'HotKeyAlreadyRegisteredInSubNet':
     ///     - The hotkey is already registered on this network.
     ///
+    /// * 'InviteRequired':
+    ///     - The network only registers hotkeys invited by its owner.
+    ///
     pub fn do_burned_registration(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -48,6 +55,211 @@ impl<T: Config> Pallet<T> {
             hotkey
         );
 
+        Self::burned_register_for_coldkey(coldkey, netuid, hotkey, false)
+    }
+
+    /// ---- The implementation for the extrinsic burned_register_by_proxy: registering by burning
+    /// TAO of a coldkey, on its behalf.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the provider submitting the registration.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'hotkey' ( T::AccountId ):
+    ///     - Hotkey to be registered to the network.
+    ///
+    /// * 'coldkey' ( T::AccountId ):
+    ///     - Coldkey paying the burn and owning the hotkey.
+    ///
+    /// * 'max_burn' (u64):
+    ///     - The highest burn the coldkey agrees to pay.
+    ///
+    /// * 'coldkey_signature' ( T::CallSignature ):
+    ///     - Signature of the coldkey over the encoded (b"subtensor/register_by_proxy",
+    ///       genesis_hash, netuid, hotkey, provider, nonce, max_burn), where nonce is the current
+    ///       registration nonce of the coldkey.
+    ///
+    /// # Event:
+    /// * NeuronRegistered;
+    ///     - On successfully registereing a uid to a neuron slot on a subnetwork.
+    ///
+    /// # Raises:
+    /// * 'InvalidRegistrationSignature':
+    ///     - The signature is not by the coldkey, is over another provider, a used nonce or
+    ///       another chain.
+    ///
+    /// * 'BurnAboveSignedMaximum':
+    ///     - The burn of the subnet is above the maximum the coldkey signed for.
+    ///
+    /// * Any error of 'do_burned_registration'.
+    ///
+    pub fn do_burned_register_by_proxy(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+        coldkey: T::AccountId,
+        max_burn: u64,
+        coldkey_signature: T::CallSignature,
+    ) -> DispatchResult {
+        // --- 1. Check that the provider has signed the transaction.
+        let provider = ensure_signed(origin)?;
+        log::debug!(
+            "do_burned_register_by_proxy( provider:{:?} coldkey:{:?} netuid:{:?} hotkey:{:?} max_burn:{:?} )",
+            provider,
+            coldkey,
+            netuid,
+            hotkey,
+            max_burn
+        );
+
+        // --- 2. Ensure the coldkey signed this registration for this provider on this chain with
+        // its current nonce.
+        let nonce: u64 = RegistrationNonce::<T>::get(&coldkey);
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+        let message = (
+            b"subtensor/register_by_proxy",
+            genesis_hash,
+            netuid,
+            &hotkey,
+            &provider,
+            nonce,
+            max_burn,
+        )
+            .encode();
+        ensure!(
+            coldkey_signature.verify(message.as_slice(), &coldkey),
+            Error::<T>::InvalidRegistrationSignature
+        );
+
+        // --- 3. Ensure the burn is not above what the coldkey agreed to pay.
+        ensure!(
+            Self::get_burn_as_u64(netuid) <= max_burn,
+            Error::<T>::BurnAboveSignedMaximum
+        );
+
+        // --- 4. Use up the nonce so that the signature cannot be replayed.
+        RegistrationNonce::<T>::insert(&coldkey, nonce.saturating_add(1));
+
+        // --- 5. Register as if the coldkey submitted it.
+        Self::burned_register_for_coldkey(coldkey, netuid, hotkey, false)
+    }
+
+    /// ---- The implementation for the extrinsic burned_register_with_invite: registering by
+    /// burning TAO on a subnet with invite-only registration.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the calling coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'hotkey' ( T::AccountId ):
+    ///     - Hotkey to be registered to the network.
+    ///
+    /// * 'expiry_block' (u64):
+    ///     - The last block at which the invite can be used.
+    ///
+    /// * 'owner_signature' ( T::CallSignature ):
+    ///     - Signature of the subnet owner over the encoded (b"subtensor/registration_invite",
+    ///       netuid, hotkey, coldkey, genesis_hash, expiry_block).
+    ///
+    /// # Event:
+    /// * NeuronRegistered;
+    ///     - On successfully registereing a uid to a neuron slot on a subnetwork.
+    ///
+    /// # Raises:
+    /// * 'InviteExpired':
+    ///     - The expiry block of the invite has passed.
+    ///
+    /// * 'InviteAlreadyUsed':
+    ///     - The invite has already registered the hotkey.
+    ///
+    /// * 'InvalidInviteSignature':
+    ///     - The signature is not by the subnet owner over this hotkey, coldkey and expiry block on
+    ///       this chain.
+    ///
+    /// * Any error of 'do_burned_registration'.
+    ///
+    pub fn do_burned_register_with_invite(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+        expiry_block: u64,
+        owner_signature: T::CallSignature,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller has signed the transaction.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_burned_register_with_invite( coldkey:{:?} netuid:{:?} hotkey:{:?} expiry_block:{:?} )",
+            coldkey,
+            netuid,
+            hotkey,
+            expiry_block
+        );
+
+        // --- 2. Ensure the invite is still valid and unused.
+        ensure!(
+            Self::get_current_block_as_u64() <= expiry_block,
+            Error::<T>::InviteExpired
+        );
+        ensure!(
+            !UsedInvites::<T>::get(netuid, (&hotkey, expiry_block)),
+            Error::<T>::InviteAlreadyUsed
+        );
+
+        // --- 3. Ensure the subnet owner signed the invite for this coldkey on this chain.
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+        let message = (
+            b"subtensor/registration_invite",
+            netuid,
+            &hotkey,
+            &coldkey,
+            genesis_hash,
+            expiry_block,
+        )
+            .encode();
+        ensure!(
+            owner_signature.verify(message.as_slice(), &SubnetOwner::<T>::get(netuid)),
+            Error::<T>::InvalidInviteSignature
+        );
+
+        // --- 4. Use up the invite so that it cannot be replayed, until it expires.
+        UsedInvites::<T>::insert(netuid, (&hotkey, expiry_block), true);
+        UsedInvitesByExpiry::<T>::append(expiry_block, (netuid, hotkey.clone()));
+
+        // --- 5. Register as the invited coldkey.
+        Self::burned_register_for_coldkey(coldkey, netuid, hotkey, true)
+    }
+
+    /// Removes the used invites that expired at the block before `block_number`.
+    ///
+    /// An expired invite is rejected before its use is looked up, so its record is no longer
+    /// needed to prevent a replay. Returns the weight consumed.
+    pub fn prune_expired_invites(block_number: u64) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+        let Some(expiry_block) = block_number.checked_sub(1) else {
+            return weight;
+        };
+        for (netuid, hotkey) in UsedInvitesByExpiry::<T>::take(expiry_block) {
+            UsedInvites::<T>::remove(netuid, (hotkey, expiry_block));
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight
+    }
+
+    /// Registers the hotkey on the subnet by burning TAO of the coldkey, which owns the hotkey.
+    ///
+    /// A subnet with invite-only registration only registers `invited` hotkeys.
+    fn burned_register_for_coldkey(
+        coldkey: T::AccountId,
+        netuid: u16,
+        hotkey: T::AccountId,
+        invited: bool,
+    ) -> DispatchResult {
         // --- 2. Ensure the passed network is valid.
         ensure!(
             netuid != Self::get_root_netuid(),
@@ -63,6 +275,14 @@ impl<T: Config> Pallet<T> {
             Self::get_network_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        ensure!(
+            !Self::is_registration_blocked_by_subnet_warning(netuid),
+            Error::<T>::SubnetWarningRegistrationDisabled
+        );
+        ensure!(
+            invited || !Self::is_invite_only_registration(netuid),
+            Error::<T>::InviteRequired
+        );
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -84,6 +304,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
 
+        // --- 5. Ensure the coldkey can own the hotkey.
+        Self::ensure_coldkey_can_own_hotkey(&coldkey, &hotkey)?;
+
         // DEPRECATED --- 6. Ensure that the key passes the registration requirement
         // ensure!(
         //     Self::passes_network_connection_requirement(netuid, &hotkey),
@@ -102,11 +325,15 @@ impl<T: Config> Pallet<T> {
         let actual_burn_amount =
             Self::remove_balance_from_coldkey_account(&coldkey, registration_cost)?;
 
-        // The burn occurs here.
-        Self::burn_tokens(actual_burn_amount);
+        // The owner share of the burn is paid to the subnet owner, and the rest is burned here.
+        let owner_cut = Self::get_registration_burn_owner_cut(netuid, actual_burn_amount);
+        let paid_to_owner =
+            Self::add_balance_to_coldkey_account(&SubnetOwner::<T>::get(netuid), owner_cut);
+        let burned_amount = actual_burn_amount.saturating_sub(paid_to_owner);
+        Self::burn_tokens_of_coldkey(&coldkey, burned_amount);
 
         // --- 9. If the network account does not exist we will create it here.
-        Self::create_account_if_non_existent(&coldkey, &hotkey);
+        Self::create_account_if_non_existent(&coldkey, &hotkey)?;
 
         // --- 10. Ensure that the pairing is correct.
         ensure!(
@@ -143,10 +370,11 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 14. Record the registration and increment block and interval counters.
+        RegistrationBurn::<T>::insert(netuid, subnetwork_uid, burned_amount);
         BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
-        Self::increase_rao_recycled(netuid, Self::get_burn_as_u64(netuid));
+        Self::increase_rao_recycled(netuid, burned_amount);
 
         // --- 15. Deposit successful event.
         log::debug!(
@@ -243,11 +471,17 @@ impl<T: Config> Pallet<T> {
             Error::<T>::SubNetworkDoesNotExist
         );
 
-        // --- 3. Ensure the passed network allows registrations.
+        // --- 3. Ensure the passed network allows registrations. Invite-only subnets do not
+        // register by proof of work.
         ensure!(
-            Self::get_network_pow_registration_allowed(netuid),
+            Self::get_network_pow_registration_allowed(netuid)
+                && !Self::is_invite_only_registration(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        ensure!(
+            !Self::is_registration_blocked_by_subnet_warning(netuid),
+            Error::<T>::SubnetWarningRegistrationDisabled
+        );
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -269,6 +503,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
 
+        // --- 6. Ensure the coldkey can own the hotkey.
+        Self::ensure_coldkey_can_own_hotkey(&coldkey, &hotkey)?;
+
         // --- 7. Ensure the passed block number is valid, not in the future or too old.
         // Work must have been done within 3 blocks (stops long range attacks).
         let current_block_number: u64 = Self::get_current_block_as_u64();
@@ -301,7 +538,7 @@ impl<T: Config> Pallet<T> {
         // );
 
         // --- 9. If the network account does not exist we will create it here.
-        Self::create_account_if_non_existent(&coldkey, &hotkey);
+        Self::create_account_if_non_existent(&coldkey, &hotkey)?;
 
         // --- 10. Ensure that the pairing is correct.
         ensure!(
@@ -355,6 +592,101 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic deregister: a neuron voluntarily leaving a subnet.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the coldkey owning the hotkey.
+    ///
+    /// * 'hotkey' ( T::AccountId ):
+    ///     - Hotkey to be deregistered from the network.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// # Event:
+    /// * NeuronDeregistered;
+    ///     - On successfully freeing the uid of the neuron on the subnetwork.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - Attempting to deregister from a non existent network.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The coldkey does not own the hotkey.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on this network.
+    ///
+    pub fn do_deregister(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller has signed the transaction. (the coldkey of the pairing)
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_deregister( coldkey:{:?} hotkey:{:?} netuid:{:?} )",
+            coldkey,
+            hotkey,
+            netuid
+        );
+
+        // --- 2. Ensure the passed network is valid. Root membership is tied to the senate.
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        // --- 3. Ensure that the pairing is correct.
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // --- 4. Ensure that the key is registered.
+        let uid: u16 = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+
+        // --- 5. Refund half of the burn paid for the uid if the subnet recycles it.
+        let refund: u64 = if Self::get_recycle_registration_burn(netuid) {
+            RegistrationBurn::<T>::get(netuid, uid).saturating_div(2)
+        } else {
+            0
+        };
+
+        // --- 6. Free the uid. The stake of the hotkey stays in place.
+        Self::remove_neuron(netuid, uid);
+
+        // --- 7. Mint the refund back to the coldkey.
+        let refund: u64 = Self::add_balance_to_coldkey_account(&coldkey, refund);
+        Self::coinbase(refund);
+        RAORecycledForRegistration::<T>::mutate(netuid, |recycled| {
+            *recycled = recycled.saturating_sub(refund)
+        });
+
+        // --- 8. Deposit successful event.
+        log::debug!(
+            "NeuronDeregistered( netuid:{:?} uid:{:?} hotkey:{:?} refund:{:?} ) ",
+            netuid,
+            uid,
+            hotkey,
+            refund
+        );
+        Self::deposit_event(Event::NeuronDeregistered {
+            netuid,
+            uid,
+            hotkey,
+            refund,
+        });
+
+        // --- 9. Ok and done.
+        Ok(())
+    }
+
     pub fn do_faucet(
         origin: T::RuntimeOrigin,
         block_number: u64,
@@ -442,7 +774,7 @@ impl<T: Config> Pallet<T> {
         }
 
         for neuron_uid in 0..neurons_n {
-            let pruning_score: u16 = Self::get_pruning_score_for_uid(netuid, neuron_uid);
+            let pruning_score: u16 = Self::get_effective_pruning_score_for_uid(netuid, neuron_uid);
             let block_at_registration: u64 =
                 Self::get_neuron_block_at_registration(netuid, neuron_uid);
             let is_immune = Self::get_neuron_is_immune(netuid, neuron_uid);
@@ -476,9 +808,11 @@ impl<T: Config> Pallet<T> {
 
         if found_non_immune {
             Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
+            Self::set_custom_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
             uid_to_prune
         } else {
             Self::set_pruning_score_for_uid(netuid, uid_to_prune_in_immunity, u16::MAX);
+            Self::set_custom_pruning_score_for_uid(netuid, uid_to_prune_in_immunity, u16::MAX);
             uid_to_prune_in_immunity
         }
     }
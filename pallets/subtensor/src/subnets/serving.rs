@@ -51,6 +51,9 @@ impl<T: Config> Pallet<T> {
     /// * 'ServingRateLimitExceeded':
     ///     - Attempting to set prometheus information withing the rate limit min.
     ///
+    /// * 'AxonVersionTooOld':
+    ///     - The version is below the minimum axon version of the subnet.
+    ///
     pub fn do_serve_axon(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -78,6 +81,12 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InvalidIpAddress
         );
 
+        // Ensure the axon version meets the subnet minimum.
+        ensure!(
+            version >= Self::get_min_axon_version(netuid),
+            Error::<T>::AxonVersionTooOld
+        );
+
         // Get the previous axon information.
         let mut prev_axon = Self::get_axon_info(netuid, &hotkey_id);
         let current_block: u64 = Self::get_current_block_as_u64();
@@ -113,6 +122,58 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic clear_axon which removes the axon of a hotkey on a
+    /// network, so other neurons stop dialing a stale endpoint.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the hotkey or of its coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey whose axon is removed.
+    ///
+    /// # Event:
+    /// * AxonCleared;
+    ///     - On successfully removing the axon info.
+    ///
+    /// # Raises:
+    /// * 'NonAssociatedColdKey':
+    ///     - The caller is neither the hotkey nor its coldkey.
+    ///
+    /// * 'AxonNotServed':
+    ///     - The hotkey has no axon on the network.
+    ///
+    pub fn do_clear_axon(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        // We check the caller is the hotkey or its coldkey.
+        let caller = ensure_signed(origin)?;
+        ensure!(
+            caller == hotkey || Self::coldkey_owns_hotkey(&caller, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // Ensure there is an axon to remove.
+        ensure!(
+            Self::has_axon_info(netuid, &hotkey),
+            Error::<T>::AxonNotServed
+        );
+
+        Axons::<T>::remove(netuid, &hotkey);
+
+        // We deposit axon cleared event.
+        log::debug!("AxonCleared( netuid:{:?} hotkey:{:?} ) ", netuid, hotkey);
+        Self::deposit_event(Event::AxonCleared(netuid, hotkey));
+
+        // Return is successful dispatch.
+        Ok(())
+    }
+
     /// ---- The implementation for the extrinsic serve_prometheus.
     ///
     /// # Args:
@@ -235,6 +296,12 @@ impl<T: Config> Pallet<T> {
         rate_limit == 0 || last_serve == 0 || current_block.saturating_sub(last_serve) >= rate_limit
     }
 
+    /// Returns true if the stored axon of the hotkey is below the minimum axon version of the
+    /// subnet. Raising the minimum flags existing axons rather than removing them.
+    pub fn is_axon_outdated(netuid: u16, hotkey: &T::AccountId) -> bool {
+        Self::get_axon_info(netuid, hotkey).version < Self::get_min_axon_version(netuid)
+    }
+
     pub fn has_axon_info(netuid: u16, hotkey: &T::AccountId) -> bool {
         Axons::<T>::contains_key(netuid, hotkey)
     }
@@ -30,6 +30,12 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::remove(netuid, old_hotkey.clone());
         IsNetworkMember::<T>::remove(old_hotkey.clone(), netuid);
         Keys::<T>::remove(netuid, uid_to_replace);
+        RegistrationBurn::<T>::remove(netuid, uid_to_replace);
+        Axons::<T>::remove(netuid, &old_hotkey);
+        Prometheus::<T>::remove(netuid, &old_hotkey);
+        WeightCommits::<T>::remove(netuid, &old_hotkey);
+        Self::refund_weight_commit_deposit(netuid, &old_hotkey);
+        T::OnNeuronRemoved::on_neuron_removed(netuid, &old_hotkey);
 
         // 2a. Check if the uid is registered in any other subnetworks.
         let hotkey_is_registered_on_any_network: bool =
@@ -45,6 +51,7 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), uid_to_replace); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, uid_to_replace, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+        Self::dequeue_inactive_hotkey(new_hotkey);
     }
 
     /// Appends the uid to the network.
@@ -60,6 +67,7 @@ impl<T: Config> Pallet<T> {
 
         // 2. Get and increase the uid count.
         SubnetworkN::<T>::insert(netuid, next_uid.saturating_add(1));
+        TotalNeuronCount::<T>::mutate(|n| *n = n.saturating_add(1));
 
         // 3. Expand Yuma Consensus with new position.
         Rank::<T>::mutate(netuid, |v| v.push(0));
@@ -71,6 +79,9 @@ impl<T: Config> Pallet<T> {
         Dividends::<T>::mutate(netuid, |v| v.push(0));
         LastUpdate::<T>::mutate(netuid, |v| v.push(block_number));
         PruningScores::<T>::mutate(netuid, |v| v.push(0));
+        if CustomPruningScores::<T>::contains_key(netuid) {
+            CustomPruningScores::<T>::mutate(netuid, |v| v.push(0));
+        }
         ValidatorTrust::<T>::mutate(netuid, |v| v.push(0));
         ValidatorPermit::<T>::mutate(netuid, |v| v.push(false));
 
@@ -79,6 +90,126 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), next_uid); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, next_uid, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+        Self::dequeue_inactive_hotkey(new_hotkey);
+    }
+
+    /// Removes the neuron under this uid and frees its slot.
+    ///
+    /// The neuron holding the last uid moves into the freed slot so that uids stay contiguous,
+    /// and the subnetwork count is decremented. Weights and bonds set on the removed uid are
+    /// dropped, those set on the last uid follow it. The stake of the hotkey is left untouched.
+    pub fn remove_neuron(netuid: u16, uid_to_remove: u16) {
+        // 1. Get the hotkey under this position and the last uid of the network.
+        let hotkey: T::AccountId = Keys::<T>::get(netuid, uid_to_remove);
+        let last_uid: u16 = Self::get_subnetwork_n(netuid).saturating_sub(1);
+        log::debug!(
+            "remove_neuron( netuid: {:?} | uid_to_remove: {:?} | hotkey: {:?} | last_uid: {:?} ) ",
+            netuid,
+            uid_to_remove,
+            hotkey,
+            last_uid
+        );
+
+        // 2. Remove the memberships and serving info of the hotkey.
+        Uids::<T>::remove(netuid, &hotkey);
+        IsNetworkMember::<T>::remove(&hotkey, netuid);
+        Axons::<T>::remove(netuid, &hotkey);
+        Prometheus::<T>::remove(netuid, &hotkey);
+        WeightCommits::<T>::remove(netuid, &hotkey);
+        Self::refund_weight_commit_deposit(netuid, &hotkey);
+        T::OnNeuronRemoved::on_neuron_removed(netuid, &hotkey);
+        Self::queue_inactive_hotkey(netuid, &hotkey);
+        if let Some(mut loaded_emission) = LoadedEmission::<T>::get(netuid) {
+            loaded_emission.retain(|(emission_hotkey, _, _)| *emission_hotkey != hotkey);
+            LoadedEmission::<T>::insert(netuid, loaded_emission);
+        }
+
+        // 3. Move the neuron under the last uid into the freed slot.
+        if uid_to_remove != last_uid {
+            let last_hotkey: T::AccountId = Keys::<T>::get(netuid, last_uid);
+            Keys::<T>::insert(netuid, uid_to_remove, last_hotkey.clone());
+            Uids::<T>::insert(netuid, last_hotkey, uid_to_remove);
+            BlockAtRegistration::<T>::insert(
+                netuid,
+                uid_to_remove,
+                BlockAtRegistration::<T>::get(netuid, last_uid),
+            );
+            RegistrationBurn::<T>::insert(
+                netuid,
+                uid_to_remove,
+                RegistrationBurn::<T>::get(netuid, last_uid),
+            );
+            Weights::<T>::insert(netuid, uid_to_remove, Weights::<T>::get(netuid, last_uid));
+            Bonds::<T>::insert(netuid, uid_to_remove, Bonds::<T>::get(netuid, last_uid));
+        }
+        Keys::<T>::remove(netuid, last_uid);
+        BlockAtRegistration::<T>::remove(netuid, last_uid);
+        RegistrationBurn::<T>::remove(netuid, last_uid);
+        Weights::<T>::remove(netuid, last_uid);
+        Bonds::<T>::remove(netuid, last_uid);
+
+        // 4. Drop the weight and bond columns of the removed uid and re-point those of the last uid.
+        let weights: Vec<(u16, WeightsRow)> = Weights::<T>::iter_prefix(netuid).collect();
+        for (uid_i, weights_i) in weights {
+            let moved = Self::remove_uid_from_sparse_row(weights_i, uid_to_remove, last_uid);
+            Weights::<T>::insert(netuid, uid_i, moved);
+        }
+        let bonds: Vec<(u16, WeightsRow)> = Bonds::<T>::iter_prefix(netuid).collect();
+        for (uid_i, bonds_i) in bonds {
+            let moved = Self::remove_uid_from_sparse_row(bonds_i, uid_to_remove, last_uid);
+            Bonds::<T>::insert(netuid, uid_i, moved);
+        }
+
+        // 5. Shrink Yuma Consensus, moving the last position into the freed one.
+        Rank::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        Trust::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        Active::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        Emission::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        Consensus::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        Incentive::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        Dividends::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        LastUpdate::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        PruningScores::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        if CustomPruningScores::<T>::contains_key(netuid) {
+            CustomPruningScores::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        }
+        ValidatorTrust::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        ValidatorPermit::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+        StakeWeight::<T>::mutate(netuid, |v| Self::swap_remove_uid(v, uid_to_remove));
+
+        // 6. Decrease the uid count.
+        SubnetworkN::<T>::insert(netuid, last_uid);
+        TotalNeuronCount::<T>::mutate(|n| *n = n.saturating_sub(1));
+    }
+
+    /// Removes the entries of a sparse weights or bonds row pointing at `uid_to_remove`,
+    /// and re-points the entries pointing at `last_uid` to `uid_to_remove`.
+    fn remove_uid_from_sparse_row(
+        row: WeightsRow,
+        uid_to_remove: u16,
+        last_uid: u16,
+    ) -> WeightsRow {
+        let mut moved: Vec<(u16, u16)> = row
+            .into_iter()
+            .filter(|(uid, _)| *uid != uid_to_remove)
+            .map(|(uid, value)| {
+                if uid == last_uid {
+                    (uid_to_remove, value)
+                } else {
+                    (uid, value)
+                }
+            })
+            .collect();
+        moved.sort_by_key(|(uid, _)| *uid);
+        // Filtering and re-pointing never grow the row, so it still fits the bound.
+        WeightsRow::truncate_from(moved)
+    }
+
+    /// Removes the position of `uid` from a per-uid vector, moving the last position into it.
+    fn swap_remove_uid<V>(values: &mut Vec<V>, uid: u16) {
+        if (uid as usize) < values.len() {
+            values.swap_remove(uid as usize);
+        }
     }
 
     /// Returns true if the uid is set on the network.
@@ -18,9 +18,15 @@ impl<T: Config> Pallet<T> {
     ///   - The hash representing the committed weights.
     ///
     /// # Raises:
+    /// * `NotEnoughStakeToSetWeights`:
+    ///   - The hotkey has neither a validator permit nor the stake threshold for this network.
+    ///
     /// * `WeightsCommitNotAllowed`:
     ///   - Attempting to commit when it is not allowed.
     ///
+    /// * `NotEnoughBalanceForCommitDeposit`:
+    ///   - The owner coldkey cannot pay the commit deposit.
+    ///
     pub fn do_commit_weights(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -35,11 +41,29 @@ impl<T: Config> Pallet<T> {
             Error::<T>::CommitRevealDisabled
         );
 
+        ensure!(
+            Self::check_weights_min_stake(&who, netuid),
+            Error::<T>::NotEnoughStakeToSetWeights
+        );
+
         ensure!(
             Self::can_commit(netuid, &who),
             Error::<T>::WeightsCommitNotAllowed
         );
 
+        // Withdraw the commit deposit from the owner coldkey, unless it still holds one for an
+        // unrevealed commit. The deposit is refunded on reveal.
+        let deposit: u64 = Self::get_weight_commit_deposit();
+        if deposit > 0 && !WeightCommitDeposits::<T>::contains_key(netuid, &who) {
+            let coldkey: T::AccountId = Self::get_owning_coldkey_for_hotkey(&who);
+            ensure!(
+                Self::can_remove_balance_from_coldkey_account(&coldkey, deposit),
+                Error::<T>::NotEnoughBalanceForCommitDeposit
+            );
+            let withdrawn: u64 = Self::remove_balance_from_coldkey_account(&coldkey, deposit)?;
+            WeightCommitDeposits::<T>::insert(netuid, &who, withdrawn);
+        }
+
         WeightCommits::<T>::insert(
             netuid,
             &who,
@@ -120,7 +144,136 @@ impl<T: Config> Pallet<T> {
             );
 
             Self::do_set_weights(origin, netuid, uids, values, version_key)
-        })
+        })?;
+
+        // The commit was revealed in time, refund its deposit.
+        Self::refund_weight_commit_deposit(netuid, &who);
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic prune_expired_commits.
+    ///
+    /// # Args:
+    /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+    ///   - Any signed account, which receives the commit deposit as a bounty.
+    ///
+    /// * `netuid` (`u16`):
+    ///   - The u16 network identifier.
+    ///
+    /// * `hotkey` (`T::AccountId`):
+    ///   - The hotkey of the expired commit.
+    ///
+    /// # Event:
+    /// * `ExpiredWeightsCommitPruned`:
+    ///   - On removing the commit.
+    ///
+    /// # Raises:
+    /// * `NoWeightsCommitFound`:
+    ///   - The hotkey has no commit on the network.
+    ///
+    /// * `WeightsCommitNotExpired`:
+    ///   - The commit can still be revealed.
+    ///
+    pub fn do_prune_expired_commits(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+    ) -> DispatchResult {
+        let pruner = ensure_signed(origin)?;
+
+        log::debug!(
+            "do_prune_expired_commits( pruner:{:?} netuid:{:?} hotkey:{:?} )",
+            pruner,
+            netuid,
+            hotkey
+        );
+
+        let (_, commit_block) =
+            WeightCommits::<T>::get(netuid, &hotkey).ok_or(Error::<T>::NoWeightsCommitFound)?;
+        ensure!(
+            Self::is_commit_expired(netuid, commit_block),
+            Error::<T>::WeightsCommitNotExpired
+        );
+
+        WeightCommits::<T>::remove(netuid, &hotkey);
+        let deposit: u64 = WeightCommitDeposits::<T>::take(netuid, &hotkey);
+        let bounty: u64 = Self::add_balance_to_coldkey_account_or_burn(&pruner, deposit);
+
+        Self::deposit_event(Event::ExpiredWeightsCommitPruned {
+            netuid,
+            hotkey,
+            pruner: Some(pruner),
+            bounty,
+        });
+        Ok(())
+    }
+
+    /// Removes the expired weights commits the remaining block weight allows, burning their
+    /// deposits.
+    ///
+    /// The sweep resumes after the last commit it visited, so each block makes progress over
+    /// the whole commit map.
+    pub fn prune_expired_weight_commits(remaining_weight: Weight) -> Weight {
+        // Reading the cursor and visiting a commit, removing it and its deposit, and burning it
+        // for the owner coldkey.
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+        let visit_weight: Weight = T::DbWeight::get().reads_writes(5, 4);
+        if weight.saturating_add(visit_weight).any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut commits = match WeightCommitsPruneCursor::<T>::get() {
+            Some(cursor) => WeightCommits::<T>::iter_from(cursor),
+            None => WeightCommits::<T>::iter(),
+        };
+        let mut expired: Vec<(u16, T::AccountId)> = Vec::new();
+        let mut cursor: Option<Vec<u8>> = None;
+        while weight
+            .saturating_add(visit_weight)
+            .all_lte(remaining_weight)
+        {
+            let Some((netuid, hotkey, (_, commit_block))) = commits.next() else {
+                // The sweep reached the end of the map, start over next time.
+                cursor = None;
+                break;
+            };
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
+            cursor = Some(WeightCommits::<T>::hashed_key_for(netuid, &hotkey));
+            if Self::is_commit_expired(netuid, commit_block) {
+                expired.push((netuid, hotkey));
+            }
+        }
+
+        match cursor {
+            Some(cursor) => WeightCommitsPruneCursor::<T>::put(cursor),
+            None => WeightCommitsPruneCursor::<T>::kill(),
+        }
+
+        for (netuid, hotkey) in expired {
+            WeightCommits::<T>::remove(netuid, &hotkey);
+            let deposit: u64 = WeightCommitDeposits::<T>::take(netuid, &hotkey);
+            let coldkey: T::AccountId = Self::get_owning_coldkey_for_hotkey(&hotkey);
+            Self::burn_tokens_of_coldkey(&coldkey, deposit);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 4));
+
+            Self::deposit_event(Event::ExpiredWeightsCommitPruned {
+                netuid,
+                hotkey,
+                pruner: None,
+                bounty: 0,
+            });
+        }
+        weight
+    }
+
+    /// Refunds the deposit held for the weights commit of `hotkey` on `netuid` to its owner
+    /// coldkey.
+    pub fn refund_weight_commit_deposit(netuid: u16, hotkey: &T::AccountId) {
+        let deposit: u64 = WeightCommitDeposits::<T>::take(netuid, hotkey);
+        if deposit > 0 {
+            let coldkey: T::AccountId = Self::get_owning_coldkey_for_hotkey(hotkey);
+            Self::add_balance_to_coldkey_account_or_burn(&coldkey, deposit);
+        }
     }
 
     /// ---- The implementation for the extrinsic set_weights.
@@ -170,6 +323,9 @@ impl<T: Config> Pallet<T> {
     /// * 'UidsLengthExceedUidsInSubNet':
     ///    - Attempting to set weights above the max allowed uids.
     ///
+    /// * 'TooManyWeights':
+    ///    - Attempting to set more weights than the max weights length of the subnet.
+    ///
     /// * 'UidVecContainInvalidOne':
     ///    - Attempting to set weights with invalid uids.
     ///
@@ -179,6 +335,13 @@ impl<T: Config> Pallet<T> {
     /// * 'MaxWeightExceeded':
     ///    - Attempting to set weights with max value exceeding limit.
     ///
+    /// * 'WeightOnUncommittedUid':
+    ///    - Attempting to weight a uid without a commitment on a subnet rejecting such weights.
+    ///
+    /// * 'WeightOnSameColdkeyUid':
+    ///    - Attempting to weight a uid owned by the validator's coldkey on a subnet rejecting such
+    ///      weights.
+    ///
     pub fn do_set_weights(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -196,6 +359,90 @@ impl<T: Config> Pallet<T> {
             values
         );
 
+        Self::set_weights_for_hotkey(&hotkey, netuid, uids, values, version_key)
+    }
+
+    /// ---- The implementation for the extrinsic set_weights_for_child.
+    ///
+    /// Sets the weights of `child_hotkey` on behalf of it, signed by one of its parents. The
+    /// child's owning coldkey must have permitted the parent through
+    /// `set_child_weights_permission`. The weights then go through the same checks as
+    /// `set_weights`, attributed to the child's uid, including the child's rate limit.
+    ///
+    /// # Raises:
+    ///  * 'CommitRevealEnabled':
+    ///    - Commit-reveal is enabled on the subnet, so weights must be committed.
+    ///
+    ///  * 'NotParentOfChild':
+    ///    - The caller is not a parent of the child on the subnet.
+    ///
+    ///  * 'ChildWeightsNotPermitted':
+    ///    - The child has not permitted the caller to set its weights on the subnet.
+    ///
+    /// Any error of `do_set_weights`.
+    pub fn do_set_weights_for_child(
+        origin: T::RuntimeOrigin,
+        child_hotkey: T::AccountId,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+    ) -> dispatch::DispatchResult {
+        let parent_hotkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_set_weights_for_child( parent:{:?} child:{:?} netuid:{:?}, uids:{:?}, values:{:?})",
+            parent_hotkey,
+            child_hotkey,
+            netuid,
+            uids,
+            values
+        );
+
+        ensure!(
+            !Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealEnabled
+        );
+        Self::ensure_parent_may_set_child_weights(&parent_hotkey, &child_hotkey, netuid)?;
+
+        Self::set_weights_for_hotkey(&child_hotkey, netuid, uids, values, version_key)
+    }
+
+    /// Ensures `parent_hotkey` is a parent of `child_hotkey` on `netuid`, permitted to set its
+    /// weights. Checked when `set_weights_for_child` is validated and dispatched.
+    ///
+    /// # Raises:
+    ///  * 'NotParentOfChild':
+    ///    - The caller is not a parent of the child on the subnet.
+    ///
+    ///  * 'ChildWeightsNotPermitted':
+    ///    - The child has not permitted the caller to set its weights on the subnet.
+    pub fn ensure_parent_may_set_child_weights(
+        parent_hotkey: &T::AccountId,
+        child_hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> dispatch::DispatchResult {
+        ensure!(
+            Self::get_parents(child_hotkey, netuid)
+                .iter()
+                .any(|(_, parent)| parent == parent_hotkey),
+            Error::<T>::NotParentOfChild
+        );
+        ensure!(
+            Self::get_child_weights_permission(parent_hotkey, child_hotkey, netuid),
+            Error::<T>::ChildWeightsNotPermitted
+        );
+        Ok(())
+    }
+
+    /// Checks and sets the weights of `hotkey` on `netuid`, the shared path of `set_weights`,
+    /// `reveal_weights` and `set_weights_for_child`.
+    fn set_weights_for_hotkey(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+    ) -> dispatch::DispatchResult {
         // --- Check that the netuid is not the root network.
         ensure!(
             netuid != Self::get_root_netuid(),
@@ -219,16 +466,20 @@ impl<T: Config> Pallet<T> {
             Self::check_len_uids_within_allowed(netuid, &uids),
             Error::<T>::UidsLengthExceedUidsInSubNet
         );
+        ensure!(
+            uids.len() <= Self::get_max_weights_limit_len(netuid) as usize,
+            Error::<T>::TooManyWeights
+        );
 
         // --- 5. Check to see if the hotkey is registered to the passed network.
         ensure!(
-            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Self::is_hotkey_registered_on_network(netuid, hotkey),
             Error::<T>::HotKeyNotRegisteredInSubNet
         );
 
-        // --- 6. Check to see if the hotkey has enought stake to set weights.
+        // --- 6. Check to see if the hotkey has enough stake (or a validator permit) to set weights.
         ensure!(
-            Self::get_total_stake_for_hotkey(&hotkey) >= Self::get_weights_min_stake(),
+            Self::check_weights_min_stake(hotkey, netuid),
             Error::<T>::NotEnoughStakeToSetWeights
         );
 
@@ -239,7 +490,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // --- 9. Ensure the uid is not setting weights faster than the weights_set_rate_limit.
-        let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+        let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey)?;
         let current_block: u64 = Self::get_current_block_as_u64();
         ensure!(
             Self::check_rate_limit(netuid, neuron_uid, current_block),
@@ -267,20 +518,50 @@ impl<T: Config> Pallet<T> {
             Error::<T>::WeightVecLengthIsLow
         );
 
-        // --- 14. Max-upscale the weights.
-        let max_upscaled_weights: Vec<u16> = vec_u16_max_upscale_to_u16(&values);
+        // --- 14. Max-upscale the weights, ensuring they are max weight limited.
+        let max_weight_limit: u16 =
+            Self::get_max_weight_limit_for(netuid, neuron_uid, &uids, &values);
+        let mut max_upscaled_weights: Vec<u16> = normalize_weight_values(&values, max_weight_limit)
+            .ok_or(Error::<T>::MaxWeightExceeded)?;
+
+        // --- Zero, or reject, the weights on uids whose hotkey has no commitment on the subnet.
+        // Self weights are exempt, as they carry no weight on a miner.
+        if Self::get_require_commitment_for_weights(netuid) {
+            for (uid, value) in uids.iter().zip(max_upscaled_weights.iter_mut()) {
+                if *value == 0 || *uid == neuron_uid || Self::uid_has_commitment(netuid, *uid) {
+                    continue;
+                }
+                ensure!(
+                    !Self::get_reject_uncommitted_weights(netuid),
+                    Error::<T>::WeightOnUncommittedUid
+                );
+                *value = 0;
+            }
+        }
 
-        // --- 15. Ensure the weights are max weight limited
-        ensure!(
-            Self::max_weight_limited(netuid, neuron_uid, &uids, &max_upscaled_weights),
-            Error::<T>::MaxWeightExceeded
-        );
+        // --- Zero, or reject, the weights on uids whose hotkey is owned by the coldkey of the
+        // validator. Self weights are exempt, as they carry no weight on a miner.
+        if Self::get_disallow_self_weight_by_coldkey(netuid) {
+            let owner: T::AccountId = Owner::<T>::get(hotkey);
+            for (uid, value) in uids.iter().zip(max_upscaled_weights.iter_mut()) {
+                if *value == 0 || *uid == neuron_uid || !Self::uid_owned_by(netuid, *uid, &owner) {
+                    continue;
+                }
+                ensure!(
+                    !Self::get_reject_self_weight_by_coldkey(netuid),
+                    Error::<T>::WeightOnSameColdkeyUid
+                );
+                *value = 0;
+            }
+        }
 
         // --- 16. Zip weights for sinking to storage map.
         let mut zipped_weights: Vec<(u16, u16)> = vec![];
         for (uid, val) in uids.iter().zip(max_upscaled_weights.iter()) {
             zipped_weights.push((*uid, *val))
         }
+        let zipped_weights =
+            WeightsRow::try_from(zipped_weights).map_err(|_| Error::<T>::TooManyWeights)?;
 
         // --- 17. Set weights under netuid, uid double map entry.
         Weights::<T>::insert(netuid, neuron_uid, zipped_weights);
@@ -300,6 +581,48 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Returns the limit and the provided value behind a `set_weights` failing with `error`, as
+    /// `(expected, provided)`, for the errors that have one.
+    pub fn set_weights_failure_detail(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        uids: &[u16],
+        values: &[u16],
+        version_key: u64,
+        error: &Error<T>,
+    ) -> Option<(u64, u64)> {
+        match error {
+            Error::<T>::WeightVecNotEqualSize => Some((uids.len() as u64, values.len() as u64)),
+            Error::<T>::UidsLengthExceedUidsInSubNet => {
+                Some((Self::get_subnetwork_n(netuid) as u64, uids.len() as u64))
+            }
+            Error::<T>::TooManyWeights => Some((
+                Self::get_max_weights_limit_len(netuid) as u64,
+                uids.len() as u64,
+            )),
+            Error::<T>::NotEnoughStakeToSetWeights => Some((
+                Self::get_stake_threshold_for_subnet(netuid),
+                Self::get_total_stake_for_hotkey(hotkey),
+            )),
+            Error::<T>::IncorrectWeightVersionKey => {
+                Some((Self::get_weights_version_key(netuid), version_key))
+            }
+            Error::<T>::SettingWeightsTooFast => {
+                let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey).ok()?;
+                Some((
+                    Self::get_weights_set_rate_limit(netuid),
+                    Self::get_current_block_as_u64()
+                        .saturating_sub(Self::get_last_update_for_uid(netuid, neuron_uid)),
+                ))
+            }
+            Error::<T>::WeightVecLengthIsLow => Some((
+                Self::get_min_allowed_weights(netuid).min(Self::get_subnetwork_n(netuid)) as u64,
+                values.len() as u64,
+            )),
+            _ => None,
+        }
+    }
+
     // ==========================
     // ==== Helper functions ====
     // ==========================
@@ -316,6 +639,13 @@ impl<T: Config> Pallet<T> {
         network_version_key == 0 || version_key >= network_version_key
     }
 
+    /// Returns the block at which `hotkey` last set weights on `netuid`, or `None` if it is not
+    /// registered or never set weights.
+    pub fn get_last_weights_set_block(netuid: u16, hotkey: &T::AccountId) -> Option<u64> {
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey).ok()?;
+        Some(Self::get_last_update_for_uid(netuid, uid)).filter(|last| *last != 0)
+    }
+
     /// Checks if the neuron has set weights within the weights_set_rate_limit.
     ///
     pub fn check_rate_limit(netuid: u16, neuron_uid: u16, current_block: u64) -> bool {
@@ -414,21 +744,19 @@ impl<T: Config> Pallet<T> {
         weights
     }
 
-    /// Returns False if the weights exceed the max_weight_limit for this network.
-    pub fn max_weight_limited(netuid: u16, uid: u16, uids: &[u16], weights: &[u16]) -> bool {
-        // Allow self weights to exceed max weight limit.
+    /// Returns the max weight limit the weights of `uid` are checked against. Self weights are
+    /// allowed to exceed the max weight limit of the network.
+    pub fn get_max_weight_limit_for(netuid: u16, uid: u16, uids: &[u16], weights: &[u16]) -> u16 {
         if Self::is_self_weight(uid, uids, weights) {
-            return true;
-        }
-
-        // If the max weight limit it u16 max, return true.
-        let max_weight_limit: u16 = Self::get_max_weight_limit(netuid);
-        if max_weight_limit == u16::MAX {
-            return true;
+            return u16::MAX;
         }
+        Self::get_max_weight_limit(netuid)
+    }
 
-        // Check if the weights max value is less than or equal to the limit.
-        check_vec_max_limited(weights, max_weight_limit)
+    /// Returns False if the weights exceed the max_weight_limit for this network.
+    pub fn max_weight_limited(netuid: u16, uid: u16, uids: &[u16], weights: &[u16]) -> bool {
+        let max_weight_limit: u16 = Self::get_max_weight_limit_for(netuid, uid, uids, weights);
+        normalize_weight_values(weights, max_weight_limit).is_some()
     }
 
     /// Returns true if the uids and weights correspond to a self weight on the uid.
@@ -445,6 +773,18 @@ impl<T: Config> Pallet<T> {
         true
     }
 
+    /// Returns whether the hotkey of `uid` has a commitment on `netuid`.
+    pub fn uid_has_commitment(netuid: u16, uid: u16) -> bool {
+        Self::get_hotkey_for_net_and_uid(netuid, uid)
+            .is_ok_and(|hotkey| T::Commitments::has_commitment(netuid, &hotkey))
+    }
+
+    /// Returns true if the hotkey of `uid` on `netuid` is owned by `coldkey`.
+    pub fn uid_owned_by(netuid: u16, uid: u16, coldkey: &T::AccountId) -> bool {
+        Self::get_hotkey_for_net_and_uid(netuid, uid)
+            .is_ok_and(|hotkey| Owner::<T>::get(hotkey) == *coldkey)
+    }
+
     /// Returns False is the number of uids exceeds the allowed number of uids for this network.
     pub fn check_len_uids_within_allowed(netuid: u16, uids: &[u16]) -> bool {
         let subnetwork_n: u16 = Self::get_subnetwork_n(netuid);
@@ -478,6 +818,20 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Returns true once a commit made at `commit_block` can no longer be revealed, which is
+    /// two intervals after the start of the interval it was made in.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn is_commit_expired(netuid: u16, commit_block: u64) -> bool {
+        let interval: u64 = Self::get_commit_reveal_weights_interval(netuid);
+        if interval == 0 {
+            return false; // Reveals are always in range.
+        }
+
+        let commit_interval_start: u64 = commit_block.saturating_sub(commit_block % interval);
+        Self::get_current_block_as_u64()
+            >= commit_interval_start.saturating_add(interval.saturating_mul(2))
+    }
+
     #[allow(clippy::arithmetic_side_effects)]
     pub fn is_reveal_block_range(netuid: u16, commit_block: u64) -> bool {
         let interval: u64 = Self::get_commit_reveal_weights_interval(netuid);
@@ -1,3 +1,5 @@
 use super::*;
 pub mod swap_coldkey;
+#[cfg(any(feature = "try-runtime", feature = "testing"))]
+pub mod swap_coldkey_dry_run;
 pub mod swap_hotkey;
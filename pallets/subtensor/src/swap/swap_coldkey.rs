@@ -1,6 +1,10 @@
 use super::*;
+use frame_support::storage::with_storage_layer;
+use frame_support::traits::schedule::v3::{Named as ScheduleNamed, TaskName};
 use frame_support::weights::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::Get;
+use sp_runtime::{PerU16, Saturating};
 
 impl<T: Config> Pallet<T> {
     /// Swaps the coldkey associated with a set of hotkeys from an old coldkey to a new coldkey.
@@ -20,6 +24,8 @@ impl<T: Config> Pallet<T> {
     /// - The caller is not a valid signed origin.
     /// - The old coldkey (caller) is in arbitration.
     /// - The new coldkey is already associated with other hotkeys or is a hotkey itself.
+    /// - The old coldkey has a pending operation the swap can not move (`PendingOperationsExist`).
+    /// - The swap no longer fits in the swap weight limit (`TooManyEntriesToSwap`).
     /// - There's not enough balance to pay for the swap.
     ///
     /// # Events
@@ -49,10 +55,23 @@ impl<T: Config> Pallet<T> {
         );
         weight = weight.saturating_add(T::DbWeight::get().reads(1));
 
+        // 4.1 Ensure the old coldkey has no pending operation the swap can not move
+        ensure!(
+            Self::get_pending_operation_blocking_swap(old_coldkey, new_coldkey).is_none(),
+            Error::<T>::PendingOperationsExist
+        );
+        weight.saturating_accrue(T::DbWeight::get().reads(3));
+
+        // 4.2 Ensure the swap still fits in the block. The coldkey may have staked to more hotkeys
+        // since the swap was scheduled.
+        Self::ensure_swap_coldkey_weight_within_limit(old_coldkey)?;
+        weight.saturating_accrue(T::DbWeight::get().reads(3));
+
         // 5. Swap the identity if the old coldkey has one
         if let Some(identity) = Identities::<T>::take(old_coldkey) {
             Identities::<T>::insert(new_coldkey, identity);
         }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
 
         // 6. Calculate the swap cost and ensure sufficient balance
         let swap_cost = Self::get_key_swap_cost();
@@ -63,21 +82,37 @@ impl<T: Config> Pallet<T> {
 
         // 7. Remove and burn the swap cost from the old coldkey's account
         let actual_burn_amount = Self::remove_balance_from_coldkey_account(old_coldkey, swap_cost)?;
-        Self::burn_tokens(actual_burn_amount);
+        Self::burn_tokens_of_coldkey(old_coldkey, actual_burn_amount);
+        Self::increase_key_swaps_this_interval();
+
+        // 8. Update the weight for the balance operations and the key swap counter
+        // (account, currency issuance, total issuance and key swaps)
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 4));
 
-        // 8. Update the weight for the balance operations
-        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        // 9. Remove the coldkey swap scheduled record and its task, which this swap carries out, so
+        // the old coldkey can be pruned by the swap. Dissolutions it scheduled have all executed.
+        ColdkeySwapScheduled::<T>::remove(old_coldkey);
+        ColdkeySwapDestination::<T>::remove(old_coldkey);
+        DissolveNetworkScheduledUntil::<T>::remove(old_coldkey);
+        let _ = T::Scheduler::cancel_named(Self::coldkey_swap_task_name(old_coldkey));
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 5));
 
-        // 9. Perform the actual coldkey swap
-        let _ = Self::perform_swap_coldkey(old_coldkey, new_coldkey, &mut weight);
+        // 10. Perform the actual coldkey swap. Its failure reverts the whole swap, including the
+        // burned cost and the cleared schedule.
+        let anomalies: u32 = Self::perform_swap_coldkey(old_coldkey, new_coldkey, &mut weight)?;
+        if anomalies > 0 {
+            log::warn!(
+                "ColdkeySwapAnomalies( old_coldkey:{:?} new_coldkey:{:?} count:{:?} )",
+                old_coldkey,
+                new_coldkey,
+                anomalies
+            );
+        }
 
-        // 10. Update the last transaction block for the new coldkey
+        // 11. Update the last transaction block for the new coldkey
         Self::set_last_tx_block(new_coldkey, Self::get_current_block_as_u64());
         weight.saturating_accrue(T::DbWeight::get().writes(1));
 
-        // 11. Remove the coldkey swap scheduled record
-        ColdkeySwapScheduled::<T>::remove(old_coldkey);
-
         // 12. Emit the ColdkeySwapped event
         Self::deposit_event(Event::ColdkeySwapped {
             old_coldkey: old_coldkey.clone(),
@@ -88,6 +123,246 @@ impl<T: Config> Pallet<T> {
         Ok(Some(weight).into())
     }
 
+    /// Executes the coldkey swap scheduled for `old_coldkey`, as root or as the old coldkey once
+    /// the swap is due.
+    ///
+    /// The swap is checked again at execution. If it fails, its changes are reverted, the schedule
+    /// is cleared and `ColdkeySwapFailed` is emitted, so the call itself succeeds and the failure
+    /// is visible on chain.
+    ///
+    /// # Errors
+    /// * `SwapNotScheduled` - No swap is scheduled for `old_coldkey`.
+    /// * `ColdkeySwapDestinationMismatch` - `new_coldkey` is not the scheduled destination.
+    /// * `ColdkeySwapNotDue` - The old coldkey dispatched the swap before its execution block.
+    pub fn do_execute_scheduled_coldkey_swap(
+        origin: T::RuntimeOrigin,
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        let maybe_signer = frame_system::ensure_signed_or_root(origin)?;
+        ensure!(
+            ColdkeySwapScheduled::<T>::contains_key(old_coldkey),
+            Error::<T>::SwapNotScheduled
+        );
+
+        let task_name = Self::coldkey_swap_task_name(old_coldkey);
+        if let Some(signer) = maybe_signer {
+            ensure!(signer == *old_coldkey, DispatchError::BadOrigin);
+            ensure!(
+                ColdkeySwapDestination::<T>::get(old_coldkey).as_ref() == Some(new_coldkey),
+                Error::<T>::ColdkeySwapDestinationMismatch
+            );
+            // A task still in the agenda must have reached its block. A task that is no longer
+            // scheduled was dropped by the scheduler and is overdue.
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                T::Scheduler::next_dispatch_time(task_name)
+                    .map_or(true, |when| when <= current_block),
+                Error::<T>::ColdkeySwapNotDue
+            );
+        }
+
+        // The scheduler removes the name of the task it dispatches, so this only cancels a task
+        // still waiting in the agenda.
+        let _ = T::Scheduler::cancel_named(task_name);
+
+        match with_storage_layer(|| {
+            Self::ensure_staking_not_paused()?;
+            Self::do_swap_coldkey(old_coldkey, new_coldkey)
+        }) {
+            Ok(post_info) => Ok(post_info),
+            Err(error) => {
+                if error.error == Error::<T>::PendingOperationsExist.into() {
+                    if let Some(kind) =
+                        Self::get_pending_operation_blocking_swap(old_coldkey, new_coldkey)
+                    {
+                        Self::deposit_event(Event::ColdkeySwapBlocked {
+                            old_coldkey: old_coldkey.clone(),
+                            new_coldkey: new_coldkey.clone(),
+                            kind,
+                        });
+                    }
+                }
+                ColdkeySwapScheduled::<T>::remove(old_coldkey);
+                ColdkeySwapDestination::<T>::remove(old_coldkey);
+                Self::deposit_event(Event::ColdkeySwapFailed {
+                    old_coldkey: old_coldkey.clone(),
+                    new_coldkey: new_coldkey.clone(),
+                    error: error.error,
+                });
+                Ok(().into())
+            }
+        }
+    }
+
+    /// Cancels the coldkey swap scheduled for `old_coldkey`.
+    ///
+    /// Swaps scheduled before they were named have no destination recorded. Their anonymous task
+    /// cannot be cancelled in the scheduler and still dispatches `swap_coldkey` at its execution
+    /// block, so they are refused rather than reported as cancelled.
+    ///
+    /// # Errors
+    /// * `SwapNotScheduled` - No swap is scheduled for `old_coldkey`.
+    /// * `SwapNotCancellable` - The swap was scheduled before swaps were named.
+    pub fn do_cancel_scheduled_coldkey_swap(old_coldkey: &T::AccountId) -> DispatchResult {
+        ensure!(
+            ColdkeySwapScheduled::<T>::contains_key(old_coldkey),
+            Error::<T>::SwapNotScheduled
+        );
+        let new_coldkey =
+            ColdkeySwapDestination::<T>::get(old_coldkey).ok_or(Error::<T>::SwapNotCancellable)?;
+
+        let _ = T::Scheduler::cancel_named(Self::coldkey_swap_task_name(old_coldkey));
+        ColdkeySwapScheduled::<T>::remove(old_coldkey);
+        ColdkeySwapDestination::<T>::remove(old_coldkey);
+        Self::deposit_event(Event::ColdkeySwapCancelled {
+            old_coldkey: old_coldkey.clone(),
+            new_coldkey,
+        });
+        Ok(())
+    }
+
+    /// Returns the first pending operation of `old_coldkey` that swapping it to `new_coldkey` can
+    /// not move, if any.
+    ///
+    /// Weight commits and pending children are keyed by the hotkeys, which the swap moves with
+    /// their deposits. A coldkey swap scheduled to `new_coldkey` is carried out by the swap. A
+    /// swap scheduled elsewhere, or a scheduled network dissolution, which names the old coldkey
+    /// as the subnet owner and can not be cancelled, must execute or be cancelled first. A vesting
+    /// schedule keeps its lock on the old coldkey until it has fully vested and been claimed.
+    pub fn get_pending_operation_blocking_swap(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) -> Option<PendingOperationKind> {
+        if ColdkeySwapDestination::<T>::get(old_coldkey)
+            .is_some_and(|destination| destination != *new_coldkey)
+        {
+            return Some(PendingOperationKind::ScheduledColdkeySwap);
+        }
+
+        // The dissolution is still pending in its execution block, as the swap may run before it.
+        let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
+        if DissolveNetworkScheduledUntil::<T>::get(old_coldkey)
+            .is_some_and(|until| until >= current_block)
+        {
+            return Some(PendingOperationKind::ScheduledNetworkDissolution);
+        }
+
+        if VestingSchedules::<T>::contains_key(old_coldkey) {
+            return Some(PendingOperationKind::VestingSchedule);
+        }
+        None
+    }
+
+    /// Returns the name of the scheduler task swapping `old_coldkey`.
+    pub fn coldkey_swap_task_name(old_coldkey: &T::AccountId) -> TaskName {
+        (b"subtensor/coldkey_swap", old_coldkey).using_encoded(sp_io::hashing::blake2_256)
+    }
+
+    /// Estimates the weight of swapping `old_coldkey` from the entries the swap iterates.
+    ///
+    /// The swap loops over the hotkeys owned by the coldkey, the hotkeys it stakes to and every
+    /// subnet, so its weight grows with these counts. The estimate is declared by `swap_coldkey`
+    /// and checked against `ColdkeySwapMaxWeightShare` when the swap is scheduled and again when
+    /// it executes.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_coldkey` - The coldkey to be swapped.
+    pub fn get_swap_coldkey_weight(old_coldkey: &T::AccountId) -> Weight {
+        let owned_hotkeys: u64 = OwnedHotkeys::<T>::decode_len(old_coldkey).unwrap_or(0) as u64;
+        let staking_hotkeys: u64 = StakingHotkeys::<T>::decode_len(old_coldkey).unwrap_or(0) as u64;
+        let subnets: u64 = Self::get_all_subnet_netuids().len() as u64;
+
+        Weight::from_parts(127_713_000, 11645)
+            .saturating_add(T::DbWeight::get().reads_writes(24, 23))
+            // Pruning the old coldkey once it is empty.
+            .saturating_add(T::DbWeight::get().reads_writes(7, 9))
+            // Redirects of the old and new coldkeys, and whether the old one enabled it.
+            .saturating_add(T::DbWeight::get().reads_writes(2, 3))
+            // Stake operations of the coldkey this interval.
+            .saturating_add(T::DbWeight::get().reads_writes(1, 2))
+            // Fee discount tiers of the old and new coldkeys.
+            .saturating_add(T::DbWeight::get().reads_writes(4, 2))
+            // Fallback hotkeys of the old and new coldkeys.
+            .saturating_add(T::DbWeight::get().reads_writes(2, 2))
+            // Auth keys of the old and new coldkeys.
+            .saturating_add(T::DbWeight::get().reads_writes(2, 2))
+            // Lifetime burns of the old and new coldkeys.
+            .saturating_add(T::DbWeight::get().reads_writes(2, 2))
+            // Attestations of the old and new coldkeys and their rate limits.
+            .saturating_add(T::DbWeight::get().reads_writes(6, 6))
+            // Pending operations of the old coldkey, and its scheduled swap task.
+            .saturating_add(T::DbWeight::get().reads_writes(5, 3))
+            // Interval stakes and ownership of each owned hotkey.
+            .saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(2, 4)
+                    .saturating_mul(owned_hotkeys),
+            )
+            // Deduplicating the staking hotkeys of the old coldkey.
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1))
+            // Stake, nominator count and emission payout mode of each staking hotkey.
+            .saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(5, 6)
+                    .saturating_mul(staking_hotkeys),
+            )
+            // Owner of each subnet, checked against the added subnets and read again when pruning
+            // the old coldkey.
+            .saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(4, 1)
+                    .saturating_mul(subnets),
+            )
+    }
+
+    /// Ensures the estimated weight of swapping `old_coldkey` fits in `ColdkeySwapMaxWeightShare`
+    /// of the maximum block weight.
+    ///
+    /// # Errors
+    ///
+    /// * `TooManyEntriesToSwap` - The coldkey must unstake from or swap away some of its hotkeys
+    ///   before it can be swapped.
+    pub fn ensure_swap_coldkey_weight_within_limit(old_coldkey: &T::AccountId) -> DispatchResult {
+        let max_block: Weight = <T as frame_system::Config>::BlockWeights::get().max_block;
+        let share: PerU16 = PerU16::from_parts(ColdkeySwapMaxWeightShare::<T>::get());
+        let limit: Weight = Weight::from_parts(
+            share.mul_floor(max_block.ref_time()),
+            share.mul_floor(max_block.proof_size()),
+        );
+
+        ensure!(
+            !Self::get_swap_coldkey_weight(old_coldkey).any_gt(limit),
+            Error::<T>::TooManyEntriesToSwap
+        );
+        Ok(())
+    }
+
+    /// Ensures the hotkeys owned by `old_coldkey` and `new_coldkey` fit in `OwnedHotkeys` once
+    /// merged by the swap.
+    ///
+    /// # Errors
+    ///
+    /// * `TooManyHotkeysForColdkey` - The new coldkey would own more than `MAX_OWNED_HOTKEYS`
+    ///   hotkeys.
+    pub fn ensure_swap_coldkey_owned_hotkeys_within_bound(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) -> DispatchResult {
+        let mut merged_owned_hotkeys: Vec<T::AccountId> =
+            OwnedHotkeys::<T>::get(new_coldkey).into_inner();
+        Self::merge_hotkeys_sorted(
+            &mut merged_owned_hotkeys,
+            OwnedHotkeys::<T>::get(old_coldkey).into_inner(),
+        );
+        ensure!(
+            merged_owned_hotkeys.len() <= MAX_OWNED_HOTKEYS as usize,
+            Error::<T>::TooManyHotkeysForColdkey
+        );
+        Ok(())
+    }
+
     /// Performs the actual coldkey swap operation, transferring all associated data and balances from the old coldkey to the new coldkey.
     ///
     /// # Arguments
@@ -98,15 +373,19 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Returns
     ///
-    /// Returns a `DispatchResult` indicating success or failure of the operation.
+    /// Returns the number of inconsistent entries the swap met, or the error it failed with.
     ///
     /// # Steps
     ///
     /// 1. Swap TotalHotkeyColdkeyStakesThisInterval:
     ///    - For each hotkey owned by the old coldkey, transfer its stake and block data to the new coldkey.
+    ///    - Move the auth key to the new coldkey, unless it has its own, and drop the approvals of
+    ///      the old coldkey.
     ///
     /// 2. Swap subnet ownership:
     ///    - For each subnet, if the old coldkey is the owner, transfer ownership to the new coldkey.
+    ///    - The subnet lock is not reserved on the owner's account but tracked per subnet in
+    ///      `SubnetLocked`, and refunded to the subnet owner on dissolution, so it follows the owner.
     ///
     /// 3. Swap Stakes:
     ///    - For each hotkey staking for the old coldkey, transfer its stake to the new coldkey.
@@ -116,57 +395,136 @@ impl<T: Config> Pallet<T> {
     ///
     /// 5. Swap StakingHotkeys:
     ///    - Transfer the list of staking hotkeys from the old coldkey to the new coldkey.
+    ///    - The merged list is sorted by account id.
     ///
     /// 6. Swap hotkey owners:
     ///    - For each hotkey owned by the old coldkey, transfer ownership to the new coldkey.
-    ///    - Update the list of owned hotkeys for both old and new coldkeys.
+    ///    - Update the list of owned hotkeys for both old and new coldkeys, sorting the merged list
+    ///      by account id.
     ///
-    /// 7. Transfer remaining balance:
+    /// 7. Swap take cooldowns, the attestation and the lifetime burn:
+    ///    - Transfer the delegate take and childkey take cooldowns to the new coldkey.
+    ///    - Move the attestation to the new coldkey, unless it has its own, whose deposit is then
+    ///      refunded before the balance is transferred.
+    ///    - Add the lifetime burn of the old coldkey onto the new coldkey.
+    ///
+    /// 8. Transfer remaining balance:
     ///    - Transfer any remaining balance from the old coldkey to the new coldkey.
     ///
+    /// 9. Record the redirect:
+    ///    - Balance later sent to the old coldkey can be swept to the new coldkey until the redirect expires.
+    ///    - A redirect of the new coldkey is dropped, as the new coldkey is in use again.
+    ///
+    /// 10. Report the inconsistent entries:
+    ///    - Emit `ColdkeySwapAnomalies` if the swap met any.
+    ///
     /// Throughout the process, the function updates the transaction weight to reflect the operations performed.
     ///
     /// # Notes
     ///
     /// This function is a critical part of the coldkey swap process and should be called only after all necessary checks and validations have been performed.
+    ///
+    /// Inconsistent entries are swapped or skipped as before, and reported with a
+    /// `ColdkeySwapAnomalies` event: an owner entry of a subnet that is not added, an owned hotkey
+    /// with another owner, and a staking hotkey without stake of the old coldkey.
+    ///
+    /// Weight commits of the owned hotkeys stay keyed to the hotkeys. Their deposits are refunded
+    /// to the owner of the hotkey on reveal, which is then the new coldkey.
     pub fn perform_swap_coldkey(
         old_coldkey: &T::AccountId,
         new_coldkey: &T::AccountId,
         weight: &mut Weight,
-    ) -> DispatchResult {
+    ) -> Result<u32, DispatchError> {
+        let mut anomalies: Vec<ColdkeySwapAnomalyKind> = Vec::new();
+
+        // 0. Merge the owned hotkeys up front, sorted by account id, so a coldkey that would end
+        // up owning more hotkeys than the storage bound fails before any state is moved.
+        // OwnedHotkeys: MAP ( coldkey ) --> Vec<hotkeys> | Hotkeys owned by the coldkey.
+        let old_owned_hotkeys = OwnedHotkeys::<T>::get(old_coldkey);
+        let mut new_owned_hotkeys = OwnedHotkeys::<T>::get(new_coldkey);
+        let mut merged_owned_hotkeys: Vec<T::AccountId> = new_owned_hotkeys.to_vec();
+        Self::merge_hotkeys_sorted(&mut merged_owned_hotkeys, old_owned_hotkeys.iter().cloned());
+        new_owned_hotkeys = merged_owned_hotkeys
+            .try_into()
+            .map_err(|_| Error::<T>::TooManyHotkeysForColdkey)?;
+
         // 1. Swap TotalHotkeyColdkeyStakesThisInterval
         // TotalHotkeyColdkeyStakesThisInterval: MAP ( hotkey, coldkey ) --> ( stake, block ) | Stake of the hotkey for the coldkey.
-        for hotkey in OwnedHotkeys::<T>::get(old_coldkey).iter() {
+        for hotkey in old_owned_hotkeys.iter() {
             let (stake, block) =
                 TotalHotkeyColdkeyStakesThisInterval::<T>::get(&hotkey, old_coldkey);
             TotalHotkeyColdkeyStakesThisInterval::<T>::remove(&hotkey, old_coldkey);
             TotalHotkeyColdkeyStakesThisInterval::<T>::insert(&hotkey, new_coldkey, (stake, block));
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
         }
+        // ColdkeyStakeOpsThisInterval: MAP ( coldkey ) --> ( ops, block ) | Stake operations of the coldkey.
+        let stake_ops = ColdkeyStakeOpsThisInterval::<T>::take(old_coldkey);
+        ColdkeyStakeOpsThisInterval::<T>::insert(new_coldkey, stake_ops);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        // AutoRedelegate: MAP ( coldkey ) --> hotkey | The fallback hotkey, unless the new coldkey has one.
+        if let Some(fallback_hotkey) = AutoRedelegate::<T>::take(old_coldkey) {
+            if !AutoRedelegate::<T>::contains_key(new_coldkey) {
+                AutoRedelegate::<T>::insert(new_coldkey, fallback_hotkey);
+            }
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        // ColdkeyAuth: MAP ( coldkey ) --> ( auth_key, threshold ) | The auth key, unless the new coldkey has one.
+        // The approvals were given for calls of the old coldkey and are dropped.
+        if let Some(auth) = ColdkeyAuth::<T>::take(old_coldkey) {
+            if !ColdkeyAuth::<T>::contains_key(new_coldkey) {
+                ColdkeyAuth::<T>::insert(new_coldkey, auth);
+            }
+        }
+        let approvals: u64 = ColdkeyAuthApprovals::<T>::clear_prefix(old_coldkey, u32::MAX, None)
+            .unique
+            .into();
+        weight
+            .saturating_accrue(T::DbWeight::get().reads_writes(2, 2_u64.saturating_add(approvals)));
 
         // 2. Swap subnet owner.
         // SubnetOwner: MAP ( netuid ) --> (coldkey) | Owner of the subnet.
         for netuid in Self::get_all_subnet_netuids() {
             let subnet_owner = SubnetOwner::<T>::get(netuid);
             if subnet_owner == *old_coldkey {
-                SubnetOwner::<T>::insert(netuid, new_coldkey.clone());
+                Self::set_subnet_owner(netuid, new_coldkey);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
             }
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
+        // Owner entries of subnets that are not added are left to the old coldkey.
+        for (netuid, subnet_owner) in SubnetOwner::<T>::iter() {
+            if subnet_owner == *old_coldkey && !NetworksAdded::<T>::get(netuid) {
+                anomalies.push(ColdkeySwapAnomalyKind::SubnetOwnerMismatch);
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
+        }
 
-        // 3. Swap Stake.
+        // 3. Swap Stake, repairing duplicate staking hotkeys first.
         // Stake: MAP ( hotkey, coldkey ) --> u64 | Stake of the hotkey for the coldkey.
+        weight.saturating_accrue(Self::sync_staking_hotkeys(old_coldkey));
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
         for hotkey in StakingHotkeys::<T>::get(old_coldkey) {
             // Get the stake on the old (hot,coldkey) account.
             let old_stake: u64 = Stake::<T>::get(&hotkey, old_coldkey);
+            if old_stake == 0 {
+                anomalies.push(ColdkeySwapAnomalyKind::StakingHotkeyWithoutStake);
+            }
             // Get the stake on the new (hot,coldkey) account.
             let new_stake: u64 = Stake::<T>::get(&hotkey, new_coldkey);
             // Add the stake to new account.
             Stake::<T>::insert(&hotkey, new_coldkey, new_stake.saturating_add(old_stake));
             // Remove the value from the old account.
             Stake::<T>::remove(&hotkey, old_coldkey);
-            // Add the weight for the read and write.
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+            // Count the new account as a nominator of the hotkey in place of the old one.
+            Self::update_nominator_count(&hotkey, old_stake, 0);
+            Self::update_nominator_count(&hotkey, new_stake, new_stake.saturating_add(old_stake));
+            // Move the payout mode of the old account, which compounds when it has none.
+            if EmissionPayout::<T>::contains_key(&hotkey, old_coldkey) {
+                let mode: EmissionPayoutMode = EmissionPayout::<T>::take(&hotkey, old_coldkey);
+                EmissionPayout::<T>::insert(&hotkey, new_coldkey, mode);
+            }
+            // Add the weight for the stakes, the two nominator count updates and the payout mode.
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(5, 6));
         }
 
         // 4. Swap total coldkey stake.
@@ -187,46 +545,171 @@ impl<T: Config> Pallet<T> {
         // StakingHotkeys: MAP ( coldkey ) --> Vec<hotkeys> | Hotkeys staking for the coldkey.
         let old_staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(old_coldkey);
         let mut new_staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(new_coldkey);
-        for hotkey in old_staking_hotkeys {
-            // If the hotkey is not already in the new coldkey, add it.
-            if !new_staking_hotkeys.contains(&hotkey) {
-                new_staking_hotkeys.push(hotkey);
-            }
-        }
+        // Merge them sorted by account id, adding only those missing from the new coldkey.
+        Self::merge_hotkeys_sorted(&mut new_staking_hotkeys, old_staking_hotkeys);
         StakingHotkeys::<T>::remove(old_coldkey);
         StakingHotkeys::<T>::insert(new_coldkey, new_staking_hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
         // 6. Swap hotkey owners.
         // Owner: MAP ( hotkey ) --> coldkey | Owner of the hotkey.
-        // OwnedHotkeys: MAP ( coldkey ) --> Vec<hotkeys> | Hotkeys owned by the coldkey.
-        let old_owned_hotkeys: Vec<T::AccountId> = OwnedHotkeys::<T>::get(old_coldkey);
-        let mut new_owned_hotkeys: Vec<T::AccountId> = OwnedHotkeys::<T>::get(new_coldkey);
         for owned_hotkey in old_owned_hotkeys.iter() {
+            if Owner::<T>::get(owned_hotkey) != *old_coldkey {
+                anomalies.push(ColdkeySwapAnomalyKind::OwnedHotkeyOwnerMismatch);
+            }
             // Remove the hotkey from the old coldkey.
             Owner::<T>::remove(owned_hotkey);
             // Add the hotkey to the new coldkey.
             Owner::<T>::insert(owned_hotkey, new_coldkey.clone());
-            // Addd the owned hotkey to the new set of owned hotkeys.
-            if !new_owned_hotkeys.contains(owned_hotkey) {
-                new_owned_hotkeys.push(owned_hotkey.clone());
-            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
         }
         OwnedHotkeys::<T>::remove(old_coldkey);
         OwnedHotkeys::<T>::insert(new_coldkey, new_owned_hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
-        // 7. Transfer remaining balance.
+        // 7. Swap take cooldowns, the attestation and the lifetime burn.
+        // LastRateLimitedBlock: DMAP ( DelegateTake, coldkey ) --> u64 | Last block the coldkey changed a delegate take.
+        // LastRateLimitedBlock: DMAP ( ChildKeyTake, coldkey ) --> u64 | Last block the coldkey changed a childkey take.
+        // Keep the most recent block so the swap cannot be used to skip a cooldown.
+        for operation in [
+            RateLimitedOperation::DelegateTake,
+            RateLimitedOperation::ChildKeyTake,
+        ] {
+            let last_block: u64 = LastRateLimitedBlock::<T>::take(operation, old_coldkey);
+            if last_block > 0 {
+                LastRateLimitedBlock::<T>::mutate(operation, new_coldkey, |block| {
+                    *block = (*block).max(last_block)
+                });
+            }
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 4));
+        // ColdkeyAttestations: MAP ( coldkey ) --> attestation | The latest payload attested by the coldkey.
+        weight.saturating_accrue(Self::swap_attestation(old_coldkey, new_coldkey));
+        // ColdkeyLifetimeBurn: MAP ( coldkey ) --> u64 | The TAO the coldkey ever burned, added onto the new coldkey.
+        let lifetime_burn: u64 = ColdkeyLifetimeBurn::<T>::take(old_coldkey);
+        if lifetime_burn > 0 {
+            ColdkeyLifetimeBurn::<T>::mutate(new_coldkey, |total| {
+                *total = total.saturating_add(lifetime_burn)
+            });
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
+        // 8. Transfer remaining balance.
         // Balance: MAP ( coldkey ) --> u64 | Balance of the coldkey.
         // Transfer any remaining balance from old_coldkey to new_coldkey
         let remaining_balance = Self::get_coldkey_balance(old_coldkey);
         if remaining_balance > 0 {
             Self::kill_coldkey_account(old_coldkey, remaining_balance)?;
-            Self::add_balance_to_coldkey_account(new_coldkey, remaining_balance);
+            Self::add_balance_to_coldkey_account_or_burn(new_coldkey, remaining_balance);
         }
-        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        // Both accounts and the currency issuance, once for the withdrawal and once for the deposit.
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 4));
 
-        // Return ok.
+        // Move both coldkeys to the fee discount tier of their new stake, now the swap cannot fail.
+        weight.saturating_accrue(Self::update_fee_discount_tier(old_coldkey, 0));
+        weight.saturating_accrue(Self::update_fee_discount_tier(
+            new_coldkey,
+            new_coldkey_stake.saturating_add(old_coldkey_stake),
+        ));
+
+        // 9. Prune the old coldkey if nothing remains under it.
+        weight.saturating_accrue(Self::prune_coldkey_if_empty(old_coldkey));
+
+        // 10. Record the redirect of the old coldkey, if it enabled one.
+        // ColdkeySwapRedirect: MAP ( old_coldkey ) --> ( new_coldkey, expiry ) | Where balance sent to the old coldkey is swept to.
+        if ColdkeySwapRedirectEnabled::<T>::take(old_coldkey) {
+            let expiry: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(ColdkeySwapRedirectDuration::<T>::get());
+            ColdkeySwapRedirect::<T>::insert(old_coldkey, (new_coldkey.clone(), expiry));
+        }
+        ColdkeySwapRedirect::<T>::remove(new_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 3));
+
+        // 11. Report the inconsistent entries met.
+        let count: u32 = anomalies.len() as u32;
+        if count > 0 {
+            let mut kinds: Vec<ColdkeySwapAnomalyKind> = Vec::new();
+            for kind in anomalies {
+                if !kinds.contains(&kind) {
+                    kinds.push(kind);
+                }
+            }
+            Self::deposit_event(Event::ColdkeySwapAnomalies {
+                old_coldkey: old_coldkey.clone(),
+                new_coldkey: new_coldkey.clone(),
+                count,
+                kinds,
+            });
+        }
+
+        // Return the number of inconsistent entries.
+        Ok(count)
+    }
+
+    /// Enables or disables the redirect recorded when `coldkey` is swapped.
+    ///
+    /// A swap of a coldkey that enabled it lets the old or new coldkey sweep the balance later
+    /// sent to the old coldkey to the new one. The setting is used up by the swap.
+    pub fn do_set_coldkey_swap_redirect(coldkey: &T::AccountId, enabled: bool) {
+        if enabled {
+            ColdkeySwapRedirectEnabled::<T>::insert(coldkey, true);
+        } else {
+            ColdkeySwapRedirectEnabled::<T>::remove(coldkey);
+        }
+        Self::deposit_event(Event::ColdkeySwapRedirectSet {
+            coldkey: coldkey.clone(),
+            enabled,
+        });
+    }
+
+    /// Moves the free balance of a swapped-away coldkey to the coldkey it was swapped to, while the
+    /// redirect recorded by the swap is active. Only the old or the new coldkey can sweep.
+    ///
+    /// # Errors
+    /// * `NoColdkeySwapRedirect` - The coldkey was not swapped away, or its redirect was removed.
+    /// * `BadOrigin` - The signer is neither the old nor the new coldkey.
+    /// * `ColdkeySwapRedirectExpired` - The redirect of the coldkey has expired.
+    /// * `NothingToSweep` - The coldkey holds no balance.
+    pub fn do_sweep_swapped_coldkey(
+        signer: &T::AccountId,
+        old_coldkey: &T::AccountId,
+    ) -> DispatchResult {
+        let (new_coldkey, expiry) =
+            ColdkeySwapRedirect::<T>::get(old_coldkey).ok_or(Error::<T>::NoColdkeySwapRedirect)?;
+        ensure!(
+            signer == old_coldkey || *signer == new_coldkey,
+            DispatchError::BadOrigin
+        );
+        ensure!(
+            <frame_system::Pallet<T>>::block_number() < expiry,
+            Error::<T>::ColdkeySwapRedirectExpired
+        );
+
+        let balance = Self::get_coldkey_balance(old_coldkey);
+        ensure!(balance > 0, Error::<T>::NothingToSweep);
+        let amount: u64 = Self::kill_coldkey_account(old_coldkey, balance)?;
+        Self::add_balance_to_coldkey_account_or_burn(&new_coldkey, amount);
+
+        Self::deposit_event(Event::SwappedColdkeySwept {
+            old_coldkey: old_coldkey.clone(),
+            new_coldkey,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Removes the redirect of a swapped-away coldkey, so it can be reused.
+    ///
+    /// # Errors
+    /// * `NoColdkeySwapRedirect` - The coldkey has no redirect.
+    pub fn do_clear_coldkey_swap_redirect(old_coldkey: &T::AccountId) -> DispatchResult {
+        ensure!(
+            ColdkeySwapRedirect::<T>::take(old_coldkey).is_some(),
+            Error::<T>::NoColdkeySwapRedirect
+        );
+        Self::deposit_event(Event::ColdkeySwapRedirectCleared {
+            old_coldkey: old_coldkey.clone(),
+        });
         Ok(())
     }
 }
@@ -0,0 +1,98 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use frame_support::weights::Weight;
+use sp_core::hexdisplay::AsBytesRef;
+use sp_std::collections::btree_map::BTreeMap;
+
+/// Length of a storage prefix: the hashed pallet name followed by the hashed storage item name.
+const STORAGE_PREFIX_LEN: usize = 32;
+
+#[freeze_struct("ac8c51c618495339")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ColdkeySwapDryRun {
+    pub succeeded: bool,                // Whether the swap would complete
+    pub weight: Weight,                 // Weight the swap accounted for
+    pub touched_prefixes: Vec<Vec<u8>>, // Storage prefixes whose entries the swap changed
+}
+
+impl<T: Config> Pallet<T> {
+    /// Runs `perform_swap_coldkey` from `old_coldkey` to `new_coldkey` against the current state
+    /// and reverts every change it made.
+    ///
+    /// Meant for try-runtime against a forked state snapshot: the whole state is read twice to find
+    /// the storage prefixes the swap touched, which is far too heavy to run on chain.
+    pub fn execute_coldkey_swap_dry_run(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) -> ColdkeySwapDryRun {
+        let dry_run = with_transaction(|| {
+            let before = Self::storage_prefix_digests();
+            let mut weight = Weight::zero();
+            let succeeded =
+                Self::perform_swap_coldkey(old_coldkey, new_coldkey, &mut weight).is_ok();
+            let after = Self::storage_prefix_digests();
+
+            // A prefix is touched if its digest changed, or if it was emptied or first written.
+            let mut touched_prefixes: Vec<Vec<u8>> = before
+                .iter()
+                .filter(|(prefix, digest)| after.get(*prefix) != Some(*digest))
+                .map(|(prefix, _)| prefix.clone())
+                .collect();
+            touched_prefixes.extend(
+                after
+                    .keys()
+                    .filter(|prefix| !before.contains_key(*prefix))
+                    .cloned(),
+            );
+            touched_prefixes.sort();
+
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(ColdkeySwapDryRun {
+                succeeded,
+                weight,
+                touched_prefixes,
+            }))
+        });
+
+        // The closure never fails, so the fallback is never used.
+        dry_run.unwrap_or(ColdkeySwapDryRun {
+            succeeded: false,
+            weight: Weight::zero(),
+            touched_prefixes: Vec::new(),
+        })
+    }
+
+    pub fn execute_coldkey_swap_dry_run_for_accounts(
+        old_coldkey_account_vec: Vec<u8>,
+        new_coldkey_account_vec: Vec<u8>,
+    ) -> Option<ColdkeySwapDryRun> {
+        let old_coldkey = T::AccountId::decode(&mut old_coldkey_account_vec.as_bytes_ref()).ok()?;
+        let new_coldkey = T::AccountId::decode(&mut new_coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::execute_coldkey_swap_dry_run(
+            &old_coldkey,
+            &new_coldkey,
+        ))
+    }
+
+    /// Digests the entries under every storage prefix, so two reads of the state tell which
+    /// prefixes changed without holding the state in memory.
+    fn storage_prefix_digests() -> BTreeMap<Vec<u8>, [u8; 32]> {
+        let mut digests: BTreeMap<Vec<u8>, [u8; 32]> = BTreeMap::new();
+        let mut key: Vec<u8> = Vec::new();
+        while let Some(next) = sp_io::storage::next_key(&key) {
+            // Well-known keys such as `:code` are shorter than a prefix and digested on their own.
+            let prefix: Vec<u8> = next
+                .get(..STORAGE_PREFIX_LEN)
+                .unwrap_or(next.as_slice())
+                .to_vec();
+            let value = sp_io::storage::get(&next).unwrap_or_default();
+            let digest = digests.entry(prefix).or_insert([0; 32]);
+            *digest = sp_io::hashing::blake2_256(
+                &[digest.as_slice(), next.as_slice(), value.as_ref()].concat(),
+            );
+            key = next;
+        }
+        digests
+    }
+}
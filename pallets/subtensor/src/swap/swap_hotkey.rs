@@ -29,6 +29,7 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResultWithPostInfo {
         // 1. Ensure the origin is signed and get the coldkey
         let coldkey = ensure_signed(origin)?;
+        Self::ensure_staking_not_paused()?;
 
         // 2. Initialize the weight for this operation
         let mut weight = T::DbWeight::get().reads(2);
@@ -79,7 +80,9 @@ impl<T: Config> Pallet<T> {
         let actual_burn_amount = Self::remove_balance_from_coldkey_account(&coldkey, swap_cost)?;
 
         // 13. Burn the tokens
-        Self::burn_tokens(actual_burn_amount);
+        Self::burn_tokens_of_coldkey(&coldkey, actual_burn_amount);
+        Self::increase_key_swaps_this_interval();
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
         // 14. Perform the hotkey swap
         let _ = Self::perform_hotkey_swap(old_hotkey, new_hotkey, &coldkey, &mut weight);
@@ -99,6 +102,180 @@ impl<T: Config> Pallet<T> {
         Ok(Some(weight).into())
     }
 
+    /// Swaps the hotkey of a coldkey account on a single subnet.
+    ///
+    /// The registration of the old hotkey on `netuid` moves to the new hotkey, while its registrations
+    /// on other subnets, its stake and its delegate status stay with the old hotkey. The cost is the key
+    /// swap cost divided by the number of subnets the old hotkey is registered on.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the transaction, and also the coldkey account.
+    /// * `old_hotkey` - The old hotkey to be swapped.
+    /// * `new_hotkey` - The new hotkey to replace the old one.
+    /// * `netuid` - The subnet to swap the hotkey on.
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResultWithPostInfo` - The result of the dispatch.
+    ///
+    /// # Errors
+    ///
+    /// * `NewHotKeyIsSameWithOld` - If the new hotkey is the same as the old hotkey.
+    /// * `SubNetworkDoesNotExist` - If the subnet does not exist.
+    /// * `RegistrationNotPermittedOnRootSubnet` - If the subnet is the root network.
+    /// * `NonAssociatedColdKey` - If the coldkey does not own the old hotkey, or the new hotkey is owned by another coldkey.
+    /// * `HotKeyNotRegisteredInSubNet` - If the old hotkey is not registered in the subnet.
+    /// * `HotKeyAlreadyRegisteredInSubNet` - If the new hotkey is already registered in the subnet.
+    /// * `HotKeySetTxRateLimitExceeded` - If the transaction rate limit is exceeded.
+    /// * `NotEnoughBalanceToPaySwapHotKey` - If there is not enough balance to pay for the swap.
+    pub fn do_swap_hotkey_on_subnet(
+        origin: T::RuntimeOrigin,
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> DispatchResultWithPostInfo {
+        // 1. Ensure the origin is signed and get the coldkey
+        let coldkey = ensure_signed(origin)?;
+        Self::ensure_staking_not_paused()?;
+
+        // 2. Initialize the weight for this operation
+        let mut weight = T::DbWeight::get().reads(2);
+
+        // 3. Ensure the new hotkey is different from the old one
+        ensure!(old_hotkey != new_hotkey, Error::<T>::NewHotKeyIsSameWithOld);
+
+        // 4. Ensure the subnet exists and is not the root network, whose members are tied to the senate
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        // 5. Ensure the coldkey owns the old hotkey and the new hotkey is free or its own
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, old_hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            !Self::hotkey_account_exists(new_hotkey)
+                || Self::coldkey_owns_hotkey(&coldkey, new_hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        Self::ensure_coldkey_can_own_hotkey(&coldkey, new_hotkey)?;
+
+        // 6. Ensure the old hotkey is registered on the subnet and the new one is not
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, old_hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        ensure!(
+            !Self::is_hotkey_registered_on_network(netuid, new_hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        weight.saturating_accrue(T::DbWeight::get().reads(5));
+
+        // 7. Ensure the transaction rate limit is not exceeded
+        let block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(&coldkey), block),
+            Error::<T>::HotKeySetTxRateLimitExceeded
+        );
+
+        // 8. Get the cost for swapping the key on one of the subnets of the old hotkey
+        let registered_networks: u64 =
+            Self::get_registered_networks_for_hotkey(old_hotkey).len() as u64;
+        weight.saturating_accrue(
+            T::DbWeight::get().reads((TotalNetworks::<T>::get().saturating_add(1u16)) as u64),
+        );
+        let swap_cost: u64 = Self::get_key_swap_cost()
+            .checked_div(registered_networks)
+            .unwrap_or_default();
+        log::debug!("Swap cost: {:?}", swap_cost);
+
+        // 9. Ensure the coldkey has enough balance to pay for the swap
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, swap_cost),
+            Error::<T>::NotEnoughBalanceToPaySwapHotKey
+        );
+
+        // 10. Remove the swap cost from the coldkey's account and burn it
+        let actual_burn_amount = Self::remove_balance_from_coldkey_account(&coldkey, swap_cost)?;
+        Self::burn_tokens_of_coldkey(&coldkey, actual_burn_amount);
+        Self::increase_key_swaps_this_interval();
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+        // 11. Perform the hotkey swap on the subnet
+        Self::perform_hotkey_swap_on_subnet(old_hotkey, new_hotkey, &coldkey, netuid, &mut weight)?;
+
+        // 12. Update the last transaction block for the coldkey
+        Self::set_last_tx_block(&coldkey, block);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        // 13. Emit an event for the hotkey swap
+        Self::deposit_event(Event::HotkeySwappedOnSubnet {
+            coldkey,
+            old_hotkey: old_hotkey.clone(),
+            new_hotkey: new_hotkey.clone(),
+            netuid,
+        });
+
+        // 14. Return the weight of the operation
+        Ok(Some(weight).into())
+    }
+
+    /// Performs the hotkey swap operation on a single subnet.
+    ///
+    /// 1. Creates the new hotkey account for the coldkey if it does not exist yet.
+    /// 2. Moves the registration of the old hotkey on the subnet.
+    /// 3. Swaps the child and parent keys, the childkey take and the childkey cooldowns on the subnet.
+    /// 4. Moves the emission records of the subnet, adding the lifetime emission onto the new hotkey.
+    ///
+    /// # Arguments
+    /// * `old_hotkey` - The AccountId of the current hotkey to be replaced.
+    /// * `new_hotkey` - The AccountId of the new hotkey to replace the old one.
+    /// * `coldkey` - The AccountId of the coldkey that owns both hotkeys.
+    /// * `netuid` - The subnet to swap the hotkey on.
+    /// * `weight` - A mutable reference to the Weight, updated as operations are performed.
+    pub fn perform_hotkey_swap_on_subnet(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: u16,
+        weight: &mut Weight,
+    ) -> DispatchResult {
+        // 1. Create the new hotkey account.
+        Self::create_account_if_non_existent(coldkey, new_hotkey)?;
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 4));
+
+        // 2. Swap the subnet registration.
+        Self::swap_subnet_info_for_hotkey(old_hotkey, new_hotkey, netuid, weight);
+
+        // 3. Swap ChildKeys, ParentKeys, childkey take and childkey cooldowns.
+        Self::swap_children_for_hotkey_on_subnet(old_hotkey, new_hotkey, netuid, weight);
+
+        // 4. Swap emission records.
+        // EmissionLastTempo( hotkey, netuid ) --> u64 -- the emission of the hotkey in its last epoch.
+        // EmissionLifetime( hotkey, netuid ) --> u64 -- the total emission ever credited to the hotkey.
+        if EmissionLastTempo::<T>::contains_key(old_hotkey, netuid) {
+            let last_emission: u64 = EmissionLastTempo::<T>::take(old_hotkey, netuid);
+            EmissionLastTempo::<T>::insert(new_hotkey, netuid, last_emission);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+        if EmissionLifetime::<T>::contains_key(old_hotkey, netuid) {
+            let lifetime_emission: u64 = EmissionLifetime::<T>::take(old_hotkey, netuid);
+            EmissionLifetime::<T>::mutate(new_hotkey, netuid, |lifetime| {
+                *lifetime = lifetime.saturating_add(lifetime_emission)
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+
+        Ok(())
+    }
+
     /// Performs the hotkey swap operation, transferring all associated data and state from the old hotkey to the new hotkey.
     ///
     /// This function executes a series of steps to ensure a complete transfer of all relevant information:
@@ -118,6 +295,8 @@ impl<T: Config> Pallet<T> {
     ///    - Transfers weight commits.
     ///    - Updates loaded emission data.
     /// 10. Transfers all stake information, including updating staking hotkeys for each coldkey.
+    /// 11. Swaps the child and parent keys, the childkey take and the childkey cooldowns.
+    /// 12. Moves the per-subnet emission records, adding the lifetime emission onto the new hotkey.
     ///
     /// Throughout the process, the function accumulates the computational weight of operations performed.
     ///
@@ -147,14 +326,16 @@ impl<T: Config> Pallet<T> {
 
         // 2. Swap OwnedHotkeys.
         // OwnedHotkeys( coldkey ) -> Vec<hotkey> -- the hotkeys that the coldkey owns.
-        let mut hotkeys = OwnedHotkeys::<T>::get(coldkey);
-        // Add the new key if needed.
-        if !hotkeys.contains(new_hotkey) {
-            hotkeys.push(new_hotkey.clone());
-        }
+        let mut owned_hotkeys = OwnedHotkeys::<T>::get(coldkey);
+        let mut hotkeys: Vec<T::AccountId> = owned_hotkeys.to_vec();
         // Remove the old key.
         hotkeys.retain(|hk| *hk != *old_hotkey);
-        OwnedHotkeys::<T>::insert(coldkey, hotkeys);
+        // Add the new key if needed, keeping the hotkeys sorted by account id.
+        Self::merge_hotkeys_sorted(&mut hotkeys, [new_hotkey.clone()]);
+        owned_hotkeys = hotkeys
+            .try_into()
+            .map_err(|_| Error::<T>::TooManyHotkeysForColdkey)?;
+        OwnedHotkeys::<T>::insert(coldkey, owned_hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
         // 3. Swap total hotkey stake.
@@ -179,16 +360,17 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
         }
 
-        // 5. Swap LastTxBlock
-        // LastTxBlock( hotkey ) --> u64 -- the last transaction block for the hotkey.
-        LastTxBlock::<T>::remove(old_hotkey);
-        LastTxBlock::<T>::insert(new_hotkey, Self::get_current_block_as_u64());
+        // 5. Swap the last transaction block
+        // LastRateLimitedBlock( Transaction, hotkey ) --> u64 -- the last transaction block for the hotkey.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        LastRateLimitedBlock::<T>::remove(RateLimitedOperation::Transaction, old_hotkey);
+        Self::set_last_tx_block(new_hotkey, current_block);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 2));
 
-        // 6. Swap LastTxBlockDelegateTake
-        // LastTxBlockDelegateTake( hotkey ) --> u64 -- the last transaction block for the hotkey delegate take.
-        LastTxBlockDelegateTake::<T>::remove(old_hotkey);
-        LastTxBlockDelegateTake::<T>::insert(new_hotkey, Self::get_current_block_as_u64());
+        // 6. Swap the last delegate take block
+        // LastRateLimitedBlock( DelegateTake, hotkey ) --> u64 -- the last transaction block for the hotkey delegate take.
+        LastRateLimitedBlock::<T>::remove(RateLimitedOperation::DelegateTake, old_hotkey);
+        Self::set_last_tx_block_delegate_take(new_hotkey, current_block);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
 
         // 7. Swap Senate members.
@@ -206,76 +388,19 @@ impl<T: Config> Pallet<T> {
             Delegates::<T>::insert(new_hotkey, old_delegate_take);
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
         }
+        // TakeHistory( hotkey ) -> Vec<( block, old_take, new_take )> -- the delegate take changes.
+        if TakeHistory::<T>::contains_key(old_hotkey) {
+            TakeHistory::<T>::insert(new_hotkey, TakeHistory::<T>::take(old_hotkey));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+        // PendingDelegateTake( hotkey ) -> take -- applied at once, the pending emission stays on the old hotkey.
+        if let Some(pending_take) = PendingDelegateTake::<T>::take(old_hotkey) {
+            Self::set_delegate_take(new_hotkey, pending_take);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 3));
+        }
         // 9. Swap all subnet specific info.
-        let all_netuids: Vec<u16> = Self::get_all_subnet_netuids();
-        for netuid in all_netuids {
-            // 9.1 Remove the previous hotkey and insert the new hotkey from membership.
-            // IsNetworkMember( hotkey, netuid ) -> bool -- is the hotkey a subnet member.
-            let is_network_member: bool = IsNetworkMember::<T>::get(old_hotkey, netuid);
-            IsNetworkMember::<T>::remove(old_hotkey, netuid);
-            IsNetworkMember::<T>::insert(new_hotkey, netuid, is_network_member);
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-
-            // 9.2 Swap Uids + Keys.
-            // Keys( netuid, hotkey ) -> uid -- the uid the hotkey has in the network if it is a member.
-            // Uids( netuid, hotkey ) -> uid -- the uids that the hotkey has.
-            if is_network_member {
-                // 9.2.1 Swap the UIDS
-                if let Ok(old_uid) = Uids::<T>::try_get(netuid, old_hotkey) {
-                    Uids::<T>::remove(netuid, old_hotkey);
-                    Uids::<T>::insert(netuid, new_hotkey, old_uid);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-
-                    // 9.2.2 Swap the keys.
-                    Keys::<T>::insert(netuid, old_uid, new_hotkey.clone());
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
-                }
-            }
-
-            // 9.3 Swap Prometheus.
-            // Prometheus( netuid, hotkey ) -> prometheus -- the prometheus data that a hotkey has in the network.
-            if is_network_member {
-                if let Ok(old_prometheus_info) = Prometheus::<T>::try_get(netuid, old_hotkey) {
-                    Prometheus::<T>::remove(netuid, old_hotkey);
-                    Prometheus::<T>::insert(netuid, new_hotkey, old_prometheus_info);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 9.4. Swap axons.
-            // Axons( netuid, hotkey ) -> axon -- the axon that the hotkey has.
-            if is_network_member {
-                if let Ok(old_axon_info) = Axons::<T>::try_get(netuid, old_hotkey) {
-                    Axons::<T>::remove(netuid, old_hotkey);
-                    Axons::<T>::insert(netuid, new_hotkey, old_axon_info);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 9.5 Swap WeightCommits
-            // WeightCommits( hotkey ) --> Vec<u64> -- the weight commits for the hotkey.
-            if is_network_member {
-                if let Ok(old_weight_commits) = WeightCommits::<T>::try_get(netuid, old_hotkey) {
-                    WeightCommits::<T>::remove(netuid, old_hotkey);
-                    WeightCommits::<T>::insert(netuid, new_hotkey, old_weight_commits);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 9.6. Swap the subnet loaded emission.
-            // LoadedEmission( netuid ) --> Vec<(hotkey, u64)> -- the loaded emission for the subnet.
-            if is_network_member {
-                if let Some(mut old_loaded_emission) = LoadedEmission::<T>::get(netuid) {
-                    for emission in old_loaded_emission.iter_mut() {
-                        if emission.0 == *old_hotkey {
-                            emission.0 = new_hotkey.clone();
-                        }
-                    }
-                    LoadedEmission::<T>::remove(netuid);
-                    LoadedEmission::<T>::insert(netuid, old_loaded_emission);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
+        for netuid in Self::get_all_subnet_netuids() {
+            Self::swap_subnet_info_for_hotkey(old_hotkey, new_hotkey, netuid, weight);
         }
 
         // 10. Swap Stake.
@@ -283,6 +408,8 @@ impl<T: Config> Pallet<T> {
         let stakes: Vec<(T::AccountId, u64)> = Stake::<T>::iter_prefix(old_hotkey).collect();
         // Clear the entire old prefix here.
         let _ = Stake::<T>::clear_prefix(old_hotkey, stakes.len() as u32, None);
+        NominatorCount::<T>::remove(old_hotkey);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
         // Iterate over all the staking rows and insert them into the new hotkey.
         for (coldkey, old_stake_amount) in stakes {
             weight.saturating_accrue(T::DbWeight::get().reads(1));
@@ -297,54 +424,277 @@ impl<T: Config> Pallet<T> {
                 &coldkey,
                 new_stake_value.saturating_add(old_stake_amount),
             );
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            Self::update_nominator_count(
+                new_hotkey,
+                new_stake_value,
+                new_stake_value.saturating_add(old_stake_amount),
+            );
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
             // Swap StakingHotkeys.
             // StakingHotkeys( coldkey ) --> Vec<hotkey> -- the hotkeys that the coldkey stakes.
             let mut staking_hotkeys = StakingHotkeys::<T>::get(&coldkey);
-            staking_hotkeys.retain(|hk| *hk != *old_hotkey && *hk != *new_hotkey);
-            staking_hotkeys.push(new_hotkey.clone());
+            staking_hotkeys.retain(|hk| *hk != *old_hotkey);
+            Self::merge_hotkeys_sorted(&mut staking_hotkeys, [new_hotkey.clone()]);
             StakingHotkeys::<T>::insert(coldkey.clone(), staking_hotkeys);
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
 
-        // 11. Swap ChildKeys.
-        // ChildKeys( parent, netuid ) --> Vec<(proportion,child)> -- the child keys of the parent.
+        // 10.1. Swap EmissionPayout.
+        // EmissionPayout( hotkey, coldkey ) -> mode -- where the emission of the coldkey's stake is paid.
+        for (coldkey, mode) in EmissionPayout::<T>::drain_prefix(old_hotkey) {
+            EmissionPayout::<T>::insert(new_hotkey, &coldkey, mode);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        // 11. Swap ChildKeys, ParentKeys, childkey take and childkey cooldowns.
+        Self::swap_children_for_hotkey(old_hotkey, new_hotkey, weight);
+
+        // 12. Swap emission records.
+        // EmissionLastTempo( hotkey, netuid ) --> u64 -- the emission of the hotkey in its last epoch.
+        // EmissionLifetime( hotkey, netuid ) --> u64 -- the total emission ever credited to the hotkey.
+        // Drain every netuid, including dissolved ones, and add the lifetime onto the new hotkey.
+        for (netuid, last_emission) in EmissionLastTempo::<T>::drain_prefix(old_hotkey) {
+            EmissionLastTempo::<T>::insert(new_hotkey, netuid, last_emission);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+        for (netuid, lifetime_emission) in EmissionLifetime::<T>::drain_prefix(old_hotkey) {
+            EmissionLifetime::<T>::mutate(new_hotkey, netuid, |lifetime| {
+                *lifetime = lifetime.saturating_add(lifetime_emission)
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+
+        // Return successful after swapping all the relevant terms.
+        Ok(())
+    }
+
+    /// Swaps the registration of a hotkey on one subnet.
+    ///
+    /// Moves the network membership, UID and key, Prometheus and axon info, weight commits,
+    /// loaded emission and the data other pallets keep per neuron, like the commitment, of the
+    /// old hotkey on `netuid` to the new hotkey. Weights and bonds are indexed by UID and
+    /// therefore follow the UID.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The hotkey being swapped out.
+    /// * `new_hotkey` - The hotkey taking over the registration.
+    /// * `netuid` - The subnet to swap the registration on.
+    /// * `weight` - The weight accumulator of the swap.
+    pub fn swap_subnet_info_for_hotkey(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+        weight: &mut Weight,
+    ) {
+        // 1. Remove the previous hotkey and insert the new hotkey from membership.
+        // IsNetworkMember( hotkey, netuid ) -> bool -- is the hotkey a subnet member.
+        let is_network_member: bool = IsNetworkMember::<T>::get(old_hotkey, netuid);
+        IsNetworkMember::<T>::remove(old_hotkey, netuid);
+        IsNetworkMember::<T>::insert(new_hotkey, netuid, is_network_member);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+        // 2. Swap Uids + Keys.
+        // Keys( netuid, hotkey ) -> uid -- the uid the hotkey has in the network if it is a member.
+        // Uids( netuid, hotkey ) -> uid -- the uids that the hotkey has.
+        if is_network_member {
+            // 2.1 Swap the UIDS
+            if let Ok(old_uid) = Uids::<T>::try_get(netuid, old_hotkey) {
+                Uids::<T>::remove(netuid, old_hotkey);
+                Uids::<T>::insert(netuid, new_hotkey, old_uid);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+                // 2.2 Swap the keys.
+                Keys::<T>::insert(netuid, old_uid, new_hotkey.clone());
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
+            }
+        }
+
+        // 3. Swap Prometheus.
+        // Prometheus( netuid, hotkey ) -> prometheus -- the prometheus data that a hotkey has in the network.
+        if is_network_member {
+            if let Ok(old_prometheus_info) = Prometheus::<T>::try_get(netuid, old_hotkey) {
+                Prometheus::<T>::remove(netuid, old_hotkey);
+                Prometheus::<T>::insert(netuid, new_hotkey, old_prometheus_info);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 4. Swap axons.
+        // Axons( netuid, hotkey ) -> axon -- the axon that the hotkey has.
+        if is_network_member {
+            if let Ok(old_axon_info) = Axons::<T>::try_get(netuid, old_hotkey) {
+                Axons::<T>::remove(netuid, old_hotkey);
+                Axons::<T>::insert(netuid, new_hotkey, old_axon_info);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 5. Swap WeightCommits
+        // WeightCommits( hotkey ) --> Vec<u64> -- the weight commits for the hotkey.
+        // WeightCommitDeposits( hotkey ) --> u64 -- the deposit held for the commit.
+        if is_network_member {
+            if let Ok(old_weight_commits) = WeightCommits::<T>::try_get(netuid, old_hotkey) {
+                WeightCommits::<T>::remove(netuid, old_hotkey);
+                WeightCommits::<T>::insert(netuid, new_hotkey, old_weight_commits);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+            if let Ok(deposit) = WeightCommitDeposits::<T>::try_get(netuid, old_hotkey) {
+                WeightCommitDeposits::<T>::remove(netuid, old_hotkey);
+                WeightCommitDeposits::<T>::insert(netuid, new_hotkey, deposit);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 6. Swap the subnet loaded emission.
+        // LoadedEmission( netuid ) --> Vec<(hotkey, u64)> -- the loaded emission for the subnet.
+        if is_network_member {
+            if let Some(mut old_loaded_emission) = LoadedEmission::<T>::get(netuid) {
+                for emission in old_loaded_emission.iter_mut() {
+                    if emission.0 == *old_hotkey {
+                        emission.0 = new_hotkey.clone();
+                    }
+                }
+                LoadedEmission::<T>::remove(netuid);
+                LoadedEmission::<T>::insert(netuid, old_loaded_emission);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 7. Swap the data other pallets keep per neuron, like the commitment and its deposit.
+        if is_network_member {
+            T::OnNeuronRemoved::on_hotkey_swapped(netuid, old_hotkey, new_hotkey);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(5, 7));
+        }
+    }
+
+    /// Re-keys the childkey relationships of a hotkey on every subnet.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The hotkey being swapped out.
+    /// * `new_hotkey` - The hotkey taking over the relationships.
+    /// * `weight` - The weight accumulator of the swap.
+    pub fn swap_children_for_hotkey(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        weight: &mut Weight,
+    ) {
         for netuid in Self::get_all_subnet_netuids() {
-            // Get the children of the old hotkey for this subnet
-            let my_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(old_hotkey, netuid);
-            // Remove the old hotkey's child entries
-            ChildKeys::<T>::remove(old_hotkey, netuid);
-            // Insert the same child entries for the new hotkey
+            Self::swap_children_for_hotkey_on_subnet(old_hotkey, new_hotkey, netuid, weight);
+        }
+    }
+
+    /// Re-keys the childkey relationships of a hotkey on one subnet.
+    ///
+    /// Relationships where the hotkey is the parent or the child are moved to the new hotkey,
+    /// and the counterpart entries of its children and parents are updated to point at it.
+    /// The permissions to set weights for a child, given to the hotkey or by it to its parents,
+    /// move along.
+    /// The childkey take and the set children and set childkey take cooldowns move as well,
+    /// so the swap cannot be used to skip a cooldown.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The hotkey being swapped out.
+    /// * `new_hotkey` - The hotkey taking over the relationships.
+    /// * `netuid` - The subnet to re-key the relationships on.
+    /// * `weight` - The weight accumulator of the swap.
+    pub fn swap_children_for_hotkey_on_subnet(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+        weight: &mut Weight,
+    ) {
+        // ChildKeys( parent, netuid ) --> Vec<(proportion,child)> -- the child keys of the parent.
+        // Move the children of the old hotkey and point each child's parent entry at the new hotkey.
+        let my_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::take(old_hotkey, netuid);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        if !my_children.is_empty() {
+            for (_, child_key_i) in my_children.iter() {
+                ParentKeys::<T>::mutate(child_key_i, netuid, |child_parents| {
+                    for parent in child_parents.iter_mut() {
+                        if parent.1 == *old_hotkey {
+                            parent.1 = new_hotkey.clone();
+                        }
+                    }
+                });
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            }
             ChildKeys::<T>::insert(new_hotkey, netuid, my_children);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
         }
 
-        // 12. Swap ParentKeys.
         // ParentKeys( child, netuid ) --> Vec<(proportion,parent)> -- the parent keys of the child.
-        for netuid in Self::get_all_subnet_netuids() {
-            // Get the parents of the old hotkey for this subnet
-            let parents: Vec<(u64, T::AccountId)> = ParentKeys::<T>::get(old_hotkey, netuid);
-            // Remove the old hotkey's parent entries
-            ParentKeys::<T>::remove(old_hotkey, netuid);
-            // Insert the same parent entries for the new hotkey
-            ParentKeys::<T>::insert(new_hotkey, netuid, parents.clone());
-            for (_, parent_key_i) in parents {
-                // For each parent, update their children list
-                let mut parent_children: Vec<(u64, T::AccountId)> =
-                    ChildKeys::<T>::get(parent_key_i.clone(), netuid);
-                for child in parent_children.iter_mut() {
-                    // If the child is the old hotkey, replace it with the new hotkey
-                    if child.1 == *old_hotkey {
-                        child.1 = new_hotkey.clone();
+        // Move the parents of the old hotkey and point each parent's child entry at the new hotkey.
+        let my_parents: Vec<(u64, T::AccountId)> = ParentKeys::<T>::take(old_hotkey, netuid);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        if !my_parents.is_empty() {
+            for (_, parent_key_i) in my_parents.iter() {
+                ChildKeys::<T>::mutate(parent_key_i, netuid, |parent_children| {
+                    for child in parent_children.iter_mut() {
+                        if child.1 == *old_hotkey {
+                            child.1 = new_hotkey.clone();
+                        }
                     }
-                }
-                // Update the parent's children list
-                ChildKeys::<T>::insert(parent_key_i, netuid, parent_children);
+                });
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
             }
+            ParentKeys::<T>::insert(new_hotkey, netuid, my_parents.clone());
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
         }
 
-        // Return successful after swapping all the relevant terms.
-        Ok(())
+        // ChildWeightsPermission( parent, child, netuid ) --> bool -- whether the parent may set the weights of the child.
+        // Move the permissions given to the old hotkey by its children, and by it to its parents.
+        let permitted_children: Vec<T::AccountId> =
+            ChildWeightsPermission::<T>::iter_key_prefix((old_hotkey,))
+                .filter(|(_, permission_netuid)| *permission_netuid == netuid)
+                .map(|(child, _)| child)
+                .collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(permitted_children.len() as u64));
+        for child in permitted_children {
+            ChildWeightsPermission::<T>::remove((old_hotkey, &child, netuid));
+            ChildWeightsPermission::<T>::insert((new_hotkey, &child, netuid), true);
+            weight.saturating_accrue(T::DbWeight::get().writes(2));
+        }
+        for (_, parent) in my_parents.iter() {
+            if ChildWeightsPermission::<T>::take((parent, old_hotkey, netuid)) {
+                ChildWeightsPermission::<T>::insert((parent, new_hotkey, netuid), true);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        // PendingChildKeys( netuid, parent ) --> (Vec<(proportion,child)>, effective_block) -- the requested children of the hotkey.
+        // The due index keeps the old hotkey, which is skipped once its request is gone.
+        if let Some((children, effective_block)) = PendingChildKeys::<T>::take(netuid, old_hotkey) {
+            PendingChildKeys::<T>::insert(netuid, new_hotkey, (children, effective_block));
+            PendingChildKeysDue::<T>::append(effective_block, (netuid, new_hotkey.clone()));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+        }
+
+        // ChildkeyTake( hotkey, netuid ) --> u16 -- the childkey take of the hotkey.
+        if ChildkeyTake::<T>::contains_key(old_hotkey, netuid) {
+            let take: u16 = ChildkeyTake::<T>::take(old_hotkey, netuid);
+            ChildkeyTake::<T>::insert(new_hotkey, netuid, take);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        // TransactionKeyLastBlock( hotkey, netuid, tx_type ) --> u64 -- the childkey cooldowns of the hotkey.
+        for tx_type in [
+            TransactionType::SetChildren,
+            TransactionType::SetChildkeyTake,
+        ] {
+            let last_block: u64 = Self::get_last_transaction_block(old_hotkey, netuid, &tx_type);
+            if last_block > 0 {
+                let tx_as_u16: u16 = tx_type.into();
+                TransactionKeyLastBlock::<T>::remove((old_hotkey, netuid, tx_as_u16));
+                Self::set_last_transaction_block(new_hotkey, netuid, &tx_type, last_block);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+        }
     }
 
     pub fn swap_senate_member(
@@ -0,0 +1,4 @@
+use super::*;
+pub mod subnet_builder;
+
+pub use subnet_builder::SubnetBuilder;
@@ -0,0 +1,123 @@
+use super::*;
+use frame_support::traits::fungible::Inspect;
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+/// Builds a subnet with registered and staked neurons for tests.
+///
+/// The neurons are registered with `burned_register` and staked with `add_stake` as their
+/// coldkeys, so the resulting state satisfies the same invariants as on chain. Coldkeys are funded
+/// with the registration burn, the stake, and the existential deposit that the staking withdrawal
+/// keeps alive.
+///
+/// ```ignore
+/// SubnetBuilder::<Test>::new(netuid)
+///     .tempo(10)
+///     .with_neuron(hotkey, coldkey, 1_000)
+///     .build()?;
+/// ```
+pub struct SubnetBuilder<T: Config> {
+    netuid: u16,
+    tempo: u16,
+    burn: Option<u64>,
+    balances: Vec<(T::AccountId, u64)>,
+    neurons: Vec<(T::AccountId, T::AccountId, u64)>,
+}
+
+impl<T: Config> SubnetBuilder<T> {
+    /// Starts a subnet on `netuid` with a tempo of 0, so that its epoch runs every block.
+    pub fn new(netuid: u16) -> Self {
+        Self {
+            netuid,
+            tempo: 0,
+            burn: None,
+            balances: Vec::new(),
+            neurons: Vec::new(),
+        }
+    }
+
+    /// Sets the tempo of the subnet.
+    pub fn tempo(mut self, tempo: u16) -> Self {
+        self.tempo = tempo;
+        self
+    }
+
+    /// Sets the registration burn of the subnet, which each neuron pays on registration.
+    pub fn burn(mut self, burn: u64) -> Self {
+        self.burn = Some(burn);
+        self
+    }
+
+    /// Adds free balance to `account` before the neurons are registered.
+    pub fn with_balance(mut self, account: T::AccountId, amount: u64) -> Self {
+        self.balances.push((account, amount));
+        self
+    }
+
+    /// Registers `hotkey` owned by `coldkey`, which then stakes `stake` to it.
+    pub fn with_neuron(mut self, hotkey: T::AccountId, coldkey: T::AccountId, stake: u64) -> Self {
+        self.neurons.push((hotkey, coldkey, stake));
+        self
+    }
+
+    /// Creates the subnet if it does not exist yet, then funds, registers and stakes the neurons
+    /// in the order they were added.
+    pub fn build(self) -> DispatchResult {
+        let netuid = self.netuid;
+        if !Pallet::<T>::if_subnet_exist(netuid) {
+            Pallet::<T>::init_new_network(netuid, self.tempo);
+        } else {
+            Pallet::<T>::set_tempo(netuid, self.tempo);
+        }
+        Pallet::<T>::set_network_registration_allowed(netuid, true);
+        Pallet::<T>::set_network_pow_registration_allowed(netuid, true);
+        if let Some(burn) = self.burn {
+            Pallet::<T>::set_burn(netuid, burn);
+        }
+
+        // Make room for the neurons without pruning, and allow registering them all in this block.
+        let new_neurons: u16 = u16::try_from(self.neurons.len()).unwrap_or(u16::MAX);
+        let uids_needed: u16 = Pallet::<T>::get_subnetwork_n(netuid).saturating_add(new_neurons);
+        if Pallet::<T>::get_max_allowed_uids(netuid) < uids_needed {
+            Pallet::<T>::set_max_allowed_uids(netuid, uids_needed);
+        }
+        let registrations_needed: u16 =
+            Pallet::<T>::get_registrations_this_block(netuid).saturating_add(new_neurons);
+        if Pallet::<T>::get_max_registrations_per_block(netuid) < registrations_needed {
+            Pallet::<T>::set_max_registrations_per_block(netuid, registrations_needed);
+        }
+        let interval_registrations_needed: u16 =
+            Pallet::<T>::get_registrations_this_interval(netuid).saturating_add(new_neurons);
+        if Pallet::<T>::get_target_registrations_per_interval(netuid).saturating_mul(3)
+            < interval_registrations_needed
+        {
+            Pallet::<T>::set_target_registrations_per_interval(
+                netuid,
+                interval_registrations_needed,
+            );
+        }
+
+        for (account, amount) in self.balances {
+            Pallet::<T>::add_balance_to_coldkey_account(&account, amount);
+        }
+
+        for (hotkey, coldkey, stake) in self.neurons {
+            let needed: u64 = Pallet::<T>::get_burn_as_u64(netuid)
+                .saturating_add(stake)
+                .saturating_add(T::Currency::minimum_balance());
+            let missing: u64 = needed.saturating_sub(Pallet::<T>::get_coldkey_balance(&coldkey));
+            Pallet::<T>::add_balance_to_coldkey_account(&coldkey, missing);
+
+            Pallet::<T>::burned_register(
+                RawOrigin::Signed(coldkey.clone()).into(),
+                netuid,
+                hotkey.clone(),
+            )?;
+            if stake > 0 {
+                Pallet::<T>::add_stake(RawOrigin::Signed(coldkey).into(), hotkey, stake)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::utils::rate_limiting::TransactionType;
+use frame_support::weights::Weight;
+use sp_core::hexdisplay::AsBytesRef;
+use sp_core::Get;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic attest: records `payload_hash` as the latest
+    /// payload attested by the calling coldkey, for off-chain ownership proofs.
+    ///
+    /// The first attestation of a coldkey withdraws `AttestationDeposit` from it. A later one
+    /// replaces the payload and block and keeps the deposit already held.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the attesting coldkey.
+    ///
+    /// * 'payload_hash' ([u8; 32]):
+    ///     - The payload to attest, e.g. a hash binding the coldkey to an off-chain identity.
+    ///
+    /// # Event:
+    /// * ColdkeyAttested;
+    ///     - On successfully recording the payload.
+    ///
+    /// # Raises:
+    /// * 'TxRateLimitExceeded':
+    ///     - The coldkey attested less than `AttestationRateLimit` blocks ago.
+    ///
+    /// * 'NotEnoughBalanceForAttestationDeposit':
+    ///     - The coldkey has no attestation yet and can not pay the deposit.
+    ///
+    pub fn do_attest(origin: T::RuntimeOrigin, payload_hash: [u8; 32]) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        // The last block is kept under the global netuid, so a first attestation always passes.
+        let tx_type = TransactionType::Attest;
+        ensure!(
+            Self::passes_rate_limit_on_subnet(&tx_type, &coldkey, u16::MAX),
+            Error::<T>::TxRateLimitExceeded
+        );
+
+        let deposit: u64 = match ColdkeyAttestations::<T>::get(&coldkey) {
+            Some(attestation) => attestation.deposit,
+            None => {
+                let deposit: u64 = AttestationDeposit::<T>::get();
+                ensure!(
+                    Self::can_remove_balance_from_coldkey_account(&coldkey, deposit),
+                    Error::<T>::NotEnoughBalanceForAttestationDeposit
+                );
+                Self::remove_balance_from_coldkey_account(&coldkey, deposit)?
+            }
+        };
+
+        let block: u64 = Self::get_current_block_as_u64();
+        ColdkeyAttestations::<T>::insert(
+            &coldkey,
+            ColdkeyAttestationInfo {
+                payload_hash,
+                block,
+                deposit,
+            },
+        );
+        Self::set_last_transaction_block(&coldkey, u16::MAX, &tx_type, block);
+
+        log::debug!(
+            "ColdkeyAttested( coldkey:{:?} payload_hash:{:?} block:{:?} )",
+            coldkey,
+            payload_hash,
+            block
+        );
+        Self::deposit_event(Event::ColdkeyAttested {
+            coldkey,
+            payload_hash,
+            block,
+        });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic revoke_attestation: removes the attestation of
+    /// the calling coldkey and refunds its deposit.
+    ///
+    /// # Event:
+    /// * AttestationRevoked;
+    ///     - On successfully removing the attestation.
+    ///
+    /// # Raises:
+    /// * 'NoAttestation':
+    ///     - The coldkey has no attestation.
+    ///
+    pub fn do_revoke_attestation(origin: T::RuntimeOrigin) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        let attestation =
+            ColdkeyAttestations::<T>::take(&coldkey).ok_or(Error::<T>::NoAttestation)?;
+
+        Self::add_balance_to_coldkey_account_or_burn(&coldkey, attestation.deposit);
+        Self::deposit_event(Event::AttestationRevoked {
+            coldkey,
+            deposit: attestation.deposit,
+        });
+        Ok(())
+    }
+
+    /// Returns the latest payload attested by `coldkey`, if it has one.
+    pub fn get_attestation(coldkey: &T::AccountId) -> Option<ColdkeyAttestationInfo> {
+        ColdkeyAttestations::<T>::get(coldkey)
+    }
+
+    pub fn get_attestation_for_account(
+        coldkey_account_vec: Vec<u8>,
+    ) -> Option<ColdkeyAttestationInfo> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+        Self::get_attestation(&coldkey)
+    }
+
+    /// Moves the attestation of `old_coldkey` and its rate limit to `new_coldkey`. Returns the
+    /// weight consumed.
+    ///
+    /// When the new coldkey has an attestation of its own it is kept, and the deposit of the old
+    /// one is refunded to the old coldkey, whose balance the swap then moves.
+    pub fn swap_attestation(old_coldkey: &T::AccountId, new_coldkey: &T::AccountId) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads(1);
+        if let Some(attestation) = ColdkeyAttestations::<T>::take(old_coldkey) {
+            if ColdkeyAttestations::<T>::contains_key(new_coldkey) {
+                Self::add_balance_to_coldkey_account_or_burn(old_coldkey, attestation.deposit);
+            } else {
+                ColdkeyAttestations::<T>::insert(new_coldkey, attestation);
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 4));
+        }
+
+        // Keep the most recent block so the swap cannot be used to skip the rate limit.
+        let tx_type = TransactionType::Attest;
+        let last_block: u64 = Self::get_last_transaction_block(old_coldkey, u16::MAX, &tx_type);
+        if last_block > 0 {
+            let new_last_block: u64 =
+                Self::get_last_transaction_block(new_coldkey, u16::MAX, &tx_type);
+            Self::set_last_transaction_block(
+                new_coldkey,
+                u16::MAX,
+                &tx_type,
+                last_block.max(new_last_block),
+            );
+            TransactionKeyLastBlock::<T>::remove((old_coldkey, u16::MAX, u16::from(tx_type)));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        weight
+    }
+}
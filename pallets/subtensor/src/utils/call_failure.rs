@@ -0,0 +1,59 @@
+use super::*;
+use frame_support::traits::PalletInfoAccess;
+
+impl<T: Config> Pallet<T> {
+    /// Records the detail of a failed call of `who` as its `LastCallFailureInfo`, if the pallet
+    /// error the call failed with has one.
+    ///
+    /// A `DispatchError` cannot carry the limiting value behind an error, and anything the call
+    /// writes before failing is rolled back with it. This is therefore called from the signed
+    /// extension after the dispatch, and derives the detail from the state the call was checked on.
+    pub fn record_call_failure(who: &T::AccountId, call: &Call<T>, error: &DispatchError) {
+        let DispatchError::Module(module_error) = error else {
+            return;
+        };
+        if module_error.index as usize != <Self as PalletInfoAccess>::index() {
+            return;
+        }
+        let Ok(pallet_error) = Error::<T>::decode(&mut module_error.error.as_slice()) else {
+            return;
+        };
+
+        let detail: Option<(u64, u64)> = match call {
+            Call::add_stake {
+                hotkey,
+                amount_staked,
+            } => Self::add_stake_failure_detail(who, hotkey, *amount_staked, &pallet_error),
+            Call::remove_stake {
+                hotkey,
+                amount_unstaked,
+            } => Self::remove_stake_failure_detail(who, hotkey, *amount_unstaked, &pallet_error),
+            Call::set_weights {
+                netuid,
+                dests,
+                weights,
+                version_key,
+            } => Self::set_weights_failure_detail(
+                who,
+                *netuid,
+                dests,
+                weights,
+                *version_key,
+                &pallet_error,
+            ),
+            _ => None,
+        };
+
+        if let (Some((expected, provided)), Some(error)) = (detail, module_error.error.first()) {
+            LastCallFailureInfo::<T>::insert(
+                who,
+                CallFailureInfo {
+                    error: *error,
+                    expected,
+                    provided,
+                    block: Self::get_current_block_as_u64(),
+                },
+            );
+        }
+    }
+}
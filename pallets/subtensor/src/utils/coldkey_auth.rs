@@ -0,0 +1,196 @@
+use super::*;
+use frame_support::{dispatch::PostDispatchInfo, ensure, traits::IsSubType};
+use frame_system::ensure_signed;
+use sp_runtime::traits::{Dispatchable, Hash};
+
+impl<T: Config> Pallet<T> {
+    /// Sets, rotates or removes the auth key of a coldkey.
+    ///
+    /// Once an auth key is set, unstaking more than `threshold_amount` and scheduling a coldkey
+    /// swap require the auth key's approval. Setting the first auth key only needs the coldkey's
+    /// signature; changing or removing an existing one must itself be approved by the current
+    /// auth key, either through `authorized_call` or a pre-approved call hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, which must be signed by the coldkey.
+    /// * `auth_key` - The new auth key, or `None` to remove it.
+    /// * `threshold_amount` - Unstakes above this amount require the auth key's approval.
+    ///
+    /// # Errors
+    ///
+    /// * `AuthorizationRequired` - An auth key is already set and has not approved this call.
+    pub fn do_set_coldkey_auth(
+        origin: T::RuntimeOrigin,
+        auth_key: Option<T::AccountId>,
+        threshold_amount: u64,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        // Changing an existing auth key needs the current auth key's approval.
+        Self::ensure_coldkey_call_authorized(
+            &coldkey,
+            Call::<T>::set_coldkey_auth {
+                auth_key: auth_key.clone(),
+                threshold_amount,
+            },
+        )?;
+
+        match auth_key.clone() {
+            Some(auth_key) => ColdkeyAuth::<T>::insert(&coldkey, (auth_key, threshold_amount)),
+            None => ColdkeyAuth::<T>::remove(&coldkey),
+        }
+
+        log::debug!(
+            "ColdkeyAuthSet( coldkey:{:?}, auth_key:{:?}, threshold_amount:{:?} )",
+            coldkey,
+            auth_key,
+            threshold_amount
+        );
+        Self::deposit_event(Event::ColdkeyAuthSet {
+            coldkey,
+            auth_key,
+            threshold_amount,
+        });
+        Ok(())
+    }
+
+    /// Registers the approval of a call by the auth key of a coldkey.
+    ///
+    /// The coldkey can then submit the call itself within `ColdkeyAuthApprovalWindow` blocks.
+    /// Each approval is consumed by the first call it authorizes.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, which must be signed by the auth key.
+    /// * `coldkey` - The coldkey whose call is approved.
+    /// * `call_hash` - The hash of the approved runtime call.
+    ///
+    /// # Errors
+    ///
+    /// * `NotColdkeyAuthKey` - The signer is not the auth key of the coldkey.
+    pub fn do_approve_coldkey_call(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        call_hash: T::Hash,
+    ) -> dispatch::DispatchResult {
+        let auth_key = ensure_signed(origin)?;
+        Self::ensure_coldkey_auth_key(&coldkey, &auth_key)?;
+
+        ColdkeyAuthApprovals::<T>::insert(&coldkey, call_hash, Self::get_current_block_as_u64());
+
+        Self::deposit_event(Event::ColdkeyCallApproved { coldkey, call_hash });
+        Ok(())
+    }
+
+    /// Dispatches a call on behalf of a coldkey, approved by its auth key in the same extrinsic.
+    ///
+    /// Only the calls of `is_authorizable_call` can be dispatched this way. Calls moving stake or
+    /// the coldkey itself elsewhere need the coldkey's signature and an `approve_coldkey_call`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, which must be signed by the auth key.
+    /// * `coldkey` - The coldkey the call is dispatched as.
+    /// * `call` - The call to dispatch with the coldkey's signed origin.
+    ///
+    /// # Errors
+    ///
+    /// * `NotColdkeyAuthKey` - The signer is not the auth key of the coldkey.
+    /// * `CallNotAuthorizable` - The call can not be dispatched by the auth key.
+    pub fn do_authorized_call(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        call: Box<<T as Config>::RuntimeCall>,
+    ) -> DispatchResultWithPostInfo {
+        let auth_key = ensure_signed(origin)?;
+        Self::ensure_coldkey_auth_key(&coldkey, &auth_key)?;
+        ensure!(
+            Self::is_authorizable_call(&call),
+            Error::<T>::CallNotAuthorizable
+        );
+
+        // Approve the call for this dispatch only.
+        let call_hash = T::Hashing::hash_of(&call);
+        ColdkeyAuthApprovals::<T>::insert(&coldkey, call_hash, Self::get_current_block_as_u64());
+        Self::deposit_event(Event::ColdkeyCallApproved {
+            coldkey: coldkey.clone(),
+            call_hash,
+        });
+
+        let result = call.dispatch(frame_system::RawOrigin::Signed(coldkey.clone()).into());
+
+        // Drop the approval if the call did not need it.
+        ColdkeyAuthApprovals::<T>::remove(&coldkey, call_hash);
+
+        result
+            .map(|_| PostDispatchInfo::default())
+            .map_err(|e| e.error.into())
+    }
+
+    /// Returns true if the auth key of a coldkey can dispatch `call` as the coldkey through
+    /// `authorized_call`.
+    ///
+    /// These are the calls gated by the auth key that leave the funds with the coldkey: unstaking
+    /// to its own balance, and changing or removing the auth key itself.
+    pub fn is_authorizable_call(call: &<T as Config>::RuntimeCall) -> bool {
+        matches!(
+            call.is_sub_type(),
+            Some(Call::remove_stake { .. } | Call::set_coldkey_auth { .. })
+        )
+    }
+
+    /// Ensures `auth_key` is the auth key of `coldkey`.
+    pub fn ensure_coldkey_auth_key(
+        coldkey: &T::AccountId,
+        auth_key: &T::AccountId,
+    ) -> dispatch::DispatchResult {
+        ensure!(
+            ColdkeyAuth::<T>::get(coldkey).is_some_and(|(key, _)| key == *auth_key),
+            Error::<T>::NotColdkeyAuthKey
+        );
+        Ok(())
+    }
+
+    /// Returns true if unstaking `amount` requires the approval of the coldkey's auth key.
+    pub fn unstake_requires_coldkey_auth(coldkey: &T::AccountId, amount: u64) -> bool {
+        ColdkeyAuth::<T>::get(coldkey).is_some_and(|(_, threshold)| amount > threshold)
+    }
+
+    /// Ensures the auth key of the coldkey, if any, approved `call` within the approval window,
+    /// and consumes the approval.
+    ///
+    /// # Errors
+    ///
+    /// * `AuthorizationRequired` - The coldkey has an auth key and no live approval for `call`.
+    pub fn ensure_coldkey_call_authorized(
+        coldkey: &T::AccountId,
+        call: Call<T>,
+    ) -> dispatch::DispatchResult {
+        if !ColdkeyAuth::<T>::contains_key(coldkey) {
+            return Ok(());
+        }
+
+        let call_hash = T::Hashing::hash_of(&<T as Config>::RuntimeCall::from(call));
+        ensure!(
+            Self::coldkey_call_is_authorized(coldkey, call_hash),
+            Error::<T>::AuthorizationRequired
+        );
+
+        ColdkeyAuthApprovals::<T>::remove(coldkey, call_hash);
+        Ok(())
+    }
+
+    /// Returns true if the coldkey has no auth key, or its auth key approved the call hashing to
+    /// `call_hash` within the approval window. Does not consume the approval.
+    pub fn coldkey_call_is_authorized(coldkey: &T::AccountId, call_hash: T::Hash) -> bool {
+        if !ColdkeyAuth::<T>::contains_key(coldkey) {
+            return true;
+        }
+
+        ColdkeyAuthApprovals::<T>::get(coldkey, call_hash).is_some_and(|approved_at| {
+            Self::get_current_block_as_u64()
+                <= approved_at.saturating_add(ColdkeyAuthApprovalWindow::<T>::get())
+        })
+    }
+}
@@ -15,7 +15,13 @@ impl<T: Config> Pallet<T> {
     ) -> Result<(), DispatchError> {
         let coldkey = ensure_signed_or_root(o);
         match coldkey {
-            Ok(Some(who)) if SubnetOwner::<T>::get(netuid) == who => Ok(()),
+            // Owners of subnets under validator governance go through a proposal instead.
+            Ok(Some(who))
+                if SubnetOwner::<T>::get(netuid) == who
+                    && !ParamGovernanceEnabled::<T>::get(netuid) =>
+            {
+                Ok(())
+            }
             Ok(Some(_)) => Err(DispatchError::BadOrigin),
             Ok(None) => Ok(()),
             Err(x) => Err(x.into()),
@@ -136,6 +142,15 @@ impl<T: Config> Pallet<T> {
             }
         });
     }
+    pub fn set_custom_pruning_score_for_uid(netuid: u16, uid: u16, pruning_score: u16) {
+        if CustomPruningScores::<T>::contains_key(netuid) {
+            CustomPruningScores::<T>::mutate(netuid, |v| {
+                if let Some(s) = v.get_mut(uid as usize) {
+                    *s = pruning_score;
+                }
+            });
+        }
+    }
     pub fn set_validator_permit_for_uid(netuid: u16, uid: u16, validator_permit: bool) {
         let mut updated_validator_permits = Self::get_validator_permit(netuid);
         let Some(updated_validator_permit) = updated_validator_permits.get_mut(uid as usize) else {
@@ -148,6 +163,10 @@ impl<T: Config> Pallet<T> {
         WeightsMinStake::<T>::put(min_stake);
         Self::deposit_event(Event::WeightsMinStake(min_stake));
     }
+    pub fn set_subnet_stake_threshold(netuid: u16, min_stake: Option<u64>) {
+        SubnetStakeThreshold::<T>::set(netuid, min_stake);
+        Self::deposit_event(Event::SubnetStakeThresholdSet(netuid, min_stake));
+    }
     pub fn set_target_stakes_per_interval(target_stakes_per_interval: u64) {
         TargetStakesPerInterval::<T>::set(target_stakes_per_interval);
         Self::deposit_event(Event::TargetStakesPerIntervalSet(
@@ -166,6 +185,20 @@ impl<T: Config> Pallet<T> {
             (stakes_this_interval, last_staked_block_number),
         );
     }
+    pub fn set_max_stake_ops_per_interval_per_coldkey(max_stake_ops: u64) {
+        MaxStakeOpsPerIntervalPerColdkey::<T>::set(max_stake_ops);
+        Self::deposit_event(Event::MaxStakeOpsPerIntervalPerColdkeySet(max_stake_ops));
+    }
+    pub fn set_stake_ops_this_interval_for_coldkey(
+        coldkey: &T::AccountId,
+        stake_ops_this_interval: u64,
+        last_staked_block_number: u64,
+    ) {
+        ColdkeyStakeOpsThisInterval::<T>::insert(
+            coldkey,
+            (stake_ops_this_interval, last_staked_block_number),
+        );
+    }
     pub fn set_stake_interval(block: u64) {
         StakeInterval::<T>::set(block);
     }
@@ -189,6 +222,23 @@ impl<T: Config> Pallet<T> {
         let vec = Consensus::<T>::get(netuid);
         vec.get(uid as usize).copied().unwrap_or(0)
     }
+    /// Returns the score the pruning selection of `netuid` ranks `uid` by, read from the pruning
+    /// score source of the subnet. Custom scores that no longer cover every neuron of the subnet
+    /// are ignored in favour of the scores written by the epoch.
+    pub fn get_effective_pruning_score_for_uid(netuid: u16, uid: u16) -> u16 {
+        match PruningScoreSources::<T>::get(netuid) {
+            PruningScoreSource::EmissionBased => Self::get_pruning_score_for_uid(netuid, uid),
+            PruningScoreSource::IncentiveBased => Self::get_incentive_for_uid(netuid, uid),
+            PruningScoreSource::Custom => {
+                let custom_scores = CustomPruningScores::<T>::get(netuid);
+                if custom_scores.len() == Self::get_subnetwork_n(netuid) as usize {
+                    custom_scores.get(uid as usize).copied().unwrap_or(u16::MAX)
+                } else {
+                    Self::get_pruning_score_for_uid(netuid, uid)
+                }
+            }
+        }
+    }
     pub fn get_incentive_for_uid(netuid: u16, uid: u16) -> u16 {
         let vec = Incentive::<T>::get(netuid);
         vec.get(uid as usize).copied().unwrap_or(0)
@@ -216,6 +266,9 @@ impl<T: Config> Pallet<T> {
     pub fn get_weights_min_stake() -> u64 {
         WeightsMinStake::<T>::get()
     }
+    pub fn get_stake_threshold_for_subnet(netuid: u16) -> u64 {
+        SubnetStakeThreshold::<T>::get(netuid).unwrap_or_else(Self::get_weights_min_stake)
+    }
 
     // ============================
     // ==== Subnetwork Getters ====
@@ -226,6 +279,63 @@ impl<T: Config> Pallet<T> {
     pub fn get_emission_value(netuid: u16) -> u64 {
         EmissionValues::<T>::get(netuid)
     }
+    pub fn get_subnet_warning(netuid: u16) -> bool {
+        SubnetWarning::<T>::get(netuid)
+    }
+    pub fn set_subnet_warning(netuid: u16, warning: bool) {
+        SubnetWarning::<T>::insert(netuid, warning);
+        Self::deposit_event(Event::SubnetWarningSet(netuid, warning));
+    }
+    pub fn get_subnet_warning_tempos() -> u64 {
+        SubnetWarningTempos::<T>::get()
+    }
+    pub fn set_subnet_warning_tempos(tempos: u64) {
+        SubnetWarningTempos::<T>::put(tempos);
+        Self::deposit_event(Event::SubnetWarningTemposSet(tempos));
+    }
+    pub fn get_subnet_warning_blocks_registration() -> bool {
+        SubnetWarningBlocksRegistration::<T>::get()
+    }
+    pub fn set_subnet_warning_blocks_registration(blocks_registration: bool) {
+        SubnetWarningBlocksRegistration::<T>::put(blocks_registration);
+        Self::deposit_event(Event::SubnetWarningBlocksRegistrationSet(
+            blocks_registration,
+        ));
+    }
+    /// Returns true if registrations on `netuid` are refused because it is flagged for having no
+    /// emission and the root policy blocks registrations on flagged subnets.
+    pub fn is_registration_blocked_by_subnet_warning(netuid: u16) -> bool {
+        Self::get_subnet_warning_blocks_registration() && Self::get_subnet_warning(netuid)
+    }
+    pub fn get_epoch_history_depth(netuid: u16) -> u16 {
+        EpochHistoryDepth::<T>::get(netuid)
+    }
+    /// Sets the number of epochs kept in the epoch history of a subnet and clears the history,
+    /// so summaries written under another depth are not mixed into the ring.
+    pub fn set_epoch_history_depth(netuid: u16, depth: u16) {
+        EpochHistoryDepth::<T>::insert(netuid, depth);
+        EpochHistoryNextSlot::<T>::remove(netuid);
+        let _ = EpochHistory::<T>::clear_prefix(netuid, u32::MAX, None);
+        Self::deposit_event(Event::EpochHistoryDepthSet(netuid, depth));
+    }
+    pub fn get_validator_health_drop_threshold(netuid: u16) -> u16 {
+        ValidatorHealthDropThreshold::<T>::get(netuid)
+    }
+    pub fn set_validator_health_drop_threshold(netuid: u16, threshold: u16) {
+        ValidatorHealthDropThreshold::<T>::insert(netuid, threshold);
+        Self::deposit_event(Event::ValidatorHealthDropThresholdSet(netuid, threshold));
+    }
+    pub fn get_stake_weighted_voting_from() -> Option<u32> {
+        StakeWeightedVotingFrom::<T>::get()
+    }
+    pub fn get_stake_weighted_vote_threshold() -> u16 {
+        StakeWeightedVoteThreshold::<T>::get()
+    }
+    pub fn set_stake_weighted_voting(from: Option<u32>, threshold: u16) {
+        StakeWeightedVotingFrom::<T>::set(from);
+        StakeWeightedVoteThreshold::<T>::put(threshold);
+        Self::deposit_event(Event::StakeWeightedVotingSet(from, threshold));
+    }
     pub fn get_pending_emission(netuid: u16) -> u64 {
         PendingEmission::<T>::get(netuid)
     }
@@ -334,8 +444,25 @@ impl<T: Config> Pallet<T> {
         MaxDelegateTake::<T>::get()
     }
     pub fn get_default_delegate_take() -> u16 {
-        // Default to maximum
-        MaxDelegateTake::<T>::get()
+        DefaultDelegateTake::<T>::get()
+    }
+    pub fn set_default_delegate_take(take: u16) {
+        DefaultDelegateTake::<T>::put(take);
+        Self::deposit_event(Event::DefaultDelegateTakeSet(take));
+    }
+    pub fn get_min_owner_stake_to_delegate() -> u64 {
+        MinOwnerStakeToDelegate::<T>::get()
+    }
+    pub fn set_min_owner_stake_to_delegate(min_stake: u64) {
+        MinOwnerStakeToDelegate::<T>::put(min_stake);
+        Self::deposit_event(Event::MinOwnerStakeToDelegateSet(min_stake));
+    }
+    pub fn get_auto_redelegate_after_tempos() -> u64 {
+        AutoRedelegateAfterTempos::<T>::get()
+    }
+    pub fn set_auto_redelegate_after_tempos(tempos: u64) {
+        AutoRedelegateAfterTempos::<T>::put(tempos);
+        Self::deposit_event(Event::AutoRedelegateAfterTemposSet(tempos));
     }
     // get_default_childkey_take
     pub fn get_default_childkey_take() -> u16 {
@@ -349,6 +476,13 @@ impl<T: Config> Pallet<T> {
         TxChildkeyTakeRateLimit::<T>::put(tx_rate_limit);
         Self::deposit_event(Event::TxChildKeyTakeRateLimitSet(tx_rate_limit));
     }
+    pub fn get_owner_hyperparam_rate_limit() -> u64 {
+        OwnerHyperparamRateLimit::<T>::get()
+    }
+    pub fn set_owner_hyperparam_rate_limit(rate_limit: u64) {
+        OwnerHyperparamRateLimit::<T>::put(rate_limit);
+        Self::deposit_event(Event::OwnerHyperparamRateLimitSet(rate_limit));
+    }
     pub fn set_min_childkey_take(take: u16) {
         MinChildkeyTake::<T>::put(take);
         Self::deposit_event(Event::MinChildKeyTakeSet(take));
@@ -448,6 +582,16 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::MaxWeightLimitSet(netuid, max_weight_limit));
     }
 
+    /// Returns the number of weights a validator can set on `netuid`, never above its max
+    /// allowed uids.
+    pub fn get_max_weights_limit_len(netuid: u16) -> u16 {
+        MaxWeightsLimitLen::<T>::get(netuid).min(Self::get_max_allowed_uids(netuid))
+    }
+    pub fn set_max_weights_limit_len(netuid: u16, max_weights_limit_len: u16) {
+        MaxWeightsLimitLen::<T>::insert(netuid, max_weights_limit_len);
+        Self::deposit_event(Event::MaxWeightsLimitLenSet(netuid, max_weights_limit_len));
+    }
+
     pub fn get_immunity_period(netuid: u16) -> u16 {
         ImmunityPeriod::<T>::get(netuid)
     }
@@ -610,6 +754,24 @@ impl<T: Config> Pallet<T> {
     pub fn get_subnet_owner(netuid: u16) -> T::AccountId {
         SubnetOwner::<T>::get(netuid)
     }
+    /// Makes `owner` the owner of the subnet, moving the subnet between the owned subnet counts.
+    pub fn set_subnet_owner(netuid: u16, owner: &T::AccountId) {
+        Self::remove_subnet_owner(netuid);
+        SubnetOwner::<T>::insert(netuid, owner);
+        OwnedSubnetCount::<T>::mutate(owner, |count| *count = count.saturating_add(1));
+    }
+    /// Removes the owner of the subnet, taking the subnet off its owned subnet count.
+    pub fn remove_subnet_owner(netuid: u16) {
+        if !SubnetOwner::<T>::contains_key(netuid) {
+            return;
+        }
+        let owner: T::AccountId = SubnetOwner::<T>::take(netuid);
+        OwnedSubnetCount::<T>::mutate_exists(&owner, |count| {
+            *count = count
+                .map(|count| count.saturating_sub(1))
+                .filter(|count| *count > 0)
+        });
+    }
     pub fn get_subnet_owner_cut() -> u16 {
         SubnetOwnerCut::<T>::get()
     }
@@ -618,11 +780,41 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::SubnetOwnerCutSet(subnet_owner_cut));
     }
 
+    /// Returns the hotkeys owned by `coldkey`, in registration order until a swap merges them,
+    /// after which they are sorted by account id.
     pub fn get_owned_hotkeys(coldkey: &T::AccountId) -> Vec<T::AccountId> {
-        OwnedHotkeys::<T>::get(coldkey)
+        OwnedHotkeys::<T>::get(coldkey).into_inner()
+    }
+    /// Returns the hotkeys owned by `coldkey` sorted by account id, the same on every node
+    /// whatever order they were registered or swapped in.
+    pub fn get_owned_hotkeys_sorted(coldkey: &T::AccountId) -> Vec<T::AccountId> {
+        let mut hotkeys: Vec<T::AccountId> = Self::get_owned_hotkeys(coldkey);
+        hotkeys.sort();
+        hotkeys
     }
     pub fn get_all_staked_hotkeys(coldkey: &T::AccountId) -> Vec<T::AccountId> {
-        StakingHotkeys::<T>::get(coldkey)
+        Self::dedup_hotkeys(StakingHotkeys::<T>::get(coldkey))
+    }
+    pub fn get_owned_subnets(coldkey: &T::AccountId) -> Vec<u16> {
+        SubnetOwner::<T>::iter()
+            .filter(|(_, owner)| owner == coldkey)
+            .map(|(netuid, _)| netuid)
+            .collect()
+    }
+    pub fn get_subnet_identity(netuid: u16) -> Option<SubnetIdentity> {
+        SubnetIdentities::<T>::get(netuid)
+    }
+    pub fn get_network_modality(netuid: u16) -> u16 {
+        NetworkModality::<T>::get(netuid)
+    }
+    pub fn get_total_neuron_count() -> u32 {
+        TotalNeuronCount::<T>::get()
+    }
+    pub fn get_last_block_emission() -> u64 {
+        BlockEmission::<T>::get()
+    }
+    pub fn get_emission_last_tempo(hotkey: &T::AccountId, netuid: u16) -> u64 {
+        EmissionLastTempo::<T>::get(hotkey, netuid)
     }
 
     pub fn set_total_issuance(total_issuance: u64) {
@@ -673,8 +865,180 @@ impl<T: Config> Pallet<T> {
         NominatorMinRequiredStake::<T>::put(min_stake);
     }
 
+    pub fn get_pruning_score_source(netuid: u16) -> PruningScoreSource {
+        PruningScoreSources::<T>::get(netuid)
+    }
+    pub fn set_pruning_score_source(netuid: u16, source: PruningScoreSource) {
+        PruningScoreSources::<T>::insert(netuid, source);
+        Self::deposit_event(Event::PruningScoreSourceSet(netuid, source));
+    }
+
+    /// Sets the pruning scores of the neurons of a subnet, used when its pruning score source is
+    /// `Custom`.
+    ///
+    /// # Arguments
+    /// * `netuid` - The subnet of the scores.
+    /// * `pruning_scores` - The score of every neuron of the subnet, indexed by uid.
+    ///
+    /// # Errors
+    /// * `InvalidPruningScoresLength` - The scores do not cover every neuron of the subnet.
+    ///
+    /// # Effects
+    /// * Updates `CustomPruningScores` and emits `CustomPruningScoresSet`.
+    pub fn do_set_custom_pruning_scores(
+        netuid: u16,
+        pruning_scores: Vec<u16>,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            pruning_scores.len() == Self::get_subnetwork_n(netuid) as usize,
+            Error::<T>::InvalidPruningScoresLength
+        );
+        CustomPruningScores::<T>::insert(netuid, pruning_scores);
+        Self::deposit_event(Event::CustomPruningScoresSet(netuid));
+        Ok(())
+    }
+
+    pub fn get_staking_operations_paused() -> bool {
+        StakingOperationsPaused::<T>::get()
+    }
+
+    /// Pauses or resumes staking operations chain-wide.
+    ///
+    /// While paused, adding, removing and transferring stake and swapping keys fail with
+    /// `StakingPaused`. Emission keeps accruing to existing stake.
+    ///
+    /// # Arguments
+    /// * `paused` - Whether staking operations are paused.
+    ///
+    /// # Effects
+    /// * Updates `StakingOperationsPaused` and emits `StakingOperationsPausedSet`.
+    pub fn set_staking_operations_paused(paused: bool) {
+        StakingOperationsPaused::<T>::put(paused);
+        Self::deposit_event(Event::StakingOperationsPausedSet(paused));
+    }
+
+    /// Ensures staking operations are not paused.
+    pub fn ensure_staking_not_paused() -> Result<(), Error<T>> {
+        ensure!(
+            !Self::get_staking_operations_paused(),
+            Error::<T>::StakingPaused
+        );
+        Ok(())
+    }
+
     pub fn get_key_swap_cost() -> u64 {
-        T::KeySwapCost::get()
+        KeySwapCost::<T>::get()
+    }
+
+    pub fn get_min_key_swap_cost() -> u64 {
+        MinKeySwapCost::<T>::get()
+    }
+
+    pub fn get_max_key_swap_cost() -> u64 {
+        MaxKeySwapCost::<T>::get()
+    }
+
+    /// Count a key swap toward the next key swap cost adjustment.
+    pub fn increase_key_swaps_this_interval() {
+        KeySwapsThisInterval::<T>::mutate(|swaps| *swaps = swaps.saturating_add(1));
+    }
+
+    /// Set the target number of key swaps per adjustment interval
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The target number of key swaps per adjustment interval.
+    ///
+    /// # Effects
+    ///
+    /// * Update the TargetKeySwapsPerInterval storage.
+    /// * Emits a TargetKeySwapsPerIntervalSet event.
+    pub fn set_target_key_swaps_per_interval(target: u64) {
+        TargetKeySwapsPerInterval::<T>::set(target);
+        Self::deposit_event(Event::TargetKeySwapsPerIntervalSet(target));
+    }
+
+    /// Set the number of blocks between two key swap cost adjustments
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The number of blocks.
+    ///
+    /// # Effects
+    ///
+    /// * Update the KeySwapCostAdjustmentInterval storage.
+    /// * Emits a KeySwapCostAdjustmentIntervalSet event.
+    pub fn set_key_swap_cost_adjustment_interval(interval: u64) {
+        KeySwapCostAdjustmentInterval::<T>::set(interval);
+        Self::deposit_event(Event::KeySwapCostAdjustmentIntervalSet(interval));
+    }
+
+    /// Set the minimum key swap cost
+    ///
+    /// # Arguments
+    ///
+    /// * `min_cost` - The minimum key swap cost.
+    ///
+    /// # Effects
+    ///
+    /// * Update the MinKeySwapCost storage.
+    /// * Emits a MinKeySwapCostSet event.
+    pub fn set_min_key_swap_cost(min_cost: u64) {
+        MinKeySwapCost::<T>::set(min_cost);
+        Self::deposit_event(Event::MinKeySwapCostSet(min_cost));
+    }
+
+    /// Set the maximum key swap cost
+    ///
+    /// # Arguments
+    ///
+    /// * `max_cost` - The maximum key swap cost.
+    ///
+    /// # Effects
+    ///
+    /// * Update the MaxKeySwapCost storage.
+    /// * Emits a MaxKeySwapCostSet event.
+    pub fn set_max_key_swap_cost(max_cost: u64) {
+        MaxKeySwapCost::<T>::set(max_cost);
+        Self::deposit_event(Event::MaxKeySwapCostSet(max_cost));
+    }
+
+    /// Set the number of blocks before requested children are applied
+    ///
+    /// # Arguments
+    ///
+    /// * `cooldown` - The number of blocks.
+    ///
+    /// # Effects
+    ///
+    /// * Update the PendingChildKeyCooldown storage.
+    /// * Emits a PendingChildKeyCooldownSet event.
+    pub fn set_pending_child_key_cooldown(cooldown: u64) {
+        PendingChildKeyCooldown::<T>::set(cooldown);
+        Self::deposit_event(Event::PendingChildKeyCooldownSet(cooldown));
+    }
+
+    pub fn get_pending_child_key_cooldown() -> u64 {
+        PendingChildKeyCooldown::<T>::get()
+    }
+
+    /// Set the deposit withdrawn from the owner coldkey for a weights commit
+    ///
+    /// # Arguments
+    ///
+    /// * `deposit` - The deposit in RAO.
+    ///
+    /// # Effects
+    ///
+    /// * Update the WeightCommitDeposit storage.
+    /// * Emits a WeightCommitDepositSet event.
+    pub fn set_weight_commit_deposit(deposit: u64) {
+        WeightCommitDeposit::<T>::set(deposit);
+        Self::deposit_event(Event::WeightCommitDepositSet(deposit));
+    }
+
+    pub fn get_weight_commit_deposit() -> u64 {
+        WeightCommitDeposit::<T>::get()
     }
 
     pub fn get_alpha_values(netuid: u16) -> (u16, u16) {
@@ -690,6 +1054,107 @@ impl<T: Config> Pallet<T> {
         (converted_low, converted_high)
     }
 
+    pub fn get_param_governance_enabled(netuid: u16) -> bool {
+        ParamGovernanceEnabled::<T>::get(netuid)
+    }
+    pub fn set_param_governance_enabled(netuid: u16, enabled: bool) {
+        ParamGovernanceEnabled::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::ParamGovernanceEnabledSet(netuid, enabled));
+    }
+
+    pub fn get_transfer_stake_enabled(netuid: u16) -> bool {
+        TransferStakeEnabled::<T>::get(netuid)
+    }
+    pub fn set_transfer_stake_enabled(netuid: u16, enabled: bool) {
+        TransferStakeEnabled::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::TransferStakeEnabledSet(netuid, enabled));
+    }
+
+    pub fn get_require_commitment_for_weights(netuid: u16) -> bool {
+        RequireCommitmentForWeights::<T>::get(netuid)
+    }
+    pub fn set_require_commitment_for_weights(netuid: u16, enabled: bool) {
+        RequireCommitmentForWeights::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::RequireCommitmentForWeightsSet(netuid, enabled));
+    }
+    pub fn get_reject_uncommitted_weights(netuid: u16) -> bool {
+        RejectUncommittedWeights::<T>::get(netuid)
+    }
+    pub fn set_reject_uncommitted_weights(netuid: u16, enabled: bool) {
+        RejectUncommittedWeights::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::RejectUncommittedWeightsSet(netuid, enabled));
+    }
+    pub fn get_disallow_self_weight_by_coldkey(netuid: u16) -> bool {
+        DisallowSelfWeightByColdkey::<T>::get(netuid)
+    }
+    pub fn set_disallow_self_weight_by_coldkey(netuid: u16, enabled: bool) {
+        DisallowSelfWeightByColdkey::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::DisallowSelfWeightByColdkeySet(netuid, enabled));
+    }
+    pub fn get_reject_self_weight_by_coldkey(netuid: u16) -> bool {
+        RejectSelfWeightByColdkey::<T>::get(netuid)
+    }
+    pub fn set_reject_self_weight_by_coldkey(netuid: u16, enabled: bool) {
+        RejectSelfWeightByColdkey::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::RejectSelfWeightByColdkeySet(netuid, enabled));
+    }
+    pub fn is_invite_only_registration(netuid: u16) -> bool {
+        InviteOnlyRegistration::<T>::get(netuid)
+    }
+    pub fn set_invite_only_registration(netuid: u16, enabled: bool) {
+        InviteOnlyRegistration::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::InviteOnlyRegistrationSet(netuid, enabled));
+    }
+
+    pub fn get_recycle_registration_burn(netuid: u16) -> bool {
+        RecycleRegistrationBurn::<T>::get(netuid)
+    }
+    pub fn set_recycle_registration_burn(netuid: u16, enabled: bool) {
+        RecycleRegistrationBurn::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::RecycleRegistrationBurnSet(netuid, enabled));
+    }
+    pub fn get_registration_burn_owner_share(netuid: u16) -> u16 {
+        RegistrationBurnOwnerShare::<T>::get(netuid)
+    }
+    pub fn set_registration_burn_owner_share(netuid: u16, share: u16) {
+        RegistrationBurnOwnerShare::<T>::insert(netuid, share);
+        Self::deposit_event(Event::RegistrationBurnOwnerShareSet(netuid, share));
+    }
+    pub fn get_max_registration_burn_owner_share() -> u16 {
+        MaxRegistrationBurnOwnerShare::<T>::get()
+    }
+    pub fn set_max_registration_burn_owner_share(max_share: u16) {
+        MaxRegistrationBurnOwnerShare::<T>::put(max_share);
+        Self::deposit_event(Event::MaxRegistrationBurnOwnerShareSet(max_share));
+    }
+    /// The part of a registration burn paid to the subnet owner. A share set before the cap was
+    /// lowered is held to the cap.
+    pub fn get_registration_burn_owner_cut(netuid: u16, burn: u64) -> u64 {
+        let share = Self::get_registration_burn_owner_share(netuid)
+            .min(Self::get_max_registration_burn_owner_share())
+            .min(10_000);
+        (burn as u128)
+            .saturating_mul(share as u128)
+            .checked_div(10_000)
+            .unwrap_or(0) as u64
+    }
+
+    pub fn get_min_axon_version(netuid: u16) -> u32 {
+        MinAxonVersion::<T>::get(netuid)
+    }
+    pub fn set_min_axon_version(netuid: u16, min_version: u32) {
+        MinAxonVersion::<T>::set(netuid, min_version);
+        Self::deposit_event(Event::MinAxonVersionSet(netuid, min_version));
+    }
+
+    pub fn get_empty_epoch_uniform_incentive(netuid: u16) -> bool {
+        EmptyEpochUniformIncentive::<T>::get(netuid)
+    }
+    pub fn set_empty_epoch_uniform_incentive(netuid: u16, uniform: bool) {
+        EmptyEpochUniformIncentive::<T>::set(netuid, uniform);
+        Self::deposit_event(Event::EmptyEpochUniformIncentiveSet(netuid, uniform));
+    }
+
     pub fn set_liquid_alpha_enabled(netuid: u16, enabled: bool) {
         LiquidAlphaOn::<T>::set(netuid, enabled);
     }
@@ -766,6 +1231,60 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::ColdkeySwapScheduleDurationSet(duration));
     }
 
+    /// Set the share of the maximum block weight a coldkey swap may declare
+    ///
+    /// # Arguments
+    ///
+    /// * `share` - The share of the maximum block weight, out of u16::MAX.
+    ///
+    /// # Effects
+    ///
+    /// * Update the ColdkeySwapMaxWeightShare storage.
+    /// * Emits a ColdkeySwapMaxWeightShareSet event.
+    pub fn set_coldkey_swap_max_weight_share(share: u16) {
+        ColdkeySwapMaxWeightShare::<T>::set(share);
+        Self::deposit_event(Event::ColdkeySwapMaxWeightShareSet(share));
+    }
+
+    /// Set the number of hotkeys a coldkey that is not exempt can own
+    ///
+    /// # Arguments
+    ///
+    /// * `max_hotkeys` - The number of hotkeys, capped at MAX_OWNED_HOTKEYS.
+    ///
+    /// # Effects
+    ///
+    /// * Update the MaxHotkeysPerColdkey storage.
+    /// * Emits a MaxHotkeysPerColdkeySet event.
+    pub fn set_max_hotkeys_per_coldkey(max_hotkeys: u32) {
+        let max_hotkeys: u32 = max_hotkeys.min(MAX_OWNED_HOTKEYS);
+        MaxHotkeysPerColdkey::<T>::set(max_hotkeys);
+        Self::deposit_event(Event::MaxHotkeysPerColdkeySet(max_hotkeys));
+    }
+
+    /// Exempt a coldkey from MaxHotkeysPerColdkey, or make it subject to it again
+    ///
+    /// # Arguments
+    ///
+    /// * `coldkey` - The coldkey whose exemption changes.
+    /// * `exempt` - Whether the coldkey is exempt.
+    ///
+    /// # Effects
+    ///
+    /// * Update the HotkeyLimitExempt storage.
+    /// * Emits a HotkeyLimitExemptionSet event.
+    pub fn set_hotkey_limit_exemption(coldkey: &T::AccountId, exempt: bool) {
+        if exempt {
+            HotkeyLimitExempt::<T>::insert(coldkey, ());
+        } else {
+            HotkeyLimitExempt::<T>::remove(coldkey);
+        }
+        Self::deposit_event(Event::HotkeyLimitExemptionSet {
+            coldkey: coldkey.clone(),
+            exempt,
+        });
+    }
+
     /// Set the duration for dissolve network
     ///
     /// # Arguments
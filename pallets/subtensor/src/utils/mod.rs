@@ -1,4 +1,7 @@
 use super::*;
+pub mod attestation;
+pub mod call_failure;
+pub mod coldkey_auth;
 pub mod identity;
 pub mod misc;
 pub mod rate_limiting;
@@ -7,6 +7,9 @@ pub enum TransactionType {
     SetChildren,
     SetChildkeyTake,
     Unknown,
+    OwnerHyperparamUpdate,
+    SetEmissionPayoutMode,
+    Attest,
 }
 
 /// Implement conversion from TransactionType to u16
@@ -16,6 +19,9 @@ impl From<TransactionType> for u16 {
             TransactionType::SetChildren => 0,
             TransactionType::SetChildkeyTake => 1,
             TransactionType::Unknown => 2,
+            TransactionType::OwnerHyperparamUpdate => 3,
+            TransactionType::SetEmissionPayoutMode => 4,
+            TransactionType::Attest => 5,
         }
     }
 }
@@ -26,6 +32,9 @@ impl From<u16> for TransactionType {
         match value {
             0 => TransactionType::SetChildren,
             1 => TransactionType::SetChildkeyTake,
+            3 => TransactionType::OwnerHyperparamUpdate,
+            4 => TransactionType::SetEmissionPayoutMode,
+            5 => TransactionType::Attest,
             _ => TransactionType::Unknown,
         }
     }
@@ -40,6 +49,9 @@ impl<T: Config> Pallet<T> {
             TransactionType::SetChildren => (DefaultTempo::<T>::get().saturating_mul(2)).into(), // Cannot set children twice within the default tempo period.
             TransactionType::SetChildkeyTake => TxChildkeyTakeRateLimit::<T>::get(),
             TransactionType::Unknown => 0, // Default to no limit for unknown types (no limit)
+            TransactionType::OwnerHyperparamUpdate => OwnerHyperparamRateLimit::<T>::get(),
+            TransactionType::SetEmissionPayoutMode => Self::get_tx_rate_limit(),
+            TransactionType::Attest => AttestationRateLimit::<T>::get(),
         }
     }
 
@@ -87,24 +99,38 @@ impl<T: Config> Pallet<T> {
         TransactionKeyLastBlock::<T>::insert((hotkey, netuid, tx_as_u16), block);
     }
 
+    /// Get the last block `key` ran a rate limited `operation` in, or zero if it never did
+    pub fn get_last_rate_limited_block(operation: RateLimitedOperation, key: &T::AccountId) -> u64 {
+        LastRateLimitedBlock::<T>::get(operation, key)
+    }
+
+    /// Set the last block `key` ran a rate limited `operation` in
+    pub fn set_last_rate_limited_block(
+        operation: RateLimitedOperation,
+        key: &T::AccountId,
+        block: u64,
+    ) {
+        LastRateLimitedBlock::<T>::insert(operation, key, block)
+    }
+
     pub fn set_last_tx_block(key: &T::AccountId, block: u64) {
-        LastTxBlock::<T>::insert(key, block)
+        Self::set_last_rate_limited_block(RateLimitedOperation::Transaction, key, block)
     }
     pub fn get_last_tx_block(key: &T::AccountId) -> u64 {
-        LastTxBlock::<T>::get(key)
+        Self::get_last_rate_limited_block(RateLimitedOperation::Transaction, key)
     }
     pub fn set_last_tx_block_delegate_take(key: &T::AccountId, block: u64) {
-        LastTxBlockDelegateTake::<T>::insert(key, block)
+        Self::set_last_rate_limited_block(RateLimitedOperation::DelegateTake, key, block)
     }
     pub fn get_last_tx_block_delegate_take(key: &T::AccountId) -> u64 {
-        LastTxBlockDelegateTake::<T>::get(key)
+        Self::get_last_rate_limited_block(RateLimitedOperation::DelegateTake, key)
     }
 
     pub fn set_last_tx_block_childkey_take(key: &T::AccountId, block: u64) {
-        LastTxBlockChildKeyTake::<T>::insert(key, block)
+        Self::set_last_rate_limited_block(RateLimitedOperation::ChildKeyTake, key, block)
     }
     pub fn get_last_tx_block_childkey_take(key: &T::AccountId) -> u64 {
-        LastTxBlockChildKeyTake::<T>::get(key)
+        Self::get_last_rate_limited_block(RateLimitedOperation::ChildKeyTake, key)
     }
     pub fn exceeds_tx_rate_limit(prev_tx_block: u64, current_block: u64) -> bool {
         let rate_limit: u64 = Self::get_tx_rate_limit();
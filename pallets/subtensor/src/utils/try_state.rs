@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "try-runtime")]
+use sp_std::collections::btree_map::BTreeMap;
 
 impl<T: Config> Pallet<T> {
     /// Checks if the accounting invariants for [`TotalStake`], [`TotalSubnetLocked`], and [`TotalIssuance`] are correct.
@@ -6,7 +8,8 @@ impl<T: Config> Pallet<T> {
     /// This function verifies that:
     /// 1. The sum of all stakes matches the [`TotalStake`].
     /// 2. The [`TotalSubnetLocked`] is correctly calculated.
-    /// 3. The [`TotalIssuance`] equals the sum of currency issuance, total stake, and total subnet locked.
+    /// 3. The [`TotalIssuance`] equals the sum of currency issuance, total stake, total subnet locked,
+    ///    and the deposits held for weights commits and coldkey attestations.
     ///
     /// # Returns
     ///
@@ -30,13 +33,28 @@ impl<T: Config> Pallet<T> {
         // Get the total subnet locked amount
         let total_subnet_locked: u64 = Self::get_total_subnet_locked();
 
+        // Sum the deposits held for weights commits
+        let mut total_commit_deposits: u64 = 0;
+        for (_netuid, _hotkey, deposit) in WeightCommitDeposits::<T>::iter() {
+            total_commit_deposits = total_commit_deposits.saturating_add(deposit);
+        }
+
+        // Sum the deposits held for coldkey attestations, which are withdrawn from the balances
+        let mut total_attestation_deposits: u64 = 0;
+        for (_coldkey, attestation) in ColdkeyAttestations::<T>::iter() {
+            total_attestation_deposits =
+                total_attestation_deposits.saturating_add(attestation.deposit);
+        }
+
         // Get the total currency issuance
         let currency_issuance: u64 = T::Currency::total_issuance();
 
         // Calculate the expected total issuance
         let expected_total_issuance: u64 = currency_issuance
             .saturating_add(total_staked)
-            .saturating_add(total_subnet_locked);
+            .saturating_add(total_subnet_locked)
+            .saturating_add(total_commit_deposits)
+            .saturating_add(total_attestation_deposits);
 
         // Verify that the calculated total issuance matches the stored TotalIssuance
         ensure!(
@@ -46,4 +64,117 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+
+    /// Checks the state the live migrations leave behind.
+    ///
+    /// This function verifies that:
+    /// 1. Every live migration is marked as run in [`HasMigrationRun`].
+    /// 2. The [`TotalNeuronCount`] is the sum of the subnet sizes.
+    /// 3. The [`NominatorCount`] of every hotkey counts the coldkeys with a non-zero stake on it.
+    /// 4. The [`TotalColdkeyStake`] of every coldkey is the sum of its stakes.
+    /// 5. [`IsNetworkMember`] holds exactly the neurons registered in [`Uids`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the migrated state is consistent, otherwise returns an error.
+    #[cfg(feature = "try-runtime")]
+    pub fn check_migrated_state() -> Result<(), sp_runtime::TryRuntimeError> {
+        // The migrations still run on upgrade that record themselves in HasMigrationRun
+        let live_migrations: [&[u8]; 9] = [
+            b"Populate OwnedHotkeys map",
+            b"Populate StakingHotkeys map",
+            b"fix_total_coldkey_stake_v7",
+            b"migrate_identities",
+            b"migrate_init_total_neuron_count",
+            b"migrate_bound_owned_hotkeys",
+            b"migrate_init_nominator_count",
+            b"migrate_rebuild_network_membership",
+            b"migrate_init_owned_subnet_count",
+        ];
+        for migration_name in live_migrations {
+            ensure!(
+                HasMigrationRun::<T>::get(migration_name.to_vec()),
+                "Live migration has not run",
+            );
+        }
+
+        // Verify the global neuron counter
+        let mut total_neuron_count: u32 = 0;
+        for (_netuid, subnetwork_n) in SubnetworkN::<T>::iter() {
+            total_neuron_count = total_neuron_count.saturating_add(u32::from(subnetwork_n));
+        }
+        ensure!(
+            TotalNeuronCount::<T>::get() == total_neuron_count,
+            "TotalNeuronCount does not match the subnet sizes",
+        );
+
+        // Recount the nominators and the coldkey totals from the stakes
+        let mut nominator_counts: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+        let mut coldkey_stakes: BTreeMap<T::AccountId, u64> = BTreeMap::new();
+        for (hotkey, coldkey, stake) in Stake::<T>::iter() {
+            if stake > 0 {
+                let count = nominator_counts.entry(hotkey).or_default();
+                *count = count.saturating_add(1);
+            }
+            let total = coldkey_stakes.entry(coldkey).or_default();
+            *total = total.saturating_add(stake);
+        }
+        for (hotkey, count) in NominatorCount::<T>::iter() {
+            ensure!(
+                nominator_counts.get(&hotkey).copied().unwrap_or_default() == count,
+                "NominatorCount does not match the stakes on the hotkey",
+            );
+        }
+        for (hotkey, count) in nominator_counts.iter() {
+            ensure!(
+                NominatorCount::<T>::get(hotkey) == *count,
+                "NominatorCount does not match the stakes on the hotkey",
+            );
+        }
+        for (coldkey, total) in coldkey_stakes.iter() {
+            ensure!(
+                TotalColdkeyStake::<T>::get(coldkey) == *total,
+                "TotalColdkeyStake does not match the stakes of the coldkey",
+            );
+        }
+
+        // Recount the owned subnets from the subnet owners
+        let mut owned_subnet_counts: BTreeMap<T::AccountId, u16> = BTreeMap::new();
+        for (_netuid, owner) in SubnetOwner::<T>::iter() {
+            let count = owned_subnet_counts.entry(owner).or_default();
+            *count = count.saturating_add(1);
+        }
+        for (coldkey, count) in OwnedSubnetCount::<T>::iter() {
+            ensure!(
+                owned_subnet_counts
+                    .get(&coldkey)
+                    .copied()
+                    .unwrap_or_default()
+                    == count,
+                "OwnedSubnetCount does not match the subnet owners",
+            );
+        }
+        for (coldkey, count) in owned_subnet_counts.iter() {
+            ensure!(
+                OwnedSubnetCount::<T>::get(coldkey) == *count,
+                "OwnedSubnetCount does not match the subnet owners",
+            );
+        }
+
+        // Verify the network memberships against the registrations
+        for (netuid, hotkey, _uid) in Uids::<T>::iter() {
+            ensure!(
+                IsNetworkMember::<T>::get(&hotkey, netuid),
+                "Registered neuron is not a network member",
+            );
+        }
+        for (hotkey, netuid, is_member) in IsNetworkMember::<T>::iter() {
+            ensure!(
+                !is_member || Uids::<T>::contains_key(netuid, &hotkey),
+                "Network member is not registered",
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -2,8 +2,11 @@
 use crate::mock::*;
 use frame_support::{assert_err, assert_noop, assert_ok};
 mod mock;
+use frame_support::dispatch::{DispatchInfo, GetDispatchInfo};
+use frame_support::weights::Weight;
 use pallet_subtensor::{utils::rate_limiting::TransactionType, *};
 use sp_core::U256;
+use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
 
 // 1: Successful setting of a single child
 // SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_do_set_child_singular_success --exact --nocapture
@@ -360,7 +363,9 @@ fn test_add_singular_child() {
             ),
             Err(Error::<Test>::NonAssociatedColdKey.into())
         );
-        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &coldkey, &hotkey
+        ));
         assert_eq!(
             SubtensorModule::do_set_children(
                 RuntimeOrigin::signed(coldkey),
@@ -3237,3 +3242,577 @@ fn test_rank_trust_incentive_calculation_with_parent_child() {
 
     });
 }
+
+// Registers a parent and a child with 1000 stake each on `netuid`, with a pending child key cooldown.
+fn setup_pending_children(netuid: u16, cooldown: u64) -> (U256, U256, U256) {
+    let coldkey = U256::from(1);
+    let parent = U256::from(2);
+    let child = U256::from(3);
+    add_network(netuid, 0, 0);
+    register_ok_neuron(netuid, parent, coldkey, 0);
+    register_ok_neuron(netuid, child, coldkey, 1);
+    SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &parent, 1000);
+    SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &child, 1000);
+    SubtensorModule::set_pending_child_key_cooldown(cooldown);
+    (coldkey, parent, child)
+}
+
+// Test that requested children are not used by queries or the epoch stake until applied.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_pending_children_not_applied --exact --nocapture
+#[test]
+fn test_pending_children_not_applied() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (coldkey, parent, child) = setup_pending_children(netuid, 10);
+
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX, child)]
+        ));
+        let effective_block = SubtensorModule::get_current_block_as_u64() + 10;
+        assert_eq!(
+            SubtensorModule::get_pending_children(&parent, netuid),
+            Some((vec![(u64::MAX, child)], effective_block))
+        );
+
+        // The epoch keeps using the stake of the current, empty, children.
+        step_block(5);
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        assert!(SubtensorModule::get_parents(&child, netuid).is_empty());
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&parent, netuid),
+            1000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&child, netuid),
+            1000
+        );
+    });
+}
+
+// Test that requested children are applied from the block step at their effective block.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_pending_children_applied_at_effective_block --exact --nocapture
+#[test]
+fn test_pending_children_applied_at_effective_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (coldkey, parent, child) = setup_pending_children(netuid, 10);
+
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX, child)]
+        ));
+        let effective_block = SubtensorModule::get_current_block_as_u64() + 10;
+
+        // Not applied the block before.
+        run_to_block(effective_block - 1);
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+
+        step_block(1);
+        assert_eq!(SubtensorModule::get_current_block_as_u64(), effective_block);
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(u64::MAX, child)]
+        );
+        assert_eq!(
+            SubtensorModule::get_parents(&child, netuid),
+            vec![(u64::MAX, parent)]
+        );
+        assert_eq!(SubtensorModule::get_pending_children(&parent, netuid), None);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&child, netuid),
+            2000
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::SubtensorModule(Event::ChildrenApplied(
+                parent,
+                netuid,
+                vec![(u64::MAX, child)]
+            ))));
+    });
+}
+
+// Test that a second request before the first is applied replaces it and restarts the cooldown.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_pending_children_overwrite_restarts_cooldown --exact --nocapture
+#[test]
+fn test_pending_children_overwrite_restarts_cooldown() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (coldkey, parent, child) = setup_pending_children(netuid, 10);
+        let other_child = U256::from(4);
+
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX, child)]
+        ));
+        let first_effective_block = SubtensorModule::get_current_block_as_u64() + 10;
+
+        step_block(6);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX / 2, other_child)]
+        ));
+        let second_effective_block = SubtensorModule::get_current_block_as_u64() + 10;
+        assert_eq!(
+            SubtensorModule::get_pending_children(&parent, netuid),
+            Some((vec![(u64::MAX / 2, other_child)], second_effective_block))
+        );
+
+        // Nothing is applied at the first effective block.
+        run_to_block(first_effective_block);
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+
+        // Only the second request is applied, a full cooldown after it.
+        run_to_block(second_effective_block - 1);
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(u64::MAX / 2, other_child)]
+        );
+        assert!(SubtensorModule::get_parents(&child, netuid).is_empty());
+    });
+}
+
+// Test that a pending request moved by a hotkey swap is applied to the new hotkey at its block.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_pending_children_follow_hotkey_swap --exact --nocapture
+#[test]
+fn test_pending_children_follow_hotkey_swap() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (coldkey, parent, child) = setup_pending_children(netuid, 10);
+        let new_parent = U256::from(5);
+
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX, child)]
+        ));
+        let effective_block = SubtensorModule::get_current_block_as_u64() + 10;
+        assert_eq!(
+            PendingChildKeysDue::<Test>::get(effective_block),
+            vec![(netuid, parent)]
+        );
+
+        let mut weight = Weight::zero();
+        assert_ok!(SubtensorModule::perform_hotkey_swap(
+            &parent,
+            &new_parent,
+            &coldkey,
+            &mut weight
+        ));
+
+        run_to_block(effective_block);
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        assert_eq!(
+            SubtensorModule::get_children(&new_parent, netuid),
+            vec![(u64::MAX, child)]
+        );
+        assert_eq!(
+            SubtensorModule::get_pending_children(&new_parent, netuid),
+            None
+        );
+        assert!(!PendingChildKeysDue::<Test>::contains_key(effective_block));
+    });
+}
+
+// Test that a pending request whose hotkey no longer exists when due is dropped, not applied.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_pending_children_discarded_when_invalid --exact --nocapture
+#[test]
+fn test_pending_children_discarded_when_invalid() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (coldkey, parent, child) = setup_pending_children(netuid, 10);
+
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX, child)]
+        ));
+        let effective_block = SubtensorModule::get_current_block_as_u64() + 10;
+        Owner::<Test>::remove(parent);
+
+        run_to_block(effective_block);
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        assert!(SubtensorModule::get_parents(&child, netuid).is_empty());
+        assert_eq!(SubtensorModule::get_pending_children(&parent, netuid), None);
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::SubtensorModule(Event::PendingChildrenDiscarded(parent, netuid))));
+    });
+}
+
+// Registers a parent, a child and a miner on `netuid`, sets the child of the parent and gives the
+// child a validator permit, returning (child coldkey, parent, child).
+fn setup_child_weights(netuid: u16) -> (U256, U256, U256) {
+    let parent_coldkey = U256::from(1);
+    let child_coldkey = U256::from(2);
+    let parent = U256::from(3);
+    let child = U256::from(4);
+
+    add_network(netuid, 13, 0);
+    SubtensorModule::set_min_allowed_weights(netuid, 0);
+    SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+    SubtensorModule::set_weights_set_rate_limit(netuid, 10);
+    register_ok_neuron(netuid, parent, parent_coldkey, 0);
+    register_ok_neuron(netuid, child, child_coldkey, 100_000);
+    register_ok_neuron(netuid, U256::from(5), U256::from(6), 200_000);
+    assert_ok!(SubtensorModule::do_set_children(
+        RuntimeOrigin::signed(parent_coldkey),
+        parent,
+        netuid,
+        vec![(u64::MAX, child)]
+    ));
+    let child_uid =
+        SubtensorModule::get_uid_for_net_and_hotkey(netuid, &child).expect("Not registered.");
+    SubtensorModule::set_validator_permit_for_uid(netuid, child_uid, true);
+
+    (child_coldkey, parent, child)
+}
+
+// Test that a parent cannot set the weights of a child without the child's permission.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_set_weights_for_child_requires_permission --exact --nocapture
+#[test]
+fn test_set_weights_for_child_requires_permission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (child_coldkey, parent, child) = setup_child_weights(netuid);
+
+        assert_noop!(
+            SubtensorModule::set_weights_for_child(
+                RuntimeOrigin::signed(parent),
+                child,
+                netuid,
+                vec![2],
+                vec![1],
+                0
+            ),
+            Error::<Test>::ChildWeightsNotPermitted
+        );
+
+        // Only the coldkey of the child can grant the permission.
+        assert_noop!(
+            SubtensorModule::set_child_weights_permission(
+                RuntimeOrigin::signed(U256::from(1)),
+                parent,
+                child,
+                netuid,
+                true
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // A permission does not make a hotkey that is not a parent of the child one.
+        let other = U256::from(5);
+        assert_ok!(SubtensorModule::set_child_weights_permission(
+            RuntimeOrigin::signed(child_coldkey),
+            other,
+            child,
+            netuid,
+            true
+        ));
+        assert_noop!(
+            SubtensorModule::set_weights_for_child(
+                RuntimeOrigin::signed(other),
+                child,
+                netuid,
+                vec![2],
+                vec![1],
+                0
+            ),
+            Error::<Test>::NotParentOfChild
+        );
+    });
+}
+
+// Test that weights set by a parent are attributed to the child, including its rate limit.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_set_weights_for_child_uses_child_rate_limit --exact --nocapture
+#[test]
+fn test_set_weights_for_child_uses_child_rate_limit() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (child_coldkey, parent, child) = setup_child_weights(netuid);
+        let parent_uid =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &parent).expect("Not registered.");
+        let child_uid =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &child).expect("Not registered.");
+        assert_ok!(SubtensorModule::set_child_weights_permission(
+            RuntimeOrigin::signed(child_coldkey),
+            parent,
+            child,
+            netuid,
+            true
+        ));
+        step_block(10);
+        let parent_last_update = SubtensorModule::get_last_update_for_uid(netuid, parent_uid);
+
+        assert_ok!(SubtensorModule::set_weights_for_child(
+            RuntimeOrigin::signed(parent),
+            child,
+            netuid,
+            vec![2],
+            vec![1],
+            0
+        ));
+        assert_eq!(Weights::<Test>::get(netuid, child_uid), vec![(2, u16::MAX)]);
+        assert!(Weights::<Test>::get(netuid, parent_uid).is_empty());
+        assert_eq!(
+            SubtensorModule::get_last_update_for_uid(netuid, child_uid),
+            SubtensorModule::get_current_block_as_u64()
+        );
+        assert_eq!(
+            SubtensorModule::get_last_update_for_uid(netuid, parent_uid),
+            parent_last_update
+        );
+
+        // The child is now rate limited, whoever signs its weights.
+        step_block(1);
+        assert_noop!(
+            SubtensorModule::set_weights_for_child(
+                RuntimeOrigin::signed(parent),
+                child,
+                netuid,
+                vec![2],
+                vec![1],
+                0
+            ),
+            Error::<Test>::SettingWeightsTooFast
+        );
+        assert_noop!(
+            SubtensorModule::set_weights(RuntimeOrigin::signed(child), netuid, vec![2], vec![1], 0),
+            Error::<Test>::SettingWeightsTooFast
+        );
+
+        step_block(10);
+        assert_ok!(SubtensorModule::set_weights_for_child(
+            RuntimeOrigin::signed(parent),
+            child,
+            netuid,
+            vec![2],
+            vec![1],
+            0
+        ));
+    });
+}
+
+// Test that revoking the permission takes effect immediately.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_set_weights_for_child_revocation --exact --nocapture
+#[test]
+fn test_set_weights_for_child_revocation() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (child_coldkey, parent, child) = setup_child_weights(netuid);
+        assert_ok!(SubtensorModule::set_child_weights_permission(
+            RuntimeOrigin::signed(child_coldkey),
+            parent,
+            child,
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_child_weights_permission(
+            &parent, &child, netuid
+        ));
+
+        assert_ok!(SubtensorModule::set_child_weights_permission(
+            RuntimeOrigin::signed(child_coldkey),
+            parent,
+            child,
+            netuid,
+            false
+        ));
+        System::assert_last_event(
+            Event::ChildWeightsPermissionSet {
+                parent,
+                child,
+                netuid,
+                allowed: false,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            SubtensorModule::set_weights_for_child(
+                RuntimeOrigin::signed(parent),
+                child,
+                netuid,
+                vec![2],
+                vec![1],
+                0
+            ),
+            Error::<Test>::ChildWeightsNotPermitted
+        );
+    });
+}
+
+// Test that a free set_weights_for_child call of a signer that is not a permitted parent is
+// rejected before it reaches the pool.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_set_weights_for_child_validate --exact --nocapture
+#[test]
+fn test_set_weights_for_child_validate() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (child_coldkey, parent, child) = setup_child_weights(netuid);
+        let call: RuntimeCall = SubtensorCall::set_weights_for_child {
+            child_hotkey: child,
+            netuid,
+            dests: vec![2],
+            weights: vec![1],
+            version_key: 0,
+        }
+        .into();
+        let info: DispatchInfo = call.get_dispatch_info();
+        let extension = SubtensorSignedExtension::<Test>::new();
+        let rejected = Err(InvalidTransaction::Custom(
+            CustomTransactionError::ChildWeightsNotPermitted.into(),
+        )
+        .into());
+
+        // Neither a hotkey that is not a parent, nor a parent without permission, is valid.
+        assert_eq!(
+            extension.validate(&U256::from(5), &call, &info, 10),
+            rejected
+        );
+        assert_eq!(extension.validate(&parent, &call, &info, 10), rejected);
+
+        assert_ok!(SubtensorModule::set_child_weights_permission(
+            RuntimeOrigin::signed(child_coldkey),
+            parent,
+            child,
+            netuid,
+            true
+        ));
+        assert_ok!(extension.validate(&parent, &call, &info, 10));
+    });
+}
+
+// Test the stake breakdown of a hotkey that is a delegate, a parent and a child at once.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_hotkey_stake_breakdown_parent_and_delegate --exact --nocapture
+#[test]
+fn test_hotkey_stake_breakdown_parent_and_delegate() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner_coldkey = U256::from(1);
+        let nominator = U256::from(2);
+        let grandparent_coldkey = U256::from(3);
+        let hotkey = U256::from(4);
+        let child = U256::from(5);
+        let grandparent = U256::from(6);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, owner_coldkey, 0);
+        register_ok_neuron(netuid, child, owner_coldkey, 100_000);
+        register_ok_neuron(netuid, grandparent, grandparent_coldkey, 200_000);
+        assert_ok!(SubtensorModule::become_delegate(
+            RuntimeOrigin::signed(owner_coldkey),
+            hotkey
+        ));
+
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner_coldkey, &hotkey, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 3000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &grandparent_coldkey,
+            &grandparent,
+            500,
+        );
+
+        // The hotkey lends all its stake to its child and inherits all the stake of its parent.
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(owner_coldkey),
+            hotkey,
+            netuid,
+            vec![(u64::MAX, child)]
+        ));
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(grandparent_coldkey),
+            grandparent,
+            netuid,
+            vec![(u64::MAX, hotkey)]
+        ));
+
+        let breakdown = SubtensorModule::get_hotkey_stake_breakdown(&hotkey, netuid);
+        assert_eq!(
+            breakdown,
+            HotkeyStakeBreakdown {
+                owner_stake: 1000,
+                nominated_stake: 3000,
+                inherited_stake: 500,
+                lent_stake: 4000,
+                total: 500,
+            }
+        );
+        // The epoch reads the same total.
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&hotkey, netuid),
+            breakdown.total
+        );
+
+        // The total is capped at the max stake of the subnet, the parts are not.
+        SubtensorModule::set_network_max_stake(netuid, 300);
+        let capped = SubtensorModule::get_hotkey_stake_breakdown(&hotkey, netuid);
+        assert_eq!(capped.total, 300);
+        assert_eq!(capped.inherited_stake, 500);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&hotkey, netuid),
+            300
+        );
+    });
+}
+
+// Test that a change of the childkey take emits its take event, without touching the delegate
+// take history.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_childkey_take_change_events --exact --nocapture
+#[test]
+fn test_childkey_take_change_events() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::set_tx_childkey_take_rate_limit(0);
+        let default_take = SubtensorModule::get_childkey_take(&hotkey, netuid);
+        let high_take: u16 = SubtensorModule::get_max_childkey_take() / 2;
+
+        assert_ok!(SubtensorModule::set_childkey_take(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            high_take
+        ));
+        System::assert_has_event(
+            Event::TakeIncreased {
+                coldkey,
+                hotkey,
+                old_take: default_take,
+                new_take: high_take,
+            }
+            .into(),
+        );
+
+        assert_ok!(SubtensorModule::set_childkey_take(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            high_take / 2
+        ));
+        System::assert_has_event(
+            Event::TakeDecreased {
+                coldkey,
+                hotkey,
+                old_take: high_take,
+                new_take: high_take / 2,
+            }
+            .into(),
+        );
+        assert!(TakeHistory::<Test>::get(hotkey).is_empty());
+    });
+}
@@ -2,7 +2,26 @@
 use crate::mock::*;
 mod mock;
 // use frame_support::{assert_err, assert_ok};
-use sp_core::U256;
+use codec::Compact;
+use frame_support::assert_ok;
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use pallet_subtensor::{
+    epoch_commitment_root, epoch_leaf_hash, epoch_leaf_proof, verify_epoch_leaf, BlockStepStage,
+    BlockStepWeight, EmissionLastTempo, EmissionLifetime, EmissionPayoutMode, EpochCommitment,
+    EpochCommitmentNextIndex, EpochHistory, Event, HotkeyDrainCursor, HotkeyDrainQueue, Keys,
+    PendingEmission, PendingdHotkeyEmission, Weights, WeightsRow, WithheldEmission,
+    EPOCH_COMMITMENT_DEPTH, MAX_EMISSION_MULTIPLIER,
+};
+use sp_core::{Get, U256};
+use substrate_fixed::types::I64F64;
+
+// Registers a miner on the subnet and sets a weight on it from uid 0, so that the epochs of the
+// subnet are not skipped for a lack of weights.
+fn set_weight_on_miner(netuid: u16) {
+    register_ok_neuron(netuid, U256::from(100), U256::from(101), 300000);
+    Weights::<Test>::insert(netuid, 0, WeightsRow::truncate_from(vec![(1, u16::MAX)]));
+}
 
 // Test the ability to hash all sorts of hotkeys.
 #[test]
@@ -57,8 +76,11 @@ fn test_coinbase_basic() {
         // Create a network with a tempo 1
         add_network(netuid, 1, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 100000);
-        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &coldkey, &hotkey
+        ));
         SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        set_weight_on_miner(netuid);
 
         // Set the subnet emission value to 1.
         SubtensorModule::set_emission_values(&[netuid], vec![1]).unwrap();
@@ -154,3 +176,1148 @@ fn test_set_and_get_hotkey_emission_tempo() {
         assert_eq!(updated_tempo, new_tempo);
     });
 }
+
+// Test the delegate take only applies to the nominators' part of the emission.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_distribute_hotkey_emission_take_on_nominators_only -- --nocapture
+#[test]
+fn test_distribute_hotkey_emission_take_on_nominators_only() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(0);
+        let owner = U256::from(1);
+        let nominator1 = U256::from(2);
+        let nominator2 = U256::from(3);
+
+        // 9% take.
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &owner, &hotkey
+        ));
+        pallet_subtensor::Delegates::<Test>::insert(hotkey, 5_898);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator1, &hotkey, 2_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator2, &hotkey, 1_000);
+        let total_stake_before = SubtensorModule::get_total_stake();
+
+        let emission: u64 = 1_000_000;
+        assert_eq!(
+            SubtensorModule::distribute_hotkey_emission(&hotkey, emission, 0),
+            emission
+        );
+
+        // Nominators hold 3/4 of the stake: 750_000, of which 9% (67_498) is taken.
+        // The remaining 682_502 is split 2:1 between the nominators.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator1, &hotkey),
+            2_000 + 455_001
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator2, &hotkey),
+            1_000 + 227_500
+        );
+        // The owner gets its own 250_000 untaxed, plus the 67_498 take and 1 of rounding dust.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&owner, &hotkey),
+            1_000 + 317_499
+        );
+
+        // Nothing is created or lost.
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey),
+            4_000 + emission
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake(),
+            total_stake_before + emission
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&nominator1),
+            2_000 + 455_001
+        );
+    });
+}
+
+// Test a take increase made while emission is pending only applies from the next drain.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_drain_hotkey_emission_take_increase_waits_for_drain -- --nocapture
+#[test]
+fn test_drain_hotkey_emission_take_increase_waits_for_drain() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(0);
+        let owner = U256::from(1);
+        let nominator = U256::from(2);
+
+        // 9% take, nominators hold 3/4 of the stake.
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &owner, &hotkey
+        ));
+        pallet_subtensor::Delegates::<Test>::insert(hotkey, 5_898);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 3_000);
+        let emission: u64 = 1_000_000;
+        pallet_subtensor::PendingdHotkeyEmission::<Test>::insert(hotkey, emission);
+
+        // The take doubles to 18% before the drain.
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as frame_system::Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            11_796
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey), 5_898);
+        assert_eq!(
+            pallet_subtensor::PendingDelegateTake::<Test>::get(hotkey),
+            Some(11_796)
+        );
+
+        // The pending emission is charged the old 9% take: 750_000 - 67_498.
+        SubtensorModule::drain_hotkey_emission(&hotkey, emission, 1);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey),
+            3_000 + 682_502
+        );
+
+        // The increase applies from the drain on.
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey), 11_796);
+        assert_eq!(
+            pallet_subtensor::PendingDelegateTake::<Test>::get(hotkey),
+            None
+        );
+    });
+}
+
+// Test a hotkey staked only by its owner keeps all of its emission.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_distribute_hotkey_emission_owner_only -- --nocapture
+#[test]
+fn test_distribute_hotkey_emission_owner_only() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(0);
+        let owner = U256::from(1);
+
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &owner, &hotkey
+        ));
+        pallet_subtensor::Delegates::<Test>::insert(hotkey, 5_898);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1_000);
+
+        SubtensorModule::distribute_hotkey_emission(&hotkey, 1_000_000, 0);
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&owner, &hotkey),
+            1_000 + 1_000_000
+        );
+    });
+}
+
+// Test nominators of one hotkey are paid by their own payout mode.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_distribute_hotkey_emission_mixed_payout_modes -- --nocapture
+#[test]
+fn test_distribute_hotkey_emission_mixed_payout_modes() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(0);
+        let owner = U256::from(1);
+        let nominator1 = U256::from(2);
+        let nominator2 = U256::from(3);
+
+        // 9% take, as in the take test above.
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &owner, &hotkey
+        ));
+        pallet_subtensor::Delegates::<Test>::insert(hotkey, 5_898);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator1, &hotkey, 2_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator2, &hotkey, 1_000);
+        assert_ok!(SubtensorModule::set_emission_payout_mode(
+            <<Test as frame_system::Config>::RuntimeOrigin>::signed(nominator1),
+            hotkey,
+            EmissionPayoutMode::ToBalance
+        ));
+
+        SubtensorModule::distribute_hotkey_emission(&hotkey, 1_000_000, 0);
+
+        // The first nominator is paid its share to its balance, its stake does not grow.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator1, &hotkey),
+            2_000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator1), 455_001);
+        // The second nominator and the owner compound.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator2, &hotkey),
+            1_000 + 227_500
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator2), 0);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&owner, &hotkey),
+            1_000 + 317_499
+        );
+    });
+}
+
+// Test the drained emission is conserved across stake and balances when paid to the balance.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_drain_emission_to_balance_conserved -- --nocapture
+#[test]
+fn test_drain_emission_to_balance_conserved() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(0);
+        let owner = U256::from(1);
+        let nominators = [U256::from(2), U256::from(3), U256::from(4)];
+
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &owner, &hotkey
+        ));
+        pallet_subtensor::Delegates::<Test>::insert(hotkey, 5_898);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1_000);
+        for (i, nominator) in nominators.iter().enumerate() {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                nominator,
+                &hotkey,
+                1_000 * (i as u64 + 1),
+            );
+        }
+        pallet_subtensor::EmissionPayout::<Test>::insert(
+            hotkey,
+            nominators[0],
+            EmissionPayoutMode::ToBalance,
+        );
+        pallet_subtensor::EmissionPayout::<Test>::insert(
+            hotkey,
+            nominators[2],
+            EmissionPayoutMode::ToBalance,
+        );
+        let total_stake_before = SubtensorModule::get_total_stake();
+        let total_issuance_before = SubtensorModule::get_total_issuance();
+
+        let emission: u64 = 1_234_567;
+        PendingdHotkeyEmission::<Test>::insert(hotkey, emission);
+        HotkeyDrainQueue::<Test>::put(frame_support::BoundedVec::truncate_from(vec![hotkey]));
+        SubtensorModule::drain_scheduled_hotkeys(Weight::MAX);
+
+        let paid_to_balance: u64 = nominators
+            .iter()
+            .map(SubtensorModule::get_coldkey_balance)
+            .sum();
+        assert!(paid_to_balance > 0);
+        assert_eq!(
+            SubtensorModule::get_total_stake() - total_stake_before + paid_to_balance,
+            emission
+        );
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            total_issuance_before + emission
+        );
+    });
+}
+
+// Test a share paid to the balance below the existential deposit is compounded instead.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_distribute_hotkey_emission_dust_compounds -- --nocapture
+#[test]
+fn test_distribute_hotkey_emission_dust_compounds() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(0);
+        let owner = U256::from(1);
+        let nominator = U256::from(2);
+
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &owner, &hotkey
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1_000);
+        pallet_subtensor::EmissionPayout::<Test>::insert(
+            hotkey,
+            nominator,
+            EmissionPayoutMode::ToBalance,
+        );
+        ExistentialDeposit::set(1_000_000);
+        let total_stake_before = SubtensorModule::get_total_stake();
+
+        let emission: u64 = 100_000;
+        SubtensorModule::distribute_hotkey_emission(&hotkey, emission, 0);
+
+        // The nominator's share is below the existential deposit, so it is compounded.
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator), 0);
+        assert!(SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey) > 1_000);
+        assert_eq!(
+            SubtensorModule::get_total_stake(),
+            total_stake_before + emission
+        );
+    });
+}
+
+// Test that the emission credited in an epoch is recorded per hotkey and subnet.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_emission_records_across_epochs -- --nocapture
+#[test]
+fn test_emission_records_across_epochs() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &coldkey, &hotkey
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        set_weight_on_miner(netuid);
+        SubtensorModule::set_emission_values(&[netuid], vec![1]).unwrap();
+
+        // No epoch has credited the hotkey yet.
+        next_block();
+        assert_eq!(EmissionLastTempo::<Test>::get(hotkey, netuid), 0);
+        assert_eq!(EmissionLifetime::<Test>::get(hotkey, netuid), 0);
+
+        // The first epoch credits the hotkey with the stake it gains.
+        next_block();
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 1002);
+        assert_eq!(EmissionLastTempo::<Test>::get(hotkey, netuid), 2);
+        assert_eq!(EmissionLifetime::<Test>::get(hotkey, netuid), 2);
+
+        // The next epoch replaces the last value and adds to the lifetime.
+        next_block();
+        next_block();
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 1004);
+        assert_eq!(EmissionLastTempo::<Test>::get(hotkey, netuid), 2);
+        assert_eq!(EmissionLifetime::<Test>::get(hotkey, netuid), 4);
+    });
+}
+
+// Test that a hotkey credited several times in one epoch reports the sum.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_record_hotkey_emission_sums_epoch_credits -- --nocapture
+#[test]
+fn test_record_hotkey_emission_sums_epoch_credits() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let other = U256::from(2);
+        EmissionLastTempo::<Test>::insert(hotkey, netuid, 50);
+        EmissionLifetime::<Test>::insert(hotkey, netuid, 50);
+
+        SubtensorModule::record_hotkey_emission(netuid, &[(hotkey, 10), (other, 5), (hotkey, 20)]);
+
+        assert_eq!(EmissionLastTempo::<Test>::get(hotkey, netuid), 30);
+        assert_eq!(EmissionLifetime::<Test>::get(hotkey, netuid), 80);
+        assert_eq!(EmissionLastTempo::<Test>::get(other, netuid), 5);
+        assert_eq!(EmissionLifetime::<Test>::get(other, netuid), 5);
+    });
+}
+
+// Test that the pending subnet emission reported over the runtime API accumulates and resets on the epoch.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_pending_emission_info -- --nocapture
+#[test]
+fn test_pending_emission_info() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 5;
+        add_network(netuid, tempo, 0);
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        set_weight_on_miner(netuid);
+        SubtensorModule::set_emission_values(&[netuid], vec![7]).unwrap();
+        assert_eq!(SubtensorModule::get_pending_emission_info(2), None);
+
+        // Run to the first epoch so that the accumulation starts from zero.
+        let first_epoch: u64 = SubtensorModule::get_pending_emission_info(netuid)
+            .unwrap()
+            .next_epoch_block
+            .0;
+        run_to_block(first_epoch);
+        let info = SubtensorModule::get_pending_emission_info(netuid).unwrap();
+        assert_eq!(info.pending_emission, Compact(0));
+        let next_epoch: u64 = info.next_epoch_block.0;
+        assert_eq!(next_epoch, first_epoch + tempo as u64 + 1);
+
+        // The emission accumulates every block until the epoch.
+        for blocks in 1..=tempo as u64 {
+            next_block();
+            let info = SubtensorModule::get_pending_emission_info(netuid).unwrap();
+            assert_eq!(info.netuid, Compact(netuid));
+            assert_eq!(info.pending_emission, Compact(7 * blocks));
+            assert_eq!(info.next_epoch_block, Compact(next_epoch));
+        }
+
+        // The epoch drains it.
+        assert_eq!(next_block(), next_epoch);
+        let info = SubtensorModule::get_pending_emission_info(netuid).unwrap();
+        assert_eq!(info.pending_emission, Compact(0));
+        assert_eq!(
+            info.next_epoch_block,
+            Compact(next_epoch + tempo as u64 + 1)
+        );
+    });
+}
+
+// Test that the pending hotkey emission reported over the runtime API accumulates and resets on the drain.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_pending_hotkey_emission_info -- --nocapture
+#[test]
+fn test_pending_hotkey_emission_info() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &coldkey, &hotkey
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        set_weight_on_miner(netuid);
+        SubtensorModule::set_emission_values(&[netuid], vec![1]).unwrap();
+        SubtensorModule::set_hotkey_emission_tempo(10);
+
+        // Run to a drain so that the accumulation starts from zero.
+        let first_drain: u64 = SubtensorModule::get_pending_hotkey_emission_info(&hotkey)
+            .next_drain_block
+            .0;
+        run_to_block(first_drain);
+        let info = SubtensorModule::get_pending_hotkey_emission_info(&hotkey);
+        assert_eq!(info.pending_emission, Compact(0));
+        assert_eq!(info.blocks_until_drain, Compact(11));
+        let next_drain: u64 = info.next_drain_block.0;
+        assert_eq!(next_drain, first_drain + 11);
+
+        // The emission of each epoch accumulates on the hotkey until the drain.
+        let mut pending: u64 = 0;
+        while System::block_number() + 1 < next_drain {
+            let block = next_block();
+            let info = SubtensorModule::get_pending_hotkey_emission_info(&hotkey);
+            assert!(info.pending_emission.0 >= pending);
+            pending = info.pending_emission.0;
+            assert_eq!(
+                info.pending_emission.0,
+                SubtensorModule::get_pending_hotkey_emission(&hotkey)
+            );
+            assert_eq!(info.next_drain_block, Compact(next_drain));
+            assert_eq!(info.blocks_until_drain, Compact(next_drain - block));
+        }
+        assert!(pending > 0);
+
+        // The drain pays it out to the stake of the hotkey, with any emission of an epoch on the same block.
+        let stake_before: u64 = SubtensorModule::get_total_stake_for_hotkey(&hotkey);
+        next_block();
+        let info = SubtensorModule::get_pending_hotkey_emission_info(&hotkey);
+        assert_eq!(info.pending_emission, Compact(0));
+        assert_eq!(info.next_drain_block, Compact(next_drain + 11));
+        assert!(SubtensorModule::get_total_stake_for_hotkey(&hotkey) >= stake_before + pending);
+    });
+}
+
+// Test that the block step runs its stages in order and records their weight.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_block_step_stage_order -- --nocapture
+#[test]
+fn test_block_step_stage_order() {
+    new_test_ext(1).execute_with(|| {
+        add_network(1, 1, 0);
+        next_block();
+
+        let stages: Vec<BlockStepStage> = BlockStepWeight::<Test>::get()
+            .into_iter()
+            .map(|(stage, _)| stage)
+            .collect();
+        assert_eq!(
+            stages,
+            vec![
+                BlockStepStage::AdjustRegistrationTerms,
+                BlockStepStage::ApplyPendingChildKeys,
+                BlockStepStage::RootEpoch,
+                BlockStepStage::AccumulateSubnetEmission,
+                BlockStepStage::RunSubnetEpochs,
+                BlockStepStage::ScheduleHotkeyDrains,
+                BlockStepStage::DrainHotkeys,
+                BlockStepStage::AdjustKeySwapCost,
+                BlockStepStage::PruneExpiredInvites,
+            ]
+        );
+        assert_eq!(stages, BlockStepStage::ALL.to_vec());
+    });
+}
+
+// Test that the reported block step weight matches the expected database accesses.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_block_step_weight_matches_db_weight -- --nocapture
+#[test]
+fn test_block_step_weight_matches_db_weight() {
+    new_test_ext(1).execute_with(|| {
+        let db = <Test as frame_system::Config>::DbWeight::get();
+        // Two subnets that never run their epoch and are not at their adjustment interval.
+        for netuid in [1u16, 2u16] {
+            add_network(netuid, 0, 0);
+            SubtensorModule::set_adjustment_interval(netuid, 100);
+            SubtensorModule::set_last_adjustment_block(netuid, 1);
+        }
+
+        let weight = SubtensorModule::on_initialize(System::block_number());
+
+        let expected: Vec<(BlockStepStage, Weight)> = vec![
+            (
+                BlockStepStage::AdjustRegistrationTerms,
+                db.reads_writes(3, 1).saturating_mul(2),
+            ),
+            (BlockStepStage::ApplyPendingChildKeys, db.reads_writes(1, 1)),
+            (BlockStepStage::RootEpoch, db.reads(1)),
+            (
+                BlockStepStage::AccumulateSubnetEmission,
+                db.reads(2)
+                    .saturating_add(db.reads_writes(3, 1).saturating_mul(2)),
+            ),
+            (
+                BlockStepStage::RunSubnetEpochs,
+                db.reads(2)
+                    .saturating_add(db.reads_writes(2, 1).saturating_mul(2)),
+            ),
+            (BlockStepStage::ScheduleHotkeyDrains, db.reads(2)),
+            (BlockStepStage::DrainHotkeys, db.reads(1)),
+            (BlockStepStage::AdjustKeySwapCost, db.reads(2)),
+            (BlockStepStage::PruneExpiredInvites, db.reads_writes(1, 1)),
+        ];
+        assert_eq!(BlockStepWeight::<Test>::get(), expected);
+
+        let total = expected
+            .iter()
+            .fold(Weight::zero(), |total, (_, w)| total.saturating_add(*w));
+        assert_eq!(SubtensorModule::get_block_step_weight(), total);
+        assert_eq!(weight, total.saturating_add(db.writes(1)));
+    });
+}
+
+// Test that the block step drains the due hotkeys even when no idle weight is left.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_block_step_drains_hotkeys_without_idle_weight -- --nocapture
+#[test]
+fn test_block_step_drains_hotkeys_without_idle_weight() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &coldkey, &hotkey
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        set_weight_on_miner(netuid);
+        SubtensorModule::set_emission_values(&[netuid], vec![1]).unwrap();
+
+        // Step blocks without any idle weight: the epoch credits the hotkey and the block step
+        // drains it through to the stake.
+        let mut credited: u64 = 0;
+        for _ in 0..4 {
+            SubtensorModule::on_finalize(System::block_number());
+            System::set_block_number(System::block_number() + 1);
+            credited += PendingdHotkeyEmission::<Test>::get(hotkey);
+            SubtensorModule::on_initialize(System::block_number());
+            SubtensorModule::on_idle(System::block_number(), Weight::zero());
+        }
+        assert!(credited > 0);
+        assert_eq!(SubtensorModule::get_pending_hotkey_emission(&hotkey), 0);
+        assert!(HotkeyDrainQueue::<Test>::get().is_empty());
+        assert!(SubtensorModule::get_total_stake_for_hotkey(&hotkey) > 1000);
+    });
+}
+
+// Test that the drain queue is drained in order as far as the budget allows.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_drain_scheduled_hotkeys_in_queue_order -- --nocapture
+#[test]
+fn test_drain_scheduled_hotkeys_in_queue_order() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(3);
+        let hotkeys = [U256::from(1), U256::from(2)];
+        for hotkey in hotkeys.iter() {
+            assert_ok!(SubtensorModule::create_account_if_non_existent(
+                &coldkey, hotkey
+            ));
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, hotkey, 1000);
+            PendingdHotkeyEmission::<Test>::insert(hotkey, 10);
+        }
+        HotkeyDrainQueue::<Test>::put(frame_support::BoundedVec::truncate_from(hotkeys.to_vec()));
+
+        // Only the first hotkey fits.
+        let budget = <Test as frame_system::Config>::DbWeight::get()
+            .reads_writes(3, 2)
+            .saturating_add(SubtensorModule::hotkey_drain_weight(1));
+        SubtensorModule::drain_scheduled_hotkeys(budget);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkeys[0]),
+            1010
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkeys[1]),
+            1000
+        );
+        assert_eq!(
+            HotkeyDrainQueue::<Test>::get().into_inner(),
+            vec![hotkeys[1]]
+        );
+        assert!(HotkeyDrainCursor::<Test>::get().is_none());
+
+        // The second hotkey is drained on a later block.
+        SubtensorModule::drain_scheduled_hotkeys(Weight::MAX);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkeys[1]),
+            1010
+        );
+        assert!(HotkeyDrainQueue::<Test>::get().is_empty());
+    });
+}
+
+// Test that a hotkey with more stakers than the budget allows is drained over several blocks
+// without holding up the hotkey queued behind it, and without losing emission.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_hotkey_drain_spans_blocks -- --nocapture
+#[test]
+fn test_hotkey_drain_spans_blocks() {
+    new_test_ext(1).execute_with(|| {
+        let owner = U256::from(3);
+        let hotkeys = [U256::from(1), U256::from(2)];
+        let nominators = [
+            U256::from(10),
+            U256::from(11),
+            U256::from(12),
+            U256::from(13),
+        ];
+        for hotkey in hotkeys.iter() {
+            assert_ok!(SubtensorModule::create_account_if_non_existent(
+                &owner, hotkey
+            ));
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, hotkey, 1_000);
+            PendingdHotkeyEmission::<Test>::insert(hotkey, 100_000);
+        }
+        for nominator in nominators.iter() {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                nominator,
+                &hotkeys[0],
+                1_000,
+            );
+        }
+        HotkeyDrainQueue::<Test>::put(frame_support::BoundedVec::truncate_from(hotkeys.to_vec()));
+        let total_stake_before = SubtensorModule::get_total_stake();
+        let total_issuance_before = SubtensorModule::get_total_issuance();
+
+        // Three stake entries are visited per block, the first hotkey has five.
+        let budget = <Test as frame_system::Config>::DbWeight::get()
+            .reads_writes(3, 2)
+            .saturating_add(SubtensorModule::hotkey_drain_start_weight())
+            .saturating_add(SubtensorModule::hotkey_drain_visit_weight().saturating_mul(3));
+        assert!(SubtensorModule::drain_scheduled_hotkeys(budget).all_lte(budget));
+        assert!(HotkeyDrainCursor::<Test>::get().is_some());
+        assert_eq!(
+            HotkeyDrainQueue::<Test>::get().into_inner(),
+            hotkeys.to_vec()
+        );
+        assert_eq!(SubtensorModule::get_pending_hotkey_emission(&hotkeys[0]), 0);
+
+        let mut blocks: u32 = 1;
+        while !HotkeyDrainQueue::<Test>::get().is_empty() {
+            assert!(SubtensorModule::drain_scheduled_hotkeys(budget).all_lte(budget));
+            blocks += 1;
+            assert!(blocks < 10);
+        }
+        assert!(blocks > 2);
+        assert!(HotkeyDrainCursor::<Test>::get().is_none());
+
+        // Every nominator of the first hotkey got the same share, and nothing is created or lost.
+        let share = SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominators[0], &hotkeys[0]);
+        assert!(share > 1_000);
+        for nominator in nominators.iter() {
+            assert_eq!(
+                SubtensorModule::get_stake_for_coldkey_and_hotkey(nominator, &hotkeys[0]),
+                share
+            );
+        }
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkeys[0]),
+            5_000 + 100_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkeys[1]),
+            1_000 + 100_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake(),
+            total_stake_before + 200_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            total_issuance_before + 200_000
+        );
+    });
+}
+
+// Test that a new subnet retains its emission over epochs without weights, then distributes the
+// accumulated amount once the first weights are set.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_empty_epochs_retain_emission_until_weights -- --nocapture
+#[test]
+fn test_empty_epochs_retain_emission_until_weights() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let validator = U256::from(0);
+        let validator_coldkey = U256::from(3);
+        let miner = U256::from(1);
+        let miner_coldkey = U256::from(4);
+        add_network(netuid, 2, 0);
+        register_ok_neuron(netuid, validator, validator_coldkey, 100000);
+        register_ok_neuron(netuid, miner, miner_coldkey, 200000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &validator_coldkey,
+            &validator,
+            1000,
+        );
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_emission_values(&[netuid], vec![10]).unwrap();
+
+        // Three tempos without weights: the epochs run without draining the emission.
+        let mut blocks: u64 = 0;
+        let mut epochs: u64 = 0;
+        while epochs < 3 {
+            let block = next_block();
+            blocks += 1;
+            if SubtensorModule::get_last_mechanism_step_block(netuid) == block {
+                epochs += 1;
+            }
+        }
+        let retained: u64 = SubtensorModule::get_pending_emission(netuid);
+        assert_eq!(retained, 10 * blocks);
+        assert_eq!(SubtensorModule::get_blocks_since_last_step(netuid), 0);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&validator),
+            1000
+        );
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 1), 0);
+        assert_eq!(SubtensorModule::get_emission_for_uid(netuid, 0), 0);
+        // The validator is permitted to set the weights that end the empty epochs.
+        assert!(SubtensorModule::get_validator_permit_for_uid(netuid, 0));
+
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(validator),
+            netuid,
+            vec![1],
+            vec![u16::MAX],
+            SubtensorModule::get_weights_version_key(netuid)
+        ));
+
+        // The next epoch distributes everything accumulated since the subnet was created.
+        let mut drained: u64 = 0;
+        loop {
+            let pending: u64 = SubtensorModule::get_pending_emission(netuid);
+            let block = next_block();
+            if SubtensorModule::get_last_mechanism_step_block(netuid) == block {
+                drained = pending + 10;
+                break;
+            }
+        }
+        assert!(drained > retained);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+        let credited: u64 = EmissionLastTempo::<Test>::get(validator, netuid)
+            + EmissionLastTempo::<Test>::get(miner, netuid);
+        assert!(EmissionLastTempo::<Test>::get(miner, netuid) > 0);
+        assert!(is_within_tolerance(credited, drained, 2));
+    });
+}
+
+// Test that an epoch without weights writes a uniform incentive when the subnet asks for it.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_empty_epoch_uniform_incentive -- --nocapture
+#[test]
+fn test_empty_epoch_uniform_incentive() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, U256::from(0), U256::from(3), 100000);
+        register_ok_neuron(netuid, U256::from(1), U256::from(4), 200000);
+        assert!(SubtensorModule::epoch_is_empty(netuid));
+
+        SubtensorModule::write_empty_epoch(netuid);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 0), 0);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 1), 0);
+
+        SubtensorModule::set_empty_epoch_uniform_incentive(netuid, true);
+        SubtensorModule::write_empty_epoch(netuid);
+        assert_eq!(
+            SubtensorModule::get_incentive_for_uid(netuid, 0),
+            u16::MAX / 2
+        );
+        assert_eq!(
+            SubtensorModule::get_incentive_for_uid(netuid, 1),
+            u16::MAX / 2
+        );
+        assert_eq!(SubtensorModule::get_rank_for_uid(netuid, 0), 0);
+        assert_eq!(SubtensorModule::get_dividends_for_uid(netuid, 0), 0);
+    });
+}
+
+// Test that emission keeps accruing to stake while staking operations are paused.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_coinbase_continues_while_staking_paused -- --nocapture
+#[test]
+fn test_coinbase_continues_while_staking_paused() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        set_weight_on_miner(netuid);
+        SubtensorModule::set_emission_values(&[netuid], vec![1]).unwrap();
+
+        SubtensorModule::set_staking_operations_paused(true);
+
+        // The coinbase accrues and the drain credits the hotkey stake as usual.
+        next_block();
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 1);
+        next_block();
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey),
+            1000 + 2
+        );
+    });
+}
+
+#[test]
+fn test_epoch_history_disabled_by_default() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        assert_eq!(SubtensorModule::get_epoch_history_depth(netuid), 0);
+
+        SubtensorModule::record_epoch_history(netuid, 10, 1_000);
+        step_block(5);
+
+        assert_eq!(EpochHistory::<Test>::iter_prefix(netuid).count(), 0);
+        assert!(SubtensorModule::get_epoch_history(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_epoch_history_wraps_around() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+        SubtensorModule::set_epoch_history_depth(netuid, 2);
+
+        SubtensorModule::record_epoch_history(netuid, 10, 100);
+        SubtensorModule::record_epoch_history(netuid, 20, 200);
+        SubtensorModule::record_epoch_history(netuid, 30, 300);
+
+        // The third summary overwrote the first one.
+        assert_eq!(EpochHistory::<Test>::iter_prefix(netuid).count(), 2);
+        let history = SubtensorModule::get_epoch_history(netuid);
+        assert_eq!(
+            history
+                .iter()
+                .map(|summary| (summary.block, summary.total_emission))
+                .collect::<Vec<_>>(),
+            vec![(20, 200), (30, 300)]
+        );
+        assert_eq!(history[0].top_incentives, vec![(0, 0)]);
+
+        // Changing the depth clears the history.
+        SubtensorModule::set_epoch_history_depth(netuid, 3);
+        assert!(SubtensorModule::get_epoch_history(netuid).is_empty());
+    });
+}
+
+// Recomputes the epoch commitment leaves of the subnet the way an off-chain verifier would.
+fn recompute_epoch_leaves(netuid: u16) -> Vec<[u8; 32]> {
+    let incentive = SubtensorModule::get_incentive(netuid);
+    let emission = SubtensorModule::get_emission(netuid);
+    (0..SubtensorModule::get_subnetwork_n(netuid))
+        .map(|uid| {
+            epoch_leaf_hash(
+                uid,
+                &Keys::<Test>::get(netuid, uid),
+                incentive[uid as usize],
+                emission[uid as usize],
+            )
+        })
+        .collect()
+}
+
+// Test that the epoch commitment matches the root recomputed from the outcome of the epoch.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_epoch_commitment_matches_recomputed_root -- --nocapture
+#[test]
+fn test_epoch_commitment_matches_recomputed_root() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        register_ok_neuron(netuid, U256::from(10), U256::from(11), 200000);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &coldkey, &hotkey
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        set_weight_on_miner(netuid);
+        SubtensorModule::set_emission_values(&[netuid], vec![1_000]).unwrap();
+        assert!(SubtensorModule::get_epoch_commitment(netuid, 0).is_none());
+
+        next_block();
+        next_block();
+        next_block();
+        let epoch_index = EpochCommitmentNextIndex::<Test>::get(netuid).saturating_sub(1);
+        let commitment = SubtensorModule::get_epoch_commitment(netuid, epoch_index).unwrap();
+        assert_eq!(commitment.leaf_count, 3);
+        assert!(SubtensorModule::get_emission(netuid).iter().any(|e| *e > 0));
+
+        let leaves = recompute_epoch_leaves(netuid);
+        assert_eq!(epoch_commitment_root(&leaves), commitment.root);
+        for (uid, leaf) in leaves.iter().enumerate() {
+            let proof = epoch_leaf_proof(&leaves, uid as u16).unwrap();
+            assert!(verify_epoch_leaf(
+                &commitment.root,
+                leaf,
+                &proof,
+                uid as u16,
+                commitment.leaf_count
+            ));
+        }
+        assert!(epoch_leaf_proof(&leaves, 3).is_none());
+    });
+}
+
+// Test that proofs of a tampered leaf, or of a leaf at another index, do not verify.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_epoch_commitment_rejects_tampered_leaf -- --nocapture
+#[test]
+fn test_epoch_commitment_rejects_tampered_leaf() {
+    new_test_ext(1).execute_with(|| {
+        let leaves: Vec<[u8; 32]> = (0..5_u16)
+            .map(|uid| {
+                epoch_leaf_hash(
+                    uid,
+                    &U256::from(uid),
+                    uid.saturating_mul(100),
+                    u64::from(uid).saturating_mul(1_000),
+                )
+            })
+            .collect();
+        let root = epoch_commitment_root(&leaves);
+        let proof = epoch_leaf_proof(&leaves, 2).unwrap();
+        assert!(verify_epoch_leaf(&root, &leaves[2], &proof, 2, 5));
+
+        // The emission of the uid is changed.
+        let tampered = epoch_leaf_hash(2, &U256::from(2), 200, 2_001);
+        assert!(!verify_epoch_leaf(&root, &tampered, &proof, 2, 5));
+
+        // The hotkey of the uid is changed.
+        let tampered = epoch_leaf_hash(2, &U256::from(9), 200, 2_000);
+        assert!(!verify_epoch_leaf(&root, &tampered, &proof, 2, 5));
+
+        // The leaf is claimed for another uid.
+        assert!(!verify_epoch_leaf(&root, &leaves[2], &proof, 3, 5));
+        assert!(!verify_epoch_leaf(&root, &leaves[2], &proof, 5, 5));
+
+        // The proof is cut short or extended.
+        assert!(!verify_epoch_leaf(&root, &leaves[2], &proof[1..], 2, 5));
+        let mut extended = proof.clone();
+        extended.push(root);
+        assert!(!verify_epoch_leaf(&root, &leaves[2], &extended, 2, 5));
+
+        // The last leaf of an odd level is carried up without a sibling.
+        let proof = epoch_leaf_proof(&leaves, 4).unwrap();
+        assert_eq!(proof.len(), 1);
+        assert!(verify_epoch_leaf(&root, &leaves[4], &proof, 4, 5));
+        assert!(!verify_epoch_leaf(&root, &leaves[4], &proof, 4, 6));
+    });
+}
+
+// Test that only the last EPOCH_COMMITMENT_DEPTH epoch commitments of a subnet are kept.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_epoch_commitment_keeps_last_depth -- --nocapture
+#[test]
+fn test_epoch_commitment_keeps_last_depth() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+
+        for block in 0..=EPOCH_COMMITMENT_DEPTH {
+            SubtensorModule::record_epoch_commitment(netuid, block);
+        }
+
+        assert!(SubtensorModule::get_epoch_commitment(netuid, 0).is_none());
+        let last = SubtensorModule::get_epoch_commitment(netuid, EPOCH_COMMITMENT_DEPTH).unwrap();
+        assert_eq!(last.block, EPOCH_COMMITMENT_DEPTH);
+        assert_eq!(last.leaf_count, 1);
+        assert_eq!(
+            EpochCommitment::<Test>::iter_prefix(netuid).count() as u64,
+            EPOCH_COMMITMENT_DEPTH
+        );
+
+        // Removing the subnet drops its commitments.
+        SubtensorModule::remove_network(netuid);
+        assert_eq!(EpochCommitment::<Test>::iter_prefix(netuid).count(), 0);
+        assert_eq!(EpochCommitmentNextIndex::<Test>::get(netuid), 0);
+    });
+}
+
+// Test that scaling one of three subnets by 0.5 moves the remainder to the others in proportion,
+// keeping the total emission.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_emission_multiplier_conserves_total -- --nocapture
+#[test]
+fn test_emission_multiplier_conserves_total() {
+    new_test_ext(1).execute_with(|| {
+        let netuids: Vec<u16> = vec![1, 2, 3];
+        for netuid in netuids.iter() {
+            add_network(*netuid, 1, 0);
+        }
+        let shares = || {
+            vec![
+                I64F64::from_num(0.2),
+                I64F64::from_num(0.3),
+                I64F64::from_num(0.5),
+            ]
+        };
+        let to_emission = |shares: &[I64F64]| -> Vec<u64> {
+            shares
+                .iter()
+                .map(|share| share.saturating_mul(I64F64::from_num(1_000_000_000)))
+                .map(|emission| emission.round().to_num::<u64>())
+                .collect()
+        };
+
+        // Without multipliers the shares are left as is.
+        let mut unscaled = shares();
+        SubtensorModule::apply_emission_multipliers(&netuids, &mut unscaled);
+        assert_eq!(
+            to_emission(&unscaled),
+            vec![200_000_000, 300_000_000, 500_000_000]
+        );
+
+        // Subnet 1 gets half its share, and the other 100_000_000 goes 3:5 to subnets 2 and 3.
+        SubtensorModule::set_emission_multiplier(1, 500_000);
+        let mut scaled = shares();
+        SubtensorModule::apply_emission_multipliers(&netuids, &mut scaled);
+        let emission = to_emission(&scaled);
+        assert_eq!(emission, vec![100_000_000, 337_500_000, 562_500_000]);
+        assert_eq!(emission.iter().sum::<u64>(), 1_000_000_000);
+
+        // A boost raises the part of subnet 3 in the remainder, still keeping the total.
+        SubtensorModule::set_emission_multiplier(3, MAX_EMISSION_MULTIPLIER);
+        let mut boosted = shares();
+        SubtensorModule::apply_emission_multipliers(&netuids, &mut boosted);
+        let emission = to_emission(&boosted);
+        assert_eq!(emission, vec![100_000_000, 207_692_308, 692_307_692]);
+        assert_eq!(emission.iter().sum::<u64>(), 1_000_000_000);
+
+        // With every subnet scaled down, the scaled shares are normalized.
+        SubtensorModule::set_emission_multiplier(2, 0);
+        SubtensorModule::set_emission_multiplier(3, 0);
+        let mut penalized = shares();
+        SubtensorModule::apply_emission_multipliers(&netuids, &mut penalized);
+        assert_eq!(to_emission(&penalized), vec![1_000_000_000, 0, 0]);
+    });
+}
+
+// Test that the emission of a paused subnet is withheld and redistributed to the other subnets in
+// proportion to their emission, keeping the total.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_paused_subnet_emission_redistributed -- --nocapture
+#[test]
+fn test_paused_subnet_emission_redistributed() {
+    new_test_ext(1).execute_with(|| {
+        let netuids: Vec<u16> = vec![1, 2, 3];
+        for netuid in netuids.iter() {
+            add_network(*netuid, 1, 0);
+        }
+        SubtensorModule::set_emission_values(&netuids, vec![100, 300, 600]).unwrap();
+        let pending = || -> Vec<u64> {
+            netuids
+                .iter()
+                .map(|netuid| PendingEmission::<Test>::get(netuid))
+                .collect()
+        };
+
+        SubtensorModule::set_subnet_emission_paused(1, true);
+        SubtensorModule::accumulate_subnet_emission();
+
+        // The 100 of subnet 1 goes 1:2 to subnets 2 and 3, the rounding remainder to subnet 3.
+        assert_eq!(pending(), vec![0, 333, 667]);
+        assert_eq!(SubtensorModule::get_withheld_emission(1), 100);
+        assert_eq!(pending().iter().sum::<u64>(), 1_000);
+
+        // With every subnet paused, nothing is redistributed.
+        SubtensorModule::set_subnet_emission_paused(2, true);
+        SubtensorModule::set_subnet_emission_paused(3, true);
+        SubtensorModule::accumulate_subnet_emission();
+        assert_eq!(pending(), vec![0, 333, 667]);
+        assert_eq!(SubtensorModule::get_withheld_emission(1), 200);
+        assert_eq!(SubtensorModule::get_withheld_emission(2), 300);
+        assert_eq!(SubtensorModule::get_withheld_emission(3), 600);
+    });
+}
+
+// Test that the withheld emission adds up while the subnet is paused, and stays once it resumes.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_withheld_emission_accounting -- --nocapture
+#[test]
+fn test_withheld_emission_accounting() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_emission_values(&[netuid], vec![10]).unwrap();
+
+        SubtensorModule::set_subnet_emission_paused(netuid, true);
+        System::assert_last_event(Event::SubnetEmissionPausedSet(netuid, true).into());
+        for _ in 0..3 {
+            SubtensorModule::accumulate_subnet_emission();
+        }
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+        assert_eq!(SubtensorModule::get_withheld_emission(netuid), 30);
+
+        SubtensorModule::set_subnet_emission_paused(netuid, false);
+        SubtensorModule::accumulate_subnet_emission();
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 10);
+        assert_eq!(SubtensorModule::get_withheld_emission(netuid), 30);
+
+        // Burning drops the withheld emission without touching the pending one.
+        assert_ok!(SubtensorModule::burn_withheld_emission(netuid));
+        System::assert_last_event(Event::WithheldEmissionBurned { netuid, amount: 30 }.into());
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 10);
+        assert!(!WithheldEmission::<Test>::contains_key(netuid));
+    });
+}
+
+// Test that released emission goes through the next epoch of the subnet like any pending emission.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_release_withheld_emission_drains_in_epoch -- --nocapture
+#[test]
+fn test_release_withheld_emission_drains_in_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        set_weight_on_miner(netuid);
+        SubtensorModule::set_emission_values(&[netuid], vec![10]).unwrap();
+
+        // Paused, the epochs keep running without emission to drain.
+        SubtensorModule::set_subnet_emission_paused(netuid, true);
+        let mut epochs: u64 = 0;
+        while epochs < 2 {
+            let block = next_block();
+            if SubtensorModule::get_last_mechanism_step_block(netuid) == block {
+                epochs += 1;
+            }
+        }
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+        let withheld: u64 = SubtensorModule::get_withheld_emission(netuid);
+        assert!(withheld > 0);
+
+        assert_ok!(SubtensorModule::release_withheld_emission(netuid));
+        System::assert_last_event(
+            Event::WithheldEmissionReleased {
+                netuid,
+                amount: withheld,
+            }
+            .into(),
+        );
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), withheld);
+        assert_eq!(SubtensorModule::get_withheld_emission(netuid), 0);
+        assert_eq!(
+            SubtensorModule::release_withheld_emission(netuid),
+            Err(pallet_subtensor::Error::<Test>::NoWithheldEmission.into())
+        );
+
+        // The next epoch drains the released emission.
+        loop {
+            let block = next_block();
+            if SubtensorModule::get_last_mechanism_step_block(netuid) == block {
+                break;
+            }
+        }
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+    });
+}
@@ -165,3 +165,70 @@ fn test_registration_difficulty_adjustment() {
         assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 5833); // Difficulty unchanged
     });
 }
+
+#[test]
+fn test_registration_adjustment_history_keeps_last_adjustments() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        assert!(pallet_subtensor::RegistrationAdjustmentHistory::<Test>::get(netuid).is_empty());
+
+        step_block(3);
+        let history = pallet_subtensor::RegistrationAdjustmentHistory::<Test>::get(netuid);
+        assert_eq!(history.len(), 3);
+
+        // Adjustments are recorded in order and the history is truncated to the last 8.
+        step_block(10);
+        let current_block = SubtensorModule::get_current_block_as_u64();
+        let history = pallet_subtensor::RegistrationAdjustmentHistory::<Test>::get(netuid);
+        assert_eq!(
+            history.len(),
+            pallet_subtensor::REGISTRATION_ADJUSTMENT_HISTORY_LEN
+        );
+        assert_eq!(
+            history
+                .iter()
+                .map(|(block, _, _)| *block)
+                .collect::<Vec<_>>(),
+            (current_block - 7..=current_block).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            history.last(),
+            Some(&(
+                current_block,
+                SubtensorModule::get_burn_as_u64(netuid),
+                SubtensorModule::get_difficulty_as_u64(netuid)
+            ))
+        );
+    });
+}
+
+#[test]
+fn test_get_registration_info() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_adjustment_interval(netuid, 100);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 3);
+        assert!(SubtensorModule::get_registration_info(netuid + 1).is_none());
+
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+        step_block(2);
+
+        let info = SubtensorModule::get_registration_info(netuid).unwrap();
+        assert_eq!(info.burn.0, SubtensorModule::get_burn_as_u64(netuid));
+        assert_eq!(
+            info.difficulty.0,
+            SubtensorModule::get_difficulty_as_u64(netuid)
+        );
+        assert_eq!(info.registrations_this_interval.0, 1);
+        assert_eq!(info.target_registrations_per_interval.0, 3);
+        assert_eq!(
+            info.blocks_until_adjustment.0,
+            SubtensorModule::get_last_adjustment_block(netuid) + 100
+                - SubtensorModule::get_current_block_as_u64()
+        );
+        assert!(info.adjustment_history.is_empty());
+    });
+}
@@ -5,9 +5,10 @@
 )]
 
 use crate::mock::*;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, weights::Weight};
 use frame_system::Config;
 use pallet_subtensor::epoch::math::safe_exp;
+use pallet_subtensor::epoch::run_epoch::DENSE_EPOCH_MAX_N;
 use pallet_subtensor::*;
 use rand::{distributions::Uniform, rngs::StdRng, seq::SliceRandom, thread_rng, Rng, SeedableRng};
 use sp_core::U256;
@@ -838,6 +839,151 @@ fn test_512_graph_random_weights() {
     }
 }
 
+// Registers n neurons with random stake, registration blocks, weights of random density, and
+// weight update blocks, so that some weights are outdated and some neurons inactive.
+fn init_random_epoch_graph(netuid: u16, n: u16, seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    add_network(netuid, u16::MAX - 1, 0);
+    SubtensorModule::set_max_allowed_uids(netuid, n);
+    SubtensorModule::set_max_allowed_validators(netuid, rng.gen_range(1..=n));
+    SubtensorModule::set_activity_cutoff(netuid, 8);
+    SubtensorModule::set_liquid_alpha_enabled(netuid, rng.gen_bool(0.5));
+    for uid in 0..n {
+        let key = U256::from(uid);
+        SubtensorModule::append_neuron(netuid, &key, rng.gen_range(0..5));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &key,
+            &key,
+            rng.gen_range(0..1_000_000),
+        );
+    }
+    let density: u32 = rng.gen_range(1..=100);
+    for uid in 0..n {
+        let row: Vec<(u16, u16)> = (0..n)
+            .filter(|_| rng.gen_range(0..100) < density)
+            .map(|j| (j, rng.gen_range(0..=u16::MAX)))
+            .collect();
+        Weights::<Test>::insert(netuid, uid, WeightsRow::truncate_from(row));
+        SubtensorModule::set_last_update_for_uid(netuid, uid, rng.gen_range(0..10));
+    }
+    run_to_block(10);
+}
+
+#[allow(clippy::type_complexity)]
+fn run_epochs_and_collect(
+    netuid: u16,
+    epochs: u16,
+    sparse: bool,
+) -> (
+    Vec<Vec<(U256, u64, u64)>>,
+    Vec<Vec<u16>>,
+    Vec<u64>,
+    Vec<bool>,
+    Vec<(u16, Vec<(u16, u16)>)>,
+) {
+    let emissions = (0..epochs)
+        .map(|_| {
+            if sparse {
+                SubtensorModule::epoch(netuid, 1_000_000_000)
+            } else {
+                SubtensorModule::epoch_dense(netuid, 1_000_000_000)
+            }
+        })
+        .collect();
+    let mut bonds: Vec<(u16, Vec<(u16, u16)>)> = Bonds::<Test>::iter_prefix(netuid)
+        .map(|(uid, row)| (uid, row.into_inner()))
+        .collect();
+    bonds.sort();
+    (
+        emissions,
+        vec![
+            SubtensorModule::get_rank(netuid),
+            SubtensorModule::get_trust(netuid),
+            SubtensorModule::get_consensus(netuid),
+            SubtensorModule::get_incentive(netuid),
+            SubtensorModule::get_dividends(netuid),
+            SubtensorModule::get_pruning_score(netuid),
+            SubtensorModule::get_validator_trust(netuid),
+        ],
+        SubtensorModule::get_emission(netuid),
+        SubtensorModule::get_validator_permit(netuid),
+        bonds,
+    )
+}
+
+// Test that the sparse and dense epochs produce bit-identical emissions, consensus vectors, and
+// stored bonds over several epochs of random graphs.
+#[test]
+fn test_sparse_and_dense_epoch_identical_on_random_graphs() {
+    let netuid: u16 = 1;
+    for seed in 0..16 {
+        let n: u16 = StdRng::seed_from_u64(seed).gen_range(2..48);
+        let dense = new_test_ext(1).execute_with(|| {
+            init_random_epoch_graph(netuid, n, seed);
+            run_epochs_and_collect(netuid, 4, false)
+        });
+        let sparse = new_test_ext(1).execute_with(|| {
+            init_random_epoch_graph(netuid, n, seed);
+            run_epochs_and_collect(netuid, 4, true)
+        });
+        assert_eq!(dense, sparse, "seed {seed}, n {n}");
+    }
+}
+
+// Test that run_epoch takes the dense path for small or densely weighted subnets and the sparse
+// path for large subnets with few weights.
+#[test]
+fn test_run_epoch_selects_path_by_weight_density() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let n: u16 = DENSE_EPOCH_MAX_N + 1;
+        add_network(netuid, u16::MAX - 1, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        for uid in 0..n {
+            SubtensorModule::append_neuron(netuid, &U256::from(uid), 0);
+        }
+
+        // No weights on a large subnet: sparse.
+        assert!(SubtensorModule::epoch_runs_sparse(netuid));
+
+        // Every neuron weights a few others: still below the density threshold.
+        for uid in 0..n {
+            Weights::<Test>::insert(
+                netuid,
+                uid,
+                WeightsRow::truncate_from(vec![(0, 1), (1, 1), (2, 1)]),
+            );
+        }
+        assert!(SubtensorModule::epoch_runs_sparse(netuid));
+
+        // Every neuron weights a quarter of the subnet: dense.
+        for uid in 0..n {
+            Weights::<Test>::insert(
+                netuid,
+                uid,
+                WeightsRow::truncate_from((0..n / 4).map(|j| (j, 1)).collect::<Vec<_>>()),
+            );
+        }
+        assert!(!SubtensorModule::epoch_runs_sparse(netuid));
+
+        // A small subnet is always dense.
+        let small_netuid: u16 = 2;
+        add_network(small_netuid, u16::MAX - 1, 0);
+        for uid in 0..DENSE_EPOCH_MAX_N {
+            SubtensorModule::append_neuron(
+                small_netuid,
+                &U256::from(u32::MAX as u64 + uid as u64),
+                0,
+            );
+        }
+        assert!(!SubtensorModule::epoch_runs_sparse(small_netuid));
+
+        // The selected epoch returns an emission tuple per neuron.
+        let emission = SubtensorModule::run_epoch(netuid, 1_000_000_000);
+        assert_eq!(emission.len(), n as usize);
+    });
+}
+
 // Test an epoch on a graph with 4096 nodes, of which the first 256 are validators setting non-self weights, and the rest servers setting only self-weights.
 // #[test]
 #[allow(dead_code)]
@@ -2892,3 +3038,200 @@ fn assert_approx_eq_vec_of_vec(
         }
     }
 }
+
+// Registers two validators, the first holding three quarters of the stake, and two miners, with both
+// validators weighting the first miner, and runs the epoch that hands out the validator permits.
+fn init_validator_health_graph(netuid: u16) {
+    add_network(netuid, u16::MAX - 1, 0);
+    SubtensorModule::set_max_allowed_uids(netuid, 4);
+    SubtensorModule::set_max_allowed_validators(netuid, 2);
+    for (uid, stake) in [3_000, 1_000, 0, 0].into_iter().enumerate() {
+        let key = U256::from(uid);
+        SubtensorModule::append_neuron(netuid, &key, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&key, &key, stake);
+    }
+    for uid in 0..2 {
+        Weights::<Test>::insert(netuid, uid, WeightsRow::truncate_from(vec![(2, u16::MAX)]));
+        SubtensorModule::set_last_update_for_uid(netuid, uid, 1);
+    }
+    run_to_block(2);
+    SubtensorModule::run_epoch(netuid, 1_000_000_000);
+    assert_eq!(
+        SubtensorModule::get_validator_permit(netuid),
+        vec![true, true, false, false]
+    );
+}
+
+// The second validator turns to the miner no one else weights, losing its validator trust.
+fn run_epoch_with_dissenting_validator(netuid: u16) {
+    Weights::<Test>::insert(netuid, 1, WeightsRow::truncate_from(vec![(3, u16::MAX)]));
+    SubtensorModule::set_last_update_for_uid(netuid, 1, 2);
+    run_to_block(3);
+    SubtensorModule::run_epoch(netuid, 1_000_000_000);
+}
+
+fn validator_health_degraded_events() -> Vec<(u16, u16, u16, u16)> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::SubtensorModule(Event::ValidatorHealthDegraded {
+                netuid,
+                uid,
+                old,
+                new,
+            }) => Some((netuid, uid, old, new)),
+            _ => None,
+        })
+        .collect()
+}
+
+// Test that a drop in validator trust above the threshold of the subnet is reported once, for the
+// validator it happened to.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test epoch -- test_validator_health_degraded --exact --nocapture
+#[test]
+fn test_validator_health_degraded() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        init_validator_health_graph(netuid);
+        SubtensorModule::set_validator_health_drop_threshold(netuid, 1_000);
+        let validator_trust_before = SubtensorModule::get_validator_trust(netuid);
+        assert!(validator_trust_before[1] > 1_000);
+
+        System::reset_events();
+        run_epoch_with_dissenting_validator(netuid);
+
+        let validator_trust_after = SubtensorModule::get_validator_trust(netuid);
+        assert!(validator_trust_before[1] - validator_trust_after[1] > 1_000);
+        assert_eq!(
+            validator_health_degraded_events(),
+            vec![(
+                netuid,
+                1,
+                validator_trust_before[1],
+                validator_trust_after[1]
+            )]
+        );
+    });
+}
+
+// Test that without a threshold set the same drop is not reported.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test epoch -- test_validator_health_degraded_disabled_by_default --exact --nocapture
+#[test]
+fn test_validator_health_degraded_disabled_by_default() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        init_validator_health_graph(netuid);
+        assert_eq!(
+            SubtensorModule::get_validator_health_drop_threshold(netuid),
+            0
+        );
+        let validator_trust_before = SubtensorModule::get_validator_trust(netuid);
+
+        System::reset_events();
+        run_epoch_with_dissenting_validator(netuid);
+
+        assert!(
+            validator_trust_before[1] - SubtensorModule::get_validator_trust(netuid)[1] > 1_000
+        );
+        assert!(validator_health_degraded_events().is_empty());
+    });
+}
+
+// Test that the epoch runs the same on a weights row truncated by the bounding migration as on
+// the same row set directly.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test epoch -- test_epoch_on_truncated_weights_row --exact --nocapture
+#[test]
+fn test_epoch_on_truncated_weights_row() {
+    new_test_ext(1).execute_with(|| {
+        init_validator_health_graph(1);
+        init_validator_health_graph(2);
+        MaxWeightsLimitLen::<Test>::insert(1, 1);
+        Weights::<Test>::insert(
+            1,
+            0,
+            WeightsRow::truncate_from(vec![(1, 10), (2, u16::MAX), (3, 20)]),
+        );
+        Weights::<Test>::insert(2, 0, WeightsRow::truncate_from(vec![(2, u16::MAX)]));
+
+        pallet_subtensor::migrations::migrate_bound_weights::migrate_bound_weights::<Test>(
+            Weight::MAX,
+        );
+        assert_eq!(Weights::<Test>::get(1, 0), Weights::<Test>::get(2, 0));
+
+        run_to_block(3);
+        SubtensorModule::run_epoch(1, 1_000_000_000);
+        SubtensorModule::run_epoch(2, 1_000_000_000);
+        assert_eq!(
+            SubtensorModule::get_incentive(1),
+            SubtensorModule::get_incentive(2)
+        );
+        assert_eq!(
+            SubtensorModule::get_dividends(1),
+            SubtensorModule::get_dividends(2)
+        );
+        assert_eq!(Bonds::<Test>::get(1, 0), Bonds::<Test>::get(2, 0));
+    });
+}
+
+// Test that bonds to uids past the subnet size are dropped when the bonds are read.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test epoch -- test_get_bonds_drops_out_of_range_uids --exact --nocapture
+#[test]
+fn test_get_bonds_drops_out_of_range_uids() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, u16::MAX - 1, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        for uid in 0..3 {
+            SubtensorModule::append_neuron(netuid, &U256::from(uid), 0);
+        }
+        Bonds::<Test>::insert(
+            netuid,
+            0,
+            WeightsRow::truncate_from(vec![(1, 5), (3, 7), (u16::MAX, 9)]),
+        );
+        Bonds::<Test>::insert(netuid, 3, WeightsRow::truncate_from(vec![(0, 11)]));
+
+        let bonds = SubtensorModule::get_bonds_sparse(netuid);
+        assert_eq!(bonds.len(), 3);
+        assert_eq!(bonds[0], vec![(1, I32F32::from_num(5))]);
+        assert!(bonds[1].is_empty() && bonds[2].is_empty());
+
+        let bonds = SubtensorModule::get_bonds(netuid);
+        assert_eq!(bonds.len(), 3);
+        assert!(bonds.iter().all(|row| row.len() == 3));
+        assert_eq!(bonds[0][1], I32F32::from_num(5));
+    });
+}
+
+// Test that both epoch paths run on per-uid storage that is out of sync with the subnet size,
+// and only pay out and write state for the neurons of the subnet.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test epoch -- test_epoch_on_out_of_sync_storage --exact --nocapture
+#[test]
+fn test_epoch_on_out_of_sync_storage() {
+    let netuid: u16 = 1;
+    let n: u16 = 8;
+    for sparse in [false, true] {
+        new_test_ext(1).execute_with(|| {
+            init_random_epoch_graph(netuid, n, 3);
+            LastUpdate::<Test>::insert(netuid, vec![0; 3]);
+            ValidatorPermit::<Test>::insert(netuid, vec![true; 20]);
+            Keys::<Test>::insert(netuid, 20, U256::from(100));
+            Bonds::<Test>::insert(netuid, 0, WeightsRow::truncate_from(vec![(30, 1)]));
+
+            let emission = if sparse {
+                SubtensorModule::epoch(netuid, 1_000_000_000)
+            } else {
+                SubtensorModule::epoch_dense(netuid, 1_000_000_000)
+            };
+            assert!(emission
+                .iter()
+                .all(|(hotkey, _, _)| *hotkey != U256::from(100)));
+            assert_eq!(SubtensorModule::get_emission(netuid).len(), n as usize);
+            assert_eq!(
+                SubtensorModule::get_validator_permit(netuid).len(),
+                n as usize
+            );
+            assert!(Bonds::<Test>::get(netuid, 0).iter().all(|(j, _)| *j < n));
+        });
+    }
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "epoch-fuzz")]
+#![allow(
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::unwrap_used
+)]
+
+// Panic-safety harness for the epoch: corrupts the storage the epoch reads in every way a
+// migration, a shrink or a pruning bug could leave it, and checks both epoch paths still run.
+//
+// Run with: SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test -p pallet-subtensor --features=epoch-fuzz --test epoch_fuzz
+
+mod mock;
+use crate::mock::*;
+use pallet_subtensor::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use sp_core::U256;
+
+// Seeds run per epoch path, overridable through EPOCH_FUZZ_SEEDS for longer local runs.
+fn fuzz_seeds() -> u64 {
+    std::env::var("EPOCH_FUZZ_SEEDS")
+        .ok()
+        .and_then(|seeds| seeds.parse().ok())
+        .unwrap_or(256)
+}
+
+// Returns a uid that is in range most of the time and past n, up to the maximum uid, otherwise.
+fn fuzz_uid(rng: &mut StdRng, n: u16) -> u16 {
+    if rng.gen_bool(0.8) {
+        rng.gen_range(0..n)
+    } else {
+        rng.gen_range(n..=u16::MAX)
+    }
+}
+
+// Registers a random subnet, then writes weights, bonds, last updates, validator permits and keys
+// that are out of sync with its size.
+fn init_adversarial_epoch_state(netuid: u16, seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let n: u16 = rng.gen_range(1..32);
+    add_network(netuid, u16::MAX - 1, 0);
+    SubtensorModule::set_max_allowed_uids(netuid, n);
+    SubtensorModule::set_max_allowed_validators(netuid, rng.gen_range(1..=n));
+    SubtensorModule::set_activity_cutoff(netuid, rng.gen_range(0..16));
+    SubtensorModule::set_liquid_alpha_enabled(netuid, rng.gen_bool(0.5));
+    for uid in 0..n {
+        let key = U256::from(uid);
+        SubtensorModule::append_neuron(netuid, &key, rng.gen_range(0..5));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &key,
+            &key,
+            rng.gen_range(0..=1_000_000),
+        );
+    }
+
+    // Rows and columns for uids past n, as left behind by a subnet that shrank.
+    for _ in 0..rng.gen_range(0..2 * n) {
+        let row: Vec<(u16, u16)> = (0..rng.gen_range(0..8))
+            .map(|_| (fuzz_uid(&mut rng, n), rng.gen_range(0..=u16::MAX)))
+            .collect();
+        Weights::<Test>::insert(
+            netuid,
+            fuzz_uid(&mut rng, n),
+            WeightsRow::truncate_from(row),
+        );
+    }
+    for _ in 0..rng.gen_range(0..2 * n) {
+        let row: Vec<(u16, u16)> = (0..rng.gen_range(0..8))
+            .map(|_| (fuzz_uid(&mut rng, n), rng.gen_range(0..=u16::MAX)))
+            .collect();
+        Bonds::<Test>::insert(
+            netuid,
+            fuzz_uid(&mut rng, n),
+            WeightsRow::truncate_from(row),
+        );
+    }
+
+    // Per-uid vectors that are shorter or longer than the subnet.
+    let last_update: Vec<u64> = (0..rng.gen_range(0..2 * n))
+        .map(|_| rng.gen_range(0..=u64::MAX))
+        .collect();
+    LastUpdate::<Test>::insert(netuid, last_update);
+    let validator_permit: Vec<bool> = (0..rng.gen_range(0..2 * n))
+        .map(|_| rng.gen_bool(0.5))
+        .collect();
+    ValidatorPermit::<Test>::insert(netuid, validator_permit);
+
+    // Neurons without a hotkey, and hotkeys without a neuron.
+    for _ in 0..rng.gen_range(0..4) {
+        Keys::<Test>::remove(netuid, rng.gen_range(0..n));
+    }
+    for _ in 0..rng.gen_range(0..4) {
+        let key = U256::from(rng.gen_range(n..=u16::MAX));
+        Keys::<Test>::insert(netuid, fuzz_uid(&mut rng, n), key);
+    }
+
+    run_to_block(rng.gen_range(1..32));
+}
+
+// Test that the sparse epoch never panics on adversarial storage.
+#[test]
+fn fuzz_epoch_adversarial_storage() {
+    let netuid: u16 = 1;
+    for seed in 0..fuzz_seeds() {
+        new_test_ext(1).execute_with(|| {
+            init_adversarial_epoch_state(netuid, seed);
+            for _ in 0..3 {
+                let emission = SubtensorModule::epoch(netuid, 1_000_000_000);
+                assert!(
+                    emission.len() <= SubtensorModule::get_subnetwork_n(netuid) as usize,
+                    "seed {seed}"
+                );
+            }
+        });
+    }
+}
+
+// Test that the dense epoch never panics on adversarial storage.
+#[test]
+fn fuzz_epoch_dense_adversarial_storage() {
+    let netuid: u16 = 1;
+    for seed in 0..fuzz_seeds() {
+        new_test_ext(1).execute_with(|| {
+            init_adversarial_epoch_state(netuid, seed);
+            for _ in 0..3 {
+                let emission = SubtensorModule::epoch_dense(netuid, 1_000_000_000);
+                assert!(
+                    emission.len() <= SubtensorModule::get_subnetwork_n(netuid) as usize,
+                    "seed {seed}"
+                );
+            }
+        });
+    }
+}
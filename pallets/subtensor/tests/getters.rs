@@ -0,0 +1,159 @@
+#![allow(clippy::unwrap_used)]
+
+use crate::mock::*;
+use frame_support::traits::StorageVersion;
+use pallet_subtensor::rpc_info::chain_constants::CHAIN_CONSTANTS_VERSION;
+use pallet_subtensor::SubnetIdentity;
+use sp_core::U256;
+
+mod mock;
+
+/********************************************
+    getters consumed by the runtime APIs and RPC
+*********************************************/
+
+// Pins the name and signature of the storage getters the runtime APIs and RPC read through, so a
+// renamed storage item or getter fails the build here instead of surfacing at runtime.
+#[test]
+fn test_storage_getters_used_by_rpc() {
+    let _: fn() -> u16 = SubtensorModule::get_num_subnets;
+    let _: fn() -> u32 = SubtensorModule::get_total_neuron_count;
+    let _: fn() -> u64 = SubtensorModule::get_total_issuance;
+    let _: fn() -> u64 = SubtensorModule::get_total_stake;
+    let _: fn() -> u64 = SubtensorModule::get_last_block_emission;
+    let _: fn() -> u64 = SubtensorModule::get_nominator_min_required_stake;
+    let _: fn() -> u64 = SubtensorModule::get_network_lock_cost;
+    let _: fn() -> u64 = SubtensorModule::get_network_registration_allowed_block;
+    let _: fn() -> u64 = SubtensorModule::get_key_swap_cost;
+    let _: fn() -> u64 = SubtensorModule::get_network_rate_limit;
+    let _: fn(u16) -> U256 = SubtensorModule::get_subnet_owner;
+    let _: fn(u16) -> Option<SubnetIdentity> = SubtensorModule::get_subnet_identity;
+    let _: fn(u16) -> u16 = SubtensorModule::get_network_modality;
+    let _: fn(u16) -> u16 = SubtensorModule::get_subnetwork_n;
+    let _: fn(u16) -> Vec<u16> = SubtensorModule::get_pruning_scores;
+    let _: fn(u16) -> Vec<u16> = SubtensorModule::get_trust;
+    let _: fn(u16) -> Vec<u16> = SubtensorModule::get_consensus;
+    let _: fn(u16) -> Vec<u16> = SubtensorModule::get_validator_trust;
+    let _: fn(u16) -> u16 = SubtensorModule::get_validator_health_drop_threshold;
+    let _: fn(u16, u16) -> Option<u16> = SubtensorModule::get_pruning_score_for_neuron;
+    let _: fn(&U256) -> Vec<u16> = SubtensorModule::get_owned_subnets;
+    let _: fn(&U256) -> Vec<U256> = SubtensorModule::get_owned_hotkeys;
+    let _: fn(&U256) -> Vec<U256> = SubtensorModule::get_all_staked_hotkeys;
+    let _: fn(&U256) -> U256 = SubtensorModule::get_owning_coldkey_for_hotkey;
+    let _: fn(&U256) -> u16 = SubtensorModule::get_hotkey_take;
+    let _: fn(&U256) -> u64 = SubtensorModule::get_total_stake_for_hotkey;
+    let _: fn(&U256) -> u64 = SubtensorModule::get_pending_hotkey_emission;
+    let _: fn(&U256, &U256) -> u64 = SubtensorModule::get_stake_for_coldkey_and_hotkey;
+    let _: fn(&U256, u16) -> u64 = SubtensorModule::get_emission_last_tempo;
+    let _: fn(&U256, u16) -> u32 = SubtensorModule::get_nominator_count;
+}
+
+// Every entry point the runtime API implementations call.
+#[test]
+fn test_runtime_api_entry_points() {
+    let _ = SubtensorModule::get_delegates;
+    let _ = SubtensorModule::get_delegate;
+    let _ = SubtensorModule::get_delegated;
+    let _ = SubtensorModule::get_neurons_lite;
+    let _ = SubtensorModule::get_neuron_lite;
+    let _ = SubtensorModule::get_neurons;
+    let _ = SubtensorModule::get_neuron;
+    let _ = SubtensorModule::get_weight_setting_status_for_account;
+    let _ = SubtensorModule::get_hotkey_rate_limit_status_for_account;
+    let _ = SubtensorModule::get_hotkey_registrations_for_account;
+    let _ = SubtensorModule::get_netuids_for_hotkey_account;
+    let _ = SubtensorModule::get_subnet_info;
+    let _ = SubtensorModule::get_subnets_info;
+    let _ = SubtensorModule::get_subnet_info_v2;
+    let _ = SubtensorModule::get_subnets_info_v2;
+    let _ = SubtensorModule::get_subnet_hyperparams;
+    let _ = SubtensorModule::get_epoch_history;
+    let _ = SubtensorModule::get_epoch_commitment;
+    let _ = SubtensorModule::get_subnet_netuids;
+    let _ = SubtensorModule::get_subnets_count;
+    let _ = SubtensorModule::if_subnet_exist;
+    let _ = SubtensorModule::validate_subnet_params;
+    let _ = SubtensorModule::get_registration_info;
+    let _ = SubtensorModule::get_stake_info_for_coldkey;
+    let _ = SubtensorModule::get_stake_info_for_coldkeys;
+    let _ = SubtensorModule::simulate_remove_stake_for_accounts;
+    let _ = SubtensorModule::simulate_add_stake_for_accounts;
+    let _ = SubtensorModule::get_top_stakes_on_subnet;
+    let _ = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_netuid_for_accounts;
+    let _ = SubtensorModule::get_nominators_for_hotkey_account;
+    let _ = SubtensorModule::get_nominator_count_for_account;
+    let _ = SubtensorModule::get_hotkey_stake_breakdown_for_account;
+    let _ = SubtensorModule::get_stake_rate_limits_for_accounts;
+    let _ = SubtensorModule::get_vested_balance_for_account;
+    let _ = SubtensorModule::get_attestation_for_account;
+    let _ = SubtensorModule::get_network_stats;
+    let _ = SubtensorModule::get_chain_constants;
+    let _ = SubtensorModule::get_emission_info_for_hotkey;
+    let _ = SubtensorModule::get_pending_emission_info;
+    let _ = SubtensorModule::get_pending_hotkey_emission_info_for_hotkey;
+    let _ = SubtensorModule::get_owned_hotkeys_paged_for_coldkey;
+    let _ = SubtensorModule::get_staking_hotkeys_paged_for_coldkey;
+    let _ = SubtensorModule::get_owned_hotkeys_on_subnet_paged_for_coldkey;
+    let _ = SubtensorModule::get_staking_hotkeys_on_subnet_paged_for_coldkey;
+}
+
+#[test]
+fn test_get_owned_subnets() {
+    new_test_ext(1).execute_with(|| {
+        let owner = U256::from(1);
+        add_network(1, 13, 0);
+        add_network(2, 13, 0);
+        add_network(3, 13, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(1, owner);
+        pallet_subtensor::SubnetOwner::<Test>::insert(3, owner);
+
+        let mut owned = SubtensorModule::get_owned_subnets(&owner);
+        owned.sort();
+        assert_eq!(owned, vec![1, 3]);
+        assert!(SubtensorModule::get_owned_subnets(&U256::from(2)).is_empty());
+    });
+}
+
+#[test]
+fn test_chain_constants_match_getters() {
+    new_test_ext(1).execute_with(|| {
+        StorageVersion::new(7).put::<SubtensorModule>();
+        SubtensorModule::set_network_rate_limit(42);
+        SubtensorModule::set_nominator_min_required_stake(1_000);
+        SubtensorModule::set_default_delegate_take(SubtensorModule::get_min_delegate_take());
+
+        let constants = SubtensorModule::get_chain_constants();
+        assert_eq!(constants.version.0, CHAIN_CONSTANTS_VERSION);
+        assert_eq!(
+            constants.key_swap_cost.0,
+            SubtensorModule::get_key_swap_cost()
+        );
+        assert_eq!(
+            constants.min_delegate_take.0,
+            SubtensorModule::get_min_delegate_take()
+        );
+        assert_eq!(
+            constants.max_delegate_take.0,
+            SubtensorModule::get_max_delegate_take()
+        );
+        assert_eq!(
+            constants.default_delegate_take.0,
+            SubtensorModule::get_default_delegate_take()
+        );
+        assert_eq!(
+            constants.target_stakes_per_interval.0,
+            SubtensorModule::get_target_stakes_per_interval()
+        );
+        assert_eq!(
+            constants.tx_rate_limit.0,
+            SubtensorModule::get_tx_rate_limit()
+        );
+        assert_eq!(constants.network_rate_limit.0, 42);
+        assert_eq!(constants.nominator_min_required_stake.0, 1_000);
+        assert_eq!(
+            constants.stake_threshold.0,
+            SubtensorModule::get_weights_min_stake()
+        );
+        assert_eq!(constants.storage_version.0, 7);
+    });
+}
@@ -0,0 +1,130 @@
+#![allow(clippy::indexing_slicing)]
+#![allow(clippy::arithmetic_side_effects)]
+
+mod mock;
+use codec::{Compact, Encode};
+use frame_support::assert_ok;
+use mock::*;
+use pallet_subtensor::rpc_info::hotkeys_info::MAX_HOTKEYS_PAGE_SIZE;
+use sp_core::U256;
+
+// Gives `coldkey` `count` owned hotkeys, staking `stake` to each.
+fn setup_owned_hotkeys(coldkey: U256, count: u32, stake: u64) -> Vec<U256> {
+    let hotkeys: Vec<U256> = (0..count).map(|i| U256::from(1_000 + i)).collect();
+    for hotkey in hotkeys.iter() {
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &coldkey, hotkey
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, hotkey, stake);
+    }
+    hotkeys
+}
+
+#[test]
+fn test_get_owned_hotkeys_paged_exact_page_boundary() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkeys = setup_owned_hotkeys(coldkey, 20, 1);
+
+        // Two full pages and nothing past the end.
+        let first = SubtensorModule::get_owned_hotkeys_paged(&coldkey, 0, 10);
+        assert_eq!(first.hotkeys, hotkeys[..10].to_vec());
+        assert_eq!(first.total, Compact(20));
+        let second = SubtensorModule::get_owned_hotkeys_paged(&coldkey, 10, 10);
+        assert_eq!(second.hotkeys, hotkeys[10..].to_vec());
+        assert_eq!(second.total, Compact(20));
+        let third = SubtensorModule::get_owned_hotkeys_paged(&coldkey, 20, 10);
+        assert!(third.hotkeys.is_empty());
+        assert_eq!(third.total, Compact(20));
+    });
+}
+
+#[test]
+fn test_get_staking_hotkeys_paged_exact_page_boundary() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkeys = setup_owned_hotkeys(coldkey, 6, 1);
+
+        let first = SubtensorModule::get_staking_hotkeys_paged(&coldkey, 0, 3);
+        assert_eq!(first.hotkeys, hotkeys[..3].to_vec());
+        assert_eq!(first.total, Compact(6));
+        let second = SubtensorModule::get_staking_hotkeys_paged(&coldkey, 3, 3);
+        assert_eq!(second.hotkeys, hotkeys[3..].to_vec());
+        assert!(SubtensorModule::get_staking_hotkeys_paged(&coldkey, 6, 3)
+            .hotkeys
+            .is_empty());
+    });
+}
+
+#[test]
+fn test_get_owned_hotkeys_paged_limit_capped() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkeys = setup_owned_hotkeys(coldkey, MAX_HOTKEYS_PAGE_SIZE + 1, 1);
+
+        let page = SubtensorModule::get_owned_hotkeys_paged(&coldkey, 0, u32::MAX);
+        assert_eq!(page.hotkeys.len(), MAX_HOTKEYS_PAGE_SIZE as usize);
+        assert_eq!(page.total, Compact(MAX_HOTKEYS_PAGE_SIZE + 1));
+
+        let last = SubtensorModule::get_owned_hotkeys_paged(&coldkey, MAX_HOTKEYS_PAGE_SIZE, 10);
+        assert_eq!(
+            last.hotkeys,
+            hotkeys[MAX_HOTKEYS_PAGE_SIZE as usize..].to_vec()
+        );
+    });
+}
+
+#[test]
+fn test_get_hotkeys_on_subnet_paged_filters() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let other_coldkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+
+        // Registered with stake, registered without stake, and staked but not registered.
+        let staked = U256::from(10);
+        let unstaked = U256::from(11);
+        let unregistered = U256::from(12);
+        register_ok_neuron(netuid, staked, coldkey, 0);
+        register_ok_neuron(netuid, unstaked, coldkey, 100_000);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &coldkey,
+            &unregistered
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &staked, 1);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &unregistered, 1);
+
+        let owned = SubtensorModule::get_owned_hotkeys_on_subnet_paged(&coldkey, netuid, 0, 10);
+        assert_eq!(owned.hotkeys, vec![staked]);
+        assert_eq!(owned.total, Compact(1));
+
+        // Only the stake of the queried coldkey counts for staking hotkeys.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&other_coldkey, &unstaked, 1);
+        let owned = SubtensorModule::get_owned_hotkeys_on_subnet_paged(&coldkey, netuid, 0, 10);
+        assert_eq!(owned.hotkeys, vec![staked, unstaked]);
+        let staking = SubtensorModule::get_staking_hotkeys_on_subnet_paged(&coldkey, netuid, 0, 10);
+        assert_eq!(staking.hotkeys, vec![staked]);
+        let staking =
+            SubtensorModule::get_staking_hotkeys_on_subnet_paged(&other_coldkey, netuid, 0, 10);
+        assert_eq!(staking.hotkeys, vec![unstaked]);
+    });
+}
+
+#[test]
+fn test_get_owned_hotkeys_paged_for_coldkey_invalid_account() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        setup_owned_hotkeys(coldkey, 2, 1);
+
+        assert!(
+            SubtensorModule::get_owned_hotkeys_paged_for_coldkey(vec![1, 2, 3], 0, 10).is_none()
+        );
+        let page = SubtensorModule::get_owned_hotkeys_paged_for_coldkey(coldkey.encode(), 0, 10)
+            .expect("valid coldkey");
+        assert_eq!(page.total, Compact(2));
+    });
+}
@@ -246,6 +246,103 @@ fn test_check_vec_max_limited() {
     assert!(check_vec_max_limited(&vector, max_limit));
 }
 
+// Golden vectors of `normalize_weight_values`, as (values, max_weight_limit, stored weights or
+// None when over the limit), for clients to check their implementation against.
+#[test]
+fn test_normalize_weight_values_golden() {
+    let golden: Vec<(Vec<u16>, u16, Option<Vec<u16>>)> = vec![
+        (vec![], 0, Some(vec![])),
+        (vec![0], 0, Some(vec![0])),
+        (vec![0, 0, 0], 0, Some(vec![0, 0, 0])),
+        (vec![1], 65535, Some(vec![65535])),
+        (vec![1], 65534, None),
+        (vec![65535], 65535, Some(vec![65535])),
+        (vec![65535], 0, None),
+        (vec![0, 65535], 65535, Some(vec![0, 65535])),
+        (vec![1, 2], 65535, Some(vec![32768, 65535])),
+        (vec![16385, 32770], 65535, Some(vec![32768, 65535])),
+        (vec![32767, 65534], 65535, Some(vec![32768, 65535])),
+        (vec![1, 3], 65535, Some(vec![21845, 65535])),
+        (vec![2, 3], 65535, Some(vec![43690, 65535])),
+        (vec![1, 65535], 65535, Some(vec![1, 65535])),
+        (vec![65534, 65535], 65535, Some(vec![65534, 65535])),
+        (vec![10, 10], 65535, Some(vec![65535, 65535])),
+        (vec![5, 5], 32767, None),
+        (vec![5, 5], 32768, Some(vec![65535, 65535])),
+        (vec![1, 1, 1], 21844, None),
+        (vec![1, 1, 1], 21845, Some(vec![65535, 65535, 65535])),
+        (vec![0, 65535, 65535, 65535], 21844, None),
+        (
+            vec![0, 65535, 65535, 65535],
+            21845,
+            Some(vec![0, 65535, 65535, 65535]),
+        ),
+        (vec![100, 200, 300], 32767, None),
+        (vec![100, 200, 300], 32768, Some(vec![21845, 43690, 65535])),
+        (vec![3, 1, 4, 1, 5, 9, 2, 6], 19026, None),
+        (
+            vec![3, 1, 4, 1, 5, 9, 2, 6],
+            19027,
+            Some(vec![21845, 7282, 29127, 7282, 36408, 65535, 14563, 43690]),
+        ),
+    ];
+    for (values, max_weight_limit, expected) in golden {
+        assert_eq!(
+            normalize_weight_values(&values, max_weight_limit),
+            expected,
+            "values: {:?} max_weight_limit: {}",
+            values,
+            max_weight_limit
+        );
+    }
+}
+
+// Checks `normalize_weight_values` on every largest value against the exact rational definition:
+// values round half up, and the limit boundary is the lowest limit the largest share is within.
+#[test]
+fn test_normalize_weight_values_exhaustive() {
+    let u16_max: u128 = u16::MAX as u128;
+    for max in 1..=u16::MAX {
+        for value in [0, 1, max / 2, max / 2 + 1, max - 1, max] {
+            let numerator: u128 = value as u128 * u16_max;
+            let rounded: u128 =
+                numerator / max as u128 + u128::from(2 * (numerator % max as u128) >= max as u128);
+            let normalized: Vec<u16> = vec![rounded as u16, u16::MAX];
+            assert_eq!(
+                normalize_weight_values(&[value, max], u16::MAX),
+                Some(normalized.clone())
+            );
+
+            let sum: u128 = rounded + u16_max;
+            let lowest_limit: u128 = (u16_max * u16_max).div_ceil(sum);
+            assert_eq!(
+                normalize_weight_values(&[value, max], lowest_limit as u16),
+                Some(normalized)
+            );
+            assert_eq!(
+                normalize_weight_values(&[value, max], lowest_limit as u16 - 1),
+                None
+            );
+        }
+    }
+}
+
+#[test]
+fn test_normalize_weight_values_sum_overflowing_u32() {
+    // The values sum to more than u32::MAX, each having a share of 1 / 65538.
+    let values: Vec<u16> = vec![u16::MAX; 65538];
+    assert!(values.iter().map(|value| *value as u64).sum::<u64>() > u32::MAX as u64);
+    assert_eq!(normalize_weight_values(&values, 1), Some(values.clone()));
+    assert_eq!(normalize_weight_values(&values, 0), None);
+
+    // Scaled down values are scaled up to the same weights.
+    let values: Vec<u16> = vec![1; 65538];
+    assert_eq!(
+        normalize_weight_values(&values, 1),
+        Some(vec![u16::MAX; 65538])
+    );
+}
+
 #[test]
 fn test_math_fixed_overflow() {
     let max_32: I32F32 = I32F32::max_value();
@@ -2347,3 +2444,139 @@ fn test_quantile() {
     let result = quantile(&data, 1.0);
     assert_eq!(result, I32F32::from_num(5.0));
 }
+
+/********************************************
+    properties checked over random inputs
+*********************************************/
+
+// Random vector of `n` values in [0, max).
+fn random_vec(rng: &mut impl Rng, n: usize, max: f32) -> Vec<I32F32> {
+    (0..n).map(|_| fixed(rng.gen_range(0.0..max))).collect()
+}
+
+#[test]
+fn test_math_property_weighted_median_col_within_min_max() {
+    let mut rng = thread_rng();
+    let zero: I32F32 = fixed(0.);
+    for _ in 0..200 {
+        let rows: usize = rng.gen_range(1..16);
+        let columns: usize = rng.gen_range(1..16);
+        let mut stake: Vec<I32F32> = random_vec(&mut rng, rows, 1.);
+        // Some validators hold no stake and do not take part in the median.
+        stake
+            .iter_mut()
+            .for_each(|s| *s = if rng.gen_bool(0.2) { zero } else { *s });
+        let score: Vec<Vec<I32F32>> = (0..rows)
+            .map(|_| random_vec(&mut rng, columns, 1.))
+            .collect();
+        let majority: I32F32 = fixed(rng.gen_range(0.5..1.0));
+
+        let median: Vec<I32F32> = weighted_median_col(&stake, &score, majority);
+        assert_eq!(median.len(), columns);
+        for (c, median_c) in median.iter().enumerate() {
+            let staked_scores: Vec<I32F32> = (0..rows)
+                .filter(|r| stake[*r] > zero)
+                .map(|r| score[r][c])
+                .collect();
+            match (staked_scores.iter().min(), staked_scores.iter().max()) {
+                (Some(min), Some(max)) => assert!(min <= median_c && median_c <= max),
+                _ => assert_eq!(*median_c, zero),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_math_property_normalize_sums_to_one() {
+    let mut rng = thread_rng();
+    let one: I32F32 = fixed(1.);
+    for _ in 0..200 {
+        let n: usize = rng.gen_range(1..64);
+        let mut x: Vec<I32F32> = random_vec(&mut rng, n, 1_000.);
+        let nonzero: usize = rng.gen_range(0..n);
+        x[nonzero] += one;
+        // Each element is rounded down by at most one unit of the fixed point type.
+        let epsilon: I32F32 = I32F32::from_bits(n as i64);
+
+        let normalized: Vec<I32F32> = normalize(&x);
+        assert_float_compare(sum(&normalized), one, epsilon);
+        assert!(normalized.iter().all(|v| *v >= fixed(0.) && *v <= one));
+
+        inplace_normalize(&mut x);
+        assert_eq!(x, normalized);
+    }
+
+    // A zero vector is left untouched.
+    let mut zeros: Vec<I32F32> = vec![fixed(0.); 8];
+    assert_eq!(normalize(&zeros), zeros);
+    inplace_normalize(&mut zeros);
+    assert!(is_zero(&zeros));
+}
+
+#[test]
+fn test_math_property_mask_idempotent() {
+    let mut rng = thread_rng();
+    let zero: I32F32 = fixed(0.);
+    for _ in 0..200 {
+        let n: usize = rng.gen_range(1..32);
+        let mask: Vec<bool> = (0..n).map(|_| rng.gen_bool(0.5)).collect();
+        let vector: Vec<I32F32> = random_vec(&mut rng, n, 1.);
+
+        let mut once: Vec<I32F32> = vector.clone();
+        inplace_mask_vector(&mask, &mut once);
+        let mut twice: Vec<I32F32> = once.clone();
+        inplace_mask_vector(&mask, &mut twice);
+        assert_eq!(once, twice);
+        for ((masked, original), m) in once.iter().zip(&vector).zip(&mask) {
+            assert_eq!(*masked, if *m { zero } else { *original });
+        }
+
+        let matrix: Vec<Vec<I32F32>> = (0..n).map(|_| random_vec(&mut rng, n, 1.)).collect();
+        let mut once: Vec<Vec<I32F32>> = matrix.clone();
+        inplace_mask_rows(&mask, &mut once);
+        let mut twice: Vec<Vec<I32F32>> = once.clone();
+        inplace_mask_rows(&mask, &mut twice);
+        assert_eq!(once, twice);
+
+        let mut once_diag: Vec<Vec<I32F32>> = matrix.clone();
+        inplace_mask_diag(&mut once_diag);
+        let mut twice_diag: Vec<Vec<I32F32>> = once_diag.clone();
+        inplace_mask_diag(&mut twice_diag);
+        assert_eq!(once_diag, twice_diag);
+    }
+}
+
+#[test]
+fn test_math_property_clip_and_ema_bounds() {
+    let mut rng = thread_rng();
+    for _ in 0..200 {
+        let rows: usize = rng.gen_range(1..16);
+        let columns: usize = rng.gen_range(1..16);
+        let new: Vec<Vec<I32F32>> = (0..rows)
+            .map(|_| random_vec(&mut rng, columns, 1.))
+            .collect();
+        let old: Vec<Vec<I32F32>> = (0..rows)
+            .map(|_| random_vec(&mut rng, columns, 1.))
+            .collect();
+
+        // Clipping never raises a value, and leaves none above its column threshold.
+        let threshold: Vec<I32F32> = random_vec(&mut rng, columns, 1.);
+        let mut clipped: Vec<Vec<I32F32>> = new.clone();
+        inplace_col_clip(&mut clipped, &threshold);
+        for (clipped_row, new_row) in clipped.iter().zip(&new) {
+            for ((c, n), t) in clipped_row.iter().zip(new_row).zip(&threshold) {
+                assert!(c <= n && c <= t);
+            }
+        }
+
+        // The moving average lies between the old and the new value.
+        let alpha: I32F32 = fixed(rng.gen_range(0.0..1.0));
+        let ema: Vec<Vec<I32F32>> = mat_ema(&new, &old, alpha);
+        let epsilon: I32F32 = I32F32::from_bits(2);
+        for ((ema_row, new_row), old_row) in ema.iter().zip(&new).zip(&old) {
+            for ((e, n), o) in ema_row.iter().zip(new_row).zip(old_row) {
+                assert!(*e + epsilon >= *n.min(o) && *e <= *n.max(o) + epsilon);
+            }
+        }
+    }
+}
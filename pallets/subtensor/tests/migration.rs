@@ -430,3 +430,278 @@ fn run_migration_and_check(migration_name: &'static str) -> frame_support::weigh
     // Return the weight of the executed migration
     weight
 }
+
+#[test]
+fn test_migrate_init_total_neuron_count() {
+    new_test_ext(1).execute_with(|| {
+        SubnetworkN::<Test>::insert(1, 3);
+        SubnetworkN::<Test>::insert(2, 5);
+        assert_eq!(TotalNeuronCount::<Test>::get(), 0);
+
+        pallet_subtensor::migrations::migrate_init_total_neuron_count::migrate_init_total_neuron_count::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_init_total_neuron_count".to_vec()
+        ));
+        assert_eq!(TotalNeuronCount::<Test>::get(), 8);
+    })
+}
+
+#[test]
+fn test_migrate_bound_owned_hotkeys() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let big_coldkey = U256::from(2);
+        let hotkeys: Vec<U256> = (0..3).map(U256::from).collect();
+        let too_many_hotkeys: Vec<U256> = (0..MAX_OWNED_HOTKEYS as u64 + 1)
+            .map(|i| U256::from(i + 100))
+            .collect();
+        // Write the values in the old, unbounded format.
+        frame_support::storage::unhashed::put(
+            &OwnedHotkeys::<Test>::hashed_key_for(coldkey),
+            &hotkeys,
+        );
+        frame_support::storage::unhashed::put(
+            &OwnedHotkeys::<Test>::hashed_key_for(big_coldkey),
+            &too_many_hotkeys,
+        );
+
+        pallet_subtensor::migrations::migrate_bound_owned_hotkeys::migrate_bound_owned_hotkeys::<
+            Test,
+        >();
+
+        // A list over the bound fails the migration and is kept whole.
+        assert!(!HasMigrationRun::<Test>::get(
+            b"migrate_bound_owned_hotkeys".to_vec()
+        ));
+        assert_eq!(
+            frame_support::storage::unhashed::get::<Vec<U256>>(
+                &OwnedHotkeys::<Test>::hashed_key_for(big_coldkey)
+            ),
+            Some(too_many_hotkeys)
+        );
+
+        // Once every list fits, the migration runs without changing them.
+        frame_support::storage::unhashed::kill(&OwnedHotkeys::<Test>::hashed_key_for(big_coldkey));
+        pallet_subtensor::migrations::migrate_bound_owned_hotkeys::migrate_bound_owned_hotkeys::<
+            Test,
+        >();
+
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_bound_owned_hotkeys".to_vec()
+        ));
+        assert_eq!(OwnedHotkeys::<Test>::get(coldkey).into_inner(), hotkeys);
+    })
+}
+
+#[test]
+fn test_migrate_init_nominator_count() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_a = U256::from(1);
+        let hotkey_b = U256::from(2);
+        Stake::<Test>::insert(hotkey_a, U256::from(10), 100);
+        Stake::<Test>::insert(hotkey_a, U256::from(11), 0);
+        Stake::<Test>::insert(hotkey_a, U256::from(12), 50);
+        Stake::<Test>::insert(hotkey_b, U256::from(10), 0);
+        assert_eq!(NominatorCount::<Test>::get(hotkey_a), 0);
+
+        pallet_subtensor::migrations::migrate_init_nominator_count::migrate_init_nominator_count::<
+            Test,
+        >();
+
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_init_nominator_count".to_vec()
+        ));
+        assert_eq!(NominatorCount::<Test>::get(hotkey_a), 2);
+        assert_eq!(NominatorCount::<Test>::get(hotkey_b), 0);
+    })
+}
+
+#[test]
+fn test_migrate_init_owned_subnet_count() {
+    new_test_ext(1).execute_with(|| {
+        let owner_a = U256::from(1);
+        let owner_b = U256::from(2);
+        SubnetOwner::<Test>::insert(1, owner_a);
+        SubnetOwner::<Test>::insert(2, owner_a);
+        SubnetOwner::<Test>::insert(3, owner_b);
+        assert_eq!(OwnedSubnetCount::<Test>::get(owner_a), 0);
+
+        pallet_subtensor::migrations::migrate_init_owned_subnet_count::migrate_init_owned_subnet_count::<
+            Test,
+        >();
+
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_init_owned_subnet_count".to_vec()
+        ));
+        assert_eq!(OwnedSubnetCount::<Test>::get(owner_a), 2);
+        assert_eq!(OwnedSubnetCount::<Test>::get(owner_b), 1);
+    })
+}
+
+#[test]
+fn test_migrate_rebuild_network_membership() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let stale_hotkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(101), 0);
+        // A membership left behind by a dissolved network and a missing one.
+        IsNetworkMember::<Test>::insert(stale_hotkey, 5, true);
+        IsNetworkMember::<Test>::remove(hotkey, netuid);
+
+        pallet_subtensor::migrations::migrate_rebuild_network_membership::migrate_rebuild_network_membership::<
+            Test,
+        >();
+
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_rebuild_network_membership".to_vec()
+        ));
+        assert!(IsNetworkMember::<Test>::get(hotkey, netuid));
+        assert!(!IsNetworkMember::<Test>::contains_key(stale_hotkey, 5));
+    })
+}
+
+#[test]
+fn test_migrate_bound_weights() {
+    use frame_support::traits::Get;
+
+    new_test_ext(1).execute_with(|| {
+        add_network(1, 1, 0);
+        add_network(2, 1, 0);
+        SubtensorModule::set_max_allowed_uids(1, 4);
+        SubtensorModule::set_max_allowed_uids(2, 4);
+        MaxWeightsLimitLen::<Test>::insert(1, 2);
+        let row = |entries: Vec<(u16, u16)>| WeightsRow::truncate_from(entries);
+        Weights::<Test>::insert(1, 0, row(vec![(0, 5), (1, 9), (2, 9), (3, 1)]));
+        Weights::<Test>::insert(1, 1, row(vec![(0, 1), (1, 2)]));
+        Weights::<Test>::insert(1, 2, row(vec![(0, 5), (1, 9), (2, 5)]));
+        Weights::<Test>::insert(2, 0, row(vec![(0, 5), (1, 9), (2, 9), (3, 1)]));
+
+        // With weight for a single row the sweep stops early and resumes later.
+        let one_row: Weight = <Test as Config>::DbWeight::get().reads_writes(5, 2);
+        pallet_subtensor::migrations::migrate_bound_weights::migrate_bound_weights::<Test>(one_row);
+        assert!(WeightsBoundCursor::<Test>::get().is_some());
+        assert!(!HasMigrationRun::<Test>::get(
+            b"migrate_bound_weights".to_vec()
+        ));
+
+        pallet_subtensor::migrations::migrate_bound_weights::migrate_bound_weights::<Test>(
+            Weight::MAX,
+        );
+        assert!(WeightsBoundCursor::<Test>::get().is_none());
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_bound_weights".to_vec()
+        ));
+
+        // The lowest weights are dropped, ties keeping the lower uids, and uid order is kept.
+        assert_eq!(Weights::<Test>::get(1, 0), vec![(1, 9), (2, 9)]);
+        assert_eq!(Weights::<Test>::get(1, 1), vec![(0, 1), (1, 2)]);
+        assert_eq!(Weights::<Test>::get(1, 2), vec![(0, 5), (1, 9)]);
+        assert_eq!(
+            Weights::<Test>::get(2, 0),
+            vec![(0, 5), (1, 9), (2, 9), (3, 1)]
+        );
+        System::assert_has_event(
+            Event::WeightsRowTruncated {
+                netuid: 1,
+                uid: 0,
+                removed: 2,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::WeightsRowTruncated {
+                netuid: 1,
+                uid: 2,
+                removed: 1,
+            }
+            .into(),
+        );
+
+        // Once run, the sweep does nothing.
+        assert_eq!(
+            pallet_subtensor::migrations::migrate_bound_weights::migrate_bound_weights::<Test>(
+                Weight::MAX
+            ),
+            Weight::zero()
+        );
+    })
+}
+
+#[test]
+fn test_migrate_dedup_staking_hotkeys() {
+    use frame_support::traits::Get;
+    use pallet_subtensor::migrations::migrate_dedup_staking_hotkeys::migrate_dedup_staking_hotkeys;
+
+    new_test_ext(1).execute_with(|| {
+        let (hotkey1, hotkey2) = (U256::from(1), U256::from(2));
+        StakingHotkeys::<Test>::insert(U256::from(10), vec![hotkey1, hotkey2, hotkey1, hotkey1]);
+        StakingHotkeys::<Test>::insert(U256::from(11), vec![hotkey2, hotkey2]);
+        StakingHotkeys::<Test>::insert(U256::from(12), vec![hotkey1, hotkey2]);
+
+        // With weight for a single coldkey the sweep stops early and resumes later.
+        let one_coldkey: Weight = <Test as Config>::DbWeight::get().reads_writes(3, 2);
+        migrate_dedup_staking_hotkeys::<Test>(one_coldkey);
+        assert!(StakingHotkeysDedupCursor::<Test>::get().is_some());
+        assert!(!HasMigrationRun::<Test>::get(
+            b"migrate_dedup_staking_hotkeys".to_vec()
+        ));
+
+        migrate_dedup_staking_hotkeys::<Test>(Weight::MAX);
+        assert!(StakingHotkeysDedupCursor::<Test>::get().is_none());
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_dedup_staking_hotkeys".to_vec()
+        ));
+
+        // The first entry of each hotkey is kept.
+        assert_eq!(
+            StakingHotkeys::<Test>::get(U256::from(10)),
+            vec![hotkey1, hotkey2]
+        );
+        assert_eq!(StakingHotkeys::<Test>::get(U256::from(11)), vec![hotkey2]);
+        assert_eq!(
+            StakingHotkeys::<Test>::get(U256::from(12)),
+            vec![hotkey1, hotkey2]
+        );
+    })
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test migration -- test_migrate_rate_limit_blocks --exact --nocapture
+#[test]
+fn test_migrate_rate_limit_blocks() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let old_key = |map: &[u8]| {
+            let mut key = frame_support::storage::storage_prefix(b"SubtensorModule", map).to_vec();
+            key.extend(codec::Encode::encode(&coldkey));
+            key
+        };
+        frame_support::storage::unhashed::put(&old_key(b"LastTxBlock"), &10_u64);
+        frame_support::storage::unhashed::put(&old_key(b"LastTxBlockDelegateTake"), &20_u64);
+        frame_support::storage::unhashed::put(&old_key(b"LastTxBlockChildKeyTake"), &30_u64);
+
+        migrations::migrate_rate_limit_blocks::migrate_rate_limit_blocks::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_rate_limit_blocks".to_vec()
+        ));
+        assert_eq!(SubtensorModule::get_last_tx_block(&coldkey), 10);
+        assert_eq!(
+            SubtensorModule::get_last_tx_block_delegate_take(&coldkey),
+            20
+        );
+        assert_eq!(
+            SubtensorModule::get_last_tx_block_childkey_take(&coldkey),
+            30
+        );
+        for map in [
+            &b"LastTxBlock"[..],
+            b"LastTxBlockDelegateTake",
+            b"LastTxBlockChildKeyTake",
+        ] {
+            assert!(frame_support::storage::unhashed::get::<u64>(&old_key(map)).is_none());
+        }
+    })
+}
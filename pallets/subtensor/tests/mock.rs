@@ -1,6 +1,6 @@
 #![allow(clippy::arithmetic_side_effects, clippy::unwrap_used)]
 use frame_support::derive_impl;
-use frame_support::dispatch::DispatchResultWithPostInfo;
+use frame_support::dispatch::{DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo};
 use frame_support::weights::constants::RocksDbWeight;
 use frame_support::weights::Weight;
 use frame_support::{
@@ -13,7 +13,9 @@ use pallet_collective::MemberCount;
 use sp_core::{Get, H256, U256};
 use sp_runtime::Perbill;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{
+        BlakeTwo256, Dispatchable, IdentifyAccount, IdentityLookup, Lazy, SignedExtension, Verify,
+    },
     BuildStorage,
 };
 use sp_std::cmp::Ordering;
@@ -103,7 +105,7 @@ impl system::Config for Test {
     type PalletInfo = PalletInfo;
     type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
-    type OnKilledAccount = ();
+    type OnKilledAccount = SubtensorModule;
     type SystemWeightInfo = ();
     type SS58Prefix = SS58Prefix;
     type OnSetCode = ();
@@ -120,7 +122,7 @@ parameter_types! {
         Weight::from_parts(2_000_000_000_000, u64::MAX),
         Perbill::from_percent(75),
     );
-    pub const ExistentialDeposit: Balance = 1;
+    pub static ExistentialDeposit: Balance = 1;
     pub const TransactionByteFee: Balance = 100;
     pub const SDebug:u64 = 1;
     pub const InitialRho: u16 = 30;
@@ -176,7 +178,10 @@ parameter_types! {
     pub const InitialHotkeyEmissionTempo: u64 = 0; // Defaults to draining every block.
     pub const InitialNetworkMaxStake: u64 = u64::MAX; // Maximum possible value for u64
     pub const InitialColdkeySwapScheduleDuration: u64 =  5 * 24 * 60 * 60 / 12; // Default as 5 days
+    pub const InitialColdkeySwapRedirectDuration: u64 = 180 * 24 * 60 * 60 / 12; // Default as 6 months
     pub const InitialDissolveNetworkScheduleDuration: u64 =  5 * 24 * 60 * 60 / 12; // Default as 5 days
+    pub const InitialPendingChildKeyCooldown: u64 = 0; // Children apply immediately
+    pub const InitialWeightCommitDeposit: u64 = 0; // Commits hold no deposit
 }
 
 // Configure collective pallet for council
@@ -267,6 +272,63 @@ impl CollectiveInterface<AccountId, H256, u32> for TriumvirateVotes {
     ) -> Result<bool, sp_runtime::DispatchError> {
         Triumvirate::do_vote(*hotkey, proposal, index, approve)
     }
+
+    fn proposal_index(proposal: H256) -> Option<u32> {
+        Triumvirate::proposal_index(proposal)
+    }
+
+    fn close_with_outcome(
+        proposal: H256,
+        index: u32,
+        approved: bool,
+        yes: u32,
+        no: u32,
+    ) -> DispatchResultWithPostInfo {
+        Triumvirate::do_close_with_outcome(proposal, index, approved, yes, no)
+    }
+}
+
+// Commitments live in another pallet, so tests mark them in unhashed storage of the test
+// externalities.
+pub struct TestCommitments;
+impl pallet_subtensor::CommitmentQuery<AccountId> for TestCommitments {
+    fn has_commitment(netuid: u16, hotkey: &AccountId) -> bool {
+        frame_support::storage::unhashed::exists(&test_commitment_key(netuid, hotkey))
+    }
+
+    fn last_commitment_block(netuid: u16, hotkey: &AccountId) -> Option<u64> {
+        frame_support::storage::unhashed::get(&test_commitment_key(netuid, hotkey))
+    }
+
+    // Mirrors the default of the commitments pallet, one tempo between two commitments.
+    fn commitment_rate_limit(netuid: u16) -> u64 {
+        u64::from(SubtensorModule::get_tempo(netuid)).saturating_add(1)
+    }
+}
+
+// Moves the test commitments of a swapped hotkey, and keeps them when a neuron is removed.
+pub struct TestNeuronHooks;
+impl pallet_subtensor::OnNeuronRemoved<AccountId> for TestNeuronHooks {
+    fn on_neuron_removed(_: u16, _: &AccountId) {}
+
+    fn on_subnet_removed(_: u16) {}
+
+    fn on_hotkey_swapped(netuid: u16, old_hotkey: &AccountId, new_hotkey: &AccountId) {
+        let old_key = test_commitment_key(netuid, old_hotkey);
+        if let Some(block) = frame_support::storage::unhashed::take::<u64>(&old_key) {
+            frame_support::storage::unhashed::put(&test_commitment_key(netuid, new_hotkey), &block);
+        }
+    }
+}
+
+fn test_commitment_key(netuid: u16, hotkey: &AccountId) -> Vec<u8> {
+    codec::Encode::encode(&(b"test_commitment", netuid, hotkey))
+}
+
+#[allow(dead_code)]
+pub fn set_test_commitment(netuid: u16, hotkey: &AccountId) {
+    let block: u64 = SubtensorModule::get_current_block_as_u64();
+    frame_support::storage::unhashed::put(&test_commitment_key(netuid, hotkey), &block);
 }
 
 // We call pallet_collective TriumvirateCollective
@@ -338,15 +400,44 @@ impl pallet_membership::Config<SenateMembership> for Test {
     type WeightInfo = pallet_membership::weights::SubstrateWeight<Test>;
 }
 
+// Signature of a test account over the message it carries.
+#[derive(codec::Encode, codec::Decode, Clone, PartialEq, Eq, Debug, scale_info::TypeInfo)]
+pub struct TestSignature(pub U256, pub Vec<u8>);
+
+impl Verify for TestSignature {
+    type Signer = TestSigner;
+
+    fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &U256) -> bool {
+        self.0 == *signer && self.1 == msg.get()
+    }
+}
+
+// Public key of a test account.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TestSigner(pub U256);
+
+impl IdentifyAccount for TestSigner {
+    type AccountId = U256;
+
+    fn into_account(self) -> U256 {
+        self.0
+    }
+}
+
 impl pallet_subtensor::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
+    type CallSignature = TestSignature;
+    type CallSigner = TestSigner;
     type Currency = Balances;
+    type VestingCurrency = Balances;
     type InitialIssuance = InitialIssuance;
     type SudoRuntimeCall = TestRuntimeCall;
     type CouncilOrigin = frame_system::EnsureSigned<AccountId>;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type OnNeuronRemoved = TestNeuronHooks;
+    type Commitments = TestCommitments;
     type Scheduler = Scheduler;
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -400,7 +491,10 @@ impl pallet_subtensor::Config for Test {
     type InitialNetworkMaxStake = InitialNetworkMaxStake;
     type Preimages = Preimage;
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
+    type InitialColdkeySwapRedirectDuration = InitialColdkeySwapRedirectDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type InitialPendingChildKeyCooldown = InitialPendingChildKeyCooldown;
+    type InitialWeightCommitDeposit = InitialWeightCommitDeposit;
 }
 
 pub struct OriginPrivilegeCmp;
@@ -493,6 +587,7 @@ pub(crate) fn step_block(n: u16) {
         System::on_initialize(System::block_number());
         SubtensorModule::on_initialize(System::block_number());
         Scheduler::on_initialize(System::block_number());
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
     }
 }
 
@@ -510,6 +605,7 @@ pub(crate) fn run_to_block(n: u64) {
         System::reset_events();
         SubtensorModule::on_initialize(System::block_number());
         Scheduler::on_initialize(System::block_number());
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
     }
 }
 
@@ -573,6 +669,30 @@ pub fn setup_neuron_with_stake(netuid: u16, hotkey: U256, coldkey: U256, stake:
     SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake);
 }
 
+// Returns the keys of the subtensor storage maps whose first key is `coldkey`.
+#[allow(dead_code)]
+pub fn storage_keys_under_coldkey(coldkey: &U256) -> Vec<Vec<u8>> {
+    let pallet_prefix = sp_io::hashing::twox_128(b"SubtensorModule");
+    let account = codec::Encode::encode(coldkey);
+    let mut keys = Vec::new();
+    let mut key = pallet_prefix.to_vec();
+    while let Some(next) = sp_io::storage::next_key(&key) {
+        if !next.starts_with(&pallet_prefix) {
+            break;
+        }
+        // The first key follows the 32 byte map prefix, after a 16 byte hash for Blake2_128Concat.
+        // In `LastRateLimitedBlock` the account follows the 9 byte Twox64Concat operation.
+        if next.get(32..64) == Some(&account[..])
+            || next.get(48..80) == Some(&account[..])
+            || next.get(57..89) == Some(&account[..])
+        {
+            keys.push(next.clone());
+        }
+        key = next;
+    }
+    keys
+}
+
 // Helper function to check if a value is within tolerance of an expected value
 #[allow(dead_code)]
 pub fn is_within_tolerance(actual: u64, expected: u64, tolerance: u64) -> bool {
@@ -583,3 +703,24 @@ pub fn is_within_tolerance(actual: u64, expected: u64, tolerance: u64) -> bool {
     };
     difference <= tolerance
 }
+
+// Helper function to dispatch a signed call through the pre and post dispatch of the subtensor signed extension
+#[allow(dead_code)]
+pub fn dispatch_with_signed_extension(who: U256, call: RuntimeCall) -> DispatchResult {
+    let info = call.get_dispatch_info();
+    let pre = pallet_subtensor::SubtensorSignedExtension::<Test>::new()
+        .pre_dispatch(&who, &call, &info, 0)
+        .unwrap();
+    let result = call.dispatch(RuntimeOrigin::signed(who));
+    let post_info = result.unwrap_or_else(|e| e.post_info);
+    let result = result.map(|_| ()).map_err(|e| e.error);
+    pallet_subtensor::SubtensorSignedExtension::<Test>::post_dispatch(
+        Some(pre),
+        &info,
+        &post_info,
+        0,
+        &result,
+    )
+    .unwrap();
+    result
+}
@@ -1,7 +1,12 @@
 use crate::mock::*;
+use codec::Encode;
 use frame_support::assert_ok;
 use frame_system::Config;
-use pallet_subtensor::{ColdkeySwapScheduleDuration, DissolveNetworkScheduleDuration, Event};
+use pallet_subtensor::{
+    subnets::param_invariants::SubnetParamViolation, ColdkeySwapScheduleDuration,
+    DissolveNetworkScheduleDuration, EmissionLastTempo, EmissionLifetime, Event, IsNetworkMember,
+    SubnetOwner, TotalNeuronCount,
+};
 use sp_core::U256;
 
 mod mock;
@@ -282,3 +287,227 @@ fn test_schedule_dissolve_network_execution_with_coldkey_swap() {
         assert!(!SubtensorModule::if_subnet_exist(netuid));
     })
 }
+
+#[test]
+fn test_total_neuron_count_register_prune_and_dissolve() {
+    new_test_ext(1).execute_with(|| {
+        let netuid_a: u16 = 1;
+        let netuid_b: u16 = 2;
+        let owner_coldkey = U256::from(0);
+        add_network(netuid_a, 13, 0);
+        add_network(netuid_b, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid_a, 2);
+        SubtensorModule::set_immunity_period(netuid_a, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid_a, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid_a, 10);
+        SubnetOwner::<Test>::insert(netuid_a, owner_coldkey);
+        assert_eq!(TotalNeuronCount::<Test>::get(), 0);
+
+        // Registering appends a neuron on each subnet.
+        register_ok_neuron(netuid_a, U256::from(1), U256::from(101), 0);
+        register_ok_neuron(netuid_a, U256::from(2), U256::from(102), 0);
+        register_ok_neuron(netuid_b, U256::from(3), U256::from(103), 0);
+        assert_eq!(TotalNeuronCount::<Test>::get(), 3);
+
+        // A full subnet prunes a neuron to make room, so the count does not change.
+        step_block(1);
+        register_ok_neuron(netuid_a, U256::from(4), U256::from(104), 100_000);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid_a), 2);
+        assert_eq!(TotalNeuronCount::<Test>::get(), 3);
+
+        // Dissolving a subnet removes all of its neurons from the count.
+        assert_ok!(SubtensorModule::user_remove_network(
+            owner_coldkey,
+            netuid_a
+        ));
+        assert_eq!(TotalNeuronCount::<Test>::get(), 1);
+    })
+}
+
+#[test]
+fn test_emission_records_kept_after_dissolution() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let owner_coldkey = U256::from(0);
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, owner_coldkey);
+        register_ok_neuron(netuid, hotkey, U256::from(101), 0);
+        EmissionLastTempo::<Test>::insert(hotkey, netuid, 10);
+        EmissionLifetime::<Test>::insert(hotkey, netuid, 100);
+
+        assert_ok!(SubtensorModule::user_remove_network(owner_coldkey, netuid));
+
+        // The records stay queryable under the dissolved netuid.
+        assert_eq!(EmissionLastTempo::<Test>::get(hotkey, netuid), 10);
+        assert_eq!(EmissionLifetime::<Test>::get(hotkey, netuid), 100);
+        assert_eq!(SubtensorModule::get_emission_info(&hotkey).len(), 1);
+    });
+}
+
+#[test]
+fn test_hotkey_registrations_register_prune_register() {
+    new_test_ext(1).execute_with(|| {
+        let netuid_a: u16 = 1;
+        let netuid_b: u16 = 2;
+        let hotkey_1 = U256::from(1);
+        let hotkey_2 = U256::from(2);
+        let registered_netuids = |hotkey: &U256| -> Vec<u16> {
+            SubtensorModule::get_hotkey_registrations(hotkey)
+                .into_iter()
+                .map(|registration| registration.netuid.0)
+                .collect()
+        };
+        add_network(netuid_a, 13, 0);
+        add_network(netuid_b, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid_a, 1);
+        SubtensorModule::set_immunity_period(netuid_a, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid_a, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid_a, 10);
+
+        register_ok_neuron(netuid_b, hotkey_1, U256::from(101), 0);
+        register_ok_neuron(netuid_a, hotkey_1, U256::from(101), 100_000);
+        assert_eq!(registered_netuids(&hotkey_1), vec![netuid_a, netuid_b]);
+
+        // The full subnet prunes the first hotkey to make room for the second.
+        step_block(1);
+        register_ok_neuron(netuid_a, hotkey_2, U256::from(102), 200_000);
+        assert_eq!(registered_netuids(&hotkey_1), vec![netuid_b]);
+        assert_eq!(registered_netuids(&hotkey_2), vec![netuid_a]);
+
+        // Registering again prunes the second hotkey in turn.
+        step_block(1);
+        register_ok_neuron(netuid_a, hotkey_1, U256::from(101), 300_000);
+        assert_eq!(registered_netuids(&hotkey_1), vec![netuid_a, netuid_b]);
+        assert!(registered_netuids(&hotkey_2).is_empty());
+
+        let registration = SubtensorModule::get_hotkey_registrations(&hotkey_1)
+            .into_iter()
+            .find(|registration| registration.netuid.0 == netuid_a)
+            .expect("Registered on netuid_a.");
+        assert_eq!(
+            Some(registration.uid.0),
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid_a, &hotkey_1).ok()
+        );
+    })
+}
+
+#[test]
+fn test_hotkey_registrations_after_dissolution() {
+    new_test_ext(1).execute_with(|| {
+        let netuid_a: u16 = 1;
+        let netuid_b: u16 = 2;
+        let hotkey = U256::from(1);
+        let owner_coldkey = U256::from(0);
+        add_network(netuid_a, 13, 0);
+        add_network(netuid_b, 13, 0);
+        SubnetOwner::<Test>::insert(netuid_a, owner_coldkey);
+        register_ok_neuron(netuid_a, hotkey, U256::from(101), 0);
+        register_ok_neuron(netuid_b, hotkey, U256::from(101), 100_000);
+        assert_eq!(
+            SubtensorModule::get_registered_networks_for_hotkey(&hotkey).len(),
+            2
+        );
+
+        // Dissolving a subnet drops the membership of its neurons.
+        assert_ok!(SubtensorModule::user_remove_network(
+            owner_coldkey,
+            netuid_a
+        ));
+        assert!(!IsNetworkMember::<Test>::get(hotkey, netuid_a));
+        assert_eq!(
+            SubtensorModule::get_registered_networks_for_hotkey(&hotkey),
+            vec![netuid_b]
+        );
+        let registered_netuids: Vec<u16> = SubtensorModule::get_hotkey_registrations(&hotkey)
+            .into_iter()
+            .map(|registration| registration.netuid.0)
+            .collect();
+        assert_eq!(registered_netuids, vec![netuid_b]);
+    })
+}
+
+// The subnet discovery APIs return plain SCALE values, which clients such as substrate-interface
+// decode from the type names in the runtime metadata: `Vec<u16>` as a compact length followed by
+// little-endian elements, `u16` as two little-endian bytes and `bool` as a single byte.
+#[test]
+fn test_subnet_discovery_apis() {
+    new_test_ext(1).execute_with(|| {
+        assert!(SubtensorModule::get_subnet_netuids().is_empty());
+        assert_eq!(SubtensorModule::get_subnets_count(), 0);
+
+        add_network(3, 13, 0);
+        add_network(1, 13, 0);
+        add_network(258, 13, 0);
+
+        let netuids = SubtensorModule::get_subnet_netuids();
+        assert_eq!(netuids, vec![1, 3, 258]);
+        assert_eq!(netuids.encode(), vec![12, 1, 0, 3, 0, 2, 1]);
+        assert_eq!(SubtensorModule::get_subnets_count(), 3);
+        assert_eq!(SubtensorModule::get_subnets_count().encode(), vec![3, 0]);
+        assert!(SubtensorModule::if_subnet_exist(258));
+        assert!(!SubtensorModule::if_subnet_exist(2));
+        assert_eq!(SubtensorModule::if_subnet_exist(3).encode(), vec![1]);
+        assert_eq!(SubtensorModule::if_subnet_exist(2).encode(), vec![0]);
+
+        SubnetOwner::<Test>::insert(3, U256::from(0));
+        assert_ok!(SubtensorModule::user_remove_network(U256::from(0), 3));
+        assert_eq!(SubtensorModule::get_subnet_netuids(), vec![1, 258]);
+        assert_eq!(SubtensorModule::get_subnets_count(), 2);
+    })
+}
+
+#[test]
+fn test_validate_subnet_params() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        assert_eq!(SubtensorModule::validate_subnet_params(netuid), None);
+
+        add_network(netuid, 13, 0);
+        assert_eq!(
+            SubtensorModule::validate_subnet_params(netuid),
+            Some(vec![])
+        );
+
+        SubtensorModule::set_kappa(netuid, 0);
+        SubtensorModule::set_activity_cutoff(netuid, 12);
+        SubtensorModule::set_immunity_period(netuid, 12);
+        let violations = SubtensorModule::validate_subnet_params(netuid);
+        assert_eq!(
+            violations,
+            Some(vec![
+                SubnetParamViolation::KappaOutOfRange,
+                SubnetParamViolation::ActivityCutoffBelowTempo,
+                SubnetParamViolation::ImmunityPeriodNotBelowActivityCutoff,
+            ])
+        );
+        // A compact length followed by the variant indexes.
+        assert_eq!(violations.unwrap_or_default().encode(), vec![12, 1, 3, 4]);
+    })
+}
+
+#[test]
+fn test_get_netuids_for_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        add_network(2, 13, 0);
+        add_network(1, 13, 0);
+        add_network(3, 13, 0);
+        register_ok_neuron(2, hotkey, U256::from(101), 0);
+        register_ok_neuron(1, hotkey, U256::from(101), 100_000);
+
+        let netuids = SubtensorModule::get_netuids_for_hotkey_account(hotkey.encode());
+        assert_eq!(netuids, Some(vec![1, 2]));
+        assert_eq!(netuids.unwrap_or_default().encode(), vec![8, 1, 0, 2, 0]);
+        assert_eq!(
+            SubtensorModule::get_netuids_for_hotkey_account(U256::from(2).encode()),
+            Some(vec![])
+        );
+
+        // An account that does not decode yields nothing.
+        assert_eq!(
+            SubtensorModule::get_netuids_for_hotkey_account(vec![1, 2, 3]),
+            None
+        );
+    })
+}
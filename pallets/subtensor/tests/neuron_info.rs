@@ -1,5 +1,9 @@
 mod mock;
+use codec::Compact;
 use mock::*;
+use pallet_subtensor::rpc_info::rate_limit_info::RateLimitStatus;
+use pallet_subtensor::utils::rate_limiting::TransactionType;
+use pallet_subtensor::*;
 
 use sp_core::U256;
 
@@ -70,3 +74,173 @@ fn test_get_neurons_empty() {
         assert_eq!(neurons.len(), neuron_count as usize);
     });
 }
+
+fn rate_limit_status(
+    netuid: u16,
+    hotkey: &U256,
+    operation: RateLimitedOperation,
+) -> RateLimitStatus {
+    SubtensorModule::get_hotkey_rate_limit_status(netuid, hotkey)
+        .into_iter()
+        .find(|status| status.operation == operation)
+        .expect("Every rate limited operation is listed")
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test neuron_info -- test_get_hotkey_rate_limit_status_never_limited --exact --nocapture
+#[test]
+fn test_get_hotkey_rate_limit_status_never_limited() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        add_network(netuid, 13, 0);
+        System::set_block_number(100);
+
+        let statuses = SubtensorModule::get_hotkey_rate_limit_status(netuid, &hotkey);
+        assert_eq!(statuses.len(), 8);
+        for status in statuses {
+            assert_eq!(status.last_block, None);
+            assert_eq!(status.blocks_remaining, Compact(0));
+        }
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test neuron_info -- test_get_hotkey_rate_limit_status_coldkey_operations --exact --nocapture
+#[test]
+fn test_get_hotkey_rate_limit_status_coldkey_operations() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        Owner::<Test>::insert(hotkey, coldkey);
+        SubtensorModule::set_tx_rate_limit(10);
+        SubtensorModule::set_tx_delegate_take_rate_limit(20);
+        SubtensorModule::set_last_tx_block(&coldkey, 95);
+        SubtensorModule::set_last_tx_block_delegate_take(&coldkey, 90);
+        System::set_block_number(100);
+
+        // Both limits still hold on the block their window ends on.
+        let status = rate_limit_status(netuid, &hotkey, RateLimitedOperation::Transaction);
+        assert_eq!(status.last_block, Some(Compact(95)));
+        assert_eq!(status.rate_limit, Compact(10));
+        assert_eq!(status.blocks_remaining, Compact(6));
+        assert!(SubtensorModule::exceeds_tx_rate_limit(95, 105));
+        assert!(!SubtensorModule::exceeds_tx_rate_limit(95, 106));
+
+        let status = rate_limit_status(netuid, &hotkey, RateLimitedOperation::DelegateTake);
+        assert_eq!(status.last_block, Some(Compact(90)));
+        assert_eq!(status.rate_limit, Compact(20));
+        assert_eq!(status.blocks_remaining, Compact(11));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test neuron_info -- test_get_hotkey_rate_limit_status_serving --exact --nocapture
+#[test]
+fn test_get_hotkey_rate_limit_status_serving() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_serving_rate_limit(netuid, 10);
+        Axons::<Test>::insert(
+            netuid,
+            hotkey,
+            AxonInfo {
+                block: 96,
+                ..Default::default()
+            },
+        );
+        Prometheus::<Test>::insert(
+            netuid,
+            hotkey,
+            PrometheusInfo {
+                block: 80,
+                ..Default::default()
+            },
+        );
+        System::set_block_number(100);
+
+        let status = rate_limit_status(netuid, &hotkey, RateLimitedOperation::ServeAxon);
+        assert_eq!(status.last_block, Some(Compact(96)));
+        assert_eq!(status.rate_limit, Compact(10));
+        assert_eq!(status.blocks_remaining, Compact(4));
+
+        // The prometheus window has passed.
+        let status = rate_limit_status(netuid, &hotkey, RateLimitedOperation::ServePrometheus);
+        assert_eq!(status.last_block, Some(Compact(80)));
+        assert_eq!(status.blocks_remaining, Compact(0));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test neuron_info -- test_get_hotkey_rate_limit_status_weights_and_commitment --exact --nocapture
+#[test]
+fn test_get_hotkey_rate_limit_status_weights_and_commitment() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, tempo, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 39420842);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        SubtensorModule::set_weights_set_rate_limit(netuid, 5);
+        SubtensorModule::set_last_update_for_uid(netuid, uid, 97);
+        System::set_block_number(95);
+        set_test_commitment(netuid, &hotkey);
+        System::set_block_number(100);
+
+        let status = rate_limit_status(netuid, &hotkey, RateLimitedOperation::SetWeights);
+        assert_eq!(status.last_block, Some(Compact(97)));
+        assert_eq!(status.rate_limit, Compact(5));
+        assert_eq!(status.blocks_remaining, Compact(2));
+
+        // A hotkey commits once a tempo.
+        let status = rate_limit_status(netuid, &hotkey, RateLimitedOperation::SetCommitment);
+        assert_eq!(status.last_block, Some(Compact(95)));
+        assert_eq!(status.rate_limit, Compact(u64::from(tempo) + 1));
+        assert_eq!(status.blocks_remaining, Compact(9));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test neuron_info -- test_get_hotkey_rate_limit_status_children --exact --nocapture
+#[test]
+fn test_get_hotkey_rate_limit_status_children() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_tx_childkey_take_rate_limit(30);
+        SubtensorModule::set_last_transaction_block(
+            &hotkey,
+            netuid,
+            &TransactionType::SetChildren,
+            99,
+        );
+        SubtensorModule::set_last_transaction_block(
+            &hotkey,
+            netuid,
+            &TransactionType::SetChildkeyTake,
+            90,
+        );
+        System::set_block_number(100);
+
+        let status = rate_limit_status(netuid, &hotkey, RateLimitedOperation::SetChildren);
+        let children_rate_limit = SubtensorModule::get_rate_limit(&TransactionType::SetChildren);
+        assert_eq!(status.last_block, Some(Compact(99)));
+        assert_eq!(status.rate_limit, Compact(children_rate_limit));
+        assert_eq!(
+            status.blocks_remaining,
+            Compact((99 + children_rate_limit).saturating_sub(100))
+        );
+
+        let status = rate_limit_status(netuid, &hotkey, RateLimitedOperation::SetChildkeyTake);
+        assert_eq!(status.last_block, Some(Compact(90)));
+        assert_eq!(status.rate_limit, Compact(30));
+        assert_eq!(status.blocks_remaining, Compact(20));
+
+        // The limits are per subnet.
+        let status = rate_limit_status(2, &hotkey, RateLimitedOperation::SetChildkeyTake);
+        assert_eq!(status.last_block, None);
+        assert_eq!(status.blocks_remaining, Compact(0));
+    });
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::unwrap_used)]
 
+use codec::Encode;
 use frame_support::traits::Currency;
 
 use crate::mock::*;
@@ -7,7 +8,11 @@ use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays
 use frame_support::sp_runtime::{transaction_validity::InvalidTransaction, DispatchError};
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::Config;
-use pallet_subtensor::{AxonInfoOf, Error, SubtensorSignedExtension};
+use pallet_subtensor::{
+    AxonInfoOf, CustomPruningScores, EmissionValues, Error, Incentive, PruningScoreSource,
+    RegistrationNonce, SubnetOwner, SubtensorSignedExtension, UsedInvites, UsedInvitesByExpiry,
+    WeightsRow, ZeroEmissionSince,
+};
 use sp_core::U256;
 use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
 
@@ -651,6 +656,111 @@ fn test_burn_registration_pruning_scenarios() {
     });
 }
 
+#[test]
+fn test_registration_get_neuron_to_prune_by_pruning_score_source() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        for i in 0..3 {
+            register_ok_neuron(
+                netuid,
+                U256::from(i),
+                U256::from(100 + i),
+                i as u64 * 100_000,
+            );
+        }
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 30);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 10);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 2, 20);
+        Incentive::<Test>::insert(netuid, vec![20, 30, 10]);
+
+        // Emission based by default
+        assert_eq!(
+            SubtensorModule::get_pruning_score_source(netuid),
+            PruningScoreSource::EmissionBased
+        );
+        assert_eq!(
+            SubtensorModule::get_pruning_scores(netuid),
+            vec![30, 10, 20]
+        );
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 1);
+
+        SubtensorModule::set_pruning_score_source(netuid, PruningScoreSource::IncentiveBased);
+        assert_eq!(
+            SubtensorModule::get_pruning_scores(netuid),
+            vec![20, 30, 10]
+        );
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 2);
+
+        SubtensorModule::set_pruning_score_source(netuid, PruningScoreSource::Custom);
+        assert_ok!(SubtensorModule::do_set_custom_pruning_scores(
+            netuid,
+            vec![5, 50, 40]
+        ));
+        assert_eq!(
+            SubtensorModule::get_pruning_score_for_neuron(netuid, 0),
+            Some(5)
+        );
+        assert_eq!(
+            SubtensorModule::get_pruning_score_for_neuron(netuid, 3),
+            None
+        );
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 0);
+
+        // The pruned neuron gets the maximum custom score
+        assert_eq!(
+            CustomPruningScores::<Test>::get(netuid),
+            vec![u16::MAX, 50, 40]
+        );
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 2);
+    });
+}
+
+#[test]
+fn test_registration_custom_pruning_scores_length() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        for i in 0..2 {
+            register_ok_neuron(
+                netuid,
+                U256::from(i),
+                U256::from(100 + i),
+                i as u64 * 100_000,
+            );
+        }
+        SubtensorModule::set_pruning_score_source(netuid, PruningScoreSource::Custom);
+
+        assert_err!(
+            SubtensorModule::do_set_custom_pruning_scores(netuid, vec![1]),
+            Error::<Test>::InvalidPruningScoresLength
+        );
+        assert_ok!(SubtensorModule::do_set_custom_pruning_scores(
+            netuid,
+            vec![20, 10]
+        ));
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 1);
+
+        // A new neuron extends the custom scores with a zero score
+        register_ok_neuron(netuid, U256::from(2), U256::from(102), 200_000);
+        assert_eq!(
+            CustomPruningScores::<Test>::get(netuid),
+            vec![20, u16::MAX, 0]
+        );
+
+        // Stale custom scores fall back to the emission based scores
+        CustomPruningScores::<Test>::insert(netuid, vec![20, 10]);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 7);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 8);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 2, 9);
+        assert_eq!(SubtensorModule::get_pruning_scores(netuid), vec![7, 8, 9]);
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 0);
+    });
+}
+
 #[test]
 fn test_registration_too_many_registrations_per_block() {
     new_test_ext(1).execute_with(|| {
@@ -2130,3 +2240,1081 @@ fn test_registration_disabled() {
 //         );
 //     });
 // }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration -- test_deregister_frees_slot_for_reregistration --exact --nocapture
+#[test]
+fn test_deregister_frees_slot_for_reregistration() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(100);
+        let hotkeys = [U256::from(1), U256::from(2), U256::from(3)];
+        let new_hotkey = U256::from(4);
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        for (i, hotkey) in hotkeys.iter().enumerate() {
+            register_ok_neuron(netuid, *hotkey, coldkey, i as u64 * 10_000);
+        }
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkeys[1], 1_000);
+        pallet_subtensor::Axons::<Test>::insert(netuid, hotkeys[1], AxonInfoOf::default());
+        let total_neurons = pallet_subtensor::TotalNeuronCount::<Test>::get();
+
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkeys[1],
+            netuid
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NeuronDeregistered {
+                netuid,
+                uid: 1,
+                hotkey: hotkeys[1],
+                refund: 0,
+            },
+        ));
+
+        // The last neuron moved into the freed uid and the count shrank.
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert_eq!(
+            pallet_subtensor::TotalNeuronCount::<Test>::get(),
+            total_neurons - 1
+        );
+        assert!(!SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &hotkeys[1]
+        ));
+        assert!(!pallet_subtensor::Axons::<Test>::contains_key(
+            netuid, hotkeys[1]
+        ));
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkeys[2]),
+            Ok(1)
+        );
+        assert_eq!(
+            SubtensorModule::get_hotkey_for_net_and_uid(netuid, 1),
+            Ok(hotkeys[2])
+        );
+        assert!(!SubtensorModule::is_uid_exist_on_network(netuid, 2));
+        assert_eq!(pallet_subtensor::Active::<Test>::get(netuid).len(), 2);
+
+        // The stake of the deregistered hotkey is untouched.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkeys[1]),
+            1_000
+        );
+
+        // The freed slot is appended into without pruning anyone.
+        register_ok_neuron(netuid, new_hotkey, coldkey, 30_000);
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &new_hotkey),
+            Ok(2)
+        );
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &hotkeys[0]
+        ));
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &hotkeys[2]
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 3);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration -- test_deregister_drops_weight_and_bond_columns --exact --nocapture
+#[test]
+fn test_deregister_drops_weight_and_bond_columns() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(100);
+        let hotkeys = [U256::from(1), U256::from(2), U256::from(3)];
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        for (i, hotkey) in hotkeys.iter().enumerate() {
+            register_ok_neuron(netuid, *hotkey, coldkey, i as u64 * 10_000);
+        }
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            0,
+            WeightsRow::truncate_from(vec![(0, 1), (1, 2), (2, 3)]),
+        );
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            2,
+            WeightsRow::truncate_from(vec![(1, 4), (2, 5)]),
+        );
+        pallet_subtensor::Bonds::<Test>::insert(
+            netuid,
+            0,
+            WeightsRow::truncate_from(vec![(1, 6), (2, 7)]),
+        );
+
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkeys[1],
+            netuid
+        ));
+
+        // Entries on the freed uid are dropped, entries on the moved uid follow it.
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, 0),
+            vec![(0, 1), (1, 3)]
+        );
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, 1),
+            vec![(1, 5)]
+        );
+        assert!(!pallet_subtensor::Weights::<Test>::contains_key(netuid, 2));
+        assert_eq!(
+            pallet_subtensor::Bonds::<Test>::get(netuid, 0),
+            vec![(1, 7)]
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration -- test_deregister_refunds_half_the_burn --exact --nocapture
+#[test]
+fn test_deregister_refunds_half_the_burn() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(100);
+        let hotkey = U256::from(1);
+        let other_hotkey = U256::from(2);
+        let burn_cost: u64 = 1_000;
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, burn_cost);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            other_hotkey
+        ));
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+        let issuance_before = SubtensorModule::get_total_issuance();
+
+        // Without recycling, nothing is refunded.
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            other_hotkey,
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before
+        );
+
+        // With recycling, half of the burn paid for the uid comes back.
+        SubtensorModule::set_recycle_registration_burn(netuid, true);
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before + burn_cost / 2
+        );
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            issuance_before + burn_cost / 2
+        );
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 0);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration -- test_deregister_errors --exact --nocapture
+#[test]
+fn test_deregister_errors() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(100);
+        let other_coldkey = U256::from(101);
+        let hotkey = U256::from(1);
+
+        add_network(netuid, 13, 0);
+        add_network(2, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+
+        assert_noop!(
+            SubtensorModule::deregister(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                3
+            ),
+            Error::<Test>::SubNetworkDoesNotExist
+        );
+        assert_noop!(
+            SubtensorModule::deregister(
+                <<Test as Config>::RuntimeOrigin>::signed(other_coldkey),
+                hotkey,
+                netuid
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_noop!(
+            SubtensorModule::deregister(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                2
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+    });
+}
+
+// Signs a registration on behalf of `coldkey` by `signer`, for `provider`, `nonce` and a burn of
+// at most 1000.
+fn sign_registration(
+    signer: U256,
+    netuid: u16,
+    hotkey: U256,
+    provider: U256,
+    nonce: u64,
+) -> TestSignature {
+    let genesis_hash = frame_system::Pallet::<Test>::block_hash(0);
+    TestSignature(
+        signer,
+        codec::Encode::encode(&(
+            b"subtensor/register_by_proxy",
+            genesis_hash,
+            netuid,
+            hotkey,
+            provider,
+            nonce,
+            1000u64,
+        )),
+    )
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration test_burned_register_by_proxy_ok -- --nocapture
+#[test]
+fn test_burned_register_by_proxy_ok() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let provider = U256::from(1);
+        let coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let burn_cost = 1000;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, burn_cost);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+        SubtensorModule::add_balance_to_coldkey_account(&provider, 10000);
+
+        assert_ok!(SubtensorModule::burned_register_by_proxy(
+            <<Test as Config>::RuntimeOrigin>::signed(provider),
+            netuid,
+            hotkey,
+            coldkey,
+            1000,
+            sign_registration(coldkey, netuid, hotkey, provider, 0)
+        ));
+
+        // The coldkey paid the burn and owns the hotkey, the provider paid nothing.
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            10000 - burn_cost
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&provider), 10000);
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey),
+            coldkey
+        );
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey),
+            Ok(0)
+        );
+        assert_eq!(RegistrationNonce::<Test>::get(coldkey), 1);
+
+        // The next registration is signed with the next nonce.
+        let other_hotkey = U256::from(4);
+        assert_ok!(SubtensorModule::burned_register_by_proxy(
+            <<Test as Config>::RuntimeOrigin>::signed(provider),
+            netuid,
+            other_hotkey,
+            coldkey,
+            1000,
+            sign_registration(coldkey, netuid, other_hotkey, provider, 1)
+        ));
+        assert_eq!(RegistrationNonce::<Test>::get(coldkey), 2);
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&other_hotkey),
+            coldkey
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration test_burned_register_by_proxy_rejects_replay -- --nocapture
+#[test]
+fn test_burned_register_by_proxy_rejects_replay() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let provider = U256::from(1);
+        let coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+        let signature = sign_registration(coldkey, netuid, hotkey, provider, 0);
+
+        assert_ok!(SubtensorModule::burned_register_by_proxy(
+            <<Test as Config>::RuntimeOrigin>::signed(provider),
+            netuid,
+            hotkey,
+            coldkey,
+            1000,
+            signature.clone()
+        ));
+
+        // The hotkey left the subnet, so only the used nonce stops the replay.
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            netuid
+        ));
+        assert_noop!(
+            SubtensorModule::burned_register_by_proxy(
+                <<Test as Config>::RuntimeOrigin>::signed(provider),
+                netuid,
+                hotkey,
+                coldkey,
+                1000,
+                signature
+            ),
+            Error::<Test>::InvalidRegistrationSignature
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration test_burned_register_by_proxy_rejects_bad_signature -- --nocapture
+#[test]
+fn test_burned_register_by_proxy_rejects_bad_signature() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let provider = U256::from(1);
+        let coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let other_provider = U256::from(4);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+
+        // Signed by another key than the coldkey.
+        assert_noop!(
+            SubtensorModule::burned_register_by_proxy(
+                <<Test as Config>::RuntimeOrigin>::signed(provider),
+                netuid,
+                hotkey,
+                coldkey,
+                1000,
+                sign_registration(hotkey, netuid, hotkey, provider, 0)
+            ),
+            Error::<Test>::InvalidRegistrationSignature
+        );
+        // Signed for another provider.
+        assert_noop!(
+            SubtensorModule::burned_register_by_proxy(
+                <<Test as Config>::RuntimeOrigin>::signed(provider),
+                netuid,
+                hotkey,
+                coldkey,
+                1000,
+                sign_registration(coldkey, netuid, hotkey, other_provider, 0)
+            ),
+            Error::<Test>::InvalidRegistrationSignature
+        );
+        // Signed with a stale nonce.
+        RegistrationNonce::<Test>::insert(coldkey, 1);
+        assert_noop!(
+            SubtensorModule::burned_register_by_proxy(
+                <<Test as Config>::RuntimeOrigin>::signed(provider),
+                netuid,
+                hotkey,
+                coldkey,
+                1000,
+                sign_registration(coldkey, netuid, hotkey, provider, 0)
+            ),
+            Error::<Test>::InvalidRegistrationSignature
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 10000);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration test_burned_register_by_proxy_bounds_burn_and_chain -- --nocapture
+#[test]
+fn test_burned_register_by_proxy_bounds_burn_and_chain() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let provider = U256::from(1);
+        let coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1001);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+
+        // The burn rose above the maximum the coldkey signed for.
+        assert_noop!(
+            SubtensorModule::burned_register_by_proxy(
+                <<Test as Config>::RuntimeOrigin>::signed(provider),
+                netuid,
+                hotkey,
+                coldkey,
+                1000,
+                sign_registration(coldkey, netuid, hotkey, provider, 0)
+            ),
+            Error::<Test>::BurnAboveSignedMaximum
+        );
+        // The provider can not raise the maximum the coldkey signed for.
+        assert_noop!(
+            SubtensorModule::burned_register_by_proxy(
+                <<Test as Config>::RuntimeOrigin>::signed(provider),
+                netuid,
+                hotkey,
+                coldkey,
+                1001,
+                sign_registration(coldkey, netuid, hotkey, provider, 0)
+            ),
+            Error::<Test>::InvalidRegistrationSignature
+        );
+
+        // A signature for another chain is rejected.
+        SubtensorModule::set_burn(netuid, 1000);
+        let other_chain_signature = TestSignature(
+            coldkey,
+            codec::Encode::encode(&(
+                b"subtensor/register_by_proxy",
+                sp_core::H256::repeat_byte(1),
+                netuid,
+                hotkey,
+                provider,
+                0u64,
+                1000u64,
+            )),
+        );
+        assert_noop!(
+            SubtensorModule::burned_register_by_proxy(
+                <<Test as Config>::RuntimeOrigin>::signed(provider),
+                netuid,
+                hotkey,
+                coldkey,
+                1000,
+                other_chain_signature
+            ),
+            Error::<Test>::InvalidRegistrationSignature
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 10000);
+        assert_eq!(RegistrationNonce::<Test>::get(coldkey), 0);
+    });
+}
+
+#[test]
+fn test_burned_registration_hotkey_limit_per_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+        let coldkey = U256::from(667);
+        add_network(netuid, 13, 0);
+        add_network(other_netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_burn(other_netuid, 1000);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_max_hotkeys_per_coldkey(2);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+
+        // Up to the limit.
+        for hotkey in [U256::from(1), U256::from(2)] {
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey
+            ));
+        }
+        assert_eq!(SubtensorModule::get_owned_hotkeys(&coldkey).len(), 2);
+
+        // Over the limit.
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(3)
+            ),
+            Error::<Test>::TooManyHotkeysForColdkey
+        );
+
+        // A hotkey the coldkey already owns can still register on another subnet.
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            other_netuid,
+            U256::from(1)
+        ));
+        assert_eq!(SubtensorModule::get_owned_hotkeys(&coldkey).len(), 2);
+    });
+}
+
+#[test]
+fn test_registration_hotkey_limit_per_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let block_number: u64 = 0;
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_hotkeys_per_coldkey(1);
+
+        let hotkey = U256::from(1);
+        let (nonce, work): (u64, Vec<u8>) =
+            SubtensorModule::create_work_for_block_number(netuid, block_number, 0, &hotkey);
+        assert_ok!(SubtensorModule::register(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            netuid,
+            block_number,
+            nonce,
+            work,
+            hotkey,
+            coldkey
+        ));
+
+        let hotkey = U256::from(2);
+        let (nonce, work): (u64, Vec<u8>) = SubtensorModule::create_work_for_block_number(
+            netuid,
+            block_number,
+            11231312312,
+            &hotkey,
+        );
+        assert_noop!(
+            SubtensorModule::register(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                block_number,
+                nonce,
+                work,
+                hotkey,
+                coldkey
+            ),
+            Error::<Test>::TooManyHotkeysForColdkey
+        );
+    });
+}
+
+#[test]
+fn test_burned_registration_hotkey_limit_exemption() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_max_hotkeys_per_coldkey(1);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(1)
+        ));
+
+        // An exempt coldkey registers past the limit.
+        SubtensorModule::set_hotkey_limit_exemption(&coldkey, true);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(2)
+        ));
+        assert_eq!(SubtensorModule::get_owned_hotkeys(&coldkey).len(), 2);
+
+        // Lifting the exemption applies the limit again.
+        SubtensorModule::set_hotkey_limit_exemption(&coldkey, false);
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(3)
+            ),
+            Error::<Test>::TooManyHotkeysForColdkey
+        );
+    });
+}
+
+#[test]
+fn test_subnet_warning_after_zero_emission_tempos() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        SubtensorModule::set_subnet_warning_tempos(2);
+
+        // The first adjustment without emission starts the count.
+        step_block(1);
+        let since = ZeroEmissionSince::<Test>::get(netuid).unwrap();
+        assert!(!SubtensorModule::get_subnet_warning(netuid));
+
+        // Two tempos of two blocks later the subnet is flagged.
+        run_to_block(since + 3);
+        assert!(!SubtensorModule::get_subnet_warning(netuid));
+        step_block(1);
+        assert!(SubtensorModule::get_subnet_warning(netuid));
+        assert_eq!(
+            SubtensorModule::get_subnet_info_v2(netuid)
+                .unwrap()
+                .encode()
+                .last()
+                .copied(),
+            Some(1)
+        );
+
+        // The warning clears as soon as emission returns.
+        EmissionValues::<Test>::insert(netuid, 1_000);
+        step_block(1);
+        assert!(!SubtensorModule::get_subnet_warning(netuid));
+        assert_eq!(ZeroEmissionSince::<Test>::get(netuid), None);
+    });
+}
+
+#[test]
+fn test_registration_blocked_by_subnet_warning() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_burn(netuid, 1_000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        SubtensorModule::set_subnet_warning(netuid, true);
+
+        // Flagged subnets accept registrations unless the policy blocks them.
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(1)
+        ));
+
+        SubtensorModule::set_subnet_warning_blocks_registration(true);
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(2)
+            ),
+            Error::<Test>::SubnetWarningRegistrationDisabled
+        );
+
+        // Subnets that are not flagged are unaffected by the policy.
+        SubtensorModule::set_subnet_warning(netuid, false);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(2)
+        ));
+    });
+}
+
+// Registers `hotkey` by burn and returns what the subnet owner received, what left the total
+// issuance and what was recorded as the registration burn of its uid.
+fn burned_register_split(netuid: u16, coldkey: U256, hotkey: U256) -> (u64, u64, u64) {
+    let owner = pallet_subtensor::SubnetOwner::<Test>::get(netuid);
+    let owner_before = SubtensorModule::get_coldkey_balance(&owner);
+    let issuance_before = SubtensorModule::get_total_issuance();
+    let recycled_before = SubtensorModule::get_rao_recycled(netuid);
+    assert_ok!(SubtensorModule::burned_register(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+        netuid,
+        hotkey
+    ));
+    let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+    let burned = issuance_before - SubtensorModule::get_total_issuance();
+    assert_eq!(
+        SubtensorModule::get_rao_recycled(netuid) - recycled_before,
+        burned
+    );
+    (
+        SubtensorModule::get_coldkey_balance(&owner) - owner_before,
+        burned,
+        pallet_subtensor::RegistrationBurn::<Test>::get(netuid, uid),
+    )
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration -- test_burned_register_owner_share --exact --nocapture
+#[test]
+fn test_burned_register_owner_share() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(50);
+        let coldkey = U256::from(100);
+        let burn_cost: u64 = 1_000;
+
+        add_network(netuid, 13, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        pallet_subtensor::TotalIssuance::<Test>::put(1_000_000);
+        SubtensorModule::set_burn(netuid, burn_cost);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1);
+
+        // By default the whole burn is destroyed.
+        assert_eq!(
+            burned_register_split(netuid, coldkey, U256::from(1)),
+            (0, 1_000, 1_000)
+        );
+
+        // Half of the burn goes to the owner.
+        SubtensorModule::set_registration_burn_owner_share(netuid, 5_000);
+        assert_eq!(
+            burned_register_split(netuid, coldkey, U256::from(2)),
+            (500, 500, 500)
+        );
+
+        // The share can be raised up to the cap.
+        SubtensorModule::set_max_registration_burn_owner_share(8_000);
+        SubtensorModule::set_registration_burn_owner_share(netuid, 8_000);
+        assert_eq!(
+            burned_register_split(netuid, coldkey, U256::from(3)),
+            (800, 200, 200)
+        );
+
+        // A share left above a lowered cap is held to the cap.
+        SubtensorModule::set_max_registration_burn_owner_share(2_500);
+        assert_eq!(
+            burned_register_split(netuid, coldkey, U256::from(4)),
+            (250, 750, 750)
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration -- test_deregister_refunds_half_of_the_destroyed_burn --exact --nocapture
+#[test]
+fn test_deregister_refunds_half_of_the_destroyed_burn() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(50);
+        let coldkey = U256::from(100);
+        let hotkey = U256::from(1);
+
+        add_network(netuid, 13, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        pallet_subtensor::TotalIssuance::<Test>::put(1_000_000);
+        SubtensorModule::set_burn(netuid, 1_000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1);
+        SubtensorModule::set_registration_burn_owner_share(netuid, 5_000);
+        SubtensorModule::set_recycle_registration_burn(netuid, true);
+
+        assert_eq!(
+            burned_register_split(netuid, coldkey, hotkey),
+            (500, 500, 500)
+        );
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+        let issuance_before = SubtensorModule::get_total_issuance();
+
+        // Half of the destroyed part of the burn is refunded, and the owner keeps its share.
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before + 250
+        );
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance_before + 250);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&owner), 501);
+        assert_eq!(SubtensorModule::get_rao_recycled(netuid), 250);
+    });
+}
+
+// Signs an invite of `hotkey` owned by `coldkey` to `netuid` by `signer`, valid up to
+// `expiry_block`.
+fn sign_invite(
+    signer: U256,
+    coldkey: U256,
+    netuid: u16,
+    hotkey: U256,
+    expiry_block: u64,
+) -> TestSignature {
+    let genesis_hash = frame_system::Pallet::<Test>::block_hash(0);
+    TestSignature(
+        signer,
+        codec::Encode::encode(&(
+            b"subtensor/registration_invite",
+            netuid,
+            hotkey,
+            coldkey,
+            genesis_hash,
+            expiry_block,
+        )),
+    )
+}
+
+// Adds an invite-only network owned by `owner`, with a burn of 1000.
+fn add_invite_only_network(netuid: u16, owner: U256) {
+    add_network(netuid, 13, 0);
+    SubnetOwner::<Test>::insert(netuid, owner);
+    SubtensorModule::set_burn(netuid, 1000);
+    SubtensorModule::set_invite_only_registration(netuid, true);
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration test_burned_register_with_invite_ok -- --nocapture
+#[test]
+fn test_burned_register_with_invite_ok() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        add_invite_only_network(netuid, owner);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+
+        // Without an invite, the subnet registers nothing.
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey
+            ),
+            Error::<Test>::InviteRequired
+        );
+        assert_noop!(
+            SubtensorModule::burned_register_by_proxy(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                hotkey,
+                coldkey,
+                1000,
+                sign_registration(coldkey, netuid, hotkey, owner, 0)
+            ),
+            Error::<Test>::InviteRequired
+        );
+        let (nonce, work): (u64, Vec<u8>) =
+            SubtensorModule::create_work_for_block_number(netuid, 0, 0, &hotkey);
+        assert_noop!(
+            SubtensorModule::register(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                0,
+                nonce,
+                work,
+                hotkey,
+                coldkey
+            ),
+            Error::<Test>::SubNetRegistrationDisabled
+        );
+
+        // An invite signed by another account is rejected.
+        assert_noop!(
+            SubtensorModule::burned_register_with_invite(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey,
+                10,
+                sign_invite(coldkey, coldkey, netuid, hotkey, 10)
+            ),
+            Error::<Test>::InvalidInviteSignature
+        );
+
+        assert_ok!(SubtensorModule::burned_register_with_invite(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey,
+            10,
+            sign_invite(owner, coldkey, netuid, hotkey, 10)
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 9000);
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey),
+            coldkey
+        );
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey),
+            Ok(0)
+        );
+        assert!(UsedInvites::<Test>::get(netuid, (hotkey, 10)));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration test_burned_register_with_invite_expired -- --nocapture
+#[test]
+fn test_burned_register_with_invite_expired() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        add_invite_only_network(netuid, owner);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+
+        run_to_block(11);
+        assert_noop!(
+            SubtensorModule::burned_register_with_invite(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey,
+                10,
+                sign_invite(owner, coldkey, netuid, hotkey, 10)
+            ),
+            Error::<Test>::InviteExpired
+        );
+
+        // The invite can still be used at its expiry block.
+        assert_ok!(SubtensorModule::burned_register_with_invite(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey,
+            11,
+            sign_invite(owner, coldkey, netuid, hotkey, 11)
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration test_burned_register_with_invite_rejects_reuse -- --nocapture
+#[test]
+fn test_burned_register_with_invite_rejects_reuse() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let coldkey = U256::from(2);
+        let other_coldkey = U256::from(4);
+        let hotkey = U256::from(3);
+        add_invite_only_network(netuid, owner);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+        SubtensorModule::add_balance_to_coldkey_account(&other_coldkey, 10000);
+
+        assert_ok!(SubtensorModule::burned_register_with_invite(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey,
+            10,
+            sign_invite(owner, coldkey, netuid, hotkey, 10)
+        ));
+
+        // The invite is bound to the coldkey it was signed for.
+        assert_noop!(
+            SubtensorModule::burned_register_with_invite(
+                <<Test as Config>::RuntimeOrigin>::signed(other_coldkey),
+                netuid,
+                hotkey,
+                10,
+                sign_invite(owner, coldkey, netuid, hotkey, 10)
+            ),
+            Error::<Test>::InvalidInviteSignature
+        );
+
+        // The invite registered the hotkey once, whoever it is signed for again.
+        assert_noop!(
+            SubtensorModule::burned_register_with_invite(
+                <<Test as Config>::RuntimeOrigin>::signed(other_coldkey),
+                netuid,
+                hotkey,
+                10,
+                sign_invite(owner, other_coldkey, netuid, hotkey, 10)
+            ),
+            Error::<Test>::InviteAlreadyUsed
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&other_coldkey), 10000);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration test_used_invites_pruned_after_expiry -- --nocapture
+#[test]
+fn test_used_invites_pruned_after_expiry() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        add_invite_only_network(netuid, owner);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+
+        assert_ok!(SubtensorModule::burned_register_with_invite(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey,
+            10,
+            sign_invite(owner, coldkey, netuid, hotkey, 10)
+        ));
+        assert_eq!(UsedInvitesByExpiry::<Test>::get(10), vec![(netuid, hotkey)]);
+
+        // Kept up to its expiry block, removed by the block step of the next one.
+        run_to_block(10);
+        assert!(UsedInvites::<Test>::get(netuid, (hotkey, 10)));
+        run_to_block(11);
+        assert!(!UsedInvites::<Test>::contains_key(netuid, (hotkey, 10)));
+        assert!(!UsedInvitesByExpiry::<Test>::contains_key(10));
+
+        // The expired invite is still rejected.
+        assert_noop!(
+            SubtensorModule::burned_register_with_invite(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey,
+                10,
+                sign_invite(owner, coldkey, netuid, hotkey, 10)
+            ),
+            Error::<Test>::InviteExpired
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test registration test_invite_only_registration_toggle_keeps_neurons -- --nocapture
+#[test]
+fn test_invite_only_registration_toggle_keeps_neurons() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let other_hotkey = U256::from(4);
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+
+        // Turning invites on keeps the neuron registered without one.
+        SubtensorModule::set_invite_only_registration(netuid, true);
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey),
+            Ok(0)
+        );
+        assert_ok!(SubtensorModule::burned_register_with_invite(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            other_hotkey,
+            10,
+            sign_invite(owner, coldkey, netuid, other_hotkey, 10)
+        ));
+
+        // Turning them off keeps the invited neuron and opens registration again.
+        SubtensorModule::set_invite_only_registration(netuid, false);
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey),
+            Ok(0)
+        );
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &other_hotkey),
+            Ok(1)
+        );
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(5)
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 3);
+    });
+}
@@ -8,6 +8,7 @@ use pallet_subtensor::Error;
 use pallet_subtensor::{migrations, SubnetIdentity};
 use pallet_subtensor::{SubnetIdentities, SubnetIdentityOf};
 use sp_core::{Get, H256, U256};
+use sp_runtime::DispatchError;
 
 mod mock;
 
@@ -625,9 +626,9 @@ fn test_network_pruning() {
         step_block(1);
         assert_eq!(SubtensorModule::get_pending_emission(0), 0); // root network gets no pending emission.
         assert_eq!(SubtensorModule::get_pending_emission(1), 249_435_914);
-        assert_eq!(SubtensorModule::get_pending_emission(2), 0); // This has been drained.
+        assert_eq!(SubtensorModule::get_pending_emission(2), 180_819_837); // Retained by its epoch, no weights are set.
         assert_eq!(SubtensorModule::get_pending_emission(3), 129_362_980);
-        assert_eq!(SubtensorModule::get_pending_emission(4), 0); // This network has been drained.
+        assert_eq!(SubtensorModule::get_pending_emission(4), 50_857_187); // Retained by its epoch, no weights are set.
         assert_eq!(SubtensorModule::get_pending_emission(5), 3_530_356);
         step_block(1);
     });
@@ -674,6 +675,125 @@ fn test_network_prune_results() {
     });
 }
 
+#[test]
+fn test_network_registration_rate_limit() {
+    new_test_ext(1).execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_rate_limit(10);
+
+        let owner: U256 = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+
+        let registered_at = SubtensorModule::get_current_block_as_u64();
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+        ));
+        assert_eq!(
+            SubtensorModule::get_network_registration_allowed_block(),
+            registered_at + 10
+        );
+
+        // A second registration in the next block is rejected.
+        step_block(1);
+        assert_err!(
+            SubtensorModule::register_network(<<Test as Config>::RuntimeOrigin>::signed(owner)),
+            Error::<Test>::NetworkTxRateLimitExceeded
+        );
+        assert_eq!(SubtensorModule::get_num_subnets(), 2);
+
+        // Allowed again once the limit has passed.
+        step_block(9);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+        ));
+        assert_eq!(SubtensorModule::get_num_subnets(), 3);
+    });
+}
+
+#[test]
+fn test_root_network_registration_exempt_from_rate_limit() {
+    new_test_ext(1).execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_rate_limit(10);
+
+        let owner: U256 = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+        ));
+        let allowed_block = SubtensorModule::get_network_registration_allowed_block();
+
+        // Root registers in the next block, without locking from the owner.
+        step_block(1);
+        let new_owner: U256 = U256::from(1);
+        assert_ok!(SubtensorModule::root_register_network(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            new_owner,
+            None,
+        ));
+        assert_eq!(SubtensorModule::get_num_subnets(), 3);
+        assert_eq!(SubtensorModule::get_subnet_owner(2), new_owner);
+        assert_eq!(SubtensorModule::get_subnet_locked_balance(2), 0);
+
+        // The user rate limit is unaffected by root registrations.
+        assert_eq!(
+            SubtensorModule::get_network_registration_allowed_block(),
+            allowed_block
+        );
+        assert_err!(
+            SubtensorModule::register_network(<<Test as Config>::RuntimeOrigin>::signed(owner)),
+            Error::<Test>::NetworkTxRateLimitExceeded
+        );
+
+        // Only root can register without the limit.
+        assert_err!(
+            SubtensorModule::root_register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                owner,
+                None,
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_network_rate_limit_change_takes_effect_immediately() {
+    new_test_ext(1).execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_rate_limit(100);
+
+        let owner: U256 = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+        ));
+
+        step_block(1);
+        assert_err!(
+            SubtensorModule::register_network(<<Test as Config>::RuntimeOrigin>::signed(owner)),
+            Error::<Test>::NetworkTxRateLimitExceeded
+        );
+
+        // Lowering the limit applies to the next registration.
+        SubtensorModule::set_network_rate_limit(1);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+        ));
+
+        // Raising it applies as well.
+        step_block(1);
+        SubtensorModule::set_network_rate_limit(100);
+        assert_err!(
+            SubtensorModule::register_network(<<Test as Config>::RuntimeOrigin>::signed(owner)),
+            Error::<Test>::NetworkTxRateLimitExceeded
+        );
+    });
+}
+
 #[test]
 fn test_weights_after_network_pruning() {
     new_test_ext(1).execute_with(|| {
@@ -1052,3 +1172,148 @@ fn test_user_add_network_with_identity_fields_ok() {
         );
     });
 }
+
+#[test]
+fn test_network_netuid_reused_after_dissolution() {
+    new_test_ext(1).execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_rate_limit(0);
+
+        let owner: U256 = U256::from(0);
+        let hotkey: U256 = U256::from(1);
+        let hotkey_coldkey: U256 = U256::from(2);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        for _ in 0..3 {
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+            ));
+        }
+
+        // Leave state behind on the second subnet.
+        let weights_set_rate_limit = SubtensorModule::get_weights_set_rate_limit(2);
+        SubtensorModule::set_weights_set_rate_limit(2, weights_set_rate_limit + 10);
+        SubtensorModule::set_commit_reveal_weights_enabled(2, true);
+        pallet_subtensor::BlockAtRegistration::<Test>::insert(2, 0, 5);
+        pallet_subtensor::PendingEmission::<Test>::insert(2, 1_000);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &hotkey_coldkey,
+            &hotkey
+        ));
+        pallet_subtensor::WeightCommits::<Test>::insert(2, hotkey, (H256::zero(), 1));
+        pallet_subtensor::WeightCommitDeposits::<Test>::insert(2, hotkey, 100);
+
+        assert_ok!(SubtensorModule::user_remove_network(owner, 2));
+        assert!(!SubtensorModule::if_subnet_exist(2));
+        // The pending weight commit deposit is refunded.
+        assert_eq!(SubtensorModule::get_coldkey_balance(&hotkey_coldkey), 100);
+
+        // The lowest free netuid is reused, with none of the state of the dissolved subnet.
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+        ));
+        assert!(SubtensorModule::if_subnet_exist(2));
+        assert_eq!(SubtensorModule::get_num_subnets(), 4);
+        assert_eq!(
+            SubtensorModule::get_weights_set_rate_limit(2),
+            weights_set_rate_limit
+        );
+        assert!(!SubtensorModule::get_commit_reveal_weights_enabled(2));
+        assert!(!pallet_subtensor::BlockAtRegistration::<Test>::contains_key(2, 0));
+        assert_eq!(SubtensorModule::get_pending_emission(2), 0);
+        assert!(!pallet_subtensor::WeightCommits::<Test>::contains_key(
+            2, hotkey
+        ));
+        assert!(!pallet_subtensor::WeightCommitDeposits::<Test>::contains_key(2, hotkey));
+    });
+}
+
+#[test]
+fn test_network_registration_subnet_limit_reached() {
+    new_test_ext(1).execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_rate_limit(0);
+        SubtensorModule::set_network_immunity_period(0);
+        SubtensorModule::set_max_subnets(2);
+        SubtensorModule::set_subnet_limit_pruning(false);
+
+        let owner: U256 = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        for _ in 0..2 {
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+            ));
+        }
+        step_block(1);
+
+        // With pruning disabled, a registration at the limit fails.
+        assert_err!(
+            SubtensorModule::register_network(<<Test as Config>::RuntimeOrigin>::signed(owner)),
+            Error::<Test>::SubnetLimitReached
+        );
+        assert_eq!(SubtensorModule::get_num_subnets(), 3);
+        assert!(SubtensorModule::if_subnet_exist(1));
+        assert!(SubtensorModule::if_subnet_exist(2));
+
+        // With pruning enabled, it makes room.
+        SubtensorModule::set_subnet_limit_pruning(true);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+        ));
+        assert_eq!(SubtensorModule::get_num_subnets(), 3);
+    });
+}
+
+#[test]
+fn test_network_registration_prunes_to_make_room() {
+    new_test_ext(1).execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_rate_limit(0);
+        SubtensorModule::set_network_immunity_period(10);
+        SubtensorModule::set_max_subnets(3);
+
+        let owner: U256 = U256::from(0);
+        let new_owner: U256 = U256::from(1);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&new_owner, 1_000_000_000_000_000);
+        for _ in 0..3 {
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+            ));
+            step_block(1);
+        }
+
+        // All subnets are immune at first.
+        assert_err!(
+            SubtensorModule::register_network(<<Test as Config>::RuntimeOrigin>::signed(new_owner)),
+            Error::<Test>::AllNetworksInImmunity
+        );
+
+        // Out of immunity, the oldest of the lowest emission subnets is pruned.
+        step_block(10);
+        SubtensorModule::set_emission_values(&[1u16, 2u16, 3u16], vec![4u64, 5u64, 4u64]).unwrap();
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+        ));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NetworkRemoved(1),
+        ));
+        assert_eq!(SubtensorModule::get_subnet_owner(1), new_owner);
+        assert_eq!(SubtensorModule::get_subnet_owner(2), owner);
+        assert_eq!(SubtensorModule::get_subnet_owner(3), owner);
+        assert_eq!(SubtensorModule::get_num_subnets(), 4);
+
+        // The newly registered subnet is immune, so the next lowest emission subnet is pruned.
+        SubtensorModule::set_emission_values(&[2u16, 3u16], vec![5u64, 4u64]).unwrap();
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+        ));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NetworkRemoved(3),
+        ));
+        assert_eq!(SubtensorModule::get_subnet_owner(3), new_owner);
+        assert_eq!(SubtensorModule::get_subnet_owner(2), owner);
+    });
+}
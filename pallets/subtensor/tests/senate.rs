@@ -1,4 +1,4 @@
-#![allow(clippy::unwrap_used)]
+#![allow(clippy::unwrap_used, clippy::arithmetic_side_effects)]
 
 mod mock;
 use mock::*;
@@ -808,3 +808,280 @@ fn test_adjust_senate_events() {
         );
     });
 }
+
+// Gives each of the senate members 2, 3, ... a coldkey and the given stake.
+fn setup_weighted_senate(stakes: &[u64]) {
+    for (i, stake) in stakes.iter().enumerate() {
+        let hotkey = U256::from(i + 2);
+        let coldkey = U256::from(i + 102);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, *stake);
+    }
+}
+
+// Proposes a remark through senate member 1 and returns its hash.
+fn propose_weighted(value: u64) -> H256 {
+    let proposal = make_proposal(value);
+    let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+    assert_ok!(Triumvirate::propose(
+        RuntimeOrigin::signed(U256::from(1)),
+        Box::new(proposal.clone()),
+        proposal_len,
+        TryInto::<BlockNumberFor<Test>>::try_into(100u64).expect("convert u64 to block number.")
+    ));
+    BlakeTwo256::hash_of(&proposal)
+}
+
+fn vote_weighted(member: u64, hash: H256, index: u32, approve: bool) {
+    assert_ok!(SubtensorModule::vote_weighted(
+        <<Test as Config>::RuntimeOrigin>::signed(U256::from(member + 100)),
+        U256::from(member),
+        hash,
+        index,
+        approve
+    ));
+}
+
+#[test]
+fn test_senate_vote_paths_split_at_stake_weighted_flag() {
+    new_test_ext().execute_with(|| {
+        setup_weighted_senate(&[100]);
+        let old_hash = propose_weighted(1);
+        SubtensorModule::set_stake_weighted_voting(Some(1), u16::MAX / 2 + 1);
+        let new_hash = propose_weighted(2);
+
+        // Proposals before the flag keep one vote per member.
+        assert_noop!(
+            SubtensorModule::vote_weighted(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(102)),
+                U256::from(2),
+                old_hash,
+                0,
+                true
+            ),
+            Error::<Test>::ProposalNotStakeWeighted
+        );
+        assert_ok!(SubtensorModule::do_vote_root(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(102)),
+            &U256::from(2),
+            old_hash,
+            0,
+            true
+        ));
+
+        // Proposals from the flag on are tallied by stake.
+        assert_noop!(
+            SubtensorModule::do_vote_root(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(102)),
+                &U256::from(2),
+                new_hash,
+                1,
+                true
+            ),
+            Error::<Test>::ProposalIsStakeWeighted
+        );
+        assert_noop!(
+            SubtensorModule::vote_weighted(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(102)),
+                U256::from(2),
+                new_hash,
+                2,
+                true
+            ),
+            Error::<Test>::ProposalIndexMismatch
+        );
+        vote_weighted(2, new_hash, 1, true);
+        assert_eq!(
+            pallet_subtensor::StakeWeightedVotes::<Test>::get(new_hash, U256::from(2)),
+            Some((true, 100))
+        );
+    });
+}
+
+#[test]
+fn test_senate_vote_weighted_high_stake_minority_wins() {
+    new_test_ext().execute_with(|| {
+        // Members 2, 3 and 4 hold little stake, member 5 holds most of it.
+        setup_weighted_senate(&[100, 100, 100, 1_000]);
+        SubtensorModule::set_stake_weighted_voting(Some(0), u16::MAX / 2 + 1);
+
+        // A majority of members approving loses to the stake of the minority.
+        let hash = propose_weighted(1);
+        vote_weighted(2, hash, 0, true);
+        vote_weighted(3, hash, 0, true);
+        vote_weighted(4, hash, 0, true);
+        vote_weighted(5, hash, 0, false);
+
+        assert_noop!(
+            SubtensorModule::close_weighted(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                hash,
+                0
+            ),
+            pallet_collective::Error::<Test, pallet_collective::Instance1>::TooEarlyToCloseProposal
+        );
+        System::set_block_number(101);
+        assert_ok!(SubtensorModule::close_weighted(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+            hash,
+            0
+        ));
+        assert!(System::events().contains(&record(RuntimeEvent::Triumvirate(
+            CollectiveEvent::Disapproved {
+                proposal_hash: hash
+            }
+        ))));
+        System::assert_last_event(
+            SubtensorEvent::StakeWeightedProposalClosed {
+                proposal: hash,
+                aye_stake: 300,
+                total_stake: 1_300,
+                approved: false,
+            }
+            .into(),
+        );
+        assert_eq!(Triumvirate::proposal_index(hash), None);
+        assert_eq!(
+            pallet_subtensor::StakeWeightedVotes::<Test>::iter_prefix(hash).count(),
+            0
+        );
+
+        // The minority approving alone passes the proposal without waiting for its end.
+        let hash = propose_weighted(2);
+        vote_weighted(2, hash, 1, false);
+        vote_weighted(3, hash, 1, false);
+        vote_weighted(4, hash, 1, false);
+        vote_weighted(5, hash, 1, true);
+        assert_ok!(SubtensorModule::close_weighted(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+            hash,
+            1
+        ));
+        assert!(System::events().contains(&record(RuntimeEvent::Triumvirate(
+            CollectiveEvent::Approved {
+                proposal_hash: hash
+            }
+        ))));
+        assert_eq!(Triumvirate::proposal_index(hash), None);
+    });
+}
+
+#[test]
+fn test_senate_vote_weighted_threshold_boundary() {
+    new_test_ext().execute_with(|| {
+        // The senate holds u16::MAX stake, so the threshold is reached at exactly its own value.
+        let threshold: u16 = u16::MAX / 4;
+        setup_weighted_senate(&[threshold as u64 - 1, 1, (u16::MAX - threshold) as u64]);
+        SubtensorModule::set_stake_weighted_voting(Some(0), threshold);
+
+        // One short of the threshold does not pass.
+        let below = propose_weighted(1);
+        vote_weighted(2, below, 0, true);
+        assert_noop!(
+            SubtensorModule::close_weighted(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                below,
+                0
+            ),
+            pallet_collective::Error::<Test, pallet_collective::Instance1>::TooEarlyToCloseProposal
+        );
+
+        // Exactly the threshold passes.
+        let at = propose_weighted(2);
+        vote_weighted(2, at, 1, true);
+        vote_weighted(3, at, 1, true);
+        assert_ok!(SubtensorModule::close_weighted(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+            at,
+            1
+        ));
+        System::assert_last_event(
+            SubtensorEvent::StakeWeightedProposalClosed {
+                proposal: at,
+                aye_stake: threshold as u64,
+                total_stake: u16::MAX as u64,
+                approved: true,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_senate_vote_weighted_replaces_double_vote() {
+    new_test_ext().execute_with(|| {
+        setup_weighted_senate(&[1_000, 100]);
+        SubtensorModule::set_stake_weighted_voting(Some(0), u16::MAX / 2 + 1);
+        let hash = propose_weighted(1);
+
+        vote_weighted(2, hash, 0, true);
+        vote_weighted(2, hash, 0, true);
+        assert_eq!(
+            pallet_subtensor::StakeWeightedVotes::<Test>::iter_prefix(hash).count(),
+            1
+        );
+
+        // Changing the vote replaces it, and the stake counted is the one at the last vote.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(102),
+            &U256::from(2),
+            500,
+        );
+        vote_weighted(2, hash, 0, false);
+        assert_eq!(
+            pallet_subtensor::StakeWeightedVotes::<Test>::get(hash, U256::from(2)),
+            Some((false, 1_500))
+        );
+
+        vote_weighted(3, hash, 0, true);
+        System::set_block_number(101);
+        assert_ok!(SubtensorModule::close_weighted(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+            hash,
+            0
+        ));
+        System::assert_last_event(
+            SubtensorEvent::StakeWeightedProposalClosed {
+                proposal: hash,
+                aye_stake: 100,
+                total_stake: 1_600,
+                approved: false,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_senate_vote_weighted_counts_moved_stake_once() {
+    new_test_ext().execute_with(|| {
+        setup_weighted_senate(&[100, 100, 1_000]);
+        SubtensorModule::set_stake_weighted_voting(Some(0), u16::MAX / 2 + 1);
+        let hash = propose_weighted(1);
+
+        // A nominator votes through member 2, then moves its stake to member 3 and votes again.
+        let nominator = U256::from(200);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &U256::from(2), 500);
+        vote_weighted(2, hash, 0, true);
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&nominator, &U256::from(2), 500);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &U256::from(3), 500);
+        vote_weighted(3, hash, 0, true);
+
+        // The moved stake is counted once, at its current hotkey.
+        System::set_block_number(101);
+        assert_ok!(SubtensorModule::close_weighted(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+            hash,
+            0
+        ));
+        System::assert_last_event(
+            SubtensorEvent::StakeWeightedProposalClosed {
+                proposal: hash,
+                aye_stake: 700,
+                total_stake: 1_700,
+                approved: false,
+            }
+            .into(),
+        );
+    });
+}
@@ -279,6 +279,90 @@ fn test_axon_invalid_port() {
     });
 }
 
+#[test]
+fn test_axon_version_too_old() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        let ip: u128 = 1676056785;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        SubtensorModule::set_min_axon_version(netuid, 3);
+
+        assert_noop!(
+            SubtensorModule::serve_axon(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid,
+                2,
+                ip,
+                128,
+                4,
+                0,
+                0,
+                0
+            ),
+            Error::<Test>::AxonVersionTooOld
+        );
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            3,
+            ip,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        assert!(!SubtensorModule::is_axon_outdated(
+            netuid,
+            &hotkey_account_id
+        ));
+    });
+}
+
+#[test]
+fn test_min_axon_version_flags_existing_axons() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        let version: u32 = 2;
+        let ip: u128 = 1676056785;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+
+        // The default minimum of 0 accepts any version.
+        assert_eq!(SubtensorModule::get_min_axon_version(netuid), 0);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            version,
+            ip,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        assert!(!SubtensorModule::is_axon_outdated(
+            netuid,
+            &hotkey_account_id
+        ));
+
+        // Raising the minimum keeps the axon but flags it.
+        SubtensorModule::set_min_axon_version(netuid, version + 1);
+        assert!(SubtensorModule::has_axon_info(netuid, &hotkey_account_id));
+        assert_eq!(
+            SubtensorModule::get_axon_info(netuid, &hotkey_account_id).version,
+            version
+        );
+        assert!(SubtensorModule::is_axon_outdated(
+            netuid,
+            &hotkey_account_id
+        ));
+    });
+}
+
 #[test]
 fn test_prometheus_serving_subscribe_ok_dispatch_info_ok() {
     new_test_ext(1).execute_with(|| {
@@ -1014,3 +1098,210 @@ fn test_set_subnet_identity_dispatch_info_ok() {
         assert_eq!(dispatch_info.pays_fee, Pays::Yes);
     });
 }
+
+// Serves an axon for the hotkey on the subnet.
+fn serve_test_axon(netuid: u16, hotkey: U256) {
+    assert_ok!(SubtensorModule::serve_axon(
+        <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+        netuid,
+        2,
+        1676056785,
+        128,
+        4,
+        0,
+        0,
+        0
+    ));
+}
+
+#[test]
+fn test_clear_axon() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let stranger = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        serve_test_axon(netuid, hotkey);
+
+        // Only the hotkey or its coldkey can clear the axon.
+        assert_noop!(
+            SubtensorModule::clear_axon(
+                <<Test as Config>::RuntimeOrigin>::signed(stranger),
+                netuid,
+                hotkey
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        assert_ok!(SubtensorModule::clear_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+        assert!(!SubtensorModule::has_axon_info(netuid, &hotkey));
+        System::assert_last_event(Event::AxonCleared(netuid, hotkey).into());
+
+        // The neuron info reports the default axon.
+        let registrations = SubtensorModule::get_hotkey_registrations(&hotkey);
+        assert_eq!(registrations.len(), 1);
+        assert!(registrations
+            .iter()
+            .all(|registration| registration.axon_info == AxonInfo::default()));
+
+        // There is nothing left to clear, and the hotkey can serve again.
+        assert_noop!(
+            SubtensorModule::clear_axon(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                hotkey
+            ),
+            Error::<Test>::AxonNotServed
+        );
+        serve_test_axon(netuid, hotkey);
+        assert!(SubtensorModule::has_axon_info(netuid, &hotkey));
+    });
+}
+
+#[test]
+fn test_pruned_neuron_leaves_no_serving_info() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let pruned_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 1);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        register_ok_neuron(netuid, pruned_hotkey, U256::from(11), 0);
+        serve_test_axon(netuid, pruned_hotkey);
+        assert_ok!(SubtensorModule::serve_prometheus(
+            <<Test as Config>::RuntimeOrigin>::signed(pruned_hotkey),
+            netuid,
+            2,
+            1676056785,
+            128,
+            4
+        ));
+
+        // The new registration takes the slot of the pruned neuron.
+        register_ok_neuron(netuid, new_hotkey, U256::from(12), 100_000);
+        assert!(!SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &pruned_hotkey
+        ));
+
+        // No serving info is left for a hotkey that is not registered.
+        assert!(Axons::<Test>::iter_prefix(netuid)
+            .all(|(hotkey, _)| SubtensorModule::is_hotkey_registered_on_network(netuid, &hotkey)));
+        assert!(Prometheus::<Test>::iter_prefix(netuid)
+            .all(|(hotkey, _)| SubtensorModule::is_hotkey_registered_on_network(netuid, &hotkey)));
+        assert_eq!(Axons::<Test>::iter_prefix(netuid).count(), 0);
+        assert_eq!(Prometheus::<Test>::iter_prefix(netuid).count(), 0);
+    });
+}
+
+#[test]
+fn test_attest_overwrites_payload_and_keeps_deposit() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let deposit: u64 = AttestationDeposit::<Test>::get();
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000);
+
+        assert_ok!(SubtensorModule::attest(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            [1; 32]
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            1_000_000_000 - deposit
+        );
+
+        // A later attestation replaces the payload and block, without a second deposit.
+        System::set_block_number(1 + AttestationRateLimit::<Test>::get());
+        assert_ok!(SubtensorModule::attest(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            [2; 32]
+        ));
+        assert_eq!(
+            SubtensorModule::get_attestation(&coldkey),
+            Some(ColdkeyAttestationInfo {
+                payload_hash: [2; 32],
+                block: 1 + AttestationRateLimit::<Test>::get(),
+                deposit,
+            })
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            1_000_000_000 - deposit
+        );
+    });
+}
+
+#[test]
+fn test_attest_rate_limited() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let rate_limit: u64 = AttestationRateLimit::<Test>::get();
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000);
+
+        assert_ok!(SubtensorModule::attest(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            [1; 32]
+        ));
+        System::set_block_number(rate_limit);
+        assert_noop!(
+            SubtensorModule::attest(<<Test as Config>::RuntimeOrigin>::signed(coldkey), [2; 32]),
+            Error::<Test>::TxRateLimitExceeded
+        );
+
+        // Revoking does not reset the rate limit.
+        assert_ok!(SubtensorModule::revoke_attestation(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+        ));
+        assert_noop!(
+            SubtensorModule::attest(<<Test as Config>::RuntimeOrigin>::signed(coldkey), [2; 32]),
+            Error::<Test>::TxRateLimitExceeded
+        );
+
+        System::set_block_number(1 + rate_limit);
+        assert_ok!(SubtensorModule::attest(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            [2; 32]
+        ));
+    });
+}
+
+#[test]
+fn test_revoke_attestation_refunds_deposit() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000);
+
+        assert_noop!(
+            SubtensorModule::revoke_attestation(<<Test as Config>::RuntimeOrigin>::signed(coldkey)),
+            Error::<Test>::NoAttestation
+        );
+
+        assert_ok!(SubtensorModule::attest(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            [1; 32]
+        ));
+        assert_ok!(SubtensorModule::revoke_attestation(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+        ));
+
+        assert_eq!(SubtensorModule::get_attestation(&coldkey), None);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            1_000_000_000
+        );
+        System::assert_last_event(
+            Event::AttestationRevoked {
+                coldkey,
+                deposit: AttestationDeposit::<Test>::get(),
+            }
+            .into(),
+        );
+    });
+}
@@ -1,12 +1,24 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::arithmetic_side_effects)]
+#![allow(clippy::indexing_slicing)]
 
-use frame_support::{assert_err, assert_noop, assert_ok, traits::Currency};
+use codec::{Compact, Decode, Encode};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    traits::{Currency, Get},
+};
 use frame_system::Config;
 mod mock;
-use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays};
-use frame_support::sp_runtime::DispatchError;
+use frame_support::dispatch::{DispatchClass, DispatchInfo, DispatchResult, GetDispatchInfo, Pays};
+use frame_support::sp_runtime::{
+    traits::{Hash, SignedExtension},
+    transaction_validity::InvalidTransaction,
+    DispatchError, Percent,
+};
 use mock::*;
+use pallet_subtensor::rpc_info::stake_info::{MAX_NOMINATORS_PAGE_SIZE, MAX_STAKES_PAGE_SIZE};
+use pallet_subtensor::rpc_info::stake_simulation::StakeSimulation;
+use pallet_subtensor::testing::SubnetBuilder;
 use pallet_subtensor::*;
 use sp_core::{H256, U256};
 
@@ -66,6 +78,11 @@ fn test_add_stake_ok_no_emission() {
             hotkey_account_id,
             10000
         ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::StakeAdded {
+            coldkey: coldkey_account_id,
+            hotkey: hotkey_account_id,
+            amount: 9999,
+        }));
 
         // Check if stake has increased
         assert_eq!(
@@ -81,6 +98,38 @@ fn test_add_stake_ok_no_emission() {
     });
 }
 
+#[test]
+fn test_stake_events_encoding() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let amount: u64 = 1_234;
+
+        for event in [
+            Event::<Test>::StakeAdded {
+                coldkey,
+                hotkey,
+                amount,
+            },
+            Event::<Test>::StakeRemoved {
+                coldkey,
+                hotkey,
+                amount,
+            },
+        ] {
+            let encoded = event.encode();
+
+            // The fields follow the variant index in declaration order.
+            let mut fields = (coldkey, hotkey, amount).encode();
+            let mut expected = encoded[..1].to_vec();
+            expected.append(&mut fields);
+            assert_eq!(encoded, expected);
+
+            assert_eq!(Event::<Test>::decode(&mut &encoded[..]).unwrap(), event);
+        }
+    });
+}
+
 #[test]
 fn test_dividends_with_run_to_block() {
     new_test_ext(1).execute_with(|| {
@@ -171,15 +220,13 @@ fn test_add_stake_err_neuron_does_not_belong_to_coldkey() {
         let hotkey_id = U256::from(54544);
         let other_cold_key = U256::from(99498);
         let netuid: u16 = 1;
-        let tempo: u16 = 13;
-        let start_nonce: u64 = 0;
 
-        //add network
-        add_network(netuid, tempo, 0);
-
-        register_ok_neuron(netuid, hotkey_id, coldkey_id, start_nonce);
         // Give it some $$$ in his coldkey balance
-        SubtensorModule::add_balance_to_coldkey_account(&other_cold_key, 100000);
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_neuron(hotkey_id, coldkey_id, 0)
+            .with_balance(other_cold_key, 100000)
+            .build());
 
         // Perform the request which is signed by a different cold key
         let result = SubtensorModule::add_stake(
@@ -441,6 +488,136 @@ fn test_add_stake_rate_limit_exceeded() {
     });
 }
 
+// Test that a coldkey spreading stake operations over its hotkeys hits the coldkey-wide limit
+// with its own error, distinct from the limit on a single hotkey, and that both counters reset
+// on the same interval boundary.
+#[test]
+fn test_add_stake_coldkey_rate_limit_exceeded() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(61337);
+        let hotkey1 = U256::from(561337);
+        let hotkey2 = U256::from(561338);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey1, coldkey, 0);
+        register_ok_neuron(netuid, hotkey2, coldkey, 100_000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 60000);
+        SubtensorModule::set_stake_interval(5);
+        SubtensorModule::set_target_stakes_per_interval(2);
+        assert_eq!(
+            SubtensorModule::get_max_stake_ops_per_interval_per_coldkey(),
+            u64::MAX
+        );
+        SubtensorModule::set_max_stake_ops_per_interval_per_coldkey(3);
+
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+        assert_ok!(SubtensorModule::add_stake(origin.clone(), hotkey1, 1));
+        assert_ok!(SubtensorModule::add_stake(origin.clone(), hotkey1, 1));
+        assert_err!(
+            SubtensorModule::add_stake(origin.clone(), hotkey1, 1),
+            Error::<Test>::StakeRateLimitExceeded
+        );
+        assert_ok!(SubtensorModule::add_stake(origin.clone(), hotkey2, 1));
+        assert_err!(
+            SubtensorModule::add_stake(origin.clone(), hotkey2, 1),
+            Error::<Test>::ColdkeyStakeRateLimitExceeded
+        );
+        assert_err!(
+            SubtensorModule::remove_stake(origin.clone(), hotkey2, 1),
+            Error::<Test>::ColdkeyStakeRateLimitExceeded
+        );
+
+        let limits = SubtensorModule::get_stake_rate_limits(&coldkey, &hotkey2);
+        assert_eq!(limits.stakes_this_interval, 1.into());
+        assert_eq!(limits.target_stakes_per_interval, 2.into());
+        assert_eq!(limits.coldkey_stake_ops_this_interval, 3.into());
+        assert_eq!(limits.max_stake_ops_per_interval_per_coldkey, 3.into());
+        assert_eq!(limits.stake_interval, 5.into());
+
+        run_to_block(6);
+        let limits = SubtensorModule::get_stake_rate_limits(&coldkey, &hotkey1);
+        assert_eq!(limits.stakes_this_interval, 0.into());
+        assert_eq!(limits.coldkey_stake_ops_this_interval, 0.into());
+        assert_ok!(SubtensorModule::add_stake(origin, hotkey1, 1));
+        assert_eq!(
+            SubtensorModule::get_stake_ops_this_interval_for_coldkey(&coldkey),
+            1
+        );
+    });
+}
+
+// Test that the cached fee discount tier of a coldkey follows its total stake across the
+// thresholds, and that a coldkey without stake gets no discount.
+#[test]
+fn test_fee_discount_tier_follows_stake() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(61337);
+        let hotkey = U256::from(561337);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::set_target_stakes_per_interval(10);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        assert_ok!(SubtensorModule::set_stake_fee_discount_thresholds([
+            1_000, 10_000, 100_000
+        ]));
+        assert_eq!(ColdkeyFeeDiscountTier::<Test>::get(coldkey), 0);
+        assert_eq!(SubtensorModule::get_fee_discount(&coldkey), Percent::zero());
+
+        // Staking below the first threshold keeps the coldkey in tier 0.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            999
+        ));
+        assert!(!ColdkeyFeeDiscountTier::<Test>::contains_key(coldkey));
+
+        // Crossing thresholds moves it up, one or several tiers at once.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            1
+        ));
+        assert_eq!(ColdkeyFeeDiscountTier::<Test>::get(coldkey), 1);
+        assert_eq!(
+            SubtensorModule::get_fee_discount(&coldkey),
+            Percent::from_percent(10)
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            99_000
+        ));
+        assert_eq!(ColdkeyFeeDiscountTier::<Test>::get(coldkey), 3);
+        assert_eq!(
+            SubtensorModule::get_fee_discount(&coldkey),
+            Percent::from_percent(50)
+        );
+
+        // Unstaking moves it back down, and an unstaked coldkey has no tier left.
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            95_000
+        ));
+        assert_eq!(ColdkeyFeeDiscountTier::<Test>::get(coldkey), 1);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            5_000
+        ));
+        assert!(!ColdkeyFeeDiscountTier::<Test>::contains_key(coldkey));
+        assert_eq!(SubtensorModule::get_fee_discount(&coldkey), Percent::zero());
+
+        // A zero threshold does not give unstaked coldkeys a discount.
+        assert_ok!(SubtensorModule::set_stake_fee_discount_thresholds([
+            0, 10_000, 100_000
+        ]));
+        assert_eq!(SubtensorModule::fee_discount_tier_for_stake(0), 0);
+        assert_eq!(SubtensorModule::fee_discount_tier_for_stake(1), 1);
+    });
+}
+
 // /***********************************************************
 // 	staking::remove_stake() tests
 // ************************************************************/
@@ -531,7 +708,7 @@ fn test_remove_stake_dispatch_info_ok() {
         assert_eq!(
             call.get_dispatch_info(),
             DispatchInfo {
-                weight: frame_support::weights::Weight::from_parts(1_061_000_000, 0)
+                weight: frame_support::weights::Weight::from_parts(2_636_000_000, 0)
                     .add_proof_size(43991),
                 class: DispatchClass::Normal,
                 pays_fee: Pays::No
@@ -586,6 +763,205 @@ fn test_remove_stake_ok_no_emission() {
     });
 }
 
+#[test]
+fn test_remove_stake_one_rao_to_new_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &coldkey_account_id,
+            &hotkey_account_id,
+            1,
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 0);
+
+        // With an existential deposit of 1 the new account is created with 1 rao.
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            1
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 1);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(
+                &coldkey_account_id,
+                &hotkey_account_id
+            ),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_remove_stake_below_existential_deposit_keeps_stake() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &coldkey_account_id,
+            &hotkey_account_id,
+            1,
+        );
+        ExistentialDeposit::set(500);
+        let total_issuance = SubtensorModule::get_total_issuance();
+
+        // Crediting 1 rao to a brand-new coldkey would be dusted.
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id,
+                1
+            ),
+            Error::<Test>::AmountTooLowToCreateAccount
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(
+                &coldkey_account_id,
+                &hotkey_account_id
+            ),
+            1
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 0);
+        assert_eq!(SubtensorModule::get_total_issuance(), total_issuance);
+    });
+}
+
+#[test]
+fn test_add_balance_to_coldkey_account_or_burn_dust() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        ExistentialDeposit::set(500);
+        SubtensorModule::set_total_issuance(1_000);
+
+        // Nothing is credited to a brand-new coldkey and the dust is burned from the issuance.
+        assert_eq!(
+            SubtensorModule::add_balance_to_coldkey_account_or_burn(&coldkey_account_id, 1),
+            0
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 0);
+        assert_eq!(SubtensorModule::get_total_issuance(), 999);
+
+        // Amounts above the existential deposit are credited in full.
+        assert_eq!(
+            SubtensorModule::add_balance_to_coldkey_account_or_burn(&coldkey_account_id, 600),
+            600
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            600
+        );
+        assert_eq!(SubtensorModule::get_total_issuance(), 999);
+    });
+}
+
+#[test]
+fn test_remove_stake_full_position_sentinel() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &coldkey_account_id,
+            &hotkey_account_id,
+            10_000,
+        );
+
+        // Emission compounds onto the position after the client computed its amount.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &coldkey_account_id,
+            &hotkey_account_id,
+            123,
+        );
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            u64::MAX
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::StakeRemoved {
+            coldkey: coldkey_account_id,
+            hotkey: hotkey_account_id,
+            amount: 10_123,
+        }));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            10_123
+        );
+        assert!(!Stake::<Test>::contains_key(
+            hotkey_account_id,
+            coldkey_account_id
+        ));
+        assert!(!StakingHotkeys::<Test>::get(coldkey_account_id).contains(&hotkey_account_id));
+        assert_eq!(SubtensorModule::get_total_stake(), 0);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&coldkey_account_id),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_remove_stake_full_position_sentinel_empty_position() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_neuron(hotkey_account_id, coldkey_account_id, 0)
+            .build());
+
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id,
+                u64::MAX
+            ),
+            Error::<Test>::StakeToWithdrawIsZero
+        );
+    });
+}
+
+#[test]
+fn test_remove_stake_partial_amount_keeps_position() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_neuron(hotkey_account_id, coldkey_account_id, 10_000)
+            .build());
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            4_000
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::StakeRemoved {
+            coldkey: coldkey_account_id,
+            hotkey: hotkey_account_id,
+            amount: 4_000,
+        }));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(
+                &coldkey_account_id,
+                &hotkey_account_id
+            ),
+            6_000
+        );
+        assert!(StakingHotkeys::<Test>::get(coldkey_account_id).contains(&hotkey_account_id));
+    });
+}
+
 #[test]
 fn test_remove_stake_amount_zero() {
     new_test_ext(1).execute_with(|| {
@@ -1060,10 +1436,10 @@ fn test_hotkey_belongs_to_coldkey_ok() {
         let hotkey_id = U256::from(4434334);
         let coldkey_id = U256::from(34333);
         let netuid: u16 = 1;
-        let tempo: u16 = 13;
-        let start_nonce: u64 = 0;
-        add_network(netuid, tempo, 0);
-        register_ok_neuron(netuid, hotkey_id, coldkey_id, start_nonce);
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_neuron(hotkey_id, coldkey_id, 0)
+            .build());
         assert_eq!(
             SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey_id),
             coldkey_id
@@ -1110,11 +1486,10 @@ fn test_has_enough_stake_yes() {
         let coldkey_id = U256::from(87989);
         let intial_amount = 10000;
         let netuid = 1;
-        let tempo: u16 = 13;
-        let start_nonce: u64 = 0;
-        add_network(netuid, tempo, 0);
-        register_ok_neuron(netuid, hotkey_id, coldkey_id, start_nonce);
-        SubtensorModule::increase_stake_on_hotkey_account(&hotkey_id, intial_amount);
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_neuron(hotkey_id, coldkey_id, intial_amount)
+            .build());
         assert_eq!(
             SubtensorModule::get_total_stake_for_hotkey(&hotkey_id),
             10000
@@ -2051,40 +2426,149 @@ fn test_rate_limits_enforced_on_increase_take() {
     });
 }
 
+// Verify a hotkey can not become a delegate while its owner has less stake on it than the minimum
 #[test]
-fn test_get_total_delegated_stake_after_unstaking() {
+fn test_become_delegate_below_owner_stake_minimum() {
     new_test_ext(1).execute_with(|| {
-        let netuid = 1u16;
-        let delegate_coldkey = U256::from(1);
-        let delegate_hotkey = U256::from(2);
-        let delegator = U256::from(3);
-        let initial_stake = 2000;
-        let unstake_amount = 500;
-        let existential_deposit = 1; // Account for the existential deposit
-
+        let netuid = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(3);
         add_network(netuid, 0, 0);
-        register_ok_neuron(netuid, delegate_hotkey, delegate_coldkey, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+        SubtensorModule::set_min_owner_stake_to_delegate(10_000);
 
-        // Make the account a delegate
+        assert_noop!(
+            SubtensorModule::become_delegate(RuntimeOrigin::signed(coldkey), hotkey),
+            Error::<Test>::DelegateBelowOwnerStakeMinimum
+        );
+        assert!(!SubtensorModule::hotkey_is_delegate(&hotkey));
+
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            10_000
+        ));
         assert_ok!(SubtensorModule::become_delegate(
-            RuntimeOrigin::signed(delegate_coldkey),
-            delegate_hotkey
+            RuntimeOrigin::signed(coldkey),
+            hotkey
         ));
+        assert!(SubtensorModule::hotkey_is_delegate(&hotkey));
+    });
+}
 
-        // Add balance to delegator
-        SubtensorModule::add_balance_to_coldkey_account(&delegator, initial_stake);
+// Verify a delegate whose owner unstakes below the minimum rejects new nominations, lets existing
+// nominators unstake, and accepts nominations again once the owner restakes
+#[test]
+fn test_nomination_rejected_while_owner_stake_below_minimum() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = 1;
+        let hotkey = U256::from(1);
+        let owner = U256::from(3);
+        let nominator = U256::from(4);
+        let new_nominator = U256::from(5);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, owner, 124124);
+        SubtensorModule::set_target_stakes_per_interval(10);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 100_000);
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 100_000);
+        SubtensorModule::add_balance_to_coldkey_account(&new_nominator, 100_000);
+        SubtensorModule::set_min_owner_stake_to_delegate(10_000);
 
-        // Delegate stake
         assert_ok!(SubtensorModule::add_stake(
-            RuntimeOrigin::signed(delegator),
-            delegate_hotkey,
-            initial_stake
+            RuntimeOrigin::signed(owner),
+            hotkey,
+            10_000
         ));
-
-        // Check initial delegated stake
-        assert_eq!(
-            SubtensorModule::get_total_delegated_stake(&delegate_coldkey),
-            initial_stake - existential_deposit,
+        assert_ok!(SubtensorModule::become_delegate(
+            RuntimeOrigin::signed(owner),
+            hotkey
+        ));
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkey,
+            20_000
+        ));
+
+        // The owner drops below the minimum: new nominations are rejected.
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(owner),
+            hotkey,
+            5_000
+        ));
+        assert_noop!(
+            SubtensorModule::add_stake(RuntimeOrigin::signed(new_nominator), hotkey, 20_000),
+            Error::<Test>::DelegateBelowOwnerStakeMinimum
+        );
+        assert_noop!(
+            SubtensorModule::add_stake(RuntimeOrigin::signed(nominator), hotkey, 20_000),
+            Error::<Test>::DelegateBelowOwnerStakeMinimum
+        );
+
+        // Existing nominators can still leave, and the owner can still stake.
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkey,
+            10_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey),
+            10_000
+        );
+
+        // Once the owner restakes to the minimum, nominations are accepted again.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(owner),
+            hotkey,
+            5_000
+        ));
+        assert!(SubtensorModule::owner_stake_meets_delegate_minimum(&hotkey));
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(new_nominator),
+            hotkey,
+            20_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&new_nominator, &hotkey),
+            20_000
+        );
+    });
+}
+
+#[test]
+fn test_get_total_delegated_stake_after_unstaking() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = 1u16;
+        let delegate_coldkey = U256::from(1);
+        let delegate_hotkey = U256::from(2);
+        let delegator = U256::from(3);
+        let initial_stake = 2000;
+        let unstake_amount = 500;
+        let existential_deposit = 1; // Account for the existential deposit
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, delegate_hotkey, delegate_coldkey, 0);
+
+        // Make the account a delegate
+        assert_ok!(SubtensorModule::become_delegate(
+            RuntimeOrigin::signed(delegate_coldkey),
+            delegate_hotkey
+        ));
+
+        // Add balance to delegator
+        SubtensorModule::add_balance_to_coldkey_account(&delegator, initial_stake);
+
+        // Delegate stake
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(delegator),
+            delegate_hotkey,
+            initial_stake
+        ));
+
+        // Check initial delegated stake
+        assert_eq!(
+            SubtensorModule::get_total_delegated_stake(&delegate_coldkey),
+            initial_stake - existential_deposit,
             "Initial delegated stake is incorrect"
         );
 
@@ -2306,3 +2790,2658 @@ fn test_get_total_delegated_stake_exclude_owner_stake() {
         );
     });
 }
+
+/***********************************************************
+    coldkey auth tests
+************************************************************/
+
+// Sets up a coldkey with `stake` on a registered hotkey and an auth key with `threshold`.
+fn setup_coldkey_auth(coldkey: U256, hotkey: U256, auth_key: U256, stake: u64, threshold: u64) {
+    add_network(1, 13, 0);
+    register_ok_neuron(1, hotkey, coldkey, 0);
+    SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake);
+    assert_ok!(SubtensorModule::set_coldkey_auth(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+        Some(auth_key),
+        threshold
+    ));
+}
+
+#[test]
+fn test_coldkey_auth_below_threshold_unstake_unaffected() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let auth_key = U256::from(3);
+        setup_coldkey_auth(coldkey, hotkey, auth_key, 10_000, 1_000);
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            1_000
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 1_000);
+    });
+}
+
+#[test]
+fn test_coldkey_auth_above_threshold_blocked() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let auth_key = U256::from(3);
+        setup_coldkey_auth(coldkey, hotkey, auth_key, 10_000, 1_000);
+
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                1_001
+            ),
+            Error::<Test>::AuthorizationRequired
+        );
+        // The entire position is above the threshold as well.
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                u64::MAX
+            ),
+            Error::<Test>::AuthorizationRequired
+        );
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                U256::from(4)
+            ),
+            Error::<Test>::AuthorizationRequired
+        );
+
+        // The auth key co-signs the unstake.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::remove_stake {
+            hotkey,
+            amount_unstaked: 5_000,
+        });
+        assert_ok!(SubtensorModule::authorized_call(
+            <<Test as Config>::RuntimeOrigin>::signed(auth_key),
+            coldkey,
+            Box::new(call)
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 5_000);
+    });
+}
+
+#[test]
+fn test_coldkey_auth_pre_approval_flow() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let auth_key = U256::from(3);
+        setup_coldkey_auth(coldkey, hotkey, auth_key, 10_000, 1_000);
+
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::remove_stake {
+            hotkey,
+            amount_unstaked: 5_000,
+        });
+        let call_hash = <Test as Config>::Hashing::hash_of(&call);
+
+        // Only the auth key can approve.
+        assert_noop!(
+            SubtensorModule::approve_coldkey_call(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                coldkey,
+                call_hash
+            ),
+            Error::<Test>::NotColdkeyAuthKey
+        );
+        assert_ok!(SubtensorModule::approve_coldkey_call(
+            <<Test as Config>::RuntimeOrigin>::signed(auth_key),
+            coldkey,
+            call_hash
+        ));
+
+        // The approval covers this exact call only.
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                5_001
+            ),
+            Error::<Test>::AuthorizationRequired
+        );
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            5_000
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 5_000);
+
+        // The approval is consumed.
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                5_000
+            ),
+            Error::<Test>::AuthorizationRequired
+        );
+
+        // Approvals expire after the window.
+        assert_ok!(SubtensorModule::approve_coldkey_call(
+            <<Test as Config>::RuntimeOrigin>::signed(auth_key),
+            coldkey,
+            call_hash
+        ));
+        System::set_block_number(
+            System::block_number() + ColdkeyAuthApprovalWindow::<Test>::get() + 1,
+        );
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                5_000
+            ),
+            Error::<Test>::AuthorizationRequired
+        );
+    });
+}
+
+#[test]
+fn test_coldkey_auth_rotation() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let auth_key = U256::from(3);
+        let new_auth_key = U256::from(4);
+        setup_coldkey_auth(coldkey, hotkey, auth_key, 10_000, 1_000);
+
+        // The coldkey alone cannot change or remove the auth key.
+        assert_noop!(
+            SubtensorModule::set_coldkey_auth(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                Some(new_auth_key),
+                1_000
+            ),
+            Error::<Test>::AuthorizationRequired
+        );
+        assert_noop!(
+            SubtensorModule::set_coldkey_auth(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                None,
+                0
+            ),
+            Error::<Test>::AuthorizationRequired
+        );
+
+        // Another key cannot co-sign for the coldkey.
+        let rotate = RuntimeCall::SubtensorModule(SubtensorCall::set_coldkey_auth {
+            auth_key: Some(new_auth_key),
+            threshold_amount: 1_000,
+        });
+        assert_noop!(
+            SubtensorModule::authorized_call(
+                <<Test as Config>::RuntimeOrigin>::signed(new_auth_key),
+                coldkey,
+                Box::new(rotate.clone())
+            ),
+            Error::<Test>::NotColdkeyAuthKey
+        );
+
+        // The current auth key rotates to the new one.
+        assert_ok!(SubtensorModule::authorized_call(
+            <<Test as Config>::RuntimeOrigin>::signed(auth_key),
+            coldkey,
+            Box::new(rotate)
+        ));
+        assert_eq!(
+            ColdkeyAuth::<Test>::get(coldkey),
+            Some((new_auth_key, 1_000))
+        );
+        System::assert_last_event(
+            Event::ColdkeyAuthSet {
+                coldkey,
+                auth_key: Some(new_auth_key),
+                threshold_amount: 1_000,
+            }
+            .into(),
+        );
+
+        // The old auth key no longer authorizes anything.
+        let remove = RuntimeCall::SubtensorModule(SubtensorCall::set_coldkey_auth {
+            auth_key: None,
+            threshold_amount: 0,
+        });
+        assert_noop!(
+            SubtensorModule::authorized_call(
+                <<Test as Config>::RuntimeOrigin>::signed(auth_key),
+                coldkey,
+                Box::new(remove.clone())
+            ),
+            Error::<Test>::NotColdkeyAuthKey
+        );
+
+        // The new auth key removes itself and large unstakes are open again.
+        assert_ok!(SubtensorModule::authorized_call(
+            <<Test as Config>::RuntimeOrigin>::signed(new_auth_key),
+            coldkey,
+            Box::new(remove)
+        ));
+        assert!(!ColdkeyAuth::<Test>::contains_key(coldkey));
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            10_000
+        ));
+    });
+}
+
+#[test]
+fn test_coldkey_auth_authorized_call_rejects_other_calls() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let auth_key = U256::from(3);
+        let attacker = U256::from(4);
+        setup_coldkey_auth(coldkey, hotkey, auth_key, 10_000, 1_000);
+        SubtensorModule::set_transfer_stake_enabled(1, true);
+
+        // The auth key alone can not move the stake or the coldkey to another coldkey.
+        for call in [
+            RuntimeCall::SubtensorModule(SubtensorCall::transfer_stake {
+                destination_coldkey: attacker,
+                hotkey,
+                netuid: 1,
+                amount: 5_000,
+            }),
+            RuntimeCall::SubtensorModule(SubtensorCall::schedule_swap_coldkey {
+                new_coldkey: attacker,
+            }),
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                dest: attacker,
+                value: 1,
+            }),
+        ] {
+            assert_noop!(
+                SubtensorModule::authorized_call(
+                    <<Test as Config>::RuntimeOrigin>::signed(auth_key),
+                    coldkey,
+                    Box::new(call.clone())
+                ),
+                Error::<Test>::CallNotAuthorizable
+            );
+
+            // Nor does the transaction reach the pool.
+            let outer = RuntimeCall::SubtensorModule(SubtensorCall::authorized_call {
+                coldkey,
+                call: Box::new(call),
+            });
+            let info: DispatchInfo = outer.get_dispatch_info();
+            assert_eq!(
+                SubtensorSignedExtension::<Test>::new().validate(&auth_key, &outer, &info, 10),
+                Err(
+                    InvalidTransaction::Custom(CustomTransactionError::CallNotAuthorizable.into())
+                        .into()
+                )
+            );
+        }
+        assert_eq!(Stake::<Test>::get(hotkey, coldkey), 10_000);
+    });
+}
+
+/***********************************************************
+    staking::transfer_stake() tests
+************************************************************/
+
+// Registers `hotkey` for `owner` on a subnet with stake transfers enabled and makes it a delegate.
+fn setup_transfer_stake(netuid: u16, hotkey: U256, owner: U256) {
+    add_network(netuid, 13, 0);
+    register_ok_neuron(netuid, hotkey, owner, 0);
+    Delegates::<Test>::insert(hotkey, SubtensorModule::get_min_delegate_take());
+    SubtensorModule::set_transfer_stake_enabled(netuid, true);
+}
+
+#[test]
+fn test_transfer_stake_disabled() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let source = U256::from(2);
+        let destination = U256::from(3);
+        setup_transfer_stake(netuid, hotkey, source);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&source, &hotkey, 10_000);
+        SubtensorModule::set_transfer_stake_enabled(netuid, false);
+
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(source),
+                destination,
+                hotkey,
+                netuid,
+                1_000
+            ),
+            Error::<Test>::TransferStakeDisabled
+        );
+
+        // The flag is per subnet: enabling it on another subnet of the hotkey allows transfers
+        // through that subnet only.
+        let other_netuid: u16 = 2;
+        add_network(other_netuid, 13, 0);
+        register_ok_neuron(other_netuid, hotkey, source, 0);
+        SubtensorModule::set_transfer_stake_enabled(other_netuid, true);
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(source),
+                destination,
+                hotkey,
+                netuid,
+                1_000
+            ),
+            Error::<Test>::TransferStakeDisabled
+        );
+        assert_ok!(SubtensorModule::transfer_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(source),
+            destination,
+            hotkey,
+            other_netuid,
+            1_000
+        ));
+    });
+}
+
+#[test]
+fn test_transfer_stake_to_self_fails() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let source = U256::from(2);
+        setup_transfer_stake(netuid, hotkey, source);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&source, &hotkey, 10_000);
+
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(source),
+                source,
+                hotkey,
+                netuid,
+                1_000
+            ),
+            Error::<Test>::SameColdkey
+        );
+    });
+}
+
+#[test]
+fn test_transfer_stake_partial() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let source = U256::from(2);
+        let destination = U256::from(3);
+        setup_transfer_stake(netuid, hotkey, source);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&source, &hotkey, 10_000);
+        let total_stake = SubtensorModule::get_total_stake();
+
+        assert_ok!(SubtensorModule::transfer_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(source),
+            destination,
+            hotkey,
+            netuid,
+            4_000
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::StakeTransferred {
+            from: source,
+            to: destination,
+            hotkey,
+            netuid,
+            amount: 4_000,
+        }));
+
+        // The stake moves between the positions without touching balances or totals.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&source, &hotkey),
+            6_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&destination, &hotkey),
+            4_000
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_coldkey(&source), 6_000);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&destination),
+            4_000
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 10_000);
+        assert_eq!(SubtensorModule::get_total_stake(), total_stake);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&source), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&destination), 0);
+        assert!(StakingHotkeys::<Test>::get(source).contains(&hotkey));
+        assert!(StakingHotkeys::<Test>::get(destination).contains(&hotkey));
+
+        // The source cannot transfer more than it has left.
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(source),
+                destination,
+                hotkey,
+                netuid,
+                6_001
+            ),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+    });
+}
+
+#[test]
+fn test_transfer_stake_merges_into_existing_position() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let source = U256::from(2);
+        let destination = U256::from(3);
+        setup_transfer_stake(netuid, hotkey, source);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&source, &hotkey, 10_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&destination, &hotkey, 2_500);
+
+        assert_ok!(SubtensorModule::transfer_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(source),
+            destination,
+            hotkey,
+            netuid,
+            10_000
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&destination, &hotkey),
+            12_500
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&source, &hotkey),
+            0
+        );
+        assert_eq!(
+            StakingHotkeys::<Test>::get(destination)
+                .iter()
+                .filter(|h| **h == hotkey)
+                .count(),
+            1
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 12_500);
+    });
+}
+
+#[test]
+fn test_transfer_stake_respects_unstake_rate_limit() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let source = U256::from(2);
+        let destination = U256::from(3);
+        setup_transfer_stake(netuid, hotkey, source);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&source, &hotkey, 10_000);
+        SubtensorModule::set_target_stakes_per_interval(1);
+
+        assert_ok!(SubtensorModule::transfer_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(source),
+            destination,
+            hotkey,
+            netuid,
+            1_000
+        ));
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(source),
+                destination,
+                hotkey,
+                netuid,
+                1_000
+            ),
+            Error::<Test>::UnstakeRateLimitExceeded
+        );
+    });
+}
+
+/***********************************************************
+    stake simulation tests
+************************************************************/
+
+// Asserts that a simulation reports the outcome of executing the call, moving `moved` TAO on success.
+fn assert_simulation_matches(simulation: StakeSimulation, result: DispatchResult, moved: u64) {
+    match result {
+        Ok(()) => assert_eq!(
+            simulation,
+            StakeSimulation {
+                allowed: true,
+                failure_reason: None,
+                expected_tao: moved.into(),
+            }
+        ),
+        Err(DispatchError::Module(sp_runtime::ModuleError { error, .. })) => {
+            assert!(!simulation.allowed);
+            assert_eq!(simulation.failure_reason, error.first().copied());
+            assert_eq!(moved, 0);
+        }
+        Err(e) => panic!("unexpected error {:?}", e),
+    }
+}
+
+// Simulates remove_stake without touching the state, then executes it and compares the outcome.
+fn assert_remove_stake_simulated(coldkey: U256, hotkey: U256, amount: u64) -> StakeSimulation {
+    let root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+    let simulation = SubtensorModule::simulate_remove_stake(&coldkey, &hotkey, amount);
+    assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root);
+
+    let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+    let result = SubtensorModule::remove_stake(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+        hotkey,
+        amount,
+    );
+    let credited = SubtensorModule::get_coldkey_balance(&coldkey) - balance_before;
+    assert_simulation_matches(simulation.clone(), result, credited);
+    simulation
+}
+
+// Simulates add_stake without touching the state, then executes it and compares the outcome.
+fn assert_add_stake_simulated(coldkey: U256, hotkey: U256, amount: u64) -> StakeSimulation {
+    let root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+    let simulation = SubtensorModule::simulate_add_stake(&coldkey, &hotkey, amount);
+    assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root);
+
+    let stake_before = SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+    let result = SubtensorModule::add_stake(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+        hotkey,
+        amount,
+    );
+    let staked =
+        SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey) - stake_before;
+    assert_simulation_matches(simulation.clone(), result, staked);
+    simulation
+}
+
+fn error_index(error: Error<Test>) -> Option<u8> {
+    codec::Encode::encode(&error).first().copied()
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_simulate_remove_stake_matches_execution -- --nocapture
+#[test]
+fn test_simulate_remove_stake_matches_execution() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator = U256::from(3);
+        add_network(1, 13, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 10_000);
+
+        // Unknown hotkey, foreign hotkey, zero and excessive amounts fail as the call does.
+        let simulation = assert_remove_stake_simulated(coldkey, U256::from(9), 1_000);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::HotKeyAccountNotExists)
+        );
+        let simulation = assert_remove_stake_simulated(nominator, hotkey, 1_000);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey)
+        );
+        let simulation = assert_remove_stake_simulated(coldkey, hotkey, 0);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::StakeToWithdrawIsZero)
+        );
+        let simulation = assert_remove_stake_simulated(coldkey, hotkey, 10_001);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::NotEnoughStakeToWithdraw)
+        );
+
+        // A partial unstake credits the amount, the entire position credits what is left.
+        let simulation = assert_remove_stake_simulated(coldkey, hotkey, 4_000);
+        assert_eq!(simulation.expected_tao, 4_000.into());
+        let simulation = assert_remove_stake_simulated(coldkey, hotkey, u64::MAX);
+        assert_eq!(simulation.expected_tao, 6_000.into());
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 10_000);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_simulate_remove_stake_rate_limit -- --nocapture
+#[test]
+fn test_simulate_remove_stake_rate_limit() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        add_network(1, 13, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 10_000);
+        SubtensorModule::set_target_stakes_per_interval(1);
+        SubtensorModule::set_stake_interval(10);
+
+        assert!(assert_remove_stake_simulated(coldkey, hotkey, 1_000).allowed);
+        let simulation = assert_remove_stake_simulated(coldkey, hotkey, 1_000);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::UnstakeRateLimitExceeded)
+        );
+
+        // Once the interval elapsed the simulation allows it, without resetting the counter itself.
+        run_to_block(11);
+        assert!(assert_remove_stake_simulated(coldkey, hotkey, 1_000).allowed);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_simulate_remove_stake_coldkey_auth -- --nocapture
+#[test]
+fn test_simulate_remove_stake_coldkey_auth() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let auth_key = U256::from(3);
+        setup_coldkey_auth(coldkey, hotkey, auth_key, 10_000, 1_000);
+
+        assert!(assert_remove_stake_simulated(coldkey, hotkey, 1_000).allowed);
+        let simulation = assert_remove_stake_simulated(coldkey, hotkey, 1_001);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::AuthorizationRequired)
+        );
+
+        // A live approval of the exact call lets it through, and the simulation leaves it in place.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::remove_stake {
+            hotkey,
+            amount_unstaked: 1_001,
+        });
+        assert_ok!(SubtensorModule::approve_coldkey_call(
+            <<Test as Config>::RuntimeOrigin>::signed(auth_key),
+            coldkey,
+            <Test as Config>::Hashing::hash_of(&call)
+        ));
+        assert!(assert_remove_stake_simulated(coldkey, hotkey, 1_001).allowed);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_simulate_add_stake_matches_execution -- --nocapture
+#[test]
+fn test_simulate_add_stake_matches_execution() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator = U256::from(3);
+        add_network(1, 13, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 10_000);
+        SubtensorModule::set_target_stakes_per_interval(2);
+
+        let simulation = assert_add_stake_simulated(coldkey, hotkey, 20_000);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::NotEnoughBalanceToStake)
+        );
+        let simulation = assert_add_stake_simulated(coldkey, U256::from(9), 1_000);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::HotKeyAccountNotExists)
+        );
+        let simulation = assert_add_stake_simulated(nominator, hotkey, 1_000);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey)
+        );
+
+        // Nominations below the minimum fail once the hotkey is a delegate.
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            SubtensorModule::get_min_delegate_take()
+        ));
+        SubtensorModule::set_nominator_min_required_stake(5_000);
+        let simulation = assert_add_stake_simulated(nominator, hotkey, 1_000);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::NomStakeBelowMinimumThreshold)
+        );
+        let simulation = assert_add_stake_simulated(nominator, hotkey, 5_000);
+        assert_eq!(simulation.expected_tao, 5_000.into());
+
+        // The owner stakes until the rate limit.
+        assert!(assert_add_stake_simulated(coldkey, hotkey, 1_000).allowed);
+        assert!(assert_add_stake_simulated(coldkey, hotkey, 1_000).allowed);
+        let simulation = assert_add_stake_simulated(coldkey, hotkey, 1_000);
+        assert_eq!(
+            simulation.failure_reason,
+            error_index(Error::<Test>::StakeRateLimitExceeded)
+        );
+    });
+}
+
+/***********************************************************
+    call failure info tests
+************************************************************/
+
+// Asserts the last call failure of `who` records `error` with the expected and provided values.
+fn assert_last_call_failure(who: U256, error: Error<Test>, expected: u64, provided: u64) {
+    assert_eq!(
+        LastCallFailureInfo::<Test>::get(who),
+        Some(CallFailureInfo {
+            error: error_index(error).unwrap(),
+            expected,
+            provided,
+            block: SubtensorModule::get_current_block_as_u64(),
+        })
+    );
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_remove_stake_failure_info -- --nocapture
+#[test]
+fn test_remove_stake_failure_info() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let auth_key = U256::from(3);
+        setup_coldkey_auth(coldkey, hotkey, auth_key, 10_000, 5_000);
+        SubtensorModule::set_target_stakes_per_interval(1);
+        let remove_stake = |amount_unstaked: u64| {
+            dispatch_with_signed_extension(
+                coldkey,
+                RuntimeCall::SubtensorModule(SubtensorCall::remove_stake {
+                    hotkey,
+                    amount_unstaked,
+                }),
+            )
+        };
+
+        // The failed call is rolled back, its detail is kept.
+        assert_err!(
+            remove_stake(20_000),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+        assert_last_call_failure(
+            coldkey,
+            Error::<Test>::NotEnoughStakeToWithdraw,
+            10_000,
+            20_000,
+        );
+
+        assert_err!(remove_stake(6_000), Error::<Test>::AuthorizationRequired);
+        assert_last_call_failure(coldkey, Error::<Test>::AuthorizationRequired, 5_000, 6_000);
+
+        // A successful call leaves the last failure in place.
+        assert_ok!(remove_stake(1_000));
+        assert_last_call_failure(coldkey, Error::<Test>::AuthorizationRequired, 5_000, 6_000);
+
+        assert_err!(remove_stake(1_000), Error::<Test>::UnstakeRateLimitExceeded);
+        assert_last_call_failure(coldkey, Error::<Test>::UnstakeRateLimitExceeded, 1, 1);
+
+        // Errors without a detail do not record one.
+        assert_err!(remove_stake(0), Error::<Test>::StakeToWithdrawIsZero);
+        assert_last_call_failure(coldkey, Error::<Test>::UnstakeRateLimitExceeded, 1, 1);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_add_stake_failure_info -- --nocapture
+#[test]
+fn test_add_stake_failure_info() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator = U256::from(3);
+        add_network(1, 13, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 10_000);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            SubtensorModule::get_min_delegate_take()
+        ));
+        SubtensorModule::set_nominator_min_required_stake(5_000);
+        SubtensorModule::set_target_stakes_per_interval(1);
+        let add_stake = |who: U256, amount_staked: u64| {
+            dispatch_with_signed_extension(
+                who,
+                RuntimeCall::SubtensorModule(SubtensorCall::add_stake {
+                    hotkey,
+                    amount_staked,
+                }),
+            )
+        };
+
+        assert_err!(
+            add_stake(coldkey, 20_000),
+            Error::<Test>::NotEnoughBalanceToStake
+        );
+        assert_last_call_failure(
+            coldkey,
+            Error::<Test>::NotEnoughBalanceToStake,
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            20_000,
+        );
+
+        assert_err!(
+            add_stake(nominator, 1_000),
+            Error::<Test>::NomStakeBelowMinimumThreshold
+        );
+        assert_last_call_failure(
+            nominator,
+            Error::<Test>::NomStakeBelowMinimumThreshold,
+            5_000,
+            1_000,
+        );
+
+        assert_ok!(add_stake(coldkey, 1_000));
+        assert_err!(
+            add_stake(coldkey, 1_000),
+            Error::<Test>::StakeRateLimitExceeded
+        );
+        assert_last_call_failure(coldkey, Error::<Test>::StakeRateLimitExceeded, 1, 1);
+    });
+}
+
+/***********************************************************
+    staking::burn_tao() tests
+************************************************************/
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_burn_tao_reduces_issuance -- --nocapture
+#[test]
+fn test_burn_tao_reduces_issuance() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        SubtensorModule::coinbase(10_000);
+        let issuance_before = SubtensorModule::get_total_issuance();
+        let currency_issuance_before = Balances::total_issuance();
+
+        assert_ok!(SubtensorModule::burn_tao(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            3_000
+        ));
+
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 7_000);
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            issuance_before - 3_000
+        );
+        assert_eq!(Balances::total_issuance(), currency_issuance_before - 3_000);
+        System::assert_last_event(
+            Event::TaoBurned {
+                coldkey,
+                amount: 3_000,
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_burn_tao_keeps_existential_deposit -- --nocapture
+#[test]
+fn test_burn_tao_keeps_existential_deposit() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        ExistentialDeposit::set(500);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+
+        assert_noop!(
+            SubtensorModule::burn_tao(<<Test as Config>::RuntimeOrigin>::signed(coldkey), 0),
+            Error::<Test>::AmountToBurnIsZero
+        );
+        assert_noop!(
+            SubtensorModule::burn_tao(<<Test as Config>::RuntimeOrigin>::signed(coldkey), 10_000),
+            Error::<Test>::NotEnoughBalanceToBurn
+        );
+        assert_noop!(
+            SubtensorModule::burn_tao(<<Test as Config>::RuntimeOrigin>::signed(coldkey), 9_501),
+            Error::<Test>::NotEnoughBalanceToBurn
+        );
+
+        // Everything above the existential deposit can be burned.
+        assert_ok!(SubtensorModule::burn_tao(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            9_500
+        ));
+        assert_eq!(Balances::free_balance(coldkey), 500);
+        assert!(System::account_exists(&coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_burn_tao_coldkey_in_swap_schedule -- --nocapture
+#[test]
+fn test_burn_tao_coldkey_in_swap_schedule() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        ColdkeySwapScheduled::<Test>::insert(coldkey, ());
+
+        assert_noop!(
+            SubtensorModule::burn_tao(<<Test as Config>::RuntimeOrigin>::signed(coldkey), 3_000),
+            Error::<Test>::ColdkeyInSwapSchedule
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_burn_tao_counters_accumulate -- --nocapture
+#[test]
+fn test_burn_tao_counters_accumulate() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let other_coldkey = U256::from(2);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        SubtensorModule::add_balance_to_coldkey_account(&other_coldkey, 10_000);
+
+        for amount in [1_000, 2_000] {
+            assert_ok!(SubtensorModule::burn_tao(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                amount
+            ));
+        }
+        assert_ok!(SubtensorModule::burn_tao(
+            <<Test as Config>::RuntimeOrigin>::signed(other_coldkey),
+            500
+        ));
+
+        assert_eq!(SubtensorModule::get_coldkey_lifetime_burn(&coldkey), 3_000);
+        assert_eq!(
+            SubtensorModule::get_coldkey_lifetime_burn(&other_coldkey),
+            500
+        );
+        assert_eq!(TotalBurnedByUsers::<Test>::get(), 3_500);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_lifetime_burn_counts_registration_burn -- --nocapture
+#[test]
+fn test_lifetime_burn_counts_registration_burn() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 10_000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+
+        let owner_cut = SubtensorModule::get_registration_burn_owner_cut(netuid, 10_000);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+
+        // Only the part of the burn not paid to the subnet owner is burned.
+        assert_eq!(
+            SubtensorModule::get_coldkey_lifetime_burn(&coldkey),
+            10_000 - owner_cut
+        );
+        assert_eq!(TotalBurnedByUsers::<Test>::get(), 0);
+    });
+}
+
+/***********************************************************
+    rpc_info::stake_info top stakes tests
+************************************************************/
+
+// Registers `stakes.len()` neurons on `netuid`, staking `stakes[uid]` to each hotkey.
+fn setup_subnet_stakes(netuid: u16, stakes: &[u64]) -> Vec<U256> {
+    add_network(netuid, 0, 0);
+    SubtensorModule::set_max_allowed_uids(netuid, stakes.len() as u16);
+    SubtensorModule::set_max_registrations_per_block(netuid, stakes.len() as u16);
+    SubtensorModule::set_target_registrations_per_interval(netuid, stakes.len() as u16);
+    stakes
+        .iter()
+        .enumerate()
+        .map(|(i, stake)| {
+            let hotkey = U256::from(100 + i);
+            let coldkey = U256::from(1_000 + i);
+            register_ok_neuron(netuid, hotkey, coldkey, i as u64 * 10_000);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, *stake);
+            hotkey
+        })
+        .collect()
+}
+
+#[test]
+fn test_get_top_stakes_on_subnet_sorted_with_ties() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkeys = setup_subnet_stakes(netuid, &[50, 300, 50, 700, 0]);
+
+        // Equal stakes keep their uid order.
+        let page = SubtensorModule::get_top_stakes_on_subnet(netuid, 0, 10);
+        let expected: Vec<(U256, Compact<u64>)> = vec![
+            (hotkeys[3], Compact(700)),
+            (hotkeys[1], Compact(300)),
+            (hotkeys[0], Compact(50)),
+            (hotkeys[2], Compact(50)),
+            (hotkeys[4], Compact(0)),
+        ];
+        assert_eq!(page.stakes, expected);
+        assert_eq!(page.total, Compact(5));
+
+        // Unknown subnets are empty.
+        let page = SubtensorModule::get_top_stakes_on_subnet(2, 0, 10);
+        assert!(page.stakes.is_empty());
+        assert_eq!(page.total, Compact(0));
+    });
+}
+
+#[test]
+fn test_get_top_stakes_on_subnet_paging_across_ties() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkeys = setup_subnet_stakes(netuid, &[10, 20, 20, 20, 30]);
+
+        // The tie at 20 straddles the page boundary without repeating or dropping a hotkey.
+        let first = SubtensorModule::get_top_stakes_on_subnet(netuid, 0, 2);
+        assert_eq!(
+            first.stakes,
+            vec![(hotkeys[4], Compact(30)), (hotkeys[1], Compact(20))]
+        );
+        let second = SubtensorModule::get_top_stakes_on_subnet(netuid, 2, 2);
+        assert_eq!(
+            second.stakes,
+            vec![(hotkeys[2], Compact(20)), (hotkeys[3], Compact(20))]
+        );
+        let third = SubtensorModule::get_top_stakes_on_subnet(netuid, 4, 2);
+        assert_eq!(third.stakes, vec![(hotkeys[0], Compact(10))]);
+        let past_end = SubtensorModule::get_top_stakes_on_subnet(netuid, 5, 2);
+        assert!(past_end.stakes.is_empty());
+        for page in [first, second, third, past_end] {
+            assert_eq!(page.total, Compact(5));
+        }
+    });
+}
+
+#[test]
+fn test_get_top_stakes_on_subnet_page_size_capped() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let count = MAX_STAKES_PAGE_SIZE + 10;
+        add_network(netuid, 0, 0);
+        for uid in 0..count {
+            let hotkey = U256::from(100 + uid);
+            Keys::<Test>::insert(netuid, uid as u16, hotkey);
+            Uids::<Test>::insert(netuid, hotkey, uid as u16);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(1),
+                &hotkey,
+                u64::from(uid) + 1,
+            );
+        }
+
+        let first = SubtensorModule::get_top_stakes_on_subnet(netuid, 0, u32::MAX);
+        assert_eq!(first.stakes.len(), MAX_STAKES_PAGE_SIZE as usize);
+        assert_eq!(
+            first.stakes[0],
+            (U256::from(100 + count - 1), Compact(u64::from(count)))
+        );
+        assert_eq!(first.total, Compact(count));
+
+        let second =
+            SubtensorModule::get_top_stakes_on_subnet(netuid, MAX_STAKES_PAGE_SIZE, u32::MAX);
+        assert_eq!(second.stakes.len(), 10);
+        assert_eq!(second.stakes[9], (U256::from(100), Compact(1)));
+    });
+}
+
+#[test]
+fn test_get_stake_for_hotkey_and_coldkey_on_netuid() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkeys = setup_subnet_stakes(netuid, &[500]);
+        let coldkey = U256::from(1_000);
+        let nominator = U256::from(7);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkeys[0], 200);
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_netuid(
+                &hotkeys[0],
+                &coldkey,
+                netuid
+            ),
+            500
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_netuid(
+                &hotkeys[0],
+                &nominator,
+                netuid
+            ),
+            200
+        );
+        // Zero when the hotkey is not registered on the subnet.
+        add_network(2, 0, 0);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_netuid(&hotkeys[0], &coldkey, 2),
+            0
+        );
+    });
+}
+
+/***********************************************************
+    staking::helpers::prune_coldkey_if_empty() tests
+************************************************************/
+
+// Sets up `nominator` staking `stake` to a delegate hotkey, with an identity.
+fn setup_nominator(nominator: U256, stake: u64) -> U256 {
+    let netuid: u16 = 1;
+    let hotkey = U256::from(10);
+    assert_ok!(SubnetBuilder::<Test>::new(netuid)
+        .tempo(13)
+        .with_neuron(hotkey, U256::from(11), 0)
+        .build());
+    Delegates::<Test>::insert(hotkey, SubtensorModule::get_min_delegate_take());
+
+    SubtensorModule::add_balance_to_coldkey_account(&nominator, stake);
+    assert_ok!(SubtensorModule::add_stake(
+        <<Test as Config>::RuntimeOrigin>::signed(nominator),
+        hotkey,
+        stake
+    ));
+    Identities::<Test>::insert(
+        nominator,
+        ChainIdentity {
+            name: b"nominator".to_vec(),
+            ..Default::default()
+        },
+    );
+    hotkey
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_full_exit_prunes_coldkey_storage -- --nocapture
+#[test]
+fn test_full_exit_prunes_coldkey_storage() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(0xC01D);
+        let hotkey = setup_nominator(nominator, 10_000);
+        assert!(!storage_keys_under_coldkey(&nominator).is_empty());
+
+        // Unstaking everything keeps the coldkey while it holds a balance.
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            u64::MAX
+        ));
+        assert!(SubtensorModule::coldkey_account_exists(&nominator));
+        assert!(Identities::<Test>::get(nominator).is_some());
+
+        // Transferring the balance away queues it, and the next idle weight prunes it.
+        assert_ok!(Balances::transfer_all(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            U256::from(12),
+            false
+        ));
+        assert!(!SubtensorModule::coldkey_account_exists(&nominator));
+        assert!(KilledColdkeys::<Test>::contains_key(nominator));
+        assert!(Identities::<Test>::get(nominator).is_some());
+
+        // Without the weight for one visit the coldkey stays queued.
+        assert_eq!(
+            SubtensorModule::prune_killed_coldkeys(frame_support::weights::Weight::zero()),
+            frame_support::weights::Weight::zero()
+        );
+        assert!(KilledColdkeys::<Test>::contains_key(nominator));
+
+        step_block(1);
+        // Only the identity is kept, it is the user's own data.
+        assert_eq!(
+            storage_keys_under_coldkey(&nominator),
+            vec![Identities::<Test>::hashed_key_for(nominator)]
+        );
+        assert!(Identities::<Test>::get(nominator).is_some());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_coldkey_with_pending_state_is_not_pruned -- --nocapture
+#[test]
+fn test_coldkey_with_pending_state_is_not_pruned() {
+    let pending_states: [fn(&U256); 5] = [
+        |coldkey| SubtensorModule::set_subnet_owner(2, coldkey),
+        |coldkey| ColdkeySwapRedirect::<Test>::insert(coldkey, (U256::from(12), 1_000)),
+        |coldkey| ColdkeySwapRedirectEnabled::<Test>::insert(coldkey, true),
+        |coldkey| AutoRedelegate::<Test>::insert(coldkey, U256::from(10)),
+        |coldkey| HotkeyLimitExempt::<Test>::insert(coldkey, ()),
+    ];
+    for set_pending_state in pending_states {
+        new_test_ext(1).execute_with(|| {
+            let nominator = U256::from(0xC01D);
+            let hotkey = setup_nominator(nominator, 10_000);
+            set_pending_state(&nominator);
+
+            assert_ok!(SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                hotkey,
+                u64::MAX
+            ));
+            assert_ok!(Balances::transfer_all(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                U256::from(12),
+                false
+            ));
+            let _ = SubtensorModule::prune_killed_coldkeys(frame_support::weights::Weight::MAX);
+
+            assert!(SubtensorModule::coldkey_account_exists(&nominator));
+            assert!(LastRateLimitedBlock::<Test>::contains_key(
+                RateLimitedOperation::Transaction,
+                nominator
+            ));
+            assert!(Identities::<Test>::get(nominator).is_some());
+        });
+    }
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_partial_exit_keeps_coldkey_storage -- --nocapture
+#[test]
+fn test_partial_exit_keeps_coldkey_storage() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(0xC01D);
+        let hotkey = setup_nominator(nominator, 10_000);
+        let keys_before = storage_keys_under_coldkey(&nominator);
+
+        // Unstake part of the position and transfer the whole balance away.
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            4_000
+        ));
+        assert_ok!(Balances::transfer_all(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            U256::from(12),
+            false
+        ));
+        let _ = SubtensorModule::prune_killed_coldkeys(frame_support::weights::Weight::MAX);
+
+        assert!(SubtensorModule::coldkey_account_exists(&nominator));
+        assert!(!KilledColdkeys::<Test>::contains_key(nominator));
+        assert_eq!(storage_keys_under_coldkey(&nominator), keys_before);
+        assert_eq!(StakingHotkeys::<Test>::get(nominator), vec![hotkey]);
+        assert_eq!(TotalColdkeyStake::<Test>::get(nominator), 6_000);
+        assert!(Identities::<Test>::get(nominator).is_some());
+        assert!(LastRateLimitedBlock::<Test>::contains_key(
+            RateLimitedOperation::Transaction,
+            nominator
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_prune_keeps_subnet_owner_coldkey -- --nocapture
+#[test]
+fn test_prune_keeps_subnet_owner_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let owner = U256::from(0xC01D);
+        add_network(1, 13, 0);
+        SubtensorModule::set_subnet_owner(1, &owner);
+        SubtensorModule::set_last_tx_block(&owner, 1);
+
+        let _ = SubtensorModule::prune_coldkey_if_empty(&owner);
+        assert!(LastRateLimitedBlock::<Test>::contains_key(
+            RateLimitedOperation::Transaction,
+            owner
+        ));
+
+        // A scheduled swap keeps the coldkey as well.
+        SubtensorModule::set_subnet_owner(1, &U256::from(1));
+        ColdkeySwapScheduled::<Test>::insert(owner, ());
+        let _ = SubtensorModule::prune_coldkey_if_empty(&owner);
+        assert!(LastRateLimitedBlock::<Test>::contains_key(
+            RateLimitedOperation::Transaction,
+            owner
+        ));
+
+        ColdkeySwapScheduled::<Test>::remove(owner);
+        let _ = SubtensorModule::prune_coldkey_if_empty(&owner);
+        assert!(storage_keys_under_coldkey(&owner).is_empty());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_nominator_count_add_remove_and_transfer -- --nocapture
+#[test]
+fn test_nominator_count_add_remove_and_transfer() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let nominator = U256::from(3);
+        let destination = U256::from(4);
+        setup_transfer_stake(netuid, hotkey, owner);
+        SubtensorModule::set_target_stakes_per_interval(10);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 100_000);
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 100_000);
+
+        // The zero stake entry of the owner created on registration is not counted.
+        assert_eq!(SubtensorModule::get_nominator_count(&hotkey, netuid), 0);
+
+        // Only the first stake of a coldkey is counted.
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            10_000
+        ));
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            10_000
+        ));
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            10_000
+        ));
+        assert_eq!(SubtensorModule::get_nominator_count(&hotkey, netuid), 2);
+
+        // Moving part of a position adds the destination, moving all of it replaces the source.
+        assert_ok!(SubtensorModule::transfer_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            destination,
+            hotkey,
+            netuid,
+            5_000
+        ));
+        assert_eq!(SubtensorModule::get_nominator_count(&hotkey, netuid), 3);
+        assert_ok!(SubtensorModule::transfer_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            destination,
+            hotkey,
+            netuid,
+            15_000
+        ));
+        assert_eq!(SubtensorModule::get_nominator_count(&hotkey, netuid), 2);
+
+        // A partial unstake keeps the coldkey counted, a full unstake removes it.
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            4_000
+        ));
+        assert_eq!(SubtensorModule::get_nominator_count(&hotkey, netuid), 2);
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            6_000
+        ));
+        assert_eq!(SubtensorModule::get_nominator_count(&hotkey, netuid), 1);
+
+        // The counter matches the non-zero entries of the stake prefix.
+        let nominations = Stake::<Test>::iter_prefix(hotkey)
+            .filter(|(_, stake)| *stake > 0)
+            .count() as u32;
+        assert_eq!(NominatorCount::<Test>::get(hotkey), nominations);
+
+        // Nothing is reported on a subnet the hotkey is not registered on.
+        assert_eq!(SubtensorModule::get_nominator_count(&hotkey, 2), 0);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_get_nominators_for_hotkey_paging -- --nocapture
+#[test]
+fn test_get_nominators_for_hotkey_paging() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        setup_transfer_stake(netuid, hotkey, owner);
+        for i in [7_u64, 3, 9, 5, 1] {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(100 + i),
+                &hotkey,
+                i * 1_000,
+            );
+        }
+
+        // Pages walk the nominators in the order of their encoded coldkeys.
+        let mut expected: Vec<(U256, Compact<u64>)> = [7_u64, 3, 9, 5, 1]
+            .iter()
+            .map(|i| (U256::from(100 + i), Compact(i * 1_000)))
+            .collect();
+        expected.sort_by_key(|(coldkey, _)| coldkey.encode());
+
+        let first = SubtensorModule::get_nominators_for_hotkey(&hotkey, netuid, 0, 2);
+        assert_eq!(first.nominators, expected[..2].to_vec());
+        assert_eq!(first.total, Compact(5));
+        assert!(first.truncated);
+        let second = SubtensorModule::get_nominators_for_hotkey(&hotkey, netuid, 2, 2);
+        assert_eq!(second.nominators, expected[2..4].to_vec());
+        assert!(second.truncated);
+        let last = SubtensorModule::get_nominators_for_hotkey(&hotkey, netuid, 4, 2);
+        assert_eq!(last.nominators, expected[4..].to_vec());
+        assert!(!last.truncated);
+
+        // The same page is returned on repeated calls.
+        assert_eq!(
+            SubtensorModule::get_nominators_for_hotkey(&hotkey, netuid, 0, 2),
+            first
+        );
+
+        // The page size is capped.
+        let page = SubtensorModule::get_nominators_for_hotkey(
+            &hotkey,
+            netuid,
+            0,
+            MAX_NOMINATORS_PAGE_SIZE + 1,
+        );
+        assert_eq!(page.nominators, expected);
+        assert!(!page.truncated);
+
+        // A subnet the hotkey is not registered on is empty.
+        let page = SubtensorModule::get_nominators_for_hotkey(&hotkey, 2, 0, 2);
+        assert!(page.nominators.is_empty());
+        assert_eq!(page.total, Compact(0));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_staking_paused_blocks_staking_and_swaps -- --nocapture
+#[test]
+fn test_staking_paused_blocks_staking_and_swaps() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let other_coldkey = U256::from(3);
+        let new_hotkey = U256::from(4);
+        setup_transfer_stake(netuid, hotkey, coldkey);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            10_000
+        ));
+
+        SubtensorModule::set_staking_operations_paused(true);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            Event::StakingOperationsPausedSet(true),
+        ));
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+
+        assert_noop!(
+            SubtensorModule::add_stake(origin.clone(), hotkey, 1_000),
+            Error::<Test>::StakingPaused
+        );
+        assert_noop!(
+            SubtensorModule::remove_stake(origin.clone(), hotkey, 1_000),
+            Error::<Test>::StakingPaused
+        );
+        assert_noop!(
+            SubtensorModule::transfer_stake(origin.clone(), other_coldkey, hotkey, netuid, 1_000),
+            Error::<Test>::StakingPaused
+        );
+        assert_noop!(
+            SubtensorModule::swap_hotkey(origin.clone(), hotkey, new_hotkey),
+            Error::<Test>::StakingPaused
+        );
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(origin.clone(), hotkey, new_hotkey, netuid),
+            Error::<Test>::StakingPaused
+        );
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(origin.clone(), other_coldkey),
+            Error::<Test>::StakingPaused
+        );
+
+        // Unpausing restores staking.
+        SubtensorModule::set_staking_operations_paused(false);
+        assert_ok!(SubtensorModule::add_stake(origin, hotkey, 1_000));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            11_000
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_staking_paused_leaves_serving_and_registration -- --nocapture
+#[test]
+fn test_staking_paused_leaves_serving_and_registration() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let new_hotkey = U256::from(5);
+        let coldkey = U256::from(6);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(66), 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000_000_000);
+        SubtensorModule::set_staking_operations_paused(true);
+
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            netuid,
+            2,
+            1676056785,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        assert_eq!(SubtensorModule::get_axon_info(netuid, &hotkey).port, 128);
+
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            new_hotkey
+        ));
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &new_hotkey
+        ));
+    });
+}
+
+/***********************************************************
+    staking::associate_hotkey() tests
+************************************************************/
+
+// Signs the association of `coldkey` by `signer` with `nonce` on the test chain.
+fn sign_association(signer: U256, coldkey: U256, nonce: u64) -> TestSignature {
+    let genesis_hash = frame_system::Pallet::<Test>::block_hash(0);
+    TestSignature(
+        signer,
+        (b"subtensor/associate_hotkey", coldkey, genesis_hash, nonce).encode(),
+    )
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_associate_hotkey_ok --exact --nocapture
+#[test]
+fn test_associate_hotkey_ok() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+
+        assert_ok!(SubtensorModule::associate_hotkey(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            sign_association(hotkey, coldkey, 0)
+        ));
+
+        // The coldkey owns the hotkey, which is not registered anywhere.
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey),
+            coldkey
+        );
+        assert_eq!(SubtensorModule::get_owned_hotkeys(&coldkey), vec![hotkey]);
+        assert!(!SubtensorModule::is_hotkey_registered_on_any_network(
+            &hotkey
+        ));
+        assert_eq!(AssociationNonce::<Test>::get(hotkey), 1);
+        System::assert_last_event(Event::HotkeyAssociated { coldkey, hotkey }.into());
+
+        // The associated hotkey takes stake like a registered one.
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            1_000
+        ));
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 1_000);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_associate_hotkey_rejects_owned_hotkey --exact --nocapture
+#[test]
+fn test_associate_hotkey_rejects_owned_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let owner = U256::from(1);
+        let hijacker = U256::from(2);
+        let hotkey = U256::from(3);
+        add_network(1, 13, 0);
+        register_ok_neuron(1, hotkey, owner, 0);
+
+        // Even a valid signature by the hotkey does not move an owned hotkey.
+        assert_noop!(
+            SubtensorModule::associate_hotkey(
+                RuntimeOrigin::signed(hijacker),
+                hotkey,
+                sign_association(hotkey, hijacker, 0)
+            ),
+            Error::<Test>::HotKeyAlreadyOwned
+        );
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey),
+            owner
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_associate_hotkey_rejects_bad_signature --exact --nocapture
+#[test]
+fn test_associate_hotkey_rejects_bad_signature() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let other_coldkey = U256::from(3);
+
+        // Signed by another key than the hotkey.
+        assert_noop!(
+            SubtensorModule::associate_hotkey(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                sign_association(coldkey, coldkey, 0)
+            ),
+            Error::<Test>::InvalidAssociationSignature
+        );
+        // Signed for another coldkey.
+        assert_noop!(
+            SubtensorModule::associate_hotkey(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                sign_association(hotkey, other_coldkey, 0)
+            ),
+            Error::<Test>::InvalidAssociationSignature
+        );
+        // Signed with a stale nonce.
+        AssociationNonce::<Test>::insert(hotkey, 1);
+        assert_noop!(
+            SubtensorModule::associate_hotkey(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                sign_association(hotkey, coldkey, 0)
+            ),
+            Error::<Test>::InvalidAssociationSignature
+        );
+        // Signed for another chain.
+        let other_chain = TestSignature(
+            hotkey,
+            (b"subtensor/associate_hotkey", coldkey, H256::zero(), 1u64).encode(),
+        );
+        assert_noop!(
+            SubtensorModule::associate_hotkey(RuntimeOrigin::signed(coldkey), hotkey, other_chain),
+            Error::<Test>::InvalidAssociationSignature
+        );
+        assert!(!SubtensorModule::hotkey_account_exists(&hotkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_apply_stake_delta --exact --nocapture
+#[test]
+fn test_apply_stake_delta() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+
+        // Each map is touched once, the fee discount tier is read, and the nominator count is
+        // touched when the stake starts or stops.
+        let weight = SubtensorModule::apply_stake_delta(&coldkey, &hotkey, 1_000, true);
+        assert_eq!(weight, db_weight.reads_writes(7, 5));
+        assert_eq!(Stake::<Test>::get(hotkey, coldkey), 1_000);
+        assert_eq!(TotalColdkeyStake::<Test>::get(coldkey), 1_000);
+        assert_eq!(TotalHotkeyStake::<Test>::get(hotkey), 1_000);
+        assert_eq!(TotalStake::<Test>::get(), 1_000);
+        assert_eq!(NominatorCount::<Test>::get(hotkey), 1);
+
+        let weight = SubtensorModule::apply_stake_delta(&coldkey, &hotkey, 400, false);
+        assert_eq!(weight, db_weight.reads_writes(6, 4));
+        assert_eq!(Stake::<Test>::get(hotkey, coldkey), 600);
+        assert_eq!(TotalStake::<Test>::get(), 600);
+
+        // The entries the delta empties are removed.
+        let weight = SubtensorModule::apply_stake_delta(&coldkey, &hotkey, 600, false);
+        assert_eq!(weight, db_weight.reads_writes(7, 5));
+        assert!(!Stake::<Test>::contains_key(hotkey, coldkey));
+        assert!(!TotalColdkeyStake::<Test>::contains_key(coldkey));
+        assert!(!TotalHotkeyStake::<Test>::contains_key(hotkey));
+        assert_eq!(TotalStake::<Test>::get(), 0);
+        assert_eq!(NominatorCount::<Test>::get(hotkey), 0);
+
+        // A zero delta touches no storage: an entry stored at zero, like the stake of a hotkey
+        // owner, is kept.
+        Stake::<Test>::insert(hotkey, coldkey, 0);
+        let weight = SubtensorModule::apply_stake_delta(&coldkey, &hotkey, 0, false);
+        assert_eq!(weight, frame_support::weights::Weight::zero());
+        assert!(Stake::<Test>::contains_key(hotkey, coldkey));
+
+        // A zero delta on a pair without entries does not create them.
+        let other = U256::from(3);
+        SubtensorModule::apply_stake_delta(&other, &other, 0, true);
+        assert!(!Stake::<Test>::contains_key(other, other));
+        assert!(!TotalColdkeyStake::<Test>::contains_key(other));
+        assert!(!TotalHotkeyStake::<Test>::contains_key(other));
+    });
+}
+
+// Verify every change of the delegate take emits its event and is recorded in the take history
+#[test]
+fn test_delegate_take_change_events_and_history() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        let default_take: u16 = SubtensorModule::get_hotkey_take(&hotkey0);
+        let min_take: u16 = SubtensorModule::get_min_delegate_take();
+
+        // Becoming a delegate changes the take from the default one.
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 10
+        ));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(Event::TakeDecreased {
+            coldkey: coldkey0,
+            hotkey: hotkey0,
+            old_take: default_take,
+            new_take: u16::MAX / 10,
+        }));
+
+        step_block(1);
+        assert_ok!(SubtensorModule::do_decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            min_take
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::TakeDecreased {
+            coldkey: coldkey0,
+            hotkey: hotkey0,
+            old_take: u16::MAX / 10,
+            new_take: min_take,
+        }));
+
+        step_block(1);
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            default_take
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::TakeIncreased {
+            coldkey: coldkey0,
+            hotkey: hotkey0,
+            old_take: min_take,
+            new_take: default_take,
+        }));
+
+        // The history lists the changes oldest first.
+        assert_eq!(
+            TakeHistory::<Test>::get(hotkey0),
+            vec![
+                (1, default_take, u16::MAX / 10),
+                (2, u16::MAX / 10, min_take),
+                (3, min_take, default_take),
+            ]
+        );
+    });
+}
+
+// Verify a take increase waiting for the next drain is recorded once it applies
+#[test]
+fn test_delegate_take_history_records_pending_increase_at_drain() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(0);
+        let owner = U256::from(1);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &owner, &hotkey
+        ));
+        Delegates::<Test>::insert(hotkey, 5_898);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1_000);
+        PendingdHotkeyEmission::<Test>::insert(hotkey, 1_000);
+
+        // The increase is announced at once, but only applied at the drain.
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            11_796
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::TakeIncreased {
+            coldkey: owner,
+            hotkey,
+            old_take: 5_898,
+            new_take: 11_796,
+        }));
+        assert!(TakeHistory::<Test>::get(hotkey).is_empty());
+
+        step_block(1);
+        SubtensorModule::drain_hotkey_emission(&hotkey, 1_000, 2);
+        assert_eq!(TakeHistory::<Test>::get(hotkey), vec![(2, 5_898, 11_796)]);
+    });
+}
+
+// Verify the take history keeps the last changes only, and is returned with the delegate info
+#[test]
+fn test_delegate_take_history_truncated() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        for take in 1..=(TAKE_HISTORY_LEN as u16 + 2) {
+            SubtensorModule::set_delegate_take(&hotkey, take);
+            step_block(1);
+        }
+
+        let history = TakeHistory::<Test>::get(hotkey);
+        assert_eq!(history.len(), TAKE_HISTORY_LEN);
+        assert_eq!(history.first(), Some(&(3, 2, 3)));
+        assert_eq!(
+            history.last(),
+            Some(&(
+                TAKE_HISTORY_LEN as u64 + 2,
+                TAKE_HISTORY_LEN as u16 + 1,
+                TAKE_HISTORY_LEN as u16 + 2
+            ))
+        );
+
+        // Setting the same take again is not a change.
+        SubtensorModule::set_delegate_take(&hotkey, TAKE_HISTORY_LEN as u16 + 2);
+        assert_eq!(TakeHistory::<Test>::get(hotkey), history);
+
+        let encoded_history: Vec<(Compact<u64>, Compact<u16>, Compact<u16>)> = history
+            .iter()
+            .map(|(block, old_take, new_take)| {
+                ((*block).into(), (*old_take).into(), (*new_take).into())
+            })
+            .collect();
+        let delegate_info = SubtensorModule::get_delegate(hotkey.encode()).unwrap();
+        assert!(delegate_info.encode().ends_with(&encoded_history.encode()));
+    });
+}
+
+// Verify a delegate can only be pruned once nobody stakes through it
+#[test]
+fn test_prune_delegate_rejected() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(3);
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        let prune = || {
+            SubtensorModule::prune_delegate(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(7)),
+                hotkey,
+            )
+        };
+
+        assert_noop!(prune(), Error::<Test>::HotKeyNotDelegate);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            SubtensorModule::get_min_delegate_take()
+        ));
+
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        assert_noop!(prune(), Error::<Test>::DelegateHasStake);
+
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        assert_noop!(prune(), Error::<Test>::DelegateIsRegistered);
+
+        IsNetworkMember::<Test>::insert(hotkey, netuid, false);
+        NominatorCount::<Test>::insert(hotkey, 1);
+        assert_noop!(prune(), Error::<Test>::DelegateHasNominators);
+
+        assert!(SubtensorModule::hotkey_is_delegate(&hotkey));
+    });
+}
+
+// Verify pruning removes the delegate and its take history, and the owner can delegate again
+#[test]
+fn test_prune_delegate_ok() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(3);
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        let min_take: u16 = SubtensorModule::get_min_delegate_take();
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            min_take
+        ));
+        assert!(!TakeHistory::<Test>::get(hotkey).is_empty());
+        IsNetworkMember::<Test>::insert(hotkey, netuid, false);
+
+        assert_ok!(SubtensorModule::prune_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(7)),
+            hotkey
+        ));
+        assert!(!SubtensorModule::hotkey_is_delegate(&hotkey));
+        assert!(TakeHistory::<Test>::get(hotkey).is_empty());
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::DelegatePruned(hotkey)));
+        assert_noop!(
+            SubtensorModule::prune_delegate(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(7)),
+                hotkey
+            ),
+            Error::<Test>::HotKeyNotDelegate
+        );
+
+        // The owner still owns the hotkey and can become a delegate again.
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            min_take
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey), min_take);
+    });
+}
+
+// Verify a hotkey listed twice by older stake paths is repaired and reported once
+#[test]
+fn test_staking_hotkeys_duplicates_repaired() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        setup_transfer_stake(netuid, hotkey, coldkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 10_000);
+        StakingHotkeys::<Test>::insert(coldkey, vec![hotkey, hotkey]);
+
+        // Staking again does not push the hotkey a third time.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        assert_eq!(StakingHotkeys::<Test>::get(coldkey), vec![hotkey, hotkey]);
+
+        // Reports dedup on read, and the sync repairs the stored list.
+        assert_eq!(
+            SubtensorModule::get_all_staked_hotkeys(&coldkey),
+            vec![hotkey]
+        );
+        SubtensorModule::sync_staking_hotkeys(&coldkey);
+        assert_eq!(StakingHotkeys::<Test>::get(coldkey), vec![hotkey]);
+    });
+}
+
+// Verify a coldkey stops listing a hotkey once its position on it is gone
+#[test]
+fn test_staking_hotkeys_cleaned_on_full_exit() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let source = U256::from(2);
+        let destination = U256::from(3);
+        setup_transfer_stake(netuid, hotkey, source);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&source, &hotkey, 10_000);
+
+        assert_ok!(SubtensorModule::transfer_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(source),
+            destination,
+            hotkey,
+            netuid,
+            10_000
+        ));
+        assert!(!StakingHotkeys::<Test>::get(source).contains(&hotkey));
+        assert_eq!(StakingHotkeys::<Test>::get(destination), vec![hotkey]);
+
+        // Decreasing part of a position keeps the hotkey listed.
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&destination, &hotkey, 4_000);
+        assert_eq!(StakingHotkeys::<Test>::get(destination), vec![hotkey]);
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&destination, &hotkey, 6_000);
+        assert!(StakingHotkeys::<Test>::get(destination).is_empty());
+    });
+}
+
+// Verify a default take change only applies to the delegates added after it
+#[test]
+fn test_become_delegate_uses_default_take() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = 1;
+        let (hotkey0, coldkey0) = (U256::from(1), U256::from(3));
+        let (hotkey1, coldkey1) = (U256::from(2), U256::from(4));
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        register_ok_neuron(netuid, hotkey1, coldkey1, 987907);
+        let first_take: u16 = SubtensorModule::get_default_delegate_take();
+        let second_take: u16 = SubtensorModule::get_min_delegate_take();
+        assert_ne!(first_take, second_take);
+
+        assert_ok!(SubtensorModule::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), first_take);
+
+        SubtensorModule::set_default_delegate_take(second_take);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            Event::DefaultDelegateTakeSet(second_take),
+        ));
+        assert_ok!(SubtensorModule::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey1),
+            hotkey1
+        ));
+
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey1), second_take);
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), first_take);
+    });
+}
+
+// Registers `hotkey` owned by `owner` on `netuid`, makes it a delegate and stakes `stake` to it
+// from each of `nominators`.
+fn setup_delegate_with_nominators(
+    netuid: u16,
+    hotkey: U256,
+    owner: U256,
+    nonce: u64,
+    nominators: &[U256],
+    stake: u64,
+) {
+    register_ok_neuron(netuid, hotkey, owner, nonce);
+    assert_ok!(SubtensorModule::become_delegate(
+        RuntimeOrigin::signed(owner),
+        hotkey
+    ));
+    for nominator in nominators {
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(nominator, &hotkey, stake);
+    }
+}
+
+// Test that the stake of a nominator with a fallback hotkey moves to it exactly N tempos after the
+// hotkey it stakes on left its last subnet, and not a block earlier.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_auto_redelegate_after_n_tempos -- --nocapture
+#[test]
+fn test_auto_redelegate_after_n_tempos() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        let hotkey = U256::from(1);
+        let fallback = U256::from(2);
+        let owner = U256::from(10);
+        let nominator = U256::from(20);
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        setup_delegate_with_nominators(netuid, hotkey, owner, 0, &[nominator], 1_000);
+        setup_delegate_with_nominators(netuid, fallback, U256::from(11), 10_000, &[], 0);
+        assert_ok!(SubtensorModule::set_auto_redelegate(
+            RuntimeOrigin::signed(nominator),
+            Some(fallback)
+        ));
+
+        assert_ok!(SubtensorModule::deregister(
+            RuntimeOrigin::signed(owner),
+            hotkey,
+            netuid
+        ));
+        let due_block = System::block_number()
+            + SubtensorModule::get_auto_redelegate_after_tempos() * (tempo as u64 + 1);
+        assert_eq!(
+            InactiveHotkeyQueue::<Test>::get(),
+            vec![(hotkey, due_block)]
+        );
+
+        run_to_block(due_block - 1);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey),
+            1_000
+        );
+
+        run_to_block(due_block);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &fallback),
+            1_000
+        );
+        System::assert_has_event(RuntimeEvent::SubtensorModule(Event::StakeAutoRedelegated {
+            coldkey: nominator,
+            from_hotkey: hotkey,
+            to_hotkey: fallback,
+            amount: 1_000,
+        }));
+        assert!(InactiveHotkeyQueue::<Test>::get().is_empty());
+    });
+}
+
+// Test that a nominator whose fallback hotkey is itself off every subnet keeps its stake and is
+// warned once.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_auto_redelegate_inactive_fallback_only_warns -- --nocapture
+#[test]
+fn test_auto_redelegate_inactive_fallback_only_warns() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let fallback = U256::from(2);
+        let owner = U256::from(10);
+        let fallback_owner = U256::from(11);
+        let nominator = U256::from(20);
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_auto_redelegate_after_tempos(1);
+        setup_delegate_with_nominators(netuid, hotkey, owner, 0, &[nominator], 1_000);
+        setup_delegate_with_nominators(netuid, fallback, fallback_owner, 10_000, &[], 0);
+        assert_ok!(SubtensorModule::set_auto_redelegate(
+            RuntimeOrigin::signed(nominator),
+            Some(fallback)
+        ));
+
+        // Both hotkeys leave the subnet. The fallback has no stake, so it is not queued.
+        assert_ok!(SubtensorModule::deregister(
+            RuntimeOrigin::signed(fallback_owner),
+            fallback,
+            netuid
+        ));
+        assert_ok!(SubtensorModule::deregister(
+            RuntimeOrigin::signed(owner),
+            hotkey,
+            netuid
+        ));
+        let (_, due_block) = InactiveHotkeyQueue::<Test>::get()[0];
+        run_to_block(due_block);
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey),
+            1_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &fallback),
+            0
+        );
+        System::assert_has_event(RuntimeEvent::SubtensorModule(Event::StakedHotkeyInactive {
+            hotkey,
+            coldkey: nominator,
+        }));
+        assert!(InactiveHotkeyQueue::<Test>::get().is_empty());
+
+        // The warning is not repeated.
+        run_to_block(due_block + 1);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::SubtensorModule(Event::StakedHotkeyInactive { .. })
+        )));
+    });
+}
+
+// Test that the nominators of an inactive hotkey are handled over several blocks when the idle
+// weight only fits some of them, each exactly once.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking test_auto_redelegate_bounded_per_block -- --nocapture
+#[test]
+fn test_auto_redelegate_bounded_per_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let fallback = U256::from(2);
+        let owner = U256::from(10);
+        let nominators: Vec<U256> = (20..25).map(U256::from).collect();
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        setup_delegate_with_nominators(netuid, hotkey, owner, 0, &nominators, 1_000);
+        setup_delegate_with_nominators(netuid, fallback, U256::from(11), 10_000, &[], 0);
+        for nominator in nominators.iter() {
+            assert_ok!(SubtensorModule::set_auto_redelegate(
+                RuntimeOrigin::signed(*nominator),
+                Some(fallback)
+            ));
+        }
+        assert_ok!(SubtensorModule::deregister(
+            RuntimeOrigin::signed(owner),
+            hotkey,
+            netuid
+        ));
+        let (_, due_block) = InactiveHotkeyQueue::<Test>::get()[0];
+        System::set_block_number(due_block);
+
+        // Two nominators fit per call, so the five nominators take at least three calls.
+        let budget = <Test as frame_system::Config>::DbWeight::get()
+            .reads_writes(2, 2)
+            .saturating_add(SubtensorModule::inactive_nominator_visit_weight().saturating_mul(2));
+        let moved = || {
+            nominators
+                .iter()
+                .filter(|nominator| {
+                    SubtensorModule::get_stake_for_coldkey_and_hotkey(nominator, &fallback) > 0
+                })
+                .count()
+        };
+        let mut calls: usize = 0;
+        while !InactiveHotkeyQueue::<Test>::get().is_empty() {
+            let before = moved();
+            assert!(SubtensorModule::process_inactive_hotkeys(budget).all_lte(budget));
+            calls += 1;
+            assert!(moved() - before <= 2);
+            assert!(calls <= 4);
+        }
+        assert!(calls >= 3);
+        assert_eq!(moved(), nominators.len());
+        for nominator in nominators.iter() {
+            assert_eq!(
+                SubtensorModule::get_stake_for_coldkey_and_hotkey(nominator, &fallback),
+                1_000
+            );
+        }
+
+        // The last call saw the end of the nominators and dropped the hotkey.
+        assert!(InactiveHotkeyQueue::<Test>::get().is_empty());
+        assert!(InactiveHotkeyCursor::<Test>::get().is_none());
+        assert_eq!(
+            SubtensorModule::process_inactive_hotkeys(budget),
+            <Test as frame_system::Config>::DbWeight::get().reads(1)
+        );
+    });
+}
+
+/***********************************************************
+    staking::vesting tests
+************************************************************/
+
+// Returns the balance the vesting lock holds on `coldkey`.
+fn vesting_lock(coldkey: &U256) -> u64 {
+    pallet_balances::Locks::<Test>::get(coldkey)
+        .iter()
+        .find(|lock| lock.id == pallet_subtensor::staking::vesting::VESTING_LOCK_ID)
+        .map_or(0, |lock| lock.amount)
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_vesting_balance_stakeable_but_not_transferable --exact --nocapture
+#[test]
+fn test_vesting_balance_stakeable_but_not_transferable() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let other = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+
+        assert_noop!(
+            SubtensorModule::sudo_create_vesting_schedule(
+                RuntimeOrigin::signed(coldkey),
+                coldkey,
+                8_000,
+                100,
+                1
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::sudo_create_vesting_schedule(
+                RuntimeOrigin::root(),
+                coldkey,
+                20_000,
+                100,
+                1
+            ),
+            Error::<Test>::NotEnoughBalanceToVest
+        );
+        assert_ok!(SubtensorModule::sudo_create_vesting_schedule(
+            RuntimeOrigin::root(),
+            coldkey,
+            8_000,
+            100,
+            1
+        ));
+        assert_eq!(vesting_lock(&coldkey), 8_000);
+        assert_eq!(SubtensorModule::get_vested_balance(&coldkey), 0);
+
+        // Only the balance outside the lock can be transferred.
+        assert!(
+            Balances::transfer_allow_death(RuntimeOrigin::signed(coldkey), other, 5_000).is_err()
+        );
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(coldkey),
+            other,
+            1_000
+        ));
+
+        // The locked balance can be staked, leaving the lock on the rest.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            8_500
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            8_500
+        );
+        assert_eq!(SubtensorModule::get_vesting_staked(&coldkey), 7_500);
+        assert_eq!(vesting_lock(&coldkey), 500);
+        assert!(Balances::transfer_allow_death(RuntimeOrigin::signed(coldkey), other, 1).is_err());
+
+        // The vesting balance keeps its lock on the coldkey, which can not be swapped.
+        assert_eq!(
+            SubtensorModule::get_pending_operation_blocking_swap(&coldkey, &other),
+            Some(PendingOperationKind::VestingSchedule)
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_vesting_lock_shrinks_over_blocks --exact --nocapture
+#[test]
+fn test_vesting_lock_shrinks_over_blocks() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let other = U256::from(3);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000);
+        assert_ok!(SubtensorModule::sudo_create_vesting_schedule(
+            RuntimeOrigin::root(),
+            coldkey,
+            1_000,
+            10,
+            1
+        ));
+        assert_noop!(
+            SubtensorModule::claim_vested(RuntimeOrigin::signed(other)),
+            Error::<Test>::NoVestingSchedule
+        );
+
+        run_to_block(51);
+        assert_eq!(SubtensorModule::get_vested_balance(&coldkey), 500);
+        // The lock is only updated once the vested balance is claimed.
+        assert_eq!(vesting_lock(&coldkey), 1_000);
+        assert!(
+            Balances::transfer_allow_death(RuntimeOrigin::signed(coldkey), other, 500).is_err()
+        );
+
+        assert_ok!(SubtensorModule::claim_vested(RuntimeOrigin::signed(
+            coldkey
+        )));
+        System::assert_last_event(
+            Event::VestedBalanceClaimed {
+                coldkey,
+                vested: 500,
+                unvested: 500,
+            }
+            .into(),
+        );
+        assert_eq!(vesting_lock(&coldkey), 500);
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(coldkey),
+            other,
+            500
+        ));
+
+        // Once fully vested, the schedule and its lock are removed.
+        run_to_block(101);
+        assert_ok!(SubtensorModule::claim_vested(RuntimeOrigin::signed(
+            coldkey
+        )));
+        assert_eq!(vesting_lock(&coldkey), 0);
+        assert!(!VestingSchedules::<Test>::contains_key(coldkey));
+        assert_eq!(SubtensorModule::get_vested_balance(&coldkey), 0);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_vesting_unstake_returns_balance_under_lock --exact --nocapture
+#[test]
+fn test_vesting_unstake_returns_balance_under_lock() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let other = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        // Vesting starts after the test, so the whole total stays unvested.
+        assert_ok!(SubtensorModule::sudo_create_vesting_schedule(
+            RuntimeOrigin::root(),
+            coldkey,
+            8_000,
+            100,
+            1_000
+        ));
+
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            9_000
+        ));
+        assert_eq!(SubtensorModule::get_vesting_staked(&coldkey), 7_000);
+        assert_eq!(vesting_lock(&coldkey), 1_000);
+
+        // The unstaked balance comes back under the lock.
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            3_000
+        ));
+        assert_eq!(SubtensorModule::get_vesting_staked(&coldkey), 6_000);
+        assert_eq!(vesting_lock(&coldkey), 2_000);
+        assert!(
+            Balances::transfer_allow_death(RuntimeOrigin::signed(coldkey), other, 2_001).is_err()
+        );
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(coldkey),
+            other,
+            2_000
+        ));
+    });
+}
+
+// Stakes `stakes` on `hotkey` from the coldkeys 100, 101, ... and funds the correction
+// compensation pool with `pool_balance`, keeping its existential deposit aside.
+fn setup_nominator_compensation(hotkey: U256, stakes: &[u64], pool_balance: u64) -> Vec<U256> {
+    let coldkeys: Vec<U256> = (0..stakes.len() as u64)
+        .map(|index| U256::from(100 + index))
+        .collect();
+    for (coldkey, stake) in coldkeys.iter().zip(stakes) {
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(coldkey, &hotkey, *stake);
+    }
+    SubtensorModule::add_balance_to_coldkey_account(
+        &SubtensorModule::get_correction_compensation_pool(),
+        pool_balance + ExistentialDeposit::get(),
+    );
+    coldkeys
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_compensate_nominators_pro_rata --exact --nocapture
+#[test]
+fn test_compensate_nominators_pro_rata() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 0);
+        let coldkeys = setup_nominator_compensation(hotkey, &[1_000, 2_000, 3_000], 10_000);
+        let pool = SubtensorModule::get_correction_compensation_pool();
+        System::set_block_number(7);
+
+        assert_ok!(SubtensorModule::snapshot_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            netuid
+        ));
+        let snapshot = SubtensorModule::get_nominator_snapshot(netuid, &hotkey).unwrap();
+        assert_eq!(snapshot.block, 7);
+        assert_eq!(snapshot.total_stake, 6_000);
+        assert_eq!(snapshot.nominators.len(), 3);
+
+        // A correction after the snapshot does not change the shares.
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&coldkeys[2], &hotkey, 3_000);
+        assert_ok!(SubtensorModule::compensate_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            netuid,
+            1_000
+        ));
+
+        // The rounding remainder stays in the pool.
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkeys[0]), 166);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkeys[1]), 333);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkeys[2]), 500);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance_keep_alive(&pool),
+            9_001
+        );
+        assert!(SubtensorModule::get_nominator_snapshot(netuid, &hotkey).is_none());
+        System::assert_last_event(
+            Event::NominatorsCompensated {
+                hotkey,
+                netuid,
+                paid: 999,
+                nominators: 3,
+                complete: true,
+            }
+            .into(),
+        );
+        assert_noop!(
+            SubtensorModule::compensate_nominators(RuntimeOrigin::root(), hotkey, netuid, 1_000),
+            Error::<Test>::NoNominatorSnapshot
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_compensate_nominators_paginates --exact --nocapture
+#[test]
+fn test_compensate_nominators_paginates() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let page = MAX_COMPENSATION_PAYOUTS_PER_CALL as usize;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 0);
+        let coldkeys = setup_nominator_compensation(hotkey, &vec![100; page + 6], 100_000);
+        let amount: u64 = 10 * (page as u64 + 6);
+
+        assert_ok!(SubtensorModule::snapshot_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            netuid
+        ));
+        assert_ok!(SubtensorModule::compensate_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            netuid,
+            amount
+        ));
+        let paid = |coldkeys: &[U256]| {
+            coldkeys
+                .iter()
+                .filter(|coldkey| SubtensorModule::get_coldkey_balance(coldkey) == 10)
+                .count()
+        };
+        assert_eq!(paid(&coldkeys), page);
+        assert_eq!(
+            SubtensorModule::get_nominator_snapshot(netuid, &hotkey)
+                .unwrap()
+                .next_index,
+            page as u32
+        );
+
+        // The started payout can neither change its amount nor be replaced.
+        assert_noop!(
+            SubtensorModule::compensate_nominators(RuntimeOrigin::root(), hotkey, netuid, 1),
+            Error::<Test>::CompensationAmountMismatch
+        );
+        assert_noop!(
+            SubtensorModule::snapshot_nominators(RuntimeOrigin::root(), hotkey, netuid),
+            Error::<Test>::CompensationInProgress
+        );
+
+        assert_ok!(SubtensorModule::compensate_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            netuid,
+            amount
+        ));
+        assert_eq!(paid(&coldkeys), page + 6);
+        assert!(SubtensorModule::get_nominator_snapshot(netuid, &hotkey).is_none());
+        System::assert_last_event(
+            Event::NominatorsCompensated {
+                hotkey,
+                netuid,
+                paid: 60,
+                nominators: 6,
+                complete: true,
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_compensate_nominators_insufficient_pool --exact --nocapture
+#[test]
+fn test_compensate_nominators_insufficient_pool() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 0);
+        let coldkeys = setup_nominator_compensation(hotkey, &[1_000, 1_000], 999);
+
+        assert_ok!(SubtensorModule::snapshot_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            netuid
+        ));
+        assert_noop!(
+            SubtensorModule::compensate_nominators(RuntimeOrigin::root(), hotkey, netuid, 1_000),
+            Error::<Test>::NotEnoughBalanceInCompensationPool
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkeys[0]), 0);
+        assert_eq!(
+            SubtensorModule::get_nominator_snapshot(netuid, &hotkey)
+                .unwrap()
+                .next_index,
+            0
+        );
+
+        // Only root compensates nominators.
+        assert_noop!(
+            SubtensorModule::compensate_nominators(
+                RuntimeOrigin::signed(coldkeys[0]),
+                hotkey,
+                netuid,
+                998
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::compensate_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            netuid,
+            998
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkeys[0]), 499);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_snapshot_nominators_one_subnet_at_a_time --exact --nocapture
+#[test]
+fn test_snapshot_nominators_one_subnet_at_a_time() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        add_network(1, 13, 0);
+        add_network(2, 13, 0);
+        register_ok_neuron(1, hotkey, U256::from(2), 0);
+        register_ok_neuron(2, hotkey, U256::from(2), 0);
+        setup_nominator_compensation(hotkey, &[1_000, 2_000], 10_000);
+
+        // The stake of the hotkey is not per subnet, so a second snapshot would pay it twice.
+        assert_ok!(SubtensorModule::snapshot_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            1
+        ));
+        assert_noop!(
+            SubtensorModule::snapshot_nominators(RuntimeOrigin::root(), hotkey, 2),
+            Error::<Test>::NominatorSnapshotOnOtherSubnet
+        );
+
+        // Once the payout completes the hotkey can be snapshotted on the other subnet.
+        assert_ok!(SubtensorModule::compensate_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            1,
+            3_000
+        ));
+        assert_ok!(SubtensorModule::snapshot_nominators(
+            RuntimeOrigin::root(),
+            hotkey,
+            2
+        ));
+        assert!(SubtensorModule::get_nominator_snapshot(2, &hotkey).is_some());
+    });
+}
+
+// Test that the payout mode of a coldkey on a hotkey is dropped with the last of its stake.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_removing_all_stake_clears_emission_payout --exact --nocapture
+#[test]
+fn test_removing_all_stake_clears_emission_payout() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator = U256::from(3);
+
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        EmissionPayout::<Test>::insert(hotkey, coldkey, EmissionPayoutMode::ToBalance);
+
+        // A partial decrease keeps the mode.
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 400);
+        assert!(EmissionPayout::<Test>::contains_key(hotkey, coldkey));
+
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 600);
+        assert!(!EmissionPayout::<Test>::contains_key(hotkey, coldkey));
+
+        // Emptying the stake account drops it as well.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1_000);
+        EmissionPayout::<Test>::insert(hotkey, nominator, EmissionPayoutMode::ToBalance);
+        SubtensorModule::empty_stake_on_coldkey_hotkey_account(&nominator, &hotkey);
+        assert!(!EmissionPayout::<Test>::contains_key(hotkey, nominator));
+    });
+}
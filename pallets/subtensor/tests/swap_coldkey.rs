@@ -4,16 +4,93 @@ use frame_support::weights::Weight;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::{Config, RawOrigin};
 mod mock;
+use frame_support::dispatch::{DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo};
 use frame_support::error::BadOrigin;
 use frame_support::traits::schedule::v3::Named as ScheduleNamed;
 use frame_support::traits::schedule::DispatchTime;
+use frame_support::traits::Get;
 use frame_support::traits::OnInitialize;
 use mock::*;
+use pallet_subtensor::testing::SubnetBuilder;
 use pallet_subtensor::*;
 use pallet_subtensor::{Call, ColdkeySwapScheduleDuration, Error};
 use sp_core::H256;
 use sp_core::U256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 use sp_runtime::DispatchError;
+use std::collections::{BTreeMap, BTreeSet};
+
+// Returns every storage key and value, leaving out the events, which are not charged to the
+// extrinsic that deposits them.
+fn storage_snapshot() -> BTreeMap<Vec<u8>, Vec<u8>> {
+    let skipped: Vec<[u8; 32]> = [&b"Events"[..], b"EventCount", b"EventTopics"]
+        .iter()
+        .map(|item| frame_support::storage::storage_prefix(b"System", item))
+        .collect();
+    let mut snapshot = BTreeMap::new();
+    let mut key: Vec<u8> = Vec::new();
+    while let Some(next) = sp_io::storage::next_key(&key) {
+        if !skipped.iter().any(|prefix| next.starts_with(prefix)) {
+            let value = sp_io::storage::get(&next).expect("key was just iterated");
+            snapshot.insert(next.clone(), value.to_vec());
+        }
+        key = next;
+    }
+    snapshot
+}
+
+// Runs `f` and asserts the weight it accounts for covers a write of every storage key it changed.
+fn assert_weight_covers_writes<R>(f: impl FnOnce() -> (R, Weight)) -> R {
+    let before = storage_snapshot();
+    let (result, weight) = f();
+    let after = storage_snapshot();
+    let changed = before
+        .keys()
+        .chain(after.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .count() as u64;
+    let writes = <Test as frame_system::Config>::DbWeight::get().writes(changed);
+    assert!(
+        weight.all_gte(writes),
+        "accounted weight {:?} is below the {} storage writes made",
+        weight,
+        changed
+    );
+    result
+}
+
+// `perform_swap_coldkey`, checking the weight it accrues against the storage it changes.
+fn perform_swap_coldkey_counted(
+    old_coldkey: &U256,
+    new_coldkey: &U256,
+    weight: &mut Weight,
+) -> Result<u32, DispatchError> {
+    let start = *weight;
+    let mut accrued = Weight::zero();
+    let result = assert_weight_covers_writes(|| {
+        let result = SubtensorModule::perform_swap_coldkey(old_coldkey, new_coldkey, &mut accrued);
+        (result, accrued)
+    });
+    *weight = start.saturating_add(accrued);
+    result
+}
+
+// `do_swap_coldkey`, checking the weight it reports against the storage it changes.
+fn do_swap_coldkey_counted(old_coldkey: &U256, new_coldkey: &U256) -> DispatchResultWithPostInfo {
+    assert_weight_covers_writes(|| {
+        let result = SubtensorModule::do_swap_coldkey(old_coldkey, new_coldkey);
+        let weight = match &result {
+            Ok(post_info) => post_info
+                .actual_weight
+                .expect("the swap reports its weight"),
+            // A failed swap is reverted when dispatched, so its weight is not checked.
+            Err(_) => Weight::MAX,
+        };
+        (result, weight)
+    })
+}
 
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_total_hotkey_coldkey_stakes_this_interval --exact --nocapture
 #[test]
@@ -29,7 +106,7 @@ fn test_swap_total_hotkey_coldkey_stakes_this_interval() {
         TotalHotkeyColdkeyStakesThisInterval::<Test>::insert(hotkey, old_coldkey, (stake, block));
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -58,7 +135,7 @@ fn test_swap_subnet_owner() {
         SubnetOwner::<Test>::insert(netuid, old_coldkey);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -68,6 +145,47 @@ fn test_swap_subnet_owner() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_subnet_lock_refund --exact --nocapture
+#[test]
+fn test_swap_coldkey_subnet_lock_refund() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let netuid = 1u16;
+        let lock = 1_000;
+
+        add_network(netuid, 1, 0);
+        SubnetOwner::<Test>::insert(netuid, old_coldkey);
+        SubtensorModule::set_subnet_locked_balance(netuid, lock);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 100_000);
+
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
+        let old_balance = SubtensorModule::get_coldkey_balance(&old_coldkey);
+        let new_balance = SubtensorModule::get_coldkey_balance(&new_coldkey);
+
+        // The old coldkey can no longer dissolve the subnet.
+        assert_noop!(
+            SubtensorModule::dissolve_network(RuntimeOrigin::root(), old_coldkey, netuid),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert_ok!(SubtensorModule::dissolve_network(
+            RuntimeOrigin::root(),
+            new_coldkey,
+            netuid
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&new_coldkey),
+            new_balance + lock
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            old_balance
+        );
+        assert_eq!(SubtensorModule::get_subnet_locked_balance(netuid), 0);
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_stake --exact --nocapture
 #[test]
 fn test_swap_stake() {
@@ -80,7 +198,7 @@ fn test_swap_stake() {
         StakingHotkeys::<Test>::insert(old_coldkey, vec![hotkey]);
         Stake::<Test>::insert(hotkey, old_coldkey, stake);
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -102,7 +220,7 @@ fn test_swap_total_coldkey_stake() {
         TotalColdkeyStake::<Test>::insert(old_coldkey, stake);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -124,7 +242,7 @@ fn test_swap_staking_hotkeys() {
         StakingHotkeys::<Test>::insert(old_coldkey, vec![hotkey]);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -147,7 +265,7 @@ fn test_swap_hotkey_owners() {
         OwnedHotkeys::<Test>::insert(old_coldkey, vec![hotkey]);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -169,7 +287,7 @@ fn test_transfer_remaining_balance() {
         SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, balance);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -188,7 +306,7 @@ fn test_swap_with_no_stake() {
         let new_coldkey = U256::from(2);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -211,7 +329,7 @@ fn test_swap_with_multiple_hotkeys() {
         OwnedHotkeys::<Test>::insert(old_coldkey, vec![hotkey1, hotkey2]);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -240,7 +358,7 @@ fn test_swap_with_multiple_subnets() {
         SubnetOwner::<Test>::insert(netuid2, old_coldkey);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -259,7 +377,7 @@ fn test_swap_with_zero_balance() {
         let new_coldkey = U256::from(2);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -281,12 +399,12 @@ fn test_swap_idempotency() {
         TotalColdkeyStake::<Test>::insert(old_coldkey, stake);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
         ));
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -308,7 +426,7 @@ fn test_swap_with_max_values() {
         TotalColdkeyStake::<Test>::insert(old_coldkey, max_stake);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -330,7 +448,7 @@ fn test_swap_with_non_existent_new_coldkey() {
         TotalColdkeyStake::<Test>::insert(old_coldkey, stake);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -353,7 +471,7 @@ fn test_swap_with_overflow_in_stake_addition() {
         TotalColdkeyStake::<Test>::insert(new_coldkey, 1);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -376,7 +494,7 @@ fn test_swap_with_max_hotkeys() {
         OwnedHotkeys::<Test>::insert(old_coldkey, hotkeys.clone());
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -403,7 +521,7 @@ fn test_swap_effect_on_delegated_stake() {
         Stake::<Test>::insert(hotkey, delegator, stake);
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -440,7 +558,7 @@ fn test_swap_concurrent_modifications() {
         ));
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -468,7 +586,7 @@ fn test_swap_with_invalid_subnet_ownership() {
         SubnetOwner::<Test>::insert(netuid, U256::from(3));
 
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &old_coldkey,
             &new_coldkey,
             &mut weight
@@ -492,16 +610,13 @@ fn test_do_swap_coldkey_success() {
         let swap_cost = SubtensorModule::get_key_swap_cost();
         let free_balance_old = 12345u64 + swap_cost;
 
-        // Setup initial state
-        add_network(netuid, 13, 0);
-        register_ok_neuron(netuid, hotkey1, old_coldkey, 0);
-        register_ok_neuron(netuid, hotkey2, old_coldkey, 0);
-
-        // Add balance to old coldkey
-        SubtensorModule::add_balance_to_coldkey_account(
-            &old_coldkey,
-            stake_amount1 + stake_amount2 + free_balance_old,
-        );
+        // Setup initial state: the old coldkey stakes to both neurons and keeps a free balance
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_balance(old_coldkey, free_balance_old)
+            .with_neuron(hotkey1, old_coldkey, stake_amount1)
+            .with_neuron(hotkey2, old_coldkey, stake_amount2)
+            .build());
 
         // Log initial state
         log::info!(
@@ -517,18 +632,6 @@ fn test_do_swap_coldkey_success() {
             SubtensorModule::get_total_stake_for_coldkey(&new_coldkey)
         );
 
-        // Add stake to the neurons
-        assert_ok!(SubtensorModule::add_stake(
-            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
-            hotkey1,
-            stake_amount1
-        ));
-        assert_ok!(SubtensorModule::add_stake(
-            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
-            hotkey2,
-            stake_amount2
-        ));
-
         // Insert an Identity
         let name: Vec<u8> = b"The fourth Coolest Identity".to_vec();
         let identity: ChainIdentity = ChainIdentity {
@@ -563,7 +666,7 @@ fn test_do_swap_coldkey_success() {
         let total_stake_before_swap = SubtensorModule::get_total_stake();
 
         // Perform the swap
-        assert_ok!(SubtensorModule::do_swap_coldkey(
+        assert_ok!(do_swap_coldkey_counted(
             // <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
             &old_coldkey,
             &new_coldkey
@@ -675,7 +778,7 @@ fn test_swap_stake_for_coldkey() {
         let initial_total_stake = SubtensorModule::get_total_stake();
 
         // Perform the swap
-        SubtensorModule::perform_swap_coldkey(&old_coldkey, &new_coldkey, &mut weight);
+        perform_swap_coldkey_counted(&old_coldkey, &new_coldkey, &mut weight);
 
         // Verify stake is additive, not replaced
         assert_eq!(
@@ -744,7 +847,7 @@ fn test_swap_staking_hotkeys_for_coldkey() {
         TotalStake::<Test>::put(total_stake);
 
         // Perform the swap
-        SubtensorModule::perform_swap_coldkey(&old_coldkey, &new_coldkey, &mut weight);
+        perform_swap_coldkey_counted(&old_coldkey, &new_coldkey, &mut weight);
 
         // Verify StakingHotkeys transfer
         assert_eq!(
@@ -788,7 +891,7 @@ fn test_swap_delegated_stake_for_coldkey() {
         let initial_total_stake = SubtensorModule::get_total_stake();
 
         // Perform the swap
-        SubtensorModule::perform_swap_coldkey(&old_coldkey, &new_coldkey, &mut weight);
+        perform_swap_coldkey_counted(&old_coldkey, &new_coldkey, &mut weight);
 
         // Verify stake transfer
         assert_eq!(Stake::<Test>::get(hotkey1, new_coldkey), stake_amount1);
@@ -838,7 +941,7 @@ fn test_swap_total_hotkey_coldkey_stakes_this_interval_for_coldkey() {
         OwnedHotkeys::<Test>::insert(old_coldkey, vec![hotkey1, hotkey2]);
 
         // Perform the swap
-        SubtensorModule::perform_swap_coldkey(&old_coldkey, &new_coldkey, &mut weight);
+        perform_swap_coldkey_counted(&old_coldkey, &new_coldkey, &mut weight);
 
         // Verify the swap
         assert_eq!(
@@ -880,7 +983,7 @@ fn test_swap_subnet_owner_for_coldkey() {
         TotalNetworks::<Test>::put(3);
 
         // Perform the swap
-        SubtensorModule::perform_swap_coldkey(&old_coldkey, &new_coldkey, &mut weight);
+        perform_swap_coldkey_counted(&old_coldkey, &new_coldkey, &mut weight);
 
         // Verify the swap
         assert_eq!(SubnetOwner::<Test>::get(netuid1), new_coldkey);
@@ -900,20 +1003,21 @@ fn test_do_swap_coldkey_with_subnet_ownership() {
         let swap_cost = SubtensorModule::get_key_swap_cost();
 
         // Setup initial state
-        add_network(netuid, 13, 0);
-        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_neuron(hotkey, old_coldkey, 0)
+            .with_balance(old_coldkey, stake_amount + swap_cost)
+            .build());
 
         // Set TotalNetworks because swap relies on it
         pallet_subtensor::TotalNetworks::<Test>::set(1);
-
-        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, stake_amount + swap_cost);
         SubnetOwner::<Test>::insert(netuid, old_coldkey);
 
         // Populate OwnedHotkeys map
         OwnedHotkeys::<Test>::insert(old_coldkey, vec![hotkey]);
 
         // Perform the swap
-        assert_ok!(SubtensorModule::do_swap_coldkey(&old_coldkey, &new_coldkey));
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
 
         // Verify subnet ownership transfer
         assert_eq!(SubnetOwner::<Test>::get(netuid), new_coldkey);
@@ -928,9 +1032,11 @@ fn test_coldkey_has_associated_hotkeys() {
         let netuid = 1u16;
 
         // Setup initial state
-        add_network(netuid, 13, 0);
-        register_ok_neuron(netuid, hotkey, coldkey, 0);
-        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1000);
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_neuron(hotkey, coldkey, 0)
+            .with_balance(coldkey, 1000)
+            .build());
     });
 }
 
@@ -1157,7 +1263,7 @@ fn test_coldkey_swap_total() {
         let new_coldkey = U256::from(1100);
         assert_eq!(SubtensorModule::get_total_stake_for_coldkey(&coldkey), 600);
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &coldkey,
             &new_coldkey,
             &mut weight
@@ -1299,7 +1405,7 @@ fn test_coldkey_delegations() {
             100
         ));
         let mut weight = Weight::zero();
-        assert_ok!(SubtensorModule::perform_swap_coldkey(
+        assert_ok!(perform_swap_coldkey_counted(
             &coldkey,
             &new_coldkey,
             &mut weight
@@ -1387,14 +1493,11 @@ fn test_schedule_swap_coldkey_execution() {
         let netuid = 1u16;
         let stake_amount = 100;
 
-        add_network(netuid, 13, 0);
-        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
-        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1000000000000000);
-        assert_ok!(SubtensorModule::add_stake(
-            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
-            hotkey,
-            stake_amount
-        ));
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_balance(old_coldkey, 1000000000000000)
+            .with_neuron(hotkey, old_coldkey, stake_amount)
+            .build());
 
         // Check initial ownership
         assert_eq!(
@@ -1541,7 +1644,7 @@ fn test_coldkey_swap_delegate_identity_updated() {
         assert!(Identities::<Test>::get(old_coldkey).is_some());
         assert!(Identities::<Test>::get(new_coldkey).is_none());
 
-        assert_ok!(SubtensorModule::do_swap_coldkey(&old_coldkey, &new_coldkey));
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
 
         assert!(Identities::<Test>::get(old_coldkey).is_none());
         assert!(Identities::<Test>::get(new_coldkey).is_some());
@@ -1577,7 +1680,7 @@ fn test_coldkey_swap_no_identity_no_changes() {
         assert!(Identities::<Test>::get(old_coldkey).is_none());
 
         // Perform the coldkey swap
-        assert_ok!(SubtensorModule::do_swap_coldkey(&old_coldkey, &new_coldkey));
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
 
         // Ensure no identities have been changed
         assert!(Identities::<Test>::get(old_coldkey).is_none());
@@ -1621,10 +1724,1302 @@ fn test_coldkey_swap_no_identity_no_changes_newcoldkey_exists() {
         assert!(Identities::<Test>::get(old_coldkey).is_none());
 
         // Perform the coldkey swap
-        assert_ok!(SubtensorModule::do_swap_coldkey(&old_coldkey, &new_coldkey));
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
 
         // Ensure no identities have been changed
         assert!(Identities::<Test>::get(old_coldkey).is_none());
         assert!(Identities::<Test>::get(new_coldkey).is_some());
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_coldkey_swap_keeps_childkeys_and_take_cooldowns --exact --nocapture
+#[test]
+fn test_coldkey_swap_keeps_childkeys_and_take_cooldowns() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let child = U256::from(4);
+        let netuid = 1u16;
+        let mut weight = Weight::zero();
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(old_coldkey),
+            hotkey,
+            netuid,
+            vec![(100u64, child)]
+        ));
+        SubtensorModule::set_last_tx_block_delegate_take(&old_coldkey, 10);
+        SubtensorModule::set_last_tx_block_childkey_take(&old_coldkey, 20);
+        // The most recent cooldown wins when the new coldkey already has one.
+        SubtensorModule::set_last_tx_block_childkey_take(&new_coldkey, 15);
+
+        assert_ok!(perform_swap_coldkey_counted(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+
+        // The hotkey keeps its relationships and is now controlled by the new coldkey.
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert_eq!(
+            ChildKeys::<Test>::get(hotkey, netuid),
+            vec![(100u64, child)]
+        );
+        assert_eq!(
+            ParentKeys::<Test>::get(child, netuid),
+            vec![(100u64, hotkey)]
+        );
+
+        // The coldkey-scoped take cooldowns are re-keyed to the new coldkey.
+        assert_eq!(
+            SubtensorModule::get_last_tx_block_delegate_take(&new_coldkey),
+            10
+        );
+        assert_eq!(
+            SubtensorModule::get_last_tx_block_childkey_take(&new_coldkey),
+            20
+        );
+        assert!(!LastRateLimitedBlock::<Test>::contains_key(
+            RateLimitedOperation::DelegateTake,
+            old_coldkey
+        ));
+        assert!(!LastRateLimitedBlock::<Test>::contains_key(
+            RateLimitedOperation::ChildKeyTake,
+            old_coldkey
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_schedule_swap_coldkey_weight_limit_boundary --exact --nocapture
+#[test]
+fn test_schedule_swap_coldkey_weight_limit_boundary() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        SubtensorModule::set_coldkey_swap_max_weight_share(100);
+
+        // Find the largest number of staking hotkeys that fits in the limit.
+        let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+        let limit = sp_runtime::PerU16::from_parts(100).mul_floor(max_block.ref_time());
+        let base = SubtensorModule::get_swap_coldkey_weight(&old_coldkey).ref_time();
+        let per_hotkey = <Test as frame_system::Config>::DbWeight::get()
+            .reads_writes(5, 6)
+            .ref_time();
+        let boundary = (limit - base) / per_hotkey;
+        assert!(boundary > 0);
+        let staking_hotkeys: Vec<U256> = (0..=boundary).map(|i| U256::from(1_000 + i)).collect();
+
+        // One hotkey over the boundary is refused.
+        StakingHotkeys::<Test>::insert(old_coldkey, staking_hotkeys.clone());
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                new_coldkey
+            ),
+            Error::<Test>::TooManyEntriesToSwap
+        );
+
+        // Exactly at the boundary the swap is scheduled.
+        StakingHotkeys::<Test>::insert(old_coldkey, staking_hotkeys[..boundary as usize].to_vec());
+        assert!(SubtensorModule::get_swap_coldkey_weight(&old_coldkey).ref_time() <= limit);
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_schedule_swap_coldkey_rejects_owned_hotkeys_over_bound --exact --nocapture
+#[test]
+fn test_schedule_swap_coldkey_rejects_owned_hotkeys_over_bound() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, swap_cost);
+
+        // The new coldkey already owns as many hotkeys as storage holds.
+        let owned: Vec<U256> = (0..MAX_OWNED_HOTKEYS as u64)
+            .map(|i| U256::from(1_000 + i))
+            .collect();
+        OwnedHotkeys::<Test>::insert(new_coldkey, frame_support::BoundedVec::truncate_from(owned));
+        OwnedHotkeys::<Test>::insert(
+            old_coldkey,
+            frame_support::BoundedVec::truncate_from(vec![U256::from(3)]),
+        );
+
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                new_coldkey
+            ),
+            Error::<Test>::TooManyHotkeysForColdkey
+        );
+    });
+}
+
+// A swap that fails midway keeps the cost, the schedule and the hotkeys of the old coldkey.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_failure_reverts_swap --exact --nocapture
+#[test]
+fn test_swap_coldkey_failure_reverts_swap() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, swap_cost + 1_000);
+        Owner::<Test>::insert(hotkey, old_coldkey);
+        OwnedHotkeys::<Test>::insert(
+            old_coldkey,
+            frame_support::BoundedVec::truncate_from(vec![hotkey]),
+        );
+
+        // The swap was scheduled before the new coldkey filled its hotkeys.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+        let owned: Vec<U256> = (0..MAX_OWNED_HOTKEYS as u64)
+            .map(|i| U256::from(1_000 + i))
+            .collect();
+        OwnedHotkeys::<Test>::insert(new_coldkey, frame_support::BoundedVec::truncate_from(owned));
+
+        let balance_before = SubtensorModule::get_coldkey_balance(&old_coldkey);
+        assert_noop!(
+            SubtensorModule::swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::TooManyHotkeysForColdkey
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            balance_before
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert!(ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_declared_weight_grows_with_entries --exact --nocapture
+#[test]
+fn test_swap_coldkey_declared_weight_grows_with_entries() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::swap_coldkey {
+            old_coldkey,
+            new_coldkey,
+        });
+        let empty_weight = call.get_dispatch_info().weight;
+        assert_eq!(
+            empty_weight,
+            SubtensorModule::get_swap_coldkey_weight(&old_coldkey)
+        );
+
+        OwnedHotkeys::<Test>::insert(old_coldkey, vec![U256::from(3), U256::from(4)]);
+        StakingHotkeys::<Test>::insert(old_coldkey, vec![U256::from(3), U256::from(4)]);
+
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        assert_eq!(
+            call.get_dispatch_info().weight,
+            empty_weight
+                .saturating_add(db_weight.reads_writes(1, 4).saturating_mul(2))
+                .saturating_add(db_weight.reads_writes(5, 6).saturating_mul(2))
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_coldkey_swap_counts_toward_key_swap_cost --exact --nocapture
+#[test]
+fn test_coldkey_swap_counts_toward_key_swap_cost() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, swap_cost + 1_000);
+
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
+        assert_eq!(KeySwapsThisInterval::<Test>::get(), 1);
+    });
+}
+
+// Schedules the swap of `old_coldkey`, which owns `hotkey`, and returns its execution block.
+fn schedule_coldkey_swap_with_neuron(
+    old_coldkey: U256,
+    new_coldkey: U256,
+    hotkey: U256,
+) -> BlockNumber {
+    assert_ok!(SubnetBuilder::<Test>::new(1)
+        .tempo(13)
+        .with_balance(old_coldkey, 1000000000000000)
+        .with_neuron(hotkey, old_coldkey, 100)
+        .build());
+    assert_ok!(SubtensorModule::schedule_swap_coldkey(
+        <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+        new_coldkey
+    ));
+    System::block_number() + ColdkeySwapScheduleDuration::<Test>::get()
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_cancel_scheduled_coldkey_swap --exact --nocapture
+#[test]
+fn test_cancel_scheduled_coldkey_swap() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let execution_block = schedule_coldkey_swap_with_neuron(old_coldkey, new_coldkey, hotkey);
+
+        // The swap is a named task of the scheduler.
+        let task_name = SubtensorModule::coldkey_swap_task_name(&old_coldkey);
+        assert_eq!(
+            pallet_scheduler::Lookup::<Test>::get(task_name).map(|(when, _)| when),
+            Some(execution_block)
+        );
+        assert_eq!(
+            ColdkeySwapDestination::<Test>::get(old_coldkey),
+            Some(new_coldkey)
+        );
+
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                old_coldkey
+            ),
+            BadOrigin
+        );
+        assert_ok!(SubtensorModule::cancel_scheduled_coldkey_swap(
+            RawOrigin::Root.into(),
+            old_coldkey
+        ));
+        System::assert_last_event(
+            Event::ColdkeySwapCancelled {
+                old_coldkey,
+                new_coldkey,
+            }
+            .into(),
+        );
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(!pallet_scheduler::Lookup::<Test>::contains_key(task_name));
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_coldkey_swap(RawOrigin::Root.into(), old_coldkey),
+            Error::<Test>::SwapNotScheduled
+        );
+
+        // Nothing is swapped at the execution block.
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_cancel_legacy_coldkey_swap_is_refused --exact --nocapture
+#[test]
+fn test_cancel_legacy_coldkey_swap_is_refused() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        assert_ok!(SubnetBuilder::<Test>::new(1)
+            .tempo(13)
+            .with_balance(old_coldkey, 1000000000000000)
+            .with_neuron(hotkey, old_coldkey, 100)
+            .build());
+
+        // Schedule the swap the way it was before swaps were named: an anonymous task and no
+        // recorded destination.
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        assert_ok!(Scheduler::schedule(
+            RawOrigin::Root.into(),
+            execution_block,
+            None,
+            63,
+            Box::new(RuntimeCall::SubtensorModule(SubtensorCall::swap_coldkey {
+                old_coldkey,
+                new_coldkey,
+            })),
+        ));
+        ColdkeySwapScheduled::<Test>::insert(old_coldkey, ());
+
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_coldkey_swap(RawOrigin::Root.into(), old_coldkey),
+            Error::<Test>::SwapNotCancellable
+        );
+        assert!(ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+
+        // The anonymous task still swaps the coldkey at the execution block.
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_scheduled_coldkey_swap_failing_at_execution_emits_event --exact --nocapture
+#[test]
+fn test_scheduled_coldkey_swap_failing_at_execution_emits_event() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let execution_block = schedule_coldkey_swap_with_neuron(old_coldkey, new_coldkey, hotkey);
+
+        // The destination becomes a hotkey before the swap executes.
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &U256::from(4),
+            &new_coldkey
+        ));
+
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+
+        System::assert_has_event(
+            Event::ColdkeySwapFailed {
+                old_coldkey,
+                new_coldkey,
+                error: Error::<Test>::NewColdKeyIsHotkey.into(),
+            }
+            .into(),
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeySwapDestination::<Test>::contains_key(old_coldkey));
+
+        // The old coldkey can schedule a new swap.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            U256::from(5)
+        ));
+    });
+}
+
+// A scheduled swap failing midway reverts what it already moved, and its burned cost.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_scheduled_coldkey_swap_failing_midway_reverts --exact --nocapture
+#[test]
+fn test_scheduled_coldkey_swap_failing_midway_reverts() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let execution_block = schedule_coldkey_swap_with_neuron(old_coldkey, new_coldkey, hotkey);
+
+        // The new coldkey fills its owned hotkeys before the swap executes.
+        let owned: Vec<U256> = (0..MAX_OWNED_HOTKEYS as u64)
+            .map(|i| U256::from(1_000 + i))
+            .collect();
+        OwnedHotkeys::<Test>::insert(new_coldkey, frame_support::BoundedVec::truncate_from(owned));
+
+        run_to_block(execution_block);
+        let balance_before = SubtensorModule::get_coldkey_balance(&old_coldkey);
+        let issuance_before = SubtensorModule::get_total_issuance();
+        let stake_before = Stake::<Test>::get(hotkey, old_coldkey);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+
+        System::assert_has_event(
+            Event::ColdkeySwapFailed {
+                old_coldkey,
+                new_coldkey,
+                error: Error::<Test>::TooManyHotkeysForColdkey.into(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            balance_before
+        );
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance_before);
+        assert_eq!(Stake::<Test>::get(hotkey, old_coldkey), stake_before);
+        assert_eq!(Stake::<Test>::get(hotkey, new_coldkey), 0);
+        assert_eq!(StakingHotkeys::<Test>::get(old_coldkey), vec![hotkey]);
+        assert!(!ColdkeySwapRedirect::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_scheduled_coldkey_swap_over_weight_limit_fails --exact --nocapture
+#[test]
+fn test_scheduled_coldkey_swap_over_weight_limit_fails() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let execution_block = schedule_coldkey_swap_with_neuron(old_coldkey, new_coldkey, hotkey);
+
+        // The coldkey stakes to more hotkeys than the limit allows after scheduling the swap.
+        SubtensorModule::set_coldkey_swap_max_weight_share(100);
+        let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+        let limit = sp_runtime::PerU16::from_parts(100).mul_floor(max_block.ref_time());
+        let per_hotkey = <Test as frame_system::Config>::DbWeight::get()
+            .reads_writes(5, 6)
+            .ref_time();
+        let staking_hotkeys: Vec<U256> = (0..=limit / per_hotkey)
+            .map(|i| U256::from(1_000 + i))
+            .collect();
+        StakingHotkeys::<Test>::insert(old_coldkey, staking_hotkeys);
+        assert!(SubtensorModule::get_swap_coldkey_weight(&old_coldkey).ref_time() > limit);
+
+        run_to_block(execution_block);
+        let balance_before = SubtensorModule::get_coldkey_balance(&old_coldkey);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+
+        System::assert_has_event(
+            Event::ColdkeySwapFailed {
+                old_coldkey,
+                new_coldkey,
+                error: Error::<Test>::TooManyEntriesToSwap.into(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            balance_before
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_execute_scheduled_coldkey_swap_by_old_coldkey --exact --nocapture
+#[test]
+fn test_execute_scheduled_coldkey_swap_by_old_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let execution_block = schedule_coldkey_swap_with_neuron(old_coldkey, new_coldkey, hotkey);
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(old_coldkey);
+
+        assert_noop!(
+            SubtensorModule::execute_scheduled_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(new_coldkey),
+                old_coldkey,
+                new_coldkey
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::execute_scheduled_coldkey_swap(
+                origin.clone(),
+                old_coldkey,
+                U256::from(5)
+            ),
+            Error::<Test>::ColdkeySwapDestinationMismatch
+        );
+        assert_noop!(
+            SubtensorModule::execute_scheduled_coldkey_swap(
+                origin.clone(),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::ColdkeySwapNotDue
+        );
+
+        // Once due, the old coldkey can execute a swap the scheduler has not serviced yet.
+        run_to_block(execution_block);
+        assert_ok!(SubtensorModule::execute_scheduled_coldkey_swap(
+            origin,
+            old_coldkey,
+            new_coldkey
+        ));
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        System::assert_has_event(
+            Event::ColdkeySwapped {
+                old_coldkey,
+                new_coldkey,
+            }
+            .into(),
+        );
+        assert!(!pallet_scheduler::Lookup::<Test>::contains_key(
+            SubtensorModule::coldkey_swap_task_name(&old_coldkey)
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_sweep_swapped_coldkey --exact --nocapture
+#[test]
+fn test_sweep_swapped_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let sender = U256::from(3);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, swap_cost + 1_000);
+        assert_ok!(SubtensorModule::set_coldkey_swap_redirect(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            true
+        ));
+        System::assert_last_event(
+            Event::ColdkeySwapRedirectSet {
+                coldkey: old_coldkey,
+                enabled: true,
+            }
+            .into(),
+        );
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
+        assert!(!ColdkeySwapRedirectEnabled::<Test>::contains_key(
+            old_coldkey
+        ));
+        assert_eq!(
+            ColdkeySwapRedirect::<Test>::get(old_coldkey),
+            Some((
+                new_coldkey,
+                System::block_number() + ColdkeySwapRedirectDuration::<Test>::get()
+            ))
+        );
+
+        // Someone keeps sending TAO to the old coldkey.
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 5_000);
+        let new_balance = SubtensorModule::get_coldkey_balance(&new_coldkey);
+
+        // Only the old or the new coldkey can sweep it to the new coldkey.
+        assert_noop!(
+            SubtensorModule::sweep_swapped_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(sender),
+                old_coldkey
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::sweep_swapped_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(new_coldkey),
+            old_coldkey
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&old_coldkey), 0);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&new_coldkey),
+            new_balance + 5_000
+        );
+        System::assert_last_event(
+            Event::SwappedColdkeySwept {
+                old_coldkey,
+                new_coldkey,
+                amount: 5_000,
+            }
+            .into(),
+        );
+
+        // Nothing is left to sweep.
+        assert_noop!(
+            SubtensorModule::sweep_swapped_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                old_coldkey
+            ),
+            Error::<Test>::NothingToSweep
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_sweep_swapped_coldkey_expired_redirect --exact --nocapture
+#[test]
+fn test_sweep_swapped_coldkey_expired_redirect() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+        ColdkeySwapRedirectDuration::<Test>::put(10);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, swap_cost + 1_000);
+        SubtensorModule::do_set_coldkey_swap_redirect(&old_coldkey, true);
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
+
+        System::set_block_number(System::block_number() + 10);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 5_000);
+        assert_noop!(
+            SubtensorModule::sweep_swapped_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(new_coldkey),
+                old_coldkey
+            ),
+            Error::<Test>::ColdkeySwapRedirectExpired
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&old_coldkey), 5_000);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_sweep_swapped_coldkey_without_redirect --exact --nocapture
+#[test]
+fn test_sweep_swapped_coldkey_without_redirect() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+
+        // A coldkey that was never swapped has no redirect.
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, swap_cost + 1_000);
+        assert_noop!(
+            SubtensorModule::sweep_swapped_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(3)),
+                old_coldkey
+            ),
+            Error::<Test>::NoColdkeySwapRedirect
+        );
+
+        // A swap records no redirect unless the old coldkey enabled it.
+        let unredirected_coldkey = U256::from(4);
+        SubtensorModule::add_balance_to_coldkey_account(&unredirected_coldkey, swap_cost + 1_000);
+        assert_ok!(do_swap_coldkey_counted(
+            &unredirected_coldkey,
+            &U256::from(5)
+        ));
+        assert!(!ColdkeySwapRedirect::<Test>::contains_key(
+            unredirected_coldkey
+        ));
+
+        // Root removes the redirect so the old coldkey can be reused.
+        SubtensorModule::do_set_coldkey_swap_redirect(&old_coldkey, true);
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
+        assert_noop!(
+            SubtensorModule::clear_coldkey_swap_redirect(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                old_coldkey
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::clear_coldkey_swap_redirect(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            old_coldkey
+        ));
+        System::assert_last_event(Event::ColdkeySwapRedirectCleared { old_coldkey }.into());
+
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 5_000);
+        assert_noop!(
+            SubtensorModule::sweep_swapped_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(3)),
+                old_coldkey
+            ),
+            Error::<Test>::NoColdkeySwapRedirect
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&old_coldkey), 5_000);
+    });
+}
+
+// The dry run reports what the swap would do without changing any state. Under try-runtime the same
+// report is served by `ColdkeySwapDryRunRuntimeApi::execute_coldkey_swap_dry_run` against a fork.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_execute_coldkey_swap_dry_run --exact --nocapture
+#[test]
+fn test_execute_coldkey_swap_dry_run() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid = 1u16;
+
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_balance(old_coldkey, 10_000)
+            .with_neuron(hotkey, old_coldkey, 1_000)
+            .build());
+
+        // The dry run leaves every key untouched.
+        let before = storage_snapshot();
+        let dry_run = SubtensorModule::execute_coldkey_swap_dry_run(&old_coldkey, &new_coldkey);
+        assert_eq!(storage_snapshot(), before);
+        assert!(dry_run.succeeded);
+        assert!(dry_run.weight.any_gt(Weight::zero()));
+        assert_eq!(
+            SubtensorModule::get_owned_hotkeys(&old_coldkey),
+            vec![hotkey]
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&old_coldkey),
+            1_000
+        );
+
+        // It reports the storage prefixes the swap then changes, leaving out the events.
+        let mut weight = Weight::zero();
+        assert_ok!(SubtensorModule::perform_swap_coldkey(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+        let after = storage_snapshot();
+        let changed: BTreeSet<Vec<u8>> = before
+            .keys()
+            .chain(after.keys())
+            .filter(|key| before.get(*key) != after.get(*key))
+            .map(|key| key.get(..32).unwrap_or(key).to_vec())
+            .collect();
+        let events: Vec<[u8; 32]> = [&b"Events"[..], b"EventCount", b"EventTopics"]
+            .iter()
+            .map(|item| frame_support::storage::storage_prefix(b"System", item))
+            .collect();
+        let reported: BTreeSet<Vec<u8>> = dry_run
+            .touched_prefixes
+            .into_iter()
+            .filter(|prefix| !events.iter().any(|event| prefix.as_slice() == event))
+            .collect();
+        assert_eq!(reported, changed);
+        assert!(reported.contains(
+            &frame_support::storage::storage_prefix(b"SubtensorModule", b"OwnedHotkeys").to_vec()
+        ));
+        assert_eq!(weight, dry_run.weight);
+    });
+}
+
+// A swap that would fail is reported as such, and still leaves the state unchanged.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_execute_coldkey_swap_dry_run_failure --exact --nocapture
+#[test]
+fn test_execute_coldkey_swap_dry_run_failure() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let netuid = 1u16;
+
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_neuron(U256::from(100), old_coldkey, 0)
+            .build());
+
+        // The new coldkey already owns as many hotkeys as it can.
+        let owned: Vec<U256> = (0..MAX_OWNED_HOTKEYS as u64)
+            .map(|i| U256::from(1_000 + i))
+            .collect();
+        OwnedHotkeys::<Test>::insert(new_coldkey, frame_support::BoundedVec::truncate_from(owned));
+
+        let before = storage_snapshot();
+        let dry_run = SubtensorModule::execute_coldkey_swap_dry_run(&old_coldkey, &new_coldkey);
+        assert_eq!(storage_snapshot(), before);
+        assert!(!dry_run.succeeded);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_moves_auth_key --exact --nocapture
+#[test]
+fn test_swap_coldkey_moves_auth_key() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let auth_key = U256::from(3);
+        let call_hash = H256::repeat_byte(1);
+
+        ColdkeyAuth::<Test>::insert(old_coldkey, (auth_key, 1_000));
+        ColdkeyAuthApprovals::<Test>::insert(old_coldkey, call_hash, 1);
+
+        let mut weight = Weight::zero();
+        assert_ok!(perform_swap_coldkey_counted(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+
+        // The new coldkey stays protected by the auth key, and the approvals are dropped.
+        assert_eq!(
+            ColdkeyAuth::<Test>::get(new_coldkey),
+            Some((auth_key, 1_000))
+        );
+        assert!(!ColdkeyAuth::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeyAuthApprovals::<Test>::contains_key(
+            old_coldkey,
+            call_hash
+        ));
+
+        // An auth key the new coldkey already has is kept.
+        let other_auth_key = U256::from(4);
+        ColdkeyAuth::<Test>::insert(old_coldkey, (auth_key, 1_000));
+        ColdkeyAuth::<Test>::insert(new_coldkey, (other_auth_key, 500));
+        assert_ok!(perform_swap_coldkey_counted(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+        assert_eq!(
+            ColdkeyAuth::<Test>::get(new_coldkey),
+            Some((other_auth_key, 500))
+        );
+        assert!(!ColdkeyAuth::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_dedups_staking_hotkeys --exact --nocapture
+#[test]
+fn test_swap_coldkey_dedups_staking_hotkeys() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let stake = 100;
+
+        StakingHotkeys::<Test>::insert(old_coldkey, vec![hotkey, hotkey]);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&old_coldkey, &hotkey, stake);
+
+        let mut weight = Weight::zero();
+        assert_ok!(perform_swap_coldkey_counted(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+
+        assert_eq!(StakingHotkeys::<Test>::get(new_coldkey), vec![hotkey]);
+        assert_eq!(Stake::<Test>::get(hotkey, new_coldkey), stake);
+        assert_eq!(NominatorCount::<Test>::get(hotkey), 1);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_keeps_pending_weight_commit --exact --nocapture
+#[test]
+fn test_swap_coldkey_keeps_pending_weight_commit() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let old_coldkey = U256::from(2);
+        let new_coldkey = U256::from(5);
+        add_network(netuid, 1000, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 100000);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        SubtensorModule::set_weight_commit_deposit(1_000);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+        System::set_block_number(0);
+
+        let commit_hash: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            netuid,
+            vec![0_u16, 1],
+            vec![10_u16, 10],
+            vec![1_u16, 2, 3],
+            0_u64,
+        ));
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+
+        assert_ok!(do_swap_coldkey_counted(&old_coldkey, &new_coldkey));
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert!(WeightCommits::<Test>::contains_key(netuid, hotkey));
+
+        // The commit is revealed as usual, and its deposit is refunded to the new coldkey.
+        let balance = SubtensorModule::get_coldkey_balance(&new_coldkey);
+        System::set_block_number(5);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![0, 1],
+            vec![10, 10],
+            vec![1, 2, 3],
+            0
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&new_coldkey),
+            balance + 1_000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&old_coldkey), 0);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_blocked_by_scheduled_dissolution --exact --nocapture
+#[test]
+fn test_swap_coldkey_blocked_by_scheduled_dissolution() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid: u16 = 1;
+        assert_ok!(SubnetBuilder::<Test>::new(netuid)
+            .tempo(13)
+            .with_balance(old_coldkey, 1000000000000000)
+            .with_neuron(hotkey, old_coldkey, 100)
+            .build());
+        SubnetOwner::<Test>::insert(netuid, old_coldkey);
+
+        assert_ok!(SubtensorModule::schedule_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            netuid
+        ));
+        let dissolve_block =
+            System::block_number() + DissolveNetworkScheduleDuration::<Test>::get();
+        assert_eq!(
+            DissolveNetworkScheduledUntil::<Test>::get(old_coldkey),
+            Some(dissolve_block)
+        );
+
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                new_coldkey
+            ),
+            Error::<Test>::PendingOperationsExist
+        );
+        assert_noop!(
+            SubtensorModule::swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::PendingOperationsExist
+        );
+
+        // The dissolution still counts as pending in its execution block.
+        run_to_block(dissolve_block);
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+        assert_noop!(
+            SubtensorModule::swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::PendingOperationsExist
+        );
+
+        run_to_block(dissolve_block + 1);
+        assert_ok!(SubtensorModule::swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            old_coldkey,
+            new_coldkey
+        ));
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert!(!DissolveNetworkScheduledUntil::<Test>::contains_key(
+            old_coldkey
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_scheduled_swap_blocked_by_later_dissolution --exact --nocapture
+#[test]
+fn test_scheduled_swap_blocked_by_later_dissolution() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid: u16 = 1;
+        let execution_block = schedule_coldkey_swap_with_neuron(old_coldkey, new_coldkey, hotkey);
+        SubnetOwner::<Test>::insert(netuid, old_coldkey);
+
+        // The dissolution is scheduled after the swap, so it executes after it.
+        run_to_block(System::block_number() + 1);
+        assert_ok!(SubtensorModule::schedule_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            netuid
+        ));
+
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+
+        System::assert_has_event(
+            Event::ColdkeySwapBlocked {
+                old_coldkey,
+                new_coldkey,
+                kind: PendingOperationKind::ScheduledNetworkDissolution,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::ColdkeySwapFailed {
+                old_coldkey,
+                new_coldkey,
+                error: Error::<Test>::PendingOperationsExist.into(),
+            }
+            .into(),
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert_eq!(SubnetOwner::<Test>::get(netuid), old_coldkey);
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_blocked_by_swap_scheduled_elsewhere --exact --nocapture
+#[test]
+fn test_swap_coldkey_blocked_by_swap_scheduled_elsewhere() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        schedule_coldkey_swap_with_neuron(old_coldkey, new_coldkey, hotkey);
+        let task_name = SubtensorModule::coldkey_swap_task_name(&old_coldkey);
+
+        assert_eq!(
+            SubtensorModule::get_pending_operation_blocking_swap(&old_coldkey, &U256::from(5)),
+            Some(PendingOperationKind::ScheduledColdkeySwap)
+        );
+        assert_noop!(
+            SubtensorModule::swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                old_coldkey,
+                U256::from(5)
+            ),
+            Error::<Test>::PendingOperationsExist
+        );
+
+        // A swap to the scheduled destination carries out the scheduled swap and drops its task.
+        assert_ok!(SubtensorModule::swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            old_coldkey,
+            new_coldkey
+        ));
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert!(!ColdkeySwapDestination::<Test>::contains_key(old_coldkey));
+        assert!(!pallet_scheduler::Lookup::<Test>::contains_key(task_name));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_reports_subnet_owner_mismatch --exact --nocapture
+#[test]
+fn test_swap_coldkey_reports_subnet_owner_mismatch() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let netuid = 1u16;
+        let removed_netuid = 2u16;
+        add_network(netuid, 1, 0);
+        SubnetOwner::<Test>::insert(netuid, old_coldkey);
+        // An owner entry left behind by a subnet that is not added.
+        SubnetOwner::<Test>::insert(removed_netuid, old_coldkey);
+
+        let mut weight = Weight::zero();
+        assert_eq!(
+            perform_swap_coldkey_counted(&old_coldkey, &new_coldkey, &mut weight),
+            Ok(1)
+        );
+
+        // The entry is still skipped.
+        assert_eq!(SubnetOwner::<Test>::get(netuid), new_coldkey);
+        assert_eq!(SubnetOwner::<Test>::get(removed_netuid), old_coldkey);
+        System::assert_last_event(
+            Event::ColdkeySwapAnomalies {
+                old_coldkey,
+                new_coldkey,
+                count: 1,
+                kinds: vec![ColdkeySwapAnomalyKind::SubnetOwnerMismatch],
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_reports_owned_hotkey_owner_mismatch --exact --nocapture
+#[test]
+fn test_swap_coldkey_reports_owned_hotkey_owner_mismatch() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey1 = U256::from(3);
+        let hotkey2 = U256::from(4);
+        let hotkey3 = U256::from(5);
+        OwnedHotkeys::<Test>::insert(old_coldkey, vec![hotkey1, hotkey2, hotkey3]);
+        Owner::<Test>::insert(hotkey1, old_coldkey);
+        Owner::<Test>::insert(hotkey2, U256::from(9));
+
+        let mut weight = Weight::zero();
+        assert_eq!(
+            perform_swap_coldkey_counted(&old_coldkey, &new_coldkey, &mut weight),
+            Ok(2)
+        );
+
+        // Every owned hotkey still moves to the new coldkey.
+        for hotkey in [hotkey1, hotkey2, hotkey3] {
+            assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        }
+        System::assert_last_event(
+            Event::ColdkeySwapAnomalies {
+                old_coldkey,
+                new_coldkey,
+                count: 2,
+                kinds: vec![ColdkeySwapAnomalyKind::OwnedHotkeyOwnerMismatch],
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_reports_staking_hotkey_without_stake --exact --nocapture
+#[test]
+fn test_swap_coldkey_reports_staking_hotkey_without_stake() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey1 = U256::from(3);
+        let hotkey2 = U256::from(4);
+        let stake = 1_000;
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&old_coldkey, &hotkey1, stake);
+        StakingHotkeys::<Test>::insert(old_coldkey, vec![hotkey1, hotkey2]);
+        SubnetOwner::<Test>::insert(1, old_coldkey);
+
+        let mut weight = Weight::zero();
+        assert_eq!(
+            perform_swap_coldkey_counted(&old_coldkey, &new_coldkey, &mut weight),
+            Ok(2)
+        );
+
+        assert_eq!(Stake::<Test>::get(hotkey1, new_coldkey), stake);
+        assert_eq!(
+            StakingHotkeys::<Test>::get(new_coldkey),
+            vec![hotkey1, hotkey2]
+        );
+        System::assert_last_event(
+            Event::ColdkeySwapAnomalies {
+                old_coldkey,
+                new_coldkey,
+                count: 2,
+                kinds: vec![
+                    ColdkeySwapAnomalyKind::SubnetOwnerMismatch,
+                    ColdkeySwapAnomalyKind::StakingHotkeyWithoutStake,
+                ],
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_without_anomalies --exact --nocapture
+#[test]
+fn test_swap_coldkey_without_anomalies() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid = 1u16;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&old_coldkey, &hotkey, 1_000);
+
+        let mut weight = Weight::zero();
+        assert_eq!(
+            perform_swap_coldkey_counted(&old_coldkey, &new_coldkey, &mut weight),
+            Ok(0)
+        );
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::SubtensorModule(Event::ColdkeySwapAnomalies { .. })
+        )));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_merges_hotkeys_sorted --exact --nocapture
+#[test]
+fn test_swap_coldkey_merges_hotkeys_sorted() {
+    let old_coldkey = U256::from(1);
+    let new_coldkey = U256::from(2);
+    let hotkeys = |ids: [u64; 2]| -> Vec<U256> { ids.into_iter().map(U256::from).collect() };
+
+    // The same hotkeys, stored in opposite orders, merge into the same vectors.
+    for (old_hotkeys, new_hotkeys) in [([7, 5], [6, 4]), ([5, 7], [4, 6])] {
+        new_test_ext(1).execute_with(|| {
+            OwnedHotkeys::<Test>::insert(old_coldkey, hotkeys(old_hotkeys));
+            OwnedHotkeys::<Test>::insert(new_coldkey, hotkeys(new_hotkeys));
+            StakingHotkeys::<Test>::insert(old_coldkey, hotkeys(old_hotkeys));
+            StakingHotkeys::<Test>::insert(new_coldkey, hotkeys(new_hotkeys));
+
+            let mut weight = Weight::zero();
+            assert_ok!(perform_swap_coldkey_counted(
+                &old_coldkey,
+                &new_coldkey,
+                &mut weight
+            ));
+
+            let merged: Vec<U256> = [4, 5, 6, 7].into_iter().map(U256::from).collect();
+            assert_eq!(OwnedHotkeys::<Test>::get(new_coldkey).into_inner(), merged);
+            assert_eq!(StakingHotkeys::<Test>::get(new_coldkey), merged);
+            assert_eq!(
+                SubtensorModule::get_owned_hotkeys_sorted(&new_coldkey),
+                merged
+            );
+        });
+    }
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_migrates_attestation --exact --nocapture
+#[test]
+fn test_swap_coldkey_migrates_attestation() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000);
+        assert_ok!(SubtensorModule::attest(
+            RuntimeOrigin::signed(old_coldkey),
+            [1; 32]
+        ));
+        let attestation = SubtensorModule::get_attestation(&old_coldkey);
+
+        let mut weight = Weight::zero();
+        assert_ok!(perform_swap_coldkey_counted(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+
+        assert_eq!(SubtensorModule::get_attestation(&old_coldkey), None);
+        assert_eq!(SubtensorModule::get_attestation(&new_coldkey), attestation);
+
+        // The rate limit moves with the attestation.
+        assert_noop!(
+            SubtensorModule::attest(RuntimeOrigin::signed(new_coldkey), [2; 32]),
+            Error::<Test>::TxRateLimitExceeded
+        );
+
+        // The new coldkey gets the deposit back on revoking.
+        let balance: u64 = SubtensorModule::get_coldkey_balance(&new_coldkey);
+        assert_ok!(SubtensorModule::revoke_attestation(RuntimeOrigin::signed(
+            new_coldkey
+        )));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&new_coldkey),
+            balance + AttestationDeposit::<Test>::get()
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_moves_emission_payout --exact --nocapture
+#[test]
+fn test_swap_coldkey_moves_emission_payout() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&old_coldkey, &hotkey, 1_000);
+        EmissionPayout::<Test>::insert(hotkey, old_coldkey, EmissionPayoutMode::ToBalance);
+
+        let mut weight = Weight::zero();
+        assert_ok!(perform_swap_coldkey_counted(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+
+        assert_eq!(
+            EmissionPayout::<Test>::get(hotkey, new_coldkey),
+            EmissionPayoutMode::ToBalance
+        );
+        assert!(!EmissionPayout::<Test>::contains_key(hotkey, old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_moves_lifetime_burn --exact --nocapture
+#[test]
+fn test_swap_coldkey_moves_lifetime_burn() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+
+        ColdkeyLifetimeBurn::<Test>::insert(old_coldkey, 3_000);
+        ColdkeyLifetimeBurn::<Test>::insert(new_coldkey, 500);
+
+        let mut weight = Weight::zero();
+        assert_ok!(perform_swap_coldkey_counted(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_coldkey_lifetime_burn(&new_coldkey),
+            3_500
+        );
+        assert!(!ColdkeyLifetimeBurn::<Test>::contains_key(old_coldkey));
+    });
+}
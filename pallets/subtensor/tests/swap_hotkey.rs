@@ -6,6 +6,7 @@ use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::{Config, RawOrigin};
 mod mock;
 use mock::*;
+use pallet_subtensor::utils::rate_limiting::TransactionType;
 use pallet_subtensor::*;
 use sp_core::H256;
 use sp_core::U256;
@@ -114,7 +115,7 @@ fn test_swap_last_tx_block() {
         let coldkey = U256::from(3);
         let mut weight = Weight::zero();
 
-        LastTxBlock::<Test>::insert(old_hotkey, 1000);
+        SubtensorModule::set_last_tx_block(&old_hotkey, 1000);
         assert_ok!(SubtensorModule::perform_hotkey_swap(
             &old_hotkey,
             &new_hotkey,
@@ -122,9 +123,12 @@ fn test_swap_last_tx_block() {
             &mut weight
         ));
 
-        assert!(!LastTxBlock::<Test>::contains_key(old_hotkey));
+        assert!(!LastRateLimitedBlock::<Test>::contains_key(
+            RateLimitedOperation::Transaction,
+            old_hotkey
+        ));
         assert_eq!(
-            LastTxBlock::<Test>::get(new_hotkey),
+            SubtensorModule::get_last_tx_block(&new_hotkey),
             SubtensorModule::get_current_block_as_u64()
         );
     });
@@ -139,7 +143,7 @@ fn test_swap_last_tx_block_delegate_take() {
         let coldkey = U256::from(3);
         let mut weight = Weight::zero();
 
-        pallet_subtensor::LastTxBlockDelegateTake::<Test>::insert(old_hotkey, 1000);
+        SubtensorModule::set_last_tx_block_delegate_take(&old_hotkey, 1000);
         assert_ok!(SubtensorModule::perform_hotkey_swap(
             &old_hotkey,
             &new_hotkey,
@@ -147,9 +151,12 @@ fn test_swap_last_tx_block_delegate_take() {
             &mut weight
         ));
 
-        assert!(!LastTxBlockDelegateTake::<Test>::contains_key(old_hotkey));
+        assert!(!LastRateLimitedBlock::<Test>::contains_key(
+            RateLimitedOperation::DelegateTake,
+            old_hotkey
+        ));
         assert_eq!(
-            LastTxBlockDelegateTake::<Test>::get(new_hotkey),
+            SubtensorModule::get_last_tx_block_delegate_take(&new_hotkey),
             SubtensorModule::get_current_block_as_u64()
         );
     });
@@ -201,6 +208,35 @@ fn test_swap_delegates() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_take_history --exact --nocapture
+#[test]
+fn test_swap_take_history() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let mut weight = Weight::zero();
+
+        Delegates::<Test>::insert(old_hotkey, 100);
+        TakeHistory::<Test>::insert(old_hotkey, vec![(1, 50, 100)]);
+        PendingDelegateTake::<Test>::insert(old_hotkey, 200);
+        System::set_block_number(5);
+        assert_ok!(SubtensorModule::perform_hotkey_swap(
+            &old_hotkey,
+            &new_hotkey,
+            &coldkey,
+            &mut weight
+        ));
+
+        // The history follows the hotkey, along with the pending increase applied by the swap.
+        assert!(!TakeHistory::<Test>::contains_key(old_hotkey));
+        assert_eq!(
+            TakeHistory::<Test>::get(new_hotkey),
+            vec![(1, 50, 100), (5, 100, 200)]
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_subnet_membership --exact --nocapture
 #[test]
 fn test_swap_subnet_membership() {
@@ -900,7 +936,7 @@ fn test_swap_hotkey_error_cases() {
         // Set up initial state
         Owner::<Test>::insert(old_hotkey, coldkey);
         TotalNetworks::<Test>::put(1);
-        LastTxBlock::<Test>::insert(coldkey, 0);
+        SubtensorModule::set_last_tx_block(&coldkey, 0);
 
         // Test not enough balance
         let swap_cost = SubtensorModule::get_key_swap_cost();
@@ -1022,6 +1058,47 @@ fn test_swap_parent_keys() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_child_weights_permissions --exact --nocapture
+#[test]
+fn test_swap_child_weights_permissions() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let parent = U256::from(4);
+        let child = U256::from(5);
+        let netuid = 0u16;
+        let mut weight = Weight::zero();
+
+        // The old hotkey is the child of `parent` and the parent of `child`, and both permissions
+        // are given.
+        add_network(netuid, 1, 0);
+        ParentKeys::<Test>::insert(old_hotkey, netuid, vec![(100u64, parent)]);
+        ChildKeys::<Test>::insert(parent, netuid, vec![(100u64, old_hotkey)]);
+        ChildKeys::<Test>::insert(old_hotkey, netuid, vec![(100u64, child)]);
+        ParentKeys::<Test>::insert(child, netuid, vec![(100u64, old_hotkey)]);
+        ChildWeightsPermission::<Test>::insert((parent, old_hotkey, netuid), true);
+        ChildWeightsPermission::<Test>::insert((old_hotkey, child, netuid), true);
+
+        // Perform the swap
+        SubtensorModule::perform_hotkey_swap(&old_hotkey, &new_hotkey, &coldkey, &mut weight);
+
+        // Verify the permissions follow the new hotkey
+        assert!(ChildWeightsPermission::<Test>::get((
+            parent, new_hotkey, netuid
+        )));
+        assert!(ChildWeightsPermission::<Test>::get((
+            new_hotkey, child, netuid
+        )));
+        assert!(!ChildWeightsPermission::<Test>::contains_key((
+            parent, old_hotkey, netuid
+        )));
+        assert!(!ChildWeightsPermission::<Test>::contains_key((
+            old_hotkey, child, netuid
+        )));
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_multiple_subnets --exact --nocapture
 #[test]
 fn test_swap_multiple_subnets() {
@@ -1115,3 +1192,577 @@ fn test_swap_complex_parent_child_structure() {
         );
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_parent_with_two_children --exact --nocapture
+#[test]
+fn test_swap_parent_with_two_children() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let child1 = U256::from(4);
+        let child2 = U256::from(5);
+        let netuid = 1u16;
+        let mut weight = Weight::zero();
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, old_hotkey, coldkey, 0);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            old_hotkey,
+            netuid,
+            vec![(100u64, child1), (200u64, child2)]
+        ));
+        ChildkeyTake::<Test>::insert(old_hotkey, netuid, 1_000);
+
+        SubtensorModule::perform_hotkey_swap(&old_hotkey, &new_hotkey, &coldkey, &mut weight);
+
+        // The children move to the new hotkey and their parent entries follow.
+        assert_eq!(
+            ChildKeys::<Test>::get(new_hotkey, netuid),
+            vec![(100u64, child1), (200u64, child2)]
+        );
+        assert!(!ChildKeys::<Test>::contains_key(old_hotkey, netuid));
+        assert_eq!(
+            ParentKeys::<Test>::get(child1, netuid),
+            vec![(100u64, new_hotkey)]
+        );
+        assert_eq!(
+            ParentKeys::<Test>::get(child2, netuid),
+            vec![(200u64, new_hotkey)]
+        );
+
+        // The childkey take moves with the relationships.
+        assert_eq!(ChildkeyTake::<Test>::get(new_hotkey, netuid), 1_000);
+        assert!(!ChildkeyTake::<Test>::contains_key(old_hotkey, netuid));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_child_with_two_parents --exact --nocapture
+#[test]
+fn test_swap_child_with_two_parents() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let parent1 = U256::from(4);
+        let parent2 = U256::from(5);
+        let sibling = U256::from(6);
+        let netuid = 1u16;
+        let mut weight = Weight::zero();
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, parent1, coldkey, 0);
+        register_ok_neuron(netuid, parent2, coldkey, 100_000);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent1,
+            netuid,
+            vec![(100u64, old_hotkey), (300u64, sibling)]
+        ));
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent2,
+            netuid,
+            vec![(200u64, old_hotkey)]
+        ));
+
+        SubtensorModule::perform_hotkey_swap(&old_hotkey, &new_hotkey, &coldkey, &mut weight);
+
+        // Both parents now route to the new hotkey, leaving other children untouched.
+        assert_eq!(
+            ChildKeys::<Test>::get(parent1, netuid),
+            vec![(100u64, new_hotkey), (300u64, sibling)]
+        );
+        assert_eq!(
+            ChildKeys::<Test>::get(parent2, netuid),
+            vec![(200u64, new_hotkey)]
+        );
+        assert_eq!(
+            ParentKeys::<Test>::get(new_hotkey, netuid),
+            vec![(100u64, parent1), (200u64, parent2)]
+        );
+        assert!(!ParentKeys::<Test>::contains_key(old_hotkey, netuid));
+        assert_eq!(
+            ParentKeys::<Test>::get(sibling, netuid),
+            vec![(300u64, parent1)]
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_childkey_cooldowns --exact --nocapture
+#[test]
+fn test_swap_childkey_cooldowns() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let child = U256::from(4);
+        let netuid = 1u16;
+        let mut weight = Weight::zero();
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, old_hotkey, coldkey, 0);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            old_hotkey,
+            netuid,
+            vec![(100u64, child)]
+        ));
+        let children_block = SubtensorModule::get_current_block_as_u64();
+        SubtensorModule::set_last_transaction_block(
+            &old_hotkey,
+            netuid,
+            &TransactionType::SetChildkeyTake,
+            children_block,
+        );
+
+        SubtensorModule::perform_hotkey_swap(&old_hotkey, &new_hotkey, &coldkey, &mut weight);
+
+        // The cooldowns are re-keyed to the new hotkey.
+        for tx_type in [
+            TransactionType::SetChildren,
+            TransactionType::SetChildkeyTake,
+        ] {
+            assert_eq!(
+                SubtensorModule::get_last_transaction_block(&new_hotkey, netuid, &tx_type),
+                children_block
+            );
+            assert_eq!(
+                SubtensorModule::get_last_transaction_block(&old_hotkey, netuid, &tx_type),
+                0
+            );
+        }
+
+        // The swap cannot be used to skip the childkey take cooldown.
+        SubtensorModule::set_tx_childkey_take_rate_limit(100);
+        assert_noop!(
+            SubtensorModule::set_childkey_take(
+                RuntimeOrigin::signed(coldkey),
+                new_hotkey,
+                netuid,
+                MaxChildkeyTake::<Test>::get()
+            ),
+            Error::<Test>::TxChildkeyTakeRateLimitExceeded
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_emission_records --exact --nocapture
+#[test]
+fn test_swap_emission_records() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let mut weight = Weight::zero();
+
+        // The old hotkey was credited on subnet 1 and on a since dissolved subnet 7.
+        EmissionLastTempo::<Test>::insert(old_hotkey, 1, 10);
+        EmissionLifetime::<Test>::insert(old_hotkey, 1, 100);
+        EmissionLifetime::<Test>::insert(old_hotkey, 7, 70);
+        // The new hotkey already has its own history on subnet 1.
+        EmissionLastTempo::<Test>::insert(new_hotkey, 1, 3);
+        EmissionLifetime::<Test>::insert(new_hotkey, 1, 30);
+
+        Owner::<Test>::insert(old_hotkey, coldkey);
+        assert_ok!(SubtensorModule::perform_hotkey_swap(
+            &old_hotkey,
+            &new_hotkey,
+            &coldkey,
+            &mut weight
+        ));
+
+        assert_eq!(EmissionLastTempo::<Test>::get(new_hotkey, 1), 10);
+        assert_eq!(EmissionLifetime::<Test>::get(new_hotkey, 1), 130);
+        assert_eq!(EmissionLifetime::<Test>::get(new_hotkey, 7), 70);
+        assert!(!EmissionLastTempo::<Test>::contains_key(old_hotkey, 1));
+        assert!(!EmissionLifetime::<Test>::contains_key(old_hotkey, 1));
+        assert!(!EmissionLifetime::<Test>::contains_key(old_hotkey, 7));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_on_subnet_only_moves_that_subnet --exact --nocapture
+#[test]
+fn test_swap_hotkey_on_subnet_only_moves_that_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1: u16 = 1;
+        let netuid2: u16 = 2;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let nominator = U256::from(4);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+
+        add_network(netuid1, 13, 0);
+        add_network(netuid2, 13, 0);
+        register_ok_neuron(netuid1, U256::from(10), U256::from(11), 0);
+        register_ok_neuron(netuid1, old_hotkey, coldkey, 0);
+        register_ok_neuron(netuid2, old_hotkey, coldkey, 0);
+        let uid1 = SubtensorModule::get_uid_for_net_and_hotkey(netuid1, &old_hotkey).unwrap();
+        let uid2 = SubtensorModule::get_uid_for_net_and_hotkey(netuid2, &old_hotkey).unwrap();
+        Axons::<Test>::insert(netuid1, old_hotkey, AxonInfo::default());
+        Axons::<Test>::insert(netuid2, old_hotkey, AxonInfo::default());
+        Weights::<Test>::insert(netuid1, uid1, WeightsRow::truncate_from(vec![(0u16, 1u16)]));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &old_hotkey, 1_000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, swap_cost);
+
+        assert_ok!(SubtensorModule::swap_hotkey_on_subnet(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            old_hotkey,
+            new_hotkey,
+            netuid1
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            Event::HotkeySwappedOnSubnet {
+                coldkey,
+                old_hotkey,
+                new_hotkey,
+                netuid: netuid1,
+            },
+        ));
+
+        // The registration on the swapped subnet follows the new hotkey, with its weights row.
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid1, &new_hotkey),
+            Ok(uid1)
+        );
+        assert_eq!(
+            SubtensorModule::get_hotkey_for_net_and_uid(netuid1, uid1),
+            Ok(new_hotkey)
+        );
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid1, &old_hotkey).is_err());
+        assert!(IsNetworkMember::<Test>::get(new_hotkey, netuid1));
+        assert!(!IsNetworkMember::<Test>::get(old_hotkey, netuid1));
+        assert!(Axons::<Test>::contains_key(netuid1, new_hotkey));
+        assert!(!Axons::<Test>::contains_key(netuid1, old_hotkey));
+        assert_eq!(Weights::<Test>::get(netuid1, uid1), vec![(0u16, 1u16)]);
+
+        // The other subnet is untouched.
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid2, &old_hotkey),
+            Ok(uid2)
+        );
+        assert!(!IsNetworkMember::<Test>::get(new_hotkey, netuid2));
+        assert!(Axons::<Test>::contains_key(netuid2, old_hotkey));
+
+        // The stake stays with the old hotkey, and the coldkey owns both.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &old_hotkey),
+            1_000
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&new_hotkey), 0);
+        assert!(SubtensorModule::coldkey_owns_hotkey(&coldkey, &old_hotkey));
+        assert!(SubtensorModule::coldkey_owns_hotkey(&coldkey, &new_hotkey));
+
+        // The cost is shared between the two subnets of the old hotkey.
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            swap_cost - swap_cost / 2
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_on_subnet_moves_children_on_that_subnet --exact --nocapture
+#[test]
+fn test_swap_hotkey_on_subnet_moves_children_on_that_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1: u16 = 1;
+        let netuid2: u16 = 2;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let child = U256::from(4);
+
+        add_network(netuid1, 13, 0);
+        add_network(netuid2, 13, 0);
+        register_ok_neuron(netuid1, old_hotkey, coldkey, 0);
+        register_ok_neuron(netuid2, old_hotkey, coldkey, 0);
+        for netuid in [netuid1, netuid2] {
+            ChildKeys::<Test>::insert(old_hotkey, netuid, vec![(u64::MAX, child)]);
+            ParentKeys::<Test>::insert(child, netuid, vec![(u64::MAX, old_hotkey)]);
+            PendingChildKeys::<Test>::insert(netuid, old_hotkey, (vec![(u64::MAX, child)], 100));
+        }
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, u64::MAX / 2);
+
+        assert_ok!(SubtensorModule::swap_hotkey_on_subnet(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            old_hotkey,
+            new_hotkey,
+            netuid1
+        ));
+
+        assert_eq!(
+            ChildKeys::<Test>::get(new_hotkey, netuid1),
+            vec![(u64::MAX, child)]
+        );
+        assert!(ChildKeys::<Test>::get(old_hotkey, netuid1).is_empty());
+        assert_eq!(
+            ParentKeys::<Test>::get(child, netuid1),
+            vec![(u64::MAX, new_hotkey)]
+        );
+        assert_eq!(
+            ChildKeys::<Test>::get(old_hotkey, netuid2),
+            vec![(u64::MAX, child)]
+        );
+        assert_eq!(
+            ParentKeys::<Test>::get(child, netuid2),
+            vec![(u64::MAX, old_hotkey)]
+        );
+
+        // The pending children follow the hotkey on the swapped subnet only.
+        assert!(PendingChildKeys::<Test>::contains_key(netuid1, new_hotkey));
+        assert!(!PendingChildKeys::<Test>::contains_key(netuid1, old_hotkey));
+        assert!(PendingChildKeys::<Test>::contains_key(netuid2, old_hotkey));
+        assert!(PendingChildKeysDue::<Test>::get(100).contains(&(netuid1, new_hotkey)));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_on_subnet_moves_commitment --exact --nocapture
+#[test]
+fn test_swap_hotkey_on_subnet_moves_commitment() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1: u16 = 1;
+        let netuid2: u16 = 2;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+
+        add_network(netuid1, 13, 0);
+        add_network(netuid2, 13, 0);
+        register_ok_neuron(netuid1, old_hotkey, coldkey, 0);
+        register_ok_neuron(netuid2, old_hotkey, coldkey, 0);
+        set_test_commitment(netuid1, &old_hotkey);
+        set_test_commitment(netuid2, &old_hotkey);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, u64::MAX / 2);
+
+        assert_ok!(SubtensorModule::swap_hotkey_on_subnet(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            old_hotkey,
+            new_hotkey,
+            netuid1
+        ));
+
+        assert!(TestCommitments::has_commitment(netuid1, &new_hotkey));
+        assert!(!TestCommitments::has_commitment(netuid1, &old_hotkey));
+        assert!(TestCommitments::has_commitment(netuid2, &old_hotkey));
+        assert!(!TestCommitments::has_commitment(netuid2, &new_hotkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_on_subnet_errors --exact --nocapture
+#[test]
+fn test_swap_hotkey_on_subnet_errors() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1: u16 = 1;
+        let netuid2: u16 = 2;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let other_coldkey = U256::from(4);
+        let other_hotkey = U256::from(5);
+
+        add_network(netuid1, 13, 0);
+        add_network(netuid2, 13, 0);
+        register_ok_neuron(netuid1, old_hotkey, coldkey, 0);
+        register_ok_neuron(netuid2, new_hotkey, coldkey, 0);
+        register_ok_neuron(netuid2, other_hotkey, other_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, u64::MAX / 2);
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(origin.clone(), old_hotkey, old_hotkey, netuid1),
+            Error::<Test>::NewHotKeyIsSameWithOld
+        );
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(origin.clone(), old_hotkey, new_hotkey, 0),
+            Error::<Test>::RegistrationNotPermittedOnRootSubnet
+        );
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(origin.clone(), old_hotkey, new_hotkey, 3),
+            Error::<Test>::SubNetworkDoesNotExist
+        );
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(
+                origin.clone(),
+                old_hotkey,
+                other_hotkey,
+                netuid1
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(origin.clone(), old_hotkey, new_hotkey, netuid2),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(origin.clone(), new_hotkey, old_hotkey, netuid1),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        // A hotkey of the coldkey registered elsewhere can take over the registration.
+        assert_ok!(SubtensorModule::swap_hotkey_on_subnet(
+            origin, old_hotkey, new_hotkey, netuid1
+        ));
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid1,
+            &new_hotkey
+        ));
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid2,
+            &new_hotkey
+        ));
+    });
+}
+
+// Swaps a hotkey for each of `swaps` coldkeys, paying the current key swap cost.
+fn swap_hotkeys(swaps: u64) {
+    SubtensorModule::set_tx_rate_limit(0);
+    for i in 0..swaps {
+        let old_hotkey = U256::from(1_000 + i);
+        let new_hotkey = U256::from(2_000 + i);
+        let coldkey = U256::from(3_000 + i);
+        Owner::<Test>::insert(old_hotkey, coldkey);
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            SubtensorModule::get_key_swap_cost() + 1_000,
+        );
+        assert_ok!(SubtensorModule::do_swap_hotkey(
+            RuntimeOrigin::signed(coldkey),
+            &old_hotkey,
+            &new_hotkey
+        ));
+    }
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_key_swap_cost_rises_under_spam --exact --nocapture
+#[test]
+fn test_key_swap_cost_rises_under_spam() {
+    new_test_ext(1).execute_with(|| {
+        let initial_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::set_target_key_swaps_per_interval(2);
+        SubtensorModule::set_key_swap_cost_adjustment_interval(10);
+
+        swap_hotkeys(4);
+        assert_eq!(KeySwapsThisInterval::<Test>::get(), 4);
+
+        // Not at the adjustment interval yet.
+        SubtensorModule::adjust_key_swap_cost(9);
+        assert_eq!(SubtensorModule::get_key_swap_cost(), initial_cost);
+
+        // Twice the target: the cost rises by half.
+        SubtensorModule::adjust_key_swap_cost(10);
+        assert_eq!(SubtensorModule::get_key_swap_cost(), initial_cost * 3 / 2);
+        assert_eq!(KeySwapsThisInterval::<Test>::get(), 0);
+        assert_eq!(LastKeySwapCostAdjustmentBlock::<Test>::get(), 10);
+        System::assert_last_event(Event::KeySwapCostAdjusted(initial_cost * 3 / 2).into());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_key_swap_cost_decays_when_idle --exact --nocapture
+#[test]
+fn test_key_swap_cost_decays_when_idle() {
+    new_test_ext(1).execute_with(|| {
+        let initial_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::set_target_key_swaps_per_interval(2);
+        SubtensorModule::set_key_swap_cost_adjustment_interval(10);
+        SubtensorModule::set_min_key_swap_cost(0);
+
+        // No swaps: the cost halves each interval.
+        SubtensorModule::adjust_key_swap_cost(10);
+        assert_eq!(SubtensorModule::get_key_swap_cost(), initial_cost / 2);
+        SubtensorModule::adjust_key_swap_cost(20);
+        assert_eq!(SubtensorModule::get_key_swap_cost(), initial_cost / 4);
+
+        // At the target the cost holds.
+        swap_hotkeys(2);
+        SubtensorModule::adjust_key_swap_cost(30);
+        assert_eq!(SubtensorModule::get_key_swap_cost(), initial_cost / 4);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_key_swap_cost_clamped --exact --nocapture
+#[test]
+fn test_key_swap_cost_clamped() {
+    new_test_ext(1).execute_with(|| {
+        let initial_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::set_target_key_swaps_per_interval(1);
+        SubtensorModule::set_key_swap_cost_adjustment_interval(10);
+        SubtensorModule::set_max_key_swap_cost(initial_cost + 1);
+        SubtensorModule::set_min_key_swap_cost(initial_cost - 1);
+
+        swap_hotkeys(3);
+        SubtensorModule::adjust_key_swap_cost(10);
+        assert_eq!(SubtensorModule::get_key_swap_cost(), initial_cost + 1);
+
+        SubtensorModule::adjust_key_swap_cost(20);
+        assert_eq!(SubtensorModule::get_key_swap_cost(), initial_cost - 1);
+
+        // Without a target, the cost only follows its bounds.
+        SubtensorModule::set_target_key_swaps_per_interval(0);
+        SubtensorModule::set_min_key_swap_cost(initial_cost);
+        SubtensorModule::adjust_key_swap_cost(30);
+        assert_eq!(SubtensorModule::get_key_swap_cost(), initial_cost);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_keeps_hotkeys_sorted --exact --nocapture
+#[test]
+fn test_swap_hotkey_keeps_hotkeys_sorted() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(9);
+        let new_hotkey = U256::from(1);
+        let other_hotkey = U256::from(5);
+        let coldkey = U256::from(3);
+        let mut weight = Weight::zero();
+
+        OwnedHotkeys::<Test>::insert(coldkey, vec![old_hotkey, other_hotkey]);
+        Stake::<Test>::insert(old_hotkey, coldkey, 100);
+        StakingHotkeys::<Test>::insert(coldkey, vec![old_hotkey, other_hotkey]);
+        assert_ok!(SubtensorModule::perform_hotkey_swap(
+            &old_hotkey,
+            &new_hotkey,
+            &coldkey,
+            &mut weight
+        ));
+
+        // The new hotkey is not appended but takes its place by account id.
+        assert_eq!(
+            OwnedHotkeys::<Test>::get(coldkey).into_inner(),
+            vec![new_hotkey, other_hotkey]
+        );
+        assert_eq!(
+            StakingHotkeys::<Test>::get(coldkey),
+            vec![new_hotkey, other_hotkey]
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_moves_emission_payout --exact --nocapture
+#[test]
+fn test_swap_hotkey_moves_emission_payout() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let nominator = U256::from(4);
+        let mut weight = Weight::zero();
+
+        Owner::<Test>::insert(old_hotkey, coldkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &old_hotkey, 1_000);
+        EmissionPayout::<Test>::insert(old_hotkey, nominator, EmissionPayoutMode::ToBalance);
+
+        assert_ok!(SubtensorModule::perform_hotkey_swap(
+            &old_hotkey,
+            &new_hotkey,
+            &coldkey,
+            &mut weight
+        ));
+
+        assert_eq!(
+            EmissionPayout::<Test>::get(new_hotkey, nominator),
+            EmissionPayoutMode::ToBalance
+        );
+        assert!(!EmissionPayout::<Test>::contains_key(old_hotkey, nominator));
+    });
+}
@@ -5,9 +5,15 @@ use frame_support::{
     assert_err, assert_ok,
     dispatch::{DispatchClass, DispatchInfo, DispatchResult, GetDispatchInfo, Pays},
     pallet_prelude::{InvalidTransaction, TransactionValidityError},
+    traits::Hooks,
+    weights::Weight,
 };
 use mock::*;
-use pallet_subtensor::{Error, Owner};
+use pallet_subtensor::rpc_info::weights_info::WeightSettingStatus;
+use pallet_subtensor::{
+    CallFailureInfo, Error, Event, LastCallFailureInfo, Owner, WeightCommitDeposits, WeightCommits,
+    Weights,
+};
 use sp_core::{H256, U256};
 use sp_runtime::{
     traits::{BlakeTwo256, DispatchInfoOf, Hash, SignedExtension},
@@ -477,11 +483,11 @@ fn test_set_weights_min_stake_failed() {
 
         // Check the signed extension function.
         assert_eq!(SubtensorModule::get_weights_min_stake(), 20_000_000_000_000);
-        assert!(!SubtensorModule::check_weights_min_stake(&hotkey));
+        assert!(!SubtensorModule::check_weights_min_stake(&hotkey, netuid));
         SubtensorModule::increase_stake_on_hotkey_account(&hotkey, 19_000_000_000_000);
-        assert!(!SubtensorModule::check_weights_min_stake(&hotkey));
+        assert!(!SubtensorModule::check_weights_min_stake(&hotkey, netuid));
         SubtensorModule::increase_stake_on_hotkey_account(&hotkey, 20_000_000_000_000);
-        assert!(SubtensorModule::check_weights_min_stake(&hotkey));
+        assert!(SubtensorModule::check_weights_min_stake(&hotkey, netuid));
 
         // Check that it fails at the pallet level.
         SubtensorModule::set_weights_min_stake(100_000_000_000_000);
@@ -509,6 +515,140 @@ fn test_set_weights_min_stake_failed() {
     });
 }
 
+// To execute this test: cargo test --package pallet-subtensor --test weights test_set_weights_below_stake_threshold_rejected -- --nocapture`
+#[test]
+fn test_set_weights_below_stake_threshold_rejected() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 2143124);
+        SubtensorModule::set_weights_min_stake(1_000);
+        SubtensorModule::increase_stake_on_hotkey_account(&hotkey, 999);
+
+        assert_eq!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![0],
+                vec![1],
+                0
+            ),
+            Err(Error::<Test>::NotEnoughStakeToSetWeights.into())
+        );
+
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        assert_eq!(
+            SubtensorModule::commit_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                H256::from_low_u64_be(1)
+            ),
+            Err(Error::<Test>::NotEnoughStakeToSetWeights.into())
+        );
+
+        // Rejected by the signed extension as well.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::set_weights {
+            netuid,
+            dests: vec![0],
+            weights: vec![1],
+            version_key: 0,
+        });
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = pallet_subtensor::SubtensorSignedExtension::<Test>::new();
+        assert_err!(
+            extension.validate(&hotkey, &call, &info, 10),
+            TransactionValidityError::Invalid(InvalidTransaction::Custom(3))
+        );
+    });
+}
+
+// To execute this test: cargo test --package pallet-subtensor --test weights test_set_weights_validator_permit_bypasses_stake_threshold -- --nocapture`
+#[test]
+fn test_set_weights_validator_permit_bypasses_stake_threshold() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 2143124);
+        SubtensorModule::set_weights_min_stake(1_000);
+        assert!(!SubtensorModule::check_weights_min_stake(&hotkey, netuid));
+
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey)
+            .expect("Hotkey is registered");
+        SubtensorModule::set_validator_permit_for_uid(netuid, uid, true);
+
+        assert!(SubtensorModule::check_weights_min_stake(&hotkey, netuid));
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![uid],
+            vec![1],
+            0
+        ));
+    });
+}
+
+// To execute this test: cargo test --package pallet-subtensor --test weights test_stake_threshold_change_takes_immediate_effect -- --nocapture`
+#[test]
+fn test_stake_threshold_change_takes_immediate_effect() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 0, 0);
+        add_network(other_netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 2143124);
+        register_ok_neuron(other_netuid, hotkey, coldkey, 3124124);
+        SubtensorModule::increase_stake_on_hotkey_account(&hotkey, 500);
+
+        SubtensorModule::set_weights_min_stake(1_000);
+        assert!(!SubtensorModule::check_weights_min_stake(&hotkey, netuid));
+
+        // Lowering the chain-wide threshold applies straight away.
+        SubtensorModule::set_weights_min_stake(500);
+        assert!(SubtensorModule::check_weights_min_stake(&hotkey, netuid));
+        assert!(SubtensorModule::check_weights_min_stake(
+            &hotkey,
+            other_netuid
+        ));
+
+        // A subnet override only affects its own subnet.
+        SubtensorModule::set_subnet_stake_threshold(netuid, Some(501));
+        assert_eq!(SubtensorModule::get_stake_threshold_for_subnet(netuid), 501);
+        assert!(!SubtensorModule::check_weights_min_stake(&hotkey, netuid));
+        assert!(SubtensorModule::check_weights_min_stake(
+            &hotkey,
+            other_netuid
+        ));
+        assert_eq!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![0],
+                vec![1],
+                0
+            ),
+            Err(Error::<Test>::NotEnoughStakeToSetWeights.into())
+        );
+
+        // Removing the override falls back to the chain-wide threshold.
+        SubtensorModule::set_subnet_stake_threshold(netuid, None);
+        assert_eq!(SubtensorModule::get_stake_threshold_for_subnet(netuid), 500);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![0],
+            vec![1],
+            0
+        ));
+    });
+}
+
 // Test ensures that a uid can only set weights if it has the valid weights set version key.
 #[test]
 fn test_weights_version_key() {
@@ -972,6 +1112,56 @@ fn test_set_weight_too_many_uids() {
     });
 }
 
+// Tests that the weights set fails if you pass more weights than the subnet allows a validator.
+#[test]
+fn test_set_weights_err_too_many_weights() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey_account_id = U256::from(55);
+        let netuid: u16 = 1;
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 65555);
+        register_ok_neuron(netuid, U256::from(2), U256::from(2), 75555);
+        let neuron_uid: u16 =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey_account_id)
+                .expect("Not registered.");
+        SubtensorModule::set_validator_permit_for_uid(netuid, neuron_uid, true);
+
+        // The bound never goes above the max allowed uids.
+        assert_eq!(SubtensorModule::get_max_weights_limit_len(netuid), 3);
+        SubtensorModule::set_max_weights_limit_len(netuid, 1);
+        assert_eq!(SubtensorModule::get_max_weights_limit_len(netuid), 1);
+
+        let result = commit_reveal_set_weights(
+            hotkey_account_id,
+            netuid,
+            vec![1, 2],
+            vec![1, 2],
+            salt.clone(),
+            0,
+        );
+        assert_eq!(result, Err(Error::<Test>::TooManyWeights.into()));
+        assert!(Weights::<Test>::get(netuid, neuron_uid).is_empty());
+
+        assert_ok!(commit_reveal_set_weights(
+            hotkey_account_id,
+            netuid,
+            vec![2],
+            vec![1],
+            salt,
+            0,
+        ));
+        assert_eq!(
+            Weights::<Test>::get(netuid, neuron_uid),
+            vec![(2, u16::MAX)]
+        );
+    });
+}
+
 // Tests that the weights set doesn't panic if you pass weights that sum to larger than u16 max.
 #[test]
 fn test_set_weights_sum_larger_than_u16_max() {
@@ -1527,6 +1717,7 @@ fn test_commit_reveal_interval() {
             commit_hash
         ));
         step_block(205);
+        // The expired commit was pruned on idle.
         assert_err!(
             SubtensorModule::reveal_weights(
                 RuntimeOrigin::signed(hotkey),
@@ -1536,7 +1727,7 @@ fn test_commit_reveal_interval() {
                 salt.clone(),
                 version_key,
             ),
-            Error::<Test>::InvalidRevealCommitTempo
+            Error::<Test>::NoWeightsCommitFound
         );
 
         // Testing when you commit but do not reveal until later intervals
@@ -1934,3 +2125,635 @@ fn commit_reveal_set_weights(
 
     Ok(())
 }
+
+// Test that a failed set_weights records the limit it was checked against as the last call failure of the hotkey.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_set_weights_failure_info -- --nocapture
+#[test]
+fn test_set_weights_failure_info() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(55);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        register_ok_neuron(netuid, hotkey, U256::from(66), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 65555);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_weights_version_key(netuid, 5);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 10);
+        let set_weights = |dests: Vec<u16>, weights: Vec<u16>, version_key: u64| {
+            dispatch_with_signed_extension(
+                hotkey,
+                RuntimeCall::SubtensorModule(SubtensorCall::set_weights {
+                    netuid,
+                    dests,
+                    weights,
+                    version_key,
+                }),
+            )
+        };
+        let failure = |error: Error<Test>, expected: u64, provided: u64| {
+            Some(CallFailureInfo {
+                error: codec::Encode::encode(&error)[0],
+                expected,
+                provided,
+                block: SubtensorModule::get_current_block_as_u64(),
+            })
+        };
+
+        assert_err!(
+            set_weights(vec![0, 1], vec![1], 5),
+            Error::<Test>::WeightVecNotEqualSize
+        );
+        assert_eq!(
+            LastCallFailureInfo::<Test>::get(hotkey),
+            failure(Error::<Test>::WeightVecNotEqualSize, 2, 1)
+        );
+
+        assert_err!(
+            set_weights(vec![0, 1], vec![1, 1], 3),
+            Error::<Test>::IncorrectWeightVersionKey
+        );
+        assert_eq!(
+            LastCallFailureInfo::<Test>::get(hotkey),
+            failure(Error::<Test>::IncorrectWeightVersionKey, 5, 3)
+        );
+
+        assert_ok!(set_weights(vec![0, 1], vec![1, 1], 5));
+        step_block(3);
+        assert_err!(
+            set_weights(vec![0, 1], vec![1, 1], 5),
+            Error::<Test>::SettingWeightsTooFast
+        );
+        assert_eq!(
+            LastCallFailureInfo::<Test>::get(hotkey),
+            failure(Error::<Test>::SettingWeightsTooFast, 10, 3)
+        );
+    });
+}
+
+/***************************
+  pub fn get_weight_setting_status() tests
+*****************************/
+
+// Asserts the status agrees with the outcome of a `set_weights` call made in the same state.
+fn assert_status_matches(status: &WeightSettingStatus, result: DispatchResult) {
+    match result {
+        Ok(()) => {
+            assert!(status.can_set_now);
+            assert_eq!(status.failure_reason, None);
+        }
+        Err(DispatchError::Module(error)) => {
+            assert!(!status.can_set_now);
+            assert_eq!(status.failure_reason, Some(error.error[0]));
+        }
+        Err(error) => panic!("unexpected error {:?}", error),
+    }
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_weight_setting_status_matches_set_weights -- --nocapture
+#[test]
+fn test_weight_setting_status_matches_set_weights() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let uids: Vec<u16> = vec![0, 1];
+        let values: Vec<u16> = vec![10, 10];
+        add_network(netuid, 1000, 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 5);
+        SubtensorModule::set_weights_min_stake(0);
+
+        let try_set_weights = || {
+            let status = SubtensorModule::get_weight_setting_status(netuid, &hotkey);
+            let result = SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                values.clone(),
+                0,
+            );
+            assert_status_matches(&status, result);
+            status
+        };
+
+        // Not registered.
+        let status = try_set_weights();
+        assert!(!status.registered);
+        assert_eq!(status.uid, None);
+
+        // Registered without a validator permit.
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        let status = try_set_weights();
+        assert!(status.registered);
+        assert_eq!(status.uid, Some(1.into()));
+        assert!(!status.validator_permit);
+        assert_eq!(status.blocks_since_last_set, None);
+
+        // With a permit the weights are set.
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        let status = try_set_weights();
+        assert!(status.validator_permit);
+        assert!(status.can_set_now);
+
+        // Rate limited until the limit has passed.
+        let status = try_set_weights();
+        assert!(!status.can_set_now);
+        assert_eq!(status.blocks_since_last_set, Some(0.into()));
+        assert_eq!(status.weights_set_rate_limit, 5.into());
+
+        step_block(5);
+        let status = try_set_weights();
+        assert!(status.can_set_now);
+
+        // The root network never accepts set_weights.
+        let status = SubtensorModule::get_weight_setting_status(0, &hotkey);
+        assert_eq!(
+            status.failure_reason,
+            codec::Encode::encode(&Error::<Test>::CanNotSetRootNetworkWeights)
+                .first()
+                .copied()
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_weight_setting_status_commit_reveal -- --nocapture
+#[test]
+fn test_weight_setting_status_commit_reveal() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let uids: Vec<u16> = vec![0, 1];
+        let values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        add_network(netuid, 1000, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 100);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        System::set_block_number(0);
+
+        // set_weights is rejected while commits are accepted.
+        let status = SubtensorModule::get_weight_setting_status(netuid, &hotkey);
+        assert_status_matches(
+            &status,
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                values.clone(),
+                0,
+            ),
+        );
+        assert!(status.commit_reveal_enabled);
+        assert!(status.can_commit);
+        assert!(!status.can_reveal);
+        assert_eq!(status.commit_block, None);
+
+        let commit_hash: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            netuid,
+            uids.clone(),
+            values.clone(),
+            salt.clone(),
+            0,
+        ));
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+
+        // The commit is pending until the next interval.
+        let status = SubtensorModule::get_weight_setting_status(netuid, &hotkey);
+        assert!(!status.can_commit);
+        assert!(!status.can_reveal);
+        assert_eq!(status.commit_block, Some(0.into()));
+
+        step_block(100);
+        let status = SubtensorModule::get_weight_setting_status(netuid, &hotkey);
+        assert!(status.can_reveal);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids,
+            values,
+            salt,
+            0
+        ));
+    });
+}
+
+/***************************
+  weights commit deposit and expiry tests
+*****************************/
+
+// Registers the committing hotkey 1 of coldkey 2 on subnet 1 with commit-reveal every 5 blocks.
+fn setup_commit_deposit(deposit: u64, balance: u64) -> (U256, U256, H256) {
+    let netuid: u16 = 1;
+    let hotkey = U256::from(1);
+    let coldkey = U256::from(2);
+    add_network(netuid, 1000, 0);
+    register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+    register_ok_neuron(netuid, hotkey, coldkey, 100000);
+    SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+    SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+    SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+    SubtensorModule::set_weight_commit_deposit(deposit);
+    SubtensorModule::add_balance_to_coldkey_account(&coldkey, balance);
+    System::set_block_number(0);
+
+    let commit_hash: H256 = BlakeTwo256::hash_of(&(
+        hotkey,
+        netuid,
+        vec![0_u16, 1],
+        vec![10_u16, 10],
+        vec![1_u16, 2, 3],
+        0_u64,
+    ));
+    (hotkey, coldkey, commit_hash)
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_commit_deposit_withdrawn_and_refunded_on_reveal -- --nocapture
+#[test]
+fn test_commit_deposit_withdrawn_and_refunded_on_reveal() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (hotkey, coldkey, commit_hash) = setup_commit_deposit(1_000, 10_000);
+        let total_issuance = SubtensorModule::get_total_issuance();
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 9_000);
+        assert_eq!(WeightCommitDeposits::<Test>::get(netuid, hotkey), 1_000);
+
+        System::set_block_number(5);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![0, 1],
+            vec![10, 10],
+            vec![1, 2, 3],
+            0
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 10_000);
+        assert!(!WeightCommitDeposits::<Test>::contains_key(netuid, hotkey));
+        assert_eq!(SubtensorModule::get_total_issuance(), total_issuance);
+
+        // A coldkey that cannot pay the deposit cannot commit.
+        SubtensorModule::set_weight_commit_deposit(100_000);
+        System::set_block_number(10);
+        assert_err!(
+            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash),
+            Error::<Test>::NotEnoughBalanceForCommitDeposit
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_commit_deposit_refunded_on_replace_neuron -- --nocapture
+#[test]
+fn test_commit_deposit_refunded_on_replace_neuron() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (hotkey, coldkey, commit_hash) = setup_commit_deposit(1_000, 10_000);
+        let total_issuance = SubtensorModule::get_total_issuance();
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 9_000);
+
+        // The neuron is pruned for a new registration before it reveals.
+        SubtensorModule::replace_neuron(netuid, 1, &U256::from(5), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 10_000);
+        assert!(WeightCommits::<Test>::get(netuid, hotkey).is_none());
+        assert!(!WeightCommitDeposits::<Test>::contains_key(netuid, hotkey));
+        assert_eq!(SubtensorModule::get_total_issuance(), total_issuance);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_prune_expired_commit_pays_bounty -- --nocapture
+#[test]
+fn test_prune_expired_commit_pays_bounty() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let pruner = U256::from(99);
+        let (hotkey, coldkey, commit_hash) = setup_commit_deposit(1_000, 10_000);
+
+        assert_err!(
+            SubtensorModule::prune_expired_commits(RuntimeOrigin::signed(pruner), netuid, hotkey),
+            Error::<Test>::NoWeightsCommitFound
+        );
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+
+        // The commit can be revealed until the end of the next interval.
+        System::set_block_number(9);
+        assert_err!(
+            SubtensorModule::prune_expired_commits(RuntimeOrigin::signed(pruner), netuid, hotkey),
+            Error::<Test>::WeightsCommitNotExpired
+        );
+
+        System::set_block_number(10);
+        assert_ok!(SubtensorModule::prune_expired_commits(
+            RuntimeOrigin::signed(pruner),
+            netuid,
+            hotkey
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&pruner), 1_000);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 9_000);
+        assert!(WeightCommits::<Test>::get(netuid, hotkey).is_none());
+        assert!(!WeightCommitDeposits::<Test>::contains_key(netuid, hotkey));
+        System::assert_last_event(
+            Event::ExpiredWeightsCommitPruned {
+                netuid,
+                hotkey,
+                pruner: Some(pruner),
+                bounty: 1_000,
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_expired_commits_pruned_on_idle -- --nocapture
+#[test]
+fn test_expired_commits_pruned_on_idle() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let (hotkey, _coldkey, commit_hash) = setup_commit_deposit(1_000, 10_000);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        let total_issuance = SubtensorModule::get_total_issuance();
+
+        // Nothing is pruned while the commit can be revealed.
+        System::set_block_number(9);
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
+        assert!(WeightCommits::<Test>::get(netuid, hotkey).is_some());
+
+        // Without weight for a single commit the sweep does nothing.
+        System::set_block_number(10);
+        assert_eq!(
+            SubtensorModule::prune_expired_weight_commits(Weight::zero()),
+            Weight::zero()
+        );
+        assert!(WeightCommits::<Test>::get(netuid, hotkey).is_some());
+
+        // The sweep removes the commit and burns its deposit.
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
+        assert!(WeightCommits::<Test>::get(netuid, hotkey).is_none());
+        assert!(!WeightCommitDeposits::<Test>::contains_key(netuid, hotkey));
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            total_issuance - 1_000
+        );
+        System::assert_last_event(
+            Event::ExpiredWeightsCommitPruned {
+                netuid,
+                hotkey,
+                pruner: None,
+                bounty: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_set_weights_require_commitment -- --nocapture
+#[test]
+fn test_set_weights_require_commitment() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let miner = U256::from(3);
+        let validator = U256::from(1);
+        add_network(netuid, 1000, 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_weights_min_stake(0);
+        register_ok_neuron(netuid, miner, U256::from(4), 300000);
+        register_ok_neuron(netuid, validator, U256::from(2), 100000);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        let set_weights = || {
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(validator),
+                netuid,
+                vec![0, 1],
+                vec![10, 10],
+                0,
+            )
+        };
+        let stored_weights = || Weights::<Test>::get(netuid, 1).into_inner();
+
+        // Flag off: uncommitted uids are weighted as before.
+        assert_ok!(set_weights());
+        assert_eq!(stored_weights(), vec![(0, u16::MAX), (1, u16::MAX)]);
+
+        // Zeroing mode: the weight on the uncommitted miner is zeroed, the self weight kept.
+        SubtensorModule::set_require_commitment_for_weights(netuid, true);
+        step_block(1);
+        assert_ok!(set_weights());
+        assert_eq!(stored_weights(), vec![(0, 0), (1, u16::MAX)]);
+
+        // Rejection mode: the whole weight set fails.
+        SubtensorModule::set_reject_uncommitted_weights(netuid, true);
+        step_block(1);
+        assert_err!(set_weights(), Error::<Test>::WeightOnUncommittedUid);
+
+        // A commitment made mid-tempo counts from the next weight set.
+        set_test_commitment(netuid, &miner);
+        assert_eq!(stored_weights(), vec![(0, 0), (1, u16::MAX)]);
+        step_block(1);
+        assert_ok!(set_weights());
+        assert_eq!(stored_weights(), vec![(0, u16::MAX), (1, u16::MAX)]);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_set_weights_disallow_self_weight_by_coldkey -- --nocapture
+#[test]
+fn test_set_weights_disallow_self_weight_by_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(2);
+        let validator = U256::from(1);
+        add_network(netuid, 1000, 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_weights_min_stake(0);
+        // Uid 0 shares the coldkey of the validator, uid 1 has another coldkey.
+        register_ok_neuron(netuid, U256::from(3), coldkey, 300000);
+        register_ok_neuron(netuid, U256::from(5), U256::from(4), 200000);
+        register_ok_neuron(netuid, validator, coldkey, 100000);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 2, true);
+        let set_weights = || {
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(validator),
+                netuid,
+                vec![0, 1, 2],
+                vec![10, 10, 10],
+                0,
+            )
+        };
+        let stored_weights = || Weights::<Test>::get(netuid, 2).into_inner();
+
+        // Flag off: uids of the same coldkey are weighted as before.
+        assert_ok!(set_weights());
+        assert_eq!(
+            stored_weights(),
+            vec![(0, u16::MAX), (1, u16::MAX), (2, u16::MAX)]
+        );
+
+        // Zeroing mode: only the weight on the uid of the same coldkey is zeroed, the other
+        // coldkey and the self weight are kept.
+        SubtensorModule::set_disallow_self_weight_by_coldkey(netuid, true);
+        step_block(1);
+        assert_ok!(set_weights());
+        assert_eq!(stored_weights(), vec![(0, 0), (1, u16::MAX), (2, u16::MAX)]);
+
+        // Rejection mode: the whole weight set fails.
+        SubtensorModule::set_reject_self_weight_by_coldkey(netuid, true);
+        step_block(1);
+        assert_err!(set_weights(), Error::<Test>::WeightOnSameColdkeyUid);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(validator),
+            netuid,
+            vec![1, 2],
+            vec![10, 10],
+            0,
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_reveal_weights_disallow_self_weight_by_coldkey -- --nocapture
+#[test]
+fn test_reveal_weights_disallow_self_weight_by_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let version_key: u64 = 0;
+        let hotkey: U256 = U256::from(1);
+
+        let commit_hash: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            version_key,
+        ));
+
+        add_network(netuid, 0, 0);
+        SubtensorModule::set_weights_min_stake(0);
+        // Uid 0 shares the coldkey of the validator.
+        register_ok_neuron(netuid, U256::from(3), U256::from(2), 300000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 5);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+
+        // The flag is checked when the weights are revealed, not committed.
+        SubtensorModule::set_disallow_self_weight_by_coldkey(netuid, true);
+        step_block(5);
+
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids,
+            weight_values,
+            salt,
+            version_key,
+        ));
+        assert_eq!(
+            Weights::<Test>::get(netuid, 1).into_inner(),
+            vec![(0, 0), (1, u16::MAX)]
+        );
+    });
+}
+
+// Tests that setting weights directly and revealing them store the same normalized weights, and
+// check them against the max weight limit alike.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test weights test_set_weights_normalization_matches_across_paths -- --nocapture
+#[test]
+fn test_set_weights_normalization_matches_across_paths() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let validator = U256::from(1);
+        add_network(netuid, 1000, 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_weights_min_stake(0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, validator, U256::from(2), 100000);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+
+        // 16385 scales to exactly 32767.5, which rounds up. The largest share is 65535 / 98303,
+        // within a limit of 43690 but not 43689.
+        let uids: Vec<u16> = vec![0, 1];
+        let values: Vec<u16> = vec![16385, 32770];
+        let expected: Vec<(u16, u16)> = vec![(0, 32768), (1, u16::MAX)];
+
+        SubtensorModule::set_max_weight_limit(netuid, 43689);
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(validator),
+                netuid,
+                uids.clone(),
+                values.clone(),
+                0
+            ),
+            Error::<Test>::MaxWeightExceeded
+        );
+        SubtensorModule::set_max_weight_limit(netuid, 43690);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(validator),
+            netuid,
+            uids.clone(),
+            values.clone(),
+            0
+        ));
+        assert_eq!(Weights::<Test>::get(netuid, 1).into_inner(), expected);
+        Weights::<Test>::remove(netuid, 1);
+
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        SubtensorModule::set_max_weight_limit(netuid, 43689);
+        assert_err!(
+            commit_reveal_set_weights(
+                validator,
+                netuid,
+                uids.clone(),
+                values.clone(),
+                salt.clone(),
+                0
+            ),
+            Error::<Test>::MaxWeightExceeded
+        );
+        SubtensorModule::set_max_weight_limit(netuid, 43690);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(validator),
+            netuid,
+            uids,
+            values,
+            salt,
+            0
+        ));
+        assert_eq!(Weights::<Test>::get(netuid, 1).into_inner(), expected);
+    });
+}
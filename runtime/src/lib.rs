@@ -10,6 +10,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 pub mod check_nonce;
 mod migrations;
+pub mod stake_fee_discount;
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::traits::Imbalance;
@@ -17,7 +18,10 @@ use frame_support::{
     dispatch::DispatchResultWithPostInfo,
     genesis_builder_helper::{build_config, create_default_config},
     pallet_prelude::Get,
-    traits::{fungible::HoldConsideration, Contains, LinearStoragePrice, OnUnbalanced},
+    traits::{
+        fungible::HoldConsideration, Contains, EitherOf, LinearStoragePrice, MapSuccess,
+        OnUnbalanced,
+    },
 };
 use frame_system::{EnsureNever, EnsureRoot, EnsureRootWithSuccess, RawOrigin};
 use pallet_balances::NegativeImbalance;
@@ -34,7 +38,8 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata, RuntimeDebug};
 use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{
-        AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+        AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Replace,
+        Verify,
     },
     transaction_validity::{TransactionSource, TransactionValidity},
     ApplyExtrinsicResult, MultiSignature,
@@ -65,6 +70,7 @@ pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{CurrencyAdapter, Multiplier};
+use stake_fee_discount::StakeDiscountedFee;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
@@ -142,7 +148,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 195,
+    spec_version: 196,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -234,7 +240,7 @@ impl frame_system::Config for Runtime {
     // What to do if a new account is created.
     type OnNewAccount = ();
     // What to do if an account is fully reaped from the system.
-    type OnKilledAccount = ();
+    type OnKilledAccount = SubtensorModule;
     // The data to be stored in an account.
     type AccountData = pallet_balances::AccountData<Balance>;
     // Weight information for the extrinsics of this pallet.
@@ -316,10 +322,12 @@ impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
                 | RuntimeCall::Timestamp(_)
                 | RuntimeCall::SubtensorModule(
                     pallet_subtensor::Call::set_weights { .. }
+                        | pallet_subtensor::Call::set_weights_for_child { .. }
                         | pallet_subtensor::Call::set_root_weights { .. }
                         | pallet_subtensor::Call::serve_axon { .. }
                 )
                 | RuntimeCall::Commitments(pallet_commitments::Call::set_commitment { .. })
+                | RuntimeCall::Commitments(pallet_commitments::Call::set_hotkey_commitment { .. })
         )
     }
 }
@@ -406,7 +414,8 @@ impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
 
     //type TransactionByteFee = TransactionByteFee;
-    type OnChargeTransaction = CurrencyAdapter<Balances, TransactionFeeHandler>;
+    // Fees are discounted by the stake tier of the signing coldkey.
+    type OnChargeTransaction = StakeDiscountedFee<CurrencyAdapter<Balances, TransactionFeeHandler>>;
 
     // Convert dispatch weight to a chargeable fee.
     type WeightToFee = LinearWeightToFee<FeeWeightRatio>;
@@ -507,10 +516,44 @@ impl CollectiveInterface<AccountId, Hash, u32> for TriumvirateVotes {
     ) -> Result<bool, sp_runtime::DispatchError> {
         Triumvirate::do_vote(hotkey.clone(), proposal, index, approve)
     }
+
+    fn proposal_index(proposal: Hash) -> Option<u32> {
+        Triumvirate::proposal_index(proposal)
+    }
+
+    fn close_with_outcome(
+        proposal: Hash,
+        index: u32,
+        approved: bool,
+        yes: u32,
+        no: u32,
+    ) -> DispatchResultWithPostInfo {
+        Triumvirate::do_close_with_outcome(proposal, index, approved, yes, no)
+    }
+}
+
+// Stake-weighted senate proposals pass at the stake threshold of pallet_subtensor instead.
+type EnsureSenateStakeApproved =
+    pallet_collective::EnsureStakeApproved<AccountId, TriumvirateCollective>;
+
+type EnsureMajoritySenate = EitherOf<
+    pallet_collective::EnsureProportionMoreThan<AccountId, TriumvirateCollective, 1, 2>,
+    EnsureSenateStakeApproved,
+>;
+
+parameter_types! {
+    // A stake-weighted proposal already reached `StakeWeightedVoteThreshold`, so it counts as
+    // approved by the whole senate.
+    pub const StakeApprovedSenateVotes: (u32, u32) = (1, 1);
 }
 
-type EnsureMajoritySenate =
-    pallet_collective::EnsureProportionMoreThan<AccountId, TriumvirateCollective, 1, 2>;
+// Executed senate proposals may dispatch the network-wide admin calls whitelisted in
+// pallet_admin_utils, which checks their ayes against its `SenateApprovalThreshold`.
+// Proposals live for at least `CouncilMotionDuration` blocks.
+type EnsureSenateMajority = EitherOf<
+    pallet_collective::EnsureMembers<AccountId, TriumvirateCollective, 1>,
+    MapSuccess<EnsureSenateStakeApproved, Replace<StakeApprovedSenateVotes>>,
+>;
 
 // We call pallet_collective TriumvirateCollective
 type TriumvirateCollective = pallet_collective::Instance1;
@@ -641,14 +684,24 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
     fn filter(&self, c: &RuntimeCall) -> bool {
         match self {
             ProxyType::Any => true,
-            ProxyType::NonTransfer => !matches!(c, RuntimeCall::Balances(..)),
+            ProxyType::NonTransfer => !matches!(
+                c,
+                RuntimeCall::Balances(..)
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burn_tao { .. })
+            ),
             ProxyType::NonFungibile => !matches!(
                 c,
                 RuntimeCall::Balances(..)
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_register_by_proxy { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burn_tao { .. })
             ),
             ProxyType::Transfer => matches!(
                 c,
@@ -672,6 +725,11 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::dissolve_network { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_register_by_proxy { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::deregister { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burn_tao { .. })
                     | RuntimeCall::Triumvirate(..)
             ),
             ProxyType::Triumvirate => matches!(
@@ -693,6 +751,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             ProxyType::Registration => matches!(
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_register_by_proxy { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::register { .. })
             ),
         }
@@ -841,10 +902,10 @@ impl pallet_registry::Config for Runtime {
 }
 
 parameter_types! {
-    pub const MaxCommitFields: u32 = 1;
+    pub const MaxCommitFields: u32 = 16;
+    pub const MaxCommitmentSize: u32 = 1024;
     pub const CommitmentInitialDeposit: Balance = 0; // Free
-    pub const CommitmentFieldDeposit: Balance = 0; // Free
-    pub const CommitmentRateLimit: BlockNumber = 100; // Allow commitment every 100 blocks
+    pub const CommitmentByteDeposit: Balance = 0; // Free
 }
 
 pub struct AllowCommitments;
@@ -858,6 +919,47 @@ impl CanCommit<AccountId> for AllowCommitments {
     fn can_commit(_: u16, _: &AccountId) -> bool {
         true
     }
+
+    fn is_owner(hotkey: &AccountId, coldkey: &AccountId) -> bool {
+        SubtensorModule::coldkey_owns_hotkey(coldkey, hotkey)
+    }
+}
+
+pub struct SubnetTempo;
+impl pallet_commitments::GetTempo for SubnetTempo {
+    fn get_tempo(netuid: u16) -> u16 {
+        SubtensorModule::get_tempo(netuid)
+    }
+}
+
+pub struct PurgeCommitments;
+impl pallet_subtensor::OnNeuronRemoved<AccountId> for PurgeCommitments {
+    fn on_neuron_removed(netuid: u16, hotkey: &AccountId) {
+        Commitments::purge_commitment(netuid, hotkey);
+    }
+
+    fn on_subnet_removed(netuid: u16) {
+        Commitments::purge_subnet(netuid);
+    }
+
+    fn on_hotkey_swapped(netuid: u16, old_hotkey: &AccountId, new_hotkey: &AccountId) {
+        Commitments::swap_commitment(netuid, old_hotkey, new_hotkey);
+    }
+}
+
+pub struct HotkeyCommitments;
+impl pallet_subtensor::CommitmentQuery<AccountId> for HotkeyCommitments {
+    fn has_commitment(netuid: u16, hotkey: &AccountId) -> bool {
+        Commitments::get_commitment(netuid, hotkey).is_some()
+    }
+
+    fn last_commitment_block(netuid: u16, hotkey: &AccountId) -> Option<u64> {
+        Commitments::last_commitment(netuid, hotkey).map(u64::from)
+    }
+
+    fn commitment_rate_limit(netuid: u16) -> u64 {
+        u64::from(Commitments::rate_limit_blocks(netuid))
+    }
 }
 
 impl pallet_commitments::Config for Runtime {
@@ -866,11 +968,12 @@ impl pallet_commitments::Config for Runtime {
     type WeightInfo = pallet_commitments::weights::SubstrateWeight<Runtime>;
 
     type CanCommit = AllowCommitments;
+    type TempoInterface = SubnetTempo;
 
     type MaxFields = MaxCommitFields;
+    type MaxCommitmentSize = MaxCommitmentSize;
     type InitialDeposit = CommitmentInitialDeposit;
-    type FieldDeposit = CommitmentFieldDeposit;
-    type RateLimit = CommitmentRateLimit;
+    type ByteDeposit = CommitmentByteDeposit;
 }
 
 #[cfg(not(feature = "fast-blocks"))]
@@ -939,18 +1042,26 @@ parameter_types! {
     pub const SubtensorInitialHotkeyEmissionTempo: u64 = 7200; // Drain every day.
     pub const SubtensorInitialNetworkMaxStake: u64 = u64::MAX; // Maximum possible value for u64, this make the make stake infinity
     pub const  InitialColdkeySwapScheduleDuration: BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const  InitialColdkeySwapRedirectDuration: BlockNumber = 180 * 24 * 60 * 60 / 12; // 6 months
     pub const  InitialDissolveNetworkScheduleDuration: BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const SubtensorInitialPendingChildKeyCooldown: u64 = 7200; // 1 day
+    pub const SubtensorInitialWeightCommitDeposit: u64 = 10_000_000; // 0.01 TAO
 
 }
 
 impl pallet_subtensor::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
+    type CallSignature = Signature;
+    type CallSigner = <Signature as Verify>::Signer;
     type SudoRuntimeCall = RuntimeCall;
     type Currency = Balances;
+    type VestingCurrency = Balances;
     type CouncilOrigin = EnsureMajoritySenate;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type OnNeuronRemoved = PurgeCommitments;
+    type Commitments = HotkeyCommitments;
     type Scheduler = Scheduler;
     type InitialRho = SubtensorInitialRho;
     type InitialKappa = SubtensorInitialKappa;
@@ -1005,7 +1116,10 @@ impl pallet_subtensor::Config for Runtime {
     type InitialNetworkMaxStake = SubtensorInitialNetworkMaxStake;
     type Preimages = Preimage;
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
+    type InitialColdkeySwapRedirectDuration = InitialColdkeySwapRedirectDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type InitialPendingChildKeyCooldown = SubtensorInitialPendingChildKeyCooldown;
+    type InitialWeightCommitDeposit = SubtensorInitialWeightCommitDeposit;
 }
 
 use sp_runtime::BoundedVec;
@@ -1019,11 +1133,13 @@ impl pallet_admin_utils::AuraInterface<AuraId, ConstU32<32>> for AuraPalletIntrf
 
 impl pallet_admin_utils::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
     type AuthorityId = AuraId;
     type MaxAuthorities = ConstU32<32>;
     type Aura = AuraPalletIntrf;
     type Balance = Balance;
     type WeightInfo = pallet_admin_utils::weights::SubstrateWeight<Runtime>;
+    type SenateOrigin = EnsureSenateMajority;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -1408,6 +1524,48 @@ impl_runtime_apis! {
                 vec![]
             }
         }
+
+        fn get_weight_setting_status(netuid: u16, hotkey_account_vec: Vec<u8>) -> Vec<u8> {
+            let _result = SubtensorModule::get_weight_setting_status_for_account(netuid, hotkey_account_vec);
+            if _result.is_some() {
+                let result = _result.expect("Could not get WeightSettingStatus");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_hotkey_rate_limit_status(netuid: u16, hotkey_account_vec: Vec<u8>) -> Vec<u8> {
+            let _result = SubtensorModule::get_hotkey_rate_limit_status_for_account(netuid, hotkey_account_vec);
+            if _result.is_some() {
+                let result = _result.expect("Could not get HotkeyRateLimitStatus");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_hotkey_registrations(hotkey_account_vec: Vec<u8>) -> Vec<u8> {
+            let _result = SubtensorModule::get_hotkey_registrations_for_account(hotkey_account_vec);
+            if _result.is_some() {
+                let result = _result.expect("Could not get HotkeyRegistrations");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_pruning_scores(netuid: u16) -> Vec<u16> {
+            SubtensorModule::get_pruning_scores(netuid)
+        }
+
+        fn get_pruning_score_for_uid(netuid: u16, uid: u16) -> Option<u16> {
+            SubtensorModule::get_pruning_score_for_neuron(netuid, uid)
+        }
+
+        fn get_netuids_for_hotkey(hotkey_account_vec: Vec<u8>) -> Vec<u16> {
+            SubtensorModule::get_netuids_for_hotkey_account(hotkey_account_vec).unwrap_or_default()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi<Block> for Runtime {
@@ -1450,6 +1608,35 @@ impl_runtime_apis! {
                 vec![]
             }
         }
+
+        fn get_epoch_history(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_epoch_history(netuid);
+            result.encode()
+        }
+
+        fn get_epoch_commitment(netuid: u16, epoch_index: u64) -> Vec<u8> {
+            let result = SubtensorModule::get_epoch_commitment(netuid, epoch_index);
+            result.encode()
+        }
+
+        fn get_all_subnet_netuids() -> Vec<u16> {
+            SubtensorModule::get_subnet_netuids()
+        }
+
+        fn get_subnets_count() -> u16 {
+            SubtensorModule::get_subnets_count()
+        }
+
+        fn subnet_exists(netuid: u16) -> bool {
+            SubtensorModule::if_subnet_exist(netuid)
+        }
+
+        fn validate_subnet_params(netuid: u16) -> Vec<u8> {
+            match SubtensorModule::validate_subnet_params(netuid) {
+                Some(violations) => violations.encode(),
+                None => vec![],
+            }
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block> for Runtime {
@@ -1462,12 +1649,212 @@ impl_runtime_apis! {
             let result = SubtensorModule::get_stake_info_for_coldkeys( coldkey_account_vecs );
             result.encode()
         }
+
+        fn simulate_remove_stake( coldkey_account_vec: Vec<u8>, hotkey_account_vec: Vec<u8>, amount: u64 ) -> Vec<u8> {
+            let _result = SubtensorModule::simulate_remove_stake_for_accounts( coldkey_account_vec, hotkey_account_vec, amount );
+            if _result.is_some() {
+                let result = _result.expect("Could not get StakeSimulation");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn simulate_add_stake( coldkey_account_vec: Vec<u8>, hotkey_account_vec: Vec<u8>, amount: u64 ) -> Vec<u8> {
+            let _result = SubtensorModule::simulate_add_stake_for_accounts( coldkey_account_vec, hotkey_account_vec, amount );
+            if _result.is_some() {
+                let result = _result.expect("Could not get StakeSimulation");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_top_stakes_on_subnet( netuid: u16, start: u32, limit: u32 ) -> Vec<u8> {
+            let result = SubtensorModule::get_top_stakes_on_subnet( netuid, start, limit );
+            result.encode()
+        }
+
+        fn get_stake_for_hotkey_and_coldkey_on_netuid( hotkey_account_vec: Vec<u8>, coldkey_account_vec: Vec<u8>, netuid: u16 ) -> u64 {
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_netuid_for_accounts( hotkey_account_vec, coldkey_account_vec, netuid ).unwrap_or(0)
+        }
+
+        fn get_nominators_for_hotkey( hotkey_account_vec: Vec<u8>, netuid: u16, start: u32, limit: u32 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_nominators_for_hotkey_account( hotkey_account_vec, netuid, start, limit );
+            if _result.is_some() {
+                let result = _result.expect("Could not get NominatorsPage");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_nominator_count( hotkey_account_vec: Vec<u8>, netuid: u16 ) -> u32 {
+            SubtensorModule::get_nominator_count_for_account( hotkey_account_vec, netuid ).unwrap_or(0)
+        }
+
+        fn get_hotkey_stake_breakdown( hotkey_account_vec: Vec<u8>, netuid: u16 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_hotkey_stake_breakdown_for_account( hotkey_account_vec, netuid );
+            if _result.is_some() {
+                let result = _result.expect("Could not get HotkeyStakeBreakdown");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_stake_rate_limits( coldkey_account_vec: Vec<u8>, hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_stake_rate_limits_for_accounts( coldkey_account_vec, hotkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get StakeRateLimits");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn vested_balance( coldkey_account_vec: Vec<u8> ) -> u64 {
+            SubtensorModule::get_vested_balance_for_account( coldkey_account_vec ).unwrap_or(0)
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
         fn get_network_registration_cost() -> u64 {
             SubtensorModule::get_network_lock_cost()
         }
+
+        fn get_network_registration_allowed_block() -> u64 {
+            SubtensorModule::get_network_registration_allowed_block()
+        }
+
+        fn get_registration_info(netuid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_registration_info(netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get RegistrationInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::NetworkStatsRuntimeApi<Block> for Runtime {
+        fn get_network_stats() -> Vec<u8> {
+            let result = SubtensorModule::get_network_stats();
+            result.encode()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::EmissionInfoRuntimeApi<Block> for Runtime {
+        fn get_emission_info_for_hotkey( hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_emission_info_for_hotkey( hotkey_account_vec );
+            result.encode()
+        }
+
+        fn get_pending_emission( netuid: u16 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_pending_emission_info( netuid );
+            if _result.is_some() {
+                let result = _result.expect("Could not get PendingEmissionInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_pending_hotkey_emission( hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_pending_hotkey_emission_info_for_hotkey( hotkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get PendingHotkeyEmissionInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::HotkeysInfoRuntimeApi<Block> for Runtime {
+        fn get_owned_hotkeys_paged( coldkey_account_vec: Vec<u8>, start: u32, limit: u32 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_owned_hotkeys_paged_for_coldkey( coldkey_account_vec, start, limit );
+            if _result.is_some() {
+                let result = _result.expect("Could not get HotkeysPage");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_staking_hotkeys_paged( coldkey_account_vec: Vec<u8>, start: u32, limit: u32 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_staking_hotkeys_paged_for_coldkey( coldkey_account_vec, start, limit );
+            if _result.is_some() {
+                let result = _result.expect("Could not get HotkeysPage");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_owned_hotkeys_on_subnet_paged( coldkey_account_vec: Vec<u8>, netuid: u16, start: u32, limit: u32 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_owned_hotkeys_on_subnet_paged_for_coldkey( coldkey_account_vec, netuid, start, limit );
+            if _result.is_some() {
+                let result = _result.expect("Could not get HotkeysPage");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_staking_hotkeys_on_subnet_paged( coldkey_account_vec: Vec<u8>, netuid: u16, start: u32, limit: u32 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_staking_hotkeys_on_subnet_paged_for_coldkey( coldkey_account_vec, netuid, start, limit );
+            if _result.is_some() {
+                let result = _result.expect("Could not get HotkeysPage");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::KeySwapRuntimeApi<Block> for Runtime {
+        fn get_key_swap_cost() -> u64 {
+            SubtensorModule::get_key_swap_cost()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::ChainConstantsRuntimeApi<Block> for Runtime {
+        fn get_chain_constants() -> Vec<u8> {
+            let result = SubtensorModule::get_chain_constants();
+            result.encode()
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    impl subtensor_custom_rpc_runtime_api::ColdkeySwapDryRunRuntimeApi<Block> for Runtime {
+        fn execute_coldkey_swap_dry_run( old_coldkey_account_vec: Vec<u8>, new_coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::execute_coldkey_swap_dry_run_for_accounts(old_coldkey_account_vec, new_coldkey_account_vec);
+            if _result.is_some() {
+                let result = _result.expect("Could not get ColdkeySwapDryRun");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::CommitmentsRuntimeApi<Block> for Runtime {
+        fn get_commitment( netuid: u16, hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let Ok(hotkey) = AccountId::decode(&mut hotkey_account_vec.as_slice()) else {
+                return vec![];
+            };
+            match Commitments::get_commitment( netuid, &hotkey ) {
+                Some(registration) => registration.encode(),
+                None => vec![],
+            }
+        }
+
+        fn get_attestation( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_attestation_for_account( coldkey_account_vec );
+            result.encode()
+        }
     }
 }
 
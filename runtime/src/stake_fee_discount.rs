@@ -0,0 +1,73 @@
+use crate::{Balance, Runtime, RuntimeCall, SubtensorModule};
+use frame_support::dispatch::Pays;
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+    traits::{DispatchInfoOf, PostDispatchInfoOf, Zero},
+    transaction_validity::TransactionValidityError,
+    Percent,
+};
+use sp_std::marker::PhantomData;
+
+/// Charges transaction fees through `Inner`, discounted by the stake tier of the signer.
+///
+/// The discount applies to the fee without the tip. The tier is read once per transaction, from
+/// the cache the subtensor pallet keeps up to date as stake changes, and not at all for calls
+/// that do not pay fees.
+pub struct StakeDiscountedFee<Inner>(PhantomData<Inner>);
+
+impl<Inner> OnChargeTransaction<Runtime> for StakeDiscountedFee<Inner>
+where
+    Inner: OnChargeTransaction<Runtime, Balance = Balance>,
+{
+    type Balance = Balance;
+    // The discount is kept to correct the fee after dispatch without reading the tier again.
+    type LiquidityInfo = (Inner::LiquidityInfo, Percent);
+
+    fn withdraw_fee(
+        who: &<Runtime as frame_system::Config>::AccountId,
+        call: &RuntimeCall,
+        dispatch_info: &DispatchInfoOf<RuntimeCall>,
+        fee: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let discount = match dispatch_info.pays_fee {
+            Pays::Yes => SubtensorModule::get_fee_discount(who),
+            Pays::No => Percent::zero(),
+        };
+        let liquidity_info = Inner::withdraw_fee(
+            who,
+            call,
+            dispatch_info,
+            discounted_fee(fee, tip, discount),
+            tip,
+        )?;
+        Ok((liquidity_info, discount))
+    }
+
+    fn correct_and_deposit_fee(
+        who: &<Runtime as frame_system::Config>::AccountId,
+        dispatch_info: &DispatchInfoOf<RuntimeCall>,
+        post_info: &PostDispatchInfoOf<RuntimeCall>,
+        corrected_fee: Self::Balance,
+        tip: Self::Balance,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        let (liquidity_info, discount) = already_withdrawn;
+        Inner::correct_and_deposit_fee(
+            who,
+            dispatch_info,
+            post_info,
+            discounted_fee(corrected_fee, tip, discount),
+            tip,
+            liquidity_info,
+        )
+    }
+}
+
+/// Applies `discount` to `fee`, which includes `tip`, leaving the tip whole.
+pub fn discounted_fee(fee: Balance, tip: Balance, discount: Percent) -> Balance {
+    let base_fee = fee.saturating_sub(tip);
+    base_fee
+        .saturating_sub(discount.mul_floor(base_fee))
+        .saturating_add(tip)
+}
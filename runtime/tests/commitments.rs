@@ -0,0 +1,69 @@
+#![allow(clippy::unwrap_used)]
+
+use frame_support::assert_ok;
+use node_subtensor_runtime::{
+    AccountId, BuildStorage, Commitments, RuntimeGenesisConfig, RuntimeOrigin, SubtensorModule,
+    System,
+};
+use pallet_commitments::{CommitmentInfo, Data};
+
+const HOTKEY: [u8; 32] = [1_u8; 32];
+const NEW_HOTKEY: [u8; 32] = [2_u8; 32];
+const NETUID: u16 = 1;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+        balances: pallet_balances::GenesisConfig {
+            balances: vec![(AccountId::from(HOTKEY), 1_000_000_000_000)],
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+    .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// registers the hotkey on the subnet and commits some data for it
+fn commit_for_registered_hotkey() {
+    SubtensorModule::init_new_network(NETUID, 10);
+    SubtensorModule::append_neuron(NETUID, &AccountId::from(HOTKEY), 0);
+
+    let info = CommitmentInfo {
+        fields: vec![Data::Raw(vec![1; 32].try_into().unwrap())]
+            .try_into()
+            .unwrap(),
+    };
+    assert_ok!(Commitments::set_commitment(
+        RuntimeOrigin::signed(AccountId::from(HOTKEY)),
+        NETUID,
+        Box::new(info)
+    ));
+    assert!(Commitments::commitment_of(NETUID, AccountId::from(HOTKEY)).is_some());
+}
+
+#[test]
+fn test_commitment_removed_on_prune() {
+    new_test_ext().execute_with(|| {
+        commit_for_registered_hotkey();
+
+        SubtensorModule::replace_neuron(NETUID, 0, &AccountId::from(NEW_HOTKEY), 1);
+
+        assert!(Commitments::commitment_of(NETUID, AccountId::from(HOTKEY)).is_none());
+        assert!(Commitments::last_commitment(NETUID, AccountId::from(HOTKEY)).is_none());
+    });
+}
+
+#[test]
+fn test_commitment_removed_on_subnet_dissolve() {
+    new_test_ext().execute_with(|| {
+        commit_for_registered_hotkey();
+
+        SubtensorModule::remove_network(NETUID);
+
+        assert!(Commitments::commitment_of(NETUID, AccountId::from(HOTKEY)).is_none());
+        assert!(Commitments::last_commitment(NETUID, AccountId::from(HOTKEY)).is_none());
+    });
+}
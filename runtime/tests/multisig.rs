@@ -0,0 +1,214 @@
+#![allow(clippy::unwrap_used)]
+
+use codec::Encode;
+use frame_support::{assert_ok, dispatch::GetDispatchInfo, weights::Weight};
+use node_subtensor_runtime::{
+    AccountId, BuildStorage, Multisig, Proxy, ProxyType, Runtime, RuntimeCall, RuntimeEvent,
+    RuntimeGenesisConfig, RuntimeOrigin, SubtensorModule, System,
+};
+use sp_runtime::{DispatchError, DispatchResult};
+
+const ALICE: [u8; 32] = [1_u8; 32];
+const BOB: [u8; 32] = [2_u8; 32];
+const CHARLIE: [u8; 32] = [3_u8; 32];
+const HOTKEY: [u8; 32] = [4_u8; 32];
+const NEW_COLDKEY: [u8; 32] = [5_u8; 32];
+
+const THRESHOLD: u16 = 2;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let amount = 1_000_000_000_000;
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+        balances: pallet_balances::GenesisConfig {
+            balances: vec![
+                (AccountId::from(ALICE), amount),
+                (AccountId::from(BOB), amount),
+                (AccountId::from(CHARLIE), amount),
+                (multisig_account(), amount),
+            ],
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+    .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// the 2-of-3 multisig coldkey of alice, bob and charlie
+fn multisig_account() -> AccountId {
+    Multisig::multi_account_id(
+        &[
+            AccountId::from(ALICE),
+            AccountId::from(BOB),
+            AccountId::from(CHARLIE),
+        ],
+        THRESHOLD,
+    )
+}
+
+// returns the result of the last call executed by a multisig
+fn last_multisig_result() -> DispatchResult {
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::Multisig(pallet_multisig::Event::MultisigExecuted { result, .. }) => {
+                Some(result)
+            }
+            _ => None,
+        })
+        .unwrap()
+}
+
+// alice opens the multisig operation and bob approves and executes it
+fn dispatch_as_multisig(call: RuntimeCall) -> DispatchResult {
+    let max_weight: Weight = call.get_dispatch_info().weight;
+    let call_hash = call.using_encoded(sp_io::hashing::blake2_256);
+
+    assert_ok!(Multisig::as_multi(
+        RuntimeOrigin::signed(AccountId::from(ALICE)),
+        THRESHOLD,
+        vec![AccountId::from(BOB), AccountId::from(CHARLIE)],
+        None,
+        Box::new(call.clone()),
+        Weight::zero(),
+    ));
+    let timepoint = pallet_multisig::Multisigs::<Runtime>::get(multisig_account(), call_hash)
+        .unwrap()
+        .when;
+
+    assert_ok!(Multisig::as_multi(
+        RuntimeOrigin::signed(AccountId::from(BOB)),
+        THRESHOLD,
+        vec![AccountId::from(ALICE), AccountId::from(CHARLIE)],
+        Some(timepoint),
+        Box::new(call),
+        max_weight,
+    ));
+    last_multisig_result()
+}
+
+// creates the hotkey owned by the multisig coldkey
+fn create_multisig_hotkey() {
+    assert_ok!(SubtensorModule::create_account_if_non_existent(
+        &multisig_account(),
+        &AccountId::from(HOTKEY)
+    ));
+}
+
+#[test]
+fn test_multisig_add_stake() {
+    new_test_ext().execute_with(|| {
+        let amount_staked = 1_000_000_000;
+        create_multisig_hotkey();
+
+        assert_ok!(dispatch_as_multisig(RuntimeCall::SubtensorModule(
+            pallet_subtensor::Call::add_stake {
+                hotkey: AccountId::from(HOTKEY),
+                amount_staked,
+            }
+        )));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(
+                &multisig_account(),
+                &AccountId::from(HOTKEY)
+            ),
+            amount_staked
+        );
+    });
+}
+
+#[test]
+fn test_multisig_become_delegate() {
+    new_test_ext().execute_with(|| {
+        create_multisig_hotkey();
+
+        assert_ok!(dispatch_as_multisig(RuntimeCall::SubtensorModule(
+            pallet_subtensor::Call::become_delegate {
+                hotkey: AccountId::from(HOTKEY),
+            }
+        )));
+
+        assert!(SubtensorModule::hotkey_is_delegate(&AccountId::from(
+            HOTKEY
+        )));
+    });
+}
+
+#[test]
+fn test_multisig_swap_coldkey() {
+    new_test_ext().execute_with(|| {
+        create_multisig_hotkey();
+
+        assert_ok!(dispatch_as_multisig(RuntimeCall::SubtensorModule(
+            pallet_subtensor::Call::schedule_swap_coldkey {
+                new_coldkey: AccountId::from(NEW_COLDKEY),
+            }
+        )));
+        assert!(
+            pallet_subtensor::ColdkeySwapScheduled::<Runtime>::contains_key(multisig_account())
+        );
+
+        // The multisig is the coldkey of the scheduled swap, not the signatory that executed it.
+        assert_eq!(
+            dispatch_as_multisig(RuntimeCall::SubtensorModule(
+                pallet_subtensor::Call::burn_tao { amount: 1_000 }
+            )),
+            Err(DispatchError::from(
+                pallet_subtensor::Error::<Runtime>::ColdkeyInSwapSchedule
+            ))
+        );
+
+        // The scheduler dispatches the swap as root.
+        assert_ok!(SubtensorModule::swap_coldkey(
+            RuntimeOrigin::root(),
+            multisig_account(),
+            AccountId::from(NEW_COLDKEY)
+        ));
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&AccountId::from(HOTKEY)),
+            AccountId::from(NEW_COLDKEY)
+        );
+    });
+}
+
+#[test]
+fn test_pure_proxy_add_stake() {
+    new_test_ext().execute_with(|| {
+        let amount_staked = 1_000_000_000;
+        assert_ok!(Proxy::create_pure(
+            RuntimeOrigin::signed(AccountId::from(ALICE)),
+            ProxyType::Any,
+            0,
+            0
+        ));
+        let pure = Proxy::pure_account(&AccountId::from(ALICE), &ProxyType::Any, 0, None);
+        SubtensorModule::add_balance_to_coldkey_account(&pure, 10_000_000_000);
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &pure,
+            &AccountId::from(HOTKEY)
+        ));
+
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(AccountId::from(ALICE)),
+            pure.clone().into(),
+            None,
+            Box::new(RuntimeCall::SubtensorModule(
+                pallet_subtensor::Call::add_stake {
+                    hotkey: AccountId::from(HOTKEY),
+                    amount_staked,
+                }
+            )),
+        ));
+        System::assert_last_event(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }.into());
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&pure, &AccountId::from(HOTKEY)),
+            amount_staked
+        );
+    });
+}
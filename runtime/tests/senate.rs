@@ -0,0 +1,164 @@
+#![allow(clippy::unwrap_used)]
+
+use codec::Encode;
+use frame_support::{assert_ok, dispatch::GetDispatchInfo, traits::Get, BoundedVec};
+use node_subtensor_runtime::{
+    AccountId, AdminUtils, BuildStorage, CouncilMotionDuration, Runtime, RuntimeCall, RuntimeEvent,
+    RuntimeGenesisConfig, RuntimeOrigin, SubtensorModule, System, Triumvirate,
+};
+use sp_runtime::DispatchError;
+
+const PROPOSER: [u8; 32] = [1_u8; 32];
+const SENATE_HOTKEYS: [[u8; 32]; 3] = [[11_u8; 32], [12_u8; 32], [13_u8; 32]];
+const SENATE_COLDKEYS: [[u8; 32]; 3] = [[21_u8; 32], [22_u8; 32], [23_u8; 32]];
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+        triumvirate: pallet_collective::GenesisConfig {
+            members: vec![AccountId::from(PROPOSER)],
+            phantom: Default::default(),
+        },
+        senate_members: pallet_membership::GenesisConfig {
+            members: BoundedVec::try_from(
+                SENATE_HOTKEYS
+                    .iter()
+                    .map(|hotkey| AccountId::from(*hotkey))
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap(),
+            phantom: Default::default(),
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+    .into();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        for (hotkey, coldkey) in SENATE_HOTKEYS.iter().zip(SENATE_COLDKEYS.iter()) {
+            pallet_subtensor::Owner::<Runtime>::insert(
+                AccountId::from(*hotkey),
+                AccountId::from(*coldkey),
+            );
+        }
+    });
+    ext
+}
+
+// Proposes `call` to the senate, has the first `ayes` senators approve it and closes the vote.
+fn pass_senate_proposal(call: RuntimeCall, ayes: usize) {
+    let length_bound: u32 = call.using_encoded(|c| c.len() as u32);
+    let weight_bound = call.get_dispatch_info().weight;
+
+    assert_ok!(Triumvirate::propose(
+        RuntimeOrigin::signed(AccountId::from(PROPOSER)),
+        Box::new(call),
+        length_bound,
+        CouncilMotionDuration::get(),
+    ));
+    let proposal_hash = *Triumvirate::proposals().first().unwrap();
+
+    for (hotkey, coldkey) in SENATE_HOTKEYS.iter().zip(SENATE_COLDKEYS.iter()).take(ayes) {
+        assert_ok!(SubtensorModule::vote(
+            RuntimeOrigin::signed(AccountId::from(*coldkey)),
+            AccountId::from(*hotkey),
+            proposal_hash,
+            0,
+            true,
+        ));
+    }
+
+    assert_ok!(Triumvirate::close(
+        RuntimeOrigin::signed(AccountId::from(PROPOSER)),
+        proposal_hash,
+        0,
+        weight_bound,
+        length_bound,
+    ));
+}
+
+fn last_executed_result() -> Option<Result<(), DispatchError>> {
+    System::events()
+        .iter()
+        .rev()
+        .find_map(|record| match &record.event {
+            RuntimeEvent::Triumvirate(pallet_collective::Event::Executed { result, .. }) => {
+                Some(*result)
+            }
+            _ => None,
+        })
+}
+
+#[test]
+fn test_senate_supermajority_sets_default_take() {
+    new_test_ext().execute_with(|| {
+        let new_take: u16 = 1_000;
+        assert_ne!(SubtensorModule::get_default_delegate_take(), new_take);
+
+        let call = RuntimeCall::AdminUtils(pallet_admin_utils::Call::sudo_set_default_take {
+            default_take: new_take,
+        });
+        pass_senate_proposal(call, 2);
+
+        assert_eq!(last_executed_result(), Some(Ok(())));
+        assert_eq!(SubtensorModule::get_default_delegate_take(), new_take);
+    });
+}
+
+#[test]
+fn test_senate_cannot_dispatch_non_whitelisted_call() {
+    new_test_ext().execute_with(|| {
+        let max_subnets: u16 = SubtensorModule::get_max_subnets();
+
+        let call = RuntimeCall::AdminUtils(pallet_admin_utils::Call::sudo_set_subnet_limit {
+            max_subnets: max_subnets.saturating_add(1),
+        });
+        pass_senate_proposal(call, 3);
+
+        assert_eq!(last_executed_result(), Some(Err(DispatchError::BadOrigin)));
+        assert_eq!(SubtensorModule::get_max_subnets(), max_subnets);
+    });
+}
+
+#[test]
+fn test_senate_approval_threshold_is_root_settable() {
+    new_test_ext().execute_with(|| {
+        let take: u16 = SubtensorModule::get_default_delegate_take();
+        assert_ok!(AdminUtils::sudo_set_senate_approval_threshold(
+            RuntimeOrigin::root(),
+            u16::MAX
+        ));
+
+        // Two ayes out of three seats execute the proposal but fall short of a unanimous
+        // threshold.
+        let call = RuntimeCall::AdminUtils(pallet_admin_utils::Call::sudo_set_default_take {
+            default_take: take.saturating_add(1),
+        });
+        pass_senate_proposal(call, 2);
+
+        assert_eq!(
+            last_executed_result(),
+            Some(Err(
+                pallet_admin_utils::Error::<Runtime>::SenateApprovalBelowThreshold.into()
+            ))
+        );
+        assert_eq!(SubtensorModule::get_default_delegate_take(), take);
+    });
+}
+
+#[test]
+fn test_senate_dissolves_subnet() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        SubtensorModule::init_new_network(netuid, 10);
+        assert!(SubtensorModule::if_subnet_exist(netuid));
+
+        let call =
+            RuntimeCall::AdminUtils(pallet_admin_utils::Call::sudo_dissolve_network { netuid });
+        pass_senate_proposal(call, 2);
+
+        assert_eq!(last_executed_result(), Some(Ok(())));
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+    });
+}
@@ -0,0 +1,117 @@
+#![allow(clippy::unwrap_used, clippy::arithmetic_side_effects)]
+
+use frame_support::{
+    assert_ok,
+    dispatch::{DispatchInfo, Pays},
+};
+use node_subtensor_runtime::{
+    stake_fee_discount::discounted_fee, AccountId, Balances, BuildStorage, Runtime, RuntimeCall,
+    RuntimeGenesisConfig, SubtensorModule, System,
+};
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::Percent;
+
+type FeeHandler = <Runtime as pallet_transaction_payment::Config>::OnChargeTransaction;
+
+const COLDKEY: [u8; 32] = [1_u8; 32];
+const HOTKEY: [u8; 32] = [2_u8; 32];
+const FEE: u64 = 1_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+        balances: pallet_balances::GenesisConfig {
+            balances: vec![(AccountId::from(COLDKEY), 1_000_000_000_000)],
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+    .into();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(SubtensorModule::set_stake_fee_discount_thresholds([
+            1_000, 10_000, 100_000
+        ]));
+    });
+    ext
+}
+
+// Withdraws `fee` with `tip` from the coldkey for a call with `pays_fee`, returning the amount
+// charged.
+fn charge(pays_fee: Pays, fee: u64, tip: u64) -> u64 {
+    let who = AccountId::from(COLDKEY);
+    let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+    let info = DispatchInfo {
+        pays_fee,
+        ..Default::default()
+    };
+    let before = Balances::free_balance(&who);
+    assert_ok!(FeeHandler::withdraw_fee(&who, &call, &info, fee, tip));
+    before - Balances::free_balance(&who)
+}
+
+fn stake(amount: u64) {
+    SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+        &AccountId::from(COLDKEY),
+        &AccountId::from(HOTKEY),
+        amount,
+    );
+}
+
+#[test]
+fn test_unstaked_account_pays_full_fee() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(charge(Pays::Yes, FEE, 0), FEE);
+    });
+}
+
+#[test]
+fn test_fee_discounted_at_each_tier() {
+    new_test_ext().execute_with(|| {
+        stake(999);
+        assert_eq!(charge(Pays::Yes, FEE, 0), FEE);
+
+        stake(1);
+        assert_eq!(charge(Pays::Yes, FEE, 0), 900_000);
+
+        stake(9_000);
+        assert_eq!(charge(Pays::Yes, FEE, 0), 750_000);
+
+        stake(90_000);
+        assert_eq!(charge(Pays::Yes, FEE, 0), 500_000);
+
+        // The tip is not discounted.
+        assert_eq!(charge(Pays::Yes, FEE + 1_000, 1_000), 501_000);
+    });
+}
+
+#[test]
+fn test_fee_discount_follows_unstaking() {
+    new_test_ext().execute_with(|| {
+        stake(100_000);
+        assert_eq!(charge(Pays::Yes, FEE, 0), 500_000);
+
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(
+            &AccountId::from(COLDKEY),
+            &AccountId::from(HOTKEY),
+            95_000,
+        );
+        assert_eq!(charge(Pays::Yes, FEE, 0), 900_000);
+
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(
+            &AccountId::from(COLDKEY),
+            &AccountId::from(HOTKEY),
+            5_000,
+        );
+        assert_eq!(charge(Pays::Yes, FEE, 0), FEE);
+    });
+}
+
+#[test]
+fn test_discounted_fee() {
+    assert_eq!(discounted_fee(1_000, 0, Percent::zero()), 1_000);
+    assert_eq!(discounted_fee(1_000, 0, Percent::from_percent(50)), 500);
+    assert_eq!(discounted_fee(1_100, 100, Percent::from_percent(50)), 600);
+    assert_eq!(discounted_fee(100, 100, Percent::from_percent(50)), 100);
+}